@@ -0,0 +1,22 @@
+"""Mark reminder {"id"} (JSON on stdin) as done."""
+
+import json
+import sys
+
+import store
+
+
+def main():
+    reminder_id = json.load(sys.stdin)["id"]
+    reminders = store.load()
+    for reminder in reminders:
+        if reminder["id"] == reminder_id and not reminder.get("done"):
+            reminder["done"] = True
+            store.save(reminders)
+            print(f"Cleared reminder #{reminder_id}: {reminder['text']}")
+            return
+    sys.exit(f"No open reminder #{reminder_id}")
+
+
+if __name__ == "__main__":
+    main()
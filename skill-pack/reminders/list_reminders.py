@@ -0,0 +1,24 @@
+"""List open reminders, due ones first; {"due_only": true} hides future ones."""
+
+import json
+import sys
+
+import store
+
+
+def main():
+    args = json.load(sys.stdin)
+    reminders = [r for r in store.load() if not r.get("done")]
+    if args.get("due_only"):
+        reminders = [r for r in reminders if store.is_due(r)]
+    if not reminders:
+        print("No due reminders." if args.get("due_only") else "No open reminders.")
+        return
+    for reminder in sorted(reminders, key=lambda r: r["at"]):
+        state = "DUE" if store.is_due(reminder) else "upcoming"
+        channel = f" [channel {reminder['channel']}]" if reminder.get("channel") else ""
+        print(f"#{reminder['id']} {state} {reminder['at']}{channel}: {reminder['text']}")
+
+
+if __name__ == "__main__":
+    main()
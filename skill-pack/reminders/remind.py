@@ -0,0 +1,25 @@
+"""Add a reminder from {"text", "at", "channel"} (JSON on stdin)."""
+
+import json
+import sys
+
+import store
+
+
+def main():
+    args = json.load(sys.stdin)
+    try:
+        at = store.parse_time(args["at"]).strftime(store.TIME_FORMAT)
+    except ValueError:
+        sys.exit(f"`at` must look like YYYY-MM-DD HH:MM, got {args['at']!r}")
+    reminders = store.load()
+    reminder_id = max((r["id"] for r in reminders), default=0) + 1
+    reminders.append(
+        {"id": reminder_id, "text": args["text"], "at": at, "channel": args.get("channel"), "done": False}
+    )
+    store.save(reminders)
+    print(f"Reminder #{reminder_id} set for {at}: {args['text']}")
+
+
+if __name__ == "__main__":
+    main()
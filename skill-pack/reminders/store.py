@@ -0,0 +1,33 @@
+"""Shared storage for the reminder tools: brain/reminders.json in the guild."""
+
+import json
+import os
+from datetime import datetime
+from pathlib import Path
+
+TIME_FORMAT = "%Y-%m-%d %H:%M"
+
+
+def path():
+    return Path(os.environ.get("TELLAR_WORKSPACE", ".")) / "brain" / "reminders.json"
+
+
+def load():
+    try:
+        return json.loads(path().read_text())
+    except FileNotFoundError:
+        return []
+
+
+def save(reminders):
+    target = path()
+    target.parent.mkdir(parents=True, exist_ok=True)
+    target.write_text(json.dumps(reminders, indent=2) + "\n")
+
+
+def parse_time(text):
+    return datetime.strptime(text.strip(), TIME_FORMAT)
+
+
+def is_due(reminder):
+    return parse_time(reminder["at"]) <= datetime.now()
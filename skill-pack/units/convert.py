@@ -0,0 +1,64 @@
+"""Convert {"value", "from", "to"} (JSON on stdin) between units of the same kind."""
+
+import json
+import sys
+
+# Factor to the base unit of each kind.
+FACTORS = {
+    "length": {"m": 1, "km": 1000, "cm": 0.01, "mm": 0.001, "mi": 1609.344,
+               "yd": 0.9144, "ft": 0.3048, "in": 0.0254, "nmi": 1852},
+    "mass": {"kg": 1, "g": 0.001, "mg": 1e-6, "t": 1000, "lb": 0.45359237,
+             "oz": 0.028349523125, "st": 6.35029318},
+    "volume": {"l": 1, "ml": 0.001, "m3": 1000, "gal": 3.785411784, "qt": 0.946352946,
+               "pt": 0.473176473, "cup": 0.2365882365, "floz": 0.0295735295625},
+    "speed": {"m/s": 1, "km/h": 1 / 3.6, "mph": 0.44704, "kn": 0.514444},
+    "data": {"b": 1, "kb": 1e3, "mb": 1e6, "gb": 1e9, "tb": 1e12,
+             "kib": 1024, "mib": 1024 ** 2, "gib": 1024 ** 3, "tib": 1024 ** 4},
+}
+ALIASES = {
+    "meter": "m", "meters": "m", "metre": "m", "metres": "m",
+    "kilometer": "km", "kilometers": "km", "kilometre": "km", "kilometres": "km",
+    "centimeter": "cm", "centimeters": "cm", "millimeter": "mm", "millimeters": "mm",
+    "mile": "mi", "miles": "mi", "yard": "yd", "yards": "yd", "foot": "ft", "feet": "ft",
+    "inch": "in", "inches": "in", "kilogram": "kg", "kilograms": "kg", "kilo": "kg",
+    "kilos": "kg", "gram": "g", "grams": "g", "tonne": "t", "tonnes": "t",
+    "pound": "lb", "pounds": "lb", "lbs": "lb", "ounce": "oz", "ounces": "oz",
+    "stone": "st", "liter": "l", "liters": "l", "litre": "l", "litres": "l",
+    "milliliter": "ml", "milliliters": "ml", "gallon": "gal", "gallons": "gal",
+    "quart": "qt", "quarts": "qt", "pint": "pt", "pints": "pt", "cups": "cup",
+    "kph": "km/h", "kmh": "km/h", "knot": "kn", "knots": "kn", "byte": "b", "bytes": "b",
+    "celsius": "c", "fahrenheit": "f", "kelvin": "k",
+}
+TEMPERATURE = {
+    "c": (lambda v: v + 273.15, lambda k: k - 273.15),
+    "f": (lambda v: (v - 32) * 5 / 9 + 273.15, lambda k: (k - 273.15) * 9 / 5 + 32),
+    "k": (lambda v: v, lambda k: k),
+}
+
+
+def normalize(unit):
+    unit = unit.strip().lower().replace("°", "")
+    return ALIASES.get(unit, unit)
+
+
+def convert(value, source, target):
+    if source in TEMPERATURE and target in TEMPERATURE:
+        return TEMPERATURE[target][1](TEMPERATURE[source][0](value))
+    for kind, factors in FACTORS.items():
+        if source in factors and target in factors:
+            return value * factors[source] / factors[target]
+    raise ValueError(f"cannot convert {source} to {target}: unknown units or different kinds")
+
+
+def main():
+    args = json.load(sys.stdin)
+    source, target = normalize(args["from"]), normalize(args["to"])
+    try:
+        result = convert(float(args["value"]), source, target)
+    except ValueError as error:
+        sys.exit(str(error))
+    print(f"{args['value']:g} {args['from']} = {result:.6g} {args['to']}")
+
+
+if __name__ == "__main__":
+    main()
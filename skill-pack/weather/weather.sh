@@ -0,0 +1,13 @@
+#!/bin/sh
+# Current conditions, or a forecast of $2 days, for location $1 from wttr.in.
+set -eu
+
+location=$(printf '%s' "$1" | sed 's/ /+/g')
+days=${2:-0}
+
+if [ "$days" = "0" ]; then
+  curl -fsS --max-time 15 "https://wttr.in/${location}?format=%l:+%C,+%t+(feels+like+%f),+wind+%w,+humidity+%h"
+  echo
+else
+  curl -fsS --max-time 15 "https://wttr.in/${location}?${days}&T&n&q"
+fi
@@ -0,0 +1,51 @@
+"""Look up {"query"} (JSON on stdin) with the DuckDuckGo Instant Answer API."""
+
+import json
+import sys
+import urllib.parse
+import urllib.request
+
+API = "https://api.duckduckgo.com/"
+MAX_TOPICS = 5
+
+
+def topics(items):
+    # Related topics may be grouped one level deep.
+    for item in items:
+        if "Topics" in item:
+            yield from topics(item["Topics"])
+        elif item.get("Text"):
+            yield item
+
+
+def main():
+    query = json.load(sys.stdin)["query"]
+    params = urllib.parse.urlencode(
+        {"q": query, "format": "json", "no_html": 1, "skip_disambig": 1}
+    )
+    request = urllib.request.Request(f"{API}?{params}", headers={"User-Agent": "tellar-skill"})
+    with urllib.request.urlopen(request, timeout=15) as response:
+        data = json.load(response)
+
+    lines = []
+    if data.get("Answer"):
+        lines.append(f"Answer: {data['Answer']}")
+    if data.get("AbstractText"):
+        lines.append(f"{data.get('Heading') or query}: {data['AbstractText']}")
+        if data.get("AbstractURL"):
+            lines.append(f"Source: {data['AbstractURL']}")
+    if data.get("Definition"):
+        lines.append(f"Definition: {data['Definition']} ({data.get('DefinitionURL', '')})")
+    related = list(topics(data.get("RelatedTopics", [])))[:MAX_TOPICS]
+    if related:
+        lines.append("Related:")
+        lines.extend(f"- {item['Text']} {item.get('FirstURL', '')}".rstrip() for item in related)
+
+    if not lines:
+        print(f"No instant answer for {query!r}. Try more general terms, or use web_search.")
+    else:
+        print("\n".join(lines))
+
+
+if __name__ == "__main__":
+    main()
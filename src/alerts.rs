@@ -0,0 +1,309 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/alerts.rs
+ * Responsibility: Classify Guardian findings by severity and deliver critical ones right away.
+ */
+
+use crate::config::{Config, GuardianConfig, Language};
+use crate::health_report::HealthReport;
+use crate::i18n::Message;
+use crate::lifecycle::{self, LifecycleEvent, LifecycleEventKind};
+use crate::skill_audit::SkillAuditRecord;
+use crate::{log_info, log_warn};
+use once_cell::sync::Lazy;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// When each finding key last raised an alert, so a standing problem alerts once per cooldown.
+static LAST_ALERTED: Lazy<Mutex<HashMap<String, Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warn,
+    Critical,
+}
+
+impl Severity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Critical => "critical",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Info => "ℹ️",
+            Self::Warn => "⚠️",
+            Self::Critical => "🚨",
+        }
+    }
+}
+
+/// Something the Guardian noticed. `key` names the thing at fault, so repeats are recognised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub key: String,
+    pub summary: String,
+}
+
+impl Finding {
+    pub(crate) fn new(severity: Severity, key: impl Into<String>, summary: impl Into<String>) -> Self {
+        Self {
+            severity,
+            key: key.into(),
+            summary: summary.into(),
+        }
+    }
+
+    pub fn render(&self, language: Language) -> String {
+        let severity = Message::SeverityLabel {
+            severity: self.severity,
+        };
+        format!(
+            "{} [{}] {}",
+            self.severity.icon(),
+            severity.render(language),
+            self.summary
+        )
+    }
+}
+
+fn ritual_finding(task_id: &str, streak: usize, settings: &GuardianConfig) -> Option<Finding> {
+    let severity = match streak {
+        0 => return None,
+        streak if streak >= settings.critical_failure_streak => Severity::Critical,
+        _ => Severity::Warn,
+    };
+    Some(Finding::new(
+        severity,
+        format!("ritual:{}", task_id),
+        Message::RitualFailing { task_id, streak }.render(crate::i18n::language()),
+    ))
+}
+
+/// Failed calls in a row at the end of each skill tool's journal, keyed `skill/tool`.
+pub(crate) fn skill_failure_streaks(records: &[SkillAuditRecord]) -> BTreeMap<String, usize> {
+    let mut streaks: BTreeMap<String, usize> = BTreeMap::new();
+    for record in records {
+        let streak = streaks
+            .entry(format!("{}/{}", record.skill, record.tool))
+            .or_default();
+        if record.exit_code != Some(0) || record.error.is_some() {
+            *streak += 1;
+        } else {
+            *streak = 0;
+        }
+    }
+    streaks.retain(|_, streak| *streak > 0);
+    streaks
+}
+
+fn skill_finding(tool: &str, streak: usize, settings: &GuardianConfig) -> Finding {
+    let severity = if streak >= settings.critical_failure_streak {
+        Severity::Critical
+    } else {
+        Severity::Warn
+    };
+    Finding::new(
+        severity,
+        format!("skill:{}", tool),
+        Message::SkillFailing { tool, streak }.render(crate::i18n::language()),
+    )
+}
+
+/// Classify what a pulse's health report shows, most severe first.
+pub fn pulse_findings(
+    base_path: &Path,
+    report: &HealthReport,
+    settings: &GuardianConfig,
+) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = report
+        .failing_rituals
+        .iter()
+        .filter_map(|(task_id, streak)| ritual_finding(task_id, *streak, settings))
+        .collect();
+    findings.extend(
+        skill_failure_streaks(&crate::skill_audit::load_records(base_path, 2))
+            .into_iter()
+            .map(|(tool, streak)| skill_finding(&tool, streak, settings)),
+    );
+    if report.failed_requests > 0 {
+        findings.push(Finding::new(
+            Severity::Warn,
+            "requests",
+            Message::RequestsFailed {
+                count: report.failed_requests,
+            }
+            .render(crate::i18n::language()),
+        ));
+    }
+    findings.extend(report.stale_rituals.iter().map(|ritual| {
+        Finding::new(
+            Severity::Info,
+            format!("stale:{}", ritual),
+            Message::RitualStale { ritual }.render(crate::i18n::language()),
+        )
+    }));
+    findings.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.key.cmp(&b.key)));
+    findings
+}
+
+/// Whether `key` may alert now, recording that it did.
+fn claim_alert(key: &str, cooldown: Duration) -> bool {
+    let mut last = LAST_ALERTED.lock().unwrap_or_else(|e| e.into_inner());
+    let now = Instant::now();
+    if last
+        .get(key)
+        .is_some_and(|at| now.duration_since(*at) < cooldown)
+    {
+        return false;
+    }
+    last.insert(key.to_string(), now);
+    true
+}
+
+/// Deliver a critical finding now: a ping in the alert channel and a `guardian_alarm`
+/// lifecycle event (outbound webhooks and ntfy). Other severities wait for the pulse report.
+pub async fn raise(config: &Config, finding: &Finding) {
+    let settings = &config.guardian;
+    if finding.severity != Severity::Critical
+        || !claim_alert(
+            &finding.key,
+            Duration::from_secs(settings.alert_cooldown_mins * 60),
+        )
+    {
+        return;
+    }
+    log_info!("guardian", "🛡️ Guardian alarm: {}", finding.summary);
+    let channel_id = settings
+        .alert_channel_id
+        .as_ref()
+        .or(settings.report_channel_id.as_ref())
+        .or(config.discord.admin_channel_id.as_ref());
+    lifecycle::emit(
+        config,
+        LifecycleEvent::new(
+            LifecycleEventKind::GuardianAlarm,
+            finding.key.clone(),
+            channel_id.map(String::as_str).unwrap_or("0"),
+            finding.summary.clone(),
+            finding.severity.label(),
+        ),
+    );
+    let Some(channel_id) = channel_id else {
+        return;
+    };
+    let text = match settings.alert_mention.as_deref() {
+        Some(mention) => format!("{} {}", mention, finding.render(config.runtime.language)),
+        None => finding.render(config.runtime.language),
+    };
+    if let Err(e) =
+        crate::discord::client::send_bot_message(&config.discord.token, channel_id, &text).await
+    {
+        log_warn!(
+            "guardian",
+            "⚠️ Failed to post Guardian alarm to {}: {:?}",
+            channel_id, e
+        );
+    }
+}
+
+/// Check a ritual right after its run was recorded.
+pub async fn after_ritual_run(base_path: &Path, config: &Config, task_id: &str) {
+    let streak = crate::ritual_runs::load_runs(base_path, task_id)
+        .iter()
+        .rev()
+        .take_while(|run| !run.succeeded())
+        .count();
+    if let Some(finding) = ritual_finding(task_id, streak, &config.guardian) {
+        raise(config, &finding).await;
+    }
+}
+
+/// Check a skill tool right after its call was journaled.
+pub async fn after_skill_call(base_path: &Path, config: &Config, skill: &str, tool: &str) {
+    let key = format!("{}/{}", skill, tool);
+    let records = crate::skill_audit::load_records(base_path, 2);
+    if let Some(streak) = skill_failure_streaks(&records).get(&key) {
+        raise(config, &skill_finding(&key, *streak, &config.guardian)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(tool: &str, exit_code: Option<i32>) -> SkillAuditRecord {
+        SkillAuditRecord {
+            timestamp: "2026-03-01T09:00:00+00:00".to_string(),
+            skill: "weather".to_string(),
+            tool: tool.to_string(),
+            args: "{}".to_string(),
+            exit_code,
+            duration_ms: 10,
+            caller: "rhythm".to_string(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_findings_are_classified_by_streak() {
+        let settings = GuardianConfig::default();
+        let streaks = skill_failure_streaks(&[
+            call("forecast", Some(1)),
+            call("forecast", Some(0)),
+            call("forecast", None),
+            call("forecast", Some(2)),
+            call("forecast", Some(1)),
+            call("radar", Some(1)),
+            call("radar", Some(0)),
+        ]);
+        assert_eq!(
+            streaks.into_iter().collect::<Vec<_>>(),
+            [("weather/forecast".to_string(), 3)]
+        );
+        assert_eq!(
+            skill_finding("weather/forecast", 3, &settings).severity,
+            Severity::Critical
+        );
+        assert_eq!(
+            skill_finding("weather/radar", 1, &settings).severity,
+            Severity::Warn
+        );
+        assert!(ritual_finding("backup", 0, &settings).is_none());
+
+        let dir = tempfile::tempdir().unwrap();
+        let report = HealthReport {
+            failing_rituals: vec![("backup".to_string(), 4), ("report".to_string(), 1)],
+            stale_rituals: vec!["rituals/todo.md (idle 2d)".to_string()],
+            failed_requests: 2,
+            ..Default::default()
+        };
+        let findings = pulse_findings(dir.path(), &report, &settings);
+        let severities: Vec<Severity> = findings.iter().map(|f| f.severity).collect();
+        assert_eq!(
+            severities,
+            [
+                Severity::Critical,
+                Severity::Warn,
+                Severity::Warn,
+                Severity::Info
+            ]
+        );
+        assert_eq!(
+            findings[0].render(Language::En),
+            "🚨 [critical] Ritual `backup` failed its last 4 run(s)"
+        );
+
+        assert!(claim_alert("test:cooldown", Duration::from_secs(60)));
+        assert!(!claim_alert("test:cooldown", Duration::from_secs(60)));
+        assert!(claim_alert("test:cooldown", Duration::ZERO));
+    }
+}
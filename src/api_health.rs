@@ -0,0 +1,302 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/api_health.rs
+ * Responsibility: Track Gemini and Discord error rates and switch the steward into a degraded mode while they fail.
+ */
+
+use crate::config::{Config, DegradedModeConfig};
+use crate::i18n::Message;
+use crate::lifecycle::{self, LifecycleEvent, LifecycleEventKind};
+use crate::{log_info, log_warn};
+use once_cell::sync::Lazy;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Outcomes kept per service; older ones fall out even inside the window.
+const MAX_OUTCOMES: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Service {
+    Llm,
+    Discord,
+}
+
+impl Service {
+    const ALL: [Service; 2] = [Service::Llm, Service::Discord];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Llm => "Gemini",
+            Self::Discord => "Discord",
+        }
+    }
+}
+
+/// Recent call outcomes per service, shared by everything that talks to an API.
+#[derive(Debug, Default)]
+pub struct ApiHealth {
+    llm: VecDeque<(Instant, bool)>,
+    discord: VecDeque<(Instant, bool)>,
+}
+
+/// Calls and failures of one service within a window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ServiceStats {
+    pub calls: usize,
+    pub failures: usize,
+}
+
+impl ApiHealth {
+    fn outcomes(&mut self, service: Service) -> &mut VecDeque<(Instant, bool)> {
+        match service {
+            Service::Llm => &mut self.llm,
+            Service::Discord => &mut self.discord,
+        }
+    }
+
+    pub fn record(&mut self, service: Service, ok: bool, at: Instant) {
+        let outcomes = self.outcomes(service);
+        outcomes.push_back((at, ok));
+        if outcomes.len() > MAX_OUTCOMES {
+            outcomes.pop_front();
+        }
+    }
+
+    /// Stats for the `window` before `now`, forgetting anything older.
+    pub fn stats(&mut self, service: Service, window: Duration, now: Instant) -> ServiceStats {
+        let outcomes = self.outcomes(service);
+        while outcomes
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) > window)
+        {
+            outcomes.pop_front();
+        }
+        ServiceStats {
+            calls: outcomes.len(),
+            failures: outcomes.iter().filter(|(_, ok)| !ok).count(),
+        }
+    }
+}
+
+static HEALTH: Lazy<Mutex<ApiHealth>> = Lazy::new(|| Mutex::new(ApiHealth::default()));
+static DEGRADED: AtomicBool = AtomicBool::new(false);
+/// Notifications held while degraded, as `(channel_id, text)`, posted on recovery.
+static HELD: Lazy<Mutex<Vec<(String, String)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Record the outcome of one Gemini or Discord call.
+pub fn record(service: Service, ok: bool) {
+    HEALTH
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .record(service, ok, Instant::now());
+}
+
+pub fn is_degraded() -> bool {
+    DEGRADED.load(Ordering::Relaxed)
+}
+
+/// Hold an unsolicited post until the steward recovers.
+pub fn hold(channel_id: &str, text: String) {
+    HELD.lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push((channel_id.to_string(), text));
+}
+
+pub fn held_count() -> usize {
+    HELD.lock().unwrap_or_else(|e| e.into_inner()).len()
+}
+
+/// Which services fail at `rate` or more over at least `min_calls` calls, described.
+fn failing(
+    stats: &[(Service, ServiceStats)],
+    settings: &DegradedModeConfig,
+    rate: f64,
+) -> Vec<String> {
+    stats
+        .iter()
+        .filter(|(_, stats)| {
+            stats.calls >= settings.min_calls && stats.failures as f64 >= rate * stats.calls as f64
+        })
+        .map(|(service, stats)| {
+            format!(
+                "{} {}/{} calls failed",
+                service.label(),
+                stats.failures,
+                stats.calls
+            )
+        })
+        .collect()
+}
+
+/// Whether the steward should be degraded given `stats`. Entering takes `error_rate`;
+/// leaving takes dropping below half of it, so a rate hovering at the line does not flap.
+fn assess(
+    stats: &[(Service, ServiceStats)],
+    settings: &DegradedModeConfig,
+    degraded: bool,
+) -> (bool, Vec<String>) {
+    let rate = if degraded {
+        settings.error_rate / 2.0
+    } else {
+        settings.error_rate
+    };
+    let reasons = failing(stats, settings, rate);
+    (!reasons.is_empty(), reasons)
+}
+
+async fn announce(config: &Config, text: &str) {
+    log_info!("guardian", "{}", text);
+    let settings = &config.guardian;
+    let Some(channel_id) = settings
+        .alert_channel_id
+        .as_ref()
+        .or(settings.report_channel_id.as_ref())
+        .or(config.discord.admin_channel_id.as_ref())
+    else {
+        return;
+    };
+    if let Err(e) =
+        crate::discord::client::send_bot_message(&config.discord.token, channel_id, text).await
+    {
+        log_warn!(
+            "guardian",
+            "⚠️ Failed to announce degraded mode to {}: {:?}",
+            channel_id,
+            e
+        );
+    }
+}
+
+/// The Guardian's health watch: enter degraded mode when Gemini or Discord calls fail too
+/// often, and leave it, posting the held notifications, once they recover.
+pub async fn evaluate(config: &Config) {
+    let settings = &config.guardian.degraded;
+    if !settings.enabled {
+        return;
+    }
+    let window = Duration::from_secs(settings.window_mins * 60);
+    let now = Instant::now();
+    let stats: Vec<(Service, ServiceStats)> = {
+        let mut health = HEALTH.lock().unwrap_or_else(|e| e.into_inner());
+        Service::ALL
+            .iter()
+            .map(|service| (*service, health.stats(*service, window, now)))
+            .collect()
+    };
+    let was_degraded = is_degraded();
+    let (degraded, reasons) = assess(&stats, settings, was_degraded);
+    if degraded == was_degraded {
+        return;
+    }
+    DEGRADED.store(degraded, Ordering::Relaxed);
+
+    if degraded {
+        let summary = format!(
+            "Degraded mode: {} in the last {}m",
+            reasons.join(", "),
+            settings.window_mins
+        );
+        lifecycle::emit(
+            config,
+            LifecycleEvent::new(
+                LifecycleEventKind::GuardianAlarm,
+                "degraded",
+                config.discord.admin_channel_id.as_deref().unwrap_or("0"),
+                summary.clone(),
+                "critical",
+            ),
+        );
+        announce(
+            config,
+            &Message::DegradedEntered { summary: &summary }.render(config.runtime.language),
+        )
+        .await;
+        return;
+    }
+
+    let held = std::mem::take(&mut *HELD.lock().unwrap_or_else(|e| e.into_inner()));
+    announce(
+        config,
+        &Message::DegradedRecovered { held: held.len() }.render(config.runtime.language),
+    )
+    .await;
+    for (channel_id, text) in held {
+        if let Err(e) = crate::discord::client::send_bot_message_or_file(
+            &config.discord.token,
+            &channel_id,
+            &text,
+        )
+        .await
+        {
+            log_warn!(
+                "guardian",
+                "⚠️ Failed to send held post to {}: {:?}",
+                channel_id,
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degraded_mode_enters_and_recovers_with_hysteresis() {
+        let settings = DegradedModeConfig::default();
+        let start = Instant::now();
+        let mut health = ApiHealth::default();
+        for i in 0..10 {
+            health.record(Service::Llm, i % 5 == 0, start + Duration::from_secs(i));
+        }
+        health.record(Service::Discord, false, start);
+        let window = Duration::from_secs(settings.window_mins * 60);
+        let now = start + Duration::from_secs(10);
+        let stats = [
+            (Service::Llm, health.stats(Service::Llm, window, now)),
+            (
+                Service::Discord,
+                health.stats(Service::Discord, window, now),
+            ),
+        ];
+        assert_eq!(
+            stats[0].1,
+            ServiceStats {
+                calls: 10,
+                failures: 8
+            }
+        );
+        // One failed Discord call is too few to count.
+        assert_eq!(
+            assess(&stats, &settings, false),
+            (true, vec!["Gemini 8/10 calls failed".to_string()])
+        );
+
+        let easing = [(
+            Service::Llm,
+            ServiceStats {
+                calls: 10,
+                failures: 3,
+            },
+        )];
+        assert!(!assess(&easing, &settings, false).0);
+        assert!(assess(&easing, &settings, true).0);
+        let calm = [(
+            Service::Llm,
+            ServiceStats {
+                calls: 10,
+                failures: 2,
+            },
+        )];
+        assert!(!assess(&calm, &settings, true).0);
+
+        let later = start + window + Duration::from_secs(11);
+        assert_eq!(
+            health.stats(Service::Llm, window, later),
+            ServiceStats::default()
+        );
+    }
+}
@@ -0,0 +1,297 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/approval.rs
+ * Responsibility: Human-approval gate pausing destructive tool calls until a Discord reaction decides.
+ */
+
+use crate::config::Config;
+use crate::discord::client as discord_client;
+use crate::i18n::Message;
+use crate::{log_info, log_warn};
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+pub const APPROVE_EMOJI: &str = "✅";
+pub const DENY_EMOJI: &str = "❌";
+const MAX_ARGS_PREVIEW_CHARS: usize = 1500;
+const APPROVAL_EMBED_COLOR: u32 = 0xF1C40F;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalOutcome {
+    Approved {
+        by: String,
+    },
+    Denied {
+        by: String,
+    },
+    TimedOut,
+    /// The request could not be posted, so nobody was asked.
+    Unavailable(String),
+}
+
+impl ApprovalOutcome {
+    /// Tool error for every outcome except approval.
+    pub fn refusal(&self, tool_name: &str) -> Option<String> {
+        match self {
+            Self::Approved { .. } => None,
+            Self::Denied { by } => Some(format!(
+                "Error: `{}` was denied by <@{}>. Do not retry it; ask how to proceed instead.",
+                tool_name, by
+            )),
+            Self::TimedOut => Some(format!(
+                "Error: Approval for `{}` timed out; the step was aborted.",
+                tool_name
+            )),
+            Self::Unavailable(reason) => Some(format!(
+                "Error: `{}` needs approval, but {}.",
+                tool_name, reason
+            )),
+        }
+    }
+}
+
+struct PendingApproval {
+    approvers: Vec<String>,
+    decision: oneshot::Sender<(bool, String)>,
+}
+
+/// Approval requests waiting for a reaction, keyed by the embed's message ID.
+static PENDING: Lazy<Mutex<HashMap<String, PendingApproval>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub(crate) fn requires_approval(config: &Config, name: &str) -> bool {
+    let approval = &config.runtime.approval;
+    approval.enabled && crate::tools::tool_pattern_matches(&approval.tools, name)
+}
+
+/// `Some(true)` approves, `Some(false)` denies; other emoji are ignored.
+pub fn decision_from_emoji(emoji: &str) -> Option<bool> {
+    match emoji.trim_end_matches('\u{fe0f}') {
+        APPROVE_EMOJI => Some(true),
+        DENY_EMOJI => Some(false),
+        _ => None,
+    }
+}
+
+fn register(message_id: &str, approvers: Vec<String>) -> oneshot::Receiver<(bool, String)> {
+    let (decision, receiver) = oneshot::channel();
+    PENDING.lock().unwrap_or_else(|e| e.into_inner()).insert(
+        message_id.to_string(),
+        PendingApproval {
+            approvers,
+            decision,
+        },
+    );
+    receiver
+}
+
+/// Settle a pending approval. Returns false when the message is not awaiting a decision
+/// or `user_id` is not an approver.
+pub fn resolve(message_id: &str, user_id: &str, approved: bool) -> bool {
+    let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(entry) = pending.get(message_id) else {
+        return false;
+    };
+    if !entry.approvers.is_empty() && !entry.approvers.iter().any(|id| id.trim() == user_id) {
+        return false;
+    }
+    if let Some(entry) = pending.remove(message_id) {
+        let _ = entry.decision.send((approved, user_id.to_string()));
+    }
+    true
+}
+
+async fn wait_for_decision(
+    message_id: &str,
+    receiver: oneshot::Receiver<(bool, String)>,
+    timeout: Duration,
+) -> ApprovalOutcome {
+    match tokio::time::timeout(timeout, receiver).await {
+        Ok(Ok((true, by))) => ApprovalOutcome::Approved { by },
+        Ok(Ok((false, by))) => ApprovalOutcome::Denied { by },
+        _ => {
+            PENDING
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(message_id);
+            ApprovalOutcome::TimedOut
+        }
+    }
+}
+
+/// Ask in the originating channel; rituals and other non-Discord sessions fall back to the
+/// admin channel.
+fn approval_channel(config: &Config, channel_id: &str) -> Option<String> {
+    let is_discord_id = |id: &str| id.parse::<u64>().is_ok_and(|id| id != 0);
+    if is_discord_id(channel_id) {
+        return Some(channel_id.to_string());
+    }
+    config
+        .discord
+        .admin_channel_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| is_discord_id(id))
+        .map(str::to_string)
+}
+
+fn args_preview(args: &Value, config: &Config) -> String {
+    let raw = serde_json::to_string_pretty(args).unwrap_or_default();
+    let masked = crate::tools::mask_sensitive_data(&raw, config);
+    if masked.chars().count() <= MAX_ARGS_PREVIEW_CHARS {
+        return masked;
+    }
+    let cut: String = masked.chars().take(MAX_ARGS_PREVIEW_CHARS).collect();
+    format!("{}\n…", cut)
+}
+
+/// Post the confirmation embed and block the step until it is approved, denied, or times out.
+pub(crate) async fn request_approval(
+    config: &Config,
+    name: &str,
+    args: &Value,
+    channel_id: &str,
+) -> ApprovalOutcome {
+    let approval = &config.runtime.approval;
+    let Some(target) = approval_channel(config, channel_id) else {
+        return ApprovalOutcome::Unavailable(
+            "there is no Discord channel to ask in; set `discord.admin_channel_id`".to_string(),
+        );
+    };
+    let token = &config.discord.token;
+    let language = config.runtime.language;
+    let description = Message::ApprovalRequest {
+        tool: name,
+        args: &args_preview(args, config),
+        approve: APPROVE_EMOJI,
+        deny: DENY_EMOJI,
+        timeout_secs: approval.timeout_secs,
+    }
+    .render(language);
+    let message = match discord_client::send_embed_message(
+        token,
+        &target,
+        &Message::ApprovalTitle.render(language),
+        &description,
+        Some(APPROVAL_EMBED_COLOR),
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(e) => {
+            return ApprovalOutcome::Unavailable(format!(
+                "the approval request could not be posted ({})",
+                e
+            ));
+        }
+    };
+
+    let message_id = message.id.to_string();
+    let receiver = register(&message_id, approval.approvers.clone());
+    for emoji in [APPROVE_EMOJI, DENY_EMOJI] {
+        if let Err(e) = discord_client::add_reaction(token, &target, &message_id, emoji).await {
+            log_warn!(
+                "discord",
+                "⚠️ Failed to add approval reaction {}: {}",
+                emoji,
+                e
+            );
+        }
+    }
+    log_info!(
+        "discord",
+        "⏸️ Waiting for approval of `{}` (message {})",
+        name,
+        message_id
+    );
+
+    let outcome = wait_for_decision(
+        &message_id,
+        receiver,
+        Duration::from_secs(approval.timeout_secs.max(1)),
+    )
+    .await;
+    match &outcome {
+        ApprovalOutcome::Approved { by } => {
+            log_info!("discord", "▶️ `{}` approved by {}", name, by)
+        }
+        ApprovalOutcome::Denied { by } => log_info!("discord", "⛔ `{}` denied by {}", name, by),
+        _ => {
+            log_info!("discord", "⌛ Approval for `{}` timed out", name);
+            let _ = discord_client::send_bot_message(
+                token,
+                &target,
+                &Message::ApprovalTimedOut { tool: name }.render(language),
+            )
+            .await;
+        }
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve_only_accepts_listed_approvers() {
+        let receiver = register("approval-test-1", vec!["42".to_string()]);
+        assert!(!resolve("approval-test-1", "7", true));
+        assert!(!resolve("approval-test-unknown", "42", true));
+        assert!(resolve("approval-test-1", "42", false));
+        assert!(!resolve("approval-test-1", "42", true));
+
+        let outcome = wait_for_decision("approval-test-1", receiver, Duration::from_secs(1)).await;
+        assert_eq!(
+            outcome,
+            ApprovalOutcome::Denied {
+                by: "42".to_string()
+            }
+        );
+        assert!(
+            outcome
+                .refusal("write")
+                .unwrap()
+                .contains("denied by <@42>")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unanswered_approval_times_out_and_is_forgotten() {
+        let receiver = register("approval-test-2", Vec::new());
+        let outcome =
+            wait_for_decision("approval-test-2", receiver, Duration::from_millis(20)).await;
+        assert_eq!(outcome, ApprovalOutcome::TimedOut);
+        assert!(!resolve("approval-test-2", "7", true));
+        assert_eq!(decision_from_emoji("✅"), Some(true));
+        assert_eq!(decision_from_emoji("👍"), None);
+    }
+
+    #[tokio::test]
+    async fn test_gated_tool_is_refused_without_a_channel_to_ask() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.runtime.approval.enabled = true;
+        assert!(requires_approval(&config, "write"));
+        assert!(!requires_approval(&config, "read"));
+        config.runtime.approval.tools = vec!["skill_*".to_string()];
+        assert!(requires_approval(&config, "skill_deploy"));
+        config.runtime.approval.tools = vec!["write".to_string()];
+
+        let refused = crate::tools::dispatch_tool(
+            "write",
+            &serde_json::json!({ "path": "notes.md", "content": "x" }),
+            dir.path(),
+            &config,
+            "ritual",
+        )
+        .await;
+        assert!(refused.is_error);
+        assert!(refused.output.contains("discord.admin_channel_id"));
+        assert!(!dir.path().join("notes.md").exists());
+    }
+}
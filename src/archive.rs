@@ -0,0 +1,456 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/archive.rs
+ * Responsibility: The `archive` and `extract` core tools for zip and tar(.gz) files inside the guild.
+ */
+
+use crate::file_ops::require_new_rel_path;
+use crate::tools::{ToolExecutionResult, is_path_safe, normalize_path, require_safe_rel_path};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde_json::{Value, json};
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use zip::write::SimpleFileOptions;
+
+/// Refuse archives that would unpack past this many bytes (zip bombs, runaway uploads).
+const MAX_EXTRACT_BYTES: u64 = 200 * 1024 * 1024;
+const MAX_EXTRACT_ENTRIES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+fn detect_format(path: &str) -> Option<ArchiveFormat> {
+    let lower = path.to_ascii_lowercase();
+    if lower.ends_with(".zip") {
+        Some(ArchiveFormat::Zip)
+    } else if lower.ends_with(".tar.gz") || lower.ends_with(".tgz") {
+        Some(ArchiveFormat::TarGz)
+    } else if lower.ends_with(".tar") {
+        Some(ArchiveFormat::Tar)
+    } else {
+        None
+    }
+}
+
+/// Guild-relative files under `rel_path`, skipping the archive being written and `.git`.
+fn collect_files(base_path: &Path, rel_path: &str, skip: &Path, out: &mut Vec<String>) {
+    let path = base_path.join(rel_path);
+    // Symlinks could pull files from outside the guild into the bundle.
+    if path == skip || path.is_symlink() {
+        return;
+    }
+    if path.is_file() {
+        out.push(rel_path.to_string());
+        return;
+    }
+    let Ok(entries) = fs::read_dir(&path) else {
+        return;
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() != ".git")
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .collect();
+    names.sort();
+    for name in names {
+        let child = if rel_path == "." {
+            name
+        } else {
+            format!("{}/{}", rel_path.trim_end_matches('/'), name)
+        };
+        collect_files(base_path, &child, skip, out);
+    }
+}
+
+fn write_archive(
+    base_path: &Path,
+    files: &[String],
+    output: &Path,
+    format: ArchiveFormat,
+) -> anyhow::Result<()> {
+    let file = File::create(output)?;
+    match format {
+        ArchiveFormat::Zip => {
+            let mut writer = zip::ZipWriter::new(file);
+            for rel_path in files {
+                writer.start_file(rel_path.as_str(), SimpleFileOptions::default())?;
+                io::copy(&mut File::open(base_path.join(rel_path))?, &mut writer)?;
+            }
+            writer.finish()?;
+        }
+        ArchiveFormat::Tar => {
+            let mut builder = tar::Builder::new(file);
+            for rel_path in files {
+                builder.append_path_with_name(base_path.join(rel_path), rel_path)?;
+            }
+            builder.finish()?;
+        }
+        ArchiveFormat::TarGz => {
+            let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+            for rel_path in files {
+                builder.append_path_with_name(base_path.join(rel_path), rel_path)?;
+            }
+            builder.into_inner()?.finish()?.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// An entry name is only unpacked if it stays below the destination directory.
+fn safe_entry_path(name: &Path) -> Option<PathBuf> {
+    let mut clean = PathBuf::new();
+    for component in name.components() {
+        match component {
+            Component::Normal(part) => clean.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    (!clean.as_os_str().is_empty()).then_some(clean)
+}
+
+/// Accumulates what an extraction will write, enforcing the size and entry caps.
+struct ExtractPlan<'a> {
+    destination: &'a Path,
+    overwrite: bool,
+    total_bytes: u64,
+    files: usize,
+}
+
+impl ExtractPlan<'_> {
+    fn admit(&mut self, name: &Path, size: u64) -> anyhow::Result<PathBuf> {
+        let relative = safe_entry_path(name)
+            .ok_or_else(|| anyhow::anyhow!("entry `{}` escapes the destination", name.display()))?;
+        self.files += 1;
+        self.total_bytes += size;
+        if self.files > MAX_EXTRACT_ENTRIES {
+            anyhow::bail!("archive has more than {} entries", MAX_EXTRACT_ENTRIES);
+        }
+        if self.total_bytes > MAX_EXTRACT_BYTES {
+            anyhow::bail!("archive expands beyond {} bytes", MAX_EXTRACT_BYTES);
+        }
+        let target = self.destination.join(&relative);
+        if target.exists() && !self.overwrite {
+            anyhow::bail!(
+                "{} already exists; pass overwrite=true to replace it",
+                relative.display()
+            );
+        }
+        Ok(target)
+    }
+}
+
+fn write_entry(target: &Path, reader: &mut impl Read, limit: u64) -> anyhow::Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(target)?;
+    // Declared sizes can lie; never copy more than was admitted.
+    io::copy(&mut reader.take(limit), &mut file)?;
+    Ok(())
+}
+
+fn extract_zip(archive: &Path, plan: &mut ExtractPlan<'_>) -> anyhow::Result<Vec<String>> {
+    let mut zip = zip::ZipArchive::new(File::open(archive)?)?;
+    let mut staged = Vec::new();
+    // Validate every entry before writing anything.
+    for index in 0..zip.len() {
+        let entry = zip.by_index(index)?;
+        if entry.is_dir() || entry.is_symlink() {
+            continue;
+        }
+        let target = plan.admit(Path::new(entry.name()), entry.size())?;
+        staged.push((index, target, entry.size()));
+    }
+    let mut written = Vec::new();
+    for (index, target, size) in staged {
+        let mut entry = zip.by_index(index)?;
+        write_entry(&target, &mut entry, size)?;
+        written.push(entry.name().to_string());
+    }
+    Ok(written)
+}
+
+fn open_tar(archive: &Path, gzip: bool) -> io::Result<tar::Archive<Box<dyn Read>>> {
+    let file = File::open(archive)?;
+    let reader: Box<dyn Read> = if gzip {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    Ok(tar::Archive::new(reader))
+}
+
+fn extract_tar(
+    archive: &Path,
+    gzip: bool,
+    plan: &mut ExtractPlan<'_>,
+) -> anyhow::Result<Vec<String>> {
+    // Validate every entry before writing anything.
+    for entry in open_tar(archive, gzip)?.entries()? {
+        let entry = entry?;
+        if entry.header().entry_type().is_file() {
+            plan.admit(&entry.path()?, entry.size())?;
+        }
+    }
+    let mut written = Vec::new();
+    for entry in open_tar(archive, gzip)?.entries()? {
+        let mut entry = entry?;
+        // Symlinks, hard links, and devices are skipped rather than recreated.
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry.path()?.into_owned();
+        let Some(relative) = safe_entry_path(&name) else {
+            continue;
+        };
+        let size = entry.size();
+        write_entry(&plan.destination.join(relative), &mut entry, size)?;
+        written.push(name.display().to_string());
+    }
+    Ok(written)
+}
+
+fn run_archive_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let output = match require_new_rel_path(args, "output", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let Some(format) = detect_format(output) else {
+        return ToolExecutionResult::error(
+            "Error: `output` must end in .zip, .tar, .tar.gz, or .tgz.",
+        );
+    };
+    let sources: Vec<&str> = match args.get("paths").and_then(Value::as_array) {
+        Some(paths) => paths
+            .iter()
+            .filter_map(Value::as_str)
+            .map(normalize_path)
+            .collect(),
+        None => Vec::new(),
+    };
+    if sources.is_empty() {
+        return ToolExecutionResult::error("Error: Missing required argument `paths`.");
+    }
+    if let Some(unsafe_path) = sources
+        .iter()
+        .find(|path| !is_path_safe(base_path, path) || !base_path.join(path).exists())
+    {
+        return ToolExecutionResult::error(format!(
+            "Error: {} is missing or outside the guild directory.",
+            unsafe_path
+        ));
+    }
+    let output_path = base_path.join(output);
+    let overwrite = args
+        .get("overwrite")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if output_path.exists() && !overwrite {
+        return ToolExecutionResult::error(format!(
+            "Error: {} already exists. Pass overwrite=true to replace it.",
+            output
+        ));
+    }
+
+    let mut files = Vec::new();
+    for source in &sources {
+        collect_files(base_path, source, &output_path, &mut files);
+    }
+    if files.is_empty() {
+        return ToolExecutionResult::error("Error: No files to archive.");
+    }
+    if let Some(parent) = output_path.parent()
+        && let Err(error) = fs::create_dir_all(parent)
+    {
+        return ToolExecutionResult::error(format!("Error creating {}: {}", output, error));
+    }
+    match write_archive(base_path, &files, &output_path, format) {
+        Ok(_) => {
+            let size = fs::metadata(&output_path)
+                .map(|meta| meta.len())
+                .unwrap_or(0);
+            ToolExecutionResult::success(format!(
+                "Archived {} file(s) into {} ({} bytes)",
+                files.len(),
+                output,
+                size
+            ))
+        }
+        Err(error) => {
+            let _ = fs::remove_file(&output_path);
+            ToolExecutionResult::error(format!("Error writing {}: {}", output, error))
+        }
+    }
+}
+
+fn run_extract_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let archive = match require_safe_rel_path(args, "path", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let Some(format) = detect_format(archive) else {
+        return ToolExecutionResult::error(
+            "Error: Only .zip, .tar, .tar.gz, and .tgz archives can be extracted.",
+        );
+    };
+    let destination = match require_new_rel_path(args, "to", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let destination_path = base_path.join(destination);
+    if destination_path.is_file() {
+        return ToolExecutionResult::error(format!("Error: {} is a file.", destination));
+    }
+
+    let mut plan = ExtractPlan {
+        destination: &destination_path,
+        overwrite: args
+            .get("overwrite")
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+        total_bytes: 0,
+        files: 0,
+    };
+    let archive_path = base_path.join(archive);
+    let result = match format {
+        ArchiveFormat::Zip => extract_zip(&archive_path, &mut plan),
+        ArchiveFormat::Tar => extract_tar(&archive_path, false, &mut plan),
+        ArchiveFormat::TarGz => extract_tar(&archive_path, true, &mut plan),
+    };
+    match result {
+        Ok(written) if written.is_empty() => {
+            ToolExecutionResult::success(format!("{} contains no files.", archive))
+        }
+        Ok(written) => ToolExecutionResult::success(format!(
+            "Extracted {} file(s) into {}:\n{}",
+            written.len(),
+            destination,
+            written.join("\n")
+        )),
+        Err(error) => {
+            ToolExecutionResult::error(format!("Error extracting {}: {}", archive, error))
+        }
+    }
+}
+
+pub(crate) fn archive_tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": "archive",
+            "description": "Bundle guild files or folders into a .zip, .tar, or .tar.gz archive inside the guild, e.g. to attach logs.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "paths": { "type": "array", "items": { "type": "string" }, "description": "Files or directories to include, relative to guild root" },
+                    "output": { "type": "string", "description": "Archive path relative to guild root; the extension picks the format" },
+                    "overwrite": { "type": "boolean", "description": "Replace an existing archive" }
+                },
+                "required": ["paths", "output"]
+            }
+        }),
+        json!({
+            "name": "extract",
+            "description": "Unpack a .zip, .tar, or .tar.gz archive from the guild into a guild directory. Entries that would escape the destination are refused.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Archive path relative to guild root" },
+                    "to": { "type": "string", "description": "Destination directory relative to guild root" },
+                    "overwrite": { "type": "boolean", "description": "Replace files that already exist" }
+                },
+                "required": ["path", "to"]
+            }
+        }),
+    ]
+}
+
+pub(crate) fn dispatch_archive_tool(
+    name: &str,
+    args: &Value,
+    base_path: &Path,
+) -> Option<ToolExecutionResult> {
+    match name {
+        "archive" => Some(run_archive_tool(args, base_path)),
+        "extract" => Some(run_extract_tool(args, base_path)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn run(name: &str, args: Value, base_path: &Path) -> ToolExecutionResult {
+        dispatch_archive_tool(name, &args, base_path).unwrap()
+    }
+
+    #[test]
+    fn test_archive_and_extract_round_trip_zip_and_tar_gz() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("logs/old")).unwrap();
+        fs::write(dir.path().join("logs/today.md"), "today").unwrap();
+        fs::write(dir.path().join("logs/old/jan.md"), "january").unwrap();
+
+        for output in ["out/logs.zip", "out/logs.tar.gz"] {
+            let archived = run(
+                "archive",
+                json!({ "paths": ["logs"], "output": output }),
+                dir.path(),
+            );
+            assert!(!archived.is_error, "{}", archived.output);
+            assert!(archived.output.contains("Archived 2 file(s)"));
+
+            let to = format!("unpacked/{}", output.replace('.', "_"));
+            let extracted = run("extract", json!({ "path": output, "to": to }), dir.path());
+            assert!(!extracted.is_error, "{}", extracted.output);
+            assert_eq!(
+                fs::read_to_string(dir.path().join(&to).join("logs/old/jan.md")).unwrap(),
+                "january"
+            );
+            let again = run("extract", json!({ "path": output, "to": to }), dir.path());
+            assert!(again.output.contains("overwrite=true"));
+        }
+    }
+
+    #[test]
+    fn test_extract_refuses_entries_that_escape_the_destination() {
+        let dir = tempdir().unwrap();
+        let mut writer = zip::ZipWriter::new(File::create(dir.path().join("evil.zip")).unwrap());
+        writer
+            .start_file("fine.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"ok").unwrap();
+        writer
+            .start_file("../escape.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"boom").unwrap();
+        writer.finish().unwrap();
+
+        let result = run(
+            "extract",
+            json!({ "path": "evil.zip", "to": "inbox" }),
+            dir.path(),
+        );
+        assert!(result.is_error);
+        assert!(result.output.contains("escapes the destination"));
+        assert!(!dir.path().join("inbox/fine.txt").exists());
+        assert!(!dir.path().join("escape.txt").exists());
+        assert!(
+            run(
+                "archive",
+                json!({ "paths": ["../"], "output": "x.zip" }),
+                dir.path()
+            )
+            .is_error
+        );
+    }
+}
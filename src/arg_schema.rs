@@ -0,0 +1,209 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/arg_schema.rs
+ * Responsibility: Check tool arguments against the JSON-schema subset used in tool parameters.
+ */
+
+use serde_json::Value;
+
+/// One argument that does not fit the schema, addressed like `filters.from` or `tags[2]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgIssue {
+    pub field: String,
+    pub problem: String,
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn matches_type(value: &Value, expected: &str) -> bool {
+    match expected.to_ascii_lowercase().as_str() {
+        "number" => value.is_number(),
+        "integer" => {
+            type_name(value) == "integer" || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        other => type_name(value) == other,
+    }
+}
+
+fn child_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+fn check(schema: &Value, value: &Value, path: &str, issues: &mut Vec<ArgIssue>) {
+    let mut fail = |problem: String| {
+        issues.push(ArgIssue {
+            field: if path.is_empty() {
+                "(arguments)".to_string()
+            } else {
+                path.to_string()
+            },
+            problem,
+        })
+    };
+
+    let expected: Vec<&str> = match schema.get("type") {
+        Some(Value::String(kind)) => vec![kind.as_str()],
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !expected.is_empty() && !expected.iter().any(|kind| matches_type(value, kind)) {
+        fail(format!(
+            "expected {}, got {}",
+            expected.join(" or ").to_ascii_lowercase(),
+            type_name(value)
+        ));
+        return;
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array)
+        && !options.contains(value)
+    {
+        let options: Vec<String> = options.iter().map(Value::to_string).collect();
+        fail(format!("must be one of {}", options.join(", ")));
+    }
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+            && n < min
+        {
+            fail(format!("must be at least {}", min));
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+            && n > max
+        {
+            fail(format!("must be at most {}", max));
+        }
+    }
+    if let Some(text) = value.as_str() {
+        let length = text.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+            && length < min
+        {
+            fail(format!("must be at least {} characters", min));
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+            && length > max
+        {
+            fail(format!("must be at most {} characters", max));
+        }
+    }
+
+    match value {
+        Value::Object(fields) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for name in schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+            {
+                if fields.get(name).is_none_or(Value::is_null) {
+                    issues.push(ArgIssue {
+                        field: child_path(path, name),
+                        problem: "missing required field".to_string(),
+                    });
+                }
+            }
+            let closed = schema.get("additionalProperties") == Some(&Value::Bool(false));
+            for (name, field) in fields {
+                match properties.and_then(|properties| properties.get(name)) {
+                    Some(field_schema) => {
+                        check(field_schema, field, &child_path(path, name), issues)
+                    }
+                    None if closed => issues.push(ArgIssue {
+                        field: child_path(path, name),
+                        problem: "unknown field".to_string(),
+                    }),
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(item_schema, item, &format!("{}[{}]", path, index), issues);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Every way `args` breaks `schema`; empty when the arguments are acceptable.
+pub fn validate_args(schema: &Value, args: &Value) -> Vec<ArgIssue> {
+    let mut issues = Vec::new();
+    check(schema, args, "", &mut issues);
+    issues
+}
+
+/// Error text that lists each bad field and repeats the schema, so the model can fix
+/// every problem in its next call.
+pub fn describe_issues(tool_name: &str, schema: &Value, issues: &[ArgIssue]) -> String {
+    let mut message = format!("Invalid arguments for `{}`:", tool_name);
+    for issue in issues {
+        message.push_str(&format!("\n- `{}`: {}", issue.field, issue.problem));
+    }
+    message.push_str(&format!("\nExpected parameters: {}", schema));
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_args_names_missing_and_invalid_fields() {
+        let schema = json!({
+            "type": "object",
+            "required": ["city", "days"],
+            "additionalProperties": false,
+            "properties": {
+                "city": { "type": "string", "minLength": 1 },
+                "days": { "type": "integer", "minimum": 1, "maximum": 7 },
+                "units": { "type": "string", "enum": ["metric", "imperial"] },
+                "tags": { "type": "array", "items": { "type": "string" } }
+            }
+        });
+
+        assert!(validate_args(&schema, &json!({ "city": "Oslo", "days": 3 })).is_empty());
+        assert!(validate_args(&schema, &json!({ "city": "Oslo", "days": 3.0 })).is_empty());
+
+        let issues = validate_args(
+            &schema,
+            &json!({ "days": "3", "units": "kelvin", "tags": ["a", 2], "color": "red" }),
+        );
+        let described: Vec<String> = issues
+            .iter()
+            .map(|issue| format!("{}: {}", issue.field, issue.problem))
+            .collect();
+        assert_eq!(
+            described,
+            vec![
+                "city: missing required field",
+                "color: unknown field",
+                "days: expected integer, got string",
+                "tags[1]: expected string, got integer",
+                "units: must be one of \"metric\", \"imperial\"",
+            ]
+        );
+
+        let message = describe_issues("forecast", &schema, &issues);
+        assert!(message.starts_with("Invalid arguments for `forecast`:\n- `city`"));
+        assert!(message.contains("Expected parameters: {"));
+    }
+}
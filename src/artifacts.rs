@@ -0,0 +1,129 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/artifacts.rs
+ * Responsibility: Save oversized tool output to brain/artifacts/ so the truncated preview can point at the full text.
+ */
+
+use crate::log_warn;
+use crate::tools::{ToolExecutionResult, truncate_output_with_hint};
+use chrono::Local;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+pub const ARTIFACTS_DIR: &str = "brain/artifacts";
+const ARTIFACT_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+fn tool_slug(tool_name: &str) -> String {
+    tool_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Drop artifacts older than the retention window.
+fn prune_artifacts(dir: &Path) {
+    let Some(cutoff) = SystemTime::now().checked_sub(ARTIFACT_RETENTION) else {
+        return;
+    };
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let expired = entry
+            .metadata()
+            .and_then(|meta| meta.modified())
+            .is_ok_and(|modified| modified < cutoff);
+        if expired {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Write `content` to brain/artifacts/ and return its guild-relative path.
+pub(crate) fn store_artifact(
+    base_path: &Path,
+    tool_name: &str,
+    content: &str,
+) -> std::io::Result<String> {
+    let dir = base_path.join(ARTIFACTS_DIR);
+    fs::create_dir_all(&dir)?;
+    prune_artifacts(&dir);
+
+    let digest: String = Sha256::digest(content.as_bytes())
+        .iter()
+        .take(4)
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    let file_name = format!(
+        "{}-{}-{}.txt",
+        Local::now().format("%Y%m%d-%H%M%S"),
+        tool_slug(tool_name),
+        digest
+    );
+    fs::write(dir.join(&file_name), content)?;
+    Ok(format!("{}/{}", ARTIFACTS_DIR, file_name))
+}
+
+/// Cut `result` to `limit` bytes, saving the full output as an artifact first.
+/// `read` output is only truncated: its source file is already in the guild.
+pub(crate) fn spill_large_output(
+    result: ToolExecutionResult,
+    tool_name: &str,
+    base_path: &Path,
+    limit: usize,
+) -> ToolExecutionResult {
+    if limit == 0 || result.output.len() <= limit || tool_name == "read" {
+        return result.with_truncated_output(limit);
+    }
+    let path = match store_artifact(base_path, tool_name, &result.output) {
+        Ok(path) => path,
+        Err(e) => {
+            log_warn!("steward", "⚠️ Failed to save tool output artifact: {}", e);
+            return result.with_truncated_output(limit);
+        }
+    };
+    let hint = format!(
+        "📦 **Full output** ({} bytes) saved to `{}`. Page through it with `read` (offset/limit), search it with `grep`, or share it with `send_attachment` instead of re-running the tool.",
+        result.output.len(),
+        path
+    );
+    ToolExecutionResult {
+        output: truncate_output_with_hint(result.output, limit, &hint),
+        is_error: result.is_error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_oversized_output_is_saved_as_artifact_with_preview() {
+        let dir = tempdir().unwrap();
+        let full = format!("{}{}", "a".repeat(100), "z".repeat(100));
+
+        let result = spill_large_output(
+            ToolExecutionResult::success(full.clone()),
+            "exec",
+            dir.path(),
+            50,
+        );
+        assert!(result.output.starts_with(&"a".repeat(25)));
+        let path = result
+            .output
+            .split('`')
+            .find(|part| part.starts_with(ARTIFACTS_DIR))
+            .unwrap();
+        assert!(path.ends_with(".txt") && path.contains("-exec-"));
+        assert_eq!(fs::read_to_string(dir.path().join(path)).unwrap(), full);
+
+        let read = spill_large_output(ToolExecutionResult::success(full), "read", dir.path(), 50);
+        assert!(!read.output.contains(ARTIFACTS_DIR));
+        assert_eq!(
+            fs::read_dir(dir.path().join(ARTIFACTS_DIR))
+                .unwrap()
+                .count(),
+            1
+        );
+    }
+}
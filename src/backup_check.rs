@@ -0,0 +1,270 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/backup_check.rs
+ * Responsibility: The Guardian's backup verification: the newest archive is recent, matches its checksum, and unpacks.
+ */
+
+use crate::alerts::{Finding, Severity};
+use crate::config::BackupCheckConfig;
+use crate::history_retention::human_bytes;
+use crate::i18n::Message;
+use flate2::read::GzDecoder;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// The archive last verified and its modification time, so each backup is read once.
+static LAST_VERIFIED: Lazy<Mutex<Option<(PathBuf, SystemTime)>>> = Lazy::new(|| Mutex::new(None));
+
+fn is_archive(path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    name.ends_with(".tar.gz") || name.ends_with(".tgz")
+}
+
+/// The most recently modified archive in `dir`.
+fn latest_archive(dir: &Path) -> Option<(PathBuf, SystemTime)> {
+    fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && is_archive(path))
+        .filter_map(|path| {
+            let modified = path.metadata().ok()?.modified().ok()?;
+            Some((path, modified))
+        })
+        .max_by_key(|(_, modified)| *modified)
+}
+
+fn sha256_hex(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Read every entry to the end, so truncation or a bad gzip stream shows up. Returns the
+/// number of entries and whether the guild's `brain/` is among them.
+fn read_manifest(path: &Path) -> io::Result<(usize, bool)> {
+    let mut archive = tar::Archive::new(GzDecoder::new(File::open(path)?));
+    let mut entries = 0;
+    let mut has_brain = false;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        has_brain |= entry
+            .path()?
+            .components()
+            .any(|part| part.as_os_str() == "brain");
+        io::copy(&mut entry, &mut io::sink())?;
+        entries += 1;
+    }
+    Ok((entries, has_brain))
+}
+
+/// Check one archive: its `<archive>.sha256` sidecar when present, then its contents.
+fn verify_archive(path: &Path) -> Finding {
+    let name = &*path.file_name().unwrap_or_default().to_string_lossy();
+    let key = "backup";
+    let language = crate::i18n::language();
+    let sidecar = PathBuf::from(format!("{}.sha256", path.display()));
+    let checksum = match fs::read_to_string(&sidecar) {
+        Ok(expected) => {
+            let expected = expected
+                .split_whitespace()
+                .next()
+                .unwrap_or("")
+                .to_lowercase();
+            match sha256_hex(path) {
+                Ok(actual) if actual == expected => Some(true),
+                Ok(_) => {
+                    return Finding::new(
+                        Severity::Critical,
+                        key,
+                        Message::BackupChecksumMismatch { name }.render(language),
+                    );
+                }
+                Err(e) => {
+                    return Finding::new(
+                        Severity::Critical,
+                        key,
+                        Message::BackupUnreadable {
+                            name,
+                            error: &e.to_string(),
+                        }
+                        .render(language),
+                    );
+                }
+            }
+        }
+        Err(_) => None,
+    };
+    match read_manifest(path) {
+        Ok((0, _)) | Err(_) => Finding::new(
+            Severity::Critical,
+            key,
+            Message::BackupCorrupt { name }.render(language),
+        ),
+        Ok((_, false)) => Finding::new(
+            Severity::Warn,
+            key,
+            Message::BackupWithoutBrain { name }.render(language),
+        ),
+        Ok((entries, true)) if checksum.is_none() => Finding::new(
+            Severity::Warn,
+            key,
+            Message::BackupWithoutChecksum {
+                name,
+                files: entries,
+            }
+            .render(language),
+        ),
+        Ok((entries, true)) => Finding::new(
+            Severity::Info,
+            key,
+            Message::BackupVerified {
+                name,
+                files: entries,
+                size: &human_bytes(path.metadata().map(|meta| meta.len()).unwrap_or(0) as i64),
+            }
+            .render(language),
+        ),
+    }
+}
+
+/// Verify the newest backup in `settings.dir` (relative to the guild unless absolute) as
+/// of `now`. Staleness is checked every time; a given archive's contents only once.
+pub fn verify_backups(
+    base_path: &Path,
+    settings: &BackupCheckConfig,
+    now: SystemTime,
+) -> Vec<Finding> {
+    let Some(dir) = settings.dir.as_deref().filter(|dir| !dir.trim().is_empty()) else {
+        return Vec::new();
+    };
+    let dir = base_path.join(dir.trim());
+    let Some((latest, modified)) = latest_archive(&dir) else {
+        return vec![Finding::new(
+            Severity::Critical,
+            "backup",
+            Message::BackupMissing {
+                dir: &dir.display().to_string(),
+            }
+            .render(crate::i18n::language()),
+        )];
+    };
+    let mut findings = Vec::new();
+    let max_age = Duration::from_secs(settings.max_age_hours * 3600);
+    if let Ok(age) = now.duration_since(modified)
+        && age > max_age
+    {
+        findings.push(Finding::new(
+            Severity::Critical,
+            "backup:stale",
+            Message::BackupStale {
+                name: &latest.file_name().unwrap_or_default().to_string_lossy(),
+                age_hours: age.as_secs() / 3600,
+                limit_hours: settings.max_age_hours,
+            }
+            .render(crate::i18n::language()),
+        ));
+    }
+    let mut last = LAST_VERIFIED.lock().unwrap_or_else(|e| e.into_inner());
+    if last.as_ref() != Some(&(latest.clone(), modified)) {
+        let finding = verify_archive(&latest);
+        if finding.severity == Severity::Info {
+            *last = Some((latest, modified));
+        }
+        findings.push(finding);
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use tempfile::tempdir;
+
+    fn write_backup(path: &Path) {
+        let mut builder = tar::Builder::new(GzEncoder::new(
+            File::create(path).unwrap(),
+            Compression::default(),
+        ));
+        let data = b"- fact\n";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "guild/brain/KNOWLEDGE.md", &data[..])
+            .unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn test_backups_are_checked_for_age_checksum_and_contents() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let settings = BackupCheckConfig {
+            dir: Some("backups".to_string()),
+            max_age_hours: 26,
+        };
+        let now = SystemTime::now();
+        assert!(verify_backups(base, &BackupCheckConfig::default(), now).is_empty());
+        assert_eq!(
+            verify_backups(base, &settings, now)[0].severity,
+            Severity::Critical
+        );
+
+        let backups = base.join("backups");
+        fs::create_dir_all(&backups).unwrap();
+        let archive = backups.join("guild-2026-03-01.tar.gz");
+        write_backup(&archive);
+        let findings = verify_backups(base, &settings, now);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warn);
+        assert!(findings[0].summary.contains("no `.sha256` checksum"));
+
+        fs::write(
+            backups.join("guild-2026-03-01.tar.gz.sha256"),
+            format!(
+                "{}  guild-2026-03-01.tar.gz\n",
+                sha256_hex(&archive).unwrap()
+            ),
+        )
+        .unwrap();
+        let findings = verify_backups(base, &settings, now);
+        assert_eq!(findings[0].severity, Severity::Info);
+        assert!(findings[0].summary.contains("checksum matches, 1 file(s)"));
+        // The same archive is not read again, but its age still counts.
+        assert!(verify_backups(base, &settings, now).is_empty());
+        let later = now + Duration::from_secs(30 * 3600 + 1800);
+        assert!(
+            verify_backups(base, &settings, later)[0]
+                .summary
+                .contains("is 30h old (limit 26h)")
+        );
+
+        let broken = backups.join("broken.tgz");
+        fs::write(&broken, b"not gzip").unwrap();
+        let finding = verify_archive(&broken);
+        assert_eq!(finding.severity, Severity::Critical);
+        assert!(finding.summary.contains("corrupt or empty"));
+        fs::write(
+            backups.join("broken.tgz.sha256"),
+            "0000000000000000000000000000000000000000000000000000000000000000\n",
+        )
+        .unwrap();
+        assert!(verify_archive(&broken).summary.contains("does not match"));
+    }
+}
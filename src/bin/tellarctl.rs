@@ -1,18 +1,22 @@
 use anyhow::{Context, Result, anyhow, bail};
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use dirs::home_dir;
 use include_dir::{Dir, include_dir};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use tellar::config::{Config, DiscordConfig, GeminiConfig, RuntimeConfig};
+use tellar::config::{Config, DiscordConfig, GeminiConfig};
+use tellar::skill_registry;
+use tellar::skills::{SkillManifest, SkillMetadata};
 
 static ASSETS: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/assets");
+#[cfg(feature = "skill-pack")]
+static SKILL_PACK: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/skill-pack");
 const SKILL_SCHEMA: &str = include_str!("../../schemas/skill.schema.json");
 
 #[derive(Parser)]
@@ -25,6 +29,10 @@ struct Cli {
     /// Guild workspace path (default: ~/.tellar/guild)
     #[arg(short, long, global = true)]
     guild: Option<PathBuf>,
+
+    /// Named profile from tellar.yml's `profiles:` (default: $TELLAR_PROFILE)
+    #[arg(long, global = true)]
+    profile: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -44,6 +52,29 @@ enum Commands {
         /// Overwrite an existing SKILL.json
         #[arg(long)]
         force: bool,
+        /// Fail instead of asking Gemini when the frontmatter cannot be compiled directly
+        #[arg(long)]
+        offline: bool,
+    },
+    /// Install and update skills from git or the registry index
+    Skill {
+        #[command(subcommand)]
+        command: SkillCommands,
+    },
+    /// Manage the encrypted secrets that skills and `exec` can receive as variables
+    Secret {
+        #[command(subcommand)]
+        command: SecretCommands,
+    },
+    /// Manage channel folders and their Discord channel bindings
+    Channel {
+        #[command(subcommand)]
+        command: ChannelCommands,
+    },
+    /// Manage tellar.yml itself
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
     },
     /// Start the Tellar user service
     Start,
@@ -52,9 +83,326 @@ enum Commands {
     /// Restart the Tellar user service
     Restart,
     /// Show the Tellar user service status
-    Status,
-    /// Tail Tellar service logs
-    Logs,
+    Status {
+        /// Ask the running daemon for its sessions, queues, jobs, and recent errors
+        #[arg(long)]
+        detail: bool,
+    },
+    /// Tail Tellar service logs; any option reads the structured log in brain/logs/ instead
+    Logs {
+        /// Only lines from this part of the daemon
+        #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(
+            tellar::runtime_log::SUBSYSTEMS
+        ))]
+        subsystem: Option<String>,
+        /// Only lines from this channel, by ID or folder name
+        #[arg(long)]
+        channel: Option<String>,
+        /// Only lines at this level or above: info, warn, or error
+        #[arg(long, value_parser = parse_log_level)]
+        level: Option<tellar::runtime_log::Level>,
+        /// Print matching records as JSON lines
+        #[arg(long)]
+        json: bool,
+        /// Days of logs to show, counting today
+        #[arg(long)]
+        days: Option<u32>,
+        /// Keep printing new lines as they are written
+        #[arg(short, long)]
+        follow: bool,
+    },
+    /// Run Tellar in a container with Docker Compose
+    Docker {
+        #[command(subcommand)]
+        command: DockerCommands,
+    },
+    /// Check the config, tokens, skill binaries, watcher limits, disk space, and service
+    Doctor,
+    /// Replace tellar and tellarctl with the newest GitHub release, then restart the service
+    SelfUpdate {
+        /// Release channel: stable, or beta to include pre-releases
+        #[arg(long, default_value = "stable", value_parser = ["stable", "beta"])]
+        channel: String,
+        /// Only report whether an update is available
+        #[arg(long)]
+        check: bool,
+        /// Install the release even if it is not newer than this build
+        #[arg(long)]
+        force: bool,
+        /// Base64 Ed25519 key the release must be signed with (default: $TELLAR_RELEASE_KEY,
+        /// else the key built into official releases)
+        #[arg(long)]
+        key: Option<String>,
+        /// Install without checking the release signature, trusting its checksums alone
+        #[arg(long)]
+        insecure_skip_signature: bool,
+        /// Do not restart the systemd service after installing
+        #[arg(long)]
+        no_restart: bool,
+    },
+    /// Export a channel's blackboards and history over a date range as one report
+    Export {
+        /// Channel ID or folder name under channels/
+        channel: String,
+        /// First day to include, YYYY-MM-DD (default: six days before --to)
+        #[arg(long)]
+        from: Option<String>,
+        /// Last day to include, YYYY-MM-DD (default: today)
+        #[arg(long)]
+        to: Option<String>,
+        /// Report format: md or html (default: from the --output extension, else md)
+        #[arg(long, value_parser = ["md", "html"])]
+        format: Option<String>,
+        /// File to write (default: stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Open the report with a summary written by the model
+        #[arg(long)]
+        summarize: bool,
+    },
+    /// Create a throwaway guild wired to the offline mock model
+    Sandbox {
+        /// Directory to create (default: a fresh folder in the system temp directory)
+        path: Option<PathBuf>,
+        /// Populate the directory even if it is not empty
+        #[arg(long)]
+        force: bool,
+    },
+    /// List rituals with their schedules, last run, and failure streaks
+    Rituals,
+    /// Create rituals
+    Ritual {
+        #[command(subcommand)]
+        command: RitualCommands,
+    },
+    /// Upgrade the guild's files to the workspace format this Tellar expects
+    Migrate {
+        /// Show the pending migrations and their diffs without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Try prompts against the configured model
+    Prompt {
+        #[command(subcommand)]
+        command: PromptCommands,
+    },
+    /// Execute a thread or ritual file now, bypassing the watcher
+    Run {
+        /// Blackboard path relative to the guild, e.g. rituals/backup.md
+        file: PathBuf,
+        /// Reopen steps that timed out (`- [!]`) before running
+        #[arg(long)]
+        retry: bool,
+    },
+    /// Show the git history of a workspace file (requires `git.enabled`)
+    History {
+        /// File path relative to the guild, e.g. brain/KNOWLEDGE.md
+        file: String,
+        /// Maximum number of commits to show
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+        /// Include the diff of every commit
+        #[arg(short, long)]
+        patch: bool,
+    },
+    /// Print a shell completion script for tellarctl
+    Completions {
+        /// bash, zsh, fish, elvish, or powershell
+        shell: clap_complete::Shell,
+    },
+    /// Generate man pages for tellarctl and each of its commands
+    Man {
+        /// Write tellarctl.1 and tellarctl-<command>.1 here (default: print tellarctl.1)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SkillCommands {
+    /// Clone a skill into skills/ from a git URL or a `skills.registry_index` name
+    Add {
+        /// Git URL, or a skill name listed in the registry index
+        source: String,
+        /// Folder name under skills/ (default: derived from the source)
+        #[arg(long)]
+        name: Option<String>,
+        /// Branch or tag to install and follow
+        #[arg(long)]
+        rev: Option<String>,
+        /// Replace an existing skill folder
+        #[arg(long)]
+        force: bool,
+    },
+    /// Re-fetch locked skills whose upstream commit changed
+    Update {
+        /// Only update this skill
+        name: Option<String>,
+    },
+    /// Run the `examples` a skill declares for its tools and check their output
+    Test {
+        /// Skill directory containing SKILL.json or SKILL.md
+        dir: PathBuf,
+    },
+    /// Scaffold a new skill under skills/ by answering a few prompts
+    New {
+        /// Skill name (asked for when omitted)
+        name: Option<String>,
+    },
+    /// List installed skills with their version, tools, source, and whether they are enabled
+    List,
+    /// Delete a skill folder and forget it in the lock file and manifest
+    Remove {
+        /// Folder name under skills/
+        name: String,
+        /// Do not ask for confirmation
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Keep a skill installed but hide its tools from the steward
+    Disable {
+        /// Folder name under skills/
+        name: String,
+    },
+    /// Re-enable a disabled skill, or install one from the bundled skill pack (listed when
+    /// no name is given)
+    Enable {
+        /// Disabled skill, or bundled skill name, e.g. weather
+        name: Option<String>,
+        /// Replace an existing skill folder
+        #[arg(long)]
+        force: bool,
+    },
+    /// Summarize recent skill calls from brain/audit/skills.jsonl and flag unusual patterns
+    Audit {
+        /// How many days back to look
+        #[arg(long, default_value_t = 7)]
+        days: i64,
+    },
+}
+
+#[derive(Subcommand)]
+enum SecretCommands {
+    /// Store a secret, reading the value from stdin when it is not given
+    Set {
+        /// Variable name the secret is injected as, e.g. GITHUB_TOKEN
+        name: String,
+        value: Option<String>,
+    },
+    /// Print a stored secret
+    Get { name: String },
+    /// List stored secret names
+    List,
+    /// Delete a stored secret
+    Remove { name: String },
+}
+
+#[derive(Subcommand)]
+enum RitualCommands {
+    /// Write a ritual under rituals/, prompting for anything not given as a flag
+    New {
+        /// Ritual name; its slug becomes the task_id and file name
+        name: Option<String>,
+        /// Cron expression (five fields, or six with seconds)
+        #[arg(long, conflicts_with = "every")]
+        schedule: Option<String>,
+        /// Fixed interval instead of cron, e.g. 15m or 1h 30m
+        #[arg(long)]
+        every: Option<String>,
+        /// IANA timezone the schedule is written in (default: runtime.timezone)
+        #[arg(long)]
+        timezone: Option<String>,
+        /// Discord channel ID the ritual belongs to
+        #[arg(long)]
+        channel: Option<String>,
+        /// Steps appended on every run
+        #[arg(long)]
+        template: Option<String>,
+        /// Replace an existing ritual with the same name
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum PromptCommands {
+    /// Answer one message with the real system prompt, knowledge, and tool catalog, tools
+    /// mocked, and print each turn
+    Test {
+        message: String,
+        /// Discord channel ID whose identity and settings to use (default: base identity)
+        #[arg(long, default_value = tellar::prompt_test::BASE_CHANNEL_ID)]
+        channel: String,
+        /// Print the full system prompt too
+        #[arg(long)]
+        show_prompt: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ChannelCommands {
+    /// List channel folders and the Discord channels they belong to
+    List,
+    /// Bind a Discord channel ID to a folder under channels/ in `discord.channel_mappings`
+    Bind {
+        channel_id: String,
+        /// Folder name under channels/, created if missing
+        folder: String,
+    },
+    /// Rename a channel folder, keeping its channel ID suffix and updating mappings
+    Rename {
+        folder: String,
+        new_name: String,
+    },
+    /// Delete folders of channels that no longer exist on Discord
+    Prune {
+        /// Do not ask for confirmation
+        #[arg(long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum DockerCommands {
+    /// Write a Dockerfile, docker-compose.yml, and .env template for the guild
+    Init {
+        /// Directory for the files (default: `docker` beside the guild)
+        #[arg(long)]
+        dir: Option<PathBuf>,
+        /// Pull this image instead of building from source
+        #[arg(long)]
+        image: Option<String>,
+        /// Overwrite existing Dockerfile and docker-compose.yml (.env is never overwritten)
+        #[arg(long)]
+        force: bool,
+        /// Publish the webhook port on the host's 127.0.0.1 (default: container-only)
+        #[arg(long)]
+        publish: bool,
+    },
+    /// Write any missing files, build or pull the image, and start the stack
+    Up {
+        /// Directory for the files (default: `docker` beside the guild)
+        #[arg(long)]
+        dir: Option<PathBuf>,
+        /// Pull this image instead of building from source
+        #[arg(long)]
+        image: Option<String>,
+        /// Publish the webhook port on the host's 127.0.0.1 (default: container-only)
+        #[arg(long)]
+        publish: bool,
+    },
+    /// Stop and remove the stack
+    Down {
+        /// Directory holding docker-compose.yml (default: `docker` beside the guild)
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Encrypt the API keys, tokens, and passwords in tellar.yml with the secrets key
+    Encrypt,
 }
 
 #[tokio::main]
@@ -65,14 +413,154 @@ async fn main() -> Result<()> {
     match cli.command {
         Commands::Setup { force } => run_setup(&guild_path, force).await?,
         Commands::InstallService => install_linux_service(&guild_path)?,
-        Commands::InstallSkill { path, force } => {
-            run_install_skill(&guild_path, &path, force).await?
+        Commands::InstallSkill {
+            path,
+            force,
+            offline,
+        } => run_install_skill(&guild_path, &path, force, offline).await?,
+        Commands::Skill { command } => match command {
+            SkillCommands::Add {
+                source,
+                name,
+                rev,
+                force,
+            } => run_skill_add(&guild_path, &source, name, rev, force).await?,
+            SkillCommands::Update { name } => run_skill_update(&guild_path, name).await?,
+            SkillCommands::Test { dir } => run_skill_test(&dir).await?,
+            SkillCommands::New { name } => run_skill_new(&guild_path, name)?,
+            SkillCommands::List => run_skill_list(&guild_path)?,
+            SkillCommands::Remove { name, yes } => run_skill_remove(&guild_path, &name, yes)?,
+            SkillCommands::Disable { name } => run_skill_disable(&guild_path, &name)?,
+            SkillCommands::Enable { name, force } => {
+                run_skill_enable(&guild_path, name, force).await?
+            }
+            SkillCommands::Audit { days } => run_skill_audit(&guild_path, days),
+        },
+        Commands::Secret { command } => run_secret(&guild_path, command)?,
+        Commands::Channel { command } => {
+            run_channel(&guild_path, cli.profile.as_deref(), command).await?
         }
+        Commands::Config { command } => match command {
+            ConfigCommands::Encrypt => run_config_encrypt(&guild_path)?,
+        },
         Commands::Start => run_service_cmd("start")?,
         Commands::Stop => run_service_cmd("stop")?,
         Commands::Restart => run_service_cmd("restart")?,
-        Commands::Status => run_service_cmd("status")?,
-        Commands::Logs => run_logs()?,
+        Commands::Status { detail: false } => run_service_cmd("status")?,
+        Commands::Status { detail: true } => run_status_detail(&guild_path)?,
+        Commands::Logs {
+            subsystem,
+            channel,
+            level,
+            json,
+            days,
+            follow,
+        } => {
+            let filter = tellar::runtime_log::LogFilter {
+                subsystem,
+                channel,
+                level,
+            };
+            if filter == Default::default() && !json && days.is_none() && !follow {
+                run_logs()?
+            } else {
+                run_structured_logs(&guild_path, &filter, json, days.unwrap_or(1), follow).await?
+            }
+        }
+        Commands::Docker { command } => run_docker(&guild_path, command)?,
+        Commands::Doctor => run_doctor(&guild_path, cli.profile.as_deref()).await?,
+        Commands::SelfUpdate {
+            channel,
+            check,
+            force,
+            key,
+            insecure_skip_signature,
+            no_restart,
+        } => {
+            let channel = if channel == "beta" {
+                tellar::self_update::ReleaseChannel::Beta
+            } else {
+                tellar::self_update::ReleaseChannel::Stable
+            };
+            run_self_update(channel, check, force, key, insecure_skip_signature, no_restart)
+                .await?
+        }
+        Commands::Export {
+            channel,
+            from,
+            to,
+            format,
+            output,
+            summarize,
+        } => {
+            run_export(
+                &guild_path,
+                cli.profile.as_deref(),
+                &channel,
+                (from.as_deref(), to.as_deref()),
+                format.as_deref(),
+                output.as_deref(),
+                summarize,
+            )
+            .await?
+        }
+        Commands::Sandbox { path, force } => {
+            run_sandbox(path.unwrap_or_else(default_sandbox_path), force)?;
+        }
+        Commands::Rituals => run_rituals(&guild_path),
+        Commands::Ritual { command } => match command {
+            RitualCommands::New {
+                name,
+                schedule,
+                every,
+                timezone,
+                channel,
+                template,
+                force,
+            } => {
+                let spec = tellar::ritual_scaffold::RitualSpec {
+                    name: name.unwrap_or_default(),
+                    schedule,
+                    every,
+                    timezone,
+                    origin_channel: channel.unwrap_or_default(),
+                    injection_template: template.unwrap_or_default(),
+                };
+                run_ritual_new(&guild_path, spec, force)?
+            }
+        },
+        Commands::Migrate { dry_run } => run_migrate(&guild_path, dry_run)?,
+        Commands::Prompt { command } => match command {
+            PromptCommands::Test {
+                message,
+                channel,
+                show_prompt,
+            } => {
+                run_prompt_test(
+                    &guild_path,
+                    cli.profile.as_deref(),
+                    &message,
+                    &channel,
+                    show_prompt,
+                )
+                .await?
+            }
+        },
+        Commands::Run { file, retry } => {
+            run_blackboard(&guild_path, cli.profile.as_deref(), &file, retry).await?
+        }
+        Commands::History { file, limit, patch } => {
+            let history = tellar::workspace_git::file_history(&guild_path, &file, limit, patch)?;
+            if history.trim().is_empty() {
+                println!("No recorded history for {}", file);
+            } else {
+                print!("{}", history);
+            }
+        }
+        Commands::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "tellarctl", &mut io::stdout());
+        }
+        Commands::Man { out_dir } => run_man(out_dir.as_deref())?,
     }
 
     Ok(())
@@ -85,6 +573,8 @@ struct InstalledSkill {
     #[serde(default)]
     guidance: Option<String>,
     tools: Vec<InstalledSkillTool>,
+    #[serde(flatten)]
+    runtime: tellar::skills::SkillRuntime,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -92,7 +582,16 @@ struct InstalledSkillTool {
     name: String,
     description: String,
     parameters: Value,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     command: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wasm: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    python: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    examples: Vec<tellar::skills::SkillExample>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    output: Option<tellar::output_transform::OutputTransform>,
 }
 
 async fn run_setup(guild_path: &Path, force: bool) -> Result<()> {
@@ -100,6 +599,8 @@ async fn run_setup(guild_path: &Path, force: bool) -> Result<()> {
     println!("Target guild: {}", guild_path.display());
 
     fs::create_dir_all(guild_path).context("failed to create guild directory")?;
+    // An existing guild keeps its format until `tellarctl migrate` upgrades it.
+    let format = tellar::migrate::detect_version(guild_path)?;
 
     if let Some(guild_dir) = ASSETS.get_dir("guild") {
         println!("Installing workspace assets...");
@@ -113,20 +614,21 @@ async fn run_setup(guild_path: &Path, force: bool) -> Result<()> {
     let config_file = guild_path.join("tellar.yml");
     let mut config = load_or_default_config(&config_file)?;
 
-    if needs_value(&config.gemini.api_key) {
-        config.gemini.api_key = prompt_required("Enter your Gemini API key")?;
+    if needs_value(&config_str(&config, "gemini", "api_key")) {
+        config["gemini"]["api_key"] = prompt_required("Enter your Gemini API key")?.into();
     }
 
-    if !needs_value(&config.gemini.api_key) {
+    if !needs_value(&config_str(&config, "gemini", "api_key")) {
         configure_model(&mut config).await?;
     }
 
-    if needs_value(&config.discord.token) {
-        config.discord.token = prompt_required("Enter your Discord bot token")?;
+    if needs_value(&config_str(&config, "discord", "token")) {
+        config["discord"]["token"] = prompt_required("Enter your Discord bot token")?.into();
     }
 
-    save_config(&config_file, &config)?;
+    write_config_value(&config_file, &config)?;
     println!("Configuration written to {}", config_file.display());
+    tellar::migrate::write_version(guild_path, format)?;
 
     if cfg!(target_os = "linux") {
         println!(
@@ -144,103 +646,1357 @@ async fn run_setup(guild_path: &Path, force: bool) -> Result<()> {
     Ok(())
 }
 
-async fn run_install_skill(guild_path: &Path, skill_path: &Path, force: bool) -> Result<()> {
-    let skill_dir = fs::canonicalize(skill_path)
-        .with_context(|| format!("failed to resolve skill directory {}", skill_path.display()))?;
-    if !skill_dir.is_dir() {
-        bail!("skill path is not a directory: {}", skill_dir.display());
-    }
-
-    let skill_md = skill_dir.join("SKILL.md");
-    if !skill_md.exists() {
-        bail!("missing SKILL.md in {}", skill_dir.display());
+async fn run_install_skill(
+    guild_path: &Path,
+    skill_path: &Path,
+    force: bool,
+    offline: bool,
+) -> Result<()> {
+    let skill_dir = fs::canonicalize(skill_path)
+        .with_context(|| format!("failed to resolve skill directory {}", skill_path.display()))?;
+    if !skill_dir.is_dir() {
+        bail!("skill path is not a directory: {}", skill_dir.display());
+    }
+
+    let skill_md = skill_dir.join("SKILL.md");
+    if !skill_md.exists() {
+        bail!("missing SKILL.md in {}", skill_dir.display());
+    }
+
+    let target = skill_dir.join("SKILL.json");
+    if target.exists() && !force {
+        bail!(
+            "{} already exists. Re-run with `--force` to overwrite.",
+            target.display()
+        );
+    }
+
+    let skill_md_content = tokio::fs::read_to_string(&skill_md)
+        .await
+        .with_context(|| format!("failed to read {}", skill_md.display()))?;
+    let dir_name = skill_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("skill");
+    match compile_skill_md_offline(&skill_md_content, dir_name) {
+        Ok(compiled) => {
+            println!("Compiled {} from its frontmatter.", skill_md.display());
+            return write_compiled_skill(&target, &compiled).await;
+        }
+        Err(e) if offline => return Err(e.context("SKILL.md cannot be compiled offline")),
+        Err(e) => println!(
+            "Frontmatter is not directly compilable ({}); using Gemini.",
+            e
+        ),
+    }
+
+    let config_path = guild_path.join("tellar.yml");
+    let config = Config::load(&config_path).with_context(|| {
+        format!(
+            "failed to load Tellar config at {} for skill compilation",
+            config_path.display()
+        )
+    })?;
+    if needs_value(&config.gemini.api_key) || needs_value(&config.gemini.model) {
+        bail!("Gemini API key and model must be configured before installing a skill");
+    }
+
+    let tree = collect_skill_tree(&skill_dir)?;
+    let prompt = build_skill_install_prompt(&skill_md_content, &tree);
+
+    println!("Compiling skill {} with Gemini...", skill_dir.display());
+    let turn = tellar::llm::generate_turn(
+        "You compile SKILL.md documents into strict machine-readable SKILL.json files. Output valid JSON only, with no markdown fences and no commentary.",
+        vec![tellar::llm::Message {
+            role: tellar::llm::MessageRole::User,
+            parts: vec![tellar::llm::MultimodalPart::text(prompt)],
+        }],
+        &config.gemini.api_key,
+        &config.gemini.model,
+        0.2,
+        None,
+    )
+    .await
+    .context("failed to compile skill with Gemini")?;
+
+    let raw_output = match turn {
+        tellar::llm::ModelTurn::Narrative(text) => text,
+        tellar::llm::ModelTurn::ToolCalls { .. } => {
+            bail!("skill compiler unexpectedly returned tool calls")
+        }
+    };
+
+    let json_payload = extract_json_object(&raw_output)?;
+    let compiled: InstalledSkill =
+        serde_json::from_str(&json_payload).context("generated SKILL.json is not valid JSON")?;
+    validate_installed_skill(&compiled)?;
+    write_compiled_skill(&target, &compiled).await
+}
+
+async fn write_compiled_skill(target: &Path, compiled: &InstalledSkill) -> Result<()> {
+    let rendered =
+        serde_json::to_string_pretty(&compiled).context("failed to serialize SKILL.json")?;
+    tokio::fs::write(target, rendered)
+        .await
+        .with_context(|| format!("failed to write {}", target.display()))?;
+
+    println!(
+        "Installed skill `{}` with {} tool(s) -> {}",
+        compiled.name,
+        compiled.tools.len(),
+        target.display()
+    );
+    for tool in &compiled.tools {
+        println!("  - {}", tool.name);
+    }
+
+    match target.parent() {
+        Some(skill_dir) => prepare_python_env(skill_dir),
+        None => Ok(()),
+    }
+}
+
+/// Build the virtualenv of a skill that has `python` tools.
+fn prepare_python_env(skill_dir: &Path) -> Result<()> {
+    let skill = load_skill_dir(skill_dir)?;
+    if !tellar::python_skill::needs_python_env(&skill) {
+        return Ok(());
+    }
+    println!(
+        "Preparing {}...",
+        skill_dir.join(tellar::python_skill::VENV_DIR).display()
+    );
+    let installer = tellar::python_skill::prepare_venv(skill_dir)?;
+    println!("Python environment ready ({}).", installer);
+    Ok(())
+}
+
+/// Compile a freshly fetched skill unless its repository ships a SKILL.json.
+async fn compile_fetched_skill(guild_path: &Path, skill_dir: &Path) -> Result<()> {
+    if skill_dir.join("SKILL.json").exists() {
+        println!("Using the SKILL.json shipped with the skill.");
+        return prepare_python_env(skill_dir);
+    }
+    run_install_skill(guild_path, skill_dir, true, false).await
+}
+
+async fn run_skill_add(
+    guild_path: &Path,
+    source: &str,
+    name: Option<String>,
+    rev: Option<String>,
+    force: bool,
+) -> Result<()> {
+    let registry_index = Config::load(guild_path.join("tellar.yml"))
+        .map(|config| config.skills.registry_index)
+        .unwrap_or_default();
+    let source = skill_registry::resolve_source(source, &registry_index).await?;
+    let name = name.unwrap_or_else(|| source.default_name());
+    let skill_dir = guild_path.join("skills").join(&name);
+    if skill_dir.exists() && !force {
+        bail!(
+            "{} already exists. Re-run with `--force` to replace it.",
+            skill_dir.display()
+        );
+    }
+
+    println!("Fetching {} into {}...", source.git, skill_dir.display());
+    let locked = skill_registry::fetch_skill(guild_path, &name, &source, rev.as_deref())?;
+    println!("Fetched commit {}", locked.commit);
+    let mut lock = skill_registry::SkillLock::load(guild_path)?;
+    lock.skills.insert(name.clone(), locked);
+    lock.save(guild_path)?;
+
+    compile_fetched_skill(guild_path, &skill_dir).await?;
+    println!(
+        "Skill `{}` installed and recorded in {}",
+        name,
+        skill_registry::LOCK_FILE
+    );
+    Ok(())
+}
+
+async fn run_skill_update(guild_path: &Path, only: Option<String>) -> Result<()> {
+    let mut lock = skill_registry::SkillLock::load(guild_path)?;
+    if let Some(name) = &only
+        && !lock.skills.contains_key(name)
+    {
+        bail!("`{}` is not in {}", name, skill_registry::LOCK_FILE);
+    }
+    let names: Vec<String> = lock
+        .skills
+        .keys()
+        .filter(|name| only.as_ref().is_none_or(|only| only == *name))
+        .cloned()
+        .collect();
+    if names.is_empty() {
+        println!("No skills installed from git.");
+        return Ok(());
+    }
+
+    for name in names {
+        let locked = lock.skills[&name].clone();
+        let latest = skill_registry::remote_commit(&locked.git, locked.rev.as_deref())?;
+        if latest == locked.commit {
+            println!("{}: up to date ({})", name, short_commit(&locked.commit));
+            continue;
+        }
+        let source = skill_registry::SkillSource {
+            git: locked.git.clone(),
+            path: locked.path.clone(),
+        };
+        let updated =
+            skill_registry::fetch_skill(guild_path, &name, &source, locked.rev.as_deref())?;
+        println!(
+            "{}: {} -> {}",
+            name,
+            short_commit(&locked.commit),
+            short_commit(&updated.commit)
+        );
+        lock.skills.insert(name.clone(), updated);
+        lock.save(guild_path)?;
+        compile_fetched_skill(guild_path, &guild_path.join("skills").join(&name)).await?;
+    }
+    Ok(())
+}
+
+/// Load a skill folder the way the daemon does: SKILL.json first, then SKILL.md.
+fn load_skill_dir(dir: &Path) -> Result<SkillMetadata> {
+    let installed = dir.join("SKILL.json");
+    if installed.exists() {
+        return SkillMetadata::from_installed_file(&installed)
+            .with_context(|| format!("failed to load {}", installed.display()));
+    }
+    let source = dir.join("SKILL.md");
+    SkillMetadata::from_file(&source)
+        .with_context(|| format!("failed to load {}", source.display()))
+}
+
+/// Run every declared example against the real tool, returning how many passed and a
+/// description of each failure.
+async fn run_skill_examples(
+    skill: &SkillMetadata,
+    skill_dir: &Path,
+) -> Result<(usize, Vec<String>)> {
+    // Examples run in a scratch workspace with the variables the skill asks for passed
+    // through from the caller's environment, so CI can provide them.
+    let workspace = std::env::temp_dir().join(format!(
+        "tellar-skill-test-{}-{}",
+        skill.name,
+        std::process::id()
+    ));
+    fs::create_dir_all(&workspace)?;
+    let mut config = Config::default();
+    config.runtime.env.skills.insert(
+        skill.name.clone(),
+        tellar::config::EnvRules {
+            pass: [&skill.runtime.env[..], &skill.runtime.secrets[..]].concat(),
+            ..Default::default()
+        },
+    );
+
+    let mut tool_names: Vec<&String> = skill.tools.keys().collect();
+    tool_names.sort();
+    let mut passed = 0;
+    let mut failures = Vec::new();
+    for name in tool_names {
+        let tool = &skill.tools[name];
+        for (index, example) in tool.examples.iter().enumerate() {
+            let label = format!("{} #{}", name, index + 1);
+            let issues = tellar::arg_schema::validate_args(&tool.parameters, &example.args);
+            let result = if issues.is_empty() {
+                tellar::skills::execute_skill_tool(
+                    tool,
+                    skill,
+                    skill_dir,
+                    &workspace,
+                    &example.args,
+                    &config,
+                )
+                .await
+                .map_err(|e| e.to_string())
+            } else {
+                Err(tellar::arg_schema::describe_issues(
+                    name,
+                    &tool.parameters,
+                    &issues,
+                ))
+            };
+            let text = match (&result, example.expect_error) {
+                (Ok(output), false) | (Err(output), true) => output,
+                (Ok(output), true) => {
+                    failures.push(format!("{}: expected an error, got: {}", label, output));
+                    continue;
+                }
+                (Err(error), false) => {
+                    failures.push(format!("{}: {}", label, error));
+                    continue;
+                }
+            };
+            if let Some(pattern) = &example.expect {
+                match Regex::new(pattern) {
+                    Ok(re) if re.is_match(text) => {}
+                    Ok(_) => {
+                        failures.push(format!("{}: `{}` did not match: {}", label, pattern, text));
+                        continue;
+                    }
+                    Err(e) => {
+                        failures.push(format!("{}: invalid expect pattern: {}", label, e));
+                        continue;
+                    }
+                }
+            }
+            passed += 1;
+        }
+    }
+    let _ = fs::remove_dir_all(&workspace);
+    Ok((passed, failures))
+}
+
+async fn run_skill_test(dir: &Path) -> Result<()> {
+    let skill = load_skill_dir(dir)?;
+    skill.runtime.validate()?;
+    prepare_python_env(dir)?;
+    let (passed, failures) = run_skill_examples(&skill, dir).await?;
+    let total = passed + failures.len();
+    if total == 0 {
+        bail!("skill `{}` declares no tool examples", skill.name);
+    }
+    for failure in &failures {
+        println!("FAIL {}", failure);
+    }
+    println!("{}: {} of {} examples passed", skill.name, passed, total);
+    if !failures.is_empty() {
+        bail!("{} example(s) failed", failures.len());
+    }
+    Ok(())
+}
+
+/// Answers collected by `skill new`.
+struct SkillScaffold {
+    name: String,
+    description: String,
+    tool: String,
+    tool_description: String,
+    /// `(name, JSON type, required)`
+    args: Vec<(String, String, bool)>,
+    /// Empty for `./run.sh` followed by a placeholder per argument.
+    command: String,
+}
+
+const SCAFFOLD_SCRIPT: &str = "run.sh";
+
+/// Parse `city:string, days:integer?` into arguments; a trailing `?` marks one optional.
+fn parse_scaffold_args(spec: &str) -> Result<Vec<(String, String, bool)>> {
+    let name_re = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").expect("valid argument name regex");
+    let mut args = Vec::new();
+    for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (name, kind) = entry.split_once(':').unwrap_or((entry, "string"));
+        let (kind, required) = match kind.trim().strip_suffix('?') {
+            Some(kind) => (kind.trim(), false),
+            None => (kind.trim(), true),
+        };
+        let name = name.trim();
+        if !name_re.is_match(name) {
+            bail!("argument name `{}` must be a plain identifier", name);
+        }
+        if !["string", "integer", "number", "boolean"].contains(&kind) {
+            bail!(
+                "argument `{}` has type `{}`; use string, integer, number, or boolean",
+                name,
+                kind
+            );
+        }
+        args.push((name.to_string(), kind.to_string(), required));
+    }
+    Ok(args)
+}
+
+/// The files of a scaffolded skill as `(relative path, content)`, checked to compile.
+fn render_skill_scaffold(scaffold: &SkillScaffold) -> Result<Vec<(String, String)>> {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    let mut default_command = format!("./{}", SCAFFOLD_SCRIPT);
+    for (name, kind, is_required) in &scaffold.args {
+        properties.insert(name.clone(), serde_json::json!({ "type": kind }));
+        if *is_required {
+            required.push(name.clone());
+            default_command.push_str(&format!(" {{{{{}}}}}", name));
+        } else {
+            default_command.push_str(&format!(" {{{{#{0}}}}}--{0} {{{{{0}}}}}{{{{/{0}}}}}", name));
+        }
+    }
+    let command = if scaffold.command.trim().is_empty() {
+        default_command
+    } else {
+        scaffold.command.trim().to_string()
+    };
+    let mut parameters = serde_json::json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        parameters["required"] = serde_json::json!(required);
+    }
+    let frontmatter = serde_json::json!({
+        "name": scaffold.name,
+        "description": scaffold.description,
+        "tools": {
+            scaffold.tool.clone(): {
+                "description": scaffold.tool_description,
+                "shell": command,
+                "parameters": parameters,
+            }
+        }
+    });
+    let skill_md = format!(
+        "---\n{}---\n# {}\n\n{}\n\nDescribe here when the Steward should use `{}` and how to read its output.\n",
+        serde_yml::to_string(&frontmatter).context("failed to render frontmatter")?,
+        scaffold.name,
+        scaffold.description,
+        scaffold.tool
+    );
+    compile_skill_md_offline(&skill_md, &scaffold.name)
+        .context("scaffolded SKILL.md does not compile")?;
+
+    let mut files = vec![("SKILL.md".to_string(), skill_md)];
+    if command.contains(SCAFFOLD_SCRIPT) {
+        files.push((
+            SCAFFOLD_SCRIPT.to_string(),
+            format!(
+                "#!/bin/sh\n# {}: {}\n# Arguments arrive as shell-quoted words from the command template;\n# the full JSON object is in $TELLAR_ARGS.\nset -eu\n\necho \"{} called with: $TELLAR_ARGS\"\n",
+                scaffold.tool, scaffold.tool_description, scaffold.tool
+            ),
+        ));
+    }
+    Ok(files)
+}
+
+fn prompt_default(label: &str, default: &str) -> Result<String> {
+    print!("{} [{}]: ", label, default);
+    io::stdout().flush().context("failed to flush stdout")?;
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .context("failed to read stdin")?;
+    let trimmed = input.trim();
+    Ok(if trimmed.is_empty() {
+        default.to_string()
+    } else {
+        trimmed.to_string()
+    })
+}
+
+fn run_skill_new(guild_path: &Path, name: Option<String>) -> Result<()> {
+    let name = match name {
+        Some(name) => name,
+        None => prompt_required("Skill name")?,
+    };
+    let target = guild_path.join("skills").join(&name);
+    if target.exists() {
+        bail!("{} already exists", target.display());
+    }
+    let description = prompt_required("What does the skill do")?;
+    let tool = prompt_default("Tool name", &name.replace('-', "_"))?;
+    let tool_description = prompt_default("Tool description", &description)?;
+    let args = loop {
+        let spec = prompt_default(
+            "Arguments as name:type, comma-separated, `?` for optional (e.g. city:string, days:integer?)",
+            "none",
+        )?;
+        let spec = if spec == "none" { String::new() } else { spec };
+        match parse_scaffold_args(&spec) {
+            Ok(args) => break args,
+            Err(e) => println!("{}", e),
+        }
+    };
+    // Keeping the default lets the scaffold append the arguments to the script call.
+    let default_command = format!("./{}", SCAFFOLD_SCRIPT);
+    let command = prompt_default("Command", &default_command)?;
+    let command = if command == default_command {
+        String::new()
+    } else {
+        command
+    };
+
+    let files = render_skill_scaffold(&SkillScaffold {
+        name: name.clone(),
+        description,
+        tool,
+        tool_description,
+        args,
+        command,
+    })?;
+    fs::create_dir_all(&target)?;
+    for (file, content) in files {
+        let path = target.join(&file);
+        fs::write(&path, content).with_context(|| format!("failed to write {}", path.display()))?;
+        #[cfg(unix)]
+        if file == SCAFFOLD_SCRIPT {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+        }
+        println!("  + {}", path.display());
+    }
+    println!(
+        "Created skill `{}`. Edit {} and add `examples`, then run `tellarctl skill test {}`.",
+        name,
+        SCAFFOLD_SCRIPT,
+        target.display()
+    );
+    Ok(())
+}
+
+/// Print each bundled skill with its description and whether the guild has it.
+#[cfg(feature = "skill-pack")]
+fn list_skill_pack(guild_path: &Path) -> Result<()> {
+    for dir in SKILL_PACK.dirs() {
+        let name = dir.path().to_string_lossy();
+        let skill_md = dir
+            .get_file(dir.path().join("SKILL.md"))
+            .and_then(|file| file.contents_utf8())
+            .with_context(|| format!("bundled skill `{}` has no SKILL.md", name))?;
+        let compiled = compile_skill_md_offline(skill_md, &name)?;
+        let state = if !guild_path.join("skills").join(name.as_ref()).exists() {
+            ""
+        } else if SkillManifest::load(guild_path)?.is_enabled(&name) {
+            " (enabled)"
+        } else {
+            " (disabled)"
+        };
+        println!("{:<12} {}{}", name, compiled.description, state);
+    }
+    Ok(())
+}
+
+async fn run_skill_enable(guild_path: &Path, name: Option<String>, force: bool) -> Result<()> {
+    if let Some(name) = &name {
+        let mut manifest = SkillManifest::load(guild_path)?;
+        if manifest.disabled.remove(name) {
+            manifest.save(guild_path)?;
+            println!("Skill `{}` enabled again.", name);
+            return Ok(());
+        }
+    }
+    install_bundled_skill(guild_path, name, force).await
+}
+
+#[cfg(not(feature = "skill-pack"))]
+async fn install_bundled_skill(
+    _guild_path: &Path,
+    name: Option<String>,
+    _force: bool,
+) -> Result<()> {
+    match name {
+        Some(name) => bail!(
+            "`{}` is not a disabled skill, and this build has no bundled skill pack",
+            name
+        ),
+        None => bail!("this build has no bundled skill pack"),
+    }
+}
+
+#[cfg(feature = "skill-pack")]
+async fn install_bundled_skill(guild_path: &Path, name: Option<String>, force: bool) -> Result<()> {
+    let Some(name) = name else {
+        return list_skill_pack(guild_path);
+    };
+    let Some(bundled) = SKILL_PACK.get_dir(&name) else {
+        let available: Vec<_> = SKILL_PACK
+            .dirs()
+            .map(|dir| dir.path().to_string_lossy().into_owned())
+            .collect();
+        bail!(
+            "no bundled skill named `{}` (available: {})",
+            name,
+            available.join(", ")
+        );
+    };
+    let skill_dir = guild_path.join("skills").join(&name);
+    if skill_dir.exists() && !force {
+        bail!(
+            "{} already exists. Re-run with `--force` to replace it.",
+            skill_dir.display()
+        );
+    }
+
+    fs::create_dir_all(&skill_dir)
+        .with_context(|| format!("failed to create {}", skill_dir.display()))?;
+    extract_dir_contents(bundled, &skill_dir, true)?;
+    run_install_skill(guild_path, &skill_dir, true, true).await?;
+    println!("Skill `{}` enabled from the bundled skill pack.", name);
+    Ok(())
+}
+
+/// A folder directly under skills/ that holds a skill, for the commands that change one.
+fn installed_skill_dir(guild_path: &Path, name: &str) -> Result<PathBuf> {
+    let dir = guild_path.join("skills").join(name);
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        bail!("`{}` is not a skill folder name", name);
+    }
+    if !dir.join("SKILL.json").is_file() && !dir.join("SKILL.md").is_file() {
+        bail!("no skill installed at {}", dir.display());
+    }
+    Ok(dir)
+}
+
+fn run_skill_list(guild_path: &Path) -> Result<()> {
+    let skills_dir = guild_path.join("skills");
+    let mut folders: Vec<PathBuf> = fs::read_dir(&skills_dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default();
+    folders.retain(|path| {
+        path.is_dir()
+            && !path.file_name().unwrap_or_default().to_string_lossy().starts_with('.')
+    });
+    folders.sort();
+    if folders.is_empty() {
+        println!("No skills installed in {}", skills_dir.display());
+        return Ok(());
+    }
+
+    let lock = skill_registry::SkillLock::load(guild_path)?;
+    let manifest = SkillManifest::load(guild_path)?;
+    for folder in folders {
+        let name = folder.file_name().unwrap_or_default().to_string_lossy().into_owned();
+        let state = if manifest.is_enabled(&name) { "enabled" } else { "disabled" };
+        let source = match lock.skills.get(&name) {
+            Some(locked) => match &locked.rev {
+                Some(rev) => format!("{} @ {} ({})", locked.git, rev, short_commit(&locked.commit)),
+                None => format!("{} ({})", locked.git, short_commit(&locked.commit)),
+            },
+            None => "local".to_string(),
+        };
+        match load_skill_dir(&folder) {
+            Ok(skill) => {
+                let mut tools: Vec<&String> = skill.tools.keys().collect();
+                tools.sort();
+                let tools: Vec<&str> = tools.into_iter().map(String::as_str).collect();
+                println!(
+                    "{} {} [{}]\n  tools: {}\n  source: {}",
+                    name,
+                    skill.runtime.version.as_deref().unwrap_or("(no version)"),
+                    state,
+                    tools.join(", "),
+                    source
+                );
+            }
+            Err(e) => println!("{} [{}]\n  ⚠️ {:#}\n  source: {}", name, state, e, source),
+        }
+    }
+    Ok(())
+}
+
+fn run_skill_remove(guild_path: &Path, name: &str, yes: bool) -> Result<()> {
+    let dir = installed_skill_dir(guild_path, name)?;
+    if !yes {
+        let question = format!("Delete {} and everything in it?", dir.display());
+        let answer = prompt_default(&question, "no")?;
+        if !matches!(answer.to_ascii_lowercase().as_str(), "y" | "yes") {
+            println!("Left {} in place.", dir.display());
+            return Ok(());
+        }
+    }
+    fs::remove_dir_all(&dir).with_context(|| format!("failed to remove {}", dir.display()))?;
+
+    let mut lock = skill_registry::SkillLock::load(guild_path)?;
+    if lock.skills.remove(name).is_some() {
+        lock.save(guild_path)?;
+    }
+    let mut manifest = SkillManifest::load(guild_path)?;
+    if manifest.disabled.remove(name) {
+        manifest.save(guild_path)?;
+    }
+    println!("Removed skill `{}`.", name);
+    Ok(())
+}
+
+fn run_skill_disable(guild_path: &Path, name: &str) -> Result<()> {
+    installed_skill_dir(guild_path, name)?;
+    let mut manifest = SkillManifest::load(guild_path)?;
+    if !manifest.disabled.insert(name.to_string()) {
+        println!("Skill `{}` is already disabled.", name);
+        return Ok(());
+    }
+    manifest.save(guild_path)?;
+    println!(
+        "Skill `{}` disabled in {}; `tellarctl skill enable {}` brings it back.",
+        name,
+        tellar::skills::MANIFEST_FILE,
+        name
+    );
+    Ok(())
+}
+
+fn run_skill_audit(guild_path: &Path, days: i64) {
+    let records = tellar::skill_audit::load_records(guild_path, days);
+    if records.is_empty() {
+        println!("No skill calls recorded in the last {} day(s).", days);
+        return;
+    }
+    // (calls, failures, total duration) per skill/tool
+    let mut totals: BTreeMap<String, (usize, usize, u64)> = BTreeMap::new();
+    for record in &records {
+        let entry = totals
+            .entry(format!("{}/{}", record.skill, record.tool))
+            .or_default();
+        entry.0 += 1;
+        entry.1 += usize::from(record.exit_code != Some(0));
+        entry.2 += record.duration_ms;
+    }
+    for (tool, (calls, failures, total_ms)) in &totals {
+        println!(
+            "{:<32} {:>5} calls {:>4} failed {:>7} ms avg",
+            tool,
+            calls,
+            failures,
+            total_ms / *calls as u64
+        );
+    }
+    let findings = tellar::skill_audit::unusual_patterns(&records);
+    if findings.is_empty() {
+        println!("\nNo unusual patterns.");
+    } else {
+        println!("\nUnusual patterns:");
+        for finding in findings {
+            println!("- {}", finding);
+        }
+    }
+}
+
+/// Fill in what `spec` lacks from prompts. Timing is checked as it is entered, so a
+/// typo is asked again rather than written to a ritual the Rhythm would never run.
+fn run_ritual_new(
+    guild_path: &Path,
+    mut spec: tellar::ritual_scaffold::RitualSpec,
+    force: bool,
+) -> Result<()> {
+    use tellar::ritual_scaffold;
+
+    if spec.name.trim().is_empty() {
+        spec.name = prompt_required("Ritual name")?;
+    }
+    if spec.schedule.is_none() && spec.every.is_none() {
+        while spec.timezone.is_none() {
+            let timezone = prompt_default("Timezone (IANA name, or `default`)", "default")?;
+            if timezone == "default" {
+                break;
+            }
+            match ritual_scaffold::check_timezone(Some(&timezone)) {
+                Ok(()) => spec.timezone = Some(timezone),
+                Err(e) => println!("{:#}", e),
+            }
+        }
+        loop {
+            let timing =
+                prompt_required("Cron schedule (e.g. `30 2 * * *`) or interval (e.g. `15m`)")?;
+            let timezone = spec.timezone.as_deref();
+            if ritual_scaffold::check_timing(None, Some(&timing), timezone).is_ok() {
+                spec.every = Some(timing);
+                break;
+            }
+            match ritual_scaffold::check_timing(Some(&timing), None, timezone) {
+                Ok(_) => {
+                    spec.schedule = Some(timing);
+                    break;
+                }
+                Err(e) => println!("{:#}", e),
+            }
+        }
+    }
+    if spec.origin_channel.trim().is_empty() {
+        spec.origin_channel = prompt_required("Discord channel ID")?;
+    }
+    if spec.injection_template.trim().is_empty() {
+        spec.injection_template = prompt_required("Step to append on each run")?;
+    }
+    let path = ritual_scaffold::create(guild_path, &spec, force)?;
+    if tellar::discord::resolve_folder_by_id(guild_path, spec.origin_channel.trim()).is_none() {
+        println!(
+            "⚠️ No folder under channels/ belongs to channel {} yet.",
+            spec.origin_channel.trim()
+        );
+    }
+    println!(
+        "Created {}. A running Tellar schedules it as soon as the file appears.",
+        path.display()
+    );
+    Ok(())
+}
+
+fn run_rituals(guild_path: &Path) {
+    let statuses = tellar::ritual_runs::ritual_statuses(guild_path);
+    if statuses.is_empty() {
+        println!("No rituals in {}", guild_path.join("rituals").display());
+        return;
+    }
+    for status in &statuses {
+        let last = match &status.last {
+            Some(run) => format!(
+                "{} {} ({} step(s), {} tokens{})",
+                run.finished_at,
+                run.result,
+                run.steps_completed,
+                run.tokens,
+                if run.tools.is_empty() {
+                    String::new()
+                } else {
+                    format!(", {}", run.tools.join(" "))
+                }
+            ),
+            None => "never run".to_string(),
+        };
+        println!(
+            "{:<24} {:<20} {:>4} run(s)  last: {}",
+            status.task_id,
+            status.schedule.as_deref().unwrap_or("-"),
+            status.runs,
+            last
+        );
+        if status.failure_streak > 0 {
+            println!(
+                "{:<24} ⚠️ {} failed run(s) in a row ({})",
+                "", status.failure_streak, status.file
+            );
+        }
+    }
+}
+
+#[cfg(unix)]
+fn run_status_detail(guild_path: &Path) -> Result<()> {
+    println!("{}", tellar::introspect::query(guild_path)?.render());
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn run_status_detail(_guild_path: &Path) -> Result<()> {
+    bail!("`status --detail` needs a unix socket and is not available on this platform");
+}
+
+fn docker_dir(guild_path: &Path, dir: Option<PathBuf>) -> PathBuf {
+    dir.unwrap_or_else(|| {
+        guild_path
+            .parent()
+            .unwrap_or(guild_path)
+            .join("docker")
+    })
+}
+
+/// Write the compose files into `dir`, keeping existing ones unless `force`. An existing
+/// `.env` is always kept, since it holds the user's secrets, but it must set a webhook
+/// token unless `tellar.yml` does.
+fn write_docker_files(
+    guild_path: &Path,
+    dir: &Path,
+    image: Option<String>,
+    force: bool,
+    publish: bool,
+) -> Result<()> {
+    use tellar::docker_compose::{self, ComposeSettings};
+
+    let guild_path = fs::canonicalize(guild_path).with_context(|| {
+        format!(
+            "guild {} does not exist; run `tellarctl setup` first",
+            guild_path.display()
+        )
+    })?;
+    #[cfg(unix)]
+    let user = Some(unsafe { (libc::getuid(), libc::getgid()) });
+    #[cfg(not(unix))]
+    let user = None;
+    let settings = ComposeSettings {
+        image,
+        user,
+        publish,
+    };
+    let env_path = dir.join(docker_compose::ENV_FILE);
+    let env = if env_path.exists() {
+        let existing = fs::read_to_string(&env_path)?;
+        let yml_token = read_config_value(&guild_path.join("tellar.yml"))
+            .ok()
+            .and_then(|config| {
+                config["inbound_webhook"]["token"]
+                    .as_str()
+                    .map(|token| !token.is_empty())
+            })
+            .unwrap_or(false);
+        if !docker_compose::env_sets_webhook_token(&existing) && !yml_token {
+            bail!(
+                "{} sets no {}, and tellar.yml has no inbound_webhook.token; set one before running the webhook server in Docker.",
+                env_path.display(),
+                docker_compose::WEBHOOK_TOKEN_ENV
+            );
+        }
+        existing
+    } else {
+        docker_compose::render_env(&docker_compose::generate_webhook_token()?)
+    };
+    fs::create_dir_all(dir)?;
+    let files = [
+        (
+            docker_compose::DOCKERFILE,
+            docker_compose::render_dockerfile(),
+            force,
+        ),
+        (
+            docker_compose::COMPOSE_FILE,
+            docker_compose::render_compose(&guild_path, &settings),
+            force,
+        ),
+        (docker_compose::ENV_FILE, env, false),
+    ];
+    for (name, content, overwrite) in files {
+        let path = dir.join(name);
+        if path.exists() && !overwrite {
+            println!("Keeping existing {}", path.display());
+            continue;
+        }
+        fs::write(&path, content).with_context(|| format!("failed to write {}", path.display()))?;
+        #[cfg(unix)]
+        if name == docker_compose::ENV_FILE {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn run_docker(guild_path: &Path, command: DockerCommands) -> Result<()> {
+    match command {
+        DockerCommands::Init {
+            dir,
+            image,
+            force,
+            publish,
+        } => {
+            let dir = docker_dir(guild_path, dir);
+            write_docker_files(guild_path, &dir, image, force, publish)?;
+            println!(
+                "Put credentials in {} (or keep them in tellar.yml), then run `tellarctl docker up`.",
+                dir.join(tellar::docker_compose::ENV_FILE).display()
+            );
+        }
+        DockerCommands::Up {
+            dir,
+            image,
+            publish,
+        } => {
+            let dir = docker_dir(guild_path, dir);
+            write_docker_files(guild_path, &dir, image, false, publish)?;
+            require_command("docker")?;
+            let project = dir.to_string_lossy();
+            let compose = ["compose", "--project-directory", project.as_ref()];
+            run_checked_cmd("docker", &[&compose[..], &["pull", "--ignore-buildable"]].concat())?;
+            run_checked_cmd("docker", &[&compose[..], &["up", "-d", "--build"]].concat())?;
+            println!(
+                "🐳 Tellar is starting; `docker compose --project-directory {} ps` shows its health.",
+                project
+            );
+        }
+        DockerCommands::Down { dir } => {
+            let dir = docker_dir(guild_path, dir);
+            require_command("docker")?;
+            let project = dir.to_string_lossy();
+            run_checked_cmd(
+                "docker",
+                &["compose", "--project-directory", project.as_ref(), "down"],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+async fn run_doctor(guild_path: &Path, profile: Option<&str>) -> Result<()> {
+    let profile = tellar::profiles::resolve(profile);
+    println!("🩺 Checking {}", guild_path.display());
+    let checks = tellar::doctor::run_checks(guild_path, profile.as_deref()).await;
+    for check in &checks {
+        println!("{}", check.render());
+    }
+    let failed = checks
+        .iter()
+        .filter(|check| check.status == tellar::doctor::Status::Fail)
+        .count();
+    if failed > 0 {
+        bail!("{} check(s) failed", failed);
+    }
+    Ok(())
+}
+
+/// Run a blackboard in this process, or through the running service's `POST /run`
+/// endpoint when it holds the guild lock.
+async fn run_blackboard(
+    guild_path: &Path,
+    profile: Option<&str>,
+    file: &Path,
+    retry: bool,
+) -> Result<()> {
+    let profile = tellar::profiles::resolve(profile);
+    let config = Config::load_profile(guild_path.join("tellar.yml"), profile.as_deref())
+        .context("tellar.yml is missing or invalid; run `tellarctl setup` first")?;
+    let _lock = match tellar::workspace_lock::WorkspaceLock::acquire(guild_path) {
+        Ok(lock) => lock,
+        Err(e) => {
+            let settings = &config.inbound_webhook;
+            if !settings.enabled {
+                bail!(
+                    "{}. Stop the service, or enable `inbound_webhook` so it can run the file itself.",
+                    e
+                );
+            }
+            if settings.token.is_empty() {
+                bail!(
+                    "{}. The service only serves POST /run with `inbound_webhook.token` set; stop it, or set a token.",
+                    e
+                );
+            }
+            tellar::manual_run::resolve_target(guild_path, file)?;
+            let response = reqwest::Client::new()
+                .post(format!("http://{}/run", settings.bind))
+                .header("X-Tellar-Token", &settings.token)
+                .json(&serde_json::json!({ "path": file, "retry": retry }))
+                .send()
+                .await
+                .context("The running service did not answer on inbound_webhook.bind")?;
+            if !response.status().is_success() {
+                bail!(
+                    "The running service refused the run: {}",
+                    response.text().await.unwrap_or_default()
+                );
+            }
+            println!("▶️ Queued {} on the running service.", file.display());
+            return Ok(());
+        }
+    };
+    tellar::manual_run::run_now(guild_path, std::sync::Arc::new(config), file, retry).await?;
+    println!("✅ Finished running {}.", file.display());
+    Ok(())
+}
+
+fn run_config_encrypt(guild_path: &Path) -> Result<()> {
+    let path = guild_path.join("tellar.yml");
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    let mut config: serde_yml::Value =
+        serde_yml::from_str(&content).context("failed to parse tellar.yml")?;
+    let encrypted = tellar::secrets::encrypt_credentials(&mut config)?;
+    if encrypted == 0 {
+        println!("No plaintext credentials left in {}", path.display());
+        return Ok(());
     }
+    fs::write(&path, serde_yml::to_string(&config)?)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    println!(
+        "🔐 Encrypted {} credential(s) in {} (comments are not preserved).",
+        encrypted,
+        path.display()
+    );
+    println!(
+        "Keep {} (or {}) out of guild backups; Tellar needs it to start.",
+        tellar::secrets::key_path().display(),
+        tellar::secrets::KEY_ENV
+    );
+    Ok(())
+}
 
-    let target = skill_dir.join("SKILL.json");
-    if target.exists() && !force {
+/// Refuse to move folders under a running daemon, which would recreate them.
+fn ensure_daemon_stopped(guild_path: &Path) -> Result<()> {
+    if let Some(owner) = tellar::workspace_lock::read_lock_owner(guild_path)
+        && tellar::workspace_lock::is_process_alive(owner.pid)
+    {
         bail!(
-            "{} already exists. Re-run with `--force` to overwrite.",
-            target.display()
+            "Tellar (PID {}) is running on this guild; stop it first with `tellarctl stop`",
+            owner.pid
         );
     }
+    Ok(())
+}
 
-    let config_path = guild_path.join("tellar.yml");
-    let config = Config::load(&config_path).with_context(|| {
-        format!(
-            "failed to load Tellar config at {} for skill compilation",
-            config_path.display()
-        )
-    })?;
-    if needs_value(&config.gemini.api_key) || needs_value(&config.gemini.model) {
-        bail!("Gemini API key and model must be configured before installing a skill");
-    }
+fn read_config_value(path: &Path) -> Result<serde_yml::Value> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    serde_yml::from_str(&content).context("failed to parse tellar.yml")
+}
 
-    let skill_md_content = tokio::fs::read_to_string(&skill_md).await
-        .with_context(|| format!("failed to read {}", skill_md.display()))?;
-    let tree = collect_skill_tree(&skill_dir)?;
-    let prompt = build_skill_install_prompt(&skill_md_content, &tree);
+fn write_config_value(path: &Path, config: &serde_yml::Value) -> Result<()> {
+    fs::write(path, serde_yml::to_string(config)?)
+        .with_context(|| format!("failed to write {}", path.display()))
+}
 
-    println!("Compiling skill {} with Gemini...", skill_dir.display());
-    let turn = tellar::llm::generate_turn(
-        "You compile SKILL.md documents into strict machine-readable SKILL.json files. Output valid JSON only, with no markdown fences and no commentary.",
-        vec![tellar::llm::Message {
-            role: tellar::llm::MessageRole::User,
-            parts: vec![tellar::llm::MultimodalPart::text(prompt)],
-        }],
-        &config.gemini.api_key,
-        &config.gemini.model,
-        0.2,
-        None,
-    )
-    .await
-    .context("failed to compile skill with Gemini")?;
+async fn run_channel(
+    guild_path: &Path,
+    profile: Option<&str>,
+    command: ChannelCommands,
+) -> Result<()> {
+    use tellar::channel_folders;
 
-    let raw_output = match turn {
-        tellar::llm::ModelTurn::Narrative(text) => text,
-        tellar::llm::ModelTurn::ToolCalls { .. } => {
-            bail!("skill compiler unexpectedly returned tool calls")
+    let config_path = guild_path.join("tellar.yml");
+    let channels_dir = guild_path.join("channels");
+    match command {
+        ChannelCommands::List => {
+            let mappings = read_config_value(&config_path)
+                .map(|config| channel_folders::mappings(&config))
+                .unwrap_or_default();
+            let folders = channel_folders::list_folders(guild_path);
+            if folders.is_empty() && mappings.is_empty() {
+                println!("No channel folders in {}", channels_dir.display());
+            }
+            for folder in &folders {
+                let bound: Vec<&str> = mappings
+                    .iter()
+                    .filter(|(_, mapped)| *mapped == folder)
+                    .map(|(id, _)| id.as_str())
+                    .collect();
+                let channel = if !bound.is_empty() {
+                    format!("bound to {}", bound.join(", "))
+                } else if let Some(suffix) = tellar::discord::extract_id_from_folder(folder) {
+                    format!("channel ID ending in {}", suffix)
+                } else {
+                    "no channel".to_string()
+                };
+                println!("{:<32} {}", folder, channel);
+            }
+            for (id, folder) in &mappings {
+                if !folders.contains(folder) {
+                    println!("{:<32} bound to {}, folder missing", folder, id);
+                }
+            }
         }
-    };
+        ChannelCommands::Bind { channel_id, folder } => {
+            channel_folders::validate_folder_name(&folder)?;
+            if channel_id.is_empty() || !channel_id.chars().all(|c| c.is_ascii_digit()) {
+                bail!("`{}` is not a Discord channel ID", channel_id);
+            }
+            let mut config = read_config_value(&config_path)?;
+            channel_folders::bind(&mut config, &channel_id, &folder)?;
+            write_config_value(&config_path, &config)?;
+            fs::create_dir_all(channels_dir.join(&folder))?;
+            println!(
+                "Bound channel {} to channels/{} (comments in tellar.yml are not preserved). \
+                 Restart Tellar to apply.",
+                channel_id, folder
+            );
+        }
+        ChannelCommands::Rename { folder, new_name } => {
+            channel_folders::validate_folder_name(&folder)?;
+            channel_folders::validate_folder_name(&new_name)?;
+            ensure_daemon_stopped(guild_path)?;
+            let from = channels_dir.join(&folder);
+            if !from.is_dir() {
+                bail!("no channel folder at {}", from.display());
+            }
+            let target = channel_folders::rename_target(&folder, &new_name);
+            let to = channels_dir.join(&target);
+            if to.exists() {
+                bail!("{} already exists", to.display());
+            }
+            fs::rename(&from, &to)
+                .with_context(|| format!("failed to rename {}", from.display()))?;
+            let mut changed = 0;
+            if config_path.exists() {
+                let mut config = read_config_value(&config_path)?;
+                changed = channel_folders::rename_mappings(&mut config, &folder, &target)?;
+                if changed > 0 {
+                    write_config_value(&config_path, &config)?;
+                }
+            }
+            println!(
+                "Renamed channels/{} to channels/{} and updated {} mapping(s).",
+                folder, target, changed
+            );
+        }
+        ChannelCommands::Prune { yes } => {
+            let profile = tellar::profiles::resolve(profile);
+            let settings = Config::load_profile(&config_path, profile.as_deref())
+                .context("tellar.yml is missing or invalid; run `tellarctl setup` first")?;
+            let Some(guild_id) = settings.discord.guild_id.as_deref() else {
+                bail!("`discord.guild_id` is not set, so live channels cannot be listed");
+            };
+            let live: HashSet<String> =
+                tellar::discord::fetch_guild_channels(&settings.discord.token, guild_id)
+                    .await?
+                    .into_keys()
+                    .collect();
+            let mut config = read_config_value(&config_path).unwrap_or_default();
+            let mappings = channel_folders::mappings(&config);
+            let stale = channel_folders::stale_folders(
+                &channel_folders::list_folders(guild_path),
+                &live,
+                &mappings,
+            );
+            let dead: HashSet<String> = mappings
+                .keys()
+                .filter(|id| !live.contains(*id))
+                .cloned()
+                .collect();
+            if stale.is_empty() && dead.is_empty() {
+                println!("Every channel folder belongs to a live Discord channel.");
+                return Ok(());
+            }
+            for folder in &stale {
+                println!("  - channels/{}", folder);
+            }
+            if !yes && !stale.is_empty() {
+                let question = format!("Delete these {} folder(s) and their logs?", stale.len());
+                let answer = prompt_default(&question, "no")?;
+                if !matches!(answer.to_ascii_lowercase().as_str(), "y" | "yes") {
+                    println!("Nothing deleted.");
+                    return Ok(());
+                }
+            }
+            ensure_daemon_stopped(guild_path)?;
+            for folder in &stale {
+                let path = channels_dir.join(folder);
+                fs::remove_dir_all(&path)
+                    .with_context(|| format!("failed to remove {}", path.display()))?;
+            }
+            let unbound = if dead.is_empty() {
+                0
+            } else {
+                let unbound = channel_folders::unbind(&mut config, &dead)?;
+                write_config_value(&config_path, &config)?;
+                unbound
+            };
+            println!(
+                "Pruned {} folder(s) and {} mapping(s) of deleted channels.",
+                stale.len(),
+                unbound
+            );
+        }
+    }
+    Ok(())
+}
 
-    let json_payload = extract_json_object(&raw_output)?;
-    let compiled: InstalledSkill =
-        serde_json::from_str(&json_payload).context("generated SKILL.json is not valid JSON")?;
-    validate_installed_skill(&compiled)?;
+fn run_secret(guild_path: &Path, command: SecretCommands) -> Result<()> {
+    use tellar::secrets::SecretStore;
+
+    let mut store = SecretStore::load(guild_path)?;
+    match command {
+        SecretCommands::Set { name, value } => {
+            let value = match value {
+                Some(value) => value,
+                None => {
+                    let mut input = String::new();
+                    io::stdin().read_line(&mut input)?;
+                    input.trim_end_matches(['\r', '\n']).to_string()
+                }
+            };
+            if value.is_empty() {
+                bail!("refusing to store an empty secret");
+            }
+            store.set(&name, value)?;
+            store.save(guild_path)?;
+            println!("🔐 Stored secret {}", name);
+        }
+        SecretCommands::Get { name } => match store.get(&name) {
+            Some(value) => println!("{}", value),
+            None => bail!("no secret named {}", name),
+        },
+        SecretCommands::List => {
+            for name in store.names() {
+                println!("{}", name);
+            }
+        }
+        SecretCommands::Remove { name } => {
+            if !store.remove(&name) {
+                bail!("no secret named {}", name);
+            }
+            store.save(guild_path)?;
+            println!("🗑️ Removed secret {}", name);
+        }
+    }
+    Ok(())
+}
 
-    let rendered =
-        serde_json::to_string_pretty(&compiled).context("failed to serialize SKILL.json")?;
-    tokio::fs::write(&target, rendered).await
-        .with_context(|| format!("failed to write {}", target.display()))?;
+fn short_commit(commit: &str) -> &str {
+    &commit[..commit.len().min(10)]
+}
 
-    println!(
-        "Installed skill `{}` with {} tool(s) -> {}",
-        compiled.name,
-        compiled.tools.len(),
-        target.display()
-    );
-    for tool in &compiled.tools {
-        println!("  - {}", tool.name);
+fn default_sandbox_path() -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "tellar-sandbox-{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ))
+}
+
+fn sandbox_config() -> Config {
+    Config {
+        gemini: GeminiConfig {
+            api_key: "sandbox-offline".to_string(),
+            model: tellar::mock_llm::MOCK_MODEL.to_string(),
+            temperature: None,
+        },
+        discord: DiscordConfig {
+            token: "sandbox-offline".to_string(),
+            guild_id: None,
+            channel_mappings: None,
+            ..Default::default()
+        },
+        ..Default::default()
     }
+}
 
+fn run_man(out_dir: Option<&Path>) -> Result<()> {
+    match out_dir {
+        Some(dir) => {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+            clap_mangen::generate_to(Cli::command(), dir)?;
+            println!("Man pages written to {}", dir.display());
+        }
+        None => clap_mangen::Man::new(Cli::command()).render(&mut io::stdout())?,
+    }
     Ok(())
 }
 
-fn load_or_default_config(path: &Path) -> Result<Config> {
-    match Config::load(path) {
-        Ok(config) => Ok(config),
-        Err(_) => Ok(Config {
-            gemini: GeminiConfig {
-                api_key: "YOUR_KEY".to_string(),
-                model: String::new(),
-            },
-            discord: DiscordConfig {
-                token: "YOUR_TOKEN".to_string(),
-                guild_id: None,
-                channel_mappings: None,
-            },
-            runtime: RuntimeConfig::default(),
-        }),
+fn run_sandbox(target: PathBuf, force: bool) -> Result<()> {
+    let is_populated = fs::read_dir(&target)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if is_populated && !force {
+        bail!(
+            "{} is not empty. Pick another path or re-run with `--force`.",
+            target.display()
+        );
+    }
+    fs::create_dir_all(&target).context("failed to create sandbox directory")?;
+
+    let guild_dir = ASSETS.get_dir("guild").context("missing embedded guild assets")?;
+    let sandbox_dir = ASSETS
+        .get_dir("sandbox")
+        .context("missing embedded sandbox assets")?;
+    extract_dir_contents(guild_dir, &target, force)?;
+    extract_dir_contents(sandbox_dir, &target, true)?;
+    save_config(&target.join("tellar.yml"), &sandbox_config())?;
+    tellar::migrate::write_version(&target, tellar::migrate::CURRENT_VERSION)?;
+
+    println!("Sandbox guild created at {}", target.display());
+    println!("It uses the offline `mock` model: no API keys, no tokens, no Discord.");
+    println!();
+    println!("Try it:");
+    println!("  tellar --guild {} chat", target.display());
+    println!("  > list the files");
+    println!("  > what is in the guild knowledge?");
+    println!("  > run sandbox_echo with hello");
+    println!("  > why did you do that? explain");
+    println!();
+    println!("Delete the directory when you are done; nothing outside it is touched.");
+    Ok(())
+}
+
+/// tellar.yml as written, or placeholder credentials when there is none yet. Setup edits
+/// this tree rather than a `Config`, so a re-run keeps `profiles:` and does not write out
+/// every default section.
+fn load_or_default_config(path: &Path) -> Result<serde_yml::Value> {
+    if !path.exists() {
+        return serde_yml::from_str(
+            "gemini:\n  api_key: YOUR_KEY\n  model: \"\"\ndiscord:\n  token: YOUR_TOKEN\n",
+        )
+        .context("invalid default config");
     }
+    read_config_value(path)
+}
+
+fn config_str(config: &serde_yml::Value, section: &str, key: &str) -> String {
+    config[section][key].as_str().unwrap_or_default().to_string()
 }
 
 fn collect_skill_tree(skill_dir: &Path) -> Result<String> {
@@ -251,6 +2007,9 @@ fn collect_skill_tree(skill_dir: &Path) -> Result<String> {
         entries.sort_by_key(|entry| entry.file_name());
 
         for entry in entries {
+            if entry.file_name() == tellar::python_skill::VENV_DIR {
+                continue;
+            }
             let path = entry.path();
             let rel = path
                 .strip_prefix(base)
@@ -275,9 +2034,73 @@ fn collect_skill_tree(skill_dir: &Path) -> Result<String> {
     Ok(lines.join("\n"))
 }
 
+/// SKILL.md frontmatter in the shape the daemon reads it.
+#[derive(Debug, Deserialize)]
+struct SkillFrontmatter {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    tools: BTreeMap<String, tellar::skills::SkillTool>,
+    #[serde(flatten)]
+    runtime: tellar::skills::SkillRuntime,
+}
+
+/// Compile SKILL.md without a model when its frontmatter already declares the tools.
+/// The description falls back to the first line of the body, the name to the folder.
+fn compile_skill_md_offline(content: &str, dir_name: &str) -> Result<InstalledSkill> {
+    let parts: Vec<&str> = content.splitn(3, "---").collect();
+    if !content.starts_with("---") || parts.len() < 3 {
+        bail!("no YAML frontmatter");
+    }
+    let front: SkillFrontmatter =
+        serde_yml::from_str(parts[1]).context("frontmatter is not valid skill YAML")?;
+    if front.tools.is_empty() {
+        bail!("frontmatter declares no tools");
+    }
+    let body = parts[2].trim();
+    let description = if front.description.trim().is_empty() {
+        body.lines()
+            .map(|line| line.trim_start_matches('#').trim())
+            .find(|line| !line.is_empty())
+            .unwrap_or_default()
+            .to_string()
+    } else {
+        front.description.trim().to_string()
+    };
+
+    let compiled = InstalledSkill {
+        name: if front.name.trim().is_empty() {
+            dir_name.to_string()
+        } else {
+            front.name
+        },
+        description,
+        guidance: (!body.is_empty()).then(|| body.to_string()),
+        tools: front
+            .tools
+            .into_iter()
+            .map(|(name, tool)| InstalledSkillTool {
+                name,
+                description: tool.description,
+                parameters: tool.parameters,
+                command: tool.shell,
+                wasm: tool.wasm,
+                python: tool.python,
+                examples: tool.examples,
+                output: tool.output,
+            })
+            .collect(),
+        runtime: front.runtime,
+    };
+    validate_installed_skill(&compiled)?;
+    Ok(compiled)
+}
+
 fn build_skill_install_prompt(skill_md: &str, tree: &str) -> String {
     format!(
-        "Compile the following skill into a strict SKILL.json document.\n\nRequirements:\n- Output JSON only.\n- Conform to this schema exactly.\n- Do not invent files or commands that are not supported by the SKILL.md or directory tree.\n- `tools` must be a non-empty array.\n- Each tool requires `name`, `description`, `parameters`, and `command`.\n- `parameters.type` must be `object`.\n- Use concise but useful descriptions.\n\n### SKILL.json Schema\n{}\n\n### Skill Directory Tree\n{}\n\n### SKILL.md\n{}",
+        "Compile the following skill into a strict SKILL.json document.\n\nRequirements:\n- Output JSON only.\n- Conform to this schema exactly.\n- Do not invent files or commands that are not supported by the SKILL.md or directory tree.\n- `tools` must be a non-empty array.\n- Each tool requires `name`, `description`, `parameters`, and exactly one of `command`, `wasm` (a WASI module), or `python` (a script run in the skill's virtualenv).\n- `parameters.type` must be `object`.\n- Use concise but useful descriptions.\n- Set `version`, `timeout_secs`, `env`, `secrets`, `cwd`, `wasi`, or `hooks` only when the SKILL.md declares them.\n- Copy example invocations the SKILL.md gives into the tool's `examples`.\n- Add an `output` transform (`pointer`, `jq`, or `regex`) only when the SKILL.md asks for one.\n\n### SKILL.json Schema\n{}\n\n### Skill Directory Tree\n{}\n\n### SKILL.md\n{}",
         SKILL_SCHEMA, tree, skill_md
     )
 }
@@ -289,10 +2112,10 @@ fn extract_json_object(raw: &str) -> Result<String> {
     }
 
     let fenced = Regex::new(r"(?s)```(?:json)?\s*(\{.*\})\s*```").expect("valid fenced json regex");
-    if let Some(caps) = fenced.captures(trimmed) {
-        if let Some(body) = caps.get(1) {
-            return Ok(body.as_str().trim().to_string());
-        }
+    if let Some(caps) = fenced.captures(trimmed)
+        && let Some(body) = caps.get(1)
+    {
+        return Ok(body.as_str().trim().to_string());
     }
 
     bail!("model output did not contain a JSON object")
@@ -313,6 +2136,7 @@ fn validate_installed_skill(skill: &InstalledSkill) -> Result<()> {
     if skill.tools.is_empty() {
         bail!("skill must declare at least one tool");
     }
+    skill.runtime.validate()?;
 
     let mut seen = HashSet::new();
     for tool in &skill.tools {
@@ -328,8 +2152,26 @@ fn validate_installed_skill(skill: &InstalledSkill) -> Result<()> {
         if tool.description.trim().is_empty() {
             bail!("tool `{}` description cannot be empty", tool.name);
         }
-        if tool.command.trim().is_empty() {
-            bail!("tool `{}` command cannot be empty", tool.name);
+        let kinds = [
+            !tool.command.trim().is_empty(),
+            tool.wasm.is_some(),
+            tool.python.is_some(),
+        ];
+        match kinds.iter().filter(|declared| **declared).count() {
+            0 => bail!("tool `{}` command cannot be empty", tool.name),
+            1 => {}
+            _ => bail!(
+                "tool `{}` must declare only one of command, wasm, or python",
+                tool.name
+            ),
+        }
+        if let Some(path) = tool.wasm.as_ref().or(tool.python.as_ref())
+            && !tellar::wasm_skill::is_contained_path(path)
+        {
+            bail!(
+                "tool `{}` entrypoint must be a path inside the skill folder",
+                tool.name
+            );
         }
         if tool
             .parameters
@@ -341,6 +2183,11 @@ fn validate_installed_skill(skill: &InstalledSkill) -> Result<()> {
             bail!("tool `{}` parameters.type must be `object`", tool.name);
         }
     }
+    for hook in skill.runtime.hooks.keys() {
+        if !seen.contains(hook) {
+            bail!("hook `{}` does not name a tool of this skill", hook);
+        }
+    }
 
     Ok(())
 }
@@ -372,14 +2219,18 @@ fn prompt_required(label: &str) -> Result<String> {
     }
 }
 
-async fn configure_model(config: &mut Config) -> Result<()> {
-    if !config.gemini.model.trim().is_empty() && !config.gemini.model.contains("YOUR_") {
-        println!("Using configured Gemini model: {}", config.gemini.model);
+async fn configure_model(config: &mut serde_yml::Value) -> Result<()> {
+    let model = config_str(config, "gemini", "model");
+    if !model.trim().is_empty() && !model.contains("YOUR_") {
+        println!("Using configured Gemini model: {}", model);
         return Ok(());
     }
 
+    // The key may already be encrypted by `tellarctl config encrypt`.
+    let mut api_key = config["gemini"]["api_key"].clone();
+    tellar::secrets::decrypt_credentials(&mut api_key)?;
     println!("Fetching available Gemini models...");
-    let models = tellar::llm::list_models(&config.gemini.api_key)
+    let models = tellar::llm::list_models(api_key.as_str().unwrap_or_default())
         .await
         .context("failed to fetch Gemini models")?;
 
@@ -402,10 +2253,11 @@ async fn configure_model(config: &mut Config) -> Result<()> {
         .parse::<usize>()
         .unwrap_or(1)
         .saturating_sub(1);
-    config.gemini.model = models
+    config["gemini"]["model"] = models
         .get(index)
         .cloned()
-        .unwrap_or_else(|| models[0].clone());
+        .unwrap_or_else(|| models[0].clone())
+        .into();
 
     Ok(())
 }
@@ -483,6 +2335,295 @@ fn run_logs() -> Result<()> {
     run_checked_cmd("journalctl", &["--user", "-u", "tellar", "-f"])
 }
 
+async fn run_self_update(
+    channel: tellar::self_update::ReleaseChannel,
+    check: bool,
+    force: bool,
+    key: Option<String>,
+    insecure_skip_signature: bool,
+    no_restart: bool,
+) -> Result<()> {
+    use tellar::self_update;
+
+    let current = env!("CARGO_PKG_VERSION");
+    let releases = self_update::fetch_releases().await?;
+    let Some(release) = self_update::pick_release(&releases, channel) else {
+        bail!("no {:?} release found on GitHub", channel);
+    };
+    let newer = self_update::compare_versions(&release.tag_name, current)
+        .is_some_and(|order| order.is_gt());
+    if !newer && !force {
+        println!(
+            "tellarctl {} is up to date ({} is the newest {:?} release).",
+            current, release.tag_name, channel
+        );
+        return Ok(());
+    }
+    if check {
+        println!("Update available: {} → {}", current, release.tag_name);
+        return Ok(());
+    }
+
+    let asset_name = self_update::platform_asset();
+    let asset = release
+        .asset(&asset_name)
+        .with_context(|| format!("{} has no {} asset", release.tag_name, asset_name))?;
+    let checksums = release
+        .asset(self_update::CHECKSUMS_ASSET)
+        .with_context(|| format!("{} publishes no checksums", release.tag_name))?;
+    let checksums = self_update::download(checksums).await?;
+    if insecure_skip_signature {
+        println!(
+            "⚠️ Skipping the signature check; only checksums are verified, not who published them."
+        );
+    } else {
+        let key = self_update::release_key(key).with_context(|| {
+            format!(
+                "this build has no release key; pass --key or set {}, or use --insecure-skip-signature",
+                self_update::RELEASE_KEY_ENV
+            )
+        })?;
+        let signature = release
+            .asset(self_update::SIGNATURE_ASSET)
+            .with_context(|| format!("{} is not signed", release.tag_name))?;
+        let signature = self_update::download(signature).await?;
+        self_update::verify_signature(&checksums, &String::from_utf8_lossy(&signature), &key)?;
+        println!("🔏 Checksums signed by the release key.");
+    }
+    println!("⬇️ Downloading {} {}...", release.tag_name, asset_name);
+    let archive = self_update::download(asset).await?;
+    self_update::verify_checksum(&String::from_utf8_lossy(&checksums), &asset_name, &archive)?;
+    let binaries = self_update::unpack_binaries(&archive)?;
+
+    // Stage every binary first, so a failure leaves the old install untouched; each
+    // rename then replaces one binary atomically.
+    let install_dir = std::env::current_exe()?
+        .parent()
+        .map(Path::to_path_buf)
+        .context("cannot locate the install directory")?;
+    let mut staged = Vec::new();
+    for name in self_update::BINARIES {
+        let staging = install_dir.join(format!(".{}.update", name));
+        fs::write(&staging, &binaries[*name])
+            .with_context(|| format!("failed to write {}", staging.display()))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&staging, fs::Permissions::from_mode(0o755))?;
+        }
+        staged.push((staging, install_dir.join(name)));
+    }
+    for (staging, target) in &staged {
+        fs::rename(staging, target)
+            .with_context(|| format!("failed to replace {}", target.display()))?;
+    }
+    println!("✅ Installed {} into {}", release.tag_name, install_dir.display());
+
+    let active = Command::new("systemctl")
+        .args(["--user", "is-active", "tellar"])
+        .output()
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "active");
+    if active && !no_restart {
+        run_service_cmd("restart")?;
+        println!("🔄 Restarted the tellar service.");
+    } else if active {
+        println!("Restart the service with `tellarctl restart` to run the new version.");
+    }
+    Ok(())
+}
+
+fn parse_day(value: &str) -> Result<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .with_context(|| format!("`{}` is not a YYYY-MM-DD date", value))
+}
+
+fn run_migrate(guild_path: &Path, dry_run: bool) -> Result<()> {
+    use tellar::migrate;
+
+    let version = migrate::detect_version(guild_path)?;
+    let pending = migrate::pending(version)?;
+    println!(
+        "Workspace format: {} (this Tellar expects {})",
+        version,
+        migrate::CURRENT_VERSION
+    );
+    if pending.is_empty() {
+        println!("Nothing to migrate.");
+        return Ok(());
+    }
+    if dry_run {
+        // Later migrations are planned against the files as they are now.
+        for migration in pending {
+            let changes = migration.plan(guild_path)?;
+            println!(
+                "\n## {} -> {}: {} ({} change(s))",
+                migration.from,
+                migration.from + 1,
+                migration.summary,
+                changes.len()
+            );
+            for change in changes {
+                println!("\n{}", change.render());
+            }
+        }
+        println!("\nDry run: nothing was changed. Re-run without --dry-run to apply.");
+        return Ok(());
+    }
+
+    ensure_daemon_stopped(guild_path)?;
+    for migration in pending {
+        let changes = migration.apply(guild_path)?;
+        println!(
+            "{} -> {}: {} ({} change(s))",
+            migration.from,
+            migration.from + 1,
+            migration.summary,
+            changes.len()
+        );
+    }
+    println!("Workspace is now at format {}.", migrate::CURRENT_VERSION);
+    Ok(())
+}
+
+async fn run_prompt_test(
+    guild_path: &Path,
+    profile: Option<&str>,
+    message: &str,
+    channel: &str,
+    show_prompt: bool,
+) -> Result<()> {
+    let profile = tellar::profiles::resolve(profile);
+    let config = Config::load_profile(guild_path.join("tellar.yml"), profile.as_deref())
+        .context("tellar.yml is missing or invalid; run `tellarctl setup` first")?;
+    let report =
+        tellar::prompt_test::run(guild_path, std::sync::Arc::new(config), channel, message).await?;
+    println!("{}", report.render(show_prompt));
+    Ok(())
+}
+
+async fn run_export(
+    guild_path: &Path,
+    profile: Option<&str>,
+    channel: &str,
+    (from, to): (Option<&str>, Option<&str>),
+    format: Option<&str>,
+    output: Option<&Path>,
+    summarize: bool,
+) -> Result<()> {
+    use tellar::channel_export::{self, ExportFormat};
+
+    let folder = if guild_path.join("channels").join(channel).is_dir() {
+        channel.to_string()
+    } else {
+        tellar::discord::resolve_folder_by_id(guild_path, channel)
+            .with_context(|| format!("no channel folder for `{}`", channel))?
+    };
+    let to = match to {
+        Some(to) => parse_day(to)?,
+        None => chrono::Local::now().date_naive(),
+    };
+    let from = match from {
+        Some(from) => parse_day(from)?,
+        None => to - chrono::Duration::days(6),
+    };
+    let html = match format {
+        Some(format) => format == "html",
+        None => output
+            .and_then(|path| path.extension())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm")),
+    };
+    let format = if html {
+        ExportFormat::Html
+    } else {
+        ExportFormat::Markdown
+    };
+
+    let documents = channel_export::collect(guild_path, &folder, from, to)?;
+    if documents.is_empty() {
+        bail!("channels/{} has nothing dated {} to {}", folder, from, to);
+    }
+    let summary = if summarize {
+        let profile = tellar::profiles::resolve(profile);
+        let config = Config::load_profile(guild_path.join("tellar.yml"), profile.as_deref())
+            .context("--summarize needs a loadable tellar.yml with a Gemini key")?;
+        Some(channel_export::summarize(&config, &documents).await?)
+    } else {
+        None
+    };
+    let report = channel_export::render(format, &folder, from, to, &documents, summary.as_deref());
+    match output {
+        Some(path) => {
+            fs::write(path, report)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            eprintln!(
+                "Exported {} file(s) from channels/{} to {}",
+                documents.len(),
+                folder,
+                path.display()
+            );
+        }
+        None => print!("{}", report),
+    }
+    Ok(())
+}
+
+fn parse_log_level(value: &str) -> Result<tellar::runtime_log::Level, String> {
+    tellar::runtime_log::Level::parse(value)
+        .ok_or_else(|| format!("unknown level `{}`; use info, warn, or error", value))
+}
+
+async fn run_structured_logs(
+    guild_path: &Path,
+    filter: &tellar::runtime_log::LogFilter,
+    json: bool,
+    days: u32,
+    follow: bool,
+) -> Result<()> {
+    use tellar::runtime_log::{self, LogRecord};
+
+    let print = |record: &LogRecord| -> Result<()> {
+        if filter.matches(record) {
+            if json {
+                println!("{}", serde_json::to_string(record)?);
+            } else {
+                println!("{}", record.render());
+            }
+        }
+        Ok(())
+    };
+    let mut date = chrono::Local::now().date_naive();
+    for back in (1..days.max(1)).rev() {
+        let day = date - chrono::Duration::days(back.into());
+        for record in runtime_log::load_day(guild_path, day) {
+            print(&record)?;
+        }
+    }
+    let (records, mut offset) = runtime_log::read_from(guild_path, date, 0);
+    for record in &records {
+        print(record)?;
+    }
+    if !follow {
+        return Ok(());
+    }
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        let today = chrono::Local::now().date_naive();
+        if today != date {
+            // Finish the previous day before moving to the new file.
+            for record in runtime_log::read_from(guild_path, date, offset).0 {
+                print(&record)?;
+            }
+            date = today;
+            offset = 0;
+        }
+        let (records, next) = runtime_log::read_from(guild_path, date, offset);
+        for record in &records {
+            print(record)?;
+        }
+        offset = next;
+    }
+}
+
 fn ensure_systemd_service_support() -> Result<()> {
     ensure_linux()?;
     require_command("systemctl")
@@ -608,9 +2749,37 @@ mod tests {
         let dir = tempdir().unwrap();
         let config = load_or_default_config(&dir.path().join("missing.yml")).unwrap();
 
-        assert_eq!(config.gemini.api_key, "YOUR_KEY");
-        assert_eq!(config.discord.token, "YOUR_TOKEN");
-        assert!(config.gemini.model.is_empty());
+        assert_eq!(config_str(&config, "gemini", "api_key"), "YOUR_KEY");
+        assert_eq!(config_str(&config, "discord", "token"), "YOUR_TOKEN");
+        assert!(config_str(&config, "gemini", "model").is_empty());
+    }
+
+    #[test]
+    fn test_load_or_default_config_keeps_profiles_and_unset_sections() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("tellar.yml");
+        fs::write(
+            &path,
+            "gemini:\n  api_key: YOUR_KEY\n  model: gemini-2.5-flash\nprofiles:\n  work:\n    discord:\n      token: WORK\n",
+        )
+        .unwrap();
+        let mut config = load_or_default_config(&path).unwrap();
+        config["gemini"]["api_key"] = "key".into();
+        config["discord"]["token"] = "token".into();
+        write_config_value(&path, &config).unwrap();
+
+        let written = read_config_value(&path).unwrap();
+        assert_eq!(written["profiles"]["work"]["discord"]["token"], "WORK");
+        assert_eq!(written["gemini"]["api_key"], "key");
+        assert_eq!(written["discord"]["token"], "token");
+        let sections: Vec<_> = written
+            .as_mapping()
+            .unwrap()
+            .keys()
+            .filter_map(|key| key.as_str())
+            .collect();
+        assert_eq!(sections, ["gemini", "profiles", "discord"]);
+        assert!(Config::load_file(&path).is_ok());
     }
 
     #[test]
@@ -649,6 +2818,28 @@ mod tests {
         assert!(format!("{}", err).contains("could not find the `tellar` binary"));
     }
 
+    #[test]
+    fn test_run_sandbox_creates_mock_guild_and_refuses_populated_dirs() {
+        let dir = tempdir().unwrap();
+        let target = dir.path().join("sandbox");
+
+        run_sandbox(target.clone(), false).unwrap();
+        let config = Config::load(target.join("tellar.yml")).unwrap();
+        assert_eq!(config.gemini.model, tellar::mock_llm::MOCK_MODEL);
+        assert!(target.join("agents/AGENTS.md").exists());
+        assert!(target.join("rituals/sandbox-tour.md").exists());
+        assert!(target.join("skills/sandbox-echo/SKILL.json").exists());
+        assert!(
+            std::fs::read_to_string(target.join("brain/KNOWLEDGE.md"))
+                .unwrap()
+                .contains("sandbox guild")
+        );
+
+        let err = run_sandbox(target.clone(), false).unwrap_err();
+        assert!(format!("{}", err).contains("is not empty"));
+        run_sandbox(target, true).unwrap();
+    }
+
     #[test]
     fn test_extract_dir_contents_preserves_existing_files() {
         let dir = tempdir().unwrap();
@@ -713,17 +2904,160 @@ mod tests {
                     description: "a".to_string(),
                     parameters: serde_json::json!({ "type": "object" }),
                     command: "printf a".to_string(),
+                    wasm: None,
+                    python: None,
+                    examples: Vec::new(),
+                    output: None,
                 },
                 InstalledSkillTool {
                     name: "dup".to_string(),
                     description: "b".to_string(),
                     parameters: serde_json::json!({ "type": "object" }),
                     command: "printf b".to_string(),
+                    wasm: None,
+                    python: None,
+                    examples: Vec::new(),
+                    output: None,
                 },
             ],
+            runtime: Default::default(),
         };
 
         let err = validate_installed_skill(&skill).unwrap_err();
         assert!(format!("{}", err).contains("duplicate tool name"));
     }
+
+    #[test]
+    fn test_compile_skill_md_offline_maps_frontmatter_and_rejects_free_form() {
+        let compiled = compile_skill_md_offline(
+            r#"---
+timeout_secs: 30
+tools:
+  forecast:
+    description: Forecast for a city
+    shell: curl -s wttr.in/{{city}}
+    parameters:
+      type: object
+      properties:
+        city: { type: string }
+---
+# Weather lookups
+
+Use when the user asks about the weather.
+"#,
+            "weather",
+        )
+        .unwrap();
+        assert_eq!(compiled.name, "weather");
+        assert_eq!(compiled.description, "Weather lookups");
+        assert_eq!(compiled.tools[0].command, "curl -s wttr.in/{{city}}");
+        assert_eq!(compiled.runtime.timeout_secs, Some(30));
+
+        assert!(compile_skill_md_offline("# Weather\n\nRun curl.", "weather").is_err());
+        assert!(compile_skill_md_offline("---\nname: x\n---\nbody", "x").is_err());
+    }
+
+    #[test]
+    fn test_render_skill_scaffold_builds_compilable_skill() {
+        let args = parse_scaffold_args("city:string, days:integer?").unwrap();
+        assert_eq!(args[1], ("days".to_string(), "integer".to_string(), false));
+        assert!(parse_scaffold_args("when:date").is_err());
+
+        let files = render_skill_scaffold(&SkillScaffold {
+            name: "weather".to_string(),
+            description: "Weather lookups".to_string(),
+            tool: "forecast".to_string(),
+            tool_description: "Forecast for a city".to_string(),
+            args,
+            command: String::new(),
+        })
+        .unwrap();
+        assert_eq!(files[1].0, "run.sh");
+
+        let compiled = compile_skill_md_offline(&files[0].1, "weather").unwrap();
+        let tool = &compiled.tools[0];
+        assert_eq!(tool.name, "forecast");
+        assert_eq!(
+            tool.command,
+            "./run.sh {{city}} {{#days}}--days {{days}}{{/days}}"
+        );
+        assert_eq!(tool.parameters["required"], serde_json::json!(["city"]));
+        assert_eq!(tool.parameters["properties"]["days"]["type"], "integer");
+    }
+
+    #[cfg(feature = "skill-pack")]
+    #[test]
+    fn test_bundled_skills_compile_offline() {
+        let mut names = Vec::new();
+        for dir in SKILL_PACK.dirs() {
+            let name = dir.path().to_string_lossy().into_owned();
+            let skill_md = dir
+                .get_file(dir.path().join("SKILL.md"))
+                .and_then(|file| file.contents_utf8())
+                .unwrap();
+            let compiled = compile_skill_md_offline(skill_md, &name).unwrap();
+            assert_eq!(compiled.name, name);
+            validate_installed_skill(&compiled).unwrap();
+            compiled.runtime.validate().unwrap();
+            names.push(name);
+        }
+        names.sort();
+        assert_eq!(names, ["reminders", "units", "weather", "web-search"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_skill_examples_checks_output_and_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("SKILL.md"),
+            r#"---
+name: greet
+tools:
+  hello:
+    description: Greet someone
+    shell: printf 'hello {{name}}'
+    parameters:
+      type: object
+      required: [name]
+      properties:
+        name: { type: string }
+    examples:
+      - args: { name: Ada }
+        expect: "^hello Ada$"
+      - args: {}
+        expect_error: true
+        expect: "missing required field"
+      - args: { name: Bob }
+        expect: "^goodbye"
+---
+"#,
+        )
+        .unwrap();
+
+        let skill = load_skill_dir(dir.path()).unwrap();
+        let (passed, failures) = run_skill_examples(&skill, dir.path()).await.unwrap();
+        assert_eq!(passed, 2);
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].starts_with("hello #3: `^goodbye` did not match"));
+    }
+
+    #[test]
+    fn test_completions_and_man_pages_cover_subcommands() {
+        let mut script = Vec::new();
+        clap_complete::generate(
+            clap_complete::Shell::Bash,
+            &mut Cli::command(),
+            "tellarctl",
+            &mut script,
+        );
+        let script = String::from_utf8(script).unwrap();
+        assert!(script.contains("tellarctl__subcmd__self__subcmd__update"));
+        assert!(script.contains("--dry-run"));
+
+        let dir = tempfile::tempdir().unwrap();
+        run_man(Some(dir.path())).unwrap();
+        let page = std::fs::read_to_string(dir.path().join("tellarctl.1")).unwrap();
+        assert!(page.contains("tellarctl\\-migrate(1)"));
+        assert!(dir.path().join("tellarctl-ritual-new.1").exists());
+    }
 }
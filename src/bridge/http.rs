@@ -0,0 +1,78 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/bridge/http.rs
+ * Responsibility: Generic HTTP bridge adapter for WhatsApp-style gateways.
+ */
+
+use super::BridgeMessage;
+use crate::config::BridgeConfig;
+use once_cell::sync::Lazy;
+use serde_json::{Value, json};
+use std::time::Duration;
+
+static BRIDGE_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(30))
+        .build()
+        .expect("Failed to create bridge reqwest client")
+});
+
+/// Parse an inbound `{"from": "...", "name": "...", "text": "..."}` payload.
+pub(crate) fn parse_http_message(payload: &Value) -> Option<BridgeMessage> {
+    let sender = payload["from"].as_str()?.trim();
+    let text = payload["text"].as_str()?.trim();
+    if sender.is_empty() || text.is_empty() {
+        return None;
+    }
+    Some(BridgeMessage {
+        sender: sender.to_string(),
+        sender_name: payload["name"]
+            .as_str()
+            .filter(|name| !name.is_empty())
+            .map(str::to_string),
+        text: text.to_string(),
+    })
+}
+
+/// POST `{"to": "...", "text": "..."}` to the bridge's send endpoint.
+pub(super) async fn send_message(
+    bridge: &BridgeConfig,
+    recipient: &str,
+    text: &str,
+) -> anyhow::Result<()> {
+    let mut request = BRIDGE_CLIENT
+        .post(&bridge.url)
+        .json(&json!({ "to": recipient, "text": text }));
+    if !bridge.token.is_empty() {
+        request = request.bearer_auth(&bridge.token);
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!(
+            "{} bridge returned HTTP {}",
+            bridge.name,
+            response.status()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_http_message_requires_sender_and_text() {
+        let message =
+            parse_http_message(&json!({ "from": "+15551234567", "name": "Dad", "text": " hi " }))
+                .unwrap();
+        assert_eq!(message.sender, "+15551234567");
+        assert_eq!(message.sender_name.as_deref(), Some("Dad"));
+        assert_eq!(message.text, "hi");
+
+        assert!(parse_http_message(&json!({ "from": "+15551234567", "text": "" })).is_none());
+        assert!(parse_http_message(&json!({ "text": "hi" })).is_none());
+    }
+}
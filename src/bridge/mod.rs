@@ -0,0 +1,211 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/bridge/mod.rs
+ * Responsibility: Messaging bridges (Signal, WhatsApp gateways) mapped to per-contact blackboards.
+ */
+
+mod http;
+mod signal;
+
+pub(crate) use http::parse_http_message;
+
+use crate::config::{BridgeConfig, BridgeKind, Config};
+use crate::discord::ingest_store::append_to_message_log;
+use crate::session::run_conversational_loop;
+use crate::{log_info, log_warn};
+use chrono::Local;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Bridged contacts have no Discord channel; "0" selects the base identity only.
+const BRIDGE_CHANNEL_ID: &str = "0";
+
+/// One inbound message, already normalized from the bridge's wire format.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BridgeMessage {
+    pub(crate) sender: String,
+    pub(crate) sender_name: Option<String>,
+    pub(crate) text: String,
+}
+
+fn folder_slug(value: &str) -> String {
+    let mut slug = String::new();
+    for ch in value.trim().chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Folder under `channels/` for a sender, or `None` when the sender is not allowed.
+pub(crate) fn contact_folder(bridge: &BridgeConfig, sender: &str) -> Option<String> {
+    let contact = match bridge.contacts.get(sender) {
+        Some(alias) => folder_slug(alias),
+        None if bridge.allow_unknown => folder_slug(sender),
+        None => return None,
+    };
+    if contact.is_empty() {
+        return None;
+    }
+    Some(format!("{}/{}", folder_slug(&bridge.name), contact))
+}
+
+async fn append_bridge_entry(
+    base_path: &Path,
+    thread_id: &str,
+    author: &str,
+    author_id: &str,
+    message_id: &str,
+    content: &str,
+) -> anyhow::Result<()> {
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    append_to_message_log(
+        base_path,
+        thread_id,
+        author,
+        author_id,
+        content,
+        message_id,
+        &timestamp,
+        None,
+        Vec::new(),
+    )
+    .await
+}
+
+/// Send a text message back to a contact through the bridge it came from.
+pub(crate) async fn send_to_contact(
+    bridge: &BridgeConfig,
+    recipient: &str,
+    text: &str,
+) -> anyhow::Result<()> {
+    match bridge.kind {
+        BridgeKind::SignalCli => signal::send_message(bridge, recipient, text).await,
+        BridgeKind::Http => http::send_message(bridge, recipient, text).await,
+    }
+}
+
+/// Inscribe an inbound message into the contact's blackboard, let the Steward answer it,
+/// and deliver the reply through the same bridge. Unknown senders are ignored.
+pub(crate) async fn handle_inbound(
+    base_path: &Path,
+    config: Arc<Config>,
+    bridge: &BridgeConfig,
+    message: BridgeMessage,
+) -> anyhow::Result<()> {
+    let Some(folder) = contact_folder(bridge, &message.sender) else {
+        log_info!(
+            "runtime",
+            "🚫 Ignoring {} message from unknown contact {}",
+            bridge.name,
+            message.sender
+        );
+        return Ok(());
+    };
+
+    let thread_id = format!("{}/{}.md", folder, Local::now().format("%Y-%m-%d"));
+    let blackboard: PathBuf = base_path.join("channels").join(&thread_id);
+    let message_id = format!("{}-{}", bridge.name, uuid::Uuid::new_v4());
+    let author = message
+        .sender_name
+        .clone()
+        .or_else(|| bridge.contacts.get(&message.sender).cloned())
+        .unwrap_or_else(|| message.sender.clone());
+    append_bridge_entry(
+        base_path,
+        &thread_id,
+        &author,
+        &message.sender,
+        &message_id,
+        &message.text,
+    )
+    .await?;
+    log_info!(
+        "runtime",
+        "📱 {} message from {} inscribed into {}",
+        bridge.name,
+        author,
+        thread_id
+    );
+
+    let content = tokio::fs::read_to_string(&blackboard).await?;
+    let outcome = run_conversational_loop(
+        &content,
+        &blackboard,
+        base_path,
+        Arc::clone(&config),
+        Some(message_id),
+        BRIDGE_CHANNEL_ID,
+    )
+    .await?;
+    log_warn!("runtime", "🗣️ {} {}", bridge.name, outcome.log_summary());
+
+    let reply = crate::tools::mask_sensitive_data(&outcome.user_response, &config);
+    send_to_contact(bridge, &message.sender, &reply).await?;
+    append_bridge_entry(
+        base_path,
+        &thread_id,
+        "Tellar",
+        "local",
+        &format!("{}-{}", bridge.name, uuid::Uuid::new_v4()),
+        &reply,
+    )
+    .await
+}
+
+/// Start the receivers for every configured bridge that pulls its own messages.
+/// HTTP bridges are push-based and arrive through the inbound webhook server instead.
+pub fn start_bridges(base_path: PathBuf, config: Arc<Config>) {
+    for bridge in config.bridges.clone() {
+        match bridge.kind {
+            BridgeKind::SignalCli => {
+                let base_path = base_path.clone();
+                let config = Arc::clone(&config);
+                tokio::spawn(async move {
+                    signal::run_receiver(base_path, config, bridge).await;
+                });
+            }
+            BridgeKind::Http => {
+                if !config.inbound_webhook.enabled {
+                    log_warn!(
+                        "runtime",
+                        "⚠️ Bridge `{}` needs inbound_webhook.enabled to receive messages.",
+                        bridge.name
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn bridge(allow_unknown: bool) -> BridgeConfig {
+        BridgeConfig {
+            name: "Signal".to_string(),
+            url: "http://127.0.0.1:8080".to_string(),
+            contacts: HashMap::from([("+15551234567".to_string(), "Mom".to_string())]),
+            allow_unknown,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_contact_folder_maps_known_contacts_and_gates_unknown_ones() {
+        assert_eq!(
+            contact_folder(&bridge(false), "+15551234567").as_deref(),
+            Some("signal/mom")
+        );
+        assert_eq!(contact_folder(&bridge(false), "+15550000000"), None);
+        assert_eq!(
+            contact_folder(&bridge(true), "+1 555 000 0000").as_deref(),
+            Some("signal/1-555-000-0000")
+        );
+    }
+}
@@ -0,0 +1,172 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/bridge/signal.rs
+ * Responsibility: signal-cli daemon adapter (SSE receive stream and JSON-RPC send).
+ */
+
+use super::{BridgeMessage, handle_inbound};
+use crate::config::{BridgeConfig, Config};
+use crate::{log_info, log_warn};
+use once_cell::sync::Lazy;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(15);
+
+static RPC_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(30))
+        .build()
+        .expect("Failed to create signal-cli reqwest client")
+});
+
+/// The event stream stays open indefinitely, so it must not carry a request timeout.
+static EVENTS_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .build()
+        .expect("Failed to create signal-cli events client")
+});
+
+/// Extract a text message from one signal-cli `receive` notification. Receipts,
+/// typing indicators, and sync messages carry no `dataMessage.message` and are skipped.
+pub(crate) fn parse_receive_event(data: &str) -> Option<BridgeMessage> {
+    let event: Value = serde_json::from_str(data).ok()?;
+    let envelope = event
+        .get("envelope")
+        .or_else(|| event["params"].get("envelope"))?;
+    let text = envelope["dataMessage"]["message"].as_str()?.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    let sender = envelope["sourceNumber"]
+        .as_str()
+        .or_else(|| envelope["source"].as_str())?
+        .to_string();
+    let sender_name = envelope["sourceName"]
+        .as_str()
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+    Some(BridgeMessage {
+        sender,
+        sender_name,
+        text: text.to_string(),
+    })
+}
+
+pub(super) async fn send_message(
+    bridge: &BridgeConfig,
+    recipient: &str,
+    text: &str,
+) -> anyhow::Result<()> {
+    let mut params = json!({ "recipient": [recipient], "message": text });
+    if let Some(account) = &bridge.account {
+        params["account"] = json!(account);
+    }
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": uuid::Uuid::new_v4().to_string(),
+        "method": "send",
+        "params": params,
+    });
+
+    let url = format!("{}/api/v1/rpc", bridge.url.trim_end_matches('/'));
+    let response: Value = RPC_CLIENT
+        .post(url)
+        .json(&request)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    if let Some(error) = response.get("error") {
+        return Err(anyhow::anyhow!("signal-cli send failed: {}", error));
+    }
+    Ok(())
+}
+
+async fn stream_events(
+    base_path: &Path,
+    config: &Arc<Config>,
+    bridge: &BridgeConfig,
+) -> anyhow::Result<()> {
+    let mut url = format!("{}/api/v1/events", bridge.url.trim_end_matches('/'));
+    if let Some(account) = &bridge.account {
+        url.push_str(&format!("?account={}", account.replace('+', "%2B")));
+    }
+    let mut response = EVENTS_CLIENT.get(url).send().await?.error_for_status()?;
+
+    let mut buffer = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        buffer.extend_from_slice(&chunk);
+        while let Some(newline) = buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line);
+            let Some(message) = line
+                .trim()
+                .strip_prefix("data:")
+                .and_then(|data| parse_receive_event(data.trim()))
+            else {
+                continue;
+            };
+            if let Err(error) = handle_inbound(base_path, Arc::clone(config), bridge, message).await
+            {
+                log_warn!(
+                    "runtime",
+                    "⚠️ {} bridge failed to handle a message: {:?}",
+                    bridge.name,
+                    error
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Follow the signal-cli event stream forever, reconnecting after drops.
+pub(super) async fn run_receiver(base_path: PathBuf, config: Arc<Config>, bridge: BridgeConfig) {
+    log_info!(
+        "runtime",
+        "📱 {} bridge listening on signal-cli at {}",
+        bridge.name,
+        bridge.url
+    );
+    loop {
+        if let Err(error) = stream_events(&base_path, &config, &bridge).await {
+            log_warn!(
+                "runtime",
+                "⚠️ {} bridge stream dropped: {:?}",
+                bridge.name,
+                error
+            );
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_receive_event_reads_data_messages_only() {
+        let message = parse_receive_event(
+            r#"{"envelope":{"source":"+15551234567","sourceNumber":"+15551234567","sourceName":"Mom","timestamp":1772618400000,"dataMessage":{"timestamp":1772618400000,"message":"Is dinner at 7?"}},"account":"+15550000000"}"#,
+        )
+        .unwrap();
+        assert_eq!(message.sender, "+15551234567");
+        assert_eq!(message.sender_name.as_deref(), Some("Mom"));
+        assert_eq!(message.text, "Is dinner at 7?");
+
+        assert!(
+            parse_receive_event(
+                r#"{"envelope":{"source":"+15551234567","receiptMessage":{"isDelivery":true}}}"#
+            )
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,840 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/calendar.rs
+ * Responsibility: Poll ICS/CalDAV calendars, turn upcoming events into rituals, and set reminders ahead of them.
+ */
+
+use crate::config::{CalendarSource, Config};
+use crate::datetime::{self, Zone};
+use crate::{log_info, log_warn};
+use chrono::{
+    DateTime, Datelike, Days, FixedOffset, Local, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeDelta, Timelike, Utc, Weekday,
+};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const CALENDAR_RITUALS_DIR: &str = "rituals/calendar";
+const SEEN_DIR: &str = "brain/calendar";
+const MAX_SEEN_PER_SOURCE: usize = 1000;
+const MIN_POLL_SECS: u64 = 60;
+/// Recurrence periods walked before giving up on finding the next occurrence.
+const MAX_PERIODS: u32 = 5000;
+/// An event keeps its ritual this long after it starts, so a running ritual is not archived.
+const GRACE_HOURS: i64 = 6;
+
+static CALENDAR_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(30))
+        .build()
+        .expect("Failed to create calendar reqwest client")
+});
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CalendarEvent {
+    pub(crate) uid: String,
+    pub(crate) summary: String,
+    pub(crate) start: DateTime<FixedOffset>,
+    /// The zone `start` was written in; recurrences keep its wall-clock time.
+    pub(crate) zone: Zone,
+    pub(crate) all_day: bool,
+    pub(crate) location: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) rrule: Option<String>,
+    pub(crate) exdates: Vec<DateTime<FixedOffset>>,
+    pub(crate) cancelled: bool,
+}
+
+/// Join folded lines: a line starting with a space or tab continues the previous one.
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        let line = line.trim_end_matches('\r');
+        match line.strip_prefix([' ', '\t']) {
+            Some(rest) if !lines.is_empty() => lines.last_mut().unwrap().push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// A content line: upper-cased name, parameters, and raw value.
+type Property = (String, Vec<(String, String)>, String);
+
+/// Split `NAME;PARAM=V:value` into the upper-cased name, its parameters, and the value.
+fn split_property(line: &str) -> Option<Property> {
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(i, ch)| {
+        if ch == '"' {
+            quoted = !quoted;
+        }
+        (ch == ':' && !quoted).then_some(i)
+    })?;
+    let mut parts = line[..colon].split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| param.split_once('='))
+        .map(|(key, value)| {
+            (
+                key.to_ascii_uppercase(),
+                value.trim_matches('"').to_string(),
+            )
+        })
+        .collect();
+    Some((name, params, line[colon + 1..].to_string()))
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+/// Read a DATE or DATE-TIME value. `Z` times are UTC, a known `TZID` names the zone, and
+/// anything else is wall-clock time in `fallback`.
+fn parse_ics_time(
+    value: &str,
+    params: &[(String, String)],
+    fallback: Zone,
+) -> Option<(DateTime<FixedOffset>, Zone, bool)> {
+    let value = value.trim();
+    let param = |key: &str| {
+        params
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    };
+    if param("VALUE") == Some("DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        let start = fallback.localize(date.and_time(NaiveTime::MIN)).ok()?;
+        return Some((start, fallback, true));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some((
+            naive.and_utc().fixed_offset(),
+            Zone::parse(Some("UTC")).ok()?,
+            false,
+        ));
+    }
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    let zone = param("TZID")
+        .and_then(|tzid| Zone::parse(Some(tzid)).ok())
+        .unwrap_or(fallback);
+    Some((zone.localize(naive).ok()?, zone, false))
+}
+
+/// Every VEVENT in an iCalendar document. Events without a UID or start are skipped.
+pub(crate) fn parse_ics(ics: &str, fallback: Zone) -> Vec<CalendarEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<Vec<Property>> = None;
+    for line in unfold(ics) {
+        match line.trim() {
+            "BEGIN:VEVENT" => current = Some(Vec::new()),
+            "END:VEVENT" => {
+                if let Some(event) = current
+                    .take()
+                    .and_then(|props| build_event(&props, fallback))
+                {
+                    events.push(event);
+                }
+            }
+            _ => {
+                if let (Some(props), Some(property)) = (current.as_mut(), split_property(&line)) {
+                    props.push(property);
+                }
+            }
+        }
+    }
+    events
+}
+
+fn build_event(props: &[Property], fallback: Zone) -> Option<CalendarEvent> {
+    let text = |key: &str| {
+        props
+            .iter()
+            .find(|(name, _, _)| name == key)
+            .map(|(_, _, value)| unescape(value))
+            .filter(|value| !value.is_empty())
+    };
+    let (start, zone, all_day) = props
+        .iter()
+        .find(|(name, _, _)| name == "DTSTART")
+        .and_then(|(_, params, value)| parse_ics_time(value, params, fallback))?;
+    let exdates = props
+        .iter()
+        .filter(|(name, _, _)| name == "EXDATE")
+        .flat_map(|(_, params, value)| {
+            value
+                .split(',')
+                .filter_map(|item| parse_ics_time(item, params, zone).map(|(at, _, _)| at))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    Some(CalendarEvent {
+        uid: text("UID")?,
+        summary: text("SUMMARY").unwrap_or_else(|| "(untitled event)".to_string()),
+        start,
+        zone,
+        all_day,
+        location: text("LOCATION"),
+        description: text("DESCRIPTION"),
+        rrule: text("RRULE"),
+        exdates,
+        cancelled: text("STATUS").is_some_and(|status| status.eq_ignore_ascii_case("CANCELLED")),
+    })
+}
+
+/// `MO`..`SU`, ignoring an ordinal prefix such as `1MO` or `-1FR`.
+fn ics_weekday(day: &str) -> Option<Weekday> {
+    let code = day
+        .trim()
+        .trim_start_matches(|c: char| c == '+' || c == '-' || c.is_ascii_digit());
+    Some(match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// The dates of the `period`-th recurrence period, in order.
+fn period_dates(
+    freq: &str,
+    start: NaiveDate,
+    interval: u32,
+    period: u32,
+    by_day: &[Weekday],
+) -> Vec<NaiveDate> {
+    let step = interval * period;
+    match freq {
+        "DAILY" => start
+            .checked_add_days(Days::new(step as u64))
+            .into_iter()
+            .collect(),
+        "WEEKLY" if by_day.is_empty() => start
+            .checked_add_days(Days::new(7 * step as u64))
+            .into_iter()
+            .collect(),
+        "WEEKLY" => {
+            let monday = start - Days::new(start.weekday().num_days_from_monday() as u64);
+            let Some(week) = monday.checked_add_days(Days::new(7 * step as u64)) else {
+                return Vec::new();
+            };
+            let mut dates: Vec<NaiveDate> = by_day
+                .iter()
+                .map(|day| week + Days::new(day.num_days_from_monday() as u64))
+                .collect();
+            dates.sort();
+            dates
+        }
+        // Months without the start's day (e.g. the 31st) are skipped, as RFC 5545 says.
+        "MONTHLY" | "YEARLY" => {
+            let months = if freq == "MONTHLY" { step } else { 12 * step };
+            start
+                .with_day(1)
+                .and_then(|first| first.checked_add_months(Months::new(months)))
+                .and_then(|month| month.with_day(start.day()))
+                .into_iter()
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// The first occurrence of `event` at or after `after`, following a simple RRULE
+/// (FREQ, INTERVAL, COUNT, UNTIL, and BYDAY for weekly rules) and skipping EXDATEs.
+pub(crate) fn next_occurrence(
+    event: &CalendarEvent,
+    after: DateTime<FixedOffset>,
+) -> Option<DateTime<FixedOffset>> {
+    let Some(rrule) = &event.rrule else {
+        return (event.start >= after).then_some(event.start);
+    };
+    let rule: HashMap<String, String> = rrule
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .map(|(key, value)| (key.to_ascii_uppercase(), value.to_ascii_uppercase()))
+        .collect();
+    let freq = rule.get("FREQ")?.as_str();
+    let interval = rule
+        .get("INTERVAL")
+        .and_then(|value| value.parse().ok())
+        .filter(|interval| *interval > 0)
+        .unwrap_or(1);
+    let count: Option<usize> = rule.get("COUNT").and_then(|value| value.parse().ok());
+    let until = rule
+        .get("UNTIL")
+        .and_then(|value| parse_ics_time(value, &[], event.zone))
+        .map(|(until, _, all_day)| {
+            if all_day {
+                until + TimeDelta::days(1)
+            } else {
+                until
+            }
+        });
+    let by_day: Vec<Weekday> = rule
+        .get("BYDAY")
+        .map(|days| days.split(',').filter_map(ics_weekday).collect())
+        .unwrap_or_default();
+
+    let local_start = event.start.naive_local();
+    let mut seen = 0usize;
+    for period in 0..MAX_PERIODS {
+        for date in period_dates(freq, local_start.date(), interval, period, &by_day) {
+            if date < local_start.date() {
+                continue;
+            }
+            seen += 1;
+            if count.is_some_and(|count| seen > count) {
+                return None;
+            }
+            let Ok(occurrence) = event.zone.localize(date.and_time(local_start.time())) else {
+                continue;
+            };
+            if until.is_some_and(|until| occurrence > until) {
+                return None;
+            }
+            if occurrence >= after && !event.exdates.contains(&occurrence) {
+                return Some(occurrence);
+            }
+        }
+    }
+    None
+}
+
+/// The earliest occurrence per UID starting within `[from, to]`, skipping cancelled events.
+pub(crate) fn upcoming(
+    events: &[CalendarEvent],
+    from: DateTime<FixedOffset>,
+    to: DateTime<FixedOffset>,
+) -> BTreeMap<String, (CalendarEvent, DateTime<FixedOffset>)> {
+    let mut upcoming: BTreeMap<String, (CalendarEvent, DateTime<FixedOffset>)> = BTreeMap::new();
+    for event in events.iter().filter(|event| !event.cancelled) {
+        let Some(start) = next_occurrence(event, from).filter(|start| *start <= to) else {
+            continue;
+        };
+        if upcoming
+            .get(&event.uid)
+            .is_none_or(|(_, current)| start < *current)
+        {
+            upcoming.insert(event.uid.clone(), (event.clone(), start));
+        }
+    }
+    upcoming
+}
+
+fn safe_name(name: &str) -> String {
+    let slug = crate::entities::slugify(name);
+    if slug.is_empty() {
+        "calendar".to_string()
+    } else {
+        slug
+    }
+}
+
+fn uid_hash(uid: &str) -> String {
+    Sha256::digest(uid.as_bytes())
+        .iter()
+        .take(4)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn source_dir(base_path: &Path, source: &CalendarSource) -> PathBuf {
+    base_path
+        .join(CALENDAR_RITUALS_DIR)
+        .join(safe_name(&source.name))
+}
+
+/// Header of a calendar ritual. Like Discord event rituals, the schedule is a one-off cron
+/// in UTC that the next poll moves to the following occurrence.
+#[derive(Debug, Serialize)]
+struct CalendarRitualHeader<'a> {
+    calendar_uid: &'a str,
+    calendar_source: &'a str,
+    task_id: String,
+    source: &'static str,
+    origin_channel: &'a str,
+    status: &'a str,
+    schedule: String,
+    timezone: &'static str,
+    injection_template: String,
+}
+
+fn ritual_header(
+    source: &CalendarSource,
+    event: &CalendarEvent,
+    start: DateTime<FixedOffset>,
+    status: &str,
+) -> anyhow::Result<String> {
+    let utc = start.with_timezone(&Utc);
+    let header = serde_yml::to_string(&CalendarRitualHeader {
+        calendar_uid: &event.uid,
+        calendar_source: &source.name,
+        task_id: format!("calendar_{}", uid_hash(&event.uid)),
+        source: "calendar",
+        origin_channel: &source.channel_id,
+        status,
+        schedule: format!(
+            "0 {} {} {} {} *",
+            utc.minute(),
+            utc.hour(),
+            utc.day(),
+            utc.month()
+        ),
+        timezone: "UTC",
+        injection_template: format!("- [ ] Start the Ritual: {}", event.summary),
+    })?;
+    Ok(format!("---\n{}---", header))
+}
+
+fn ritual_body(
+    source: &CalendarSource,
+    event: &CalendarEvent,
+    start: DateTime<FixedOffset>,
+) -> String {
+    let mut body = format!(
+        "\n# Ritual: {}\n\nThis ritual is synchronized from the calendar `{}`.\nStarts: {}\n",
+        event.summary,
+        source.name,
+        if event.all_day {
+            start.format("%Y-%m-%d (all day)").to_string()
+        } else {
+            start.format("%Y-%m-%d %H:%M %:z").to_string()
+        }
+    );
+    if let Some(location) = &event.location {
+        body.push_str(&format!("Location: {}\n", location));
+    }
+    if let Some(description) = &event.description {
+        body.push_str(&format!("\n{}\n", description));
+    }
+    body
+}
+
+/// Calendar ritual files under the source's folder, keyed by `calendar_uid`.
+fn existing_rituals(dir: &Path) -> HashMap<String, PathBuf> {
+    let mut paths = Vec::new();
+    let _ = crate::rhythm::collect_thread_files(dir, &mut paths);
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let header = content.get(..crate::thread::doc::body_start(&content))?;
+            let uid = header
+                .lines()
+                .find_map(|line| line.strip_prefix("calendar_uid:"))?;
+            let uid: String = serde_yml::from_str(uid.trim()).ok()?;
+            Some((uid, path))
+        })
+        .collect()
+}
+
+fn seen_file(base_path: &Path, source: &CalendarSource) -> PathBuf {
+    base_path
+        .join(SEEN_DIR)
+        .join(format!("{}.seen", safe_name(&source.name)))
+}
+
+fn load_seen(base_path: &Path, source: &CalendarSource) -> Vec<String> {
+    fs::read_to_string(seen_file(base_path, source))
+        .map(|content| content.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn save_seen(base_path: &Path, source: &CalendarSource, seen: &[String]) -> anyhow::Result<()> {
+    let path = seen_file(base_path, source);
+    fs::create_dir_all(base_path.join(SEEN_DIR))?;
+    let start = seen.len().saturating_sub(MAX_SEEN_PER_SOURCE);
+    fs::write(path, seen[start..].join("\n"))?;
+    Ok(())
+}
+
+/// What one poll changed on disk.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct CalendarSync {
+    /// Rituals written or rescheduled, plus new reminder files; each needs a Rhythm sync.
+    pub(crate) scheduled: Vec<PathBuf>,
+    /// Rituals whose events are gone, cancelled, or past; their jobs must be dropped.
+    pub(crate) archived: Vec<PathBuf>,
+}
+
+/// Bring the source's rituals and reminders in line with `events` as of `now`. A rewritten
+/// ritual keeps its body, so injected steps and notes survive a reschedule, and a paused
+/// ritual stays paused.
+pub(crate) fn sync_source_events(
+    base_path: &Path,
+    source: &CalendarSource,
+    events: &[CalendarEvent],
+    now: DateTime<FixedOffset>,
+    lookahead_days: u64,
+) -> anyhow::Result<CalendarSync> {
+    let dir = source_dir(base_path, source);
+    fs::create_dir_all(&dir)?;
+    let mut existing = existing_rituals(&dir);
+    let mut seen = load_seen(base_path, source);
+    let mut sync = CalendarSync::default();
+    let window_end = now + TimeDelta::days(lookahead_days as i64);
+
+    for (uid, (event, start)) in upcoming(events, now - TimeDelta::hours(GRACE_HOURS), window_end) {
+        let path = existing.remove(&uid).unwrap_or_else(|| {
+            dir.join(format!(
+                "{}-{}.md",
+                crate::entities::slugify(&event.summary)
+                    .chars()
+                    .take(40)
+                    .collect::<String>(),
+                uid_hash(&uid)
+            ))
+        });
+        let current = fs::read_to_string(&path).ok();
+        let status = if current
+            .as_deref()
+            .is_some_and(crate::ritual_control::is_paused)
+        {
+            crate::ritual_control::PAUSED_STATUS
+        } else {
+            "active"
+        };
+        let header = ritual_header(source, &event, start, status)?;
+        let content = match &current {
+            Some(current) => {
+                let body_start = crate::thread::doc::body_start(current);
+                if current[..body_start] == header {
+                    None
+                } else {
+                    Some(format!("{}{}", header, &current[body_start..]))
+                }
+            }
+            None => Some(format!("{}{}", header, ritual_body(source, &event, start))),
+        };
+        if let Some(content) = content {
+            crate::journal::write(base_path, &path, &content)?;
+            sync.scheduled.push(path);
+        }
+
+        for lead in &source.remind_before {
+            let key = format!("{}@{}@{}", uid, start.to_rfc3339(), lead);
+            if seen.contains(&key) {
+                continue;
+            }
+            let due = match datetime::add_duration(start, &format!("-{}", lead.trim()), event.zone)
+            {
+                Ok(due) => due,
+                Err(e) => {
+                    log_warn!(
+                        "rhythm",
+                        "⚠️ Calendar `{}`: bad remind_before: {}",
+                        source.name,
+                        e
+                    );
+                    continue;
+                }
+            };
+            seen.push(key);
+            if due <= now {
+                continue;
+            }
+            let when = if event.all_day {
+                start.format("%Y-%m-%d").to_string()
+            } else {
+                start.format("%Y-%m-%d %H:%M").to_string()
+            };
+            let message = format!("📅 {} at {}", event.summary, when);
+            sync.scheduled.push(crate::reminders::create_reminder(
+                base_path,
+                &source.channel_id,
+                &message,
+                due,
+            )?);
+        }
+    }
+
+    // Whatever is left has no occurrence in the window; keep it in history, as reminders are.
+    if !existing.is_empty() {
+        let history = base_path
+            .join("rituals")
+            .join("history")
+            .join(Local::now().format("%Y-%m-%d").to_string());
+        fs::create_dir_all(&history)?;
+        for path in existing.into_values() {
+            fs::rename(&path, history.join(path.file_name().unwrap_or_default()))?;
+            sync.archived.push(path);
+        }
+    }
+    save_seen(base_path, source, &seen)?;
+    Ok(sync)
+}
+
+/// Multistatus body of a CalDAV `calendar-query` for events in `[start, end]`, with
+/// recurrences expanded by the server.
+fn caldav_query(start: DateTime<Utc>, end: DateTime<Utc>) -> String {
+    let start = start.format("%Y%m%dT%H%M%SZ");
+    let end = end.format("%Y%m%dT%H%M%SZ");
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop><C:calendar-data><C:expand start="{start}" end="{end}"/></C:calendar-data></D:prop>
+  <C:filter><C:comp-filter name="VCALENDAR"><C:comp-filter name="VEVENT">
+    <C:time-range start="{start}" end="{end}"/>
+  </C:comp-filter></C:comp-filter></C:filter>
+</C:calendar-query>"#
+    )
+}
+
+/// The iCalendar payloads inside a CalDAV multistatus response.
+pub(crate) fn caldav_calendar_data(xml: &str) -> anyhow::Result<Vec<String>> {
+    let document = roxmltree::Document::parse(xml)?;
+    Ok(document
+        .descendants()
+        .filter(|node| node.is_element() && node.tag_name().name() == "calendar-data")
+        .filter_map(|node| node.text())
+        .map(str::to_string)
+        .collect())
+}
+
+async fn fetch_events(
+    source: &CalendarSource,
+    zone: Zone,
+    lookahead_days: u64,
+) -> anyhow::Result<Vec<CalendarEvent>> {
+    let request = if source.caldav {
+        let now = Utc::now();
+        CALENDAR_CLIENT
+            .request(reqwest::Method::from_bytes(b"REPORT")?, &source.url)
+            .header("Depth", "1")
+            .header("Content-Type", "application/xml; charset=utf-8")
+            .body(caldav_query(
+                now - TimeDelta::hours(GRACE_HOURS),
+                now + TimeDelta::days(lookahead_days as i64),
+            ))
+    } else {
+        CALENDAR_CLIENT.get(&source.url)
+    };
+    let request = match &source.username {
+        Some(username) => request.basic_auth(username, source.password.as_deref()),
+        None => request,
+    };
+    let body = request.send().await?.error_for_status()?.text().await?;
+    if !source.caldav {
+        return Ok(parse_ics(&body, zone));
+    }
+    Ok(caldav_calendar_data(&body)?
+        .iter()
+        .flat_map(|ics| parse_ics(ics, zone))
+        .collect())
+}
+
+async fn poll_source(
+    base_path: &Path,
+    source: &CalendarSource,
+    config: &Config,
+) -> anyhow::Result<CalendarSync> {
+    let zone = Zone::parse(
+        source
+            .timezone
+            .as_deref()
+            .or(config.runtime.timezone.as_deref()),
+    )
+    .map_err(|e| anyhow::anyhow!(e))?;
+    let lookahead_days = config.calendars.lookahead_days;
+    let events = fetch_events(source, zone, lookahead_days).await?;
+    let (base, settings) = (base_path.to_path_buf(), source.clone());
+    let sync = tokio::task::spawn_blocking(move || {
+        sync_source_events(
+            &base,
+            &settings,
+            &events,
+            Local::now().fixed_offset(),
+            lookahead_days,
+        )
+    })
+    .await??;
+    for path in &sync.archived {
+        crate::rhythm::handle_file_removal(path).await?;
+    }
+    for path in &sync.scheduled {
+        crate::rhythm::sync_job_from_file(path).await?;
+    }
+    Ok(sync)
+}
+
+/// Poll every configured calendar on `calendars.poll_secs` until the process exits.
+pub async fn start_calendar_watcher(base_path: PathBuf, config: Arc<Config>) {
+    let settings = &config.calendars;
+    let interval_secs = settings.poll_secs.max(MIN_POLL_SECS);
+    log_info!(
+        "rhythm",
+        "📅 Watching {} calendar(s) every {}s",
+        settings.sources.len(),
+        interval_secs
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+    loop {
+        interval.tick().await;
+        for source in &settings.sources {
+            match poll_source(&base_path, source, &config).await {
+                Ok(sync) if sync == CalendarSync::default() => {}
+                Ok(sync) => log_info!(
+                    "rhythm",
+                    "📅 Calendar `{}`: {} ritual(s)/reminder(s) scheduled, {} archived",
+                    source.name,
+                    sync.scheduled.len(),
+                    sync.archived.len()
+                ),
+                Err(error) => log_warn!(
+                    "rhythm",
+                    "⚠️ Calendar `{}` poll failed: {:?}",
+                    source.name,
+                    error
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const ICS: &str = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\nUID:standup@example.com\r\nSUMMARY:Team stand\r\n up\r\nDTSTART;TZID=Europe/Paris:20260302T091500\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=6\r\nEXDATE;TZID=Europe/Paris:20260304T091500\r\nLOCATION:Room 4\\, east wing\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:dentist\r\nSUMMARY:Dentist\r\nDTSTART:20260303T140000Z\r\nDESCRIPTION:Bring the form\\nand the card\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:holiday\r\nSUMMARY:Holiday\r\nDTSTART;VALUE=DATE:20260305\r\nSTATUS:CANCELLED\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+
+    fn at(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_ics_and_expand_recurrences() {
+        let events = parse_ics(ICS, Zone::parse(Some("UTC")).unwrap());
+        assert_eq!(events.len(), 3);
+        let standup = &events[0];
+        assert_eq!(standup.summary, "Team standup");
+        assert_eq!(standup.location.as_deref(), Some("Room 4, east wing"));
+        assert_eq!(standup.start, at("2026-03-02T09:15:00+01:00"));
+        assert_eq!(
+            events[1].description.as_deref(),
+            Some("Bring the form\nand the card")
+        );
+        assert!(events[2].all_day && events[2].cancelled);
+
+        // The first Wednesday is excluded but still counts; COUNT=6 ends on 18 March.
+        assert_eq!(
+            next_occurrence(standup, at("2026-03-02T10:00:00+01:00")),
+            Some(at("2026-03-09T09:15:00+01:00"))
+        );
+        assert_eq!(
+            next_occurrence(standup, at("2026-03-12T00:00:00+01:00")),
+            Some(at("2026-03-16T09:15:00+01:00"))
+        );
+        assert_eq!(
+            next_occurrence(standup, at("2026-03-17T00:00:00+01:00")),
+            Some(at("2026-03-18T09:15:00+01:00"))
+        );
+        assert_eq!(
+            next_occurrence(standup, at("2026-03-19T00:00:00+01:00")),
+            None
+        );
+        // Paris leaves winter time on 29 March; the wall-clock time is kept.
+        let mut daily = standup.clone();
+        daily.rrule = Some("FREQ=DAILY".to_string());
+        assert_eq!(
+            next_occurrence(&daily, at("2026-03-30T00:00:00+02:00")),
+            Some(at("2026-03-30T09:15:00+02:00"))
+        );
+
+        let window = upcoming(
+            &events,
+            at("2026-03-03T00:00:00Z"),
+            at("2026-03-10T00:00:00Z"),
+        );
+        assert_eq!(
+            window.keys().collect::<Vec<_>>(),
+            ["dentist", "standup@example.com"]
+        );
+
+        let xml = r#"<D:multistatus xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav"><D:response><D:propstat><D:prop><C:calendar-data>BEGIN:VCALENDAR</C:calendar-data></D:prop></D:propstat></D:response></D:multistatus>"#;
+        assert_eq!(caldav_calendar_data(xml).unwrap(), ["BEGIN:VCALENDAR"]);
+    }
+
+    #[test]
+    fn test_sync_source_events_writes_rituals_and_reminders() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let source = CalendarSource {
+            name: "Family".to_string(),
+            channel_id: "999123456".to_string(),
+            remind_before: vec!["1h".to_string()],
+            ..Default::default()
+        };
+        let events = parse_ics(ICS, Zone::parse(Some("UTC")).unwrap());
+        let now = at("2026-03-03T12:00:00Z");
+
+        let sync = sync_source_events(base, &source, &events, now, 7).unwrap();
+        assert!(sync.archived.is_empty());
+        // Two rituals and one reminder each.
+        assert_eq!(sync.scheduled.len(), 4);
+        let rituals = existing_rituals(&base.join("rituals/calendar/family"));
+        let dentist = rituals.get("dentist").unwrap();
+        let content = fs::read_to_string(dentist).unwrap();
+        let (header, _) = crate::rhythm::parse_thread_metadata(&content).unwrap();
+        assert_eq!(header.schedule.as_deref(), Some("0 0 14 3 3 *"));
+        assert_eq!(header.source.as_deref(), Some("calendar"));
+        assert_eq!(header.origin_channel.as_deref(), Some("999123456"));
+        assert!(content.contains("Bring the form\nand the card"));
+        let reminders: Vec<String> = fs::read_dir(base.join("rituals"))
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with("reminder-"))
+            .collect();
+        assert!(
+            reminders
+                .contains(&"reminder-20260303-1300-dentist-at-2026-03-03-14-00.md".to_string())
+        );
+
+        // Unchanged events rewrite nothing; a paused ritual keeps its status and steps.
+        let paused = content.replace("status: active", "status: paused") + "\n- [ ] Leave early\n";
+        fs::write(dentist, &paused).unwrap();
+        let again = sync_source_events(base, &source, &events, now, 7).unwrap();
+        assert_eq!(again, CalendarSync::default());
+
+        let mut moved = events.clone();
+        moved[1].start = at("2026-03-04T15:00:00Z");
+        let sync = sync_source_events(base, &source, &moved, now, 7).unwrap();
+        assert_eq!(sync.scheduled.len(), 2);
+        let content = fs::read_to_string(dentist).unwrap();
+        assert!(content.contains("status: paused"));
+        let (header, _) = crate::rhythm::parse_thread_metadata(&content).unwrap();
+        assert_eq!(header.schedule.as_deref(), Some("0 0 15 4 3 *"));
+        assert!(content.ends_with("- [ ] Leave early\n"));
+
+        let sync = sync_source_events(base, &source, &moved[..1], now, 7).unwrap();
+        assert_eq!(&sync.archived, &[dentist.to_path_buf()]);
+        assert!(!dentist.exists());
+    }
+}
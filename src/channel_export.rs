@@ -0,0 +1,297 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/channel_export.rs
+ * Responsibility: `tellarctl export`: gather a channel's blackboards and history over a date range into one Markdown or HTML report.
+ */
+
+use crate::config::Config;
+use crate::llm;
+use anyhow::{Result, bail};
+use chrono::{DateTime, Local, NaiveDate};
+use std::fs;
+use std::path::Path;
+
+/// Files in a channel folder that are memory, not conversation.
+const SKIPPED_FILES: &[&str] = &["KNOWLEDGE.md", crate::channel_summary::SUMMARY_FILE];
+/// The summarizer sees at most this much of the export.
+const MAX_SUMMARY_INPUT_CHARS: usize = 200_000;
+
+const SUMMARIZER_PROMPT: &str = "You write the summary at the top of an exported chat channel report. From the conversation and task documents, list the decisions made, action items with their owners, open questions, and notable events, with dates. Be concise and factual; do not invent anything. Output Markdown only.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+}
+
+/// One blackboard or archived file in the export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportDocument {
+    pub date: NaiveDate,
+    /// Path relative to the channel folder.
+    pub path: String,
+    pub content: String,
+}
+
+fn date_prefix(name: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(name.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// The day a file belongs to: the date it is named for (daily logs and their archived
+/// parts), else the `history/<date>/` it was archived into, else when it was last written.
+fn document_date(path: &Path, folder_date: Option<NaiveDate>) -> Option<NaiveDate> {
+    let name = path.file_name()?.to_str()?;
+    date_prefix(name).or(folder_date).or_else(|| {
+        let modified = path.metadata().ok()?.modified().ok()?;
+        Some(DateTime::<Local>::from(modified).date_naive())
+    })
+}
+
+fn collect_dir(
+    dir: &Path,
+    relative: &str,
+    folder_date: Option<NaiveDate>,
+    from: NaiveDate,
+    to: NaiveDate,
+    documents: &mut Vec<ExportDocument>,
+) -> Result<()> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') {
+            continue;
+        }
+        let child = if relative.is_empty() {
+            name.clone()
+        } else {
+            format!("{}/{}", relative, name)
+        };
+        if path.is_dir() {
+            // Only `history/` and its day folders hold conversation.
+            let day = date_prefix(&name).filter(|_| name.len() == 10);
+            if relative.is_empty() && name == "history" {
+                collect_dir(&path, &child, None, from, to, documents)?;
+            } else if relative == "history" && day.is_some() {
+                collect_dir(&path, &child, day, from, to, documents)?;
+            }
+            continue;
+        }
+        if !name.ends_with(".md") || (relative.is_empty() && SKIPPED_FILES.contains(&name.as_str()))
+        {
+            continue;
+        }
+        let Some(date) = document_date(&path, folder_date) else {
+            continue;
+        };
+        if date < from || date > to {
+            continue;
+        }
+        documents.push(ExportDocument {
+            date,
+            path: child,
+            content: fs::read_to_string(&path)?,
+        });
+    }
+    Ok(())
+}
+
+/// Every blackboard and archived file of `channels/<folder>` dated within `from..=to`,
+/// oldest first. Months already packed into `history/YYYY-MM.tar.gz` are not read.
+pub fn collect(
+    base_path: &Path,
+    folder: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<ExportDocument>> {
+    let dir = base_path.join("channels").join(folder);
+    if !dir.is_dir() {
+        bail!("no channel folder at {}", dir.display());
+    }
+    if from > to {
+        bail!("--from {} is after --to {}", from, to);
+    }
+    let mut documents = Vec::new();
+    collect_dir(&dir, "", None, from, to, &mut documents)?;
+    documents.sort_by(|a, b| (a.date, &a.path).cmp(&(b.date, &b.path)));
+    Ok(documents)
+}
+
+fn title(folder: &str, from: NaiveDate, to: NaiveDate) -> String {
+    format!("#{}: {} to {}", folder, from, to)
+}
+
+pub fn render_markdown(
+    folder: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+    documents: &[ExportDocument],
+    summary: Option<&str>,
+) -> String {
+    let mut report = format!(
+        "# {}\n\n_Exported {} from channels/{}: {} file(s)._\n",
+        title(folder, from, to),
+        Local::now().format("%Y-%m-%d %H:%M"),
+        folder,
+        documents.len()
+    );
+    if let Some(summary) = summary {
+        report.push_str(&format!("\n## Summary\n\n{}\n", summary.trim()));
+    }
+    for document in documents {
+        report.push_str(&format!(
+            "\n## {} · {}\n\n{}\n",
+            document.date,
+            document.path,
+            document.content.trim()
+        ));
+    }
+    report
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A standalone page. Blackboards are shown as written, in preformatted blocks, so
+/// nothing in them is interpreted as markup.
+pub fn render_html(
+    folder: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+    documents: &[ExportDocument],
+    summary: Option<&str>,
+) -> String {
+    let title = escape_html(&title(folder, from, to));
+    let mut body = format!(
+        "<h1>{}</h1>\n<p><em>Exported {} from channels/{}: {} file(s).</em></p>\n",
+        title,
+        Local::now().format("%Y-%m-%d %H:%M"),
+        escape_html(folder),
+        documents.len()
+    );
+    if let Some(summary) = summary {
+        body.push_str(&format!(
+            "<h2>Summary</h2>\n<pre>{}</pre>\n",
+            escape_html(summary.trim())
+        ));
+    }
+    for document in documents {
+        body.push_str(&format!(
+            "<h2>{} · {}</h2>\n<pre>{}</pre>\n",
+            document.date,
+            escape_html(&document.path),
+            escape_html(document.content.trim())
+        ));
+    }
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>body {{ font-family: sans-serif; max-width: 60rem; margin: 2rem auto; }} pre {{ white-space: pre-wrap; background: #f6f6f6; padding: 1rem; }}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        title, body
+    )
+}
+
+pub fn render(
+    format: ExportFormat,
+    folder: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+    documents: &[ExportDocument],
+    summary: Option<&str>,
+) -> String {
+    match format {
+        ExportFormat::Markdown => render_markdown(folder, from, to, documents, summary),
+        ExportFormat::Html => render_html(folder, from, to, documents, summary),
+    }
+}
+
+/// Ask the model for the report's summary section.
+pub async fn summarize(config: &Config, documents: &[ExportDocument]) -> Result<String> {
+    let mut transcript = String::new();
+    for document in documents {
+        transcript.push_str(&format!(
+            "### {} ({})\n{}\n\n",
+            document.path, document.date, document.content
+        ));
+    }
+    let transcript: String = transcript.chars().take(MAX_SUMMARY_INPUT_CHARS).collect();
+    let turn = llm::generate_turn(
+        SUMMARIZER_PROMPT,
+        vec![llm::Message {
+            role: llm::MessageRole::User,
+            parts: vec![llm::MultimodalPart::text(transcript)],
+        }],
+        &config.gemini.api_key,
+        &config.gemini.model,
+        0.2,
+        None,
+    )
+    .await?;
+    match turn {
+        llm::ModelTurn::Narrative(text) if !text.trim().is_empty() => Ok(text),
+        _ => bail!("the model returned no summary"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_export_collects_logs_and_history_in_range() {
+        let dir = tempdir().unwrap();
+        let channel = dir.path().join("channels/general-123456");
+        fs::create_dir_all(channel.join("history/2026-03-02")).unwrap();
+        fs::write(channel.join("2026-03-01.md"), "first <day>").unwrap();
+        fs::write(channel.join("2026-03-05.md"), "out of range").unwrap();
+        fs::write(channel.join("KNOWLEDGE.md"), "memory").unwrap();
+        fs::write(
+            channel.join("history/2026-03-02/plan.md"),
+            "archived thread",
+        )
+        .unwrap();
+        fs::write(
+            channel.join("history/2026-03-02/2026-02-28.part1.md"),
+            "older part",
+        )
+        .unwrap();
+
+        let day = |d: &str| NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap();
+        let documents = collect(
+            dir.path(),
+            "general-123456",
+            day("2026-03-01"),
+            day("2026-03-03"),
+        )
+        .unwrap();
+        let paths: Vec<&str> = documents.iter().map(|doc| doc.path.as_str()).collect();
+        assert_eq!(paths, vec!["2026-03-01.md", "history/2026-03-02/plan.md"]);
+
+        let markdown = render_markdown(
+            "general-123456",
+            day("2026-03-01"),
+            day("2026-03-03"),
+            &documents,
+            Some("- Shipped"),
+        );
+        assert!(markdown.starts_with("# #general-123456: 2026-03-01 to 2026-03-03\n"));
+        assert!(markdown.contains("\n## Summary\n\n- Shipped\n"));
+        assert!(
+            markdown.contains("\n## 2026-03-02 · history/2026-03-02/plan.md\n\narchived thread\n")
+        );
+        let html = render_html(
+            "general-123456",
+            day("2026-03-01"),
+            day("2026-03-03"),
+            &documents,
+            None,
+        );
+        assert!(html.contains("<pre>first &lt;day&gt;</pre>"));
+        assert!(collect(dir.path(), "missing", day("2026-03-01"), day("2026-03-03")).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/channel_folders.rs
+ * Responsibility: `tellarctl channel`: list, bind, rename, and prune channel folders and their `discord.channel_mappings`.
+ */
+
+use crate::discord::extract_id_from_folder;
+use anyhow::{Result, bail};
+use serde_yml::{Mapping, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Folders under channels/, sorted. Hidden folders are left out.
+pub fn list_folders(base_path: &Path) -> Vec<String> {
+    let mut folders: Vec<String> = fs::read_dir(base_path.join("channels"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .filter(|name| !name.starts_with('.'))
+                .collect()
+        })
+        .unwrap_or_default();
+    folders.sort();
+    folders
+}
+
+pub fn validate_folder_name(name: &str) -> Result<()> {
+    if name.trim().is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        bail!("`{}` is not a valid channel folder name", name);
+    }
+    Ok(())
+}
+
+/// The name a folder gets when renamed to `requested`. Discovered folders are found by the
+/// `-<id suffix>` at the end of their name, so the suffix is kept when `requested` drops it.
+pub fn rename_target(old: &str, requested: &str) -> String {
+    match extract_id_from_folder(old) {
+        Some(suffix) if extract_id_from_folder(requested).as_deref() != Some(suffix.as_str()) => {
+            format!("{}-{}", requested, suffix)
+        }
+        _ => requested.to_string(),
+    }
+}
+
+/// A mapping key as a channel ID; YAML reads unquoted IDs as numbers.
+fn channel_id(key: &Value) -> Option<String> {
+    match key {
+        Value::Number(id) => Some(id.to_string()),
+        id => id.as_str().map(str::to_string),
+    }
+}
+
+/// `discord.channel_mappings` of a raw tellar.yml, as channel ID -> folder.
+pub fn mappings(config: &Value) -> BTreeMap<String, String> {
+    config
+        .get("discord")
+        .and_then(|discord| discord.get("channel_mappings"))
+        .and_then(Value::as_mapping)
+        .map(|mappings| {
+            mappings
+                .iter()
+                .filter_map(|(id, folder)| Some((channel_id(id)?, folder.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn mappings_mut(config: &mut Value) -> Result<&mut Mapping> {
+    let Some(root) = config.as_mapping_mut() else {
+        bail!("tellar.yml is not a YAML mapping");
+    };
+    let discord = root
+        .entry(Value::from("discord"))
+        .or_insert_with(|| Value::Mapping(Mapping::new()));
+    let Some(discord) = discord.as_mapping_mut() else {
+        bail!("`discord` in tellar.yml is not a mapping");
+    };
+    let mappings = discord
+        .entry(Value::from("channel_mappings"))
+        .or_insert_with(|| Value::Mapping(Mapping::new()));
+    if mappings.is_null() {
+        *mappings = Value::Mapping(Mapping::new());
+    }
+    match mappings.as_mapping_mut() {
+        Some(mappings) => Ok(mappings),
+        None => bail!("`discord.channel_mappings` in tellar.yml is not a mapping"),
+    }
+}
+
+/// Map `id_to_bind` to `folder`, replacing any earlier binding of that channel.
+pub fn bind(config: &mut Value, id_to_bind: &str, folder: &str) -> Result<()> {
+    let mappings = mappings_mut(config)?;
+    mappings.retain(|id, _| channel_id(id).as_deref() != Some(id_to_bind));
+    mappings.insert(Value::from(id_to_bind), Value::from(folder));
+    Ok(())
+}
+
+/// Point every mapping at `old` to `new`; returns how many changed.
+pub fn rename_mappings(config: &mut Value, old: &str, new: &str) -> Result<usize> {
+    let mut changed = 0;
+    for (_, folder) in mappings_mut(config)?.iter_mut() {
+        if folder.as_str() == Some(old) {
+            *folder = Value::from(new);
+            changed += 1;
+        }
+    }
+    Ok(changed)
+}
+
+/// Drop the mappings of the given channels; returns how many were removed.
+pub fn unbind(config: &mut Value, channel_ids: &HashSet<String>) -> Result<usize> {
+    let mappings = mappings_mut(config)?;
+    let before = mappings.len();
+    mappings.retain(|id, _| channel_id(id).is_none_or(|id| !channel_ids.contains(&id)));
+    Ok(before - mappings.len())
+}
+
+/// Folders whose channel no longer exists on Discord: bound in `mappings` to a channel
+/// that is gone, or unbound and named for an ID suffix no live channel has. Folders
+/// without an ID suffix and no binding are not Discord's and are left alone.
+pub fn stale_folders(
+    folders: &[String],
+    live_ids: &HashSet<String>,
+    mappings: &BTreeMap<String, String>,
+) -> Vec<String> {
+    let live_suffixes: HashSet<&str> = live_ids
+        .iter()
+        .map(|id| &id[id.len().saturating_sub(6)..])
+        .collect();
+    folders
+        .iter()
+        .filter(|folder| {
+            let bound: Vec<&String> = mappings
+                .iter()
+                .filter(|(_, mapped)| mapped == folder)
+                .map(|(id, _)| id)
+                .collect();
+            if !bound.is_empty() {
+                return bound.iter().all(|id| !live_ids.contains(*id));
+            }
+            extract_id_from_folder(folder)
+                .is_some_and(|suffix| !live_suffixes.contains(suffix.as_str()))
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_folders_bind_rename_and_prune() {
+        assert_eq!(rename_target("general-123456", "lobby"), "lobby-123456");
+        assert_eq!(
+            rename_target("general-123456", "lobby-123456"),
+            "lobby-123456"
+        );
+        assert_eq!(rename_target("notes", "journal"), "journal");
+
+        let mut config: Value =
+            serde_yml::from_str("discord:\n  token: t\n  channel_mappings:\n    111: ops\n")
+                .unwrap();
+        bind(&mut config, "111", "operations").unwrap();
+        bind(&mut config, "222", "alerts").unwrap();
+        assert_eq!(rename_mappings(&mut config, "alerts", "alarms").unwrap(), 1);
+        let bound = mappings(&config);
+        assert_eq!(bound.len(), 2);
+        assert_eq!(bound["111"], "operations");
+        assert_eq!(bound["222"], "alarms");
+
+        let folders: Vec<String> = [
+            "alarms",
+            "general-123456",
+            "gone-654321",
+            "notes",
+            "operations",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+        let live: HashSet<String> = ["111", "900000123456"]
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        assert_eq!(
+            stale_folders(&folders, &live, &bound),
+            vec!["alarms", "gone-654321"]
+        );
+        let gone: HashSet<String> = ["222".to_string()].into_iter().collect();
+        assert_eq!(unbind(&mut config, &gone).unwrap(), 1);
+        assert_eq!(mappings(&config).len(), 1);
+
+        let mut empty: Value = serde_yml::from_str("gemini:\n  model: m\n").unwrap();
+        bind(&mut empty, "333", "x").unwrap();
+        assert_eq!(mappings(&empty)["333"], "x");
+        assert!(validate_folder_name("../etc").is_err());
+    }
+}
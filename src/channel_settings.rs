@@ -0,0 +1,169 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/channel_settings.rs
+ * Responsibility: Per-channel `channel.yml` overrides of model, temperature, tools, language, and style.
+ */
+
+use crate::config::{Config, ToolRules};
+use crate::log_warn;
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::Arc;
+
+pub const CHANNEL_CONFIG_FILE: &str = "channel.yml";
+
+/// What a channel owner can tune in `channels/<folder>/channel.yml`. Unset fields keep
+/// the guild's settings.
+#[derive(Debug, Deserialize, Default, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChannelSettings {
+    pub model: Option<String>,
+    pub temperature: Option<f32>,
+    /// Replaces this channel's rules under `runtime.tools.channels`.
+    pub tools: Option<ToolRules>,
+    /// Language the steward replies in, e.g. `German`.
+    pub language: Option<String>,
+    /// How replies should read, e.g. `terse bullet points, no greetings`.
+    pub style: Option<String>,
+}
+
+impl ChannelSettings {
+    /// The channel's `channel.yml`, or defaults when it is missing or invalid.
+    pub fn load(base_path: &Path, channel_id: &str) -> Self {
+        let Some(folder) = crate::discord::resolve_folder_by_id(base_path, channel_id) else {
+            return Self::default();
+        };
+        let path = base_path
+            .join("channels")
+            .join(folder)
+            .join(CHANNEL_CONFIG_FILE);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        match serde_yml::from_str::<Option<Self>>(&content) {
+            Ok(settings) => settings.unwrap_or_default(),
+            Err(e) => {
+                log_warn!("steward", "⚠️ Ignoring invalid {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// `config` with this channel's model, temperature, and tool rules applied.
+    pub fn apply(&self, config: Arc<Config>, channel_id: &str) -> Arc<Config> {
+        if self.model.is_none() && self.temperature.is_none() && self.tools.is_none() {
+            return config;
+        }
+        let mut merged = (*config).clone();
+        if let Some(model) = &self.model {
+            merged.gemini.model = model.clone();
+        }
+        if self.temperature.is_some() {
+            merged.gemini.temperature = self.temperature;
+        }
+        if let Some(tools) = &self.tools {
+            merged
+                .runtime
+                .tools
+                .channels
+                .insert(channel_id.to_string(), tools.clone());
+        }
+        Arc::new(merged)
+    }
+
+    /// System prompt lines for the language and style, empty when neither is set.
+    pub fn prompt_section(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(language) = &self.language {
+            lines.push(format!("- Always reply in {}.", language));
+        }
+        if let Some(style) = &self.style {
+            lines.push(format!(
+                "- Response style: {}.",
+                style.trim_end_matches('.')
+            ));
+        }
+        if lines.is_empty() {
+            return String::new();
+        }
+        format!("\n\n### Channel Preferences\n{}\n", lines.join("\n"))
+    }
+}
+
+/// `config` with the channel's `runtime.channel_limits` turn and read-only limits in place
+/// of the guild-wide ones.
+fn apply_limits(config: Arc<Config>, channel_id: &str) -> Arc<Config> {
+    let Some(limits) = config.runtime.channel_limits.get(channel_id) else {
+        return config;
+    };
+    if limits.max_turns.is_none() && limits.read_only_budget.is_none() {
+        return config;
+    }
+    let mut merged = (*config).clone();
+    merged.runtime.max_turns = limits.max_turns.unwrap_or(config.runtime.max_turns);
+    merged.runtime.read_only_budget = limits
+        .read_only_budget
+        .unwrap_or(config.runtime.read_only_budget);
+    Arc::new(merged)
+}
+
+/// The channel's merged config and the prompt section for its preferences.
+pub fn for_channel(
+    base_path: &Path,
+    config: Arc<Config>,
+    channel_id: &str,
+) -> (Arc<Config>, String) {
+    let settings = ChannelSettings::load(base_path, channel_id);
+    (
+        settings.apply(apply_limits(config, channel_id), channel_id),
+        settings.prompt_section(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_channel_yml_overrides_the_guild_config() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let folder = base.join("channels/support-654321");
+        std::fs::create_dir_all(&folder).unwrap();
+        let config = Arc::new(Config::default());
+        assert!(ChannelSettings::load(base, "987654321").model.is_none());
+
+        std::fs::write(
+            folder.join(CHANNEL_CONFIG_FILE),
+            "model: gemini-2.5-pro\ntemperature: 0.1\ntools:\n  deny: [exec]\nlanguage: German\nstyle: terse bullet points.\n",
+        )
+        .unwrap();
+        let (merged, section) = for_channel(base, Arc::clone(&config), "987654321");
+        assert_eq!(merged.gemini.model, "gemini-2.5-pro");
+        assert_eq!(merged.gemini.temperature, Some(0.1));
+        assert!(crate::tools::tool_policy_violation(&merged, "exec", "987654321").is_some());
+        assert!(crate::tools::tool_policy_violation(&merged, "exec", "1").is_none());
+        assert_eq!(
+            section,
+            "\n\n### Channel Preferences\n- Always reply in German.\n- Response style: terse bullet points.\n"
+        );
+
+        std::fs::write(folder.join(CHANNEL_CONFIG_FILE), "modle: typo\n").unwrap();
+        let (merged, section) = for_channel(base, Arc::clone(&config), "987654321");
+        assert!(Arc::ptr_eq(&merged, &config));
+        assert!(section.is_empty());
+
+        let mut limited = Config::default();
+        limited.runtime.channel_limits.insert(
+            "987654321".to_string(),
+            crate::config::ChannelLimits {
+                max_turns: Some(4),
+                ..Default::default()
+            },
+        );
+        let (merged, _) = for_channel(base, Arc::new(limited), "987654321");
+        assert_eq!(merged.runtime.max_turns, 4);
+        assert_eq!(merged.runtime.read_only_budget, 4);
+    }
+}
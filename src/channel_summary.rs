@@ -0,0 +1,214 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/channel_summary.rs
+ * Responsibility: Roll older sections of oversized daily logs into a channel SUMMARY.md.
+ */
+
+use crate::config::Config;
+use crate::journal;
+use crate::llm;
+use anyhow::{Result, bail};
+use chrono::Local;
+use std::fs;
+use std::path::Path;
+
+pub const SUMMARY_FILE: &str = "SUMMARY.md";
+const ENTRY_SEPARATOR: &str = "\n---\n";
+
+const SUMMARIZER_PROMPT: &str = "You maintain the rolling memory of one chat channel. Merge the existing summary with the older conversation into one updated summary in Markdown. Keep decisions, commitments, open questions, names, dates, and facts people will refer back to; drop greetings and chatter. Write at most about 400 words. Output the summary only.";
+
+/// Split a daily log into the part to summarize and the `keep_recent` newest entries.
+/// Returns `None` when there are not more entries than that.
+pub fn split_older_sections(content: &str, keep_recent: usize) -> Option<(&str, &str)> {
+    let starts: Vec<usize> = content
+        .match_indices(ENTRY_SEPARATOR)
+        .map(|(index, _)| index)
+        .collect();
+    if starts.len() <= keep_recent {
+        return None;
+    }
+    let cut = starts[starts.len() - keep_recent.max(1)];
+    (cut > 0).then(|| content.split_at(cut))
+}
+
+fn summary_request(previous: &str, older: &str) -> String {
+    format!(
+        "### Existing Summary\n{}\n\n### Older Conversation\n{}",
+        if previous.trim().is_empty() {
+            "(none yet)"
+        } else {
+            previous.trim()
+        },
+        older.trim()
+    )
+}
+
+/// Summarize the older sections of `log_path` into its folder's `SUMMARY.md` when the
+/// log is longer than `summary.max_log_chars`. The summarized sections are moved to
+/// `history/<today>/` so the log keeps only recent entries. Returns whether it ran.
+pub async fn summarize_log_if_needed(
+    base_path: &Path,
+    log_path: &Path,
+    config: &Config,
+) -> Result<bool> {
+    let settings = &config.summary;
+    let content = tokio::fs::read_to_string(log_path).await?;
+    if content.chars().count() <= settings.max_log_chars {
+        return Ok(false);
+    }
+    let Some((older, _)) = split_older_sections(&content, settings.keep_recent) else {
+        return Ok(false);
+    };
+    let Some(folder) = log_path.parent() else {
+        bail!("log {} has no channel folder", log_path.display());
+    };
+    let summary_path = folder.join(SUMMARY_FILE);
+    let previous = fs::read_to_string(&summary_path).unwrap_or_default();
+
+    let turn = llm::generate_turn(
+        SUMMARIZER_PROMPT,
+        vec![llm::Message {
+            role: llm::MessageRole::User,
+            parts: vec![llm::MultimodalPart::text(summary_request(&previous, older))],
+        }],
+        &config.gemini.api_key,
+        &config.gemini.model,
+        0.2,
+        None,
+    )
+    .await?;
+    let summary = match turn {
+        llm::ModelTurn::Narrative(text) if !text.trim().is_empty() => text,
+        _ => bail!("summarizer returned no summary"),
+    };
+
+    // New messages may have been appended while the model was working.
+    let current = tokio::fs::read_to_string(log_path).await?;
+    let Some(recent) = current.strip_prefix(older) else {
+        bail!("{} changed underneath the summarizer", log_path.display());
+    };
+
+    let now = Local::now();
+    let today = now.format("%Y-%m-%d").to_string();
+    let history_dir = folder.join("history").join(&today);
+    fs::create_dir_all(&history_dir)?;
+    let file_name = log_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let archive_path = history_dir.join(&file_name);
+    let mut archived = fs::read_to_string(&archive_path).unwrap_or_default();
+    archived.push_str(older);
+    journal::write_async(base_path, &archive_path, &archived).await?;
+
+    journal::write_async(
+        base_path,
+        &summary_path,
+        &format!(
+            "# Channel Summary\n\n_Updated {}_\n\n{}\n",
+            now.format("%Y-%m-%d %H:%M"),
+            summary.trim()
+        ),
+    )
+    .await?;
+    journal::write_async(
+        base_path,
+        log_path,
+        &format!(
+            "> [Tellar] ({}): Earlier messages were summarized into {} and moved to history/{}/{}.\n{}",
+            now.format("%Y-%m-%d %H:%M:%S"),
+            SUMMARY_FILE,
+            today,
+            file_name,
+            recent
+        ),
+    )
+    .await?;
+    Ok(true)
+}
+
+/// The channel's rolling summary as a system-prompt section, or an empty string.
+pub fn summary_section(base_path: &Path, channel_id: &str) -> String {
+    if channel_id == "0" {
+        return String::new();
+    }
+    let Some(folder) = crate::discord::resolve_folder_by_id(base_path, channel_id) else {
+        return String::new();
+    };
+    match fs::read_to_string(base_path.join("channels").join(&folder).join(SUMMARY_FILE)) {
+        Ok(summary) if !summary.trim().is_empty() => format!(
+            "\n\n### Conversation So Far (channels/{}/{}):\n{}",
+            folder,
+            SUMMARY_FILE,
+            summary.trim()
+        ),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn entry(id: usize) -> String {
+        format!(
+            "\n---\n**Author**: Ada (ID: 1) | **Time**: t{0} | **Message ID**: m{0}\n\nmessage {0}\n",
+            id
+        )
+    }
+
+    #[test]
+    fn test_split_older_sections_keeps_recent_entries() {
+        let log: String = (1..=5).map(entry).collect();
+        let (older, recent) = split_older_sections(&log, 2).unwrap();
+        assert!(older.contains("message 3") && !older.contains("message 4"));
+        assert!(recent.starts_with("\n---\n") && recent.contains("message 4"));
+        assert!(split_older_sections(&log, 5).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_summarize_log_moves_older_entries_and_writes_summary() {
+        let dir = tempdir().unwrap();
+        let folder = dir.path().join("channels/general-123456");
+        fs::create_dir_all(&folder).unwrap();
+        let log_path = folder.join("2026-03-01.md");
+        let log: String = (1..=6).map(entry).collect();
+        fs::write(&log_path, &log).unwrap();
+
+        let mut config = Config::default();
+        config.gemini.model = crate::mock_llm::MOCK_MODEL.to_string();
+        config.summary.max_log_chars = 100;
+        config.summary.keep_recent = 2;
+
+        assert!(
+            summarize_log_if_needed(dir.path(), &log_path, &config)
+                .await
+                .unwrap()
+        );
+        let rewritten = fs::read_to_string(&log_path).unwrap();
+        assert!(rewritten.starts_with("> [Tellar]"));
+        assert!(!rewritten.contains("message 4") && rewritten.contains("message 5"));
+        assert!(
+            crate::input::collect_pending_workset(&rewritten, Some("m6"))
+                .text()
+                .contains("message 6")
+        );
+
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let archived = fs::read_to_string(folder.join("history").join(today).join("2026-03-01.md"))
+            .unwrap();
+        assert!(archived.contains("message 1") && archived.contains("message 4"));
+
+        let section = summary_section(dir.path(), "999123456");
+        assert!(section.contains("channels/general-123456/SUMMARY.md"));
+        assert!(section.contains("[mock]"));
+
+        config.summary.max_log_chars = 100_000;
+        assert!(
+            !summarize_log_if_needed(dir.path(), &log_path, &config)
+                .await
+                .unwrap()
+        );
+    }
+}
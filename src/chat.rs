@@ -0,0 +1,169 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/chat.rs
+ * Responsibility: Local terminal chat (`tellar chat`) that drives the conversational loop without Discord.
+ */
+
+use crate::config::Config;
+use crate::discord::ingest_store::append_to_message_log;
+use crate::session::run_conversational_loop;
+use crate::{log_error, log_info, log_warn};
+use chrono::Local;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+/// Channel folder holding the local chat blackboards (`channels/cli/YYYY-MM-DD.md`).
+pub const CHAT_CHANNEL_FOLDER: &str = "cli";
+/// Chat mode has no Discord channel; "0" selects the base identity only.
+const CHAT_CHANNEL_ID: &str = "0";
+
+fn chat_thread_id() -> String {
+    format!(
+        "{}/{}.md",
+        CHAT_CHANNEL_FOLDER,
+        Local::now().format("%Y-%m-%d")
+    )
+}
+
+async fn append_chat_entry(
+    base_path: &Path,
+    thread_id: &str,
+    author: &str,
+    author_id: &str,
+    message_id: &str,
+    content: &str,
+) -> anyhow::Result<()> {
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    append_to_message_log(
+        base_path,
+        thread_id,
+        author,
+        author_id,
+        content,
+        message_id,
+        &timestamp,
+        None,
+        Vec::new(),
+    )
+    .await
+}
+
+/// Inscribe one user message into today's chat blackboard, run the conversational
+/// loop on it, inscribe the reply, and return it.
+pub async fn chat_turn(
+    base_path: &Path,
+    config: Arc<Config>,
+    message: &str,
+) -> anyhow::Result<String> {
+    let thread_id = chat_thread_id();
+    let blackboard: PathBuf = base_path.join("channels").join(&thread_id);
+    let message_id = format!("cli-{}", uuid::Uuid::new_v4());
+    append_chat_entry(base_path, &thread_id, "You", "cli", &message_id, message).await?;
+
+    let content = tokio::fs::read_to_string(&blackboard).await?;
+    let outcome = run_conversational_loop(
+        &content,
+        &blackboard,
+        base_path,
+        Arc::clone(&config),
+        Some(message_id),
+        CHAT_CHANNEL_ID,
+    )
+    .await?;
+    log_warn!("steward", "🗣️ {}", outcome.log_summary());
+
+    let reply = crate::tools::mask_sensitive_data(&outcome.user_response, &config);
+    append_chat_entry(
+        base_path,
+        &thread_id,
+        "Tellar",
+        "local",
+        &format!("cli-{}", uuid::Uuid::new_v4()),
+        &reply,
+    )
+    .await?;
+    Ok(reply)
+}
+
+/// Read messages from stdin until EOF or `/exit`, answering each through `chat_turn`.
+pub async fn run_chat_repl(base_path: &Path, config: Arc<Config>) -> anyhow::Result<()> {
+    log_info!(
+        "steward",
+        "💬 Local chat with the Steward. Blackboard: channels/{}/. Type /exit to leave.",
+        CHAT_CHANNEL_FOLDER
+    );
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+    loop {
+        stdout.write_all(b"\nyou> ").await?;
+        stdout.flush().await?;
+
+        let Some(line) = lines.next_line().await? else {
+            break;
+        };
+        let message = line.trim();
+        if message.is_empty() {
+            continue;
+        }
+        if matches!(message, "/exit" | "/quit") {
+            break;
+        }
+
+        match chat_turn(base_path, Arc::clone(&config), message).await {
+            Ok(reply) => log_info!("steward", "\ntellar> {}", reply),
+            Err(e) => log_error!("steward", "❌ Steward loop failed: {:?}", e),
+        }
+    }
+
+    log_info!("steward", "👋 Chat session closed.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::collect_pending_workset;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_chat_entries_form_a_conversation_the_loop_can_window() {
+        let dir = tempdir().unwrap();
+        let thread_id = "cli/2026-03-04.md";
+        append_chat_entry(
+            dir.path(),
+            thread_id,
+            "You",
+            "cli",
+            "cli-1",
+            "first question",
+        )
+        .await
+        .unwrap();
+        append_chat_entry(
+            dir.path(),
+            thread_id,
+            "Tellar",
+            "local",
+            "cli-2",
+            "first answer",
+        )
+        .await
+        .unwrap();
+        append_chat_entry(
+            dir.path(),
+            thread_id,
+            "You",
+            "cli",
+            "cli-3",
+            "second question",
+        )
+        .await
+        .unwrap();
+
+        let content = std::fs::read_to_string(dir.path().join("channels").join(thread_id)).unwrap();
+        let workset = collect_pending_workset(&content, Some("cli-3"));
+        assert_eq!(workset.text(), "second question");
+    }
+}
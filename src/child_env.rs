@@ -0,0 +1,150 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/child_env.rs
+ * Responsibility: Resolve `runtime.env` into the exact environment given to `exec` commands and skills.
+ */
+
+use crate::config::{Config, EnvRules};
+use crate::log_warn;
+use crate::secrets::SecretStore;
+use std::path::Path;
+use tokio::process::Command;
+
+/// The environment for one child process.
+#[derive(Debug, Default)]
+pub(crate) struct ChildEnv {
+    pub vars: Vec<(String, String)>,
+    /// Names granted by the rules rather than `base`; sandboxes that do not inherit the
+    /// environment (docker) forward these explicitly.
+    pub granted: Vec<String>,
+}
+
+impl ChildEnv {
+    /// Replace the inherited environment of `command` with this one.
+    pub fn apply(&self, command: &mut Command) {
+        command.env_clear();
+        command.envs(self.vars.iter().map(|(name, value)| (name, value)));
+    }
+}
+
+/// Secrets Tellar can expose by name: its own keys, then the guild secrets store.
+fn secret_value(config: &Config, store: &SecretStore, name: &str) -> Option<String> {
+    match name {
+        "GEMINI_API_KEY" => Some(config.gemini.api_key.clone()),
+        _ => store.get(name).map(str::to_string),
+    }
+}
+
+fn load_store(base_path: &Path, rules: Option<&EnvRules>, declared: &[String]) -> SecretStore {
+    // Only touch the store (and its key) when someone actually asks for secrets.
+    if declared.is_empty() && rules.is_none_or(|rules| rules.secrets.is_empty()) {
+        return SecretStore::default();
+    }
+    SecretStore::load(base_path).unwrap_or_else(|e| {
+        log_warn!(
+            "steward",
+            "⚠️ runtime.env: secrets store unavailable: {}",
+            e
+        );
+        SecretStore::default()
+    })
+}
+
+fn push(env: &mut ChildEnv, name: &str, value: String, granted: bool) {
+    env.vars.retain(|(existing, _)| existing != name);
+    env.vars.push((name.to_string(), value));
+    if granted && !env.granted.iter().any(|existing| existing == name) {
+        env.granted.push(name.to_string());
+    }
+}
+
+fn resolve(config: &Config, store: &SecretStore, rules: Option<&EnvRules>) -> ChildEnv {
+    let mut env = ChildEnv::default();
+    for name in &config.runtime.env.base {
+        if let Ok(value) = std::env::var(name) {
+            push(&mut env, name, value, false);
+        }
+    }
+    let Some(rules) = rules else {
+        return env;
+    };
+    for name in &rules.pass {
+        if let Ok(value) = std::env::var(name) {
+            push(&mut env, name, value, true);
+        }
+    }
+    for name in &rules.secrets {
+        match secret_value(config, store, name) {
+            Some(value) => push(&mut env, name, value, true),
+            None => log_warn!(
+                "steward",
+                "⚠️ runtime.env: unknown secret `{}` was not exposed",
+                name
+            ),
+        }
+    }
+    let mut literals: Vec<_> = rules.set.iter().collect();
+    literals.sort();
+    for (name, value) in literals {
+        push(&mut env, name, value.clone(), true);
+    }
+    env
+}
+
+/// Environment for `exec` and background jobs.
+pub(crate) fn exec_env(config: &Config, base_path: &Path) -> ChildEnv {
+    let rules = Some(&config.runtime.env.exec);
+    resolve(config, &load_store(base_path, rules, &[]), rules)
+}
+
+/// Environment for the tools of one skill. `declared` are the stored secrets the skill
+/// names in its own `secrets` list; missing ones are left for the caller to report.
+pub(crate) fn skill_env(
+    config: &Config,
+    base_path: &Path,
+    skill_name: &str,
+    declared: &[String],
+) -> ChildEnv {
+    let rules = config.runtime.env.skills.get(skill_name);
+    let store = load_store(base_path, rules, declared);
+    let mut env = resolve(config, &store, rules);
+    for name in declared {
+        if let Some(value) = store.get(name) {
+            push(&mut env, name, value.to_string(), true);
+        }
+    }
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_child_env_exposes_only_base_and_listed_variables() {
+        let mut config = Config::default();
+        config.gemini.api_key = "secret-key".to_string();
+        config.runtime.env.base = vec!["PATH".to_string()];
+        config.runtime.env.skills.insert(
+            "draw".to_string(),
+            EnvRules {
+                pass: vec!["TELLAR_TEST_MISSING_VAR".to_string()],
+                secrets: vec!["GEMINI_API_KEY".to_string(), "NOPE".to_string()],
+                set: [("MODE".to_string(), "fast".to_string())].into(),
+            },
+        );
+
+        let names = |env: &ChildEnv| -> Vec<String> {
+            env.vars.iter().map(|(name, _)| name.clone()).collect()
+        };
+        let guild = Path::new("/nonexistent-guild");
+        let plain = skill_env(&config, guild, "other", &[]);
+        assert_eq!(names(&plain), vec!["PATH"]);
+        assert!(exec_env(&config, guild).granted.is_empty());
+
+        let draw = skill_env(&config, guild, "draw", &[]);
+        assert_eq!(names(&draw), vec!["PATH", "GEMINI_API_KEY", "MODE"]);
+        assert_eq!(draw.granted, vec!["GEMINI_API_KEY", "MODE"]);
+        assert_eq!(draw.vars[1].1, "secret-key");
+    }
+}
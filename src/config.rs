@@ -6,34 +6,129 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
-
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Config {
     pub gemini: GeminiConfig,
     pub discord: DiscordConfig,
     #[serde(default)]
     pub runtime: RuntimeConfig,
+    #[serde(default)]
+    pub voice: VoiceConfig,
+    #[serde(default)]
+    pub context_cache: ContextCacheConfig,
+    #[serde(default)]
+    pub knowledge_index: KnowledgeIndexConfig,
+    #[serde(default)]
+    pub summary: SummaryConfig,
+    #[serde(default)]
+    pub history_retention: HistoryRetentionConfig,
+    #[serde(default)]
+    pub hygiene: HygieneConfig,
+    #[serde(default)]
+    pub digest: DigestConfig,
+    #[serde(default)]
+    pub quiet_hours: QuietHoursConfig,
+    #[serde(default)]
+    pub guardian: GuardianConfig,
+    #[serde(default)]
+    pub email: EmailConfig,
+    #[serde(default)]
+    pub inbound_webhook: InboundWebhookConfig,
+    #[serde(default)]
+    pub outbound_webhooks: Vec<OutboundWebhook>,
+    #[serde(default)]
+    pub ntfy: NtfyConfig,
+    #[serde(default)]
+    pub bridges: Vec<BridgeConfig>,
+    #[serde(default)]
+    pub feeds: FeedsConfig,
+    #[serde(default)]
+    pub calendars: CalendarsConfig,
+    #[serde(default)]
+    pub git: GitConfig,
+    #[serde(default)]
+    pub http: HttpToolConfig,
+    #[serde(default)]
+    pub web_search: WebSearchConfig,
+    #[serde(default)]
+    pub skills: SkillsConfig,
+    #[serde(default)]
+    pub watch: WatchConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(default)]
 pub struct RuntimeConfig {
+    /// Tool calls one plan may make, and how many of them may be read-only lookups.
     pub max_turns: usize,
     pub read_only_budget: usize,
+    /// Blackboards processed at once across the guild.
+    pub max_concurrent_threads: usize,
+    /// Per-channel overrides of the limits above, keyed by Discord channel ID.
+    pub channel_limits: std::collections::HashMap<String, ChannelLimits>,
     pub max_tool_output_bytes: usize,
     pub privileged: bool,
     pub exec_mode: ExecMode,
+    /// Options for the sandboxed `exec_mode`s.
+    pub exec_sandbox: ExecSandboxConfig,
+    /// Which tools may run, globally and per channel.
+    pub tools: ToolPolicyConfig,
+    /// Tools that wait for a human to approve each call in Discord.
+    pub approval: ApprovalConfig,
+    /// Environment variables exposed to `exec` commands and skills.
+    pub env: EnvPolicyConfig,
+    /// IANA zone (e.g. `Europe/Paris`) cron schedules fire in; the host's local time when unset.
+    pub timezone: Option<String>,
+    /// Language of the steward's own Discord notices and blackboard log lines.
+    pub language: Language,
+}
+
+/// One channel's `runtime.channel_limits` entry; unset fields use the guild-wide value.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct ChannelLimits {
+    /// Blackboards of this channel processed at once, within the guild-wide limit.
+    pub max_concurrent_threads: Option<usize>,
+    pub max_turns: Option<usize>,
+    pub read_only_budget: Option<usize>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+/// Languages of the built-in message catalog in [`crate::i18n`].
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    En,
+    Zh,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecMode {
+    #[default]
     Unrestricted,
+    /// `bwrap` with a read-only system, private /tmp, and the guild bound at /guild.
+    Bubblewrap,
+    /// `firejail` with only the guild visible in its home-like directories.
+    Firejail,
+    /// A throwaway container of `exec_sandbox.docker_image` with the guild mounted at /guild.
+    Docker,
 }
 
-impl Default for ExecMode {
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct ExecSandboxConfig {
+    pub docker_image: String,
+    /// Sandboxed commands have no network access unless this is set.
+    pub network: bool,
+}
+
+impl Default for ExecSandboxConfig {
     fn default() -> Self {
-        Self::Unrestricted
+        Self {
+            docker_image: "debian:stable-slim".to_string(),
+            network: false,
+        }
     }
 }
 
@@ -42,34 +137,849 @@ impl Default for RuntimeConfig {
         Self {
             max_turns: 16,
             read_only_budget: 4,
+            max_concurrent_threads: 5,
+            channel_limits: std::collections::HashMap::new(),
             max_tool_output_bytes: 5000,
             privileged: false,
             exec_mode: ExecMode::Unrestricted,
+            exec_sandbox: ExecSandboxConfig::default(),
+            tools: ToolPolicyConfig::default(),
+            approval: ApprovalConfig::default(),
+            env: EnvPolicyConfig::default(),
+            timezone: None,
+            language: Language::En,
         }
     }
 }
 
+/// Tool names, optionally ending in `*` to match a prefix (e.g. `send_*`).
+/// An empty `allow` list allows every tool that is not denied.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct ToolRules {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+/// `runtime.tools`: guild-wide rules plus per-channel overrides keyed by Discord channel ID.
+/// A channel's own rules win over the guild-wide ones.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct ToolPolicyConfig {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+    pub channels: std::collections::HashMap<String, ToolRules>,
+}
+
+/// Variables one kind of child process receives on top of `runtime.env.base`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct EnvRules {
+    /// Host environment variables passed through by name.
+    pub pass: Vec<String>,
+    /// Secrets Tellar holds, exposed under their variable name (e.g. `GEMINI_API_KEY`).
+    pub secrets: Vec<String>,
+    /// Literal values.
+    pub set: std::collections::HashMap<String, String>,
+}
+
+/// `runtime.env`: child processes start from an empty environment plus `base`, then get only
+/// what their rules list. Skill rules are keyed by skill name.
 #[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct GeminiConfig {
+#[serde(default)]
+pub struct EnvPolicyConfig {
+    pub base: Vec<String>,
+    pub exec: EnvRules,
+    pub skills: std::collections::HashMap<String, EnvRules>,
+}
+
+impl Default for EnvPolicyConfig {
+    fn default() -> Self {
+        Self {
+            base: ["PATH", "HOME", "USER", "LANG", "LC_ALL", "TZ", "TERM", "TMPDIR"]
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            exec: EnvRules::default(),
+            skills: std::collections::HashMap::new(),
+        }
+    }
+}
+
+/// `runtime.approval`: when enabled, calls to `tools` post a confirmation embed and pause
+/// until an approver reacts, or abort after `timeout_secs`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct ApprovalConfig {
+    pub enabled: bool,
+    /// Tool names, optionally ending in `*`; skill tools can be listed too.
+    pub tools: Vec<String>,
+    /// Discord user IDs allowed to decide. Empty lets any non-bot member decide.
+    pub approvers: Vec<String>,
+    pub timeout_secs: u64,
+}
+
+impl Default for ApprovalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            tools: ["write", "edit", "patch", "exec", "exec_start", "mv", "rm"]
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            approvers: Vec::new(),
+            timeout_secs: 300,
+        }
+    }
+}
+
+/// Limits for the `http_request` tool. Hosts outside `allowed_domains` are only reachable
+/// with `runtime.privileged: true`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct HttpToolConfig {
+    /// Domains (and their subdomains) the tool may call; `*` allows every host.
+    pub allowed_domains: Vec<String>,
+    pub timeout_secs: u64,
+    /// Response bodies are cut to this many bytes before reaching the model.
+    pub max_response_bytes: usize,
+}
+
+impl Default for HttpToolConfig {
+    fn default() -> Self {
+        Self {
+            allowed_domains: Vec::new(),
+            timeout_secs: 20,
+            max_response_bytes: 20_000,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchProvider {
+    /// Self-hosted SearxNG instance with the JSON format enabled.
+    #[default]
+    Searxng,
+    Brave,
+    GoogleCse,
+}
+
+/// Backend for the `web_search` tool.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct WebSearchConfig {
+    pub enabled: bool,
+    pub provider: SearchProvider,
+    /// SearxNG instance URL, e.g. `http://127.0.0.1:8888`.
+    pub base_url: String,
+    /// Brave subscription token or Google API key.
     pub api_key: String,
+    /// Google Programmable Search Engine ID.
+    pub cx: String,
+    pub max_results: usize,
+}
+
+impl Default for WebSearchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: SearchProvider::Searxng,
+            base_url: String::new(),
+            api_key: String::new(),
+            cx: String::new(),
+            max_results: 5,
+        }
+    }
+}
+
+/// Text-to-speech settings used by the `send_voice_note` delivery tool.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct VoiceConfig {
     pub model: String,
+    pub voice: String,
+    pub max_chars: usize,
+}
+
+impl Default for VoiceConfig {
+    fn default() -> Self {
+        Self {
+            model: "gemini-2.5-flash-preview-tts".to_string(),
+            voice: "Kore".to_string(),
+            max_chars: 600,
+        }
+    }
+}
+
+/// Gemini context caching for the static part of each channel's prompt.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct ContextCacheConfig {
+    pub enabled: bool,
+    pub ttl_secs: u64,
+    pub min_chars: usize,
+    pub include_knowledge: bool,
+}
+
+impl Default for ContextCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_secs: 3600,
+            min_chars: 8000,
+            include_knowledge: true,
+        }
+    }
+}
+
+/// Semantic index over knowledge files and archived threads in `brain/index/`. When
+/// enabled, only the most relevant chunks are injected into each turn's prompt.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct KnowledgeIndexConfig {
+    pub enabled: bool,
+    /// Gemini embedding model.
+    pub model: String,
+    pub top_k: usize,
+    /// Chunks scoring below this cosine similarity are never injected.
+    pub min_score: f32,
+    pub chunk_chars: usize,
+}
+
+impl Default for KnowledgeIndexConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            model: "gemini-embedding-001".to_string(),
+            top_k: 5,
+            min_score: 0.35,
+            chunk_chars: 1200,
+        }
+    }
+}
+
+/// Rolling channel memory: once a daily log is longer than `max_log_chars`, everything
+/// but the `keep_recent` newest entries is summarized into the channel's `SUMMARY.md`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct SummaryConfig {
+    pub enabled: bool,
+    pub max_log_chars: usize,
+    pub keep_recent: usize,
+}
+
+impl Default for SummaryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_log_chars: 40000,
+            keep_recent: 20,
+        }
+    }
 }
 
+/// How long archived `history/<date>/` folders are kept. `0` disables a step.
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct HistoryRetentionConfig {
+    pub enabled: bool,
+    /// Day folders older than this are packed into `history/YYYY-MM.tar.gz`.
+    pub compress_after_days: u32,
+    /// Folders and monthly archives older than this are deleted.
+    pub delete_after_days: u32,
+}
+
+impl Default for HistoryRetentionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            compress_after_days: 30,
+            delete_after_days: 365,
+        }
+    }
+}
+
+/// Deterministic cleanup the Guardian runs every pulse. `0` disables a step.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct HygieneConfig {
+    pub enabled: bool,
+    /// Daily logs larger than this have their older messages moved to `history/`.
+    pub rotate_log_bytes: u64,
+    /// Files in `brain/outbox/` older than this are deleted.
+    pub outbox_max_age_days: u32,
+    /// Files in `brain/attachments/` older than this are deleted.
+    pub attachments_max_age_days: u32,
+}
+
+impl Default for HygieneConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rotate_log_bytes: 1024 * 1024,
+            outbox_max_age_days: 7,
+            attachments_max_age_days: 90,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchMode {
+    /// The OS notification backend (inotify, FSEvents, ...), falling back to polling if it fails.
+    #[default]
+    Native,
+    /// Rescan the guild every `poll_interval_secs`; for NFS, SSHFS, and other network mounts.
+    Poll,
+}
+
+/// The Watchman's filesystem observer.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct WatchConfig {
+    pub mode: WatchMode,
+    pub poll_interval_secs: u64,
+    /// Gitignore-style patterns, relative to the guild, whose changes are never acted on.
+    /// Setting this replaces the defaults.
+    pub ignore: Vec<String>,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            mode: WatchMode::Native,
+            poll_interval_secs: 2,
+            ignore: [
+                "*.swp",
+                "*.swo",
+                "*.swx",
+                "*~",
+                ".#*",
+                "#*#",
+                "*.tmp",
+                ".DS_Store",
+                ".trash/",
+                "history/",
+            ]
+            .iter()
+            .map(|pattern| pattern.to_string())
+            .collect(),
+        }
+    }
+}
+
+/// Daily digest of the steward's activity, saved to `brain/digests/`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct DigestConfig {
+    pub enabled: bool,
+    /// Cron schedule (five or six fields) for compiling the digest.
+    pub schedule: String,
+    /// Discord channel the digest is posted to, such as a `steward-log` channel.
+    pub channel_id: Option<String>,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            schedule: "55 23 * * *".to_string(),
+            channel_id: None,
+        }
+    }
+}
+
+/// The Guardian's pulse: how often it runs, what a pulse may spend, and where it may write.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct GuardianConfig {
+    pub enabled: bool,
+    /// Time between pulses, written like a ritual's `every:` (e.g. `1h`, `30m`).
+    pub interval: String,
+    /// Model turns and read-only tool calls one pulse may spend.
+    pub max_turns: usize,
+    pub read_only_budget: usize,
+    /// Guild directories the Guardian may change; it only reads everything else.
+    pub paths: Vec<String>,
+    /// Gemini model for Guardian checks and the conflict audit; `gemini.model` when unset.
+    pub model: Option<String>,
+    /// Merge duplicate remembered facts every pulse.
+    pub distill: bool,
+    /// Also ask the model which facts contradict each other and drop the stale one.
+    pub resolve_conflicts: bool,
+    /// Channel that receives a health report after every pulse.
+    pub report_channel_id: Option<String>,
+    /// Channel for critical alerts; defaults to `report_channel_id`, then the admin channel.
+    pub alert_channel_id: Option<String>,
+    /// Prepended to critical alerts, e.g. `@here` or `<@&role-id>`.
+    pub alert_mention: Option<String>,
+    /// Failed runs or skill calls in a row that make a finding critical.
+    pub critical_failure_streak: usize,
+    /// Minimum time between two alerts about the same thing.
+    pub alert_cooldown_mins: u64,
+    /// When Gemini or Discord failures switch the steward into degraded mode.
+    pub degraded: DegradedModeConfig,
+    /// Where backups land, so each pulse can verify the newest one.
+    pub backups: BackupCheckConfig,
+}
+
+/// Backup archives (`*.tar.gz` or `*.tgz`, optionally with a `<archive>.sha256` beside
+/// each) the Guardian verifies. Nothing is checked while `dir` is unset.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct BackupCheckConfig {
+    /// Backup directory, relative to the guild unless absolute.
+    pub dir: Option<String>,
+    /// The newest archive older than this is reported as stale.
+    pub max_age_hours: u64,
+}
+
+impl Default for BackupCheckConfig {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            max_age_hours: 26,
+        }
+    }
+}
+
+/// Degraded mode holds notifications and pauses rituals not marked `essential: true`
+/// while an API fails too often.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct DegradedModeConfig {
+    pub enabled: bool,
+    /// Failure rate (0.0-1.0) over the window that switches degraded mode on; recovery
+    /// needs the rate to fall below half of it.
+    pub error_rate: f64,
+    /// Fewer calls than this in the window never count as failing.
+    pub min_calls: usize,
+    pub window_mins: u64,
+}
+
+impl Default for DegradedModeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            error_rate: 0.5,
+            min_calls: 5,
+            window_mins: 10,
+        }
+    }
+}
+
+impl GuardianConfig {
+    /// Whether a guild-relative path lies inside one of `paths`.
+    pub fn may_write(&self, relative: &std::path::Path) -> bool {
+        self.paths
+            .iter()
+            .map(|dir| dir.trim().trim_matches('/'))
+            .any(|dir| !dir.is_empty() && relative.starts_with(dir))
+    }
+}
+
+impl Default for GuardianConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval: "1h".to_string(),
+            max_turns: 3,
+            read_only_budget: 2,
+            paths: vec![
+                "brain".to_string(),
+                "rituals".to_string(),
+                "channels".to_string(),
+            ],
+            model: None,
+            distill: true,
+            resolve_conflicts: true,
+            report_channel_id: None,
+            alert_channel_id: None,
+            alert_mention: None,
+            critical_failure_streak: 3,
+            alert_cooldown_mins: 360,
+            degraded: DegradedModeConfig::default(),
+            backups: BackupCheckConfig::default(),
+        }
+    }
+}
+
+/// Windows in which scheduled injections and unsolicited Discord posts wait for the next
+/// allowed time.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct QuietHoursConfig {
+    pub enabled: bool,
+    /// Daily window as `HH:MM`; a window past midnight such as 22:00–07:00 is allowed.
+    pub start: String,
+    pub end: String,
+    /// Zone the window and holidays are read in; defaults to `runtime.timezone`.
+    pub timezone: Option<String>,
+    /// Whole quiet days: `YYYY-MM-DD` for one date, `MM-DD` for every year.
+    pub holidays: Vec<String>,
+}
+
+impl Default for QuietHoursConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start: "22:00".to_string(),
+            end: "07:00".to_string(),
+            timezone: None,
+            holidays: Vec::new(),
+        }
+    }
+}
+
+/// IMAP/SMTP mailbox polled by the email inscriber and used by `send_email`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct EmailConfig {
+    pub enabled: bool,
+    pub imap_host: String,
+    pub imap_port: u16,
+    pub mailbox: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_starttls: bool,
+    pub username: String,
+    pub password: String,
+    pub from: Option<String>,
+    pub poll_secs: u64,
+    pub notify_channel_id: Option<String>,
+    /// Largest message fetched over IMAP; bigger ones are skipped and marked seen.
+    pub max_message_bytes: usize,
+}
+
+impl EmailConfig {
+    /// The From address for outgoing mail; falls back to the login username.
+    pub fn sender(&self) -> &str {
+        self.from.as_deref().unwrap_or(&self.username)
+    }
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            imap_host: String::new(),
+            imap_port: 993,
+            mailbox: "INBOX".to_string(),
+            smtp_host: String::new(),
+            smtp_port: 465,
+            smtp_starttls: false,
+            username: String::new(),
+            password: String::new(),
+            from: None,
+            poll_secs: 120,
+            notify_channel_id: None,
+            max_message_bytes: 25 * 1024 * 1024,
+        }
+    }
+}
+
+/// HTTP endpoint that inscribes external JSON payloads into channel blackboards.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct InboundWebhookConfig {
+    pub enabled: bool,
+    pub bind: String,
+    /// Shared secret expected in `X-Tellar-Token` or `Authorization: Bearer`.
+    pub token: String,
+    pub routes: std::collections::HashMap<String, WebhookRoute>,
+}
+
+impl Default for InboundWebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: "127.0.0.1:8787".to_string(),
+            token: String::new(),
+            routes: std::collections::HashMap::new(),
+        }
+    }
+}
+
+/// One `POST /hooks/<name>` route.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct WebhookRoute {
+    /// Discord channel ID whose blackboard receives the payload.
+    pub channel_id: String,
+    /// `{{field.path}}` template; defaults to the pretty-printed payload.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Wake the Steward on the inscribed entry, like a Discord mention.
+    #[serde(default)]
+    pub wake: bool,
+}
+
+/// Webhook fired on lifecycle events (ritual completed, task failed, thread archived, guardian alarm).
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct OutboundWebhook {
+    pub url: String,
+    /// Event names to send; empty means every event.
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// `{{field}}` body template over the event payload; defaults to the payload as JSON.
+    #[serde(default)]
+    pub template: Option<String>,
+    #[serde(default)]
+    pub headers: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeKind {
+    /// signal-cli JSON-RPC daemon (`signal-cli daemon --http`).
+    #[default]
+    SignalCli,
+    /// Generic HTTP bridge (e.g. a WhatsApp gateway): inbound on `POST /bridges/<name>`
+    /// of the inbound webhook server, outbound as a JSON POST to `url`.
+    Http,
+}
+
+/// Skill installation settings used by `tellarctl skill`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct SkillsConfig {
+    /// URL or local path of a JSON index mapping skill names to git sources, so
+    /// `tellarctl skill add <name>` works without a URL.
+    pub registry_index: String,
+}
+
+/// Optional git history of the workspace: the Steward's edits are committed after every turn.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct GitConfig {
+    pub enabled: bool,
+    pub author_name: String,
+    pub author_email: String,
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            author_name: "Tellar".to_string(),
+            author_email: "tellar@localhost".to_string(),
+        }
+    }
+}
+
+/// Messaging bridge for people who are not on Discord. Each known contact gets its own
+/// blackboard folder at `channels/<name>/<contact>/`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct BridgeConfig {
+    pub name: String,
+    #[serde(default)]
+    pub kind: BridgeKind,
+    /// signal-cli daemon base URL, or the HTTP bridge's send endpoint.
+    pub url: String,
+    /// Signal account (phone number) the daemon sends from.
+    #[serde(default)]
+    pub account: Option<String>,
+    /// Bearer token for the HTTP bridge, also required on its inbound requests.
+    #[serde(default)]
+    pub token: String,
+    /// Sender ID (phone number) -> contact folder name.
+    #[serde(default)]
+    pub contacts: std::collections::HashMap<String, String>,
+    /// Accept senders missing from `contacts`, filed under their sender ID.
+    #[serde(default)]
+    pub allow_unknown: bool,
+}
+
+/// RSS/Atom watcher: new entries from each source are inscribed into its channel blackboard.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct FeedsConfig {
+    pub enabled: bool,
+    pub poll_secs: u64,
+    /// Upper bound on entries inscribed per source and poll, newest first.
+    pub max_entries_per_poll: usize,
+    pub sources: Vec<FeedSource>,
+}
+
+impl Default for FeedsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_secs: 1800,
+            max_entries_per_poll: 5,
+            sources: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct FeedSource {
+    pub name: String,
+    pub url: String,
+    pub channel_id: String,
+    /// Ritual under `rituals/` that receives each digest, e.g. `feed-summary.md`.
+    #[serde(default)]
+    pub ritual: Option<String>,
+}
+
+/// Calendar subscriptions: upcoming events become rituals, with reminders posted ahead.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct CalendarsConfig {
+    pub enabled: bool,
+    pub poll_secs: u64,
+    /// How far ahead events are turned into rituals.
+    pub lookahead_days: u64,
+    pub sources: Vec<CalendarSource>,
+}
+
+impl Default for CalendarsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_secs: 900,
+            lookahead_days: 7,
+            sources: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct CalendarSource {
+    pub name: String,
+    /// An `.ics` URL, or a CalDAV calendar collection when `caldav` is set.
+    pub url: String,
+    pub caldav: bool,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Channel that receives the reminders and owns the rituals.
+    pub channel_id: String,
+    /// Zone for event times that carry none; defaults to `runtime.timezone`.
+    pub timezone: Option<String>,
+    /// Reminder lead times before each event, e.g. `["1d", "15m"]`.
+    pub remind_before: Vec<String>,
+}
+
+impl Default for CalendarSource {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            url: String::new(),
+            caldav: false,
+            username: None,
+            password: None,
+            channel_id: String::new(),
+            timezone: None,
+            remind_before: vec!["15m".to_string()],
+        }
+    }
+}
+
+/// ntfy push channel: lifecycle events and `send_push` publish to `topic`;
+/// messages on `reply_topic` are inscribed into `reply_channel_id`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct NtfyConfig {
+    pub enabled: bool,
+    pub server: String,
+    pub topic: String,
+    /// Access token for protected topics, sent as `Authorization: Bearer`.
+    pub token: String,
+    /// Lifecycle event names to push; empty means every event.
+    pub events: Vec<String>,
+    pub reply_topic: Option<String>,
+    pub reply_channel_id: Option<String>,
+    /// Wake the Steward on inscribed replies, as if it had been mentioned.
+    pub wake: bool,
+}
+
+impl Default for NtfyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            server: "https://ntfy.sh".to_string(),
+            topic: String::new(),
+            token: String::new(),
+            events: Vec::new(),
+            reply_topic: None,
+            reply_channel_id: None,
+            wake: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct GeminiConfig {
+    pub api_key: String,
+    pub model: String,
+    /// Sampling temperature of replies; 0.4 when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct DiscordConfig {
     pub token: String,
     pub guild_id: Option<String>,
     pub channel_mappings: Option<std::collections::HashMap<String, String>>, // Discord Channel ID -> Tellar Folder Name
+    /// Channel for operator-facing reports such as the startup permission self-test.
+    #[serde(default)]
+    pub admin_channel_id: Option<String>,
+    /// Seconds between guild channel discovery refreshes. `0` disables the refresh.
+    #[serde(default = "default_discovery_refresh_secs")]
+    pub discovery_refresh_secs: u64,
+}
+
+fn default_discovery_refresh_secs() -> u64 {
+    900
 }
 
 use std::path::Path;
 
 impl Config {
+    /// Load `tellar.yml` with `TELLAR__SECTION__FIELD` environment variables layered on
+    /// top, under the profile named by `TELLAR_PROFILE` if set. The file may be missing when
+    /// the environment configures everything.
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let profile = crate::profiles::resolve(None);
+        Self::load_profile(path, profile.as_deref())
+    }
+
+    /// Like [`Config::load`], overlaying the named entry of `profiles:` first.
+    pub fn load_profile<P: AsRef<Path>>(path: P, profile: Option<&str>) -> Result<Self> {
+        let overrides = crate::env_overrides::from_env();
+        let content = match std::fs::read_to_string(path.as_ref()) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && !overrides.is_empty() => {
+                String::new()
+            }
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to read config file at {:?}", path.as_ref()));
+            }
+        };
+        let mut value: serde_yml::Value =
+            serde_yml::from_str(&content).context("Failed to parse config file")?;
+        crate::profiles::select(&mut value, profile)?;
+        let defaults = serde_yml::to_value(Config::default())?;
+        crate::env_overrides::apply(&mut value, &defaults, &overrides);
+        crate::secrets::decrypt_credentials(&mut value)?;
+        serde_yml::from_value(value).context("Failed to parse config file or TELLAR__ overrides")
+    }
+
+    /// Load `tellar.yml` alone, for tools that write the config back. Encrypted values
+    /// stay encrypted.
+    pub fn load_file<P: AsRef<Path>>(path: P) -> Result<Self> {
         let content = std::fs::read_to_string(path.as_ref())
             .with_context(|| format!("Failed to read config file at {:?}", path.as_ref()))?;
         let config: Config =
             serde_yml::from_str(&content).context("Failed to parse config file")?;
         Ok(config)
     }
+
+    /// Whether any `TELLAR__` environment override is set.
+    pub fn has_env_overrides() -> bool {
+        !crate::env_overrides::from_env().is_empty()
+    }
 }
@@ -0,0 +1,266 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/context_cache.rs
+ * Responsibility: Per-channel Gemini context caching for the static prompt and knowledge.
+ */
+
+use crate::config::Config;
+use crate::llm;
+use crate::{log_info, log_warn};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Refresh a cache this long before Gemini would expire it.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+/// After a failed cache creation, fall back to inline prompts for this long.
+const FAILURE_BACKOFF: Duration = Duration::from_secs(300);
+
+#[derive(Clone)]
+struct CacheEntry {
+    fingerprint: u64,
+    name: Option<String>,
+    valid_until: Instant,
+}
+
+impl CacheEntry {
+    fn is_reusable(&self, fingerprint: u64, now: Instant) -> bool {
+        self.fingerprint == fingerprint && now < self.valid_until
+    }
+}
+
+static CONTEXT_CACHES: Lazy<Mutex<HashMap<(PathBuf, String), CacheEntry>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn read_knowledge(path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    (!content.trim().is_empty()).then_some(content)
+}
+
+/// Builds the static context for a channel: the unified system prompt plus guild
+/// and channel `KNOWLEDGE.md`, so any edit to those files changes the fingerprint.
+pub(crate) fn build_static_context(
+    base_path: &Path,
+    channel_id: &str,
+    system_prompt: &str,
+    include_knowledge: bool,
+) -> String {
+    let mut context = system_prompt.to_string();
+    if !include_knowledge {
+        return context;
+    }
+
+    if let Some(knowledge) = read_knowledge(&base_path.join("brain").join("KNOWLEDGE.md")) {
+        context.push_str("\n\n### Guild Knowledge (brain/KNOWLEDGE.md):\n");
+        context.push_str(&knowledge);
+    }
+
+    if channel_id != "0"
+        && let Some(folder) = crate::discord::resolve_folder_by_id(base_path, channel_id)
+        && let Some(knowledge) = read_knowledge(
+            &base_path
+                .join("channels")
+                .join(&folder)
+                .join("KNOWLEDGE.md"),
+        )
+    {
+        context.push_str(&format!(
+            "\n\n### Channel Knowledge (channels/{}/KNOWLEDGE.md):\n",
+            folder
+        ));
+        context.push_str(&knowledge);
+    }
+
+    context
+}
+
+fn fingerprint(model: &str, context: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    model.hash(&mut hasher);
+    context.hash(&mut hasher);
+    hasher.finish()
+}
+
+async fn resolve_cached_content(
+    base_path: &Path,
+    channel_id: &str,
+    context: &str,
+    config: &Config,
+) -> Option<String> {
+    let key = (base_path.to_path_buf(), channel_id.to_string());
+    let fingerprint = fingerprint(&config.gemini.model, context);
+    let mut caches = CONTEXT_CACHES.lock().await;
+
+    if let Some(entry) = caches.get(&key)
+        && entry.is_reusable(fingerprint, Instant::now())
+    {
+        return entry.name.clone();
+    }
+
+    if let Some(stale) = caches.remove(&key).and_then(|entry| entry.name) {
+        let api_key = config.gemini.api_key.clone();
+        tokio::spawn(async move {
+            let _ = llm::delete_cached_content(&stale, &api_key).await;
+        });
+    }
+
+    let ttl = config
+        .context_cache
+        .ttl_secs
+        .max(EXPIRY_MARGIN.as_secs() * 2);
+    let entry = match llm::create_cached_content(
+        context,
+        &config.gemini.api_key,
+        &config.gemini.model,
+        ttl,
+    )
+    .await
+    {
+        Ok(cached) => {
+            log_info!(
+                "steward",
+                "🧊 Context cache refreshed for channel {}: {}",
+                channel_id,
+                cached.name
+            );
+            CacheEntry {
+                fingerprint,
+                name: Some(cached.name),
+                valid_until: Instant::now() + Duration::from_secs(ttl) - EXPIRY_MARGIN,
+            }
+        }
+        Err(e) => {
+            log_warn!(
+                "steward",
+                "⚠️ Context cache unavailable for channel {}, sending prompt inline: {}",
+                channel_id,
+                e
+            );
+            CacheEntry {
+                fingerprint,
+                name: None,
+                valid_until: Instant::now() + FAILURE_BACKOFF,
+            }
+        }
+    };
+
+    let name = entry.name.clone();
+    caches.insert(key, entry);
+    name
+}
+
+async fn forget_cached_content(base_path: &Path, channel_id: &str) {
+    let key = (base_path.to_path_buf(), channel_id.to_string());
+    CONTEXT_CACHES.lock().await.remove(&key);
+}
+
+/// Generates a turn for a channel, reusing a Gemini cached content for the static
+/// context when `context_cache` is enabled and the context is large enough to cache.
+pub(crate) async fn generate_channel_turn(
+    base_path: &Path,
+    channel_id: &str,
+    system_prompt: &str,
+    config: &Config,
+    history: Vec<llm::Message>,
+    temperature: f32,
+    tools: Option<serde_json::Value>,
+) -> anyhow::Result<llm::ModelTurn> {
+    let api_key = &config.gemini.api_key;
+    let model = &config.gemini.model;
+    if !config.context_cache.enabled {
+        return llm::generate_turn(system_prompt, history, api_key, model, temperature, tools)
+            .await;
+    }
+
+    let context = build_static_context(
+        base_path,
+        channel_id,
+        system_prompt,
+        // With the knowledge index on, the prompt already carries the relevant chunks.
+        config.context_cache.include_knowledge && !config.knowledge_index.enabled,
+    );
+    // Uncached turns send the prompt exactly as they would with the cache off.
+    if context.len() < config.context_cache.min_chars {
+        return llm::generate_turn(system_prompt, history, api_key, model, temperature, tools)
+            .await;
+    }
+
+    if let Some(name) = resolve_cached_content(base_path, channel_id, &context, config).await {
+        match llm::generate_cached_turn(
+            &name,
+            history.clone(),
+            api_key,
+            model,
+            temperature,
+            tools.clone(),
+        )
+        .await
+        {
+            Ok(turn) => return Ok(turn),
+            Err(e) => {
+                log_warn!(
+                    "steward",
+                    "⚠️ Cached context {} rejected, retrying with inline prompt: {}",
+                    name,
+                    e
+                );
+                forget_cached_content(base_path, channel_id).await;
+            }
+        }
+    }
+
+    llm::generate_turn(system_prompt, history, api_key, model, temperature, tools).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_static_context_includes_guild_and_channel_knowledge() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        std::fs::create_dir_all(base.join("brain")).unwrap();
+        std::fs::create_dir_all(base.join("channels/general-123456")).unwrap();
+        std::fs::write(base.join("brain/KNOWLEDGE.md"), "guild fact").unwrap();
+        std::fs::write(
+            base.join("channels/general-123456/KNOWLEDGE.md"),
+            "channel fact",
+        )
+        .unwrap();
+
+        let context = build_static_context(base, "999123456", "prompt", true);
+        assert!(context.starts_with("prompt"));
+        assert!(context.contains("guild fact"));
+        assert!(context.contains("channels/general-123456/KNOWLEDGE.md"));
+        assert!(context.contains("channel fact"));
+
+        assert_eq!(
+            build_static_context(base, "999123456", "prompt", false),
+            "prompt"
+        );
+    }
+
+    #[test]
+    fn test_cache_entry_invalidates_on_fingerprint_change_or_expiry() {
+        let now = Instant::now();
+        let entry = CacheEntry {
+            fingerprint: fingerprint("model", "context v1"),
+            name: Some("cachedContents/abc".to_string()),
+            valid_until: now + Duration::from_secs(30),
+        };
+
+        assert!(entry.is_reusable(fingerprint("model", "context v1"), now));
+        assert!(!entry.is_reusable(fingerprint("model", "context v2"), now));
+        assert!(!entry.is_reusable(fingerprint("other-model", "context v1"), now));
+        assert!(!entry.is_reusable(
+            fingerprint("model", "context v1"),
+            now + Duration::from_secs(31)
+        ));
+    }
+}
@@ -0,0 +1,351 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/datetime.rs
+ * Responsibility: The deterministic `datetime` core tool, so date arithmetic never rests on the model.
+ */
+
+use crate::tools::ToolExecutionResult;
+use chrono::{
+    DateTime, Datelike, Days, FixedOffset, Local, Months, NaiveDate, NaiveDateTime, TimeDelta,
+    TimeZone, Weekday,
+};
+use chrono_tz::Tz;
+use serde_json::{Value, json};
+
+pub const DATETIME_TOOL_NAME: &str = "datetime";
+
+/// The zone results are expressed in: the host's local time or an IANA zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Zone {
+    Local,
+    Named(Tz),
+}
+
+impl Zone {
+    pub(crate) fn parse(name: Option<&str>) -> Result<Self, String> {
+        match name.map(str::trim).filter(|name| !name.is_empty()) {
+            None => Ok(Zone::Local),
+            Some(name) if name.eq_ignore_ascii_case("local") => Ok(Zone::Local),
+            Some(name) if name.eq_ignore_ascii_case("utc") => Ok(Zone::Named(Tz::UTC)),
+            Some(name) => name.parse::<Tz>().map(Zone::Named).map_err(|_| {
+                format!(
+                    "unknown timezone `{}`; use an IANA name like Europe/Paris",
+                    name
+                )
+            }),
+        }
+    }
+
+    pub(crate) fn label(&self) -> String {
+        match self {
+            Zone::Local => "local".to_string(),
+            Zone::Named(tz) => tz.name().to_string(),
+        }
+    }
+
+    fn now(&self) -> DateTime<FixedOffset> {
+        self.convert(Local::now().fixed_offset())
+    }
+
+    pub(crate) fn convert(&self, moment: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        match self {
+            Zone::Local => moment.with_timezone(&Local).fixed_offset(),
+            Zone::Named(tz) => moment.with_timezone(tz).fixed_offset(),
+        }
+    }
+
+    /// Attach this zone to a wall-clock time. Ambiguous times take the earlier instant;
+    /// times skipped by a DST jump are an error.
+    pub(crate) fn localize(&self, naive: NaiveDateTime) -> Result<DateTime<FixedOffset>, String> {
+        let localized = match self {
+            Zone::Local => Local
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|dt| dt.fixed_offset()),
+            Zone::Named(tz) => tz
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|dt| dt.fixed_offset()),
+        };
+        localized.ok_or_else(|| format!("{} does not exist in {}", naive, self.label()))
+    }
+}
+
+/// Accepts RFC 3339, `YYYY-MM-DD HH:MM[:SS]`, `YYYY-MM-DDTHH:MM[:SS]`, or `YYYY-MM-DD`.
+/// Inputs without an offset are read as wall-clock time in `zone`.
+pub(crate) fn parse_moment(value: &str, zone: Zone) -> Result<DateTime<FixedOffset>, String> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("now") {
+        return Ok(zone.now());
+    }
+    if let Ok(moment) = DateTime::parse_from_rfc3339(value) {
+        return Ok(zone.convert(moment));
+    }
+    for format in [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(value, format) {
+            return zone.localize(naive);
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return zone.localize(date.and_hms_opt(0, 0, 0).expect("midnight is valid"));
+    }
+    Err(format!(
+        "cannot parse `{}`; use RFC 3339 or YYYY-MM-DD [HH:MM[:SS]]",
+        value
+    ))
+}
+
+/// Apply a duration such as `+1mo 2d`, `-3h`, or `2w 30m`. Calendar units (y, mo, w, d) keep
+/// the wall-clock time across DST changes; clock units (h, m, s) add elapsed time.
+pub(crate) fn add_duration(
+    moment: DateTime<FixedOffset>,
+    duration: &str,
+    zone: Zone,
+) -> Result<DateTime<FixedOffset>, String> {
+    let trimmed = duration.trim();
+    let (negative, body) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let invalid = || {
+        format!(
+            "cannot parse duration `{}`; use units y, mo, w, d, h, m, s (e.g. `1mo 2d`, `-90m`)",
+            duration
+        )
+    };
+
+    let mut months: u32 = 0;
+    let mut days: u64 = 0;
+    let mut seconds: i64 = 0;
+    let mut tokens = 0;
+    for token in body.split_whitespace() {
+        let split = token
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let amount: u32 = token[..split].parse().map_err(|_| invalid())?;
+        match &token[split..] {
+            "y" | "yr" | "year" | "years" => {
+                months = months.saturating_add(amount.saturating_mul(12))
+            }
+            "mo" | "month" | "months" => months = months.saturating_add(amount),
+            "w" | "week" | "weeks" => days += u64::from(amount) * 7,
+            "d" | "day" | "days" => days += u64::from(amount),
+            "h" | "hour" | "hours" => seconds += i64::from(amount) * 3600,
+            "m" | "min" | "mins" | "minute" | "minutes" => seconds += i64::from(amount) * 60,
+            "s" | "sec" | "secs" | "second" | "seconds" => seconds += i64::from(amount),
+            _ => return Err(invalid()),
+        }
+        tokens += 1;
+    }
+    if tokens == 0 {
+        return Err(invalid());
+    }
+
+    let wall = moment.naive_local();
+    let shifted = if negative {
+        wall.checked_sub_months(Months::new(months))
+            .and_then(|wall| wall.checked_sub_days(Days::new(days)))
+    } else {
+        wall.checked_add_months(Months::new(months))
+            .and_then(|wall| wall.checked_add_days(Days::new(days)))
+    }
+    .ok_or_else(|| "date out of range".to_string())?;
+    let calendar = if months == 0 && days == 0 {
+        moment
+    } else {
+        zone.localize(shifted)?
+    };
+    let elapsed = TimeDelta::seconds(if negative { -seconds } else { seconds });
+    Ok(zone.convert(calendar + elapsed))
+}
+
+pub(crate) fn parse_weekday(name: &str) -> Result<Weekday, String> {
+    name.trim()
+        .parse::<Weekday>()
+        .map_err(|_| format!("unknown weekday `{}`", name))
+}
+
+/// The next date falling on `weekday` after `moment` (or on it, with `include_today`), at midnight.
+pub(crate) fn next_weekday(
+    moment: DateTime<FixedOffset>,
+    weekday: Weekday,
+    include_today: bool,
+    zone: Zone,
+) -> Result<DateTime<FixedOffset>, String> {
+    let today = moment.date_naive();
+    let mut ahead =
+        (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    if ahead == 0 && !include_today {
+        ahead = 7;
+    }
+    let date = today + Days::new(u64::from(ahead));
+    zone.localize(date.and_hms_opt(0, 0, 0).expect("midnight is valid"))
+}
+
+fn describe(moment: DateTime<FixedOffset>, zone: Zone) -> String {
+    format!(
+        "{} ({}, {}, week {})",
+        moment.to_rfc3339(),
+        moment.format("%A %-d %B %Y"),
+        zone.label(),
+        moment.iso_week().week()
+    )
+}
+
+fn describe_span(from: DateTime<FixedOffset>, to: DateTime<FixedOffset>) -> String {
+    let delta = to - from;
+    let total_minutes = delta.num_minutes();
+    let sign = if total_minutes < 0 { "-" } else { "" };
+    let minutes = total_minutes.abs();
+    format!(
+        "{}{}d {}h {}m ({} calendar days; {} total minutes)",
+        sign,
+        minutes / 1440,
+        minutes % 1440 / 60,
+        minutes % 60,
+        (to.date_naive() - from.date_naive()).num_days(),
+        total_minutes
+    )
+}
+
+fn string_arg<'a>(args: &'a Value, field: &str) -> Option<&'a str> {
+    args.get(field).and_then(Value::as_str)
+}
+
+fn evaluate(args: &Value) -> Result<String, String> {
+    let op = string_arg(args, "op").unwrap_or("now");
+    let zone = Zone::parse(string_arg(args, "tz"))?;
+    let moment = parse_moment(string_arg(args, "value").unwrap_or("now"), zone)?;
+
+    match op {
+        "now" | "parse" => Ok(describe(moment, zone)),
+        "add" => {
+            let duration =
+                string_arg(args, "duration").ok_or("`add` requires `duration`, e.g. `+3d`")?;
+            Ok(describe(add_duration(moment, duration, zone)?, zone))
+        }
+        "next_weekday" => {
+            let weekday = parse_weekday(
+                string_arg(args, "weekday").ok_or("`next_weekday` requires `weekday`")?,
+            )?;
+            let include_today = args
+                .get("includeToday")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            Ok(describe(
+                next_weekday(moment, weekday, include_today, zone)?,
+                zone,
+            ))
+        }
+        "convert" => {
+            let target = Zone::parse(Some(
+                string_arg(args, "to").ok_or("`convert` requires `to`, e.g. `Asia/Tokyo`")?,
+            ))?;
+            Ok(describe(target.convert(moment), target))
+        }
+        "diff" => {
+            let other = parse_moment(
+                string_arg(args, "other").ok_or("`diff` requires `other`")?,
+                zone,
+            )?;
+            Ok(format!(
+                "From {} to {}: {}",
+                moment.to_rfc3339(),
+                other.to_rfc3339(),
+                describe_span(moment, other)
+            ))
+        }
+        other => Err(format!(
+            "unknown op `{}`; use now, parse, add, next_weekday, convert, or diff",
+            other
+        )),
+    }
+}
+
+pub(crate) fn datetime_tool_definition() -> Value {
+    json!({
+        "name": DATETIME_TOOL_NAME,
+        "description": "Deterministic date and time math. Use it instead of computing dates yourself: current time, parsing, adding or subtracting durations, the next given weekday, timezone conversion, and the span between two moments.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "op": { "type": "string", "enum": ["now", "parse", "add", "next_weekday", "convert", "diff"], "description": "Operation. Defaults to now" },
+                "value": { "type": "string", "description": "Starting moment: RFC 3339, YYYY-MM-DD [HH:MM[:SS]], or 'now'. Defaults to now" },
+                "tz": { "type": "string", "description": "IANA timezone for input and output, e.g. Europe/Paris. Defaults to the host's local time" },
+                "duration": { "type": "string", "description": "For add: e.g. '+3d', '-2w', '1mo 4h', '90m'. Units y, mo, w, d, h, m, s" },
+                "weekday": { "type": "string", "description": "For next_weekday: e.g. 'monday' or 'fri'" },
+                "includeToday": { "type": "boolean", "description": "For next_weekday: return today if it already matches" },
+                "to": { "type": "string", "description": "For convert: target IANA timezone" },
+                "other": { "type": "string", "description": "For diff: the second moment" }
+            }
+        }
+    })
+}
+
+pub(crate) fn run_datetime_tool(args: &Value) -> ToolExecutionResult {
+    match evaluate(args) {
+        Ok(output) => ToolExecutionResult::success(output),
+        Err(error) => ToolExecutionResult::error(format!("Error: {}", error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: Value) -> String {
+        let result = run_datetime_tool(&args);
+        assert!(!result.is_error, "{}", result.output);
+        result.output
+    }
+
+    #[test]
+    fn test_datetime_add_keeps_wall_clock_and_clamps_month_ends() {
+        assert!(
+            run(
+                json!({ "op": "add", "value": "2026-01-31 09:00", "tz": "UTC", "duration": "+1mo" })
+            )
+            .starts_with("2026-02-28T09:00:00+00:00 (Saturday 28 February 2026, UTC")
+        );
+        // Crosses the Paris DST change on 2026-03-29: still 09:00 local.
+        assert!(
+            run(json!({ "op": "add", "value": "2026-03-28 09:00", "tz": "Europe/Paris", "duration": "1d" }))
+                .starts_with("2026-03-29T09:00:00+02:00")
+        );
+        assert!(
+            run(json!({ "op": "add", "value": "2026-03-01T00:30:00Z", "tz": "UTC", "duration": "-1d 45m" }))
+                .starts_with("2026-02-27T23:45:00+00:00")
+        );
+        assert!(run_datetime_tool(&json!({ "op": "add", "duration": "3 fortnights" })).is_error);
+    }
+
+    #[test]
+    fn test_datetime_next_weekday_convert_and_diff() {
+        // 2026-10-16 is a Friday.
+        assert!(
+            run(json!({ "op": "next_weekday", "value": "2026-10-16", "tz": "UTC", "weekday": "friday" }))
+                .starts_with("2026-10-23T00:00:00+00:00")
+        );
+        assert!(
+            run(json!({ "op": "next_weekday", "value": "2026-10-16", "tz": "UTC", "weekday": "fri", "includeToday": true }))
+                .starts_with("2026-10-16T00:00:00+00:00")
+        );
+        assert!(
+            run(json!({ "op": "convert", "value": "2026-10-16T12:00:00Z", "to": "Asia/Tokyo" }))
+                .starts_with("2026-10-16T21:00:00+09:00 (Friday 16 October 2026, Asia/Tokyo")
+        );
+        assert_eq!(
+            run(
+                json!({ "op": "diff", "value": "2026-10-16 22:00", "other": "2026-10-18 08:30", "tz": "UTC" })
+            ),
+            "From 2026-10-16T22:00:00+00:00 to 2026-10-18T08:30:00+00:00: 1d 10h 30m (2 calendar days; 2070 total minutes)"
+        );
+        let bad_zone = run_datetime_tool(&json!({ "tz": "Mars/Olympus" }));
+        assert!(bad_zone.output.contains("unknown timezone"));
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::config::Config;
 use crate::discord::client as discord_client;
+use crate::log_warn;
 use crate::tools::{ToolExecutionResult, is_path_safe};
 use serde_json::{Value, json};
 use std::fs;
@@ -52,7 +53,7 @@ pub(crate) fn delivery_tool_definitions() -> Vec<Value> {
         }),
         json!({
             "name": "send_attachment",
-            "description": "Send a local file to the current Discord channel as an attachment. Relative paths resolve from the guild root. Absolute host paths require runtime.privileged=true.",
+            "description": "Send a local file to the current Discord channel as an attachment. Relative paths resolve from the guild root, including brain/artifacts/ files with full tool output. Absolute host paths require runtime.privileged=true.",
             "parameters": {
                 "type": "object",
                 "properties": {
@@ -111,6 +112,46 @@ pub(crate) fn delivery_tool_definitions() -> Vec<Value> {
                 "required": ["content"]
             }
         }),
+        json!({
+            "name": "send_voice_note",
+            "description": "Synthesize a short reply into speech and send it to the current Discord channel as an audio attachment. Use this for brief spoken summaries, not for long documents.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "content": { "type": "string", "description": "The short text to speak" },
+                    "voice": { "type": "string", "description": "Optional prebuilt voice name. Defaults to voice.voice from config" }
+                },
+                "required": ["content"]
+            }
+        }),
+        json!({
+            "name": "send_email",
+            "description": "Send a plain-text email through the configured SMTP account. To answer an inscribed email thread, pass its `Message ID` as inReplyTo and the thread file so the reply is logged there.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "to": { "type": "string", "description": "Recipient address, or several separated by commas" },
+                    "subject": { "type": "string", "description": "Email subject line" },
+                    "content": { "type": "string", "description": "Plain-text email body" },
+                    "inReplyTo": { "type": "string", "description": "Optional Message-ID being answered, e.g. '<abc@example.com>'" },
+                    "thread": { "type": "string", "description": "Optional email thread blackboard to log the reply in, e.g. 'email/engine-notes.md'" }
+                },
+                "required": ["to", "subject", "content"]
+            }
+        }),
+        json!({
+            "name": "send_push",
+            "description": "Send a push notification to the operator's phone through ntfy. Use it for alerts that should reach them away from Discord; keep it short.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "message": { "type": "string", "description": "Notification body" },
+                    "title": { "type": "string", "description": "Optional notification title" },
+                    "priority": { "type": "integer", "description": "Optional ntfy priority from 1 (min) to 5 (urgent). Defaults to 3" }
+                },
+                "required": ["message"]
+            }
+        }),
     ]
 }
 
@@ -257,6 +298,96 @@ fn build_outbox_file(
     Ok(final_path)
 }
 
+/// Wrap 16-bit mono PCM samples in a minimal RIFF/WAVE container.
+fn encode_wav(pcm: &[u8], sample_rate: u32) -> Vec<u8> {
+    let channels: u16 = 1;
+    let bits_per_sample: u16 = 16;
+    let block_align = channels * bits_per_sample / 8;
+    let byte_rate = sample_rate * u32::from(block_align);
+    let data_len = pcm.len() as u32;
+
+    let mut wav = Vec::with_capacity(44 + pcm.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&channels.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&byte_rate.to_le_bytes());
+    wav.extend_from_slice(&block_align.to_le_bytes());
+    wav.extend_from_slice(&bits_per_sample.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    wav.extend_from_slice(pcm);
+    wav
+}
+
+async fn send_voice_note(
+    content: &str,
+    voice: &str,
+    base_path: &Path,
+    config: &Config,
+    channel_id: &str,
+) -> ToolExecutionResult {
+    let max_chars = config.voice.max_chars;
+    let char_count = content.chars().count();
+    if max_chars > 0 && char_count > max_chars {
+        return ToolExecutionResult::error(format!(
+            "Error: Voice notes are limited to {} characters (got {}). Summarize first or use send_message.",
+            max_chars, char_count
+        ));
+    }
+
+    let audio = match crate::llm::synthesize_speech(
+        content,
+        &config.gemini.api_key,
+        &config.voice.model,
+        voice,
+    )
+    .await
+    {
+        Ok(audio) => audio,
+        Err(error) => return delivery_error("synthesizing voice note", error),
+    };
+
+    let wav = encode_wav(&audio.data, audio.sample_rate());
+    let outbox = base_path.join("brain").join("outbox");
+    if let Err(error) = fs::create_dir_all(&outbox) {
+        return delivery_error("creating outbox", error);
+    }
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or(0);
+    let voice_file = outbox.join(format!("{}_voice_note.wav", timestamp));
+    if let Err(error) = fs::write(&voice_file, wav) {
+        return delivery_error("writing voice note", error);
+    }
+
+    let result = send_attachment_file(
+        &config.discord.token,
+        channel_id,
+        &voice_file,
+        "voice note",
+        "sending voice note",
+        "voice_note.wav",
+    )
+    .await;
+
+    if let Err(error) = fs::remove_file(&voice_file) {
+        log_warn!(
+            "discord",
+            "⚠️ Failed to remove sent voice note {}: {}",
+            voice_file.display(),
+            error
+        );
+    }
+
+    result
+}
+
 fn path_label(path: &Path, fallback: &str) -> String {
     path.file_name()
         .and_then(|value| value.to_str())
@@ -272,6 +403,63 @@ fn delivery_success(message: impl Into<String>) -> ToolExecutionResult {
     ToolExecutionResult::success(message)
 }
 
+async fn send_email_reply(args: &Value, base_path: &Path, config: &Config) -> ToolExecutionResult {
+    let to = match require_string_arg(args, "to") {
+        Ok(to) => to,
+        Err(err) => return err,
+    };
+    let subject = match require_string_arg(args, "subject") {
+        Ok(subject) => subject,
+        Err(err) => return err,
+    };
+    let content = match require_string_arg(args, "content") {
+        Ok(content) => content,
+        Err(err) => return err,
+    };
+    let in_reply_to = args
+        .get("inReplyTo")
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty());
+    let thread = args
+        .get("thread")
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty());
+    if let Some(thread) = thread
+        && !thread.starts_with(&format!("{}/", crate::email::EMAIL_CHANNEL_DIR))
+    {
+        return ToolExecutionResult::error(format!(
+            "Error: `thread` must be an email thread under `{}/`.",
+            crate::email::EMAIL_CHANNEL_DIR
+        ));
+    }
+
+    let message_id = match crate::email::send_email(config, to, subject, content, in_reply_to).await {
+        Ok(message_id) => message_id,
+        Err(error) => return delivery_error("sending email", error),
+    };
+
+    if let Some(thread) = thread {
+        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        if let Err(error) = crate::discord::ingest_store::append_to_message_log(
+            base_path,
+            thread,
+            "Tellar",
+            config.email.sender(),
+            &format!("**Subject**: {}\n**To**: {}\n\n{}", subject, to, content),
+            &message_id,
+            &timestamp,
+            in_reply_to.map(str::to_string),
+            Vec::new(),
+        )
+        .await
+        {
+            log_warn!("discord", "⚠️ Failed to log sent email into {}: {:?}", thread, error);
+        }
+    }
+
+    delivery_success(format!("Sent email to {} ({}).", to, message_id))
+}
+
 async fn send_attachment_file(
     token: &str,
     channel_id: &str,
@@ -304,7 +492,8 @@ pub(crate) async fn dispatch_delivery_tool(
                 Err(err) => return Some(err),
             };
 
-            match discord_client::send_bot_message(&config.discord.token, channel_id, content).await
+            match discord_client::send_bot_message_or_file(&config.discord.token, channel_id, content)
+                .await
             {
                 Ok(_) => delivery_success("Sent text message to the current Discord channel."),
                 Err(error) => delivery_error("sending message", error),
@@ -320,11 +509,16 @@ pub(crate) async fn dispatch_delivery_tool(
                 Err(err) => return Some(err),
             };
 
-            match discord_client::send_reply_message(
+            match discord_client::with_text_file_fallback(
                 &config.discord.token,
                 channel_id,
-                message_id,
                 content,
+                discord_client::send_reply_message(
+                    &config.discord.token,
+                    channel_id,
+                    message_id,
+                    content,
+                ),
             )
             .await
             {
@@ -447,11 +641,16 @@ pub(crate) async fn dispatch_delivery_tool(
             };
             let language = args.get("language").and_then(Value::as_str).unwrap_or("");
 
-            match discord_client::send_code_block_message(
+            match discord_client::with_text_file_fallback(
                 &config.discord.token,
                 channel_id,
                 content,
-                language,
+                discord_client::send_code_block_message(
+                    &config.discord.token,
+                    channel_id,
+                    content,
+                    language,
+                ),
             )
             .await
             {
@@ -484,7 +683,8 @@ pub(crate) async fn dispatch_delivery_tool(
                 Ok(_) => {
                     let label = path_label(&outbox_file, "artifact.txt");
                     if let Err(error) = fs::remove_file(&outbox_file) {
-                        eprintln!(
+                        log_warn!(
+                            "discord",
                             "⚠️ Failed to remove sent outbox artifact {}: {}",
                             outbox_file.display(),
                             error
@@ -499,6 +699,44 @@ pub(crate) async fn dispatch_delivery_tool(
                 Err(error) => delivery_error("sending text file", error),
             }
         }
+        "send_voice_note" => {
+            let content = match require_string_arg(args, "content") {
+                Ok(content) => content,
+                Err(err) => return Some(err),
+            };
+            let voice = args
+                .get("voice")
+                .and_then(Value::as_str)
+                .filter(|value| !value.is_empty())
+                .unwrap_or(&config.voice.voice);
+
+            send_voice_note(content, voice, base_path, config, channel_id).await
+        }
+        "send_email" => send_email_reply(args, base_path, config).await,
+        "send_push" => {
+            let message = match require_string_arg(args, "message") {
+                Ok(message) => message,
+                Err(err) => return Some(err),
+            };
+            let push = crate::ntfy::PushMessage {
+                title: args
+                    .get("title")
+                    .and_then(Value::as_str)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string),
+                message: message.to_string(),
+                priority: args
+                    .get("priority")
+                    .and_then(Value::as_u64)
+                    .map(|priority| priority.clamp(1, 5) as u8),
+                tags: Vec::new(),
+            };
+
+            match crate::ntfy::publish(&config.ntfy, &push).await {
+                Ok(()) => delivery_success(format!("Sent push notification to ntfy topic {}.", config.ntfy.topic)),
+                Err(error) => delivery_error("sending push notification", error),
+            }
+        }
         _ => return None,
     };
 
@@ -508,7 +746,7 @@ pub(crate) async fn dispatch_delivery_tool(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DiscordConfig, GeminiConfig, RuntimeConfig};
+    use crate::config::{Config, DiscordConfig, GeminiConfig};
     use tempfile::tempdir;
 
     fn test_config() -> Config {
@@ -516,13 +754,15 @@ mod tests {
             gemini: GeminiConfig {
                 api_key: "fake".to_string(),
                 model: "fake".to_string(),
+                temperature: None,
             },
             discord: DiscordConfig {
                 token: "fake".to_string(),
                 guild_id: None,
                 channel_mappings: None,
+                ..Default::default()
             },
-            runtime: RuntimeConfig::default(),
+            ..Default::default()
         }
     }
 
@@ -539,6 +779,73 @@ mod tests {
         assert_eq!(written, "hello");
     }
 
+    #[test]
+    fn test_encode_wav_writes_riff_header_for_pcm_payload() {
+        let wav = encode_wav(&[0u8; 8], 24_000);
+
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 24_000);
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 8);
+        assert_eq!(wav.len(), 52);
+    }
+
+    #[tokio::test]
+    async fn test_send_voice_note_rejects_content_over_limit() {
+        let dir = tempdir().unwrap();
+        let mut config = test_config();
+        config.voice.max_chars = 5;
+        let result = dispatch_delivery_tool(
+            "send_voice_note",
+            &json!({ "content": "far too long to speak" }),
+            dir.path(),
+            &config,
+            "123",
+        )
+        .await
+        .unwrap();
+
+        assert!(result.is_error);
+        assert!(result.output.contains("limited to 5 characters"));
+    }
+
+    #[tokio::test]
+    async fn test_send_email_requires_email_config_and_email_thread() {
+        let dir = tempdir().unwrap();
+        let config = test_config();
+        let args = json!({ "to": "ada@example.com", "subject": "Hi", "content": "Hello" });
+        let result = dispatch_delivery_tool("send_email", &args, dir.path(), &config, "123")
+            .await
+            .unwrap();
+        assert!(result.is_error);
+        assert!(result.output.contains("email is not configured"));
+
+        let mut args = args;
+        args["thread"] = json!("general-123456/2026-03-04.md");
+        let result = dispatch_delivery_tool("send_email", &args, dir.path(), &config, "123")
+            .await
+            .unwrap();
+        assert!(result.is_error);
+        assert!(result.output.contains("must be an email thread"));
+    }
+
+    #[tokio::test]
+    async fn test_send_push_requires_ntfy_config() {
+        let dir = tempdir().unwrap();
+        let result = dispatch_delivery_tool(
+            "send_push",
+            &json!({ "message": "Backup finished" }),
+            dir.path(),
+            &test_config(),
+            "123",
+        )
+        .await
+        .unwrap();
+
+        assert!(result.is_error);
+        assert!(result.output.contains("ntfy is not configured"));
+    }
+
     #[test]
     fn test_path_label_uses_fallback_when_file_name_is_missing() {
         assert_eq!(path_label(Path::new("/"), "artifact.txt"), "artifact.txt");
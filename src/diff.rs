@@ -0,0 +1,156 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/diff.rs
+ * Responsibility: The `diff` core tool comparing guild files or literal text as unified diffs.
+ */
+
+use crate::tools::{ToolExecutionResult, is_path_safe, normalize_path};
+use serde_json::{Value, json};
+use similar::{ChangeTag, TextDiff};
+use std::path::Path;
+
+pub const DIFF_TOOL_NAME: &str = "diff";
+const DEFAULT_CONTEXT_LINES: usize = 3;
+
+/// Unified diff of `old` against `new`, or None when they are identical.
+/// The result is accepted by the `patch` tool.
+pub fn unified_diff(
+    old: &str,
+    new: &str,
+    old_label: &str,
+    new_label: &str,
+    context: usize,
+) -> Option<String> {
+    let diff = TextDiff::from_lines(old, new);
+    let (mut added, mut removed) = (0, 0);
+    for change in diff.iter_all_changes() {
+        match change.tag() {
+            ChangeTag::Insert => added += 1,
+            ChangeTag::Delete => removed += 1,
+            ChangeTag::Equal => {}
+        }
+    }
+    if added == 0 && removed == 0 {
+        return None;
+    }
+    let body = diff
+        .unified_diff()
+        .context_radius(context)
+        .header(old_label, new_label)
+        .to_string();
+    // The summary leads so the whole output still parses as a patch.
+    Some(format!(
+        "{} added, {} removed\n{}",
+        added,
+        removed,
+        body.trim_end()
+    ))
+}
+
+/// One side of the comparison: a guild file (`<side>`) or literal text (`<side>Text`).
+fn load_side(args: &Value, side: &str, base_path: &Path) -> Result<(String, String), String> {
+    let text_field = format!("{}Text", side);
+    if let Some(text) = args.get(&text_field).and_then(Value::as_str) {
+        return Ok((text.to_string(), format!("{} (text)", side)));
+    }
+    let Some(rel_path) = args
+        .get(side)
+        .and_then(Value::as_str)
+        .map(normalize_path)
+        .filter(|path| !path.is_empty())
+    else {
+        return Err(format!("provide `{}` (a file) or `{}`", side, text_field));
+    };
+    if !is_path_safe(base_path, rel_path) {
+        return Err("Access denied. Path must be within the guild directory.".to_string());
+    }
+    std::fs::read_to_string(base_path.join(rel_path))
+        .map(|content| (content, rel_path.to_string()))
+        .map_err(|e| format!("cannot read {}: {}", rel_path, e))
+}
+
+pub(crate) fn diff_tool_definition() -> Value {
+    json!({
+        "name": DIFF_TOOL_NAME,
+        "description": "Show a unified diff between two guild files, two texts, or a file and a text (e.g. a blackboard before and after an edit). The output can be applied with the patch tool.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "from": { "type": "string", "description": "Original file, relative to guild root" },
+                "fromText": { "type": "string", "description": "Original text, instead of a file" },
+                "to": { "type": "string", "description": "Changed file, relative to guild root" },
+                "toText": { "type": "string", "description": "Changed text, instead of a file" },
+                "context": { "type": "number", "description": "Unchanged lines shown around each change. Defaults to 3" }
+            }
+        }
+    })
+}
+
+pub(crate) fn run_diff_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let (old, old_label) = match load_side(args, "from", base_path) {
+        Ok(side) => side,
+        Err(error) => return ToolExecutionResult::error(format!("Error: {}", error)),
+    };
+    let (new, new_label) = match load_side(args, "to", base_path) {
+        Ok(side) => side,
+        Err(error) => return ToolExecutionResult::error(format!("Error: {}", error)),
+    };
+    let context = args
+        .get("context")
+        .and_then(Value::as_u64)
+        .map(|lines| lines as usize)
+        .unwrap_or(DEFAULT_CONTEXT_LINES);
+
+    match unified_diff(&old, &new, &old_label, &new_label, context) {
+        Some(diff) => ToolExecutionResult::success(diff),
+        None => ToolExecutionResult::success(format!(
+            "No differences between {} and {}.",
+            old_label, new_label
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_diff_tool_compares_file_and_text_and_round_trips_through_patch() {
+        let dir = tempdir().unwrap();
+        let before = "# Board\n- milk\n- eggs\n- bread\n";
+        std::fs::write(dir.path().join("board.md"), before).unwrap();
+        let after = "# Board\n- milk\n- butter\n- bread\n";
+
+        let result = run_diff_tool(
+            &json!({ "from": "board.md", "toText": after, "context": 1 }),
+            dir.path(),
+        );
+        assert!(!result.is_error, "{}", result.output);
+        assert_eq!(
+            result.output,
+            "1 added, 1 removed\n--- board.md\n+++ to (text)\n@@ -2,3 +2,3 @@\n - milk\n-- eggs\n+- butter\n - bread"
+        );
+        assert_eq!(
+            crate::patch::apply_unified_diff(before, &result.output).unwrap(),
+            after
+        );
+    }
+
+    #[test]
+    fn test_diff_tool_reports_identical_inputs_and_guards_paths() {
+        let dir = tempdir().unwrap();
+        let same = run_diff_tool(&json!({ "fromText": "a\n", "toText": "a\n" }), dir.path());
+        assert_eq!(
+            same.output,
+            "No differences between from (text) and to (text)."
+        );
+
+        let escaped = run_diff_tool(
+            &json!({ "from": "../etc/passwd", "toText": "" }),
+            dir.path(),
+        );
+        assert!(escaped.is_error);
+        assert!(run_diff_tool(&json!({ "fromText": "a" }), dir.path()).is_error);
+    }
+}
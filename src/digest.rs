@@ -0,0 +1,327 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/digest.rs
+ * Responsibility: The daily digest: what the steward did across all channels, saved and posted.
+ */
+
+use crate::config::{Config, Language};
+use crate::i18n::Message;
+use crate::memory::{self, MemoryEntry};
+use crate::trace_log::{self, TraceRecord};
+use crate::{log_info, log_warn};
+use chrono::{Local, NaiveDate};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DIGEST_DIR: &str = "brain/digests";
+const MAX_LISTED: usize = 10;
+const MAX_LINE_CHARS: usize = 160;
+
+fn one_line(text: &str) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= MAX_LINE_CHARS {
+        return line;
+    }
+    let mut clipped: String = line.chars().take(MAX_LINE_CHARS).collect();
+    clipped.push('…');
+    clipped
+}
+
+fn channel_label(base_path: &Path, channel_id: &str) -> String {
+    if channel_id == "0" {
+        return "local".to_string();
+    }
+    crate::discord::resolve_folder_by_id(base_path, channel_id)
+        .map(|folder| format!("#{}", folder))
+        .unwrap_or_else(|| format!("channel {}", channel_id))
+}
+
+fn time_of(record: &TraceRecord) -> &str {
+    record.timestamp.get(11..16).unwrap_or("")
+}
+
+fn push_list(out: &mut String, items: &[String], language: Language) {
+    for item in items.iter().take(MAX_LISTED) {
+        out.push_str(&format!("- {}\n", item));
+    }
+    if items.len() > MAX_LISTED {
+        let more = Message::MoreListed {
+            count: items.len() - MAX_LISTED,
+        };
+        out.push_str(&format!("- {}\n", more.render(language)));
+    }
+}
+
+/// Facts stored with `remember` or `note_entity` on `date`, with the file they went to.
+fn knowledge_added(base_path: &Path, date: &str) -> Vec<String> {
+    let mut added = Vec::new();
+    for path in memory::knowledge_files(base_path) {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let rel = path
+            .strip_prefix(base_path)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        added.extend(
+            content
+                .lines()
+                .filter_map(MemoryEntry::parse)
+                .filter(|entry| entry.field("added").is_some_and(|at| at.starts_with(date)))
+                .map(|entry| format!("{} ({})", one_line(&entry.fact), rel)),
+        );
+    }
+    added
+}
+
+/// Render the digest for `date` from the execution traces, skill audit, and knowledge files.
+pub fn build_digest(base_path: &Path, date: NaiveDate, language: Language) -> String {
+    let day = date.format("%Y-%m-%d").to_string();
+    let traces: Vec<TraceRecord> = trace_log::load_day_traces(base_path, date)
+        .into_iter()
+        .filter(|record| {
+            !record
+                .tool_calls
+                .iter()
+                .any(|call| call.tool == trace_log::EXPLAIN_TOOL_NAME)
+        })
+        .collect();
+    let conversations = traces
+        .iter()
+        .filter(|r| r.label == "Conversational")
+        .count();
+    let ritual_steps: Vec<&TraceRecord> = traces.iter().filter(|r| r.label == "Ritual").collect();
+
+    let mut states: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut per_channel: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for record in &traces {
+        *states.entry(record.final_state.as_str()).or_default() += 1;
+        let entry = per_channel
+            .entry(channel_label(base_path, &record.channel_id))
+            .or_default();
+        entry.0 += 1;
+        if record.final_state == "Failed" {
+            entry.1 += 1;
+        }
+    }
+
+    let mut errors: Vec<String> = traces
+        .iter()
+        .filter(|record| record.final_state == "Failed")
+        .map(|record| {
+            format!(
+                "{} {} — {}: {}",
+                time_of(record),
+                channel_label(base_path, &record.channel_id),
+                one_line(&record.request),
+                one_line(&record.response)
+            )
+        })
+        .collect();
+    errors.extend(
+        crate::skill_audit::load_records(base_path, 2)
+            .into_iter()
+            .filter(|record| record.timestamp.starts_with(&day))
+            .filter(|record| record.exit_code != Some(0) || record.error.is_some())
+            .map(|record| {
+                let error = record.error.as_deref().map(one_line);
+                Message::DigestSkillFailed {
+                    time: record.timestamp.get(11..16).unwrap_or(""),
+                    skill: &record.skill,
+                    tool: &record.tool,
+                    error: error.as_deref(),
+                }
+                .render(language)
+            }),
+    );
+
+    let mut out = format!(
+        "{}\n",
+        Message::DigestHeading { day: &day }.render(language)
+    );
+    let requests = Message::DigestRequests {
+        total: traces.len(),
+        conversations,
+        ritual_steps: ritual_steps.len(),
+    };
+    out.push_str(&format!("- {}\n", requests.render(language)));
+    if !states.is_empty() {
+        let outcomes: Vec<String> = states
+            .iter()
+            .map(|(state, count)| format!("{} {}", state, count))
+            .collect();
+        let outcomes = Message::DigestOutcomes {
+            outcomes: &outcomes.join(", "),
+        };
+        out.push_str(&format!("- {}\n", outcomes.render(language)));
+    }
+
+    if !per_channel.is_empty() {
+        out.push_str(&format!("\n{}\n", Message::DigestChannels.render(language)));
+        let lines: Vec<String> = per_channel
+            .iter()
+            .map(|(channel, (handled, failed))| {
+                Message::DigestChannelRequests {
+                    channel,
+                    handled: *handled,
+                    failed: *failed,
+                }
+                .render(language)
+            })
+            .collect();
+        push_list(&mut out, &lines, language);
+    }
+
+    if !ritual_steps.is_empty() {
+        out.push_str(&format!("\n{}\n", Message::DigestRituals.render(language)));
+        let lines: Vec<String> = ritual_steps
+            .iter()
+            .map(|record| {
+                format!(
+                    "{} {} ({})",
+                    time_of(record),
+                    one_line(&record.request),
+                    record.final_state
+                )
+            })
+            .collect();
+        push_list(&mut out, &lines, language);
+    }
+
+    let none = format!("- {}\n", Message::NoneListed.render(language));
+    out.push_str(&format!("\n{}\n", Message::DigestErrors.render(language)));
+    if errors.is_empty() {
+        out.push_str(&none);
+    } else {
+        push_list(&mut out, &errors, language);
+    }
+
+    let knowledge = knowledge_added(base_path, &day);
+    out.push_str(&format!(
+        "\n{}\n",
+        Message::DigestKnowledge.render(language)
+    ));
+    if knowledge.is_empty() {
+        out.push_str(&none);
+    } else {
+        push_list(&mut out, &knowledge, language);
+    }
+    out
+}
+
+fn digest_path(base_path: &Path, date: NaiveDate) -> PathBuf {
+    base_path
+        .join(DIGEST_DIR)
+        .join(format!("{}.md", date.format("%Y-%m-%d")))
+}
+
+/// Build today's digest, save it under `brain/digests/`, and post it to `digest.channel_id`.
+pub async fn run_daily_digest(base_path: &Path, config: &Config) {
+    let date = Local::now().date_naive();
+    let digest = build_digest(base_path, date, config.runtime.language);
+    let path = digest_path(base_path, date);
+    let saved =
+        fs::create_dir_all(base_path.join(DIGEST_DIR)).and_then(|_| fs::write(&path, &digest));
+    match saved {
+        Ok(_) => log_info!(
+            "guardian",
+            "📰 Daily digest saved to {}/{:?}",
+            DIGEST_DIR,
+            path.file_name().unwrap_or_default()
+        ),
+        Err(e) => log_warn!("guardian", "⚠️ Failed to save daily digest: {}", e),
+    }
+
+    if let Some(channel_id) = &config.digest.channel_id
+        && let Err(e) =
+            crate::discord::client::send_bot_message(&config.discord.token, channel_id, &digest)
+                .await
+    {
+        log_warn!(
+            "guardian",
+            "⚠️ Failed to post daily digest to {}: {:?}",
+            channel_id,
+            e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace_log::TracedToolCall;
+    use tempfile::tempdir;
+
+    fn trace(channel_id: &str, label: &str, request: &str, final_state: &str) -> TraceRecord {
+        TraceRecord {
+            timestamp: "2026-03-01T09:30:00+00:00".to_string(),
+            channel_id: channel_id.to_string(),
+            label: label.to_string(),
+            request: request.to_string(),
+            intent: "ToolExecution".to_string(),
+            confidence: "High".to_string(),
+            final_state: final_state.to_string(),
+            steps: Vec::new(),
+            tool_calls: vec![TracedToolCall {
+                tool: "ls".to_string(),
+                args: "{}".to_string(),
+                succeeded: Some(final_state != "Failed"),
+            }],
+            response: format!("{} response", request),
+        }
+    }
+
+    #[test]
+    fn test_build_digest_summarizes_day_across_channels() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("channels/general-123456")).unwrap();
+        fs::create_dir_all(base.join(trace_log::TRACE_DIR)).unwrap();
+        let lines: Vec<String> = [
+            trace("999123456", "Conversational", "list files", "Completed"),
+            trace("999123456", "Conversational", "fetch the weather", "Failed"),
+            trace("0", "Ritual", "Water the plants", "Completed"),
+        ]
+        .iter()
+        .map(|record| serde_json::to_string(record).unwrap())
+        .collect();
+        fs::write(
+            base.join(trace_log::TRACE_DIR).join("2026-03-01.jsonl"),
+            lines.join("\n"),
+        )
+        .unwrap();
+        fs::create_dir_all(base.join("brain")).unwrap();
+        fs::write(
+            base.join("brain/KNOWLEDGE.md"),
+            "## Remembered\n\n- Bins go out Tuesday <!-- memory: id=m1; added=2026-03-01 10:00 -->\n- Old fact <!-- memory: id=m2; added=2026-02-01 10:00 -->\n",
+        )
+        .unwrap();
+
+        let day = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let digest = build_digest(base, day, Language::En);
+        assert!(digest.starts_with("# Daily Digest — 2026-03-01"));
+        assert!(digest.contains("- Requests handled: 3 (2 conversation(s), 1 ritual step(s))"));
+        assert!(digest.contains("- Outcomes: Completed 2, Failed 1"));
+        assert!(digest.contains("- #general-123456: 2 request(s), 1 failed"));
+        assert!(digest.contains("- 09:30 Water the plants (Completed)"));
+        assert!(digest.contains("fetch the weather: fetch the weather response"));
+        assert!(digest.contains("- Bins go out Tuesday (brain/KNOWLEDGE.md)"));
+        assert!(!digest.contains("Old fact"));
+
+        let quiet = build_digest(
+            base,
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            Language::En,
+        );
+        assert!(quiet.contains("- Requests handled: 0"));
+        assert!(quiet.contains("## Errors\n- None"));
+
+        let chinese = build_digest(base, day, Language::Zh);
+        assert!(chinese.starts_with("# 每日摘要 — 2026-03-01\n\n## 活动\n"));
+        assert!(chinese.contains("- 处理请求：3（对话 2 次，仪式步骤 1 个）"));
+        assert!(chinese.contains("- #general-123456：2 个请求，1 个失败"));
+        assert!(!chinese.contains("Requests handled"));
+    }
+}
@@ -4,13 +4,20 @@
  * Responsibility: Outbound Discord messaging helpers and payload chunking.
  */
 
+use crate::{log_info, log_warn};
 use once_cell::sync::Lazy;
 use serenity::all::CreateAttachment;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-static HTTP_CHANNEL_CLIENT: Lazy<Arc<RwLock<Option<(String, Arc<serenity::http::Http>)>>>> =
+type CachedHttpClient = Option<(String, Arc<serenity::http::Http>)>;
+
+/// Discord JSON error codes meaning the payload itself was rejected:
+/// 50035 (Invalid Form Body, e.g. content too long) and 40005 (request entity too large).
+const CONTENT_REJECTION_CODES: [isize; 2] = [50035, 40005];
+
+static HTTP_CHANNEL_CLIENT: Lazy<Arc<RwLock<CachedHttpClient>>> =
     Lazy::new(|| Arc::new(RwLock::new(None)));
 
 fn split_message_chunks(content: &str, max_length: usize) -> Vec<String> {
@@ -87,11 +94,26 @@ async fn get_http_client(token: &str) -> Arc<serenity::http::Http> {
     }
 }
 
+/// In a dry run, log the post and hand back an empty message instead of sending it.
+fn simulated_send(
+    channel_id: &str,
+    detail: serde_json::Value,
+) -> Option<serenity::model::channel::Message> {
+    if !crate::dry_run::is_enabled() {
+        return None;
+    }
+    crate::dry_run::record("discord post to", channel_id, detail);
+    Some(serenity::model::channel::Message::default())
+}
+
 pub async fn send_bot_message(
     token: &str,
     channel_id: &str,
     content: &str,
 ) -> anyhow::Result<serenity::model::channel::Message> {
+    if let Some(simulated) = simulated_send(channel_id, serde_json::json!(content)) {
+        return Ok(simulated);
+    }
     if token.is_empty() {
         return Err(anyhow::anyhow!("Discord token is empty"));
     }
@@ -108,15 +130,18 @@ pub async fn send_bot_message(
     let mut last_msg = None;
     let chunks = split_message_chunks(content, max_length);
     if chunks.len() > 1 {
-        println!(
+        log_info!(
+            "discord",
             "✂️ Content length {} exceeds Discord limit, chunking...",
             content.len()
         );
     }
 
-    for chunk in chunks {
+    for (delivered, chunk) in chunks.into_iter().enumerate() {
         let map = serde_json::json!({ "content": chunk });
-        last_msg = Some(http.send_message(c_id.into(), vec![], &map).await?);
+        let sent = http.send_message(c_id.into(), vec![], &map).await;
+        crate::api_health::record(crate::api_health::Service::Discord, sent.is_ok());
+        last_msg = Some(sent.map_err(|e| mark_partial_delivery(e.into(), delivered))?);
         if last_msg.is_some() {
             tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         }
@@ -139,13 +164,17 @@ pub async fn send_code_block_message(
     }
 
     let mut last_msg = None;
-    for chunk in chunks {
+    for (delivered, chunk) in chunks.into_iter().enumerate() {
         let fenced = if language.is_empty() {
             format!("```\n{}\n```", chunk)
         } else {
             format!("```{}\n{}\n```", language, chunk)
         };
-        last_msg = Some(send_bot_message(token, channel_id, &fenced).await?);
+        last_msg = Some(
+            send_bot_message(token, channel_id, &fenced)
+                .await
+                .map_err(|e| mark_partial_delivery(e, delivered))?,
+        );
     }
 
     last_msg.ok_or_else(|| anyhow::anyhow!("Failed to send any code block chunks"))
@@ -157,6 +186,9 @@ pub async fn send_reply_message(
     message_id: &str,
     content: &str,
 ) -> anyhow::Result<serenity::model::channel::Message> {
+    if let Some(simulated) = simulated_send(channel_id, serde_json::json!(content)) {
+        return Ok(simulated);
+    }
     if token.is_empty() {
         return Err(anyhow::anyhow!("Discord token is empty"));
     }
@@ -191,6 +223,12 @@ pub async fn send_embed_message(
     description: &str,
     color: Option<u32>,
 ) -> anyhow::Result<serenity::model::channel::Message> {
+    if let Some(simulated) = simulated_send(
+        channel_id,
+        serde_json::json!({ "title": title, "description": description }),
+    ) {
+        return Ok(simulated);
+    }
     if token.is_empty() {
         return Err(anyhow::anyhow!("Discord token is empty"));
     }
@@ -223,6 +261,12 @@ pub async fn send_file_attachment(
     channel_id: &str,
     file_path: &Path,
 ) -> anyhow::Result<serenity::model::channel::Message> {
+    if let Some(simulated) = simulated_send(
+        channel_id,
+        serde_json::json!({ "file": file_path.display().to_string() }),
+    ) {
+        return Ok(simulated);
+    }
     if token.is_empty() || channel_id.is_empty() || channel_id == "0" {
         return Err(anyhow::anyhow!("Invalid parameters for file upload"));
     }
@@ -231,7 +275,8 @@ pub async fn send_file_attachment(
         return Err(anyhow::anyhow!("File not found: {:?}", file_path));
     }
 
-    println!(
+    log_info!(
+        "discord",
         "📡 Uploading file {:?} to Discord channel {}...",
         file_path, channel_id
     );
@@ -245,7 +290,14 @@ pub async fn send_file_attachment(
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create attachment: {}", e))?;
 
-    let map = serde_json::json!({ "content": format!("📎 Attached file: `{}`", file_path.file_name().and_then(|s| s.to_str()).unwrap_or("file")) });
+    let notice = crate::i18n::Message::AttachedFile {
+        name: file_path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or("file"),
+    }
+    .render(crate::i18n::language());
+    let map = serde_json::json!({ "content": notice });
     let msg = http
         .send_message(c_id.into(), vec![attachment], &map)
         .await?;
@@ -253,8 +305,128 @@ pub async fn send_file_attachment(
     Ok(msg)
 }
 
-pub async fn broadcast_typing(token: &str, channel_id: &str) -> anyhow::Result<()> {
+/// Context attached to a send that failed after some of its chunks were already posted.
+#[derive(Debug)]
+struct PartialDelivery {
+    delivered: usize,
+}
+
+impl std::fmt::Display for PartialDelivery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "send failed after {} message(s) were delivered", self.delivered)
+    }
+}
+
+fn mark_partial_delivery(error: anyhow::Error, delivered: usize) -> anyhow::Error {
+    if delivered == 0 || is_partial_delivery(&error) {
+        error
+    } else {
+        error.context(PartialDelivery { delivered })
+    }
+}
+
+fn is_partial_delivery(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<PartialDelivery>().is_some()
+}
+
+/// True when Discord refused a message because of its content (length, form body, size),
+/// as opposed to auth, permission, or network failures. Only the structured Discord
+/// response counts; the error text is never inspected.
+pub fn is_content_rejection(error: &anyhow::Error) -> bool {
+    match error.downcast_ref::<serenity::Error>() {
+        Some(serenity::Error::Http(serenity::http::HttpError::UnsuccessfulRequest(response))) => {
+            CONTENT_REJECTION_CODES.contains(&response.error.code)
+                || response.status_code.as_u16() == 413
+        }
+        _ => false,
+    }
+}
+
+/// Upload `content` as an in-memory text attachment with a one-line notice.
+pub async fn send_text_attachment(
+    token: &str,
+    channel_id: &str,
+    filename: &str,
+    content: &str,
+    notice: &str,
+) -> anyhow::Result<serenity::model::channel::Message> {
+    if let Some(simulated) = simulated_send(
+        channel_id,
+        serde_json::json!({ "file": filename, "notice": notice, "content": content }),
+    ) {
+        return Ok(simulated);
+    }
     if token.is_empty() || channel_id.is_empty() || channel_id == "0" {
+        return Err(anyhow::anyhow!("Invalid parameters for file upload"));
+    }
+
+    let http = get_http_client(token).await;
+    let c_id = channel_id
+        .parse::<u64>()
+        .map_err(|_| anyhow::anyhow!("Invalid channel ID: {}", channel_id))?;
+
+    let attachment = CreateAttachment::bytes(content.as_bytes().to_vec(), filename);
+    let map = serde_json::json!({ "content": notice });
+    let msg = http
+        .send_message(c_id.into(), vec![attachment], &map)
+        .await?;
+    Ok(msg)
+}
+
+/// Run a text send and, if Discord rejects the content itself, resend the original
+/// content as `response.md` instead of surfacing the raw error. A send that already
+/// posted some chunks is not retried, so the channel never sees the content twice.
+pub async fn with_text_file_fallback<F>(
+    token: &str,
+    channel_id: &str,
+    content: &str,
+    send: F,
+) -> anyhow::Result<serenity::model::channel::Message>
+where
+    F: std::future::Future<Output = anyhow::Result<serenity::model::channel::Message>>,
+{
+    match send.await {
+        Ok(msg) => Ok(msg),
+        Err(error) if is_content_rejection(&error) && !is_partial_delivery(&error) => {
+            log_warn!(
+                "discord",
+                "✂️ Discord rejected message content in {} ({}), falling back to a text file.",
+                channel_id, error
+            );
+            send_text_attachment(
+                token,
+                channel_id,
+                "response.md",
+                content,
+                &crate::i18n::Message::ReplyAttached.render(crate::i18n::language()),
+            )
+            .await
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// `send_bot_message` with the automatic text-file fallback for rejected content.
+pub async fn send_bot_message_or_file(
+    token: &str,
+    channel_id: &str,
+    content: &str,
+) -> anyhow::Result<serenity::model::channel::Message> {
+    with_text_file_fallback(
+        token,
+        channel_id,
+        content,
+        send_bot_message(token, channel_id, content),
+    )
+    .await
+}
+
+pub async fn broadcast_typing(token: &str, channel_id: &str) -> anyhow::Result<()> {
+    if token.is_empty()
+        || channel_id.is_empty()
+        || channel_id == "0"
+        || crate::dry_run::is_enabled()
+    {
         return Ok(());
     }
 
@@ -267,9 +439,44 @@ pub async fn broadcast_typing(token: &str, channel_id: &str) -> anyhow::Result<(
     Ok(())
 }
 
+pub async fn add_reaction(
+    token: &str,
+    channel_id: &str,
+    message_id: &str,
+    emoji: &str,
+) -> anyhow::Result<()> {
+    if crate::dry_run::is_enabled() {
+        crate::dry_run::record(
+            "discord reaction in",
+            channel_id,
+            serde_json::json!({ "message_id": message_id, "emoji": emoji }),
+        );
+        return Ok(());
+    }
+    if token.is_empty() || channel_id.is_empty() || channel_id == "0" {
+        return Err(anyhow::anyhow!("Invalid parameters for reaction"));
+    }
+
+    let http = get_http_client(token).await;
+    let c_id = channel_id
+        .parse::<u64>()
+        .map_err(|_| anyhow::anyhow!("Invalid channel ID: {}", channel_id))?;
+    let m_id = message_id
+        .parse::<u64>()
+        .map_err(|_| anyhow::anyhow!("Invalid message ID: {}", message_id))?;
+
+    let reaction = serenity::model::channel::ReactionType::Unicode(emoji.to_string());
+    http.create_reaction(c_id.into(), m_id.into(), &reaction)
+        .await?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{split_code_block_chunks, split_message_chunks};
+    use super::{
+        is_content_rejection, is_partial_delivery, mark_partial_delivery, split_code_block_chunks,
+        split_message_chunks,
+    };
 
     #[test]
     fn test_split_message_chunks_prefers_newline_boundaries() {
@@ -297,4 +504,26 @@ mod tests {
             vec!["abcd", "efgh", "ijkl", "mnop", "qrst", "uvwx", "yz"]
         );
     }
+
+    #[test]
+    fn test_is_content_rejection_ignores_error_text() {
+        assert!(!is_content_rejection(&anyhow::anyhow!(
+            "Invalid Form Body (code 50035): content must be 2000 or fewer in length"
+        )));
+        assert!(!is_content_rejection(&anyhow::anyhow!("Missing Permissions")));
+        assert!(!is_content_rejection(&anyhow::anyhow!("Discord token is empty")));
+    }
+
+    #[test]
+    fn test_mark_partial_delivery_only_after_a_chunk_was_sent() {
+        assert!(!is_partial_delivery(&mark_partial_delivery(anyhow::anyhow!("boom"), 0)));
+
+        let partial = mark_partial_delivery(anyhow::anyhow!("boom"), 2);
+        assert!(is_partial_delivery(&partial));
+        assert_eq!(partial.chain().count(), 2);
+
+        let nested = mark_partial_delivery(partial, 1);
+        assert_eq!(nested.chain().count(), 2);
+        assert!(nested.to_string().contains("after 2 message(s)"));
+    }
 }
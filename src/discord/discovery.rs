@@ -0,0 +1,158 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/discord/discovery.rs
+ * Responsibility: Periodic guild channel discovery and live reconciliation of channel mappings.
+ */
+
+use super::{fetch_guild_channels, resolve_folder_by_id};
+use crate::{log_info, log_warn};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Never poll Discord more often than this, whatever the config says.
+const MIN_REFRESH_SECS: u64 = 60;
+
+/// Merge freshly discovered channels into the live mappings. Existing entries
+/// (manual mappings, renamed folders, dynamic mappings) always win; only channels
+/// that are not yet mapped are added. Returns the newly added `(channel_id, folder)` pairs.
+pub(crate) fn reconcile_mappings(
+    current: &mut HashMap<String, String>,
+    discovered: HashMap<String, String>,
+) -> Vec<(String, String)> {
+    let mut added = Vec::new();
+    for (channel_id, folder) in discovered {
+        if current.contains_key(&channel_id) {
+            continue;
+        }
+        current.insert(channel_id.clone(), folder.clone());
+        added.push((channel_id, folder));
+    }
+    added.sort();
+    added
+}
+
+/// Prefer a folder that already exists on disk for the channel ID, so a renamed
+/// channel keeps writing into its original blackboard folder.
+fn anchor_to_existing_folders(
+    workspace_path: &Path,
+    discovered: HashMap<String, String>,
+) -> HashMap<String, String> {
+    discovered
+        .into_iter()
+        .map(|(channel_id, folder)| {
+            let folder = resolve_folder_by_id(workspace_path, &channel_id).unwrap_or(folder);
+            (channel_id, folder)
+        })
+        .collect()
+}
+
+/// Mirror and merge a set of discovered text channels into the shared mappings.
+pub(crate) async fn apply_discovered_channels(
+    workspace_path: &Path,
+    mappings: &RwLock<HashMap<String, String>>,
+    discovered: HashMap<String, String>,
+) -> anyhow::Result<usize> {
+    let discovered = anchor_to_existing_folders(workspace_path, discovered);
+    let added = {
+        let mut map = mappings.write().await;
+        reconcile_mappings(&mut map, discovered)
+    };
+
+    let new_folders: HashMap<String, String> = added.iter().cloned().collect();
+    crate::mirror_guild_structure(workspace_path, &new_folders)?;
+    for (channel_id, folder) in &added {
+        log_info!(
+            "discord",
+            "🔄 Discovered channel #{} -> {}",
+            channel_id,
+            folder
+        );
+    }
+    Ok(added.len())
+}
+
+/// Fetch the guild's text channels over HTTP and reconcile them into the mappings.
+pub async fn refresh_guild_channels(
+    token: &str,
+    guild_id: &str,
+    workspace_path: &Path,
+    mappings: &RwLock<HashMap<String, String>>,
+) -> anyhow::Result<usize> {
+    let discovered = fetch_guild_channels(token, guild_id).await?;
+    apply_discovered_channels(workspace_path, mappings, discovered).await
+}
+
+/// Re-run guild discovery every `interval_secs` until the process exits.
+pub async fn start_discovery_refresh(
+    token: String,
+    guild_id: String,
+    workspace_path: PathBuf,
+    mappings: Arc<RwLock<HashMap<String, String>>>,
+    interval_secs: u64,
+) {
+    let period = Duration::from_secs(interval_secs.max(MIN_REFRESH_SECS));
+    log_info!(
+        "discord",
+        "🔄 Channel discovery refresh every {}s for Guild: {}",
+        period.as_secs(),
+        guild_id
+    );
+
+    let mut interval = tokio::time::interval(period);
+    // The first tick fires immediately; startup discovery already covered it.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        if let Err(e) = refresh_guild_channels(&token, &guild_id, &workspace_path, &mappings).await
+        {
+            log_warn!("discord", "⚠️ Channel discovery refresh failed: {:?}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_reconcile_mappings_keeps_existing_entries() {
+        let mut current = HashMap::from([("111".to_string(), "manual-folder".to_string())]);
+        let discovered = HashMap::from([
+            ("111".to_string(), "general-000111".to_string()),
+            ("222".to_string(), "ops-000222".to_string()),
+        ]);
+
+        let added = reconcile_mappings(&mut current, discovered);
+        assert_eq!(added, vec![("222".to_string(), "ops-000222".to_string())]);
+        assert_eq!(current["111"], "manual-folder");
+        assert_eq!(current["222"], "ops-000222");
+    }
+
+    #[tokio::test]
+    async fn test_apply_discovered_channels_mirrors_new_folders_and_anchors_renames() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("channels/old-name-654321")).unwrap();
+        let mappings = RwLock::new(HashMap::new());
+
+        let added = apply_discovered_channels(
+            dir.path(),
+            &mappings,
+            HashMap::from([
+                ("111654321".to_string(), "new-name-654321".to_string()),
+                ("222123456".to_string(), "fresh-123456".to_string()),
+            ]),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(added, 2);
+        let map = mappings.read().await;
+        assert_eq!(map["111654321"], "old-name-654321");
+        assert!(dir.path().join("channels/fresh-123456").is_dir());
+        assert!(!dir.path().join("channels/new-name-654321").exists());
+    }
+}
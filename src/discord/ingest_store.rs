@@ -7,7 +7,8 @@
 use std::fs;
 use std::path::{Component, Path, PathBuf};
 
-pub fn append_to_message_log(
+#[allow(clippy::too_many_arguments)]
+pub async fn append_to_message_log(
     workspace_path: &Path,
     thread_id: &str,
     author_name: &str,
@@ -21,13 +22,6 @@ pub fn append_to_message_log(
     let file_path = resolve_thread_log_path(workspace_path, thread_id)
         .ok_or_else(|| anyhow::anyhow!("Invalid thread target: {}", thread_id))?;
 
-    if !file_path.exists()
-        && let Some(parent) = file_path.parent() {
-            let _ = fs::create_dir_all(parent);
-        }
-
-    let mut content = std::fs::read_to_string(&file_path).unwrap_or_default();
-
     let mut entry = format!(
         "\n---\n**Author**: {} (ID: {}) | **Time**: {} | **Message ID**: {}\n",
         author_name, author_id, timestamp, message_id
@@ -65,8 +59,19 @@ pub fn append_to_message_log(
 
     entry.push_str(&format!("\n{}\n", content_text));
 
-    content.push_str(&entry);
-    std::fs::write(&file_path, content)?;
+    // Reading the log and the journaled rewrite are blocking file IO; keep them off the
+    // async workers, as self_writes does.
+    let workspace_path = workspace_path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        if !file_path.exists()
+            && let Some(parent) = file_path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+        let mut content = fs::read_to_string(&file_path).unwrap_or_default();
+        content.push_str(&entry);
+        crate::journal::write(&workspace_path, &file_path, &content)
+    })
+    .await??;
     Ok(())
 }
 
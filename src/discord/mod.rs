@@ -4,10 +4,11 @@
  * Responsibility: Discord Inscriber. Perception layer powered by Serenity.
  */
 
+use crate::{log_info, log_warn};
 use serenity::async_trait;
-use serenity::model::channel::{GuildChannel, Message};
+use serenity::model::channel::{GuildChannel, Message, Reaction};
 use serenity::model::gateway::{GatewayIntents, Ready};
-use serenity::model::guild::ScheduledEvent;
+use serenity::model::guild::{Guild, ScheduledEvent};
 use serenity::prelude::*; // Added for file uploads
 
 use crate::StewardNotification;
@@ -20,12 +21,18 @@ use tokio::sync::RwLock;
 use tokio::sync::mpsc;
 
 pub mod client;
+pub mod discovery;
 pub mod ingest_store;
+mod permissions;
 
 struct Inscriber {
     workspace_path: PathBuf,
     mappings: Arc<RwLock<HashMap<String, String>>>,
     notif_tx: mpsc::Sender<StewardNotification>,
+    token: String,
+    admin_channel_id: Option<String>,
+    /// `discord.guild_id`; when set, other servers the bot is in are not mirrored.
+    guild_id: Option<String>,
 }
 
 #[async_trait]
@@ -35,6 +42,26 @@ impl EventHandler for Inscriber {
             return;
         }
 
+        if let Some(command) = crate::ritual_control::parse_command(&msg.content) {
+            let reply = match command {
+                crate::ritual_control::RitualCommand::Pause(name) => {
+                    crate::ritual_control::apply(&self.workspace_path, &name, true).await
+                }
+                crate::ritual_control::RitualCommand::Resume(name) => {
+                    crate::ritual_control::apply(&self.workspace_path, &name, false).await
+                }
+                crate::ritual_control::RitualCommand::List => {
+                    crate::ritual_control::list(&self.workspace_path)
+                }
+            };
+            if let Err(e) =
+                client::send_bot_message(&self.token, &msg.channel_id.to_string(), &reply).await
+            {
+                log_warn!("discord", "⚠️ Failed to answer /ritual command: {:?}", e);
+            }
+            return;
+        }
+
         let channel_id_str = msg.channel_id.to_string();
         let folder_name = {
             let mut found = None;
@@ -59,7 +86,8 @@ impl EventHandler for Inscriber {
                             resolved = to_folder_name(&guild_ch.name, &channel_id_str);
                         }
 
-                println!(
+                log_info!(
+                    "discord",
                     "🔍 Dynamically mapped channel: #{} -> {}",
                     channel_id_str, resolved
                 );
@@ -97,7 +125,8 @@ impl EventHandler for Inscriber {
                     attachment_data.push((attachment.url.clone(), Some(local_path)));
                 }
                 Err(e) => {
-                    eprintln!(
+                    log_warn!(
+                        "discord",
                         "⚠️ Failed to download attachment {}: {:?}",
                         attachment.filename, e
                     );
@@ -107,7 +136,8 @@ impl EventHandler for Inscriber {
         }
 
         if is_mention {
-            println!(
+            log_info!(
+                "discord",
                 "📥 Discord mention captured for #{}: {}",
                 folder_name, content
             );
@@ -130,8 +160,11 @@ impl EventHandler for Inscriber {
                 &timestamp,
                 reply_to.clone(),
                 attachment_data.clone(),
-            ) {
-                eprintln!(
+            )
+            .await
+            {
+                log_warn!(
+                    "discord",
                     "⚠️ Failed to append mentioned message {} to local log: {:?}",
                     message_id, error
                 );
@@ -151,7 +184,8 @@ impl EventHandler for Inscriber {
                 })
                 .await
             {
-                eprintln!(
+                log_warn!(
+                    "discord",
                     "⚠️ Failed to enqueue steward notification for message {}: {:?}",
                     message_id, error
                 );
@@ -162,7 +196,8 @@ impl EventHandler for Inscriber {
                     let start = pos + 9;
                     if let Some(end) = referenced.content[start..].find(']') {
                         let thread_id = &referenced.content[start..start + end];
-                        println!(
+                        log_info!(
+                            "discord",
                             "💬 Captured reply to thread: {} for id: {}",
                             content, thread_id
                         );
@@ -177,8 +212,11 @@ impl EventHandler for Inscriber {
                             &timestamp,
                             reply_to,
                             attachment_data,
-                        ) {
-                            eprintln!(
+                        )
+                        .await
+                        {
+                            log_warn!(
+                                "discord",
                                 "⚠️ Failed to append threaded reply {} to local log: {:?}",
                                 message_id, error
                             );
@@ -200,8 +238,11 @@ impl EventHandler for Inscriber {
                 &timestamp,
                 reply_to,
                 attachment_data,
-            ) {
-                eprintln!(
+            )
+            .await
+            {
+                log_warn!(
+                    "discord",
                     "⚠️ Failed to append passive message {} to local log: {:?}",
                     message_id, error
                 );
@@ -209,7 +250,38 @@ impl EventHandler for Inscriber {
         }
     }
 
+    async fn guild_create(&self, _ctx: Context, guild: Guild, _is_new: Option<bool>) {
+        if !self.is_home_guild(guild.id) {
+            return;
+        }
+        // Reconcile channels that appeared while we were offline or only manual mappings existed.
+        let discovered: HashMap<String, String> = guild
+            .channels
+            .values()
+            .filter(|channel| channel.kind == serenity::model::channel::ChannelType::Text)
+            .map(|channel| {
+                let channel_id = channel.id.to_string();
+                let folder = to_folder_name(&channel.name, &channel_id);
+                (channel_id, folder)
+            })
+            .collect();
+
+        if let Err(e) =
+            discovery::apply_discovered_channels(&self.workspace_path, &self.mappings, discovered)
+                .await
+        {
+            log_warn!(
+                "discord",
+                "⚠️ Failed to reconcile channels for guild {}: {:?}",
+                guild.name, e
+            );
+        }
+    }
+
     async fn channel_create(&self, _ctx: Context, channel: GuildChannel) {
+        if !self.is_home_guild(channel.guild_id) {
+            return;
+        }
         let channel_id = channel.id.to_string();
 
         // 1. Try to find existing folder by ID suffix first (Self-Healing)
@@ -217,7 +289,8 @@ impl EventHandler for Inscriber {
             .resolve_physical_folder(&channel_id)
             .unwrap_or_else(|| to_folder_name(&channel.name, &channel_id));
 
-        println!(
+        log_info!(
+            "discord",
             "✨ New channel detected: #{} ({})",
             channel.name, folder_name
         );
@@ -234,6 +307,9 @@ impl EventHandler for Inscriber {
     }
 
     async fn channel_update(&self, _ctx: Context, _old: Option<GuildChannel>, new: GuildChannel) {
+        if !self.is_home_guild(new.guild_id) {
+            return;
+        }
         let channel_id = new.id.to_string();
         let new_folder_name = to_folder_name(&new.name, &channel_id);
 
@@ -242,19 +318,21 @@ impl EventHandler for Inscriber {
 
         if let Some(old) = current_folder {
             if old != new_folder_name {
-                println!("📝 Channel renamed: #{} -> #{}", old, new_folder_name);
+                log_info!("discord", "📝 Channel renamed: #{} -> #{}", old, new_folder_name);
 
                 let old_path = self.workspace_path.join("channels").join(&old);
                 let new_path = self.workspace_path.join("channels").join(&new_folder_name);
 
                 if old_path.exists() {
                     if let Err(e) = fs::rename(&old_path, &new_path) {
-                        eprintln!(
+                        log_warn!(
+                            "discord",
                             "⚠️ Failed to rename local folder from {} to {}: {:?}",
                             old, new_folder_name, e
                         );
                     } else {
-                        println!(
+                        log_info!(
+                            "discord",
                             "📂 Local folder synchronized: {} -> {}",
                             old, new_folder_name
                         );
@@ -273,17 +351,26 @@ impl EventHandler for Inscriber {
     }
 
     async fn guild_scheduled_event_create(&self, _ctx: Context, event: ScheduledEvent) {
-        println!("📅 Discord Event created: {}", event.name);
+        if !self.is_home_guild(event.guild_id) {
+            return;
+        }
+        log_info!("discord", "📅 Discord Event created: {}", event.name);
         self.sync_event_to_brain(&event);
     }
 
     async fn guild_scheduled_event_update(&self, _ctx: Context, event: ScheduledEvent) {
-        println!("📅 Discord Event updated: {}", event.name);
+        if !self.is_home_guild(event.guild_id) {
+            return;
+        }
+        log_info!("discord", "📅 Discord Event updated: {}", event.name);
         self.sync_event_to_brain(&event);
     }
 
     async fn guild_scheduled_event_delete(&self, _ctx: Context, event: ScheduledEvent) {
-        println!("🗑️ Discord Event deleted: {}", event.name);
+        if !self.is_home_guild(event.guild_id) {
+            return;
+        }
+        log_info!("discord", "🗑️ Discord Event deleted: {}", event.name);
         let brain_event_path = self
             .workspace_path
             .join("brain")
@@ -292,6 +379,54 @@ impl EventHandler for Inscriber {
         let _ = fs::remove_file(brain_event_path);
     }
 
+    async fn reaction_add(&self, ctx: Context, reaction: Reaction) {
+        let Some(user_id) = reaction.user_id else {
+            return;
+        };
+        if user_id == ctx.cache.current_user().id {
+            return;
+        }
+        let serenity::model::channel::ReactionType::Unicode(emoji) = &reaction.emoji else {
+            return;
+        };
+        if crate::ritual_control::is_pause_emoji(emoji) {
+            let Ok(message) = reaction.message(&ctx.http).await else {
+                return;
+            };
+            if !message.author.bot {
+                return;
+            }
+            if let Some(name) = crate::ritual_control::ritual_from_message(&message.content) {
+                let reply = crate::ritual_control::toggle(&self.workspace_path, name).await;
+                log_info!("discord", "⏸️ Ritual toggle from {}: {}", user_id, reply);
+                if let Err(e) = client::send_bot_message(
+                    &self.token,
+                    &reaction.channel_id.to_string(),
+                    &reply,
+                )
+                .await
+                {
+                    log_warn!("discord", "⚠️ Failed to confirm ritual toggle: {:?}", e);
+                }
+            }
+            return;
+        }
+        let Some(approved) = crate::approval::decision_from_emoji(emoji) else {
+            return;
+        };
+        if crate::approval::resolve(
+            &reaction.message_id.to_string(),
+            &user_id.to_string(),
+            approved,
+        ) {
+            log_info!(
+                "discord",
+                "🗳️ Approval decision from {} on message {}",
+                user_id, reaction.message_id
+            );
+        }
+    }
+
     async fn message_delete(
         &self,
         _ctx: Context,
@@ -300,8 +435,8 @@ impl EventHandler for Inscriber {
         _guild_id: Option<serenity::model::id::GuildId>,
     ) {
         let msg_id_str = deleted_message_id.to_string();
-        println!("🗑️ Discord Message deleted: {}", msg_id_str);
-        let _ = self.scrub_message_from_logs(&msg_id_str);
+        log_info!("discord", "🗑️ Discord Message deleted: {}", msg_id_str);
+        let _ = self.scrub_message_from_logs(msg_id_str).await;
     }
 
     async fn message_delete_bulk(
@@ -311,21 +446,38 @@ impl EventHandler for Inscriber {
         multiple_deleted_message_ids: Vec<serenity::model::id::MessageId>,
         _guild_id: Option<serenity::model::id::GuildId>,
     ) {
-        println!(
+        log_info!(
+            "discord",
             "🗑️ Discord Bulk Message deletion: {} messages",
             multiple_deleted_message_ids.len()
         );
         for msg_id in multiple_deleted_message_ids {
-            let _ = self.scrub_message_from_logs(&msg_id.to_string());
+            let _ = self.scrub_message_from_logs(msg_id.to_string()).await;
         }
     }
 
-    async fn ready(&self, _: Context, ready: Ready) {
-        println!("✅ {} is connected and inscribing!", ready.user.name);
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        log_info!("discord", "✅ {} is connected and inscribing!", ready.user.name);
+
+        let guild_ids = ready.guilds.iter().map(|guild| guild.id).collect();
+        let mappings = self.mappings.read().await.clone();
+        permissions::run_permission_self_test(
+            &ctx.http,
+            &self.token,
+            guild_ids,
+            ready.user.id,
+            mappings,
+            self.admin_channel_id.clone(),
+        )
+        .await;
     }
 }
 
 impl Inscriber {
+    fn is_home_guild(&self, guild_id: serenity::model::id::GuildId) -> bool {
+        is_home_guild(self.guild_id.as_deref(), guild_id.get())
+    }
+
     fn sync_event_to_brain(&self, event: &ScheduledEvent) {
         let brain_dir = self.workspace_path.join("brain").join("events");
         if !brain_dir.exists() {
@@ -337,8 +489,16 @@ impl Inscriber {
         }
     }
 
-    fn scrub_message_from_logs(&self, message_id: &str) -> anyhow::Result<()> {
-        let channels_dir = self.workspace_path.join("channels");
+    /// Remove a deleted message from the channel logs. The scan and the journaled rewrites
+    /// are blocking file IO, so they run on the blocking pool.
+    async fn scrub_message_from_logs(&self, message_id: String) -> anyhow::Result<()> {
+        let workspace_path = self.workspace_path.clone();
+        tokio::task::spawn_blocking(move || Self::scrub_logs_blocking(&workspace_path, &message_id))
+            .await?
+    }
+
+    fn scrub_logs_blocking(workspace_path: &Path, message_id: &str) -> anyhow::Result<()> {
+        let channels_dir = workspace_path.join("channels");
         if !channels_dir.exists() {
             return Ok(());
         }
@@ -355,10 +515,15 @@ impl Inscriber {
                     if file_path.extension().and_then(|s| s.to_str()) == Some("md")
                         && let Ok(content) = std::fs::read_to_string(&file_path)
                             && content.contains(&pattern) {
-                                let new_content = self.remove_message_block(&content, &pattern);
+                                let new_content = Self::remove_message_block(&content, &pattern);
                                 if new_content != content {
-                                    std::fs::write(&file_path, new_content)?;
-                                    println!(
+                                    crate::journal::write(
+                                        workspace_path,
+                                        &file_path,
+                                        &new_content,
+                                    )?;
+                                    log_info!(
+                                        "discord",
                                         "✂️ Scrubbed message {} from {:?}",
                                         message_id,
                                         file_path.file_name().unwrap()
@@ -371,7 +536,7 @@ impl Inscriber {
         Ok(())
     }
 
-    fn remove_message_block(&self, content: &str, pattern: &str) -> String {
+    fn remove_message_block(content: &str, pattern: &str) -> String {
         let lines: Vec<&str> = content.lines().collect();
         let mut target_index = None;
 
@@ -413,6 +578,11 @@ impl Inscriber {
     }
 }
 
+/// Every guild is home when `discord.guild_id` is unset.
+fn is_home_guild(home: Option<&str>, guild_id: u64) -> bool {
+    home.is_none_or(|home| home.trim() == guild_id.to_string())
+}
+
 pub fn resolve_folder_by_id(workspace_path: &Path, channel_id: &str) -> Option<String> {
     let suffix = if channel_id.len() >= 6 {
         &channel_id[channel_id.len() - 6..]
@@ -438,18 +608,25 @@ pub async fn start_listening(
     workspace_path: PathBuf,
     mappings: Arc<RwLock<HashMap<String, String>>>,
     notif_tx: mpsc::Sender<StewardNotification>,
+    admin_channel_id: Option<String>,
+    guild_id: Option<String>,
 ) -> anyhow::Result<()> {
     let handler = Inscriber {
         workspace_path,
         mappings,
         notif_tx,
+        token: token.to_string(),
+        admin_channel_id,
+        guild_id,
     };
 
     let intents = GatewayIntents::GUILD_MESSAGES
         | GatewayIntents::DIRECT_MESSAGES
         | GatewayIntents::MESSAGE_CONTENT
         | GatewayIntents::GUILDS
-        | GatewayIntents::GUILD_SCHEDULED_EVENTS;
+        | GatewayIntents::GUILD_SCHEDULED_EVENTS
+        | GatewayIntents::GUILD_MESSAGE_REACTIONS
+        | GatewayIntents::DIRECT_MESSAGE_REACTIONS;
 
     let mut client = Client::builder(token, intents)
         .event_handler(handler)
@@ -459,25 +636,6 @@ pub async fn start_listening(
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{extract_id_from_folder, to_folder_name};
-
-    #[test]
-    fn test_extract_id_from_folder_accepts_dash_suffix_only() {
-        assert_eq!(extract_id_from_folder("General (123456)"), None);
-        assert_eq!(
-            extract_id_from_folder("general-123456"),
-            Some("123456".to_string())
-        );
-    }
-
-    #[test]
-    fn test_to_folder_name_uses_dash_suffix_format() {
-        assert_eq!(to_folder_name("general", "123456789"), "general-456789");
-    }
-}
-
 /// Helper to extract the stored channel-id suffix from a folder name.
 /// Only the `name-123456` suffix format is supported.
 pub fn extract_id_from_folder(folder_name: &str) -> Option<String> {
@@ -560,6 +718,7 @@ task_id: "ritual_{}"
 origin_channel: "{}"
 status: {}
 schedule: "{}"
+timezone: "UTC"
 injection_template: |
   - [ ] Start the Ritual: {}
 -----
@@ -581,7 +740,7 @@ Start Time (UTC): {}
     });
 
     tokio::fs::write(final_path, content).await?;
-    println!("🌌 Ritual synchronized: {} (ID: {})", name, event_id);
+    log_info!("discord", "🌌 Ritual synchronized: {} (ID: {})", name, event_id);
 
     Ok(())
 }
@@ -624,3 +783,29 @@ pub fn to_folder_name(name: &str, id: &str) -> String {
     let suffix = &id[id.len().saturating_sub(6)..];
     format!("{}-{}", name, suffix)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_id_from_folder, is_home_guild, to_folder_name};
+
+    #[test]
+    fn test_extract_id_from_folder_accepts_dash_suffix_only() {
+        assert_eq!(extract_id_from_folder("General (123456)"), None);
+        assert_eq!(
+            extract_id_from_folder("general-123456"),
+            Some("123456".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_folder_name_uses_dash_suffix_format() {
+        assert_eq!(to_folder_name("general", "123456789"), "general-456789");
+    }
+
+    #[test]
+    fn test_is_home_guild_skips_other_servers_only_when_configured() {
+        assert!(is_home_guild(Some("987654321"), 987654321));
+        assert!(!is_home_guild(Some("987654321"), 111111111));
+        assert!(is_home_guild(None, 111111111));
+    }
+}
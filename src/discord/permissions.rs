@@ -0,0 +1,141 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/discord/permissions.rs
+ * Responsibility: Startup self-test of the bot's Discord permissions in mapped channels.
+ */
+
+use super::client as discord_client;
+use crate::config::Language;
+use crate::i18n::Message;
+use crate::{log_info, log_warn};
+use serenity::model::id::{GuildId, UserId};
+use serenity::model::permissions::Permissions;
+use std::collections::HashMap;
+
+/// Permissions Tellar needs in every mapped channel, with human-readable names.
+const REQUIRED_CHANNEL_PERMISSIONS: [(Permissions, &str); 5] = [
+    (Permissions::VIEW_CHANNEL, "View Channel"),
+    (Permissions::READ_MESSAGE_HISTORY, "Read Message History"),
+    (Permissions::SEND_MESSAGES, "Send Messages"),
+    (Permissions::ATTACH_FILES, "Attach Files"),
+    (Permissions::MANAGE_EVENTS, "Manage Events"),
+];
+
+pub(crate) fn missing_permissions(granted: Permissions) -> Vec<&'static str> {
+    if granted.administrator() {
+        return Vec::new();
+    }
+
+    REQUIRED_CHANNEL_PERMISSIONS
+        .iter()
+        .filter(|(permission, _)| !granted.contains(*permission))
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+pub(crate) fn format_permission_report(
+    problems: &[(String, Vec<&'static str>)],
+    language: Language,
+) -> String {
+    let mut report = Message::PermissionsMissing.render(language) + "\n";
+    for (channel, missing) in problems {
+        report.push_str(&format!("- `#{}`: {}\n", channel, missing.join(", ")));
+    }
+    report.push_str(&Message::PermissionsHint.render(language));
+    report
+}
+
+/// Check the bot's effective permissions in each mapped channel of `guild_id`.
+/// Returns `(folder name, missing permission names)` for every channel with gaps.
+pub(crate) async fn check_guild_permissions(
+    http: &serenity::http::Http,
+    guild_id: GuildId,
+    bot_id: UserId,
+    mappings: &HashMap<String, String>,
+) -> anyhow::Result<Vec<(String, Vec<&'static str>)>> {
+    let guild = http.get_guild(guild_id).await?;
+    let member = http.get_member(guild_id, bot_id).await?;
+    let channels = http.get_channels(guild_id).await?;
+
+    let mut problems = Vec::new();
+    for channel in channels {
+        let Some(folder) = mappings.get(&channel.id.to_string()) else {
+            continue;
+        };
+        let missing = missing_permissions(guild.user_permissions_in(&channel, &member));
+        if !missing.is_empty() {
+            problems.push((folder.clone(), missing));
+        }
+    }
+    problems.sort();
+    Ok(problems)
+}
+
+/// Run the self-test for every guild and report gaps to stdout and the admin channel.
+pub(crate) async fn run_permission_self_test(
+    http: &serenity::http::Http,
+    token: &str,
+    guild_ids: Vec<GuildId>,
+    bot_id: UserId,
+    mappings: HashMap<String, String>,
+    admin_channel_id: Option<String>,
+) {
+    let mut problems = Vec::new();
+    for guild_id in guild_ids {
+        match check_guild_permissions(http, guild_id, bot_id, &mappings).await {
+            Ok(found) => problems.extend(found),
+            Err(e) => log_warn!(
+                "discord",
+                "⚠️ Permission self-test could not inspect guild {}: {:?}",
+                guild_id,
+                e
+            ),
+        }
+    }
+
+    if problems.is_empty() {
+        log_info!(
+            "discord",
+            "🔐 Permission self-test passed for {} mapped channel(s).",
+            mappings.len()
+        );
+        return;
+    }
+
+    let report = format_permission_report(&problems, crate::i18n::language());
+    log_warn!("discord", "{}", report);
+    if let Some(admin_channel_id) = admin_channel_id
+        && let Err(e) = discord_client::send_bot_message(token, &admin_channel_id, &report).await
+    {
+        log_warn!(
+            "discord",
+            "⚠️ Failed to send permission report to admin channel {}: {:?}",
+            admin_channel_id,
+            e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_permissions_lists_gaps_unless_administrator() {
+        let granted = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        assert_eq!(
+            missing_permissions(granted),
+            vec!["Read Message History", "Attach Files", "Manage Events"]
+        );
+        assert!(missing_permissions(Permissions::ADMINISTRATOR).is_empty());
+    }
+
+    #[test]
+    fn test_format_permission_report_names_each_channel() {
+        let report = format_permission_report(
+            &[("general-123456".to_string(), vec!["Attach Files"])],
+            Language::En,
+        );
+        assert!(report.contains("`#general-123456`: Attach Files"));
+    }
+}
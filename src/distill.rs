@@ -0,0 +1,406 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/distill.rs
+ * Responsibility: The Guardian's distillation phase: merge duplicate and contradictory remembered facts and log each merge.
+ */
+
+use crate::config::Config;
+use crate::llm;
+use crate::log_warn;
+use crate::memory::{MEMORY_LOCK, MemoryEntry};
+use anyhow::{Context, Result, bail};
+use chrono::Local;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const CHANGELOG_FILE: &str = "brain/KNOWLEDGE-CHANGELOG.md";
+/// Facts shown to the model when looking for contradictions.
+const MAX_FACTS_FOR_MODEL: usize = 200;
+
+const CONFLICT_PROMPT: &str = "You audit remembered facts for contradictions. Each line is `id | file | added | fact`. Find pairs that state incompatible things about the same subject. For each, keep the fact that is more likely true now (usually the more recently added one) and drop the other. Ignore facts that merely overlap or add detail. Return exactly one JSON object and nothing else: {\"conflicts\":[{\"keep\":\"<id>\",\"drop\":\"<id>\",\"reason\":\"<one short sentence>\"}]}. Return {\"conflicts\":[]} when there are none.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeKind {
+    Duplicate,
+    Conflict,
+}
+
+/// One fact dropped in favour of another, with why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeDecision {
+    pub kind: MergeKind,
+    pub kept_path: String,
+    pub kept: MemoryEntry,
+    pub dropped_path: String,
+    pub dropped: MemoryEntry,
+    pub reason: String,
+}
+
+impl MergeDecision {
+    fn changelog_line(&self, at: &str) -> String {
+        let what = match self.kind {
+            MergeKind::Duplicate => "Merged duplicate",
+            MergeKind::Conflict => "Resolved conflict",
+        };
+        format!(
+            "- {} {}: dropped \"{}\" ({} in {}), kept \"{}\" ({} in {}). {}\n",
+            at,
+            what,
+            self.dropped.fact,
+            self.dropped.field("id").unwrap_or("-"),
+            self.dropped_path,
+            self.kept.fact,
+            self.kept.field("id").unwrap_or("-"),
+            self.kept_path,
+            self.reason
+        )
+    }
+}
+
+/// A remembered fact and the guild-relative file holding it.
+#[derive(Debug, Clone)]
+struct Located {
+    path: String,
+    entry: MemoryEntry,
+}
+
+/// Facts with memory metadata in `brain/KNOWLEDGE.md` and every channel's `KNOWLEDGE.md`.
+fn load_facts(base_path: &Path) -> Vec<Located> {
+    crate::memory::knowledge_files(base_path)
+        .into_iter()
+        .filter(|path| path.file_name().is_some_and(|name| name == "KNOWLEDGE.md"))
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let relative = path
+                .strip_prefix(base_path)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            Some(
+                content
+                    .lines()
+                    .filter_map(MemoryEntry::parse)
+                    .map(|entry| Located {
+                        path: relative.clone(),
+                        entry,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
+fn normalize(fact: &str) -> String {
+    fact.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['.', '!', ';'])
+        .to_lowercase()
+}
+
+/// Which of two copies of a fact to keep: the guild-wide one, else the older one.
+fn prefer(a: &Located, b: &Located) -> bool {
+    let global = |fact: &Located| fact.path.starts_with("brain/");
+    match (global(a), global(b)) {
+        (true, false) => true,
+        (false, true) => false,
+        _ => a.entry.field("added").unwrap_or("") <= b.entry.field("added").unwrap_or(""),
+    }
+}
+
+/// Facts that say the same thing, once whitespace, case, and trailing punctuation are
+/// ignored. Each group keeps one copy.
+fn find_duplicates(facts: &[Located]) -> Vec<MergeDecision> {
+    let mut groups: Vec<(String, Vec<&Located>)> = Vec::new();
+    for fact in facts {
+        let key = normalize(&fact.entry.fact);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(fact),
+            None => groups.push((key, vec![fact])),
+        }
+    }
+    let mut decisions = Vec::new();
+    for (_, group) in groups.into_iter().filter(|(_, group)| group.len() > 1) {
+        let keeper = group
+            .iter()
+            .copied()
+            .reduce(|best, next| if prefer(best, next) { best } else { next })
+            .expect("group is not empty");
+        for fact in group {
+            if std::ptr::eq(fact, keeper) {
+                continue;
+            }
+            decisions.push(MergeDecision {
+                kind: MergeKind::Duplicate,
+                kept_path: keeper.path.clone(),
+                kept: keeper.entry.clone(),
+                dropped_path: fact.path.clone(),
+                dropped: fact.entry.clone(),
+                reason: "Same fact recorded twice.".to_string(),
+            });
+        }
+    }
+    decisions
+}
+
+#[derive(Debug, Deserialize)]
+struct ConflictReply {
+    #[serde(default)]
+    conflicts: Vec<ConflictPick>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConflictPick {
+    keep: String,
+    drop: String,
+    #[serde(default)]
+    reason: String,
+}
+
+/// Turn the model's reply into decisions, ignoring ids it made up or reused.
+fn parse_conflicts(reply: &str, facts: &[Located]) -> Result<Vec<MergeDecision>> {
+    let json = crate::router::extract_json_object(reply)?;
+    let reply: ConflictReply =
+        serde_json::from_str(&json).with_context(|| format!("invalid conflict JSON: {}", json))?;
+    let by_id: HashMap<&str, &Located> = facts
+        .iter()
+        .filter_map(|fact| fact.entry.field("id").map(|id| (id, fact)))
+        .collect();
+    let mut dropped: Vec<&str> = Vec::new();
+    let mut decisions = Vec::new();
+    for pick in &reply.conflicts {
+        let (Some(kept), Some(gone)) =
+            (by_id.get(pick.keep.as_str()), by_id.get(pick.drop.as_str()))
+        else {
+            continue;
+        };
+        if pick.keep == pick.drop
+            || dropped.contains(&pick.keep.as_str())
+            || dropped.contains(&pick.drop.as_str())
+        {
+            continue;
+        }
+        dropped.push(pick.drop.as_str());
+        decisions.push(MergeDecision {
+            kind: MergeKind::Conflict,
+            kept_path: kept.path.clone(),
+            kept: kept.entry.clone(),
+            dropped_path: gone.path.clone(),
+            dropped: gone.entry.clone(),
+            reason: pick.reason.trim().to_string(),
+        });
+    }
+    Ok(decisions)
+}
+
+async fn find_conflicts(config: &Config, facts: &[Located]) -> Result<Vec<MergeDecision>> {
+    let listing: Vec<String> = facts
+        .iter()
+        .filter(|fact| fact.entry.field("id").is_some())
+        .take(MAX_FACTS_FOR_MODEL)
+        .map(|fact| {
+            format!(
+                "{} | {} | {} | {}",
+                fact.entry.field("id").unwrap_or_default(),
+                fact.path,
+                fact.entry.field("added").unwrap_or("-"),
+                fact.entry.fact
+            )
+        })
+        .collect();
+    if listing.len() < 2 {
+        return Ok(Vec::new());
+    }
+    let turn = llm::generate_turn(
+        CONFLICT_PROMPT,
+        vec![llm::Message {
+            role: llm::MessageRole::User,
+            parts: vec![llm::MultimodalPart::text(listing.join("\n"))],
+        }],
+        &config.gemini.api_key,
+        config
+            .guardian
+            .model
+            .as_deref()
+            .unwrap_or(&config.gemini.model),
+        0.1,
+        None,
+    )
+    .await?;
+    match turn {
+        llm::ModelTurn::Narrative(text) => parse_conflicts(&text, facts),
+        llm::ModelTurn::ToolCalls { .. } => bail!("conflict audit attempted tool calls"),
+    }
+}
+
+/// Remove every dropped fact whose file `may_write` accepts, and log the merges that
+/// happened to the changelog. Returns those merges.
+fn apply(
+    base_path: &Path,
+    decisions: Vec<MergeDecision>,
+    may_write: &dyn Fn(&Path) -> bool,
+) -> std::io::Result<Vec<MergeDecision>> {
+    let _guard = MEMORY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let applied: Vec<MergeDecision> = decisions
+        .into_iter()
+        .filter(|decision| may_write(Path::new(&decision.dropped_path)))
+        .collect();
+    let mut by_file: Vec<(PathBuf, Vec<String>)> = Vec::new();
+    for decision in &applied {
+        let path = base_path.join(&decision.dropped_path);
+        let line = decision.dropped.render();
+        match by_file.iter_mut().find(|(p, _)| *p == path) {
+            Some((_, lines)) => lines.push(line),
+            None => by_file.push((path, vec![line])),
+        }
+    }
+    for (path, lines) in by_file {
+        let content = fs::read_to_string(&path)?;
+        let mut kept: Vec<&str> = Vec::new();
+        let mut pending = lines.clone();
+        for line in content.lines() {
+            if let Some(index) = MemoryEntry::parse(line)
+                .map(|entry| entry.render())
+                .and_then(|rendered| pending.iter().position(|l| *l == rendered))
+            {
+                pending.remove(index);
+                continue;
+            }
+            kept.push(line);
+        }
+        let mut next = kept.join("\n");
+        next.push('\n');
+        fs::write(&path, next)?;
+    }
+    if !applied.is_empty() {
+        let changelog = base_path.join(CHANGELOG_FILE);
+        if let Some(parent) = changelog.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut content = fs::read_to_string(&changelog)
+            .unwrap_or_else(|_| "# Knowledge Changelog\n\n".to_string());
+        let at = Local::now().format("%Y-%m-%d %H:%M").to_string();
+        for decision in &applied {
+            content.push_str(&decision.changelog_line(&at));
+        }
+        fs::write(&changelog, content)?;
+    }
+    Ok(applied)
+}
+
+/// Merge duplicate facts, then ask the model which remaining facts contradict each other
+/// and drop the losers. Only files inside `guardian.paths` change; every merge is logged
+/// to `brain/KNOWLEDGE-CHANGELOG.md`.
+pub async fn distill_knowledge(base_path: &Path, config: &Config) -> Vec<MergeDecision> {
+    let may_write = |path: &Path| config.guardian.may_write(path);
+    let facts = load_facts(base_path);
+    let mut merged = match apply(base_path, find_duplicates(&facts), &may_write) {
+        Ok(merged) => merged,
+        Err(e) => {
+            log_warn!(
+                "steward",
+                "⚠️ Guardian failed to merge duplicate facts: {}",
+                e
+            );
+            return Vec::new();
+        }
+    };
+    if !config.guardian.resolve_conflicts {
+        return merged;
+    }
+    let facts = load_facts(base_path);
+    match find_conflicts(config, &facts).await {
+        Ok(conflicts) => match apply(base_path, conflicts, &may_write) {
+            Ok(resolved) => merged.extend(resolved),
+            Err(e) => log_warn!(
+                "steward",
+                "⚠️ Guardian failed to resolve conflicting facts: {}",
+                e
+            ),
+        },
+        Err(e) => log_warn!("steward", "⚠️ Guardian conflict audit failed: {}", e),
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn fact(id: &str, added: &str, text: &str) -> String {
+        format!("- {} <!-- memory: id={}; added={} -->", text, id, added)
+    }
+
+    #[tokio::test]
+    async fn test_distill_merges_duplicates_and_conflicts() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let channel = base.join("channels/general-123456");
+        fs::create_dir_all(&channel).unwrap();
+        fs::create_dir_all(base.join("brain")).unwrap();
+        fs::write(
+            base.join("brain/KNOWLEDGE.md"),
+            format!(
+                "# Knowledge\n{}\n{}\n",
+                fact("m1", "2026-03-02 10:00", "Standup is at 9."),
+                fact("m2", "2026-03-01 10:00", "The office wifi is tellar-guest")
+            ),
+        )
+        .unwrap();
+        fs::write(
+            channel.join("KNOWLEDGE.md"),
+            format!(
+                "## Remembered\n{}\n{}\n- hand-written standup is at 9\n",
+                fact("m3", "2026-03-01 09:00", "standup is at  9"),
+                fact("m4", "2026-03-05 09:00", "Standup moved to 10")
+            ),
+        )
+        .unwrap();
+
+        let facts = load_facts(base);
+        let duplicates = find_duplicates(&facts);
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].kept.field("id"), Some("m1"));
+        assert_eq!(
+            duplicates[0].dropped_path,
+            "channels/general-123456/KNOWLEDGE.md"
+        );
+
+        let conflicts = parse_conflicts(
+            "```json\n{\"conflicts\":[{\"keep\":\"m4\",\"drop\":\"m1\",\"reason\":\"Moved later.\"},\
+             {\"keep\":\"m1\",\"drop\":\"m4\"},{\"keep\":\"m9\",\"drop\":\"m2\"}]}\n```",
+            &facts,
+        )
+        .unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].dropped.field("id"), Some("m1"));
+
+        let mut decisions = duplicates;
+        decisions.extend(conflicts);
+        let applied = apply(base, decisions.clone(), &|path| {
+            path.starts_with("channels")
+        })
+        .unwrap();
+        assert_eq!(applied.len(), 1);
+        let applied = apply(base, decisions[1..].to_vec(), &|_| true).unwrap();
+        assert_eq!(applied.len(), 1);
+
+        let guild = fs::read_to_string(base.join("brain/KNOWLEDGE.md")).unwrap();
+        assert!(!guild.contains("Standup is at 9") && guild.contains("tellar-guest"));
+        let local = fs::read_to_string(channel.join("KNOWLEDGE.md")).unwrap();
+        assert!(!local.contains("id=m3") && local.contains("Standup moved to 10"));
+        assert!(local.contains("- hand-written standup is at 9"));
+        let changelog = fs::read_to_string(base.join(CHANGELOG_FILE)).unwrap();
+        assert!(changelog.contains("Merged duplicate: dropped \"standup is at  9\" (m3 in channels/general-123456/KNOWLEDGE.md), kept \"Standup is at 9.\" (m1 in brain/KNOWLEDGE.md)."));
+        assert!(changelog.contains("Resolved conflict: dropped \"Standup is at 9.\" (m1 in brain/KNOWLEDGE.md), kept \"Standup moved to 10\" (m4 in channels/general-123456/KNOWLEDGE.md). Moved later."));
+
+        // The mock model answers in prose, which leaves the facts alone.
+        let mut config = Config::default();
+        config.gemini.model = crate::mock_llm::MOCK_MODEL.to_string();
+        assert!(distill_knowledge(base, &config).await.is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/docker_compose.rs
+ * Responsibility: The Dockerfile, docker-compose.yml, and `.env` that `tellarctl docker` writes to run Tellar in a container.
+ */
+
+use anyhow::anyhow;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use ring::rand::{SecureRandom, SystemRandom};
+use std::path::Path;
+
+/// Where the guild is mounted inside the container.
+pub const CONTAINER_GUILD: &str = "/guild";
+/// Port of the inbound webhook server, which also answers the healthcheck.
+pub const HEALTH_PORT: u16 = 8787;
+/// Image tag used when the stack is built locally.
+pub const LOCAL_IMAGE: &str = "tellar:local";
+const SOURCE_REPO: &str = "https://github.com/eric9n/tellar";
+
+pub const DOCKERFILE: &str = "Dockerfile";
+pub const COMPOSE_FILE: &str = "docker-compose.yml";
+pub const ENV_FILE: &str = ".env";
+/// The `.env` variable that holds the inbound webhook token.
+pub const WEBHOOK_TOKEN_ENV: &str = "TELLAR__INBOUND_WEBHOOK__TOKEN";
+
+/// How the compose stack gets its image and runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComposeSettings {
+    /// Pull this image instead of building one from source.
+    pub image: Option<String>,
+    /// Host owner the container runs as, so guild files stay the user's own.
+    pub user: Option<(u32, u32)>,
+    /// Listen on all container interfaces and publish the port on the host's loopback.
+    /// Otherwise the webhook server only answers inside the container.
+    pub publish: bool,
+}
+
+/// A two-stage build: `cargo install` from the repository, then a slim runtime with the
+/// tools skills commonly shell out to.
+pub fn render_dockerfile() -> String {
+    format!(
+        r#"# Generated by `tellarctl docker init`.
+FROM rust:1-bookworm AS build
+RUN cargo install --locked --root /out --git {repo} tellar
+
+FROM debian:bookworm-slim
+RUN apt-get update \
+    && apt-get install -y --no-install-recommends ca-certificates curl git python3 python3-venv \
+    && rm -rf /var/lib/apt/lists/*
+COPY --from=build /out/bin/ /usr/local/bin/
+VOLUME {guild}
+EXPOSE {port}
+ENTRYPOINT ["tellar", "--guild", "{guild}"]
+"#,
+        repo = SOURCE_REPO,
+        guild = CONTAINER_GUILD,
+        port = HEALTH_PORT,
+    )
+}
+
+/// The compose file: the guild as a bind mount, secrets from `.env`, and the webhook
+/// server's `/healthz` as the healthcheck. The server binds the container's loopback
+/// unless `publish` is set.
+pub fn render_compose(guild_path: &Path, settings: &ComposeSettings) -> String {
+    let image = match &settings.image {
+        Some(image) => format!("    image: {}\n", image),
+        None => format!("    image: {}\n    build: .\n", LOCAL_IMAGE),
+    };
+    let user = settings
+        .user
+        .map(|(uid, gid)| format!("    user: \"{}:{}\"\n", uid, gid))
+        .unwrap_or_default();
+    let (bind, ports) = if settings.publish {
+        (
+            "0.0.0.0",
+            format!("    ports:\n      - \"127.0.0.1:{0}:{0}\"\n", HEALTH_PORT),
+        )
+    } else {
+        ("127.0.0.1", String::new())
+    };
+    format!(
+        r#"# Generated by `tellarctl docker init`. Credentials live in {env}, not here.
+services:
+  tellar:
+{image}{user}    restart: unless-stopped
+    env_file: {env}
+    environment:
+      TELLAR__INBOUND_WEBHOOK__ENABLED: "true"
+      TELLAR__INBOUND_WEBHOOK__BIND: "{bind}:{port}"
+    volumes:
+      - "{host}:{guild}"
+{ports}    healthcheck:
+      test: ["CMD", "curl", "-fsS", "http://127.0.0.1:{port}/healthz"]
+      interval: 30s
+      timeout: 5s
+      start_period: 20s
+      retries: 3
+"#,
+        env = ENV_FILE,
+        image = image,
+        user = user,
+        bind = bind,
+        ports = ports,
+        port = HEALTH_PORT,
+        host = guild_path.display(),
+        guild = CONTAINER_GUILD,
+    )
+}
+
+/// A random webhook token for a new `.env`.
+pub fn generate_webhook_token() -> anyhow::Result<String> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| anyhow!("failed to generate a webhook token"))?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Whether a `.env` sets a non-empty webhook token.
+pub fn env_sets_webhook_token(env: &str) -> bool {
+    env.lines().any(|line| {
+        line.trim()
+            .strip_prefix(WEBHOOK_TOKEN_ENV)
+            .and_then(|rest| rest.strip_prefix('='))
+            .is_some_and(|value| !value.trim().is_empty())
+    })
+}
+
+/// The `.env` template with `webhook_token` set, since the compose file turns the webhook
+/// server on. Other entries stay commented out until filled in: a set but empty variable
+/// would override `tellar.yml` with an empty value.
+pub fn render_env(webhook_token: &str) -> String {
+    format!(
+        "# Secrets for the Tellar container; keep this file out of version control.\n\
+         # Uncomment and fill in what the guild's tellar.yml does not already hold.\n\
+         # TELLAR__GEMINI__API_KEY=\n\
+         # TELLAR__DISCORD__TOKEN=\n\
+         # Token for the webhook server, generated by `tellarctl docker init`; `/healthz` needs none.\n\
+         {}={}\n\
+         # Key for credentials encrypted with `tellarctl config encrypt` or `tellarctl secret`.\n\
+         # {}=\n",
+        WEBHOOK_TOKEN_ENV,
+        webhook_token,
+        crate::secrets::KEY_ENV
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_mounts_the_guild_and_checks_health() {
+        let built = render_compose(
+            Path::new("/home/ann/.tellar/guild"),
+            &ComposeSettings {
+                image: None,
+                user: Some((1000, 1000)),
+                publish: false,
+            },
+        );
+        assert!(built.contains("    image: tellar:local\n    build: .\n    user: \"1000:1000\"\n"));
+        assert!(built.contains("- \"/home/ann/.tellar/guild:/guild\""));
+        assert!(built.contains("http://127.0.0.1:8787/healthz"));
+        let parsed: serde_yml::Value = serde_yml::from_str(&built).unwrap();
+        assert_eq!(parsed["services"]["tellar"]["env_file"], ".env");
+        assert!(built.contains("TELLAR__INBOUND_WEBHOOK__BIND: \"127.0.0.1:8787\""));
+        assert!(!built.contains("ports:"));
+
+        let pulled = render_compose(
+            Path::new("/srv/guild"),
+            &ComposeSettings {
+                image: Some("ghcr.io/eric9n/tellar:latest".to_string()),
+                user: None,
+                publish: true,
+            },
+        );
+        assert!(pulled.contains("image: ghcr.io/eric9n/tellar:latest\n    restart"));
+        assert!(!pulled.contains("build:"));
+        assert!(pulled.contains("BIND: \"0.0.0.0:8787\""));
+        assert!(pulled.contains("    ports:\n      - \"127.0.0.1:8787:8787\"\n"));
+
+        assert!(render_dockerfile().contains("ENTRYPOINT [\"tellar\", \"--guild\", \"/guild\"]"));
+        let token = generate_webhook_token().unwrap();
+        assert_eq!(token.len(), 43);
+        assert_ne!(token, generate_webhook_token().unwrap());
+        let env = render_env(&token);
+        assert!(env_sets_webhook_token(&env));
+        assert!(
+            env.lines()
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .eq([format!("TELLAR__INBOUND_WEBHOOK__TOKEN={}", token).as_str()])
+        );
+        assert!(!env_sets_webhook_token(
+            "# TELLAR__INBOUND_WEBHOOK__TOKEN=abc\nTELLAR__INBOUND_WEBHOOK__TOKEN=\n"
+        ));
+    }
+}
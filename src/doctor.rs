@@ -0,0 +1,404 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/doctor.rs
+ * Responsibility: `tellarctl doctor`: check the config, API tokens, skill binaries, watcher limits, disk space, and the service.
+ */
+
+use crate::config::{Config, ExecMode, WatchMode};
+use crate::history_retention::human_bytes;
+use crate::skills::SkillMetadata;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// How long a token check may wait on the network.
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(15);
+/// Free space below which the guild's disk fails the check.
+const MIN_FREE_BYTES: u64 = 1 << 30;
+const INOTIFY_WATCHES_FILE: &str = "/proc/sys/fs/inotify/max_user_watches";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Warn,
+    Fail,
+    Skip,
+}
+
+/// One diagnostic and, when it did not pass, how to fix it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Pass,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn skip(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Skip,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn problem(
+        name: &'static str,
+        status: Status,
+        detail: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mark = match self.status {
+            Status::Pass => "✅",
+            Status::Warn => "⚠️",
+            Status::Fail => "❌",
+            Status::Skip => "⏭️",
+        };
+        let mut line = format!("{} {}: {}", mark, self.name, self.detail);
+        if let Some(fix) = &self.fix {
+            line.push_str(&format!("\n   ↳ {}", fix));
+        }
+        line
+    }
+}
+
+fn is_placeholder(value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty() || value.starts_with("YOUR_") || value == "CHANGE_ME"
+}
+
+fn check_config(guild_path: &Path, profile: Option<&str>) -> (Check, Option<Config>) {
+    let path = guild_path.join("tellar.yml");
+    let config = match Config::load_profile(&path, profile) {
+        Ok(config) => config,
+        Err(e) => {
+            return (
+                Check::problem(
+                    "config",
+                    Status::Fail,
+                    format!("{} could not be loaded: {:#}", path.display(), e),
+                    "Fix the YAML, or run `tellarctl setup` to write a fresh config.",
+                ),
+                None,
+            );
+        }
+    };
+    let mut missing = Vec::new();
+    if is_placeholder(&config.gemini.api_key)
+        && !crate::mock_llm::is_mock_model(&config.gemini.model)
+    {
+        missing.push("gemini.api_key");
+    }
+    if is_placeholder(&config.discord.token) {
+        missing.push("discord.token");
+    }
+    let check = if missing.is_empty() {
+        Check::pass("config", format!("{} is valid", path.display()))
+    } else {
+        Check::problem(
+            "config",
+            Status::Fail,
+            format!("{} still holds placeholders", missing.join(" and ")),
+            "Run `tellarctl setup`, or set them in tellar.yml or TELLAR__ variables.",
+        )
+    };
+    (check, Some(config))
+}
+
+async fn check_gemini(config: &Config) -> Check {
+    let model = &config.gemini.model;
+    if crate::mock_llm::is_mock_model(model) {
+        return Check::pass("gemini", "mock model, no API call needed");
+    }
+    let fix = "Check `gemini.api_key` at https://aistudio.google.com/apikey and the network.";
+    match tokio::time::timeout(
+        NETWORK_TIMEOUT,
+        crate::llm::list_models(&config.gemini.api_key),
+    )
+    .await
+    {
+        Ok(Ok(models)) if models.iter().any(|name| name == model) => {
+            Check::pass("gemini", format!("key accepted, `{}` is available", model))
+        }
+        Ok(Ok(models)) => Check::problem(
+            "gemini",
+            Status::Warn,
+            format!(
+                "key accepted, but `{}` is not among the {} models offered",
+                model,
+                models.len()
+            ),
+            "Pick a listed model for `gemini.model`; `tellarctl setup` lists them.",
+        ),
+        Ok(Err(e)) => Check::problem("gemini", Status::Fail, format!("{:#}", e), fix),
+        Err(_) => Check::problem(
+            "gemini",
+            Status::Fail,
+            "no answer from the Gemini API within 15s",
+            fix,
+        ),
+    }
+}
+
+async fn check_discord(config: &Config) -> Check {
+    let fix = "Reset the bot token in the Discord Developer Portal and update `discord.token`.";
+    let http = serenity::http::Http::new(&config.discord.token);
+    match tokio::time::timeout(NETWORK_TIMEOUT, http.get_bot_gateway()).await {
+        Ok(Ok(gateway)) => Check::pass(
+            "discord",
+            format!(
+                "token accepted, gateway {} ({} sessions left today)",
+                gateway.url, gateway.session_start_limit.remaining
+            ),
+        ),
+        Ok(Err(e)) => Check::problem("discord", Status::Fail, e.to_string(), fix),
+        Err(_) => Check::problem(
+            "discord",
+            Status::Fail,
+            "no answer from the Discord API within 15s",
+            fix,
+        ),
+    }
+}
+
+/// The program a skill's shell command starts, skipping leading `VAR=value` assignments.
+/// Paths (`./run.sh`, `/opt/bin/x`) are left out; they ship with the skill or are absolute.
+pub(crate) fn shell_program(command: &str) -> Option<&str> {
+    command
+        .split_whitespace()
+        .find(|word| !word.contains('='))
+        .filter(|word| !word.contains('/'))
+}
+
+fn find_in_path(program: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+}
+
+fn check_binaries(guild_path: &Path, config: &Config) -> Check {
+    let mut required: BTreeSet<(String, String)> = BTreeSet::new();
+    if let Some(program) = crate::exec_sandbox::sandbox_program(config.runtime.exec_mode) {
+        required.insert((program.to_string(), "runtime.exec_mode".to_string()));
+    }
+    let skills = SkillMetadata::discover_skills(guild_path);
+    for (skill, skill_dir) in &skills {
+        for tool in skill.tools.values() {
+            let program = if tool.wasm.is_some() {
+                Some(crate::wasm_skill::WASMTIME_PROGRAM)
+            } else if tool.python.is_some() {
+                // An existing venv brings its own interpreter; creating one needs python3.
+                (!crate::python_skill::venv_python(skill_dir).exists()).then_some("python3")
+            } else {
+                shell_program(&tool.shell)
+            };
+            if let Some(program) = program {
+                required.insert((program.to_string(), skill.name.clone()));
+            }
+        }
+    }
+    let missing: Vec<String> = required
+        .iter()
+        .filter(|(program, _)| find_in_path(program).is_none())
+        .map(|(program, user)| format!("`{}` (for {})", program, user))
+        .collect();
+    if missing.is_empty() {
+        return Check::pass(
+            "binaries",
+            format!(
+                "{} program(s) needed by {} skill(s) and the exec mode are on PATH",
+                required.len(),
+                skills.len()
+            ),
+        );
+    }
+    let status = if config.runtime.exec_mode != ExecMode::Unrestricted
+        && missing
+            .iter()
+            .any(|entry| entry.contains("runtime.exec_mode"))
+    {
+        Status::Fail
+    } else {
+        Status::Warn
+    };
+    Check::problem(
+        "binaries",
+        status,
+        format!("not on PATH: {}", missing.join(", ")),
+        "Install them, or disable the skills that need them.",
+    )
+}
+
+fn count_dirs(dir: &Path) -> usize {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && !path.is_symlink())
+        .map(|path| 1 + count_dirs(&path))
+        .sum()
+}
+
+/// Judge the inotify watch limit against the guild's directory count; the watcher holds
+/// one watch per directory, and other programs share the same per-user limit.
+pub(crate) fn assess_watches(dirs: usize, limit: usize) -> Check {
+    let fix = "Raise it: `echo fs.inotify.max_user_watches=524288 | sudo tee /etc/sysctl.d/60-tellar.conf && sudo sysctl --system`, or set `watch.mode: poll`.";
+    let detail = format!(
+        "{} guild director{} against a limit of {} watches",
+        dirs,
+        if dirs == 1 { "y" } else { "ies" },
+        limit
+    );
+    if dirs >= limit {
+        Check::problem("watcher", Status::Fail, detail, fix)
+    } else if dirs * 2 >= limit {
+        Check::problem("watcher", Status::Warn, detail, fix)
+    } else {
+        Check::pass("watcher", detail)
+    }
+}
+
+fn check_watcher(guild_path: &Path, config: &Config) -> Check {
+    if config.watch.mode == WatchMode::Poll {
+        return Check::pass("watcher", "polling, no inotify watches needed");
+    }
+    let Ok(limit) = std::fs::read_to_string(INOTIFY_WATCHES_FILE) else {
+        return Check::skip("watcher", "no inotify limit to check on this system");
+    };
+    let Ok(limit) = limit.trim().parse::<usize>() else {
+        return Check::skip("watcher", "unreadable inotify limit");
+    };
+    assess_watches(1 + count_dirs(guild_path), limit)
+}
+
+fn check_disk(guild_path: &Path) -> Check {
+    let Ok(path) = std::ffi::CString::new(guild_path.to_string_lossy().as_bytes()) else {
+        return Check::skip("disk", "guild path is not a valid C string");
+    };
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: `path` is NUL-terminated and `stats` is a writable statvfs.
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stats) } != 0 {
+        return Check::skip("disk", "could not read free space");
+    }
+    let block = stats.f_frsize as u64;
+    let free = stats.f_bavail as u64 * block;
+    let total = stats.f_blocks as u64 * block;
+    let detail = format!(
+        "{} free of {}",
+        human_bytes(free as i64),
+        human_bytes(total as i64)
+    );
+    let fix = "Free space, or enable `history_retention` and `hygiene` to prune old logs.";
+    if free < MIN_FREE_BYTES {
+        Check::problem("disk", Status::Fail, detail, fix)
+    } else if total > 0 && free * 20 < total {
+        Check::problem("disk", Status::Warn, detail, fix)
+    } else {
+        Check::pass("disk", detail)
+    }
+}
+
+fn check_service() -> Check {
+    if !cfg!(target_os = "linux") {
+        return Check::skip("service", "systemd checks only run on Linux");
+    }
+    let Ok(output) = Command::new("systemctl")
+        .args(["--user", "is-active", "tellar"])
+        .output()
+    else {
+        return Check::skip("service", "systemctl not found");
+    };
+    let state = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let installed = dirs::home_dir()
+        .is_some_and(|home| home.join(".config/systemd/user/tellar.service").is_file());
+    match state.as_str() {
+        "active" => Check::pass("service", "tellar.service is active"),
+        _ if !installed => Check::problem(
+            "service",
+            Status::Warn,
+            "tellar.service is not installed",
+            "Run `tellarctl install-service`, then `tellarctl start`.",
+        ),
+        "failed" => Check::problem(
+            "service",
+            Status::Fail,
+            "tellar.service failed",
+            "See `tellarctl logs`, then `tellarctl restart`.",
+        ),
+        other => Check::problem(
+            "service",
+            Status::Warn,
+            format!(
+                "tellar.service is {}",
+                if other.is_empty() { "unknown" } else { other }
+            ),
+            "Run `tellarctl start`.",
+        ),
+    }
+}
+
+/// Run every check against the guild. Token checks need a loadable config and are
+/// skipped without one.
+pub async fn run_checks(guild_path: &Path, profile: Option<&str>) -> Vec<Check> {
+    let (config_check, config) = check_config(guild_path, profile);
+    let mut checks = vec![config_check];
+    match &config {
+        Some(config) => {
+            checks.push(check_gemini(config).await);
+            checks.push(check_discord(config).await);
+            checks.push(check_binaries(guild_path, config));
+            checks.push(check_watcher(guild_path, config));
+        }
+        None => {
+            for name in ["gemini", "discord", "binaries", "watcher"] {
+                checks.push(Check::skip(name, "needs a valid config"));
+            }
+        }
+    }
+    checks.push(check_disk(guild_path));
+    checks.push(check_service());
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skill_programs_and_watch_limits_are_judged() {
+        assert_eq!(shell_program("curl -s https://example.com"), Some("curl"));
+        assert_eq!(shell_program("LANG=C jq -r .name"), Some("jq"));
+        assert_eq!(shell_program("./run.sh --fast"), None);
+        assert_eq!(shell_program("  "), None);
+
+        assert_eq!(assess_watches(100, 8192).status, Status::Pass);
+        assert_eq!(assess_watches(5000, 8192).status, Status::Warn);
+        let full = assess_watches(9000, 8192);
+        assert_eq!(full.status, Status::Fail);
+        assert!(full.render().contains("↳ Raise it"));
+    }
+}
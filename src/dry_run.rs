@@ -0,0 +1,98 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/dry_run.rs
+ * Responsibility: `tellar --dry-run`: side-effecting tools, Discord posts, and outbound notifications are logged instead of performed.
+ */
+
+use crate::{log_info, log_warn};
+use chrono::Local;
+use once_cell::sync::Lazy;
+use serde_json::{Value, json};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Every simulated action, one JSON object per line.
+pub const DRY_RUN_LOG: &str = "brain/dry_run.jsonl";
+
+/// The guild whose log receives simulated actions; `None` outside a dry run.
+static GUILD: Lazy<Mutex<Option<PathBuf>>> = Lazy::new(|| Mutex::new(None));
+
+/// Turn dry-run mode on for the rest of the process.
+pub fn enable(base_path: &Path) {
+    *GUILD.lock().unwrap_or_else(|e| e.into_inner()) = Some(base_path.to_path_buf());
+}
+
+pub fn is_enabled() -> bool {
+    GUILD.lock().unwrap_or_else(|e| e.into_inner()).is_some()
+}
+
+/// Tools that still run in a dry run: lookups that change nothing.
+pub fn runs_for_real(tool: &str) -> bool {
+    crate::tools::is_read_only_tool(tool)
+        || tool == crate::web_search::WEB_SEARCH_TOOL_NAME
+        || tool == crate::fetch_url::FETCH_URL_TOOL_NAME
+}
+
+fn log_line(action: &str, target: &str, detail: &Value) -> String {
+    json!({
+        "timestamp": Local::now().to_rfc3339(),
+        "action": action,
+        "target": target,
+        "detail": detail,
+    })
+    .to_string()
+}
+
+/// Log an action that was skipped, e.g. `("tool", "write", args)` or
+/// `("discord", channel_id, text)`.
+pub fn record(action: &str, target: &str, detail: Value) {
+    let Some(base_path) = GUILD.lock().unwrap_or_else(|e| e.into_inner()).clone() else {
+        return;
+    };
+    log_info!("steward", "🧪 [dry run] Skipped {} `{}`", action, target);
+    let path = base_path.join(DRY_RUN_LOG);
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let written = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", log_line(action, target, &detail)));
+    if let Err(e) = written {
+        log_warn!("steward", "⚠️ Failed to log dry-run action: {}", e);
+    }
+}
+
+/// What a simulated tool call tells the model in place of the tool's output.
+pub fn simulated_tool_output(tool: &str, args: &Value) -> String {
+    format!(
+        "[dry run] `{}` was not executed. It would have run with: {}",
+        tool, args
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_lookups_run_for_real() {
+        assert!(runs_for_real("read"));
+        assert!(runs_for_real("web_search"));
+        assert!(!runs_for_real("write"));
+        assert!(!runs_for_real("exec"));
+        assert!(!runs_for_real("stock_quote"));
+
+        let line: Value =
+            serde_json::from_str(&log_line("tool", "write", &json!({ "path": "a.md" }))).unwrap();
+        assert_eq!(line["action"], "tool");
+        assert_eq!(line["detail"]["path"], "a.md");
+        assert_eq!(
+            simulated_tool_output("write", &json!({ "path": "a.md" })),
+            "[dry run] `write` was not executed. It would have run with: {\"path\":\"a.md\"}"
+        );
+    }
+}
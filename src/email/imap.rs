@@ -0,0 +1,224 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/email/imap.rs
+ * Responsibility: Minimal IMAP4rev1 client over TLS (login, search unseen, fetch, mark seen).
+ */
+
+use anyhow::{Context, Result, anyhow};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_native_tls::{TlsConnector, TlsStream, native_tls};
+
+/// One untagged server response line plus any literal payloads it carried.
+#[derive(Debug, Default)]
+pub(crate) struct UntaggedResponse {
+    pub(crate) text: String,
+    pub(crate) literals: Vec<Vec<u8>>,
+    /// Literals longer than the session's cap, read and discarded instead of kept.
+    pub(crate) oversized_literals: usize,
+}
+
+pub(crate) struct ImapSession {
+    stream: BufReader<TlsStream<TcpStream>>,
+    next_tag: u32,
+    max_literal_bytes: usize,
+}
+
+impl ImapSession {
+    /// Connect over TLS. Literals the server announces above `max_literal_bytes` are
+    /// drained from the stream without being buffered.
+    pub(crate) async fn connect(host: &str, port: u16, max_literal_bytes: usize) -> Result<Self> {
+        let tcp = TcpStream::connect((host, port))
+            .await
+            .with_context(|| format!("connecting to IMAP server {}:{}", host, port))?;
+        let connector = TlsConnector::from(native_tls::TlsConnector::new()?);
+        let tls = connector.connect(host, tcp).await?;
+        let mut session = Self {
+            stream: BufReader::new(tls),
+            next_tag: 1,
+            max_literal_bytes,
+        };
+
+        let greeting = session.read_line().await?;
+        if !greeting.starts_with("* OK") && !greeting.starts_with("* PREAUTH") {
+            return Err(anyhow!("Unexpected IMAP greeting: {}", greeting.trim()));
+        }
+        Ok(session)
+    }
+
+    pub(crate) async fn login(&mut self, username: &str, password: &str) -> Result<()> {
+        self.command(&format!(
+            "LOGIN {} {}",
+            quote_imap_string(username),
+            quote_imap_string(password)
+        ))
+        .await
+        .map(|_| ())
+    }
+
+    pub(crate) async fn select(&mut self, mailbox: &str) -> Result<()> {
+        self.command(&format!("SELECT {}", quote_imap_string(mailbox)))
+            .await
+            .map(|_| ())
+    }
+
+    pub(crate) async fn search_unseen(&mut self) -> Result<Vec<u32>> {
+        let responses = self.command("UID SEARCH UNSEEN").await?;
+        Ok(responses
+            .iter()
+            .flat_map(|response| parse_search_response(&response.text))
+            .collect())
+    }
+
+    /// Fetch the full RFC 822 message without setting `\Seen`; `None` when it is larger
+    /// than the session's literal cap.
+    pub(crate) async fn fetch_raw(&mut self, uid: u32) -> Result<Option<Vec<u8>>> {
+        let responses = self
+            .command(&format!("UID FETCH {} BODY.PEEK[]", uid))
+            .await?;
+        if responses
+            .iter()
+            .any(|response| response.oversized_literals > 0)
+        {
+            return Ok(None);
+        }
+        responses
+            .into_iter()
+            .find_map(|response| response.literals.into_iter().next())
+            .map(Some)
+            .ok_or_else(|| anyhow!("IMAP server returned no body for UID {}", uid))
+    }
+
+    pub(crate) async fn mark_seen(&mut self, uid: u32) -> Result<()> {
+        self.command(&format!("UID STORE {} +FLAGS (\\Seen)", uid))
+            .await
+            .map(|_| ())
+    }
+
+    pub(crate) async fn logout(mut self) -> Result<()> {
+        self.command("LOGOUT").await.map(|_| ())
+    }
+
+    async fn command(&mut self, command: &str) -> Result<Vec<UntaggedResponse>> {
+        let tag = format!("T{}", self.next_tag);
+        self.next_tag += 1;
+
+        let stream = self.stream.get_mut();
+        stream
+            .write_all(format!("{} {}\r\n", tag, command).as_bytes())
+            .await?;
+        stream.flush().await?;
+
+        let mut responses = Vec::new();
+        loop {
+            let mut line = self.read_line().await?;
+            if let Some(status) = line.strip_prefix(&format!("{} ", tag)) {
+                if status.starts_with("OK") {
+                    return Ok(responses);
+                }
+                let verb = command.split_whitespace().next().unwrap_or(command);
+                return Err(anyhow!("IMAP {} failed: {}", verb, status.trim()));
+            }
+
+            let mut response = UntaggedResponse::default();
+            while let Some(len) = literal_length(&line) {
+                match read_literal(&mut self.stream, len, self.max_literal_bytes).await? {
+                    Some(literal) => response.literals.push(literal),
+                    None => response.oversized_literals += 1,
+                }
+                response.text.push_str(&line);
+                line = self.read_line().await?;
+            }
+            response.text.push_str(&line);
+            responses.push(response);
+        }
+    }
+
+    async fn read_line(&mut self) -> Result<String> {
+        let mut buf = Vec::new();
+        let read = self.stream.read_until(b'\n', &mut buf).await?;
+        if read == 0 {
+            return Err(anyhow!("IMAP connection closed unexpectedly"));
+        }
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+
+/// Read a `len`-byte literal, or drain it and return `None` when it exceeds `max_bytes`,
+/// so a server-announced size never decides how much is allocated.
+async fn read_literal<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    len: usize,
+    max_bytes: usize,
+) -> Result<Option<Vec<u8>>> {
+    if len > max_bytes {
+        let skipped = tokio::io::copy(&mut reader.take(len as u64), &mut tokio::io::sink()).await?;
+        if skipped < len as u64 {
+            return Err(anyhow!("IMAP connection closed unexpectedly"));
+        }
+        return Ok(None);
+    }
+    let mut literal = vec![0u8; len];
+    reader.read_exact(&mut literal).await?;
+    Ok(Some(literal))
+}
+
+fn quote_imap_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns `n` when a response line ends with an IMAP literal marker `{n}`.
+fn literal_length(line: &str) -> Option<usize> {
+    let trimmed = line.trim_end_matches(['\r', '\n']);
+    let open = trimmed.rfind('{')?;
+    trimmed[open + 1..].strip_suffix('}')?.parse().ok()
+}
+
+fn parse_search_response(text: &str) -> Vec<u32> {
+    text.trim()
+        .strip_prefix("* SEARCH")
+        .map(|ids| {
+            ids.split_whitespace()
+                .filter_map(|id| id.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_length_reads_trailing_marker() {
+        assert_eq!(
+            literal_length("* 1 FETCH (UID 7 BODY[] {342}\r\n"),
+            Some(342)
+        );
+        assert_eq!(literal_length("* 1 FETCH (FLAGS (\\Seen))\r\n"), None);
+    }
+
+    #[tokio::test]
+    async fn test_read_literal_drains_oversized_payloads() {
+        let mut reader: &[u8] = b"hello)\r\n";
+        assert_eq!(
+            read_literal(&mut reader, 5, 5).await.unwrap(),
+            Some(b"hello".to_vec())
+        );
+        assert_eq!(reader, b")\r\n");
+
+        let mut reader: &[u8] = b"hello)\r\n";
+        assert_eq!(read_literal(&mut reader, 5, 4).await.unwrap(), None);
+        assert_eq!(reader, b")\r\n");
+
+        let mut reader: &[u8] = b"hel";
+        assert!(read_literal(&mut reader, usize::MAX, 4).await.is_err());
+    }
+
+    #[test]
+    fn test_parse_search_response_and_quoting() {
+        assert_eq!(parse_search_response("* SEARCH 3 9 12\r\n"), vec![3, 9, 12]);
+        assert!(parse_search_response("* SEARCH\r\n").is_empty());
+        assert_eq!(quote_imap_string(r#"pa"ss\"#), r#""pa\"ss\\""#);
+    }
+}
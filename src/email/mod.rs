@@ -0,0 +1,320 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/email/mod.rs
+ * Responsibility: Email inscriber (IMAP polling into channels/email/) and SMTP delivery.
+ */
+
+mod imap;
+
+use crate::config::{Config, EmailConfig};
+use crate::discord::client as discord_client;
+use crate::discord::ingest_store::append_to_message_log;
+use crate::{log_info, log_warn};
+use chrono::Local;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Folder under `channels/` that holds one blackboard per email thread.
+pub const EMAIL_CHANNEL_DIR: &str = "email";
+const MAX_INSCRIBED_BODY_CHARS: usize = 20_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct InboundEmail {
+    pub(crate) from_name: String,
+    pub(crate) from_address: String,
+    pub(crate) subject: String,
+    pub(crate) message_id: String,
+    pub(crate) in_reply_to: Option<String>,
+    pub(crate) date: String,
+    pub(crate) body: String,
+}
+
+fn strip_reply_prefixes(subject: &str) -> &str {
+    let mut current = subject.trim();
+    loop {
+        let lower = current.to_ascii_lowercase();
+        let stripped = ["re:", "fw:", "fwd:", "aw:"]
+            .iter()
+            .find(|prefix| lower.starts_with(*prefix))
+            .map(|prefix| current[prefix.len()..].trim_start());
+        match stripped {
+            Some(rest) => current = rest,
+            None => return current,
+        }
+    }
+}
+
+/// Map a subject line to a stable thread file name, so replies land in the same blackboard.
+pub(crate) fn thread_slug(subject: &str) -> String {
+    let mut slug = String::new();
+    for ch in strip_reply_prefixes(subject).chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+        if slug.chars().count() >= 60 {
+            break;
+        }
+    }
+
+    let slug = slug.trim_end_matches('-').to_string();
+    if slug.is_empty() {
+        "no-subject".to_string()
+    } else {
+        slug
+    }
+}
+
+pub(crate) fn parse_email(raw: &[u8]) -> Option<InboundEmail> {
+    let message = mail_parser::MessageParser::default().parse(raw)?;
+    let sender = message.from().and_then(|address| address.first());
+    let from_address = sender
+        .and_then(|addr| addr.address())
+        .unwrap_or("unknown")
+        .to_string();
+    let from_name = sender
+        .and_then(|addr| addr.name())
+        .unwrap_or(&from_address)
+        .to_string();
+
+    let mut body = message
+        .body_text(0)
+        .map(|text| text.trim().to_string())
+        .unwrap_or_default();
+    if body.chars().count() > MAX_INSCRIBED_BODY_CHARS {
+        body = body.chars().take(MAX_INSCRIBED_BODY_CHARS).collect();
+        body.push_str("\n\n... [email truncated]");
+    }
+
+    Some(InboundEmail {
+        from_name,
+        from_address,
+        subject: message.subject().unwrap_or("(no subject)").to_string(),
+        message_id: message
+            .message_id()
+            .map(|id| format!("<{}>", id))
+            .unwrap_or_else(|| format!("<{}@tellar.local>", uuid::Uuid::new_v4())),
+        in_reply_to: message
+            .in_reply_to()
+            .as_text()
+            .map(|id| format!("<{}>", id)),
+        date: message
+            .date()
+            .map(|date| date.to_rfc3339())
+            .unwrap_or_else(|| Local::now().format("%Y-%m-%d %H:%M:%S").to_string()),
+        body,
+    })
+}
+
+/// Append an inbound email to `channels/email/<thread>.md` and return the thread path.
+pub(crate) async fn inscribe_email(
+    workspace_path: &Path,
+    email: &InboundEmail,
+) -> anyhow::Result<PathBuf> {
+    let thread_id = format!("{}/{}.md", EMAIL_CHANNEL_DIR, thread_slug(&email.subject));
+    let content = format!("**Subject**: {}\n\n{}", email.subject, email.body);
+    append_to_message_log(
+        workspace_path,
+        &thread_id,
+        &email.from_name,
+        &email.from_address,
+        &content,
+        &email.message_id,
+        &email.date,
+        email.in_reply_to.clone(),
+        Vec::new(),
+    )
+    .await?;
+    Ok(workspace_path.join("channels").join(thread_id))
+}
+
+async fn poll_inbox_once(
+    workspace_path: &Path,
+    email_config: &EmailConfig,
+) -> anyhow::Result<Vec<(PathBuf, InboundEmail)>> {
+    let mut session = imap::ImapSession::connect(
+        &email_config.imap_host,
+        email_config.imap_port,
+        email_config.max_message_bytes,
+    )
+    .await?;
+    session
+        .login(&email_config.username, &email_config.password)
+        .await?;
+    session.select(&email_config.mailbox).await?;
+
+    let mut inscribed = Vec::new();
+    for uid in session.search_unseen().await? {
+        let Some(raw) = session.fetch_raw(uid).await? else {
+            log_warn!(
+                "runtime",
+                "⚠️ Skipping email larger than {} bytes (UID {}).",
+                email_config.max_message_bytes,
+                uid
+            );
+            session.mark_seen(uid).await?;
+            continue;
+        };
+        match parse_email(&raw) {
+            Some(email) => {
+                let path = inscribe_email(workspace_path, &email).await?;
+                inscribed.push((path, email));
+            }
+            None => log_warn!("runtime", "⚠️ Skipping unparseable email (UID {}).", uid),
+        }
+        session.mark_seen(uid).await?;
+    }
+
+    let _ = session.logout().await;
+    Ok(inscribed)
+}
+
+/// Poll the configured IMAP mailbox forever, inscribing unseen mail into email threads.
+pub async fn start_email_inscriber(
+    workspace_path: PathBuf,
+    config: Arc<Config>,
+) -> anyhow::Result<()> {
+    let email_config = &config.email;
+    log_info!(
+        "runtime",
+        "📧 Email inscriber polling {}@{} every {}s...",
+        email_config.mailbox,
+        email_config.imap_host,
+        email_config.poll_secs
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(email_config.poll_secs.max(30)));
+    loop {
+        interval.tick().await;
+        let inscribed = match poll_inbox_once(&workspace_path, email_config).await {
+            Ok(inscribed) => inscribed,
+            Err(e) => {
+                log_warn!("runtime", "⚠️ Email poll failed: {:?}", e);
+                continue;
+            }
+        };
+
+        for (path, email) in inscribed {
+            let rel = path.strip_prefix(&workspace_path).unwrap_or(&path);
+            log_info!(
+                "runtime",
+                "📧 Inscribed email from {} into {}",
+                email.from_address,
+                rel.display()
+            );
+
+            if let Some(channel_id) = &email_config.notify_channel_id
+                && let Err(e) = discord_client::send_bot_message(
+                    &config.discord.token,
+                    channel_id,
+                    &crate::i18n::Message::NewEmail {
+                        from: &email.from_name,
+                        subject: &email.subject,
+                        path: &rel.display().to_string(),
+                    }
+                    .render(config.runtime.language),
+                )
+                .await
+            {
+                log_warn!("runtime", "⚠️ Failed to announce inscribed email: {:?}", e);
+            }
+        }
+    }
+}
+
+fn parse_mailbox(value: &str) -> anyhow::Result<Mailbox> {
+    value
+        .parse::<Mailbox>()
+        .map_err(|e| anyhow::anyhow!("invalid address `{}`: {}", value, e))
+}
+
+/// Send a plain-text email through the configured SMTP relay. Returns the new Message-ID.
+pub(crate) async fn send_email(
+    config: &Config,
+    to: &str,
+    subject: &str,
+    body: &str,
+    in_reply_to: Option<&str>,
+) -> anyhow::Result<String> {
+    let email_config = &config.email;
+    if !email_config.enabled {
+        return Err(anyhow::anyhow!(
+            "email is not configured. Set `email.enabled: true` in tellar.yml"
+        ));
+    }
+
+    let message_id = format!("<{}@tellar.local>", uuid::Uuid::new_v4());
+    let mut builder = Message::builder()
+        .from(parse_mailbox(email_config.sender())?)
+        .subject(subject)
+        .message_id(Some(message_id.clone()));
+    for recipient in to.split(',').map(str::trim).filter(|r| !r.is_empty()) {
+        builder = builder.to(parse_mailbox(recipient)?);
+    }
+    if let Some(parent) = in_reply_to {
+        builder = builder
+            .in_reply_to(parent.to_string())
+            .references(parent.to_string());
+    }
+    let message = builder.body(body.to_string())?;
+
+    let relay = if email_config.smtp_starttls {
+        AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&email_config.smtp_host)?
+    } else {
+        AsyncSmtpTransport::<Tokio1Executor>::relay(&email_config.smtp_host)?
+    };
+    let mailer = relay
+        .port(email_config.smtp_port)
+        .credentials(Credentials::new(
+            email_config.username.clone(),
+            email_config.password.clone(),
+        ))
+        .build();
+
+    mailer.send(message).await?;
+    Ok(message_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const SAMPLE: &str = "From: Ada Lovelace <ada@example.com>\r\n\
+To: steward@example.com\r\n\
+Subject: Re: Engine Notes\r\n\
+Message-ID: <m2@example.com>\r\n\
+In-Reply-To: <m1@example.com>\r\n\
+Date: Tue, 03 Mar 2026 10:00:00 +0000\r\n\
+\r\n\
+The analytical engine is ready.\r\n";
+
+    #[test]
+    fn test_thread_slug_strips_reply_prefixes() {
+        assert_eq!(thread_slug("Re: FWD: Engine Notes!"), "engine-notes");
+        assert_eq!(thread_slug("Engine Notes"), "engine-notes");
+        assert_eq!(thread_slug("  "), "no-subject");
+    }
+
+    #[tokio::test]
+    async fn test_inscribe_email_appends_to_thread_blackboard() {
+        let dir = tempdir().unwrap();
+        let email = parse_email(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(email.from_address, "ada@example.com");
+        assert_eq!(email.in_reply_to.as_deref(), Some("<m1@example.com>"));
+
+        let path = inscribe_email(dir.path(), &email).await.unwrap();
+        assert_eq!(path, dir.path().join("channels/email/engine-notes.md"));
+
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(content.contains("**Author**: Ada Lovelace (ID: ada@example.com)"));
+        assert!(content.contains("**Message ID**: <m2@example.com>"));
+        assert!(content.contains("**Reply To**: <m1@example.com>"));
+        assert!(content.contains("The analytical engine is ready."));
+    }
+}
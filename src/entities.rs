@@ -0,0 +1,372 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/entities.rs
+ * Responsibility: Per-entity fact sheets in `brain/entities/` and their injection into prompts.
+ */
+
+use crate::memory::{self, MemoryEntry};
+use crate::tools::ToolExecutionResult;
+use chrono::Local;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const NOTE_ENTITY_TOOL_NAME: &str = "note_entity";
+pub const ENTITIES_DIR: &str = "brain/entities";
+const FACTS_SECTION: &str = "Facts";
+const LINKS_SECTION: &str = "Entities";
+const KINDS: [&str; 4] = ["person", "project", "system", "other"];
+/// Sheets injected per turn, and the characters kept from each.
+const MAX_INJECTED: usize = 4;
+const MAX_SHEET_CHARS: usize = 2000;
+
+/// Front matter of an entity sheet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntityHeader {
+    pub name: String,
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// A parsed `brain/entities/<slug>.md` sheet.
+#[derive(Debug, Clone)]
+pub struct EntitySheet {
+    pub slug: String,
+    pub header: EntityHeader,
+    pub body: String,
+}
+
+impl EntitySheet {
+    pub fn parse(slug: &str, content: &str) -> Option<Self> {
+        let rest = content.strip_prefix("---")?;
+        let (yaml, body) = rest.split_once("\n---")?;
+        let header: EntityHeader = serde_yml::from_str(yaml).ok()?;
+        Some(Self {
+            slug: slug.to_string(),
+            header,
+            body: body.trim_start_matches('\n').to_string(),
+        })
+    }
+
+    pub fn render(&self) -> String {
+        format!(
+            "---\n{}---\n{}",
+            serde_yml::to_string(&self.header).unwrap_or_default(),
+            self.body
+        )
+    }
+
+    /// Names the sheet answers to: its name followed by its aliases.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.header.name.as_str())
+            .chain(self.header.aliases.iter().map(String::as_str))
+            .filter(|name| !name.trim().is_empty())
+    }
+}
+
+/// `Ada Lovelace` -> `ada-lovelace`.
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for ch in name.trim().chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+fn sheet_path(base_path: &Path, slug: &str) -> PathBuf {
+    base_path.join(ENTITIES_DIR).join(format!("{}.md", slug))
+}
+
+/// Every readable sheet, sorted by slug.
+pub fn load_sheets(base_path: &Path) -> Vec<EntitySheet> {
+    let Ok(entries) = fs::read_dir(base_path.join(ENTITIES_DIR)) else {
+        return Vec::new();
+    };
+    let mut sheets: Vec<EntitySheet> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let slug = path.file_name()?.to_str()?.strip_suffix(".md")?.to_string();
+            EntitySheet::parse(&slug, &fs::read_to_string(&path).ok()?)
+        })
+        .collect();
+    sheets.sort_by(|a, b| a.slug.cmp(&b.slug));
+    sheets
+}
+
+fn mentions(text: &str, name: &str) -> bool {
+    Regex::new(&format!(r"(?i)(^|\W){}($|\W)", regex::escape(name.trim())))
+        .map(|re| re.is_match(text))
+        .unwrap_or(false)
+}
+
+/// Sheets for the entities `text` names, as a system-prompt section, or an empty string.
+pub fn entity_section(base_path: &Path, text: &str) -> String {
+    let mentioned: Vec<EntitySheet> = load_sheets(base_path)
+        .into_iter()
+        .filter(|sheet| sheet.names().any(|name| mentions(text, name)))
+        .take(MAX_INJECTED)
+        .collect();
+    if mentioned.is_empty() {
+        return String::new();
+    }
+    let mut out = format!("\n\n### Entities Mentioned ({}):\n", ENTITIES_DIR);
+    for sheet in mentioned {
+        let mut body: String = sheet.body.trim().chars().take(MAX_SHEET_CHARS).collect();
+        if sheet.body.trim().chars().count() > MAX_SHEET_CHARS {
+            body.push('…');
+        }
+        out.push_str(&format!(
+            "\n[{}/{}.md] {} ({})\n{}\n",
+            ENTITIES_DIR,
+            sheet.slug,
+            sheet.header.name,
+            if sheet.header.kind.is_empty() {
+                "other"
+            } else {
+                &sheet.header.kind
+            },
+            body
+        ));
+    }
+    out
+}
+
+/// Link the sheet from the channel's KNOWLEDGE.md once, under `## Entities`.
+fn link_from_channel(base_path: &Path, channel_id: &str, sheet: &EntitySheet) -> Option<String> {
+    if channel_id == "0" {
+        return None;
+    }
+    let folder = crate::discord::resolve_folder_by_id(base_path, channel_id)?;
+    let path = base_path
+        .join("channels")
+        .join(&folder)
+        .join("KNOWLEDGE.md");
+    let target = format!("../../{}/{}.md", ENTITIES_DIR, sheet.slug);
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    if content.contains(&target) {
+        return None;
+    }
+    let link = format!("- [{}]({})", sheet.header.name, target);
+    let next = memory::insert_under_heading(&content, LINKS_SECTION, &link);
+    fs::write(&path, next).ok()?;
+    Some(format!("channels/{}/KNOWLEDGE.md", folder))
+}
+
+fn string_arg<'a>(args: &'a Value, field: &str) -> Option<&'a str> {
+    args.get(field)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn run_note_entity(args: &Value, base_path: &Path, channel_id: &str) -> ToolExecutionResult {
+    let Some(name) = string_arg(args, "name") else {
+        return ToolExecutionResult::error("Error: Missing required argument `name`.");
+    };
+    let slug = slugify(name);
+    if slug.is_empty() {
+        return ToolExecutionResult::error("Error: `name` needs at least one letter or digit.");
+    }
+    let kind = string_arg(args, "kind").unwrap_or("other");
+    if !KINDS.contains(&kind) {
+        return ToolExecutionResult::error(format!(
+            "Error: Unknown kind `{}`; use {}.",
+            kind,
+            KINDS.join(", ")
+        ));
+    }
+    let aliases: Vec<String> = args
+        .get("aliases")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(memory::clean_value)
+        .filter(|alias| !alias.is_empty())
+        .collect();
+
+    let _guard = memory::MEMORY_LOCK
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let path = sheet_path(base_path, &slug);
+    let existed = path.exists();
+    let mut sheet = fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| EntitySheet::parse(&slug, &content))
+        .unwrap_or_else(|| EntitySheet {
+            slug: slug.clone(),
+            header: EntityHeader {
+                name: memory::clean_value(name),
+                kind: kind.to_string(),
+                aliases: Vec::new(),
+            },
+            body: format!("# {}\n", memory::clean_value(name)),
+        });
+    if string_arg(args, "kind").is_some() {
+        sheet.header.kind = kind.to_string();
+    }
+    for alias in aliases {
+        if !sheet
+            .names()
+            .any(|known| known.eq_ignore_ascii_case(&alias))
+        {
+            sheet.header.aliases.push(alias);
+        }
+    }
+
+    let mut notes = Vec::new();
+    if let Some(fact) = string_arg(args, "fact") {
+        let fact = memory::clean_value(fact);
+        if sheet
+            .body
+            .lines()
+            .filter_map(MemoryEntry::parse)
+            .any(|entry| entry.fact.eq_ignore_ascii_case(&fact))
+        {
+            notes.push(format!("Already noted: {}", fact));
+        } else {
+            let mut fields = vec![
+                ("id".to_string(), memory::new_entry_id()),
+                (
+                    "added".to_string(),
+                    Local::now().format("%Y-%m-%d %H:%M").to_string(),
+                ),
+            ];
+            if let Some(by) = string_arg(args, "by") {
+                fields.push(("by".to_string(), memory::clean_value(by)));
+            }
+            if channel_id != "0" {
+                fields.push(("channel".to_string(), channel_id.to_string()));
+            }
+            let entry = MemoryEntry { fact, fields };
+            sheet.body = memory::insert_under_heading(&sheet.body, FACTS_SECTION, &entry.render());
+            notes.push(format!("Noted: {}", entry.fact));
+        }
+    }
+
+    if let Some(parent) = path.parent()
+        && let Err(error) = fs::create_dir_all(parent)
+    {
+        return ToolExecutionResult::error(format!("Error creating folder: {}", error));
+    }
+    if let Err(error) = fs::write(&path, sheet.render()) {
+        return ToolExecutionResult::error(format!("Error writing entity sheet: {}", error));
+    }
+    if let Some(linked) = link_from_channel(base_path, channel_id, &sheet) {
+        notes.push(format!("Linked from {}", linked));
+    }
+    ToolExecutionResult::success(format!(
+        "{} {}/{}.md ({}).{}",
+        if existed { "Updated" } else { "Created" },
+        ENTITIES_DIR,
+        slug,
+        sheet.header.kind,
+        notes
+            .iter()
+            .map(|note| format!("\n{}", note))
+            .collect::<String>()
+    ))
+}
+
+pub(crate) fn entity_tool_definitions() -> Vec<Value> {
+    vec![json!({
+        "name": NOTE_ENTITY_TOOL_NAME,
+        "description": "Create or update the fact sheet for a recurring person, project, or system in brain/entities/<slug>.md and link it from this channel's KNOWLEDGE.md. Sheets are added to the prompt whenever their name or an alias comes up, so record durable facts about someone or something here rather than in KNOWLEDGE.md.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "description": "Canonical name, e.g. \"Ada Lovelace\" or \"Boiler\"" },
+                "kind": { "type": "string", "enum": KINDS, "description": "Defaults to other for new sheets" },
+                "fact": { "type": "string", "description": "One self-contained sentence to add under Facts" },
+                "aliases": { "type": "array", "items": { "type": "string" }, "description": "Other names people use for it" },
+                "by": { "type": "string", "description": "Who stated the fact" }
+            },
+            "required": ["name"]
+        }
+    })]
+}
+
+pub(crate) fn dispatch_entity_tool(
+    name: &str,
+    args: &Value,
+    base_path: &Path,
+    channel_id: &str,
+) -> Option<ToolExecutionResult> {
+    (name == NOTE_ENTITY_TOOL_NAME).then(|| run_note_entity(args, base_path, channel_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_note_entity_builds_sheet_links_channel_and_injects_on_mention() {
+        let dir = tempdir().unwrap();
+        let folder = dir.path().join("channels/general-123456");
+        fs::create_dir_all(&folder).unwrap();
+        assert_eq!(slugify("  Ada  Lovelace! "), "ada-lovelace");
+
+        let created = dispatch_entity_tool(
+            NOTE_ENTITY_TOOL_NAME,
+            &json!({ "name": "Ada Lovelace", "kind": "person", "fact": "Prefers email over calls", "aliases": ["Ada"] }),
+            dir.path(),
+            "999123456",
+        )
+        .unwrap();
+        assert!(!created.is_error, "{}", created.output);
+        assert!(
+            created
+                .output
+                .starts_with("Created brain/entities/ada-lovelace.md (person).")
+        );
+        assert!(
+            created
+                .output
+                .contains("Linked from channels/general-123456/KNOWLEDGE.md")
+        );
+
+        let updated = dispatch_entity_tool(
+            NOTE_ENTITY_TOOL_NAME,
+            &json!({ "name": "ada lovelace", "fact": "Owns the garden project", "aliases": ["ADA", "Countess"] }),
+            dir.path(),
+            "999123456",
+        )
+        .unwrap();
+        assert!(updated.output.starts_with("Updated") && !updated.output.contains("Linked"));
+
+        let sheet = &load_sheets(dir.path())[0];
+        assert_eq!(sheet.header.kind, "person");
+        assert_eq!(sheet.header.aliases, ["Ada", "Countess"]);
+        let facts: Vec<String> = sheet
+            .body
+            .lines()
+            .filter_map(MemoryEntry::parse)
+            .map(|e| e.fact)
+            .collect();
+        assert_eq!(
+            facts,
+            ["Prefers email over calls", "Owns the garden project"]
+        );
+
+        let knowledge = fs::read_to_string(folder.join("KNOWLEDGE.md")).unwrap();
+        assert_eq!(
+            knowledge,
+            "## Entities\n\n- [Ada Lovelace](../../brain/entities/ada-lovelace.md)\n"
+        );
+
+        let section = entity_section(dir.path(), "Can you ask ada about the roses?");
+        assert!(section.contains("[brain/entities/ada-lovelace.md] Ada Lovelace (person)"));
+        assert!(section.contains("Owns the garden project"));
+        assert!(entity_section(dir.path(), "Check the adapter").is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/env_overrides.rs
+ * Responsibility: Layer `TELLAR__SECTION__FIELD` environment variables over tellar.yml.
+ */
+
+use serde_yml::{Mapping, Value};
+
+/// Variables starting with this override config fields, `__` separating levels:
+/// `TELLAR__GEMINI__MODEL=gemini-2.5-pro`.
+pub const ENV_PREFIX: &str = "TELLAR__";
+
+/// `(path, raw value)` for every override in the environment, sorted so that applying
+/// them is deterministic. Path segments are lowercased.
+pub fn from_env() -> Vec<(Vec<String>, String)> {
+    let mut overrides: Vec<(Vec<String>, String)> = std::env::vars()
+        .filter_map(|(name, value)| {
+            let path = name.strip_prefix(ENV_PREFIX)?;
+            let path: Vec<String> = path.split("__").map(str::to_lowercase).collect();
+            (!path.iter().any(String::is_empty)).then_some((path, value))
+        })
+        .collect();
+    overrides.sort();
+    overrides
+}
+
+fn lookup<'a>(node: &'a Value, path: &[String]) -> Option<&'a Value> {
+    let Some((first, rest)) = path.split_first() else {
+        return Some(node);
+    };
+    let child = match node {
+        Value::Mapping(map) => map.get(first.as_str()),
+        Value::Sequence(items) => items.get(first.parse::<usize>().ok()?),
+        _ => None,
+    }?;
+    lookup(child, rest)
+}
+
+fn set(node: &mut Value, path: &[String], value: Value) {
+    let Some((first, rest)) = path.split_first() else {
+        *node = value;
+        return;
+    };
+    if let Value::Sequence(items) = node
+        && let Some(item) = first.parse::<usize>().ok().and_then(|i| items.get_mut(i))
+    {
+        return set(item, rest, value);
+    }
+    if !node.is_mapping() {
+        *node = Value::Mapping(Mapping::new());
+    }
+    if let Value::Mapping(map) = node {
+        let child = map
+            .entry(Value::String(first.clone()))
+            .or_insert(Value::Null);
+        set(child, rest, value);
+    }
+}
+
+/// Environment values are text, so the field they replace decides how they are read:
+/// string fields take them verbatim, other fields as YAML (`true`, `5`, `[brain, rituals]`).
+/// For fields with no value to go by, whole numbers stay text since those are IDs.
+fn typed_value(raw: &str, hint: Option<&Value>) -> Value {
+    let parsed = || serde_yml::from_str::<Value>(raw).unwrap_or(Value::String(raw.to_string()));
+    match hint {
+        Some(Value::String(_)) => Value::String(raw.to_string()),
+        Some(Value::Null) | None => match parsed() {
+            Value::Number(number) if !number.is_f64() => Value::String(raw.to_string()),
+            Value::Null => Value::String(raw.to_string()),
+            value => value,
+        },
+        Some(_) => parsed(),
+    }
+}
+
+/// Apply `overrides` to the parsed config, using `defaults` (the serialized default config)
+/// for type hints where the file does not set a field.
+pub fn apply(config: &mut Value, defaults: &Value, overrides: &[(Vec<String>, String)]) {
+    if !config.is_mapping() {
+        *config = Value::Mapping(Mapping::new());
+    }
+    for (path, raw) in overrides {
+        let hint = lookup(config, path).or_else(|| lookup(defaults, path));
+        let value = typed_value(raw, hint);
+        set(config, path, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn overrides(pairs: &[(&str, &str)]) -> Vec<(Vec<String>, String)> {
+        pairs
+            .iter()
+            .map(|(path, raw)| {
+                (
+                    path.split("__").map(str::to_lowercase).collect(),
+                    raw.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_overrides_follow_the_type_of_the_field_they_replace() {
+        let defaults = serde_yml::to_value(Config::default()).unwrap();
+        let mut value: Value = serde_yml::from_str(
+            "gemini:\n  api_key: file-key\n  model: gemini-2.5-flash\ndiscord:\n  token: t\n",
+        )
+        .unwrap();
+        apply(
+            &mut value,
+            &defaults,
+            &overrides(&[
+                ("GEMINI__MODEL", "gemini-2.5-pro"),
+                ("GEMINI__API_KEY", "12345"),
+                ("GEMINI__TEMPERATURE", "0.2"),
+                ("DISCORD__GUILD_ID", "987654321"),
+                ("RUNTIME__MAX_TURNS", "9"),
+                ("GUARDIAN__ENABLED", "false"),
+                ("GUARDIAN__PATHS", "[brain, rituals]"),
+                ("GUARDIAN__PATHS__1", "channels"),
+                ("WATCH__MODE", "poll"),
+            ]),
+        );
+        let config: Config = serde_yml::from_value(value).unwrap();
+        assert_eq!(config.gemini.model, "gemini-2.5-pro");
+        assert_eq!(config.gemini.api_key, "12345");
+        assert_eq!(config.gemini.temperature, Some(0.2));
+        assert_eq!(config.discord.guild_id.as_deref(), Some("987654321"));
+        assert_eq!(config.runtime.max_turns, 9);
+        assert!(!config.guardian.enabled);
+        assert_eq!(config.guardian.paths, ["brain", "channels"]);
+        assert_eq!(config.watch.mode, crate::config::WatchMode::Poll);
+
+        // Without a file, the environment alone can configure the steward.
+        let mut value = Value::Null;
+        apply(
+            &mut value,
+            &defaults,
+            &overrides(&[
+                ("GEMINI__API_KEY", "k"),
+                ("GEMINI__MODEL", "m"),
+                ("DISCORD__TOKEN", "t"),
+            ]),
+        );
+        let config: Config = serde_yml::from_value(value).unwrap();
+        assert_eq!(config.discord.token, "t");
+    }
+}
@@ -0,0 +1,497 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/exec_jobs.rs
+ * Responsibility: Background `exec` jobs (`exec_start`, `exec_status`, `exec_logs`, `exec_kill`) persisted under brain/jobs/.
+ */
+
+use crate::config::Config;
+use crate::tools::ToolExecutionResult;
+use crate::{log_info, log_warn};
+use chrono::Local;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+pub const EXEC_START_TOOL_NAME: &str = "exec_start";
+pub const EXEC_STATUS_TOOL_NAME: &str = "exec_status";
+pub const EXEC_LOGS_TOOL_NAME: &str = "exec_logs";
+pub const EXEC_KILL_TOOL_NAME: &str = "exec_kill";
+/// Exempt from the Watchman's brain/*.json event sync.
+pub const JOBS_DIR: &str = "brain/jobs";
+const MAX_RUNNING_JOBS: usize = 8;
+const DEFAULT_LOG_LINES: usize = 50;
+const LISTED_JOBS: usize = 20;
+const KILL_GRACE: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum JobStatus {
+    Running,
+    Exited,
+    Killed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JobRecord {
+    id: String,
+    command: String,
+    status: JobStatus,
+    pid: Option<u32>,
+    started_at: String,
+    finished_at: Option<String>,
+    exit_code: Option<i32>,
+}
+
+/// Stop handles for jobs started by this process, keyed by job ID. The handle is taken
+/// once exec_kill has asked the job to stop.
+static RUNNING: Lazy<Mutex<HashMap<String, Option<oneshot::Sender<()>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static JOB_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+fn record_path(base_path: &Path, id: &str) -> PathBuf {
+    base_path.join(JOBS_DIR).join(format!("{}.json", id))
+}
+
+fn log_path(base_path: &Path, id: &str) -> PathBuf {
+    base_path.join(JOBS_DIR).join(format!("{}.log", id))
+}
+
+fn load_record(base_path: &Path, id: &str) -> Option<JobRecord> {
+    let raw = fs::read_to_string(record_path(base_path, id)).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+fn save_record(base_path: &Path, record: &JobRecord) -> std::io::Result<()> {
+    let raw = serde_json::to_string_pretty(record).map_err(std::io::Error::other)?;
+    fs::write(record_path(base_path, &record.id), raw)
+}
+
+fn is_tracked(id: &str) -> bool {
+    RUNNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains_key(id)
+}
+
+fn new_job_id(base_path: &Path) -> String {
+    loop {
+        let id = format!(
+            "{}-{}",
+            Local::now().format("%Y%m%d-%H%M%S"),
+            JOB_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        if !record_path(base_path, &id).exists() {
+            return id;
+        }
+    }
+}
+
+fn describe(record: &JobRecord) -> String {
+    let state = match record.status {
+        JobStatus::Running if !is_tracked(&record.id) => {
+            "lost (Tellar restarted while it ran)".to_string()
+        }
+        JobStatus::Running => "running".to_string(),
+        JobStatus::Exited => match record.exit_code {
+            Some(code) => format!("exited with code {}", code),
+            None => "exited (terminated by signal)".to_string(),
+        },
+        JobStatus::Killed => "killed".to_string(),
+    };
+    let finished = record
+        .finished_at
+        .as_deref()
+        .map(|at| format!(", finished {}", at))
+        .unwrap_or_default();
+    format!(
+        "{}: {} — `{}` (started {}{})",
+        record.id, state, record.command, record.started_at, finished
+    )
+}
+
+/// Ask the whole process group to stop so children of `sh -c` go too.
+#[cfg(unix)]
+fn terminate_group(pid: u32) {
+    if let Ok(pid) = libc::pid_t::try_from(pid) {
+        unsafe {
+            libc::kill(-pid, libc::SIGTERM);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn terminate_group(_pid: u32) {}
+
+fn run_exec_start(args: &Value, base_path: &Path, config: &Config) -> ToolExecutionResult {
+    let Some(command) = args
+        .get("command")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+    else {
+        return ToolExecutionResult::error("Error: Missing required argument `command`.");
+    };
+    if !config.runtime.privileged {
+        return ToolExecutionResult::error(
+            "Error: `exec_start` is disabled because runtime.privileged=false. Explain the limitation or enable privileged mode.",
+        );
+    }
+    let running = RUNNING.lock().unwrap_or_else(|e| e.into_inner()).len();
+    if running >= MAX_RUNNING_JOBS {
+        return ToolExecutionResult::error(format!(
+            "Error: {} jobs are already running. Wait for one to finish or stop one with exec_kill.",
+            running
+        ));
+    }
+
+    if let Err(e) = fs::create_dir_all(base_path.join(JOBS_DIR)) {
+        return ToolExecutionResult::error(format!("Error creating {}: {}", JOBS_DIR, e));
+    }
+    let id = new_job_id(base_path);
+    let log = match File::create(log_path(base_path, &id)) {
+        Ok(log) => log,
+        Err(e) => return ToolExecutionResult::error(format!("Error creating job log: {}", e)),
+    };
+    let stderr_log = match log.try_clone() {
+        Ok(log) => log,
+        Err(e) => return ToolExecutionResult::error(format!("Error creating job log: {}", e)),
+    };
+
+    let mode = config.runtime.exec_mode;
+    let mut process = crate::exec_sandbox::exec_command(
+        mode,
+        &config.runtime.exec_sandbox,
+        command,
+        base_path,
+        &crate::child_env::exec_env(config, base_path),
+    );
+    process
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(log))
+        .stderr(Stdio::from(stderr_log));
+    #[cfg(unix)]
+    process.process_group(0);
+    let mut child = match process.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            let _ = fs::remove_file(log_path(base_path, &id));
+            return ToolExecutionResult::error(format!("Error starting job: {}", e));
+        }
+    };
+    log_info!(
+        "steward",
+        "🔴 [AUDIT] Started background job {}: {}",
+        id,
+        command
+    );
+
+    let mut record = JobRecord {
+        id: id.clone(),
+        command: command.to_string(),
+        status: JobStatus::Running,
+        pid: child.id(),
+        started_at: Local::now().to_rfc3339(),
+        finished_at: None,
+        exit_code: None,
+    };
+    if let Err(e) = save_record(base_path, &record) {
+        log_warn!("steward", "⚠️ Failed to save job record {}: {}", id, e);
+    }
+    let (stop_tx, stop_rx) = oneshot::channel();
+    RUNNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(id.clone(), Some(stop_tx));
+
+    let base_path = base_path.to_path_buf();
+    tokio::spawn(async move {
+        let (status, exit_code) = tokio::select! {
+            status = child.wait() => (JobStatus::Exited, status.ok().and_then(|s| s.code())),
+            _ = stop_rx => {
+                if let Some(pid) = record.pid {
+                    terminate_group(pid);
+                }
+                if tokio::time::timeout(KILL_GRACE, child.wait()).await.is_err() {
+                    let _ = child.kill().await;
+                }
+                (JobStatus::Killed, None)
+            }
+        };
+        RUNNING
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&record.id);
+        record.status = status;
+        record.exit_code = exit_code;
+        record.finished_at = Some(Local::now().to_rfc3339());
+        if let Err(e) = save_record(&base_path, &record) {
+            log_warn!(
+                "steward",
+                "⚠️ Failed to save job record {}: {}",
+                record.id,
+                e
+            );
+        }
+        log_info!("steward", "🏁 Background job {}: {:?}", record.id, status);
+    });
+
+    ToolExecutionResult::success(format!(
+        "Started job {}. Check it with exec_status or exec_logs (id: {}).",
+        id, id
+    ))
+}
+
+fn job_id_arg(args: &Value) -> Option<&str> {
+    args.get("id")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && !id.contains(['/', '\\']) && !id.contains(".."))
+}
+
+fn run_exec_status(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    if let Some(id) = job_id_arg(args) {
+        return match load_record(base_path, id) {
+            Some(record) => ToolExecutionResult::success(describe(&record)),
+            None => ToolExecutionResult::error(format!("Error: No job with id `{}`.", id)),
+        };
+    }
+
+    let mut ids: Vec<String> = fs::read_dir(base_path.join(JOBS_DIR))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            name.strip_suffix(".json").map(str::to_string)
+        })
+        .collect();
+    if ids.is_empty() {
+        return ToolExecutionResult::success("No background jobs.");
+    }
+    ids.sort_by(|a, b| b.cmp(a));
+    let lines: Vec<String> = ids
+        .iter()
+        .take(LISTED_JOBS)
+        .filter_map(|id| load_record(base_path, id))
+        .map(|record| format!("- {}", describe(&record)))
+        .collect();
+    ToolExecutionResult::success(format!(
+        "Background jobs (newest first):\n{}",
+        lines.join("\n")
+    ))
+}
+
+fn run_exec_logs(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let Some(id) = job_id_arg(args) else {
+        return ToolExecutionResult::error("Error: Missing required argument `id`.");
+    };
+    let Ok(log) = fs::read(log_path(base_path, id)) else {
+        return ToolExecutionResult::error(format!("Error: No log for job `{}`.", id));
+    };
+    let log = String::from_utf8_lossy(&log);
+    let lines = args
+        .get("lines")
+        .and_then(Value::as_u64)
+        .map(|lines| lines as usize)
+        .unwrap_or(DEFAULT_LOG_LINES)
+        .max(1);
+    let all: Vec<&str> = log.lines().collect();
+    if all.is_empty() {
+        return ToolExecutionResult::success(format!("Job {} has no output yet.", id));
+    }
+    let start = all.len().saturating_sub(lines);
+    let tail = all[start..].join("\n");
+    if start == 0 {
+        ToolExecutionResult::success(tail)
+    } else {
+        ToolExecutionResult::success(format!(
+            "[Last {} of {} lines]\n{}",
+            all.len() - start,
+            all.len(),
+            tail
+        ))
+    }
+}
+
+fn run_exec_kill(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let Some(id) = job_id_arg(args) else {
+        return ToolExecutionResult::error("Error: Missing required argument `id`.");
+    };
+    let stop = RUNNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_mut(id)
+        .map(Option::take);
+    match stop {
+        Some(Some(stop)) => {
+            let _ = stop.send(());
+            log_info!("steward", "🔴 [AUDIT] Stopping background job {}", id);
+            ToolExecutionResult::success(format!("Stopping job {}.", id))
+        }
+        Some(None) => ToolExecutionResult::success(format!("Job {} is already stopping.", id)),
+        None => match load_record(base_path, id) {
+            Some(record) => ToolExecutionResult::error(format!(
+                "Error: Job {} is not running here: {}",
+                id,
+                describe(&record)
+            )),
+            None => ToolExecutionResult::error(format!("Error: No job with id `{}`.", id)),
+        },
+    }
+}
+
+pub(crate) fn exec_job_tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": EXEC_START_TOOL_NAME,
+            "description": "Start a long-running host shell command (build, backup, download) in the background and return a job id immediately. Privileged like exec. Output goes to a log read with exec_logs.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "command": { "type": "string", "description": "Shell command to run" }
+                },
+                "required": ["command"]
+            }
+        }),
+        json!({
+            "name": EXEC_STATUS_TOOL_NAME,
+            "description": "Show whether a background job is running or how it ended. Without an id, lists recent jobs.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string", "description": "Job id from exec_start" }
+                }
+            }
+        }),
+        json!({
+            "name": EXEC_LOGS_TOOL_NAME,
+            "description": "Read the last lines of a background job's combined stdout and stderr.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string", "description": "Job id from exec_start" },
+                    "lines": { "type": "number", "description": "Lines from the end. Defaults to 50" }
+                },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": EXEC_KILL_TOOL_NAME,
+            "description": "Stop a running background job and its child processes.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string", "description": "Job id from exec_start" }
+                },
+                "required": ["id"]
+            }
+        }),
+    ]
+}
+
+pub(crate) fn dispatch_exec_job_tool(
+    name: &str,
+    args: &Value,
+    base_path: &Path,
+    config: &Config,
+) -> Option<ToolExecutionResult> {
+    match name {
+        EXEC_START_TOOL_NAME => Some(run_exec_start(args, base_path, config)),
+        EXEC_STATUS_TOOL_NAME => Some(run_exec_status(args, base_path)),
+        EXEC_LOGS_TOOL_NAME => Some(run_exec_logs(args, base_path)),
+        EXEC_KILL_TOOL_NAME => Some(run_exec_kill(args, base_path)),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn privileged_config() -> Config {
+        let mut config = Config::default();
+        config.runtime.privileged = true;
+        config
+    }
+
+    fn job_id(result: &ToolExecutionResult) -> String {
+        result
+            .output
+            .strip_prefix("Started job ")
+            .and_then(|rest| rest.split('.').next())
+            .unwrap()
+            .to_string()
+    }
+
+    async fn wait_until_finished(base_path: &Path, id: &str) -> JobRecord {
+        for _ in 0..100 {
+            if let Some(record) = load_record(base_path, id)
+                && record.status != JobStatus::Running
+            {
+                return record;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("job {} did not finish", id);
+    }
+
+    #[tokio::test]
+    async fn test_background_job_records_exit_code_and_logs() {
+        let dir = tempdir().unwrap();
+        let config = privileged_config();
+        let started = run_exec_start(
+            &json!({ "command": "echo one; echo two >&2; exit 3" }),
+            dir.path(),
+            &config,
+        );
+        assert!(!started.is_error, "{}", started.output);
+        let id = job_id(&started);
+
+        let record = wait_until_finished(dir.path(), &id).await;
+        assert_eq!(record.status, JobStatus::Exited);
+        assert_eq!(record.exit_code, Some(3));
+        assert_eq!(
+            run_exec_logs(&json!({ "id": id, "lines": 1 }), dir.path()).output,
+            "[Last 1 of 2 lines]\ntwo"
+        );
+        let listing = run_exec_status(&json!({}), dir.path()).output;
+        assert!(listing.contains("exited with code 3"));
+    }
+
+    #[tokio::test]
+    async fn test_background_job_can_be_killed_and_needs_privileged_mode() {
+        let dir = tempdir().unwrap();
+        let started = run_exec_start(
+            &json!({ "command": "sleep 30" }),
+            dir.path(),
+            &privileged_config(),
+        );
+        let id = job_id(&started);
+        assert!(
+            run_exec_status(&json!({ "id": id }), dir.path())
+                .output
+                .contains(": running")
+        );
+
+        assert!(!run_exec_kill(&json!({ "id": id }), dir.path()).is_error);
+        let record = wait_until_finished(dir.path(), &id).await;
+        assert_eq!(record.status, JobStatus::Killed);
+        assert!(run_exec_kill(&json!({ "id": id }), dir.path()).is_error);
+
+        let refused = run_exec_start(
+            &json!({ "command": "true" }),
+            dir.path(),
+            &Config::default(),
+        );
+        assert!(refused.output.contains("runtime.privileged=false"));
+    }
+}
@@ -0,0 +1,158 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/exec_sandbox.rs
+ * Responsibility: Build the `exec` process for `runtime.exec_mode`, wrapping it in bubblewrap, firejail, or docker.
+ */
+
+use crate::child_env::ChildEnv;
+use crate::config::{ExecMode, ExecSandboxConfig};
+use std::path::Path;
+use tokio::process::Command;
+
+/// Where the guild is mounted inside bubblewrap and docker sandboxes.
+const SANDBOX_GUILD_DIR: &str = "/guild";
+
+/// Program and arguments that run `command` under `mode` with the guild as working directory.
+/// `forwarded` names variables docker must copy into the container.
+fn sandbox_argv(
+    mode: ExecMode,
+    sandbox: &ExecSandboxConfig,
+    command: &str,
+    guild: &Path,
+    forwarded: &[String],
+) -> Vec<String> {
+    let guild = guild.to_string_lossy().to_string();
+    let mut argv: Vec<String> = Vec::new();
+    let mut push = |parts: &[&str]| argv.extend(parts.iter().map(|part| part.to_string()));
+    match mode {
+        ExecMode::Unrestricted => push(&["sh", "-lc", command]),
+        ExecMode::Bubblewrap => {
+            push(&["bwrap", "--ro-bind", "/usr", "/usr"]);
+            for dir in ["/bin", "/sbin", "/lib", "/lib64", "/etc"] {
+                push(&["--ro-bind-try", dir, dir]);
+            }
+            push(&["--proc", "/proc", "--dev", "/dev", "--tmpfs", "/tmp"]);
+            push(&[
+                "--bind",
+                &guild,
+                SANDBOX_GUILD_DIR,
+                "--chdir",
+                SANDBOX_GUILD_DIR,
+            ]);
+            push(&["--setenv", "TELLAR_WORKSPACE", SANDBOX_GUILD_DIR]);
+            push(&["--unshare-all", "--die-with-parent", "--new-session"]);
+            if sandbox.network {
+                push(&["--share-net"]);
+            }
+            push(&["sh", "-c", command]);
+        }
+        ExecMode::Firejail => {
+            // Whitelisting keeps the guild at its host path, so TELLAR_WORKSPACE stays valid.
+            let whitelist = format!("--whitelist={}", guild);
+            push(&["firejail", "--quiet", "--noprofile", &whitelist]);
+            push(&[
+                "--private-tmp",
+                "--caps.drop=all",
+                "--nonewprivs",
+                "--noroot",
+            ]);
+            if !sandbox.network {
+                push(&["--net=none"]);
+            }
+            push(&["sh", "-c", command]);
+        }
+        ExecMode::Docker => {
+            let volume = format!("{}:{}", guild, SANDBOX_GUILD_DIR);
+            let workspace_env = format!("TELLAR_WORKSPACE={}", SANDBOX_GUILD_DIR);
+            push(&[
+                "docker",
+                "run",
+                "--rm",
+                "-i",
+                "-v",
+                &volume,
+                "-w",
+                SANDBOX_GUILD_DIR,
+            ]);
+            push(&["-e", &workspace_env]);
+            for name in forwarded {
+                push(&["-e", name]);
+            }
+            if !sandbox.network {
+                push(&["--network", "none"]);
+            }
+            push(&[&sandbox.docker_image, "sh", "-c", command]);
+        }
+    }
+    argv
+}
+
+/// The process for one `exec` call, not yet spawned, with only `env` in its environment.
+pub(crate) fn exec_command(
+    mode: ExecMode,
+    sandbox: &ExecSandboxConfig,
+    command: &str,
+    base_path: &Path,
+    env: &ChildEnv,
+) -> Command {
+    let argv = sandbox_argv(mode, sandbox, command, base_path, &env.granted);
+    let mut process = Command::new(&argv[0]);
+    env.apply(&mut process);
+    process
+        .args(&argv[1..])
+        .current_dir(base_path)
+        .env("TELLAR_WORKSPACE", base_path);
+    process
+}
+
+/// Name of the sandbox binary, for "not installed" errors.
+pub(crate) fn sandbox_program(mode: ExecMode) -> Option<&'static str> {
+    match mode {
+        ExecMode::Unrestricted => None,
+        ExecMode::Bubblewrap => Some("bwrap"),
+        ExecMode::Firejail => Some("firejail"),
+        ExecMode::Docker => Some("docker"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sandbox_argv_mounts_guild_and_cuts_network_by_default() {
+        let sandbox = ExecSandboxConfig::default();
+        let guild = Path::new("/srv/guild");
+
+        let docker = sandbox_argv(
+            ExecMode::Docker,
+            &sandbox,
+            "ls",
+            guild,
+            &["TOKEN".to_string()],
+        )
+        .join(" ");
+        assert_eq!(
+            docker,
+            "docker run --rm -i -v /srv/guild:/guild -w /guild -e TELLAR_WORKSPACE=/guild -e TOKEN --network none debian:stable-slim sh -c ls"
+        );
+
+        let bwrap = sandbox_argv(ExecMode::Bubblewrap, &sandbox, "ls", guild, &[]).join(" ");
+        assert!(bwrap.starts_with("bwrap --ro-bind /usr /usr"));
+        assert!(bwrap.contains("--bind /srv/guild /guild --chdir /guild"));
+        assert!(!bwrap.contains("--share-net"));
+        assert!(bwrap.ends_with("sh -c ls"));
+
+        let networked = ExecSandboxConfig {
+            network: true,
+            ..Default::default()
+        };
+        let firejail = sandbox_argv(ExecMode::Firejail, &networked, "ls", guild, &[]).join(" ");
+        assert!(firejail.contains("--whitelist=/srv/guild"));
+        assert!(!firejail.contains("--net=none"));
+        assert_eq!(
+            sandbox_argv(ExecMode::Unrestricted, &sandbox, "ls", guild, &[]),
+            vec!["sh", "-lc", "ls"]
+        );
+    }
+}
@@ -242,6 +242,14 @@ pub(crate) struct ExecutionTrace {
 }
 
 impl ExecutionTrace {
+    /// Names of the tools this execution called, in order.
+    pub(crate) fn called_tools(&self) -> impl Iterator<Item = &str> {
+        self.steps.iter().filter_map(|step| match &step.step {
+            ExecutionStepKind::CalledTool { tool_name, .. } => Some(tool_name.as_str()),
+            _ => None,
+        })
+    }
+
     pub(crate) fn view(&self) -> ExecutionTraceView {
         ExecutionTraceView {
             intent: self.intent,
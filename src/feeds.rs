@@ -0,0 +1,411 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/feeds.rs
+ * Responsibility: Poll RSS/Atom feeds and inscribe new entries into channel blackboards.
+ */
+
+use crate::config::{Config, FeedSource};
+use crate::discord::{ingest_store, resolve_folder_by_id};
+use crate::{log_info, log_warn};
+use chrono::Local;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+const SEEN_DIR: &str = "brain/feeds";
+const MAX_SEEN_PER_SOURCE: usize = 500;
+const MAX_SUMMARY_CHARS: usize = 280;
+const MIN_POLL_SECS: u64 = 60;
+
+static FEED_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(30))
+        .build()
+        .expect("Failed to create feed reqwest client")
+});
+
+static TAG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").expect("valid tag regex"));
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FeedEntry {
+    pub(crate) id: String,
+    pub(crate) title: String,
+    pub(crate) link: Option<String>,
+    pub(crate) published: Option<String>,
+    pub(crate) summary: String,
+}
+
+fn plain_text(html: &str) -> String {
+    let text = TAG_RE
+        .replace_all(html, " ")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= MAX_SUMMARY_CHARS {
+        return text;
+    }
+    let mut clipped: String = text.chars().take(MAX_SUMMARY_CHARS).collect();
+    clipped.push('…');
+    clipped
+}
+
+fn child_text<'a>(node: roxmltree::Node<'a, 'a>, name: &str) -> Option<String> {
+    node.children()
+        .find(|child| child.is_element() && child.tag_name().name() == name)
+        .and_then(|child| child.text())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+fn atom_link(entry: roxmltree::Node) -> Option<String> {
+    let links: Vec<_> = entry
+        .children()
+        .filter(|child| child.is_element() && child.tag_name().name() == "link")
+        .collect();
+    links
+        .iter()
+        .find(|link| link.attribute("rel").is_none_or(|rel| rel == "alternate"))
+        .or(links.first())
+        .and_then(|link| link.attribute("href"))
+        .map(str::to_string)
+}
+
+/// Parse RSS 2.0, RSS 1.0 (RDF), or Atom into entries in document order.
+pub(crate) fn parse_feed(xml: &str) -> anyhow::Result<Vec<FeedEntry>> {
+    let document = roxmltree::Document::parse(xml)?;
+    let root = document.root_element();
+
+    let entries = if root.tag_name().name() == "feed" {
+        root.children()
+            .filter(|node| node.is_element() && node.tag_name().name() == "entry")
+            .map(|entry| {
+                let link = atom_link(entry);
+                let title = child_text(entry, "title").unwrap_or_default();
+                FeedEntry {
+                    id: child_text(entry, "id")
+                        .or_else(|| link.clone())
+                        .unwrap_or_else(|| title.clone()),
+                    title,
+                    link,
+                    published: child_text(entry, "published")
+                        .or_else(|| child_text(entry, "updated")),
+                    summary: plain_text(
+                        &child_text(entry, "summary")
+                            .or_else(|| child_text(entry, "content"))
+                            .unwrap_or_default(),
+                    ),
+                }
+            })
+            .collect()
+    } else {
+        root.descendants()
+            .filter(|node| node.is_element() && node.tag_name().name() == "item")
+            .map(|item| {
+                let link = child_text(item, "link");
+                let title = child_text(item, "title").unwrap_or_default();
+                FeedEntry {
+                    id: child_text(item, "guid")
+                        .or_else(|| link.clone())
+                        .unwrap_or_else(|| title.clone()),
+                    title,
+                    link,
+                    published: child_text(item, "pubDate").or_else(|| child_text(item, "date")),
+                    summary: plain_text(&child_text(item, "description").unwrap_or_default()),
+                }
+            })
+            .collect()
+    };
+    Ok(entries)
+}
+
+/// Entries not inscribed before, capped at `max` (feeds list newest first).
+pub(crate) fn select_new_entries(
+    entries: Vec<FeedEntry>,
+    seen: &[String],
+    max: usize,
+) -> Vec<FeedEntry> {
+    entries
+        .into_iter()
+        .filter(|entry| !entry.id.is_empty() && !seen.contains(&entry.id))
+        .take(max)
+        .collect()
+}
+
+pub(crate) fn render_digest(source: &FeedSource, entries: &[FeedEntry]) -> String {
+    let mut digest = format!(
+        "📰 **{}** — {} new {}\n",
+        source.name,
+        entries.len(),
+        if entries.len() == 1 {
+            "entry"
+        } else {
+            "entries"
+        }
+    );
+    for entry in entries {
+        let title = if entry.title.is_empty() {
+            "(untitled)"
+        } else {
+            entry.title.as_str()
+        };
+        match &entry.link {
+            Some(link) => digest.push_str(&format!("\n- [{}]({})", title, link)),
+            None => digest.push_str(&format!("\n- {}", title)),
+        }
+        if let Some(published) = &entry.published {
+            digest.push_str(&format!(" ({})", published));
+        }
+        if !entry.summary.is_empty() {
+            digest.push_str(&format!("\n  {}", entry.summary));
+        }
+    }
+    digest
+}
+
+/// Append a digest to a ritual so the Watchman runs it, the same way the Rhythm injects.
+pub(crate) fn inject_digest_into_ritual(
+    base_path: &Path,
+    ritual: &str,
+    source_name: &str,
+    digest: &str,
+) -> anyhow::Result<PathBuf> {
+    let relative = Path::new(ritual);
+    if relative.is_absolute()
+        || relative
+            .components()
+            .any(|part| matches!(part, std::path::Component::ParentDir))
+    {
+        return Err(anyhow::anyhow!(
+            "feed ritual must stay inside rituals/: {}",
+            ritual
+        ));
+    }
+    let path = base_path.join("rituals").join(relative);
+    let mut content = std::fs::read_to_string(&path)
+        .map_err(|error| anyhow::anyhow!("cannot read feed ritual {:?}: {}", path, error))?;
+    content.push_str(&format!(
+        "\n\n--- [Feed Digest: {} @ {}] ---\n{}",
+        source_name,
+        Local::now().format("%Y-%m-%d %H:%M:%S"),
+        digest
+    ));
+    crate::journal::write(
+        base_path,
+        &path,
+        &content.replace("status: waiting_for_human", "status: active"),
+    )?;
+    Ok(path)
+}
+
+/// Seen entry IDs live one per line in `brain/feeds/<name>.seen`. A plain list rather than
+/// JSON, because `brain/*.json` edits make the Watchman resync Discord events.
+fn seen_file(base_path: &Path, source_name: &str) -> PathBuf {
+    let name: String = source_name
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || ch == '-' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    base_path.join(SEEN_DIR).join(format!("{}.seen", name))
+}
+
+pub(crate) fn load_seen(base_path: &Path, source_name: &str) -> Vec<String> {
+    std::fs::read_to_string(seen_file(base_path, source_name))
+        .map(|content| content.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_seen(
+    base_path: &Path,
+    source_name: &str,
+    seen: &[String],
+) -> anyhow::Result<()> {
+    let path = seen_file(base_path, source_name);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let start = seen.len().saturating_sub(MAX_SEEN_PER_SOURCE);
+    std::fs::write(path, seen[start..].join("\n"))?;
+    Ok(())
+}
+
+async fn inscribe_digest(
+    base_path: &Path,
+    source: &FeedSource,
+    digest: &str,
+) -> anyhow::Result<()> {
+    let folder = resolve_folder_by_id(base_path, &source.channel_id).ok_or_else(|| {
+        anyhow::anyhow!(
+            "no channel folder found for channel_id {}",
+            source.channel_id
+        )
+    })?;
+    let now = Local::now();
+    ingest_store::append_to_message_log(
+        base_path,
+        &format!("{}/{}.md", folder, now.format("%Y-%m-%d")),
+        &format!("feed:{}", source.name),
+        "feed",
+        digest,
+        &format!("feed-{}", uuid::Uuid::new_v4()),
+        &now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        None,
+        Vec::new(),
+    )
+    .await
+}
+
+/// Fetch one source and inscribe its unseen entries. Returns how many were new.
+async fn poll_source(
+    base_path: &Path,
+    source: &FeedSource,
+    max_entries: usize,
+) -> anyhow::Result<usize> {
+    let mut seen = load_seen(base_path, &source.name);
+    let body = FEED_CLIENT
+        .get(&source.url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let fresh = select_new_entries(parse_feed(&body)?, &seen, max_entries);
+    if fresh.is_empty() {
+        return Ok(0);
+    }
+
+    let digest = render_digest(source, &fresh);
+    inscribe_digest(base_path, source, &digest).await?;
+    if let Some(ritual) = source.ritual.clone() {
+        let (base, name, text) = (base_path.to_path_buf(), source.name.clone(), digest.clone());
+        tokio::task::spawn_blocking(move || {
+            inject_digest_into_ritual(&base, &ritual, &name, &text)
+        })
+        .await??;
+    }
+
+    seen.extend(fresh.iter().map(|entry| entry.id.clone()));
+    save_seen(base_path, &source.name, &seen)?;
+    Ok(fresh.len())
+}
+
+/// Poll every configured feed on `feeds.poll_secs` until the process exits.
+pub async fn start_feed_watcher(base_path: PathBuf, config: Arc<Config>) {
+    let settings = &config.feeds;
+    let interval_secs = settings.poll_secs.max(MIN_POLL_SECS);
+    log_info!(
+        "rhythm",
+        "📰 Watching {} feed(s) every {}s",
+        settings.sources.len(),
+        interval_secs
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+    loop {
+        interval.tick().await;
+        for source in &settings.sources {
+            match poll_source(&base_path, source, settings.max_entries_per_poll).await {
+                Ok(0) => {}
+                Ok(count) => log_info!(
+                    "rhythm",
+                    "📰 Feed `{}` inscribed {} new entries",
+                    source.name,
+                    count
+                ),
+                Err(error) => log_warn!(
+                    "rhythm",
+                    "⚠️ Feed `{}` poll failed: {:?}",
+                    source.name,
+                    error
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>Town News</title>
+  <item><title>Road closed</title><link>https://town.example/road</link><guid>road-1</guid>
+    <pubDate>Mon, 02 Mar 2026 08:00:00 GMT</pubDate><description>&lt;p&gt;Main St &amp;amp; 3rd&lt;/p&gt;</description></item>
+  <item><title>Library hours</title><link>https://town.example/library</link></item>
+</channel></rss>"#;
+
+    const ATOM: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom"><title>Blog</title>
+  <entry><id>tag:blog,2026:1</id><title>Hello</title>
+    <link rel="self" href="https://blog.example/1.atom"/><link rel="alternate" href="https://blog.example/1"/>
+    <updated>2026-03-02T08:00:00Z</updated><summary>First post</summary></entry>
+</feed>"#;
+
+    #[test]
+    fn test_parse_feed_reads_rss_and_atom_entries() {
+        let entries = parse_feed(RSS).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, "road-1");
+        assert_eq!(entries[0].summary, "Main St & 3rd");
+        assert_eq!(entries[1].id, "https://town.example/library");
+
+        let entries = parse_feed(ATOM).unwrap();
+        assert_eq!(entries[0].id, "tag:blog,2026:1");
+        assert_eq!(entries[0].link.as_deref(), Some("https://blog.example/1"));
+        assert_eq!(
+            entries[0].published.as_deref(),
+            Some("2026-03-02T08:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_select_new_entries_skips_seen_and_caps_count() {
+        let entries = parse_feed(RSS).unwrap();
+        let fresh = select_new_entries(entries.clone(), &["road-1".to_string()], 5);
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].title, "Library hours");
+        assert_eq!(select_new_entries(entries, &[], 1).len(), 1);
+
+        let dir = tempdir().unwrap();
+        save_seen(dir.path(), "town news", &["road-1".to_string()]).unwrap();
+        assert_eq!(load_seen(dir.path(), "town news"), vec!["road-1"]);
+        assert!(dir.path().join("brain/feeds/town_news.seen").exists());
+    }
+
+    #[test]
+    fn test_inject_digest_into_ritual_activates_it() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("rituals")).unwrap();
+        std::fs::write(
+            dir.path().join("rituals/news.md"),
+            "---\nstatus: waiting_for_human\n---\nSummarize the news.",
+        )
+        .unwrap();
+        let source = FeedSource {
+            name: "town".to_string(),
+            ..Default::default()
+        };
+        let digest = render_digest(&source, &parse_feed(RSS).unwrap());
+
+        let path = inject_digest_into_ritual(dir.path(), "news.md", "town", &digest).unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(content.contains("status: active"));
+        assert!(content.contains("[Feed Digest: town @"));
+        assert!(content.contains("- [Road closed](https://town.example/road)"));
+        assert!(
+            inject_digest_into_ritual(dir.path(), "../brain/KNOWLEDGE.md", "town", "x").is_err()
+        );
+    }
+}
@@ -0,0 +1,597 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/fetch_url.rs
+ * Responsibility: The `fetch_url` core tool: download a page and reduce it to readable markdown.
+ */
+
+use crate::config::Config;
+use crate::tools::{ToolExecutionResult, is_domain_allowed};
+use ego_tree::NodeRef;
+use once_cell::sync::Lazy;
+use scraper::{ElementRef, Html, Node, Selector};
+use serde_json::{Value, json};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+pub const FETCH_URL_TOOL_NAME: &str = "fetch_url";
+const DEFAULT_MAX_CHARS: usize = 8000;
+const MAX_CHARS_CAP: usize = 40_000;
+const MAX_DOWNLOAD_BYTES: usize = 3_000_000;
+
+/// Elements that never carry article text.
+const SKIPPED_TAGS: [&str; 14] = [
+    "script", "style", "noscript", "nav", "header", "footer", "aside", "form", "iframe", "svg",
+    "button", "template", "select", "dialog",
+];
+
+const MAX_REDIRECTS: usize = 10;
+
+fn client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .user_agent("Mozilla/5.0 (compatible; Tellar/0.1)")
+        .timeout(Duration::from_secs(20))
+}
+
+/// Unrestricted client for `runtime.privileged` guilds.
+static FETCH_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    client_builder()
+        .build()
+        .expect("Failed to create fetch_url reqwest client")
+});
+
+/// Resolves names like the system resolver, but drops private addresses unless the
+/// name is in `http.allowed_domains`, so a public name cannot point a fetch inward.
+struct GuardedResolver {
+    allowed_domains: Vec<String>,
+}
+
+impl reqwest::dns::Resolve for GuardedResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let allowed = is_domain_allowed(name.as_str(), &self.allowed_domains);
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| allowed || !is_private_ip(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(
+                    format!("`{}` only resolves to local or private addresses", host).into(),
+                );
+            }
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// A client for unprivileged fetches: every name is resolved through
+/// [`GuardedResolver`], and every redirect hop is checked like the first URL.
+fn guarded_client(config: &Config) -> reqwest::Result<reqwest::Client> {
+    let allowed_domains = config.http.allowed_domains.clone();
+    let redirect_allowed = allowed_domains.clone();
+    client_builder()
+        .dns_resolver(GuardedResolver { allowed_domains })
+        .redirect(reqwest::redirect::Policy::custom(move |attempt| {
+            let url = attempt.url().clone();
+            let host = url.host_str().unwrap_or_default();
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if !matches!(url.scheme(), "http" | "https") {
+                attempt.error(format!("redirect to non-http(s) URL {}", url))
+            } else if is_private_host(host) && !is_domain_allowed(host, &redirect_allowed) {
+                attempt.error(format!("redirect to local or private address `{}`", host))
+            } else {
+                attempt.follow()
+            }
+        }))
+        .build()
+}
+
+fn selector(css: &str) -> Selector {
+    Selector::parse(css).expect("valid readability selector")
+}
+
+fn is_private_ipv4(ip: Ipv4Addr) -> bool {
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.octets()[0] == 0
+}
+
+/// Loopback, private, link-local, and unspecified addresses, including IPv4 ones
+/// written as IPv4-mapped IPv6.
+fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_private_ipv4(ip),
+        IpAddr::V6(ip) => {
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || (ip.segments()[0] & 0xfe00) == 0xfc00
+                || (ip.segments()[0] & 0xffc0) == 0xfe80
+                || ip.to_ipv4_mapped().is_some_and(is_private_ipv4)
+        }
+    }
+}
+
+/// Loopback, private, and link-local targets stay off-limits to unprivileged fetches.
+/// Names are only caught here when they are local by convention; the rest are checked
+/// after resolution by [`GuardedResolver`].
+pub(crate) fn is_private_host(host: &str) -> bool {
+    let host = host
+        .trim_matches(|ch| ch == '[' || ch == ']')
+        .to_ascii_lowercase();
+    if host == "localhost" || host.ends_with(".localhost") || host.ends_with(".local") {
+        return true;
+    }
+    host.parse::<IpAddr>().is_ok_and(is_private_ip)
+}
+
+fn text_len(element: ElementRef) -> usize {
+    element.text().map(|text| text.trim().len()).sum()
+}
+
+/// The element most likely to hold the main content: `<article>`/`<main>` when present,
+/// otherwise the container whose direct paragraphs carry the most text.
+fn content_root(document: &Html) -> Option<ElementRef<'_>> {
+    for css in ["article", "main", "[role=main]"] {
+        if let Some(element) = document
+            .select(&selector(css))
+            .max_by_key(|element| text_len(*element))
+            .filter(|element| text_len(*element) > 200)
+        {
+            return Some(element);
+        }
+    }
+
+    document
+        .select(&selector("div, section, td"))
+        .map(|element| {
+            let score: usize = element
+                .children()
+                .filter_map(ElementRef::wrap)
+                .filter(|child| child.value().name() == "p")
+                .map(text_len)
+                .sum();
+            (score, element)
+        })
+        .filter(|(score, _)| *score > 0)
+        .max_by_key(|(score, _)| *score)
+        .map(|(_, element)| element)
+        .or_else(|| document.select(&selector("body")).next())
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_space = false;
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            if !last_space {
+                collapsed.push(' ');
+            }
+            last_space = true;
+        } else {
+            collapsed.push(ch);
+            last_space = false;
+        }
+    }
+    collapsed
+}
+
+fn push_block(out: &mut String, block: &str) {
+    let block = block.trim();
+    if block.is_empty() {
+        return;
+    }
+    if !out.is_empty() {
+        out.push_str("\n\n");
+    }
+    out.push_str(block);
+}
+
+fn inline_text(node: NodeRef<Node>, base: Option<&reqwest::Url>) -> String {
+    let mut text = String::new();
+    for child in node.children() {
+        match child.value() {
+            Node::Text(value) => text.push_str(&collapse_whitespace(value)),
+            Node::Element(element) => {
+                let name = element.name();
+                if SKIPPED_TAGS.contains(&name) {
+                    continue;
+                }
+                match name {
+                    "br" => text.push('\n'),
+                    "a" => {
+                        let label = inline_text(child, base).trim().to_string();
+                        let href = element
+                            .attr("href")
+                            .filter(|href| {
+                                !href.starts_with('#') && !href.starts_with("javascript:")
+                            })
+                            .map(|href| match base.and_then(|base| base.join(href).ok()) {
+                                Some(url) => url.to_string(),
+                                None => href.to_string(),
+                            });
+                        match href {
+                            Some(href) if !label.is_empty() => {
+                                text.push_str(&format!("[{}]({})", label, href))
+                            }
+                            _ => text.push_str(&label),
+                        }
+                    }
+                    "code" => text.push_str(&format!("`{}`", inline_text(child, base).trim())),
+                    "strong" | "b" => {
+                        let inner = inline_text(child, base);
+                        if inner.trim().is_empty() {
+                            text.push_str(&inner);
+                        } else {
+                            text.push_str(&format!("**{}**", inner.trim()));
+                        }
+                    }
+                    _ => text.push_str(&inline_text(child, base)),
+                }
+            }
+            _ => {}
+        }
+    }
+    text
+}
+
+fn render_blocks(node: NodeRef<Node>, base: Option<&reqwest::Url>, out: &mut String) {
+    let mut inline = String::new();
+    for child in node.children() {
+        let Node::Element(element) = child.value() else {
+            if let Node::Text(value) = child.value() {
+                inline.push_str(&collapse_whitespace(value));
+            }
+            continue;
+        };
+        let name = element.name();
+        if SKIPPED_TAGS.contains(&name) {
+            continue;
+        }
+        let block = match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = name[1..].parse::<usize>().unwrap_or(2);
+                Some(format!(
+                    "{} {}",
+                    "#".repeat(level),
+                    inline_text(child, base).trim()
+                ))
+            }
+            "p" | "figcaption" | "dt" | "dd" => Some(inline_text(child, base)),
+            "li" => Some(format!("- {}", inline_text(child, base).trim())),
+            "pre" => {
+                let code: String = ElementRef::wrap(child)
+                    .map(|element| element.text().collect())
+                    .unwrap_or_default();
+                Some(format!("```\n{}\n```", code.trim_end()))
+            }
+            "blockquote" => {
+                let mut quoted = String::new();
+                render_blocks(child, base, &mut quoted);
+                Some(
+                    quoted
+                        .lines()
+                        .map(|line| format!("> {}", line))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            }
+            "div" | "section" | "article" | "main" | "ul" | "ol" | "table" | "tbody" | "thead"
+            | "tr" | "td" | "th" | "dl" | "figure" | "center" => None,
+            _ => {
+                inline.push_str(&inline_text(child, base));
+                continue;
+            }
+        };
+
+        push_block(out, &inline);
+        inline.clear();
+        match block {
+            Some(block) => push_block(out, &block),
+            None => render_blocks(child, base, out),
+        }
+    }
+    push_block(out, &inline);
+}
+
+/// Reduce an HTML page to its title and readable markdown body.
+pub(crate) fn extract_readable(html: &str, base: Option<&reqwest::Url>) -> (String, String) {
+    let document = Html::parse_document(html);
+    let title = document
+        .select(&selector("meta[property='og:title']"))
+        .find_map(|meta| meta.value().attr("content").map(str::to_string))
+        .or_else(|| {
+            document
+                .select(&selector("title"))
+                .next()
+                .map(|title| title.text().collect::<String>())
+        })
+        .map(|title| collapse_whitespace(&title).trim().to_string())
+        .unwrap_or_default();
+
+    let mut body = String::new();
+    if let Some(root) = content_root(&document) {
+        render_blocks(*root, base, &mut body);
+    }
+    (title, body)
+}
+
+fn clip_chars(text: &str, limit: usize) -> String {
+    let total = text.chars().count();
+    if total <= limit {
+        return text.to_string();
+    }
+    let mut clipped: String = text.chars().take(limit).collect();
+    clipped.push_str(&format!(
+        "\n\n... [truncated at {} of {} characters]",
+        limit, total
+    ));
+    clipped
+}
+
+pub(crate) fn fetch_url_tool_definition() -> Value {
+    json!({
+        "name": FETCH_URL_TOOL_NAME,
+        "description": "Download a web page and return its main content as readable markdown (navigation, ads, and scripts removed). Use this to read or summarize links shared in a channel.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "url": { "type": "string", "description": "Absolute http(s) URL of the page" },
+                "maxChars": { "type": "number", "description": "Maximum characters of content to return. Defaults to 8000" }
+            },
+            "required": ["url"]
+        }
+    })
+}
+
+pub(crate) async fn run_fetch_url_tool(args: &Value, config: &Config) -> ToolExecutionResult {
+    let Some(raw_url) = args
+        .get("url")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+    else {
+        return ToolExecutionResult::error("Error: Missing required argument `url`.");
+    };
+    let url = match reqwest::Url::parse(raw_url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => url,
+        _ => {
+            return ToolExecutionResult::error(format!(
+                "Error: `{}` is not an absolute http(s) URL.",
+                raw_url
+            ));
+        }
+    };
+    let host = url.host_str().unwrap_or_default();
+    if is_private_host(host)
+        && !config.runtime.privileged
+        && !is_domain_allowed(host, &config.http.allowed_domains)
+    {
+        return ToolExecutionResult::error(format!(
+            "Error: `{}` is a local or private address; fetch_url only reaches it with runtime.privileged=true or an http.allowed_domains entry.",
+            host
+        ));
+    }
+    let max_chars = args
+        .get("maxChars")
+        .and_then(Value::as_u64)
+        .map(|limit| limit as usize)
+        .unwrap_or(DEFAULT_MAX_CHARS)
+        .clamp(200, MAX_CHARS_CAP);
+
+    let client = if config.runtime.privileged {
+        FETCH_CLIENT.clone()
+    } else {
+        match guarded_client(config) {
+            Ok(client) => client,
+            Err(error) => {
+                return ToolExecutionResult::error(format!(
+                    "Error: Failed to prepare the fetch client: {}",
+                    error
+                ));
+            }
+        }
+    };
+    let mut response = match client.get(url.clone()).send().await {
+        Ok(response) => response,
+        Err(error) => {
+            // `{:#}` keeps the cause, such as which redirect hop or address was refused.
+            return ToolExecutionResult::error(format!(
+                "Error: Failed to fetch {}: {:#}",
+                url,
+                anyhow::Error::from(error)
+            ));
+        }
+    };
+    if !response.status().is_success() {
+        return ToolExecutionResult::error(format!(
+            "Error: {} returned HTTP {}",
+            url,
+            response.status()
+        ));
+    }
+    let final_url = response.url().clone();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("text/html")
+        .to_ascii_lowercase();
+
+    let mut bytes = Vec::new();
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                bytes.extend_from_slice(&chunk);
+                if bytes.len() >= MAX_DOWNLOAD_BYTES {
+                    bytes.truncate(MAX_DOWNLOAD_BYTES);
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(error) => {
+                return ToolExecutionResult::error(format!(
+                    "Error: Failed to read {}: {}",
+                    url, error
+                ));
+            }
+        }
+    }
+    let raw = String::from_utf8_lossy(&bytes);
+
+    let output = if content_type.contains("html") {
+        let (title, body) = extract_readable(&raw, Some(&final_url));
+        if body.trim().is_empty() {
+            return ToolExecutionResult::error(format!(
+                "Error: No readable text found at {} (the page may require JavaScript).",
+                final_url
+            ));
+        }
+        format!(
+            "# {}\nSource: {}\n\n{}",
+            if title.is_empty() {
+                final_url.as_str()
+            } else {
+                &title
+            },
+            final_url,
+            clip_chars(&body, max_chars)
+        )
+    } else if content_type.starts_with("text/")
+        || content_type.contains("json")
+        || content_type.contains("xml")
+    {
+        format!(
+            "Source: {}\n\n{}",
+            final_url,
+            clip_chars(raw.trim(), max_chars)
+        )
+    } else {
+        return ToolExecutionResult::error(format!(
+            "Error: {} is `{}`, not a readable page.",
+            final_url, content_type
+        ));
+    };
+    ToolExecutionResult::success(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><head><title>Ignored</title>
+<meta property="og:title" content="Town council approves park">
+<script>var tracking = 1;</script></head>
+<body><nav><a href="/">Home</a> <a href="/news">News</a></nav>
+<header>Site banner</header>
+<article><h1>Council approves park</h1>
+<p>The council voted <strong>5-2</strong> on Tuesday to build a new park near
+   <a href="/maps/river">the river</a>.</p>
+<p>Construction starts in spring.</p>
+<ul><li>Budget: $1.2M</li><li>Opening: 2027</li></ul>
+<pre>phase 1: grading</pre>
+<aside>Subscribe to our newsletter for more updates and offers every week.</aside>
+<p>Residents can comment until March 30, and the full proposal is available online for review.</p>
+</article>
+<footer>Copyright</footer></body></html>"#;
+
+    #[test]
+    fn test_extract_readable_keeps_article_and_drops_chrome() {
+        let base = reqwest::Url::parse("https://news.example/story/1").unwrap();
+        let (title, body) = extract_readable(PAGE, Some(&base));
+
+        assert_eq!(title, "Town council approves park");
+        assert!(body.starts_with("# Council approves park"));
+        assert!(body.contains(
+            "The council voted **5-2** on Tuesday to build a new park near [the river](https://news.example/maps/river)."
+        ));
+        assert!(body.contains("- Budget: $1.2M\n\n- Opening: 2027"));
+        assert!(body.contains("```\nphase 1: grading\n```"));
+        for chrome in ["Home", "Site banner", "Subscribe", "Copyright", "tracking"] {
+            assert!(
+                !body.contains(chrome),
+                "unexpected `{}` in {}",
+                chrome,
+                body
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_private_host_flags_local_targets() {
+        for host in [
+            "localhost",
+            "127.0.0.1",
+            "10.1.2.3",
+            "192.168.0.5",
+            "169.254.169.254",
+            "[::1]",
+            "[::ffff:127.0.0.1]",
+            "printer.local",
+        ] {
+            assert!(is_private_host(host), "{}", host);
+        }
+        for host in ["example.com", "8.8.8.8", "2606:4700::1111"] {
+            assert!(!is_private_host(host), "{}", host);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_url_tool_rejects_private_hosts_without_privilege() {
+        let result = run_fetch_url_tool(
+            &json!({ "url": "http://169.254.169.254/latest/meta-data" }),
+            &Config::default(),
+        )
+        .await;
+        assert!(result.is_error);
+        assert!(result.output.contains("runtime.privileged=true"));
+    }
+
+    #[tokio::test]
+    async fn test_guarded_client_rejects_private_resolutions_and_redirects() {
+        use reqwest::dns::Resolve;
+        use std::str::FromStr;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let resolver = GuardedResolver {
+            allowed_domains: Vec::new(),
+        };
+        let name = reqwest::dns::Name::from_str("localhost").unwrap();
+        assert!(resolver.resolve(name).await.is_err());
+        let resolver = GuardedResolver {
+            allowed_domains: vec!["localhost".to_string()],
+        };
+        let name = reqwest::dns::Name::from_str("localhost").unwrap();
+        assert!(resolver.resolve(name).await.unwrap().next().is_some());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 4096];
+            let _ = socket.read(&mut request).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 302 Found\r\nLocation: http://169.254.169.254/latest\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .await
+                .unwrap();
+        });
+        let mut config = Config::default();
+        config.http.allowed_domains = vec!["127.0.0.1".to_string()];
+        let result = run_fetch_url_tool(
+            &json!({ "url": format!("http://127.0.0.1:{}/moved", port) }),
+            &config,
+        )
+        .await;
+        server.await.unwrap();
+        assert!(result.is_error);
+        assert!(
+            result
+                .output
+                .contains("redirect to local or private address `169.254.169.254`"),
+            "{}",
+            result.output
+        );
+    }
+}
@@ -0,0 +1,232 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/file_info.rs
+ * Responsibility: The `stat` and `hash` core tools for file metadata and checksums.
+ */
+
+use crate::tools::{ToolExecutionResult, is_path_safe, normalize_path};
+use chrono::{DateTime, Local};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256, Sha512};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+
+pub const STAT_TOOL_NAME: &str = "stat";
+pub const HASH_TOOL_NAME: &str = "hash";
+const MAX_STAT_PATHS: usize = 50;
+
+fn path_list(args: &Value) -> Vec<&str> {
+    match args.get("path") {
+        Some(Value::String(path)) => vec![normalize_path(path)],
+        Some(Value::Array(paths)) => paths
+            .iter()
+            .filter_map(Value::as_str)
+            .map(normalize_path)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn describe_path(base_path: &Path, rel_path: &str) -> String {
+    if !is_path_safe(base_path, rel_path) {
+        return format!("{}: access denied (outside the guild)", rel_path);
+    }
+    let path = base_path.join(rel_path);
+    let metadata = match fs::symlink_metadata(&path) {
+        Ok(metadata) => metadata,
+        Err(_) => return format!("{}: not found", rel_path),
+    };
+    let kind = if metadata.is_symlink() {
+        "symlink"
+    } else if metadata.is_dir() {
+        "directory"
+    } else {
+        "file"
+    };
+    let modified = metadata
+        .modified()
+        .map(|time| DateTime::<Local>::from(time).to_rfc3339())
+        .unwrap_or_else(|_| "unknown".to_string());
+    let mut line = format!(
+        "{}: {}, {} bytes, modified {}",
+        rel_path,
+        kind,
+        metadata.len(),
+        modified
+    );
+    if metadata.is_file() {
+        let mime = mime_guess::from_path(&path).first_or_octet_stream();
+        line.push_str(&format!(", {}", mime));
+    }
+    line
+}
+
+/// Hex digest of a file, streamed so large downloads are not loaded into memory.
+pub(crate) fn file_digest(path: &Path, algorithm: &str) -> io::Result<String> {
+    fn stream<D: Digest>(mut file: File) -> io::Result<String> {
+        let mut hasher = D::new();
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect())
+    }
+
+    let file = File::open(path)?;
+    match algorithm {
+        "sha256" => stream::<Sha256>(file),
+        "sha512" => stream::<Sha512>(file),
+        other => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported algorithm `{}`; use sha256 or sha512", other),
+        )),
+    }
+}
+
+fn run_stat_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let paths = path_list(args);
+    if paths.is_empty() {
+        return ToolExecutionResult::error("Error: Missing required argument `path`.");
+    }
+    let lines: Vec<String> = paths
+        .iter()
+        .take(MAX_STAT_PATHS)
+        .map(|rel_path| describe_path(base_path, rel_path))
+        .collect();
+    ToolExecutionResult::success(lines.join("\n"))
+}
+
+fn run_hash_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let rel_path = match crate::tools::require_safe_rel_path(args, "path", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let algorithm = args
+        .get("algorithm")
+        .and_then(Value::as_str)
+        .unwrap_or("sha256")
+        .to_ascii_lowercase();
+    let path = base_path.join(rel_path);
+    if !path.is_file() {
+        return ToolExecutionResult::error(format!("Error: {} is not a file.", rel_path));
+    }
+
+    let digest = match file_digest(&path, &algorithm) {
+        Ok(digest) => digest,
+        Err(error) => {
+            return ToolExecutionResult::error(format!("Error hashing {}: {}", rel_path, error));
+        }
+    };
+    let Some(expected) = args.get("expected").and_then(Value::as_str) else {
+        return ToolExecutionResult::success(format!("{}  {} ({})", digest, rel_path, algorithm));
+    };
+    if expected.trim().eq_ignore_ascii_case(&digest) {
+        ToolExecutionResult::success(format!(
+            "OK: {} matches the expected {}.",
+            rel_path, algorithm
+        ))
+    } else {
+        ToolExecutionResult::error(format!(
+            "MISMATCH: {} has {} {}, expected {}.",
+            rel_path,
+            algorithm,
+            digest,
+            expected.trim()
+        ))
+    }
+}
+
+pub(crate) fn file_info_tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": STAT_TOOL_NAME,
+            "description": "Show metadata for one or more guild paths: kind, size in bytes, modification time, and guessed MIME type. Use it to detect changed or missing files.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "array", "items": { "type": "string" }, "description": "Paths relative to guild root" }
+                },
+                "required": ["path"]
+            }
+        }),
+        json!({
+            "name": HASH_TOOL_NAME,
+            "description": "Compute a file checksum, or verify it against an expected value (e.g. a published sha256 for a download).",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "File path relative to guild root" },
+                    "algorithm": { "type": "string", "enum": ["sha256", "sha512"], "description": "Defaults to sha256" },
+                    "expected": { "type": "string", "description": "Hex digest to compare against" }
+                },
+                "required": ["path"]
+            }
+        }),
+    ]
+}
+
+pub(crate) fn dispatch_file_info_tool(
+    name: &str,
+    args: &Value,
+    base_path: &Path,
+) -> Option<ToolExecutionResult> {
+    match name {
+        STAT_TOOL_NAME => Some(run_stat_tool(args, base_path)),
+        HASH_TOOL_NAME => Some(run_hash_tool(args, base_path)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_stat_tool_reports_kind_size_and_mime() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("downloads")).unwrap();
+        fs::write(dir.path().join("downloads/photo.png"), [0u8; 10]).unwrap();
+
+        let result = run_stat_tool(
+            &json!({ "path": ["downloads/photo.png", "downloads", "missing.md", "../x"] }),
+            dir.path(),
+        );
+        let lines: Vec<&str> = result.output.lines().collect();
+        assert!(lines[0].starts_with("downloads/photo.png: file, 10 bytes, modified "));
+        assert!(lines[0].ends_with(", image/png"));
+        assert!(lines[1].starts_with("downloads: directory"));
+        assert_eq!(lines[2], "missing.md: not found");
+        assert!(lines[3].contains("access denied"));
+    }
+
+    #[test]
+    fn test_hash_tool_computes_and_verifies_sha256() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("hello.txt"), "hello\n").unwrap();
+        let digest = "5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03";
+
+        let result = run_hash_tool(&json!({ "path": "hello.txt" }), dir.path());
+        assert_eq!(result.output, format!("{}  hello.txt (sha256)", digest));
+        let verified = run_hash_tool(
+            &json!({ "path": "hello.txt", "expected": digest.to_uppercase() }),
+            dir.path(),
+        );
+        assert!(!verified.is_error);
+        let mismatch = run_hash_tool(
+            &json!({ "path": "hello.txt", "expected": "abc" }),
+            dir.path(),
+        );
+        assert!(mismatch.is_error);
+        assert!(mismatch.output.starts_with("MISMATCH"));
+    }
+}
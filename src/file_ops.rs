@@ -0,0 +1,454 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/file_ops.rs
+ * Responsibility: Workspace file management tools (mv, cp, rm to trash, mkdir).
+ */
+
+use crate::tools::{ToolExecutionResult, is_path_safe, normalize_path, require_safe_rel_path};
+use chrono::Local;
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+pub const TRASH_DIR: &str = "brain/.trash";
+
+/// Paths whose removal would break the workspace itself.
+const PROTECTED_PATHS: [&str; 6] = [".", "agents", "brain", "channels", "rituals", "skills"];
+
+/// Like `is_path_safe`, but for paths whose parents may not exist yet: the nearest existing
+/// ancestor must resolve inside the guild.
+fn is_new_path_safe(base_path: &Path, rel_path: &str) -> bool {
+    let relative = Path::new(rel_path);
+    if relative.is_absolute()
+        || relative
+            .components()
+            .any(|part| !matches!(part, Component::Normal(_) | Component::CurDir))
+    {
+        return false;
+    }
+    let Ok(base_real) = fs::canonicalize(base_path) else {
+        return false;
+    };
+    let mut ancestor = base_path.join(relative);
+    while !ancestor.exists() {
+        if !ancestor.pop() {
+            return false;
+        }
+    }
+    fs::canonicalize(ancestor)
+        .map(|path| path.starts_with(&base_real))
+        .unwrap_or(false)
+}
+
+pub(crate) fn require_new_rel_path<'a>(
+    args: &'a Value,
+    field: &str,
+    base_path: &Path,
+) -> Result<&'a str, ToolExecutionResult> {
+    let rel_path = args
+        .get(field)
+        .and_then(Value::as_str)
+        .map(normalize_path)
+        .filter(|path| !path.is_empty())
+        .ok_or_else(|| {
+            ToolExecutionResult::error(format!("Error: Missing required argument `{}`.", field))
+        })?;
+    if !is_new_path_safe(base_path, rel_path) {
+        return Err(ToolExecutionResult::error(
+            "Error: Access denied. Path must be within the guild directory.",
+        ));
+    }
+    Ok(rel_path)
+}
+
+fn is_protected(rel_path: &str) -> bool {
+    let trimmed = rel_path.trim_end_matches('/');
+    PROTECTED_PATHS.contains(&trimmed) || trimmed.is_empty()
+}
+
+/// Copy files and folders, skipping symlinks so a link cannot pull outside files into
+/// the guild. Returns how many symlinks were skipped.
+fn copy_recursive(source: &Path, destination: &Path) -> std::io::Result<usize> {
+    let file_type = fs::symlink_metadata(source)?.file_type();
+    if file_type.is_symlink() {
+        Ok(1)
+    } else if file_type.is_dir() {
+        fs::create_dir_all(destination)?;
+        let mut skipped = 0;
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            skipped += copy_recursive(&entry.path(), &destination.join(entry.file_name()))?;
+        }
+        Ok(skipped)
+    } else {
+        fs::copy(source, destination).map(|_| 0)
+    }
+}
+
+/// Resolve the source, the destination, and whether an existing destination may be replaced.
+fn prepare_transfer<'a>(
+    args: &'a Value,
+    base_path: &Path,
+) -> Result<(&'a str, &'a str), ToolExecutionResult> {
+    let source = require_safe_rel_path(args, "from", base_path)?;
+    let destination = require_new_rel_path(args, "to", base_path)?;
+    if !base_path.join(source).exists() {
+        return Err(ToolExecutionResult::error(format!(
+            "Error: Path not found: {}",
+            source
+        )));
+    }
+    let overwrite = args
+        .get("overwrite")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let target = base_path.join(destination);
+    if target.exists() && !overwrite {
+        return Err(ToolExecutionResult::error(format!(
+            "Error: {} already exists. Pass overwrite=true to replace it.",
+            destination
+        )));
+    }
+    if target.is_dir() {
+        return Err(ToolExecutionResult::error(format!(
+            "Error: {} is an existing directory; give the full destination path instead.",
+            destination
+        )));
+    }
+    if let Some(parent) = target.parent()
+        && let Err(error) = fs::create_dir_all(parent)
+    {
+        return Err(ToolExecutionResult::error(format!(
+            "Error creating {}: {}",
+            parent.display(),
+            error
+        )));
+    }
+    Ok((source, destination))
+}
+
+/// Move an existing destination to the trash before it is replaced, so `overwrite`
+/// never loses data. Returns a note for the tool output, empty when nothing was there.
+fn trash_replaced(base_path: &Path, destination: &str) -> Result<String, ToolExecutionResult> {
+    if fs::symlink_metadata(base_path.join(destination)).is_err() {
+        return Ok(String::new());
+    }
+    move_to_trash(base_path, destination)
+        .map(|trashed| format!(" (previous {} moved to {})", destination, trashed.display()))
+        .map_err(|error| {
+            ToolExecutionResult::error(format!(
+                "Error moving the existing {} to the trash: {}",
+                destination, error
+            ))
+        })
+}
+
+fn run_mv_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let (source, destination) = match prepare_transfer(args, base_path) {
+        Ok(paths) => paths,
+        Err(err) => return err,
+    };
+    if is_protected(source) {
+        return ToolExecutionResult::error(format!("Error: {} cannot be moved.", source));
+    }
+    let replaced = match trash_replaced(base_path, destination) {
+        Ok(note) => note,
+        Err(err) => return err,
+    };
+    match fs::rename(base_path.join(source), base_path.join(destination)) {
+        Ok(_) => {
+            ToolExecutionResult::success(format!("Moved {} to {}{}", source, destination, replaced))
+        }
+        Err(error) => ToolExecutionResult::error(format!("Error moving {}: {}", source, error)),
+    }
+}
+
+fn run_cp_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let (source, destination) = match prepare_transfer(args, base_path) {
+        Ok(paths) => paths,
+        Err(err) => return err,
+    };
+    if Path::new(destination).starts_with(source) {
+        return ToolExecutionResult::error("Error: Cannot copy a directory into itself.");
+    }
+    if base_path.join(source).is_symlink() {
+        return ToolExecutionResult::error(format!(
+            "Error: {} is a symlink; copy its target instead.",
+            source
+        ));
+    }
+    let replaced = match trash_replaced(base_path, destination) {
+        Ok(note) => note,
+        Err(err) => return err,
+    };
+    match copy_recursive(&base_path.join(source), &base_path.join(destination)) {
+        Ok(0) => ToolExecutionResult::success(format!(
+            "Copied {} to {}{}",
+            source, destination, replaced
+        )),
+        Ok(skipped) => ToolExecutionResult::success(format!(
+            "Copied {} to {}{}; skipped {} symlink(s)",
+            source, destination, replaced, skipped
+        )),
+        Err(error) => ToolExecutionResult::error(format!("Error copying {}: {}", source, error)),
+    }
+}
+
+/// Where a removed path lands: `brain/.trash/<date>/<original path>`, suffixed on collision.
+fn trash_destination(base_path: &Path, rel_path: &str) -> PathBuf {
+    let day_dir = base_path
+        .join(TRASH_DIR)
+        .join(Local::now().format("%Y-%m-%d").to_string());
+    let candidate = day_dir.join(rel_path);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let stamp = Local::now().format("%H%M%S%3f");
+    let file_name = candidate
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    candidate.with_file_name(format!("{}.{}", file_name, stamp))
+}
+
+/// Move `rel_path` into the dated trash and return where it went, guild-relative.
+fn move_to_trash(base_path: &Path, rel_path: &str) -> std::io::Result<PathBuf> {
+    let destination = trash_destination(base_path, rel_path);
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(base_path.join(rel_path), &destination)?;
+    Ok(destination
+        .strip_prefix(base_path)
+        .map(Path::to_path_buf)
+        .unwrap_or(destination))
+}
+
+fn run_rm_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let rel_path = match require_safe_rel_path(args, "path", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    if is_protected(rel_path) {
+        return ToolExecutionResult::error(format!("Error: {} cannot be removed.", rel_path));
+    }
+    if Path::new(rel_path).starts_with(TRASH_DIR) {
+        return ToolExecutionResult::error(
+            "Error: Items already in the trash are only purged by the operator.",
+        );
+    }
+    if !base_path.join(rel_path).exists() {
+        return ToolExecutionResult::error(format!("Error: Path not found: {}", rel_path));
+    }
+
+    match move_to_trash(base_path, rel_path) {
+        Ok(destination) => {
+            ToolExecutionResult::success(format!("Moved {} to {}", rel_path, destination.display()))
+        }
+        Err(error) => ToolExecutionResult::error(format!("Error removing {}: {}", rel_path, error)),
+    }
+}
+
+fn run_mkdir_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let rel_path = match require_new_rel_path(args, "path", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let target = base_path.join(rel_path);
+    if target.is_file() {
+        return ToolExecutionResult::error(format!("Error: {} is a file.", rel_path));
+    }
+    match fs::create_dir_all(&target) {
+        Ok(_) if is_path_safe(base_path, rel_path) => {
+            ToolExecutionResult::success(format!("Created directory {}", rel_path))
+        }
+        Ok(_) => ToolExecutionResult::error(
+            "Error: Access denied. Path must be within the guild directory.",
+        ),
+        Err(error) => ToolExecutionResult::error(format!("Error creating {}: {}", rel_path, error)),
+    }
+}
+
+pub(crate) fn file_tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": "mv",
+            "description": "Move or rename a file or directory inside the guild. Creates destination folders.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "from": { "type": "string", "description": "Existing path, relative to guild root" },
+                    "to": { "type": "string", "description": "Full destination path, relative to guild root" },
+                    "overwrite": { "type": "boolean", "description": "Replace an existing destination file; the old one goes to brain/.trash/" }
+                },
+                "required": ["from", "to"]
+            }
+        }),
+        json!({
+            "name": "cp",
+            "description": "Copy a file or directory (recursively) inside the guild. Creates destination folders.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "from": { "type": "string", "description": "Existing path, relative to guild root" },
+                    "to": { "type": "string", "description": "Full destination path, relative to guild root" },
+                    "overwrite": { "type": "boolean", "description": "Replace an existing destination file; the old one goes to brain/.trash/" }
+                },
+                "required": ["from", "to"]
+            }
+        }),
+        json!({
+            "name": "rm",
+            "description": "Remove a file or directory by moving it to brain/.trash/<date>/. Nothing is permanently deleted.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Path to remove, relative to guild root" }
+                },
+                "required": ["path"]
+            }
+        }),
+        json!({
+            "name": "mkdir",
+            "description": "Create a directory (and any missing parents) inside the guild.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Directory to create, relative to guild root" }
+                },
+                "required": ["path"]
+            }
+        }),
+    ]
+}
+
+pub(crate) fn dispatch_file_tool(
+    name: &str,
+    args: &Value,
+    base_path: &Path,
+) -> Option<ToolExecutionResult> {
+    let result = match name {
+        "mv" => run_mv_tool(args, base_path),
+        "cp" => run_cp_tool(args, base_path),
+        "rm" => run_rm_tool(args, base_path),
+        "mkdir" => run_mkdir_tool(args, base_path),
+        _ => return None,
+    };
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn run(name: &str, args: Value, base_path: &Path) -> ToolExecutionResult {
+        dispatch_file_tool(name, &args, base_path).unwrap()
+    }
+
+    #[test]
+    fn test_mkdir_cp_and_mv_stay_inside_the_guild() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("note.md"), "hello").unwrap();
+
+        assert!(!run("mkdir", json!({ "path": "archive/2026" }), dir.path()).is_error);
+        let copied = run(
+            "cp",
+            json!({ "from": "note.md", "to": "archive/2026/note.md" }),
+            dir.path(),
+        );
+        assert!(!copied.is_error, "{}", copied.output);
+        let clash = run(
+            "mv",
+            json!({ "from": "note.md", "to": "archive/2026/note.md" }),
+            dir.path(),
+        );
+        assert!(clash.output.contains("overwrite=true"));
+        let moved = run(
+            "mv",
+            json!({ "from": "note.md", "to": "projects/new/note.md" }),
+            dir.path(),
+        );
+        assert!(!moved.is_error, "{}", moved.output);
+        assert!(!dir.path().join("note.md").exists());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("projects/new/note.md")).unwrap(),
+            "hello"
+        );
+
+        assert!(run("mkdir", json!({ "path": "../escape" }), dir.path()).is_error);
+        assert!(
+            run(
+                "cp",
+                json!({ "from": "projects", "to": "/tmp/out" }),
+                dir.path()
+            )
+            .is_error
+        );
+    }
+
+    #[test]
+    fn test_rm_moves_to_dated_trash_and_protects_workspace_roots() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("rituals")).unwrap();
+        fs::write(dir.path().join("rituals/old.md"), "v1").unwrap();
+
+        let removed = run("rm", json!({ "path": "rituals/old.md" }), dir.path());
+        assert!(!removed.is_error, "{}", removed.output);
+        assert!(!dir.path().join("rituals/old.md").exists());
+        let trashed = dir
+            .path()
+            .join(TRASH_DIR)
+            .join(Local::now().format("%Y-%m-%d").to_string())
+            .join("rituals/old.md");
+        assert_eq!(fs::read_to_string(&trashed).unwrap(), "v1");
+
+        fs::write(dir.path().join("rituals/old.md"), "v2").unwrap();
+        assert!(!run("rm", json!({ "path": "rituals/old.md" }), dir.path()).is_error);
+        assert_eq!(fs::read_to_string(&trashed).unwrap(), "v1");
+
+        assert!(run("rm", json!({ "path": "rituals" }), dir.path()).is_error);
+        assert!(run("rm", json!({ "path": "." }), dir.path()).is_error);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_overwrite_trashes_the_old_file_and_cp_skips_symlinks() {
+        let dir = tempdir().unwrap();
+        let outside = tempdir().unwrap();
+        fs::write(outside.path().join("secret"), "outside").unwrap();
+        fs::create_dir_all(dir.path().join("notes")).unwrap();
+        fs::write(dir.path().join("notes/a.md"), "new").unwrap();
+        fs::write(dir.path().join("b.md"), "old").unwrap();
+        std::os::unix::fs::symlink(outside.path().join("secret"), dir.path().join("notes/link"))
+            .unwrap();
+
+        let moved = run(
+            "mv",
+            json!({ "from": "notes/a.md", "to": "b.md", "overwrite": true }),
+            dir.path(),
+        );
+        assert!(!moved.is_error, "{}", moved.output);
+        assert!(
+            moved
+                .output
+                .contains("(previous b.md moved to brain/.trash/")
+        );
+        assert_eq!(fs::read_to_string(dir.path().join("b.md")).unwrap(), "new");
+        let trashed = dir
+            .path()
+            .join(TRASH_DIR)
+            .join(Local::now().format("%Y-%m-%d").to_string())
+            .join("b.md");
+        assert_eq!(fs::read_to_string(trashed).unwrap(), "old");
+
+        let copied = run("cp", json!({ "from": "notes", "to": "copy" }), dir.path());
+        assert!(
+            copied.output.ends_with("skipped 1 symlink(s)"),
+            "{}",
+            copied.output
+        );
+        assert!(!dir.path().join("copy/link").exists());
+        assert!(run("cp", json!({ "from": "notes/link", "to": "x" }), dir.path()).is_error);
+    }
+}
@@ -0,0 +1,201 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/guardian.rs
+ * Responsibility: The Guardian's pulse: periodic, deterministic upkeep of the guild's durable memory.
+ */
+
+use crate::alerts::{self, Finding};
+use crate::backup_check;
+use crate::config::{Config, Language};
+use crate::distill::{self, MergeDecision};
+use crate::health_report::{self, HealthReport};
+use crate::history_retention::{self, RetentionStats};
+use crate::hygiene::{self, HygieneStats};
+use crate::i18n::Message;
+use crate::memory::{self, PrunedFact};
+use crate::{log_info, log_warn};
+use chrono::Local;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What one pulse changed, and the guild's health afterwards.
+#[derive(Debug, Default)]
+pub struct PulseOutcome {
+    pub pruned: Vec<PrunedFact>,
+    pub merged: Vec<MergeDecision>,
+    pub retention: RetentionStats,
+    pub hygiene: HygieneStats,
+    pub health: HealthReport,
+    pub findings: Vec<Finding>,
+}
+
+impl PulseOutcome {
+    /// The health report followed by what this pulse changed.
+    pub fn report(&self, language: Language) -> String {
+        let mut report = self.health.render(language);
+        if !self.findings.is_empty() {
+            report.push_str(&format!("\n{}\n", Message::PulseFindings.render(language)));
+            for finding in &self.findings {
+                report.push_str(&format!("- {}\n", finding.render(language)));
+            }
+        }
+        if !self.pruned.is_empty() {
+            let pruned = Message::PulsePruned {
+                count: self.pruned.len(),
+            };
+            report.push_str(&format!("\n{}\n", pruned.render(language)));
+        }
+        if !self.merged.is_empty() {
+            let merged = Message::PulseMerged {
+                count: self.merged.len(),
+                changelog: distill::CHANGELOG_FILE,
+            };
+            report.push_str(&format!("\n{}\n", merged.render(language)));
+        }
+        if !self.retention.is_empty() {
+            report.push_str(&format!("\n{}\n", self.retention.report()));
+        }
+        if !self.hygiene.is_empty() {
+            report.push_str(&format!("\n{}\n", self.hygiene.report()));
+        }
+        report
+    }
+}
+
+fn prune(base_path: &Path, config: &Config) -> Vec<PrunedFact> {
+    let may_write = |path: &Path| config.guardian.may_write(path);
+    match memory::prune_knowledge(base_path, Local::now().naive_local(), &may_write) {
+        Ok(pruned) => {
+            for fact in &pruned {
+                log_info!(
+                    "guardian",
+                    "🛡️ Guardian pruned {} fact from {}: {}",
+                    fact.reason, fact.path, fact.entry.fact
+                );
+            }
+            pruned
+        }
+        Err(e) => {
+            log_warn!("guardian", "⚠️ Guardian failed to prune knowledge: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+fn enforce_retention(base_path: &Path, config: &Config) -> RetentionStats {
+    let today = Local::now().date_naive();
+    let may_write = |path: &Path| config.guardian.may_write(path);
+    match history_retention::enforce_retention(
+        base_path,
+        &config.history_retention,
+        today,
+        &may_write,
+    ) {
+        Ok(stats) => stats,
+        Err(e) => {
+            log_warn!("guardian", "⚠️ Guardian failed to apply history retention: {}", e);
+            RetentionStats::default()
+        }
+    }
+}
+
+fn clean_workspace(base_path: &Path, config: &Config) -> HygieneStats {
+    let may_write = |path: &Path| config.guardian.may_write(path);
+    match hygiene::run_hygiene(base_path, &config.hygiene, SystemTime::now(), &may_write) {
+        Ok(stats) => stats,
+        Err(e) => {
+            log_warn!("guardian", "⚠️ Guardian failed to clean the workspace: {}", e);
+            HygieneStats::default()
+        }
+    }
+}
+
+/// One pulse: drop expired and superseded remembered facts, apply history retention, and
+/// clean the workspace, only within `guardian.paths`; then merge duplicate and conflicting
+/// facts and run the next check from `agents/guardian-checks/`. With `guardian.report_channel_id` set, the health report and
+/// these changes are posted there; otherwise retention stats go to
+/// `discord.admin_channel_id` when anything changed.
+pub async fn run_guardian_pulse(base_path: &Path, config: &Config) -> PulseOutcome {
+    crate::introspect::record_pulse();
+    let base: PathBuf = base_path.to_path_buf();
+    let settings = config.clone();
+    let tokens = crate::llm::take_tokens_spent();
+    let mut outcome = tokio::task::spawn_blocking(move || {
+        let pruned = prune(&base, &settings);
+        let retention = enforce_retention(&base, &settings);
+        let hygiene = clean_workspace(&base, &settings);
+        let health = health_report::build_health_report(&base, Local::now().date_naive(), tokens);
+        PulseOutcome {
+            pruned,
+            merged: Vec::new(),
+            retention,
+            hygiene,
+            findings: alerts::pulse_findings(&base, &health, &settings.guardian)
+                .into_iter()
+                .chain(backup_check::verify_backups(
+                    &base,
+                    &settings.guardian.backups,
+                    SystemTime::now(),
+                ))
+                .collect(),
+            health,
+        }
+    })
+    .await
+    .unwrap_or_else(|e| {
+        log_warn!("guardian", "⚠️ Guardian pulse panicked: {}", e);
+        PulseOutcome::default()
+    });
+
+    if config.guardian.distill {
+        outcome.merged = distill::distill_knowledge(base_path, config).await;
+    }
+    if let Some(finding) = crate::guardian_checks::run_next_check(base_path, config).await {
+        outcome.findings.push(finding);
+        outcome.findings.sort_by_key(|finding| std::cmp::Reverse(finding.severity));
+    }
+
+    for finding in &outcome.findings {
+        alerts::raise(config, finding).await;
+    }
+
+    if let Some(report_channel_id) = &config.guardian.report_channel_id {
+        if let Err(e) = crate::discord::client::send_bot_message_or_file(
+            &config.discord.token,
+            report_channel_id,
+            &outcome.report(config.runtime.language),
+        )
+        .await
+        {
+            log_warn!(
+                "guardian",
+                "⚠️ Failed to post health report to {}: {:?}",
+                report_channel_id, e
+            );
+        }
+        return outcome;
+    }
+
+    if !outcome.hygiene.is_empty() {
+        log_info!("guardian", "{}", outcome.hygiene.report());
+    }
+    if !outcome.retention.is_empty() {
+        let report = outcome.retention.report();
+        log_info!("guardian", "{}", report);
+        if let Some(admin_channel_id) = &config.discord.admin_channel_id
+            && let Err(e) = crate::discord::client::send_bot_message(
+                &config.discord.token,
+                admin_channel_id,
+                &report,
+            )
+            .await
+        {
+            log_warn!(
+                "guardian",
+                "⚠️ Failed to send retention report to admin channel {}: {:?}",
+                admin_channel_id, e
+            );
+        }
+    }
+    outcome
+}
@@ -0,0 +1,251 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/guardian_checks.rs
+ * Responsibility: Audit checks written as documents in agents/guardian-checks/, run one per pulse in turn.
+ */
+
+use crate::alerts::{Finding, Severity};
+use crate::config::Config;
+use crate::i18n::Message;
+use crate::{log_info, log_warn};
+use chrono::Local;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub const CHECKS_DIR: &str = "agents/guardian-checks";
+/// Where each check's runs are logged; the file is also the blackboard its session runs on.
+const LOG_DIR: &str = "brain/guardian";
+/// Name of the check that ran last, so the next pulse moves on to the one after it.
+const CURSOR_FILE: &str = "brain/guardian/checks.cursor";
+/// A check's answer starting with this is escalated as a critical finding.
+const ESCALATE_PREFIX: &str = "ESCALATE:";
+
+/// One audit: what to inspect, what to fix, and what to escalate, in plain prose.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardianCheck {
+    /// File name without `.md`.
+    pub name: String,
+    pub instructions: String,
+}
+
+/// Every check with instructions, ordered by name. Front matter is ignored.
+pub fn load_checks(base_path: &Path) -> Vec<GuardianCheck> {
+    let Ok(entries) = fs::read_dir(base_path.join(CHECKS_DIR)) else {
+        return Vec::new();
+    };
+    let mut checks: Vec<GuardianCheck> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("md"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().into_owned();
+            let content = fs::read_to_string(&path).ok()?;
+            let instructions = content[crate::thread::doc::body_start(&content)..].trim();
+            (!instructions.is_empty()).then(|| GuardianCheck {
+                name,
+                instructions: instructions.to_string(),
+            })
+        })
+        .collect();
+    checks.sort_by(|a, b| a.name.cmp(&b.name));
+    checks
+}
+
+/// The check after the one that ran last, wrapping around. Checks added or removed in the
+/// meantime keep their place in name order.
+pub fn next_check(base_path: &Path) -> Option<GuardianCheck> {
+    let checks = load_checks(base_path);
+    let last = fs::read_to_string(base_path.join(CURSOR_FILE)).unwrap_or_default();
+    let last = last.trim();
+    checks
+        .iter()
+        .find(|check| check.name.as_str() > last)
+        .or(checks.first())
+        .cloned()
+}
+
+fn advance_cursor(base_path: &Path, name: &str) {
+    let path = base_path.join(CURSOR_FILE);
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Err(e) = fs::write(&path, format!("{}\n", name)) {
+        log_warn!("guardian", "⚠️ Failed to save Guardian check cursor: {}", e);
+    }
+}
+
+fn check_task(check: &GuardianCheck, config: &Config) -> String {
+    format!(
+        "Guardian audit check `{}`:\n\n{}\n\nOnly change files under: {}. Read anything else. \
+         Answer with a short summary of what you found and changed. If something needs a human, \
+         start your answer with `{}` followed by what they must do.",
+        check.name,
+        check.instructions,
+        config.guardian.paths.join(", "),
+        ESCALATE_PREFIX
+    )
+}
+
+/// Classify a check's answer: escalations are critical, failed runs a warning, the rest info.
+pub(crate) fn check_finding(name: &str, response: &str, succeeded: bool) -> Finding {
+    let response = response.trim();
+    let key = format!("check:{}", name);
+    let language = crate::i18n::language();
+    if let Some(escalation) = response.strip_prefix(ESCALATE_PREFIX) {
+        let escalation = escalation.trim();
+        return Finding::new(
+            Severity::Critical,
+            key,
+            Message::CheckEscalated { name, escalation }.render(language),
+        );
+    }
+    let line = response.lines().next().unwrap_or("").trim();
+    if succeeded {
+        Finding::new(
+            Severity::Info,
+            key,
+            Message::CheckReported { name, line }.render(language),
+        )
+    } else {
+        Finding::new(
+            Severity::Warn,
+            key,
+            Message::CheckUnfinished { name, line }.render(language),
+        )
+    }
+}
+
+fn log_path(base_path: &Path, name: &str) -> PathBuf {
+    base_path.join(LOG_DIR).join(format!("{}.md", name))
+}
+
+fn append_log(path: &Path, name: &str, finding: &Finding) {
+    let mut content =
+        fs::read_to_string(path).unwrap_or_else(|_| format!("# Guardian check: {}\n", name));
+    content.push_str(&format!(
+        "\n> [Guardian] ({}): {}\n",
+        Local::now().format("%Y-%m-%d %H:%M:%S"),
+        finding.render(crate::i18n::language())
+    ));
+    if let Err(e) = fs::write(path, content) {
+        log_warn!(
+            "guardian",
+            "⚠️ Failed to log Guardian check {}: {}",
+            name,
+            e
+        );
+    }
+}
+
+/// Run the next check in turn with the pulse's `max_turns`, `read_only_budget`, and model,
+/// log the result to `brain/guardian/<check>.md`, and return it as a finding.
+pub async fn run_next_check(base_path: &Path, config: &Config) -> Option<Finding> {
+    let check = next_check(base_path)?;
+    advance_cursor(base_path, &check.name);
+    log_info!("guardian", "🛡️ Guardian running check: {}", check.name);
+
+    let mut settings = config.clone();
+    settings.runtime.max_turns = config.guardian.max_turns;
+    settings.runtime.read_only_budget = config.guardian.read_only_budget;
+    settings.runtime.channel_limits.clear();
+    if let Some(model) = &config.guardian.model {
+        settings.gemini.model = model.clone();
+    }
+    let blackboard = log_path(base_path, &check.name);
+    if let Some(parent) = blackboard.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if !blackboard.exists() {
+        let _ = fs::write(&blackboard, format!("# Guardian check: {}\n", check.name));
+    }
+    let channel_id = config.guardian.report_channel_id.as_deref().unwrap_or("0");
+    let finding = match crate::session::execute_ritual_step(
+        &check_task(&check, config),
+        "",
+        &blackboard,
+        base_path,
+        Arc::new(settings),
+        channel_id,
+    )
+    .await
+    {
+        Ok(outcome) => check_finding(
+            &check.name,
+            &outcome.user_response,
+            outcome.is_terminal_success(),
+        ),
+        Err(e) => check_finding(&check.name, &e.to_string(), false),
+    };
+    append_log(&blackboard, &check.name, &finding);
+    Some(finding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_checks_rotate_by_name() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        assert_eq!(next_check(base), None);
+
+        let checks = base.join(CHECKS_DIR);
+        fs::create_dir_all(&checks).unwrap();
+        fs::write(
+            checks.join("skills.md"),
+            "Verify every skill has a SKILL.md.\n",
+        )
+        .unwrap();
+        fs::write(
+            checks.join("knowledge.md"),
+            "---\nnote: ignored\n---\nLook for duplicate facts.\n",
+        )
+        .unwrap();
+        fs::write(checks.join("empty.md"), "---\nnote: draft\n---\n").unwrap();
+        fs::write(checks.join("README.txt"), "not a check").unwrap();
+
+        let names: Vec<String> = load_checks(base).into_iter().map(|c| c.name).collect();
+        assert_eq!(names, ["knowledge", "skills"]);
+        assert_eq!(
+            load_checks(base)[0].instructions,
+            "Look for duplicate facts."
+        );
+
+        let mut order = Vec::new();
+        for _ in 0..3 {
+            let check = next_check(base).unwrap();
+            advance_cursor(base, &check.name);
+            order.push(check.name);
+        }
+        assert_eq!(order, ["knowledge", "skills", "knowledge"]);
+
+        // A check added after the last one run is picked up next.
+        fs::write(
+            checks.join("rituals.md"),
+            "Find rituals without a schedule.\n",
+        )
+        .unwrap();
+        assert_eq!(next_check(base).unwrap().name, "rituals");
+    }
+
+    #[test]
+    fn test_check_answers_are_classified() {
+        let escalated = check_finding("backups", "ESCALATE: the disk is full\nmore", true);
+        assert_eq!(escalated.severity, Severity::Critical);
+        assert_eq!(
+            escalated.summary,
+            "Check `backups` escalated: the disk is full\nmore"
+        );
+        assert_eq!(
+            check_finding("backups", "All good.\nDetails", true).summary,
+            "Check `backups`: All good."
+        );
+        assert_eq!(
+            check_finding("backups", "tool failed", false).severity,
+            Severity::Warn
+        );
+    }
+}
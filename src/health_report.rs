@@ -0,0 +1,308 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/health_report.rs
+ * Responsibility: The Guardian's per-pulse health report: disk use, stale rituals, open TODOs, errors, and tokens.
+ */
+
+use crate::config::Language;
+use crate::history_retention::{human_bytes, size_of};
+use crate::i18n::Message;
+use chrono::{Local, NaiveDate};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Rituals with unchecked steps untouched for this long are reported as stale.
+const STALE_AFTER: Duration = Duration::from_secs(24 * 3600);
+const MAX_LISTED: usize = 10;
+
+/// Guild health as of one pulse.
+#[derive(Debug, Default, PartialEq)]
+pub struct HealthReport {
+    pub total_bytes: u64,
+    /// Bytes per top-level guild directory, largest first.
+    pub disk: Vec<(String, u64)>,
+    /// Rituals waiting on unchecked steps for over a day, with how long.
+    pub stale_rituals: Vec<String>,
+    /// Rituals whose latest runs failed, with the streak length.
+    pub failing_rituals: Vec<(String, usize)>,
+    /// Unchecked `- [ ]` items per channel folder.
+    pub open_todos: Vec<(String, usize)>,
+    pub failed_requests: usize,
+    pub failed_skill_calls: usize,
+    pub failed_ritual_runs: usize,
+    /// Gemini tokens metered since the previous pulse.
+    pub tokens_since_last_pulse: u64,
+    /// Gemini tokens recorded by today's ritual runs.
+    pub ritual_tokens_today: u64,
+}
+
+fn disk_usage(base_path: &Path) -> Vec<(String, u64)> {
+    let mut usage: Vec<(String, u64)> = fs::read_dir(base_path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| {
+                    (
+                        entry.file_name().to_string_lossy().into_owned(),
+                        size_of(&entry.path()),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    usage.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    usage
+}
+
+fn has_open_step(content: &str) -> bool {
+    let body = &content[crate::thread::doc::body_start(content)..];
+    body.lines()
+        .any(|line| line.trim_start().starts_with("- [ ]"))
+}
+
+fn stale_rituals(base_path: &Path, now: SystemTime) -> Vec<String> {
+    let mut paths = Vec::new();
+    let _ = crate::rhythm::collect_thread_files(&base_path.join("rituals"), &mut paths);
+    let mut stale: Vec<String> = paths
+        .iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(path).ok()?;
+            if !has_open_step(&content) || crate::ritual_control::is_paused(&content) {
+                return None;
+            }
+            let idle = now
+                .duration_since(path.metadata().ok()?.modified().ok()?)
+                .ok()
+                .filter(|idle| *idle > STALE_AFTER)?;
+            Some(format!(
+                "{} (idle {}d)",
+                path.strip_prefix(base_path)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+                idle.as_secs() / 86_400
+            ))
+        })
+        .collect();
+    stale.sort();
+    stale
+}
+
+fn open_todos(base_path: &Path) -> Vec<(String, usize)> {
+    let Ok(channels) = fs::read_dir(base_path.join("channels")) else {
+        return Vec::new();
+    };
+    let mut todos: Vec<(String, usize)> = channels
+        .flatten()
+        .filter(|channel| channel.path().is_dir())
+        .filter_map(|channel| {
+            let count: usize = fs::read_dir(channel.path())
+                .ok()?
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension().and_then(|ext| ext.to_str()) == Some("md")
+                        && path.file_name().and_then(|name| name.to_str()) != Some("KNOWLEDGE.md")
+                })
+                .filter_map(|path| fs::read_to_string(path).ok())
+                .map(|content| {
+                    content
+                        .lines()
+                        .filter(|line| line.trim_start().starts_with("- [ ]"))
+                        .count()
+                })
+                .sum();
+            (count > 0).then(|| (channel.file_name().to_string_lossy().into_owned(), count))
+        })
+        .collect();
+    todos.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    todos
+}
+
+/// Gather the report for `today`. `tokens_since_last_pulse` comes from the process-wide meter.
+pub fn build_health_report(
+    base_path: &Path,
+    today: NaiveDate,
+    tokens_since_last_pulse: u64,
+) -> HealthReport {
+    let day = today.format("%Y-%m-%d").to_string();
+    let disk = disk_usage(base_path);
+    let statuses = crate::ritual_runs::ritual_statuses(base_path);
+    let runs_today: Vec<crate::ritual_runs::RitualRun> = statuses
+        .iter()
+        .flat_map(|status| crate::ritual_runs::load_runs(base_path, &status.task_id))
+        .filter(|run| run.finished_at.starts_with(&day))
+        .collect();
+    HealthReport {
+        total_bytes: disk.iter().map(|(_, bytes)| bytes).sum(),
+        disk,
+        stale_rituals: stale_rituals(base_path, SystemTime::now()),
+        failing_rituals: statuses
+            .iter()
+            .filter(|status| status.failure_streak > 0)
+            .map(|status| (status.task_id.clone(), status.failure_streak))
+            .collect(),
+        open_todos: open_todos(base_path),
+        failed_requests: crate::trace_log::load_day_traces(base_path, today)
+            .iter()
+            .filter(|record| record.final_state == "Failed")
+            .count(),
+        failed_skill_calls: crate::skill_audit::load_records(base_path, 2)
+            .iter()
+            .filter(|record| record.timestamp.starts_with(&day))
+            .filter(|record| record.exit_code != Some(0) || record.error.is_some())
+            .count(),
+        failed_ritual_runs: runs_today.iter().filter(|run| !run.succeeded()).count(),
+        tokens_since_last_pulse,
+        ritual_tokens_today: runs_today.iter().map(|run| run.tokens).sum(),
+    }
+}
+
+fn push_list(out: &mut String, items: &[String], language: Language) {
+    for item in items.iter().take(MAX_LISTED) {
+        out.push_str(&format!("- {}\n", item));
+    }
+    if items.len() > MAX_LISTED {
+        let more = Message::MoreListed {
+            count: items.len() - MAX_LISTED,
+        };
+        out.push_str(&format!("- {}\n", more.render(language)));
+    }
+}
+
+impl HealthReport {
+    pub fn render(&self, language: Language) -> String {
+        let mut out = Message::HealthHeading {
+            time: &Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            total: &human_bytes(self.total_bytes as i64),
+        }
+        .render(language);
+        let top: Vec<String> = self
+            .disk
+            .iter()
+            .take(4)
+            .map(|(dir, bytes)| format!("{}/ {}", dir, human_bytes(*bytes as i64)))
+            .collect();
+        if !top.is_empty() {
+            out.push_str(&format!(" ({})", top.join(", ")));
+        }
+        let errors = Message::HealthErrors {
+            requests: self.failed_requests,
+            skill_calls: self.failed_skill_calls,
+            ritual_runs: self.failed_ritual_runs,
+        };
+        out.push_str(&format!("\n{}\n", errors.render(language)));
+        let tokens = Message::HealthTokens {
+            since_pulse: self.tokens_since_last_pulse,
+            ritual_runs: self.ritual_tokens_today,
+        };
+        out.push_str(&format!("{}\n", tokens.render(language)));
+
+        let count: usize = self.open_todos.iter().map(|(_, count)| count).sum();
+        let todos = Message::HealthOpenTodos { count };
+        out.push_str(&format!("\n{}\n", todos.render(language)));
+        let lines: Vec<String> = self
+            .open_todos
+            .iter()
+            .map(|(channel, count)| format!("#{}: {}", channel, count))
+            .collect();
+        push_list(&mut out, &lines, language);
+
+        if !self.stale_rituals.is_empty() {
+            let heading = Message::HealthStaleRituals.render(language);
+            out.push_str(&format!("\n{}\n", heading));
+            push_list(&mut out, &self.stale_rituals, language);
+        }
+        if !self.failing_rituals.is_empty() {
+            let heading = Message::HealthFailingRituals.render(language);
+            out.push_str(&format!("\n{}\n", heading));
+            let lines: Vec<String> = self
+                .failing_rituals
+                .iter()
+                .map(|(task_id, streak)| {
+                    Message::HealthRitualFailing {
+                        task_id,
+                        streak: *streak,
+                    }
+                    .render(language)
+                })
+                .collect();
+            push_list(&mut out, &lines, language);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ritual_runs::{RitualRun, record_run};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_health_report_counts_todos_failures_and_tokens() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("channels/general-123456")).unwrap();
+        fs::create_dir_all(base.join("rituals")).unwrap();
+        fs::write(
+            base.join("channels/general-123456/2026-03-01.md"),
+            "- [ ] Fix the gate\n- [x] Water plants\n  - [ ] Buy seeds\n",
+        )
+        .unwrap();
+        fs::write(
+            base.join("channels/general-123456/KNOWLEDGE.md"),
+            "- [ ] not a todo\n",
+        )
+        .unwrap();
+        fs::write(
+            base.join("rituals/backup.md"),
+            "---\nsource: local\ninjection_template: \"- [ ] Back up\"\n---\n# Backup\n",
+        )
+        .unwrap();
+        let today = Local::now().date_naive();
+        let stamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        for result in ["completed", "failed"] {
+            record_run(
+                base,
+                &RitualRun {
+                    task_id: "backup".to_string(),
+                    started_at: stamp.clone(),
+                    finished_at: stamp.clone(),
+                    result: result.to_string(),
+                    steps_completed: 0,
+                    tools: Vec::new(),
+                    tokens: 300,
+                },
+            )
+            .unwrap();
+        }
+
+        let report = build_health_report(base, today, 1200);
+        assert_eq!(report.open_todos, [("general-123456".to_string(), 2)]);
+        assert_eq!(report.failing_rituals, [("backup".to_string(), 1)]);
+        assert_eq!(report.failed_ritual_runs, 1);
+        assert_eq!(report.ritual_tokens_today, 600);
+        assert!(report.stale_rituals.is_empty());
+        assert!(report.disk.iter().any(|(dir, _)| dir == "channels"));
+        assert!(report.total_bytes > 0);
+
+        let text = report.render(Language::En);
+        assert!(text.contains("**Open TODOs**: 2\n- #general-123456: 2"));
+        assert!(text.contains("1200 since the last pulse, 600 in today's ritual runs"));
+        assert!(text.contains("`backup`: last 1 run(s) failed"));
+        let text = report.render(Language::Zh);
+        assert!(text.contains("**未完成待办**：2\n- #general-123456: 2"));
+        assert!(text.contains("`backup`：最近 1 次运行失败"));
+
+        let stale = SystemTime::now() + Duration::from_secs(3 * 86_400 + 3600);
+        fs::write(
+            base.join("rituals/backup.md"),
+            "---\nstatus: active\n---\n- [ ] Back up\n",
+        )
+        .unwrap();
+        assert_eq!(stale_rituals(base, stale), ["rituals/backup.md (idle 3d)"]);
+    }
+}
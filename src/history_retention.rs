@@ -0,0 +1,295 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/history_retention.rs
+ * Responsibility: Compress old `history/<date>/` folders into monthly tarballs and expire them.
+ */
+
+use crate::config::HistoryRetentionConfig;
+use chrono::{Datelike, NaiveDate};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+const ARCHIVE_SUFFIX: &str = ".tar.gz";
+
+/// What one retention pass did.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RetentionStats {
+    pub folders_compressed: usize,
+    pub archives_written: usize,
+    pub folders_deleted: usize,
+    pub archives_deleted: usize,
+    pub bytes_freed: i64,
+}
+
+impl RetentionStats {
+    pub fn is_empty(&self) -> bool {
+        self.folders_compressed == 0 && self.folders_deleted == 0 && self.archives_deleted == 0
+    }
+
+    pub fn report(&self) -> String {
+        format!(
+            "🗄️ History retention: compressed {} day folder(s) into {} monthly archive(s), deleted {} folder(s) and {} archive(s), freed {}.",
+            self.folders_compressed,
+            self.archives_written,
+            self.folders_deleted,
+            self.archives_deleted,
+            human_bytes(self.bytes_freed)
+        )
+    }
+}
+
+pub(crate) fn human_bytes(bytes: i64) -> String {
+    let magnitude = bytes.unsigned_abs() as f64;
+    let sign = if bytes < 0 { "-" } else { "" };
+    if magnitude >= 1024.0 * 1024.0 {
+        format!("{}{:.1} MiB", sign, magnitude / (1024.0 * 1024.0))
+    } else if magnitude >= 1024.0 {
+        format!("{}{:.1} KiB", sign, magnitude / 1024.0)
+    } else {
+        format!("{}{} B", sign, magnitude)
+    }
+}
+
+pub(crate) fn size_of(path: &Path) -> u64 {
+    if path.is_file() {
+        return path.metadata().map(|meta| meta.len()).unwrap_or(0);
+    }
+    fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|entry| size_of(&entry.path())).sum())
+        .unwrap_or(0)
+}
+
+/// Every `history/` folder the daemon archives into: `rituals/history` and each channel's.
+pub(crate) fn history_dirs(base_path: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![base_path.join("rituals").join("history")];
+    if let Ok(entries) = fs::read_dir(base_path.join("channels")) {
+        dirs.extend(entries.flatten().map(|entry| entry.path().join("history")));
+    }
+    dirs.retain(|dir| dir.is_dir());
+    dirs.sort();
+    dirs
+}
+
+/// The last day a `YYYY-MM.tar.gz` archive can hold.
+fn archive_month_end(name: &str) -> Option<NaiveDate> {
+    let month = name.strip_suffix(ARCHIVE_SUFFIX)?;
+    let first = NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d").ok()?;
+    let next = if first.month() == 12 {
+        NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)?
+    };
+    next.pred_opt()
+}
+
+fn append_dir<W: Write>(builder: &mut tar::Builder<W>, dir: &Path, name: &str) -> io::Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?.flatten().map(|e| e.path()).collect();
+    entries.sort();
+    for path in entries {
+        let child = format!(
+            "{}/{}",
+            name,
+            path.file_name().unwrap_or_default().to_string_lossy()
+        );
+        if path.is_symlink() {
+            continue;
+        } else if path.is_dir() {
+            append_dir(builder, &path, &child)?;
+        } else {
+            builder.append_path_with_name(&path, &child)?;
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite `archive` with its existing entries plus the given day folders.
+fn add_to_archive(archive: &Path, folders: &[(String, PathBuf)]) -> io::Result<()> {
+    let staging = archive.with_extension("gz.tmp");
+    {
+        let mut builder = tar::Builder::new(GzEncoder::new(
+            File::create(&staging)?,
+            Compression::default(),
+        ));
+        if archive.is_file() {
+            let mut existing = tar::Archive::new(GzDecoder::new(File::open(archive)?));
+            for entry in existing.entries()? {
+                let mut entry = entry?;
+                let mut header = entry.header().clone();
+                let mut data = Vec::new();
+                entry.read_to_end(&mut data)?;
+                let path = entry.path()?.into_owned();
+                builder.append_data(&mut header, path, data.as_slice())?;
+            }
+        }
+        for (name, folder) in folders {
+            append_dir(&mut builder, folder, name)?;
+        }
+        builder.into_inner()?.finish()?.flush()?;
+    }
+    fs::rename(&staging, archive)
+}
+
+fn enforce_in(
+    history: &Path,
+    settings: &HistoryRetentionConfig,
+    today: NaiveDate,
+    stats: &mut RetentionStats,
+) -> io::Result<()> {
+    let age = |date: NaiveDate| (today - date).num_days();
+    let expired = |date: NaiveDate| {
+        settings.delete_after_days > 0 && age(date) > settings.delete_after_days as i64
+    };
+    let mut by_month: Vec<(String, Vec<(String, PathBuf)>)> = Vec::new();
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(history)?.flatten().map(|e| e.path()).collect();
+    entries.sort();
+    for path in entries {
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        if path.is_dir() {
+            let Ok(date) = NaiveDate::parse_from_str(&name, "%Y-%m-%d") else {
+                continue;
+            };
+            if expired(date) {
+                stats.bytes_freed += size_of(&path) as i64;
+                fs::remove_dir_all(&path)?;
+                stats.folders_deleted += 1;
+            } else if settings.compress_after_days > 0
+                && age(date) > settings.compress_after_days as i64
+            {
+                let month = date.format("%Y-%m").to_string();
+                match by_month.iter_mut().find(|(m, _)| *m == month) {
+                    Some((_, folders)) => folders.push((name, path)),
+                    None => by_month.push((month, vec![(name, path)])),
+                }
+            }
+        } else if let Some(month_end) = archive_month_end(&name)
+            && expired(month_end)
+        {
+            stats.bytes_freed += size_of(&path) as i64;
+            fs::remove_file(&path)?;
+            stats.archives_deleted += 1;
+        }
+    }
+
+    for (month, folders) in by_month {
+        let archive = history.join(format!("{}{}", month, ARCHIVE_SUFFIX));
+        let before: u64 = size_of(&archive) + folders.iter().map(|(_, f)| size_of(f)).sum::<u64>();
+        add_to_archive(&archive, &folders)?;
+        for (_, folder) in &folders {
+            fs::remove_dir_all(folder)?;
+        }
+        stats.bytes_freed += before as i64 - size_of(&archive) as i64;
+        stats.folders_compressed += folders.len();
+        stats.archives_written += 1;
+    }
+    Ok(())
+}
+
+/// Apply the retention policy as of `today` to every history folder in the guild that
+/// `may_write` accepts (it gets guild-relative paths).
+pub fn enforce_retention(
+    base_path: &Path,
+    settings: &HistoryRetentionConfig,
+    today: NaiveDate,
+    may_write: &dyn Fn(&Path) -> bool,
+) -> io::Result<RetentionStats> {
+    let mut stats = RetentionStats::default();
+    if !settings.enabled {
+        return Ok(stats);
+    }
+    for history in history_dirs(base_path) {
+        if !may_write(history.strip_prefix(base_path).unwrap_or(&history)) {
+            continue;
+        }
+        enforce_in(&history, settings, today, &mut stats)?;
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn day(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+    }
+
+    fn archived_names(archive: &Path) -> Vec<String> {
+        let mut tar = tar::Archive::new(GzDecoder::new(File::open(archive).unwrap()));
+        tar.entries()
+            .unwrap()
+            .map(|entry| {
+                entry
+                    .unwrap()
+                    .path()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_retention_compresses_by_month_then_deletes() {
+        let dir = tempdir().unwrap();
+        let history = dir.path().join("channels/general-123456/history");
+        for (date, file) in [
+            ("2025-01-10", "a.md"),
+            ("2026-01-05", "b.md"),
+            ("2026-01-20", "c.md"),
+            ("2026-03-01", "d.md"),
+        ] {
+            fs::create_dir_all(history.join(date)).unwrap();
+            fs::write(history.join(date).join(file), "archived thread ".repeat(50)).unwrap();
+        }
+        let settings = HistoryRetentionConfig {
+            enabled: true,
+            compress_after_days: 30,
+            delete_after_days: 365,
+        };
+
+        let stats = enforce_retention(dir.path(), &settings, day("2026-03-10"), &|_| true).unwrap();
+        assert_eq!(stats.folders_deleted, 1);
+        assert_eq!(stats.folders_compressed, 2);
+        assert_eq!(stats.archives_written, 1);
+        assert!(stats.bytes_freed > 0);
+        assert!(!history.join("2025-01-10").exists());
+        assert!(!history.join("2026-01-05").exists());
+        assert!(history.join("2026-03-01/d.md").exists());
+        assert_eq!(
+            archived_names(&history.join("2026-01.tar.gz")),
+            ["2026-01-05/b.md", "2026-01-20/c.md"]
+        );
+
+        // A late folder for the same month is merged into the existing archive.
+        fs::create_dir_all(history.join("2026-01-31")).unwrap();
+        fs::write(history.join("2026-01-31/e.md"), "late").unwrap();
+        enforce_retention(dir.path(), &settings, day("2026-03-10"), &|_| true).unwrap();
+        assert_eq!(archived_names(&history.join("2026-01.tar.gz")).len(), 3);
+
+        let stats = enforce_retention(dir.path(), &settings, day("2027-02-01"), &|_| true).unwrap();
+        assert_eq!(stats.archives_deleted, 1);
+        assert!(!history.join("2026-01.tar.gz").exists());
+        assert!(
+            stats
+                .report()
+                .contains("deleted 0 folder(s) and 1 archive(s)")
+        );
+
+        let disabled = HistoryRetentionConfig::default();
+        assert!(
+            enforce_retention(dir.path(), &disabled, day("2030-01-01"), &|_| true)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,271 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/hygiene.rs
+ * Responsibility: Deterministic workspace hygiene: rotate huge daily logs, expire outbox and attachment files, and drop empty folders.
+ */
+
+use crate::config::HygieneConfig;
+use crate::history_retention::{human_bytes, size_of};
+use crate::log_info;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Where one message ends and the next begins in a daily log.
+const MESSAGE_BOUNDARY: &str = "\n---\n**Author**:";
+
+/// What one hygiene pass did.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HygieneStats {
+    pub logs_rotated: usize,
+    pub files_removed: usize,
+    pub dirs_removed: usize,
+    pub bytes_freed: u64,
+}
+
+impl HygieneStats {
+    pub fn is_empty(&self) -> bool {
+        self.logs_rotated == 0 && self.files_removed == 0 && self.dirs_removed == 0
+    }
+
+    pub fn report(&self) -> String {
+        format!(
+            "🧽 Hygiene: rotated {} daily log(s), removed {} old outbox/attachment file(s) and {} empty folder(s), freed {}.",
+            self.logs_rotated,
+            self.files_removed,
+            self.dirs_removed,
+            human_bytes(self.bytes_freed as i64)
+        )
+    }
+}
+
+fn relative<'a>(base_path: &Path, path: &'a Path) -> &'a Path {
+    path.strip_prefix(base_path).unwrap_or(path)
+}
+
+/// Byte offset of the first message that starts within the last `keep` bytes of `content`.
+fn split_point(content: &str, keep: usize) -> usize {
+    let from = content.len().saturating_sub(keep);
+    let boundary = content
+        .as_bytes()
+        .windows(MESSAGE_BOUNDARY.len())
+        .skip(from)
+        .position(|window| window == MESSAGE_BOUNDARY.as_bytes());
+    boundary
+        .map(|offset| from + offset)
+        .unwrap_or(content.len())
+}
+
+/// First free `history/<date>/<date>.part<N>.md` next to a channel's daily log.
+fn rotation_target(log: &Path) -> Option<PathBuf> {
+    let stem = log.file_stem()?.to_string_lossy().into_owned();
+    let dir = log.parent()?.join("history").join(&stem);
+    (1..)
+        .map(|part| dir.join(format!("{}.part{}.md", stem, part)))
+        .find(|path| !path.exists())
+}
+
+/// Move all but the most recent messages of an oversized daily log into `history/`, leaving
+/// a pointer to them at the top of the live log.
+/// Blocking, like the whole hygiene pass: the guardian runs it on the blocking pool.
+fn rotate_log(base_path: &Path, log: &Path, max_bytes: u64) -> io::Result<bool> {
+    if log.metadata()?.len() <= max_bytes {
+        return Ok(false);
+    }
+    let content = fs::read_to_string(log)?;
+    let split = split_point(&content, (max_bytes / 4) as usize);
+    let Some(target) = rotation_target(log) else {
+        return Ok(false);
+    };
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&target, &content[..split])?;
+    let note = format!(
+        "> [Tellar] Earlier messages of this log were rotated to `{}`.\n",
+        relative(base_path, &target)
+            .to_string_lossy()
+            .replace('\\', "/")
+    );
+    crate::journal::write(base_path, log, &(note + &content[split..]))?;
+    Ok(true)
+}
+
+fn rotate_logs(
+    base_path: &Path,
+    max_bytes: u64,
+    may_write: &dyn Fn(&Path) -> bool,
+    stats: &mut HygieneStats,
+) -> io::Result<()> {
+    let Ok(channels) = fs::read_dir(base_path.join("channels")) else {
+        return Ok(());
+    };
+    let mut logs: Vec<PathBuf> = channels
+        .flatten()
+        .filter(|channel| channel.path().is_dir())
+        .filter_map(|channel| fs::read_dir(channel.path()).ok())
+        .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+        .filter(|path| crate::thread::doc::is_conversational_log(path))
+        .collect();
+    logs.sort();
+    for log in logs {
+        if may_write(relative(base_path, &log)) && rotate_log(base_path, &log, max_bytes)? {
+            log_info!(
+                "guardian",
+                "🧽 Rotated oversized log {:?}",
+                relative(base_path, &log)
+            );
+            stats.logs_rotated += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Delete files under `dir` last modified more than `max_age` before `now`.
+fn expire_files(
+    dir: &Path,
+    max_age: Duration,
+    now: SystemTime,
+    stats: &mut HygieneStats,
+) -> io::Result<()> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_symlink() {
+            continue;
+        } else if path.is_dir() {
+            expire_files(&path, max_age, now, stats)?;
+        } else if now
+            .duration_since(path.metadata()?.modified()?)
+            .is_ok_and(|age| age > max_age)
+        {
+            let bytes = size_of(&path);
+            fs::remove_file(&path)?;
+            stats.files_removed += 1;
+            stats.bytes_freed += bytes;
+        }
+    }
+    Ok(())
+}
+
+/// Remove empty folders below `dir`, deepest first. `dir` itself stays.
+fn vacuum(dir: &Path, stats: &mut HygieneStats) -> io::Result<()> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() && !path.is_symlink() {
+            vacuum(&path, stats)?;
+            if fs::read_dir(&path)?.next().is_none() {
+                fs::remove_dir(&path)?;
+                stats.dirs_removed += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// One hygiene pass as of `now` over the guild, touching only what `may_write` accepts
+/// (it gets guild-relative paths). No model is involved.
+pub fn run_hygiene(
+    base_path: &Path,
+    settings: &HygieneConfig,
+    now: SystemTime,
+    may_write: &dyn Fn(&Path) -> bool,
+) -> io::Result<HygieneStats> {
+    let mut stats = HygieneStats::default();
+    if !settings.enabled {
+        return Ok(stats);
+    }
+    if settings.rotate_log_bytes > 0 {
+        rotate_logs(base_path, settings.rotate_log_bytes, may_write, &mut stats)?;
+    }
+    let day = Duration::from_secs(86_400);
+    for (dir, days) in [
+        ("brain/outbox", settings.outbox_max_age_days),
+        ("brain/attachments", settings.attachments_max_age_days),
+    ] {
+        let dir = Path::new(dir);
+        if days > 0 && may_write(dir) {
+            expire_files(&base_path.join(dir), day * days, now, &mut stats)?;
+            vacuum(&base_path.join(dir), &mut stats)?;
+        }
+    }
+    for history in crate::history_retention::history_dirs(base_path) {
+        if may_write(relative(base_path, &history)) {
+            vacuum(&history, &mut stats)?;
+        }
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn message(id: usize) -> String {
+        format!(
+            "\n---\n**Author**: Ann (ID: 1) | **Time**: 09:00 | **Message ID**: {}\n\n{}\n",
+            id,
+            "hello ".repeat(20)
+        )
+    }
+
+    #[test]
+    fn test_hygiene_rotates_expires_and_vacuums() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let channel = base.join("channels/general-123456");
+        fs::create_dir_all(channel.join("history/2026-01-01/empty")).unwrap();
+        let log = channel.join("2026-03-01.md");
+        fs::write(&log, (0..20).map(message).collect::<String>()).unwrap();
+        let small = channel.join("2026-03-02.md");
+        fs::write(&small, message(99)).unwrap();
+        fs::create_dir_all(base.join("brain/outbox/old")).unwrap();
+        fs::write(base.join("brain/outbox/old/report.txt"), "report").unwrap();
+        fs::create_dir_all(base.join("brain/attachments")).unwrap();
+        fs::write(base.join("brain/attachments/photo.png"), "png").unwrap();
+
+        let settings = HygieneConfig {
+            rotate_log_bytes: 1024,
+            outbox_max_age_days: 7,
+            attachments_max_age_days: 30,
+            ..Default::default()
+        };
+        let later = SystemTime::now() + Duration::from_secs(10 * 86_400);
+        let stats = run_hygiene(base, &settings, later, &|_| true).unwrap();
+        assert_eq!(stats.logs_rotated, 1);
+        assert_eq!(stats.files_removed, 1);
+        assert_eq!(stats.dirs_removed, 3);
+        assert!(stats.report().contains("rotated 1 daily log(s)"));
+
+        let live = fs::read_to_string(&log).unwrap();
+        assert!(live.starts_with(
+            "> [Tellar] Earlier messages of this log were rotated to \
+             `channels/general-123456/history/2026-03-01/2026-03-01.part1.md`.\n\n---\n**Author**"
+        ));
+        assert!(live.len() <= 1024);
+        assert!(live.ends_with(&message(19)));
+        let rotated =
+            fs::read_to_string(channel.join("history/2026-03-01/2026-03-01.part1.md")).unwrap();
+        assert!(rotated.starts_with(&message(0)));
+        assert_eq!(fs::read_to_string(&small).unwrap(), message(99));
+        assert!(!base.join("brain/outbox/old").exists());
+        assert!(base.join("brain/outbox").is_dir());
+        assert!(base.join("brain/attachments/photo.png").exists());
+        assert!(!channel.join("history/2026-01-01").exists());
+
+        let much_later = SystemTime::now() + Duration::from_secs(40 * 86_400);
+        let stats = run_hygiene(base, &settings, much_later, &|path| {
+            !path.starts_with("brain")
+        })
+        .unwrap();
+        assert!(stats.is_empty());
+        assert!(base.join("brain/attachments/photo.png").exists());
+    }
+}
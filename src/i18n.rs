@@ -0,0 +1,628 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/i18n.rs
+ * Responsibility: The catalog of the steward's own Discord notices and blackboard log lines, per `runtime.language`.
+ */
+
+use crate::alerts::Severity;
+use crate::config::Language;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The configured language, for code such as the Discord client that has no config at hand.
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Set the process-wide language from `runtime.language` at startup.
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::Zh,
+        _ => Language::En,
+    }
+}
+
+/// Every text the steward writes itself. Model answers and user content pass through as is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Message<'a> {
+    /// Posted when a conversation fails outright.
+    ProcessingFailed,
+    ThreadArchived {
+        thread: &'a str,
+        date: &'a str,
+    },
+    /// Notice above a reply sent as `response.md` because Discord refused it as a message.
+    ReplyAttached,
+    AttachedFile {
+        name: &'a str,
+    },
+    ApprovalTitle,
+    ApprovalRequest {
+        tool: &'a str,
+        args: &'a str,
+        approve: &'a str,
+        deny: &'a str,
+        timeout_secs: u64,
+    },
+    ApprovalTimedOut {
+        tool: &'a str,
+    },
+    NewEmail {
+        from: &'a str,
+        subject: &'a str,
+        path: &'a str,
+    },
+    /// `steps` is the list of tools run, already joined; empty when none ran.
+    SessionInterrupted {
+        started: &'a str,
+        turns: usize,
+        steps: &'a str,
+    },
+    /// Marks a failed tool in [`Message::SessionInterrupted`]'s step list.
+    StepFailed,
+    PermissionsMissing,
+    PermissionsHint,
+    DegradedEntered {
+        summary: &'a str,
+    },
+    DegradedRecovered {
+        held: usize,
+    },
+    /// Blackboard log line after a ritual step succeeds.
+    ExecutionResult {
+        response: &'a str,
+    },
+    /// Blackboard log line after a ritual step fails; `state` is the outcome label.
+    TaskFailed {
+        state: &'a str,
+        response: &'a str,
+    },
+    TaskTimedOut {
+        limit_secs: u64,
+    },
+    /// Blackboard log line after a conversation fails outright.
+    ProcessingError {
+        error: &'a str,
+    },
+    /// Posted when a reminder or other one-shot ritual fires; `text` is its TODO without
+    /// the checkbox or `Reminder:` prefix.
+    ReminderFired {
+        text: &'a str,
+    },
+    /// Last line of a list cut short in a digest or health report.
+    MoreListed {
+        count: usize,
+    },
+    NoneListed,
+    DigestHeading {
+        day: &'a str,
+    },
+    DigestRequests {
+        total: usize,
+        conversations: usize,
+        ritual_steps: usize,
+    },
+    /// `outcomes` is the final states with their counts, already joined.
+    DigestOutcomes {
+        outcomes: &'a str,
+    },
+    DigestChannels,
+    DigestChannelRequests {
+        channel: &'a str,
+        handled: usize,
+        failed: usize,
+    },
+    DigestRituals,
+    DigestErrors,
+    DigestSkillFailed {
+        time: &'a str,
+        skill: &'a str,
+        tool: &'a str,
+        error: Option<&'a str>,
+    },
+    DigestKnowledge,
+    HealthHeading {
+        time: &'a str,
+        total: &'a str,
+    },
+    HealthErrors {
+        requests: usize,
+        skill_calls: usize,
+        ritual_runs: usize,
+    },
+    HealthTokens {
+        since_pulse: u64,
+        ritual_runs: u64,
+    },
+    HealthOpenTodos {
+        count: usize,
+    },
+    HealthStaleRituals,
+    HealthFailingRituals,
+    HealthRitualFailing {
+        task_id: &'a str,
+        streak: usize,
+    },
+    PulseFindings,
+    PulsePruned {
+        count: usize,
+    },
+    PulseMerged {
+        count: usize,
+        changelog: &'a str,
+    },
+    /// The bracketed severity in a rendered Guardian finding.
+    SeverityLabel {
+        severity: Severity,
+    },
+    RitualFailing {
+        task_id: &'a str,
+        streak: usize,
+    },
+    SkillFailing {
+        tool: &'a str,
+        streak: usize,
+    },
+    RequestsFailed {
+        count: usize,
+    },
+    RitualStale {
+        ritual: &'a str,
+    },
+    CheckEscalated {
+        name: &'a str,
+        escalation: &'a str,
+    },
+    /// `line` is the first line of the check's answer.
+    CheckReported {
+        name: &'a str,
+        line: &'a str,
+    },
+    CheckUnfinished {
+        name: &'a str,
+        line: &'a str,
+    },
+    BackupChecksumMismatch {
+        name: &'a str,
+    },
+    BackupUnreadable {
+        name: &'a str,
+        error: &'a str,
+    },
+    BackupCorrupt {
+        name: &'a str,
+    },
+    BackupWithoutBrain {
+        name: &'a str,
+    },
+    BackupWithoutChecksum {
+        name: &'a str,
+        files: usize,
+    },
+    BackupVerified {
+        name: &'a str,
+        files: usize,
+        size: &'a str,
+    },
+    BackupMissing {
+        dir: &'a str,
+    },
+    BackupStale {
+        name: &'a str,
+        age_hours: u64,
+        limit_hours: u64,
+    },
+}
+
+impl Message<'_> {
+    pub fn render(&self, language: Language) -> String {
+        match language {
+            Language::En => self.english(),
+            Language::Zh => self.chinese(),
+        }
+    }
+
+    fn english(&self) -> String {
+        match *self {
+            Self::ProcessingFailed => "⚠️ *The steward ran into a problem with your request. Please try again later, or check the blackboard log.*".to_string(),
+            Self::ThreadArchived { thread, date } => {
+                format!("📦 Thread **#{}** has been archived to history/{}", thread, date)
+            }
+            Self::ReplyAttached => "📄 This reply was too long or malformed for a Discord message, so it is attached as a file.".to_string(),
+            Self::AttachedFile { name } => format!("📎 Attached file: `{}`", name),
+            Self::ApprovalTitle => "Approval required".to_string(),
+            Self::ApprovalRequest {
+                tool,
+                args,
+                approve,
+                deny,
+                timeout_secs,
+            } => format!(
+                "`{}` wants to run with:\n```json\n{}\n```\nReact {} to approve or {} to deny within {}s.",
+                tool, args, approve, deny, timeout_secs
+            ),
+            Self::ApprovalTimedOut { tool } => {
+                format!("⌛ Approval for `{}` timed out; the step was aborted.", tool)
+            }
+            Self::NewEmail {
+                from,
+                subject,
+                path,
+            } => format!("📧 New email from **{}** — {}\n`{}`", from, subject, path),
+            Self::SessionInterrupted {
+                started,
+                turns,
+                steps,
+            } => format!(
+                "Tellar restarted while working on this (started {}) after {} step(s){}. It was not resumed automatically; ask again to retry.",
+                started,
+                turns,
+                if steps.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {}", steps)
+                }
+            ),
+            Self::StepFailed => " (failed)".to_string(),
+            Self::PermissionsMissing => {
+                "🔐 **Permission self-test**: Tellar is missing permissions in mapped channels:"
+                    .to_string()
+            }
+            Self::PermissionsHint => "Grant these to the bot role, then restart Tellar.".to_string(),
+            Self::DegradedEntered { summary } => format!(
+                "🚧 {}. Holding notifications and pausing rituals not marked `essential: true`.",
+                summary
+            ),
+            Self::DegradedRecovered { held } => format!(
+                "✅ Recovered from degraded mode; posting {} held notification(s) and resuming rituals.",
+                held
+            ),
+            Self::ExecutionResult { response } => format!("Execution result: {}", response),
+            Self::TaskFailed { state, response } => {
+                format!("❌ Task failed ({}): {}", state, response)
+            }
+            Self::TaskTimedOut { limit_secs } => format!(
+                "❌ Task failed (TimedOut): still running after max_runtime_secs of {}s",
+                limit_secs
+            ),
+            Self::ProcessingError { error } => format!("❌ Error processing request: {}", error),
+            Self::ReminderFired { text } => format!("⏰ Reminder: {}", text),
+            Self::MoreListed { count } => format!("…and {} more", count),
+            Self::NoneListed => "None".to_string(),
+            Self::DigestHeading { day } => format!("# Daily Digest — {}\n\n## Activity", day),
+            Self::DigestRequests {
+                total,
+                conversations,
+                ritual_steps,
+            } => format!(
+                "Requests handled: {} ({} conversation(s), {} ritual step(s))",
+                total, conversations, ritual_steps
+            ),
+            Self::DigestOutcomes { outcomes } => format!("Outcomes: {}", outcomes),
+            Self::DigestChannels => "## Channels".to_string(),
+            Self::DigestChannelRequests {
+                channel,
+                handled,
+                failed: 0,
+            } => format!("{}: {} request(s)", channel, handled),
+            Self::DigestChannelRequests {
+                channel,
+                handled,
+                failed,
+            } => format!("{}: {} request(s), {} failed", channel, handled, failed),
+            Self::DigestRituals => "## Rituals".to_string(),
+            Self::DigestErrors => "## Errors".to_string(),
+            Self::DigestSkillFailed {
+                time,
+                skill,
+                tool,
+                error,
+            } => format!(
+                "{} skill {}/{} failed{}",
+                time,
+                skill,
+                tool,
+                error.map(|error| format!(": {}", error)).unwrap_or_default()
+            ),
+            Self::DigestKnowledge => "## Knowledge Added".to_string(),
+            Self::HealthHeading { time, total } => {
+                format!("🩺 **Guild health — {}**\n\n**Disk**: {} total", time, total)
+            }
+            Self::HealthErrors {
+                requests,
+                skill_calls,
+                ritual_runs,
+            } => format!(
+                "**Errors today**: {} failed request(s), {} failed skill call(s), {} failed ritual run(s)",
+                requests, skill_calls, ritual_runs
+            ),
+            Self::HealthTokens {
+                since_pulse,
+                ritual_runs,
+            } => format!(
+                "**Tokens**: {} since the last pulse, {} in today's ritual runs",
+                since_pulse, ritual_runs
+            ),
+            Self::HealthOpenTodos { count } => format!("**Open TODOs**: {}", count),
+            Self::HealthStaleRituals => "**Stale rituals**".to_string(),
+            Self::HealthFailingRituals => "**Failing rituals**".to_string(),
+            Self::HealthRitualFailing { task_id, streak } => {
+                format!("`{}`: last {} run(s) failed", task_id, streak)
+            }
+            Self::PulseFindings => "**Findings**".to_string(),
+            Self::PulsePruned { count } => {
+                format!("🧹 Pruned {} expired or superseded fact(s).", count)
+            }
+            Self::PulseMerged { count, changelog } => format!(
+                "🧬 Merged {} duplicate or conflicting fact(s); see {}.",
+                count, changelog
+            ),
+            Self::SeverityLabel { severity } => severity.label().to_string(),
+            Self::RitualFailing { task_id, streak } => {
+                format!("Ritual `{}` failed its last {} run(s)", task_id, streak)
+            }
+            Self::SkillFailing { tool, streak } => {
+                format!("Skill tool `{}` failed its last {} call(s)", tool, streak)
+            }
+            Self::RequestsFailed { count } => format!("{} request(s) failed today", count),
+            Self::RitualStale { ritual } => format!("{} is waiting on unchecked steps", ritual),
+            Self::CheckEscalated { name, escalation } => {
+                format!("Check `{}` escalated: {}", name, escalation)
+            }
+            Self::CheckReported { name, line } => format!("Check `{}`: {}", name, line),
+            Self::CheckUnfinished { name, line } => {
+                format!("Check `{}` did not finish: {}", name, line)
+            }
+            Self::BackupChecksumMismatch { name } => {
+                format!("Backup `{}` does not match its SHA-256 checksum", name)
+            }
+            Self::BackupUnreadable { name, error } => {
+                format!("Backup `{}` could not be read: {}", name, error)
+            }
+            Self::BackupCorrupt { name } => {
+                format!("Backup `{}` is corrupt or empty and would not restore", name)
+            }
+            Self::BackupWithoutBrain { name } => {
+                format!("Backup `{}` unpacks but holds no `brain/`", name)
+            }
+            Self::BackupWithoutChecksum { name, files } => format!(
+                "Backup `{}` unpacks ({} file(s)) but has no `.sha256` checksum beside it",
+                name, files
+            ),
+            Self::BackupVerified { name, files, size } => format!(
+                "Backup `{}` verified: checksum matches, {} file(s), {}",
+                name, files, size
+            ),
+            Self::BackupMissing { dir } => format!("No backup archive found in {}", dir),
+            Self::BackupStale {
+                name,
+                age_hours,
+                limit_hours,
+            } => format!(
+                "Latest backup `{}` is {}h old (limit {}h)",
+                name, age_hours, limit_hours
+            ),
+        }
+    }
+
+    fn chinese(&self) -> String {
+        match *self {
+            Self::ProcessingFailed => {
+                "⚠️ *管家在处理您的请求时遇到了异常，请稍后再试，或检查黑板记录。*".to_string()
+            }
+            Self::ThreadArchived { thread, date } => {
+                format!("📦 话题 **#{}** 已归档至 history/{}", thread, date)
+            }
+            Self::ReplyAttached => {
+                "📄 这条回复过长或格式无法作为 Discord 消息发送，已作为文件附上。".to_string()
+            }
+            Self::AttachedFile { name } => format!("📎 附件：`{}`", name),
+            Self::ApprovalTitle => "需要审批".to_string(),
+            Self::ApprovalRequest {
+                tool,
+                args,
+                approve,
+                deny,
+                timeout_secs,
+            } => format!(
+                "`{}` 请求以如下参数运行：\n```json\n{}\n```\n请在 {} 秒内回应 {} 批准或 {} 拒绝。",
+                tool, args, timeout_secs, approve, deny
+            ),
+            Self::ApprovalTimedOut { tool } => {
+                format!("⌛ `{}` 的审批已超时，该步骤已中止。", tool)
+            }
+            Self::NewEmail {
+                from,
+                subject,
+                path,
+            } => format!("📧 来自 **{}** 的新邮件 — {}\n`{}`", from, subject, path),
+            Self::SessionInterrupted {
+                started,
+                turns,
+                steps,
+            } => format!(
+                "Tellar 在处理此任务时重启了（开始于 {}，已执行 {} 步{}）。任务未自动恢复，如需重试请再次提出。",
+                started,
+                turns,
+                if steps.is_empty() {
+                    String::new()
+                } else {
+                    format!("：{}", steps)
+                }
+            ),
+            Self::StepFailed => "（失败）".to_string(),
+            Self::PermissionsMissing => {
+                "🔐 **权限自检**：Tellar 在以下已映射频道中缺少权限：".to_string()
+            }
+            Self::PermissionsHint => "请为机器人角色授予这些权限，然后重启 Tellar。".to_string(),
+            Self::DegradedEntered { summary } => format!(
+                "🚧 {}。暂存通知，并暂停未标记 `essential: true` 的仪式。",
+                summary
+            ),
+            Self::DegradedRecovered { held } => format!(
+                "✅ 已退出降级模式；正在发送 {} 条暂存通知并恢复仪式。",
+                held
+            ),
+            Self::ExecutionResult { response } => format!("执行结果：{}", response),
+            Self::TaskFailed { state, response } => {
+                format!("❌ 任务失败 ({})：{}", state, response)
+            }
+            Self::TaskTimedOut { limit_secs } => format!(
+                "❌ 任务失败 (TimedOut)：超过 max_runtime_secs 的 {} 秒仍在运行",
+                limit_secs
+            ),
+            Self::ProcessingError { error } => format!("❌ 处理请求时出错：{}", error),
+            Self::ReminderFired { text } => format!("⏰ 提醒：{}", text),
+            Self::MoreListed { count } => format!("……另有 {} 项", count),
+            Self::NoneListed => "无".to_string(),
+            Self::DigestHeading { day } => format!("# 每日摘要 — {}\n\n## 活动", day),
+            Self::DigestRequests {
+                total,
+                conversations,
+                ritual_steps,
+            } => format!(
+                "处理请求：{}（对话 {} 次，仪式步骤 {} 个）",
+                total, conversations, ritual_steps
+            ),
+            Self::DigestOutcomes { outcomes } => format!("结果：{}", outcomes),
+            Self::DigestChannels => "## 频道".to_string(),
+            Self::DigestChannelRequests {
+                channel,
+                handled,
+                failed: 0,
+            } => format!("{}：{} 个请求", channel, handled),
+            Self::DigestChannelRequests {
+                channel,
+                handled,
+                failed,
+            } => format!("{}：{} 个请求，{} 个失败", channel, handled, failed),
+            Self::DigestRituals => "## 仪式".to_string(),
+            Self::DigestErrors => "## 错误".to_string(),
+            Self::DigestSkillFailed {
+                time,
+                skill,
+                tool,
+                error,
+            } => format!(
+                "{} 技能 {}/{} 失败{}",
+                time,
+                skill,
+                tool,
+                error
+                    .map(|error| format!("：{}", error))
+                    .unwrap_or_default()
+            ),
+            Self::DigestKnowledge => "## 新增知识".to_string(),
+            Self::HealthHeading { time, total } => {
+                format!("🩺 **公会健康状况 — {}**\n\n**磁盘**：共 {}", time, total)
+            }
+            Self::HealthErrors {
+                requests,
+                skill_calls,
+                ritual_runs,
+            } => format!(
+                "**今日错误**：{} 个请求失败，{} 次技能调用失败，{} 次仪式运行失败",
+                requests, skill_calls, ritual_runs
+            ),
+            Self::HealthTokens {
+                since_pulse,
+                ritual_runs,
+            } => format!(
+                "**Token**：自上次巡检以来 {}，今日仪式运行 {}",
+                since_pulse, ritual_runs
+            ),
+            Self::HealthOpenTodos { count } => format!("**未完成待办**：{}", count),
+            Self::HealthStaleRituals => "**停滞的仪式**".to_string(),
+            Self::HealthFailingRituals => "**失败的仪式**".to_string(),
+            Self::HealthRitualFailing { task_id, streak } => {
+                format!("`{}`：最近 {} 次运行失败", task_id, streak)
+            }
+            Self::PulseFindings => "**发现**".to_string(),
+            Self::PulsePruned { count } => format!("🧹 已清理 {} 条过期或被取代的事实。", count),
+            Self::PulseMerged { count, changelog } => format!(
+                "🧬 已合并 {} 条重复或冲突的事实；详见 {}。",
+                count, changelog
+            ),
+            Self::SeverityLabel { severity } => match severity {
+                Severity::Info => "信息",
+                Severity::Warn => "警告",
+                Severity::Critical => "严重",
+            }
+            .to_string(),
+            Self::RitualFailing { task_id, streak } => {
+                format!("仪式 `{}` 最近 {} 次运行失败", task_id, streak)
+            }
+            Self::SkillFailing { tool, streak } => {
+                format!("技能工具 `{}` 最近 {} 次调用失败", tool, streak)
+            }
+            Self::RequestsFailed { count } => format!("今日有 {} 个请求失败", count),
+            Self::RitualStale { ritual } => format!("{} 正在等待未勾选的步骤", ritual),
+            Self::CheckEscalated { name, escalation } => {
+                format!("检查 `{}` 已上报：{}", name, escalation)
+            }
+            Self::CheckReported { name, line } => format!("检查 `{}`：{}", name, line),
+            Self::CheckUnfinished { name, line } => {
+                format!("检查 `{}` 未完成：{}", name, line)
+            }
+            Self::BackupChecksumMismatch { name } => {
+                format!("备份 `{}` 与其 SHA-256 校验和不符", name)
+            }
+            Self::BackupUnreadable { name, error } => {
+                format!("无法读取备份 `{}`：{}", name, error)
+            }
+            Self::BackupCorrupt { name } => {
+                format!("备份 `{}` 已损坏或为空，无法恢复", name)
+            }
+            Self::BackupWithoutBrain { name } => {
+                format!("备份 `{}` 可以解包，但不含 `brain/`", name)
+            }
+            Self::BackupWithoutChecksum { name, files } => format!(
+                "备份 `{}` 可以解包（{} 个文件），但旁边没有 `.sha256` 校验和",
+                name, files
+            ),
+            Self::BackupVerified { name, files, size } => format!(
+                "备份 `{}` 已验证：校验和匹配，{} 个文件，{}",
+                name, files, size
+            ),
+            Self::BackupMissing { dir } => format!("在 {} 中找不到备份归档", dir),
+            Self::BackupStale {
+                name,
+                age_hours,
+                limit_hours,
+            } => format!(
+                "最新备份 `{}` 已有 {} 小时（上限 {} 小时）",
+                name, age_hours, limit_hours
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_render_in_the_configured_language() {
+        let archived = Message::ThreadArchived {
+            thread: "release",
+            date: "2026-03-01",
+        };
+        assert_eq!(
+            archived.render(Language::En),
+            "📦 Thread **#release** has been archived to history/2026-03-01"
+        );
+        assert_eq!(
+            archived.render(Language::Zh),
+            "📦 话题 **#release** 已归档至 history/2026-03-01"
+        );
+        assert!(
+            Message::ProcessingFailed
+                .render(Language::Zh)
+                .contains("管家")
+        );
+
+        let parsed: Language = serde_yml::from_str("zh").unwrap();
+        assert_eq!(parsed, Language::Zh);
+    }
+}
@@ -0,0 +1,266 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/image_ops.rs
+ * Responsibility: The `image` core tool: inspect, resize, crop, and convert guild images.
+ */
+
+use crate::file_ops::require_new_rel_path;
+use crate::tools::{ToolExecutionResult, require_safe_rel_path};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, ImageReader};
+use serde_json::{Value, json};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::Path;
+
+pub const IMAGE_TOOL_NAME: &str = "image";
+const DEFAULT_JPEG_QUALITY: u8 = 80;
+
+fn dimension_arg(args: &Value, field: &str) -> Option<u32> {
+    args.get(field)
+        .and_then(Value::as_u64)
+        .filter(|value| *value > 0)
+        .map(|value| value.min(u64::from(u32::MAX)) as u32)
+}
+
+fn load_image(path: &Path) -> Result<(DynamicImage, Option<ImageFormat>), String> {
+    let reader = ImageReader::open(path)
+        .and_then(|reader| reader.with_guessed_format())
+        .map_err(|e| e.to_string())?;
+    let format = reader.format();
+    let image = reader.decode().map_err(|e| e.to_string())?;
+    Ok((image, format))
+}
+
+fn format_name(format: Option<ImageFormat>) -> &'static str {
+    match format {
+        Some(ImageFormat::Png) => "png",
+        Some(ImageFormat::Jpeg) => "jpeg",
+        Some(ImageFormat::WebP) => "webp",
+        Some(ImageFormat::Gif) => "gif",
+        _ => "unknown",
+    }
+}
+
+fn save_image(image: &DynamicImage, output: &Path, quality: u8) -> Result<(), String> {
+    let format = ImageFormat::from_path(output)
+        .map_err(|_| "output must end in .png, .jpg, .jpeg, .webp, or .gif".to_string())?;
+    if format == ImageFormat::Jpeg {
+        // JPEG has no alpha channel; flatten first.
+        let file = File::create(output).map_err(|e| e.to_string())?;
+        let encoder = JpegEncoder::new_with_quality(BufWriter::new(file), quality);
+        DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(encoder)
+            .map_err(|e| e.to_string())
+    } else {
+        image
+            .save_with_format(output, format)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Apply `op` to the decoded image. `info` is handled by the caller.
+fn transform(image: DynamicImage, op: &str, args: &Value) -> Result<DynamicImage, String> {
+    match op {
+        "resize" => {
+            let width = dimension_arg(args, "width");
+            let height = dimension_arg(args, "height");
+            if width.is_none() && height.is_none() {
+                return Err("resize needs `width`, `height`, or both".to_string());
+            }
+            let exact = args.get("exact").and_then(Value::as_bool).unwrap_or(false);
+            let width = width.unwrap_or(u32::MAX);
+            let height = height.unwrap_or(u32::MAX);
+            if exact && width != u32::MAX && height != u32::MAX {
+                return Ok(image.resize_exact(width, height, FilterType::Lanczos3));
+            }
+            // Never upscale: thumbnails only shrink.
+            if image.width() <= width && image.height() <= height {
+                return Ok(image);
+            }
+            Ok(image.resize(width, height, FilterType::Lanczos3))
+        }
+        "crop" => {
+            let x = args.get("x").and_then(Value::as_u64).unwrap_or(0) as u32;
+            let y = args.get("y").and_then(Value::as_u64).unwrap_or(0) as u32;
+            let (Some(width), Some(height)) =
+                (dimension_arg(args, "width"), dimension_arg(args, "height"))
+            else {
+                return Err("crop needs `width` and `height`".to_string());
+            };
+            if x >= image.width() || y >= image.height() {
+                return Err(format!(
+                    "crop origin ({}, {}) is outside the {}x{} image",
+                    x,
+                    y,
+                    image.width(),
+                    image.height()
+                ));
+            }
+            Ok(image.crop_imm(x, y, width, height))
+        }
+        "convert" => Ok(image),
+        other => Err(format!(
+            "unknown op `{}`; use info, resize, crop, or convert",
+            other
+        )),
+    }
+}
+
+pub(crate) fn run_image_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let rel_path = match require_safe_rel_path(args, "path", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let op = args.get("op").and_then(Value::as_str).unwrap_or("info");
+    let (image, format) = match load_image(&base_path.join(rel_path)) {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            return ToolExecutionResult::error(format!(
+                "Error reading image {}: {}",
+                rel_path, error
+            ));
+        }
+    };
+    let source_bytes = fs::metadata(base_path.join(rel_path))
+        .map(|meta| meta.len())
+        .unwrap_or(0);
+    if op == "info" {
+        return ToolExecutionResult::success(format!(
+            "{}: {}x{} {}, {} bytes",
+            rel_path,
+            image.width(),
+            image.height(),
+            format_name(format),
+            source_bytes
+        ));
+    }
+
+    let output = match require_new_rel_path(args, "output", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let output_path = base_path.join(output);
+    let overwrite = args
+        .get("overwrite")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if output_path.exists() && !overwrite {
+        return ToolExecutionResult::error(format!(
+            "Error: {} already exists. Pass overwrite=true to replace it.",
+            output
+        ));
+    }
+    let quality = args
+        .get("quality")
+        .and_then(Value::as_u64)
+        .map(|quality| quality.clamp(1, 100) as u8)
+        .unwrap_or(DEFAULT_JPEG_QUALITY);
+
+    let result = match transform(image, op, args) {
+        Ok(result) => result,
+        Err(error) => return ToolExecutionResult::error(format!("Error: {}", error)),
+    };
+    if let Some(parent) = output_path.parent()
+        && let Err(error) = fs::create_dir_all(parent)
+    {
+        return ToolExecutionResult::error(format!("Error creating {}: {}", output, error));
+    }
+    if let Err(error) = save_image(&result, &output_path, quality) {
+        return ToolExecutionResult::error(format!("Error writing {}: {}", output, error));
+    }
+    let output_bytes = fs::metadata(&output_path)
+        .map(|meta| meta.len())
+        .unwrap_or(0);
+    ToolExecutionResult::success(format!(
+        "Wrote {} ({}x{}, {} bytes; source was {} bytes)",
+        output,
+        result.width(),
+        result.height(),
+        output_bytes,
+        source_bytes
+    ))
+}
+
+pub(crate) fn image_tool_definition() -> Value {
+    json!({
+        "name": IMAGE_TOOL_NAME,
+        "description": "Inspect or transform a guild image (PNG, JPEG, WebP, GIF). Shrink screenshots before sending them or before vision analysis to save tokens and upload size. The output format follows the output file extension.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "op": { "type": "string", "enum": ["info", "resize", "crop", "convert"], "description": "Defaults to info" },
+                "path": { "type": "string", "description": "Source image, relative to guild root" },
+                "output": { "type": "string", "description": "Destination for resize/crop/convert, relative to guild root" },
+                "width": { "type": "number", "description": "resize: maximum width; crop: width of the region" },
+                "height": { "type": "number", "description": "resize: maximum height; crop: height of the region" },
+                "exact": { "type": "boolean", "description": "resize: stretch to exactly width x height instead of keeping the aspect ratio" },
+                "x": { "type": "number", "description": "crop: left edge" },
+                "y": { "type": "number", "description": "crop: top edge" },
+                "quality": { "type": "number", "description": "JPEG quality 1-100. Defaults to 80" },
+                "overwrite": { "type": "boolean", "description": "Replace an existing output file" }
+            },
+            "required": ["path"]
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+    use tempfile::tempdir;
+
+    fn write_png(path: &Path, width: u32, height: u32) {
+        RgbaImage::from_pixel(width, height, Rgba([200, 10, 10, 255]))
+            .save(path)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_image_tool_reports_info_and_resizes_keeping_aspect_ratio() {
+        let dir = tempdir().unwrap();
+        write_png(&dir.path().join("shot.png"), 400, 200);
+
+        let info = run_image_tool(&json!({ "path": "shot.png" }), dir.path());
+        assert!(info.output.starts_with("shot.png: 400x200 png, "));
+
+        let resized = run_image_tool(
+            &json!({ "op": "resize", "path": "shot.png", "output": "thumbs/shot.jpg", "width": 100 }),
+            dir.path(),
+        );
+        assert!(!resized.is_error, "{}", resized.output);
+        assert!(resized.output.starts_with("Wrote thumbs/shot.jpg (100x50,"));
+        let (thumb, format) = load_image(&dir.path().join("thumbs/shot.jpg")).unwrap();
+        assert_eq!((thumb.width(), thumb.height()), (100, 50));
+        assert_eq!(format, Some(ImageFormat::Jpeg));
+
+        let again = run_image_tool(
+            &json!({ "op": "convert", "path": "shot.png", "output": "thumbs/shot.jpg" }),
+            dir.path(),
+        );
+        assert!(again.output.contains("overwrite=true"));
+    }
+
+    #[test]
+    fn test_image_tool_crops_and_rejects_bad_regions() {
+        let dir = tempdir().unwrap();
+        write_png(&dir.path().join("shot.png"), 50, 40);
+
+        let cropped = run_image_tool(
+            &json!({ "op": "crop", "path": "shot.png", "output": "crop.webp", "x": 10, "y": 10, "width": 100, "height": 20 }),
+            dir.path(),
+        );
+        assert!(!cropped.is_error, "{}", cropped.output);
+        assert!(cropped.output.starts_with("Wrote crop.webp (40x20,"));
+
+        let outside = run_image_tool(
+            &json!({ "op": "crop", "path": "shot.png", "output": "x.png", "x": 60, "width": 5, "height": 5 }),
+            dir.path(),
+        );
+        assert!(outside.is_error);
+        let not_image = run_image_tool(&json!({ "path": "../shot.png" }), dir.path());
+        assert!(not_image.is_error);
+    }
+}
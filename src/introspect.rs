@@ -0,0 +1,281 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/introspect.rs
+ * Responsibility: Live runtime state for `tellarctl status --detail`, served as JSON on a unix socket in the guild.
+ */
+
+use crate::StewardNotification;
+use crate::log_warn;
+use chrono::Local;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Mutex;
+use tokio::sync::mpsc;
+
+/// Socket the running daemon answers on, next to `.tellar.lock`.
+pub const STATUS_SOCKET: &str = ".tellar.sock";
+const MAX_RECENT_ERRORS: usize = 20;
+
+static STARTED_AT: Lazy<String> = Lazy::new(timestamp);
+static LAST_PULSE: Mutex<Option<String>> = Mutex::new(None);
+static RECENT_ERRORS: Mutex<VecDeque<RecentError>> = Mutex::new(VecDeque::new());
+
+fn timestamp() -> String {
+    Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentError {
+    pub at: String,
+    pub source: String,
+    pub message: String,
+}
+
+/// A session in flight, from `brain/sessions/`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveSession {
+    pub blackboard: String,
+    pub mode: crate::session_store::SessionMode,
+    pub steps: usize,
+    pub started_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledJob {
+    pub ritual: String,
+    pub next_fire: Option<String>,
+}
+
+/// What the daemon is doing right now.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeStatus {
+    pub pid: u32,
+    pub started_at: String,
+    pub degraded: bool,
+    pub sessions: Vec<ActiveSession>,
+    /// Triggers waiting for the Watchman.
+    pub queued_notifications: usize,
+    /// Unsolicited posts held back in degraded mode.
+    pub held_notifications: usize,
+    /// Threads with a coalesced trigger waiting for their current run to end.
+    pub queued_reruns: usize,
+    pub jobs: Vec<ScheduledJob>,
+    pub last_pulse: Option<String>,
+    pub recent_errors: Vec<RecentError>,
+}
+
+/// Remember a failure for `status --detail`; only the last few are kept.
+pub fn record_error(source: &str, message: &str) {
+    let mut errors = RECENT_ERRORS.lock().unwrap_or_else(|e| e.into_inner());
+    if errors.len() == MAX_RECENT_ERRORS {
+        errors.pop_front();
+    }
+    errors.push_back(RecentError {
+        at: timestamp(),
+        source: source.to_string(),
+        message: message.to_string(),
+    });
+}
+
+pub fn record_pulse() {
+    *LAST_PULSE.lock().unwrap_or_else(|e| e.into_inner()) = Some(timestamp());
+}
+
+fn relative(base_path: &Path, path: &Path) -> String {
+    path.strip_prefix(base_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+pub async fn snapshot(
+    base_path: &Path,
+    notif_tx: &mpsc::Sender<StewardNotification>,
+) -> RuntimeStatus {
+    let sessions = crate::session_store::interrupted_sessions(base_path)
+        .into_iter()
+        .map(|record| ActiveSession {
+            blackboard: relative(base_path, &record.blackboard),
+            mode: record.mode,
+            steps: record.steps.len(),
+            started_at: record.started_at,
+        })
+        .collect();
+    let jobs = crate::rhythm::scheduled_jobs()
+        .await
+        .into_iter()
+        .map(|(path, next)| ScheduledJob {
+            ritual: relative(base_path, &path),
+            next_fire: next.map(|at| {
+                at.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            }),
+        })
+        .collect();
+    RuntimeStatus {
+        pid: std::process::id(),
+        started_at: STARTED_AT.clone(),
+        degraded: crate::api_health::is_degraded(),
+        sessions,
+        queued_notifications: notif_tx.max_capacity() - notif_tx.capacity(),
+        held_notifications: crate::api_health::held_count(),
+        queued_reruns: crate::thread::queued_reruns(),
+        jobs,
+        last_pulse: LAST_PULSE.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+        recent_errors: RECENT_ERRORS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect(),
+    }
+}
+
+impl RuntimeStatus {
+    pub fn render(&self) -> String {
+        let mut lines = vec![format!(
+            "🔎 Tellar PID {}, running since {}{}",
+            self.pid,
+            self.started_at,
+            if self.degraded {
+                " (degraded mode)"
+            } else {
+                ""
+            }
+        )];
+        lines.push(format!("Active sessions: {}", self.sessions.len()));
+        for session in &self.sessions {
+            lines.push(format!(
+                "  - {} ({:?}, {} step(s), started {})",
+                session.blackboard, session.mode, session.steps, session.started_at
+            ));
+        }
+        lines.push(format!(
+            "Queued notifications: {} (held while degraded: {}, coalesced re-runs: {})",
+            self.queued_notifications, self.held_notifications, self.queued_reruns
+        ));
+        lines.push(format!("Scheduled jobs: {}", self.jobs.len()));
+        for job in &self.jobs {
+            lines.push(format!(
+                "  - {} next {}",
+                job.ritual,
+                job.next_fire.as_deref().unwrap_or("unknown")
+            ));
+        }
+        lines.push(format!(
+            "Last guardian pulse: {}",
+            self.last_pulse.as_deref().unwrap_or("none yet")
+        ));
+        lines.push(format!("Recent errors: {}", self.recent_errors.len()));
+        for error in &self.recent_errors {
+            lines.push(format!(
+                "  - [{}] {}: {}",
+                error.at, error.source, error.message
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Answer every connection to `<guild>/.tellar.sock` with one JSON snapshot. The caller
+/// holds the workspace lock, so a socket file left over is from a dead process.
+#[cfg(unix)]
+pub async fn serve(
+    base_path: std::path::PathBuf,
+    notif_tx: mpsc::Sender<StewardNotification>,
+) -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::io::AsyncWriteExt;
+
+    Lazy::force(&STARTED_AT);
+    let path = base_path.join(STATUS_SOCKET);
+    let _ = std::fs::remove_file(&path);
+    let listener = tokio::net::UnixListener::bind(&path)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let status = snapshot(&base_path, &notif_tx).await;
+        let body = serde_json::to_vec(&status)?;
+        if let Err(e) = stream.write_all(&body).await {
+            log_warn!("runtime", "⚠️ Failed to answer a status request: {}", e);
+        }
+    }
+}
+
+/// Ask the daemon serving `base_path` for its status.
+#[cfg(unix)]
+pub fn query(base_path: &Path) -> anyhow::Result<RuntimeStatus> {
+    use anyhow::Context;
+    use std::io::Read;
+
+    let path = base_path.join(STATUS_SOCKET);
+    let mut stream = std::os::unix::net::UnixStream::connect(&path).with_context(|| {
+        format!(
+            "no running Tellar answered on {}; is the service started?",
+            path.display()
+        )
+    })?;
+    stream.set_read_timeout(Some(std::time::Duration::from_secs(10)))?;
+    let mut body = String::new();
+    stream.read_to_string(&mut body)?;
+    serde_json::from_str(&body).context("unreadable status from the daemon")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_renders_sessions_jobs_and_errors() {
+        let status = RuntimeStatus {
+            pid: 42,
+            started_at: "2026-03-01 09:00:00".to_string(),
+            degraded: true,
+            sessions: vec![ActiveSession {
+                blackboard: "channels/general-1/2026-03-01.md".to_string(),
+                mode: crate::session_store::SessionMode::Conversation,
+                steps: 2,
+                started_at: "2026-03-01 09:05:00".to_string(),
+            }],
+            queued_notifications: 1,
+            held_notifications: 0,
+            queued_reruns: 0,
+            jobs: vec![ScheduledJob {
+                ritual: "rituals/backup.md".to_string(),
+                next_fire: Some("2026-03-02 03:00:00".to_string()),
+            }],
+            last_pulse: None,
+            recent_errors: vec![RecentError {
+                at: "2026-03-01 09:06:00".to_string(),
+                source: "backup".to_string(),
+                message: "Task failed: rsync".to_string(),
+            }],
+        };
+        let rendered = status.render();
+        assert!(
+            rendered
+                .starts_with("🔎 Tellar PID 42, running since 2026-03-01 09:00:00 (degraded mode)")
+        );
+        assert!(rendered.contains("  - channels/general-1/2026-03-01.md (Conversation, 2 step(s)"));
+        assert!(rendered.contains("  - rituals/backup.md next 2026-03-02 03:00:00"));
+        assert!(rendered.contains("Last guardian pulse: none yet"));
+        assert!(rendered.contains("  - [2026-03-01 09:06:00] backup: Task failed: rsync"));
+
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            serde_json::from_str::<RuntimeStatus>(&json).unwrap(),
+            status
+        );
+
+        for i in 0..25 {
+            record_error("test", &i.to_string());
+        }
+        let errors = RECENT_ERRORS.lock().unwrap();
+        assert_eq!(errors.len(), MAX_RECENT_ERRORS);
+        assert!(errors.iter().any(|error| error.message == "24"));
+        assert!(!errors.iter().any(|error| error.message == "0"));
+    }
+}
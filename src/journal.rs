@@ -0,0 +1,155 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/journal.rs
+ * Responsibility: Write-ahead journal for blackboard writes, replayed on startup so a crash never leaves a truncated log.
+ */
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+pub const JOURNAL_FILE: &str = "brain/journal/journal.jsonl";
+
+/// Serializes journaled writes, so the journal only ever holds the one in flight.
+static JOURNAL_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// The full new content of one blackboard, recorded before the blackboard is touched.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct JournalEntry {
+    /// Guild-relative path of the blackboard.
+    path: PathBuf,
+    content: String,
+}
+
+fn is_inside_guild(relative: &Path) -> bool {
+    relative
+        .components()
+        .all(|part| matches!(part, Component::Normal(_)))
+}
+
+fn write_synced(path: &Path, content: &str) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()
+}
+
+/// Replace `path` with `content`: the content is appended to the journal and flushed to
+/// disk first, then written in place, then the journal is cleared. Files outside the guild
+/// are written directly.
+pub fn write(base_path: &Path, path: &Path, content: &str) -> io::Result<()> {
+    let Some(relative) = path
+        .strip_prefix(base_path)
+        .ok()
+        .filter(|relative| is_inside_guild(relative))
+    else {
+        return fs::write(path, content);
+    };
+    let _guard = JOURNAL_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let journal_path = base_path.join(JOURNAL_FILE);
+    if let Some(parent) = journal_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut journal = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&journal_path)?;
+    let entry = JournalEntry {
+        path: relative.to_path_buf(),
+        content: content.to_string(),
+    };
+    writeln!(journal, "{}", serde_json::to_string(&entry)?)?;
+    journal.sync_data()?;
+
+    write_synced(path, content)?;
+    journal.set_len(0)?;
+    journal.sync_data()
+}
+
+/// [`write`] on the blocking pool, for async callers: the journal is synced to disk
+/// before and after the rewrite.
+pub async fn write_async(base_path: &Path, path: &Path, content: &str) -> io::Result<()> {
+    let (base, target, text) = (
+        base_path.to_path_buf(),
+        path.to_path_buf(),
+        content.to_string(),
+    );
+    tokio::task::spawn_blocking(move || write(&base, &target, &text))
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// Finish the writes a crash interrupted by rewriting each journaled blackboard in full,
+/// then clear the journal. A torn last line means the crash came before its blackboard
+/// was touched, so it is skipped. Returns the repaired paths.
+pub fn recover(base_path: &Path) -> io::Result<Vec<PathBuf>> {
+    let journal_path = base_path.join(JOURNAL_FILE);
+    let file = match File::open(&journal_path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let _guard = JOURNAL_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut repaired = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let Ok(entry) = serde_json::from_str::<JournalEntry>(&line?) else {
+            continue;
+        };
+        if !is_inside_guild(&entry.path) {
+            continue;
+        }
+        let target = base_path.join(&entry.path);
+        if fs::read_to_string(&target).ok().as_deref() == Some(entry.content.as_str()) {
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_synced(&target, &entry.content)?;
+        if !repaired.contains(&entry.path) {
+            repaired.push(entry.path);
+        }
+    }
+    File::create(&journal_path)?.sync_all()?;
+    Ok(repaired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_interrupted_writes_are_replayed() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let log = base.join("channels/general-123456/2026-03-01.md");
+        fs::create_dir_all(log.parent().unwrap()).unwrap();
+
+        write(base, &log, "# Log\nfirst\n").unwrap();
+        assert_eq!(fs::read_to_string(&log).unwrap(), "# Log\nfirst\n");
+        assert_eq!(fs::read_to_string(base.join(JOURNAL_FILE)).unwrap(), "");
+
+        // A crash after journaling but mid-write leaves a truncated log behind.
+        let entry = JournalEntry {
+            path: PathBuf::from("channels/general-123456/2026-03-01.md"),
+            content: "# Log\nfirst\nsecond\n".to_string(),
+        };
+        fs::write(
+            base.join(JOURNAL_FILE),
+            format!(
+                "{}\n{{\"path\":\"../../etc/passwd\",\"content\":\"x\"}}\n{{\"path\":\"chan",
+                serde_json::to_string(&entry).unwrap()
+            ),
+        )
+        .unwrap();
+        fs::write(&log, "# Log\nfir").unwrap();
+
+        assert_eq!(recover(base).unwrap(), vec![entry.path]);
+        assert_eq!(fs::read_to_string(&log).unwrap(), "# Log\nfirst\nsecond\n");
+        assert_eq!(fs::read_to_string(base.join(JOURNAL_FILE)).unwrap(), "");
+        assert!(recover(base).unwrap().is_empty());
+    }
+}
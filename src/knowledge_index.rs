@@ -0,0 +1,406 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/knowledge_index.rs
+ * Responsibility: Embedding index over knowledge files and archived threads for per-turn retrieval.
+ */
+
+use crate::config::Config;
+use crate::llm::{self, EmbeddingTask};
+use crate::log_warn;
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use rusqlite::{Connection, params};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+pub const INDEX_DIR: &str = "brain/index";
+const INDEX_FILE: &str = "knowledge.db";
+
+/// Serializes refreshes so concurrent turns do not embed the same file twice.
+static REFRESH_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// A stored chunk and its similarity to the query.
+#[derive(Debug, Clone)]
+pub struct ScoredChunk {
+    pub source: String,
+    pub text: String,
+    pub score: f32,
+}
+
+fn open_index(base_path: &Path) -> rusqlite::Result<Connection> {
+    let dir = base_path.join(INDEX_DIR);
+    let _ = fs::create_dir_all(&dir);
+    let connection = Connection::open(dir.join(INDEX_FILE))?;
+    connection.busy_timeout(Duration::from_secs(5))?;
+    connection.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sources (path TEXT PRIMARY KEY, digest TEXT NOT NULL);
+         CREATE TABLE IF NOT EXISTS chunks (
+             source TEXT NOT NULL,
+             position INTEGER NOT NULL,
+             text TEXT NOT NULL,
+             vector BLOB NOT NULL,
+             PRIMARY KEY (source, position)
+         );",
+    )?;
+    Ok(connection)
+}
+
+/// Guild-relative paths of every indexed file: `brain/KNOWLEDGE.md`, each channel's
+/// `KNOWLEDGE.md` and `SUMMARY.md`, and the archived threads under `channels/**/history/`.
+pub fn collect_sources(base_path: &Path) -> Vec<String> {
+    fn walk(base: &Path, dir: &Path, in_history: bool, out: &mut Vec<String>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if path.is_dir() {
+                walk(base, &path, in_history || name == "history", out);
+            } else if (name == "KNOWLEDGE.md"
+                || name == crate::channel_summary::SUMMARY_FILE
+                || (in_history && name.ends_with(".md")))
+                && let Ok(rel) = path.strip_prefix(base)
+            {
+                out.push(rel.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+
+    let mut sources = Vec::new();
+    if base_path.join("brain").join("KNOWLEDGE.md").is_file() {
+        sources.push("brain/KNOWLEDGE.md".to_string());
+    }
+    walk(base_path, &base_path.join("channels"), false, &mut sources);
+    sources.sort();
+    sources
+}
+
+/// Split text into chunks of at most about `max_chars`, breaking at blank lines and
+/// headings, and hard-wrapping paragraphs that are longer on their own.
+pub fn chunk_text(text: &str, max_chars: usize) -> Vec<String> {
+    let max_chars = max_chars.max(200);
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut flush = |current: &mut String| {
+        if !current.trim().is_empty() {
+            chunks.push(current.trim().to_string());
+        }
+        current.clear();
+    };
+
+    for block in text.split("\n\n").filter(|block| !block.trim().is_empty()) {
+        let starts_section = block.trim_start().starts_with('#');
+        if !current.is_empty()
+            && (starts_section || current.chars().count() + block.chars().count() > max_chars)
+        {
+            flush(&mut current);
+        }
+        if block.chars().count() > max_chars {
+            let chars: Vec<char> = block.chars().collect();
+            for piece in chars.chunks(max_chars) {
+                current.extend(piece);
+                flush(&mut current);
+            }
+            continue;
+        }
+        if !current.is_empty() {
+            current.push_str("\n\n");
+        }
+        current.push_str(block);
+    }
+    flush(&mut current);
+    chunks
+}
+
+fn digest(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm =
+        a.iter().map(|x| x * x).sum::<f32>().sqrt() * b.iter().map(|y| y * y).sum::<f32>().sqrt();
+    if norm == 0.0 { 0.0 } else { dot / norm }
+}
+
+/// The model that embeds for this guild; the offline mock model embeds offline too.
+fn embedding_model(config: &Config) -> &str {
+    if crate::mock_llm::is_mock_model(&config.gemini.model) {
+        crate::mock_llm::MOCK_MODEL
+    } else {
+        &config.knowledge_index.model
+    }
+}
+
+/// Re-embed sources whose content changed and drop ones that disappeared.
+/// Returns how many files were (re)indexed.
+pub async fn refresh(base_path: &Path, config: &Config) -> Result<usize> {
+    let _guard = REFRESH_LOCK.lock().await;
+
+    let stale: Vec<(String, String, Vec<String>)> = {
+        let connection = open_index(base_path)?;
+        let known: HashMap<String, String> = connection
+            .prepare("SELECT path, digest FROM sources")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        let sources = collect_sources(base_path);
+        for gone in known.keys().filter(|path| !sources.contains(path)) {
+            connection.execute("DELETE FROM chunks WHERE source = ?1", params![gone])?;
+            connection.execute("DELETE FROM sources WHERE path = ?1", params![gone])?;
+        }
+        sources
+            .into_iter()
+            .filter_map(|path| {
+                let content = fs::read_to_string(base_path.join(&path)).ok()?;
+                let digest = digest(&content);
+                (known.get(&path) != Some(&digest)).then(|| {
+                    let chunks = chunk_text(&content, config.knowledge_index.chunk_chars);
+                    (path, digest, chunks)
+                })
+            })
+            .collect()
+    };
+
+    for (path, digest, chunks) in &stale {
+        let vectors = llm::embed_texts(
+            chunks,
+            EmbeddingTask::Document,
+            &config.gemini.api_key,
+            embedding_model(config),
+        )
+        .await?;
+        let mut connection = open_index(base_path)?;
+        let tx = connection.transaction()?;
+        tx.execute("DELETE FROM chunks WHERE source = ?1", params![path])?;
+        for (position, (text, vector)) in chunks.iter().zip(&vectors).enumerate() {
+            tx.execute(
+                "INSERT INTO chunks (source, position, text, vector) VALUES (?1, ?2, ?3, ?4)",
+                params![path, position as i64, text, encode_vector(vector)],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO sources (path, digest) VALUES (?1, ?2)",
+            params![path, digest],
+        )?;
+        tx.commit()?;
+    }
+    Ok(stale.len())
+}
+
+/// Sources a channel may draw on: the guild brain and its own channel folder.
+fn visible_to(source: &str, channel_folder: Option<&str>) -> bool {
+    source.starts_with("brain/")
+        || channel_folder.is_some_and(|folder| {
+            source
+                .strip_prefix("channels/")
+                .and_then(|rest| rest.strip_prefix(folder))
+                .is_some_and(|rest| rest.starts_with('/'))
+        })
+}
+
+/// The `top_k` indexed chunks most similar to `query` among the visible sources.
+pub async fn search(
+    base_path: &Path,
+    config: &Config,
+    query: &str,
+    channel_folder: Option<&str>,
+) -> Result<Vec<ScoredChunk>> {
+    search_sources(
+        base_path,
+        config,
+        query,
+        |source| visible_to(source, channel_folder),
+        config.knowledge_index.top_k,
+    )
+    .await
+}
+
+/// The `limit` indexed chunks most similar to `query` among sources `include` accepts.
+pub async fn search_sources(
+    base_path: &Path,
+    config: &Config,
+    query: &str,
+    include: impl Fn(&str) -> bool,
+    limit: usize,
+) -> Result<Vec<ScoredChunk>> {
+    let query_vector = llm::embed_texts(
+        &[query.to_string()],
+        EmbeddingTask::Query,
+        &config.gemini.api_key,
+        embedding_model(config),
+    )
+    .await?
+    .pop()
+    .unwrap_or_default();
+
+    let connection = open_index(base_path)?;
+    let mut statement = connection.prepare("SELECT source, text, vector FROM chunks")?;
+    let rows = statement.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Vec<u8>>(2)?,
+        ))
+    })?;
+    let mut scored = Vec::new();
+    for row in rows {
+        let (source, text, vector) = row?;
+        if !include(&source) {
+            continue;
+        }
+        let score = cosine(&query_vector, &decode_vector(&vector));
+        if score >= config.knowledge_index.min_score {
+            scored.push(ScoredChunk {
+                source,
+                text,
+                score,
+            });
+        }
+    }
+    scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+    scored.truncate(limit);
+    Ok(scored)
+}
+
+/// Render retrieved chunks as a system-prompt section.
+pub fn render_chunks(chunks: &[ScoredChunk]) -> String {
+    let mut out = String::from("\n\n### Relevant Knowledge (retrieved from brain/index):\n");
+    for chunk in chunks {
+        out.push_str(&format!("\n[{}]\n{}\n", chunk.source, chunk.text));
+    }
+    out
+}
+
+/// Refresh the index and return the knowledge section to append to the system prompt
+/// for `query`, or an empty string when the index is disabled, empty, or unavailable.
+pub async fn relevant_knowledge(
+    base_path: &Path,
+    config: &Config,
+    channel_id: &str,
+    query: &str,
+) -> String {
+    if !config.knowledge_index.enabled || query.trim().is_empty() {
+        return String::new();
+    }
+    if let Err(e) = refresh(base_path, config).await {
+        log_warn!("steward", "⚠️ Knowledge index refresh failed: {}", e);
+    }
+    let folder = (channel_id != "0")
+        .then(|| crate::discord::resolve_folder_by_id(base_path, channel_id))
+        .flatten();
+    match search(base_path, config, query, folder.as_deref()).await {
+        Ok(chunks) if !chunks.is_empty() => render_chunks(&chunks),
+        Ok(_) => String::new(),
+        Err(e) => {
+            log_warn!("steward", "⚠️ Knowledge index search failed: {}", e);
+            String::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_chunk_text_splits_at_headings_and_size() {
+        let text = "# Boiler\n\nService every autumn.\n\n# Garden\n\nWater on Sundays.";
+        let chunks = chunk_text(text, 1000);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].contains("Service every autumn."));
+        assert!(chunks[1].starts_with("# Garden"));
+
+        let long = "word ".repeat(100);
+        let chunks = chunk_text(&long, 200);
+        assert!(chunks.len() >= 3);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 200));
+    }
+
+    #[tokio::test]
+    async fn test_index_retrieves_relevant_chunks_scoped_to_channel() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("brain")).unwrap();
+        fs::create_dir_all(base.join("channels/general-123456/history/2026-03-01")).unwrap();
+        fs::create_dir_all(base.join("channels/private-654321")).unwrap();
+        fs::write(
+            base.join("brain/KNOWLEDGE.md"),
+            "# Boiler\n\nThe boiler service company is Heatwise.\n\n# Garden\n\nThe roses need pruning in March.",
+        )
+        .unwrap();
+        fs::write(
+            base.join("channels/general-123456/history/2026-03-01/thread.md"),
+            "We agreed the boiler pressure should stay near 1.5 bar.",
+        )
+        .unwrap();
+        fs::write(
+            base.join("channels/private-654321/KNOWLEDGE.md"),
+            "Secret boiler notes.",
+        )
+        .unwrap();
+
+        let mut config = Config::default();
+        config.gemini.model = crate::mock_llm::MOCK_MODEL.to_string();
+        config.knowledge_index.enabled = true;
+        config.knowledge_index.min_score = 0.1;
+
+        assert_eq!(refresh(base, &config).await.unwrap(), 3);
+        assert_eq!(refresh(base, &config).await.unwrap(), 0);
+
+        let chunks = search(
+            base,
+            &config,
+            "who services the boiler",
+            Some("general-123456"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            chunks[0].text,
+            "# Boiler\n\nThe boiler service company is Heatwise."
+        );
+        assert!(chunks.iter().all(|chunk| !chunk.source.contains("private")));
+        assert!(
+            chunks
+                .iter()
+                .any(|chunk| chunk.source.ends_with("history/2026-03-01/thread.md"))
+        );
+
+        fs::remove_file(base.join("channels/private-654321/KNOWLEDGE.md")).unwrap();
+        refresh(base, &config).await.unwrap();
+        let chunks = search(base, &config, "boiler notes", Some("private-654321"))
+            .await
+            .unwrap();
+        assert!(
+            chunks
+                .iter()
+                .all(|chunk| chunk.source.starts_with("brain/"))
+        );
+    }
+}
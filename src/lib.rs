@@ -4,24 +4,98 @@
  * Responsibility: Shared library modules
  */
 
+pub mod alerts;
+pub mod api_health;
+pub mod approval;
+pub mod arg_schema;
+pub mod archive;
+pub mod artifacts;
+pub mod backup_check;
+pub mod bridge;
+pub mod calendar;
+pub mod channel_export;
+pub mod channel_folders;
+pub mod channel_settings;
+pub mod channel_summary;
+pub mod chat;
+pub mod child_env;
 pub mod config;
+pub mod context_cache;
+pub mod datetime;
 pub mod delivery;
+pub mod diff;
+pub mod digest;
 pub mod discord;
+pub mod distill;
+pub mod docker_compose;
+pub mod doctor;
+pub mod dry_run;
+pub mod email;
+pub mod entities;
+pub mod env_overrides;
+pub mod exec_jobs;
+pub mod exec_sandbox;
 pub mod execution_contract;
+pub mod feeds;
+pub mod fetch_url;
+pub mod file_info;
+pub mod file_ops;
+pub mod guardian;
+pub mod guardian_checks;
+pub mod health_report;
+pub mod history_retention;
+pub mod hygiene;
+pub mod i18n;
+pub mod image_ops;
 pub mod input;
+pub mod introspect;
+pub mod journal;
+pub mod knowledge_index;
+pub mod lifecycle;
 pub mod llm;
+pub mod manual_run;
+pub mod memory;
+pub mod memory_search;
+pub mod migrate;
+pub mod mock_llm;
+pub mod ntfy;
+pub mod output_transform;
+pub mod patch;
 pub mod plan_executor;
+pub mod profiles;
 pub mod prompt_context;
+pub mod prompt_test;
+pub mod python_skill;
+pub mod quiet_hours;
+pub mod reminders;
 pub mod rhythm;
+pub mod ritual_control;
+pub mod ritual_runs;
+pub mod ritual_scaffold;
 pub mod router;
 pub mod routing_catalog;
+pub mod runtime_log;
+pub mod scratch;
+pub mod secrets;
+pub mod self_update;
+pub mod self_writes;
 pub mod session;
+pub mod session_store;
+pub mod skill_audit;
+pub mod skill_registry;
 pub mod skills;
+pub mod sql;
 pub mod task_policy;
 pub mod task_response;
 pub mod thread;
 pub mod tools;
+pub mod trace_log;
+pub mod wasm_skill;
 pub mod watch;
+pub mod web_search;
+pub mod webhook;
+pub mod workspace_git;
+pub mod workspace_lock;
 
 use dirs::home_dir;
 use std::fs;
@@ -45,7 +119,7 @@ pub fn mirror_guild_structure(
 
         if !channel_path.exists() {
             let _ = fs::create_dir_all(&channel_path);
-            println!("📂 Synchronized new channel folder: #{}", name);
+            crate::log_info!("discord", "📂 Synchronized new channel folder: #{}", name);
         }
     }
     Ok(())
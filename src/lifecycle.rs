@@ -0,0 +1,235 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/lifecycle.rs
+ * Responsibility: Lifecycle events and the outbound webhooks that announce them.
+ */
+
+use crate::config::{Config, OutboundWebhook};
+use crate::log_warn;
+use chrono::Local;
+use once_cell::sync::Lazy;
+use serde_json::{Value, json};
+use std::time::Duration;
+
+static WEBHOOK_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("Failed to create webhook reqwest client")
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleEventKind {
+    RitualCompleted,
+    TaskFailed,
+    ThreadArchived,
+    GuardianAlarm,
+}
+
+impl LifecycleEventKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::RitualCompleted => "ritual_completed",
+            Self::TaskFailed => "task_failed",
+            Self::ThreadArchived => "thread_archived",
+            Self::GuardianAlarm => "guardian_alarm",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LifecycleEvent {
+    pub kind: LifecycleEventKind,
+    pub thread: String,
+    pub channel_id: String,
+    pub summary: String,
+    pub detail: String,
+}
+
+impl LifecycleEvent {
+    pub fn new(
+        kind: LifecycleEventKind,
+        thread: impl Into<String>,
+        channel_id: impl Into<String>,
+        summary: impl Into<String>,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            thread: thread.into(),
+            channel_id: channel_id.into(),
+            summary: summary.into(),
+            detail: detail.into(),
+        }
+    }
+
+    fn payload(&self) -> Value {
+        json!({
+            "event": self.kind.as_str(),
+            "thread": self.thread,
+            "channel_id": self.channel_id,
+            "summary": self.summary,
+            "detail": self.detail,
+            "timestamp": Local::now().to_rfc3339(),
+        })
+    }
+}
+
+fn subscribes_to(hook: &OutboundWebhook, kind: LifecycleEventKind) -> bool {
+    hook.events.is_empty() || hook.events.iter().any(|event| event == kind.as_str())
+}
+
+/// Build the request body and content type for one hook.
+fn render_body(hook: &OutboundWebhook, payload: &Value) -> (String, &'static str) {
+    match &hook.template {
+        Some(template) => match crate::webhook::render_json_payload_template(template, payload) {
+            Some(body) => (body, "application/json"),
+            None => (
+                crate::webhook::render_payload_template(template, payload),
+                "text/plain; charset=utf-8",
+            ),
+        },
+        None => (payload.to_string(), "application/json"),
+    }
+}
+
+async fn deliver(hook: &OutboundWebhook, payload: &Value) -> anyhow::Result<()> {
+    let (body, content_type) = render_body(hook, payload);
+    let mut request = WEBHOOK_CLIENT
+        .post(&hook.url)
+        .header("Content-Type", content_type)
+        .body(body);
+    for (name, value) in &hook.headers {
+        request = request.header(name, value);
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("HTTP {}", response.status()));
+    }
+    Ok(())
+}
+
+/// Fire every subscribed outbound webhook (and ntfy push) in the background; failures are only logged.
+pub fn emit(config: &Config, event: LifecycleEvent) {
+    if matches!(
+        event.kind,
+        LifecycleEventKind::TaskFailed | LifecycleEventKind::GuardianAlarm
+    ) {
+        crate::introspect::record_error(
+            &event.thread,
+            &crate::tools::mask_sensitive_data(
+                &format!("{}: {}", event.summary, event.detail),
+                config,
+            ),
+        );
+    }
+    if crate::dry_run::is_enabled() {
+        crate::dry_run::record("lifecycle event", event.kind.as_str(), event.payload());
+        return;
+    }
+    crate::ntfy::notify_lifecycle(config, &event);
+
+    let hooks: Vec<OutboundWebhook> = config
+        .outbound_webhooks
+        .iter()
+        .filter(|hook| subscribes_to(hook, event.kind))
+        .cloned()
+        .collect();
+    if hooks.is_empty() {
+        return;
+    }
+
+    let mut payload = event.payload();
+    for field in ["summary", "detail"] {
+        if let Some(text) = payload[field].as_str() {
+            payload[field] = json!(crate::tools::mask_sensitive_data(text, config));
+        }
+    }
+
+    tokio::spawn(async move {
+        for hook in hooks {
+            if let Err(error) = deliver(&hook, &payload).await {
+                log_warn!(
+                    "runtime",
+                    "⚠️ Outbound webhook {} failed for {}: {:?}",
+                    hook.url,
+                    event.kind.as_str(),
+                    error
+                );
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(events: &[&str], template: Option<&str>) -> OutboundWebhook {
+        OutboundWebhook {
+            url: "http://localhost/hook".to_string(),
+            events: events.iter().map(|event| event.to_string()).collect(),
+            template: template.map(str::to_string),
+            headers: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_subscribes_to_filters_by_event_name() {
+        assert!(subscribes_to(&hook(&[], None), LifecycleEventKind::TaskFailed));
+        assert!(subscribes_to(
+            &hook(&["task_failed"], None),
+            LifecycleEventKind::TaskFailed
+        ));
+        assert!(!subscribes_to(
+            &hook(&["thread_archived"], None),
+            LifecycleEventKind::TaskFailed
+        ));
+    }
+
+    #[test]
+    fn test_render_body_uses_template_and_detects_json() {
+        let event = LifecycleEvent::new(
+            LifecycleEventKind::ThreadArchived,
+            "rituals/backup.md",
+            "123",
+            "Thread archived",
+            "history/2026-03-04",
+        );
+        let payload = event.payload();
+
+        let (body, content_type) = render_body(&hook(&[], None), &payload);
+        assert_eq!(content_type, "application/json");
+        assert!(body.contains("\"event\":\"thread_archived\""));
+
+        let (body, content_type) =
+            render_body(&hook(&[], Some("{{summary}}: {{thread}}")), &payload);
+        assert_eq!(content_type, "text/plain; charset=utf-8");
+        assert_eq!(body, "Thread archived: rituals/backup.md");
+
+        let (_, content_type) =
+            render_body(&hook(&[], Some(r#"{"text": "{{summary}}"}"#)), &payload);
+        assert_eq!(content_type, "application/json");
+
+        let quoted = LifecycleEvent::new(
+            LifecycleEventKind::TaskFailed,
+            "rituals/backup.md",
+            "123",
+            "Task \"backup\" failed",
+            "line one\nline two",
+        )
+        .payload();
+        let (body, content_type) = render_body(
+            &hook(&[], Some(r#"{"text": "{{summary}}: {{detail}}"}"#)),
+            &quoted,
+        );
+        assert_eq!(content_type, "application/json");
+        let parsed: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            parsed["text"],
+            "Task \"backup\" failed: line one\nline two"
+        );
+    }
+}
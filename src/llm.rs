@@ -1,7 +1,10 @@
+use crate::log_warn;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 static POOLED_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
     reqwest::Client::builder()
@@ -10,6 +13,30 @@ static POOLED_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
         .expect("Failed to create pooled reqwest client")
 });
 
+tokio::task_local! {
+    static TOKEN_METER: Arc<AtomicU64>;
+}
+
+/// Run `future`, adding the tokens Gemini reports for every turn it generates to `meter`.
+pub async fn metered<F: std::future::Future>(meter: &Arc<AtomicU64>, future: F) -> F::Output {
+    TOKEN_METER.scope(Arc::clone(meter), future).await
+}
+
+/// Every token metered by this process since the Guardian last took the count.
+static TOKENS_SINCE_PULSE: AtomicU64 = AtomicU64::new(0);
+
+/// Tokens spent since the previous call, across all conversations and rituals.
+pub fn take_tokens_spent() -> u64 {
+    TOKENS_SINCE_PULSE.swap(0, Ordering::Relaxed)
+}
+
+fn meter_tokens(res_json: &serde_json::Value) {
+    if let Some(tokens) = res_json["usageMetadata"]["totalTokenCount"].as_u64() {
+        TOKENS_SINCE_PULSE.fetch_add(tokens, Ordering::Relaxed);
+        let _ = TOKEN_METER.try_with(|meter| meter.fetch_add(tokens, Ordering::Relaxed));
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum MessageRole {
@@ -179,22 +206,9 @@ impl MultimodalPart {
     }
 }
 
-/// Call Gemini API with full structured message history and native tool calling.
-pub async fn generate_turn(
-    system_prompt: &str,
-    history: Vec<Message>,
-    api_key: &str,
-    model: &str,
-    temperature: f32,
-    tools: Option<serde_json::Value>,
-) -> anyhow::Result<ModelTurn> {
-    let url = format!(
-        "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
-        model
-    );
-
+fn history_to_contents(history: Vec<Message>) -> Vec<serde_json::Value> {
     // Map MessageRole to Gemini roles
-    let contents: Vec<serde_json::Value> = history
+    history
         .into_iter()
         .map(|msg| {
             let gemini_role = match msg.role {
@@ -208,13 +222,23 @@ pub async fn generate_turn(
                 "parts": msg.parts
             })
         })
-        .collect();
+        .collect()
+}
 
+/// Call Gemini API with full structured message history and native tool calling.
+pub async fn generate_turn(
+    system_prompt: &str,
+    history: Vec<Message>,
+    api_key: &str,
+    model: &str,
+    temperature: f32,
+    tools: Option<serde_json::Value>,
+) -> anyhow::Result<ModelTurn> {
     let mut payload = json!({
         "systemInstruction": {
             "parts": [{ "text": system_prompt }]
         },
-        "contents": contents,
+        "contents": history_to_contents(history),
         "generationConfig": {
             "temperature": temperature
         }
@@ -224,6 +248,56 @@ pub async fn generate_turn(
         payload["tools"] = t;
     }
 
+    send_turn_request(payload, api_key, model).await
+}
+
+/// Same as `generate_turn`, but the system instruction comes from a Gemini cached content.
+pub async fn generate_cached_turn(
+    cached_content: &str,
+    history: Vec<Message>,
+    api_key: &str,
+    model: &str,
+    temperature: f32,
+    tools: Option<serde_json::Value>,
+) -> anyhow::Result<ModelTurn> {
+    let mut payload = json!({
+        "cachedContent": cached_content,
+        "contents": history_to_contents(history),
+        "generationConfig": {
+            "temperature": temperature
+        }
+    });
+
+    if let Some(t) = tools {
+        payload["tools"] = t;
+    }
+
+    send_turn_request(payload, api_key, model).await
+}
+
+async fn send_turn_request(
+    payload: serde_json::Value,
+    api_key: &str,
+    model: &str,
+) -> anyhow::Result<ModelTurn> {
+    if crate::mock_llm::is_mock_model(model) {
+        return Ok(crate::mock_llm::mock_turn(&payload));
+    }
+    let result = post_turn_request(payload, api_key, model).await;
+    crate::api_health::record(crate::api_health::Service::Llm, result.is_ok());
+    result
+}
+
+async fn post_turn_request(
+    payload: serde_json::Value,
+    api_key: &str,
+    model: &str,
+) -> anyhow::Result<ModelTurn> {
+    let url = format!(
+        "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
+        model
+    );
+
     let response = POOLED_CLIENT
         .post(url)
         .header("x-goog-api-key", api_key)
@@ -245,6 +319,7 @@ pub async fn generate_turn(
     }
 
     let res_json: serde_json::Value = response.json().await?;
+    meter_tokens(&res_json);
     let parts = &res_json["candidates"][0]["content"]["parts"];
 
     if parts.is_array() {
@@ -299,7 +374,10 @@ pub async fn generate_turn(
     }
 
     if let Some(recovered) = try_recover_malformed_function_call(&res_json) {
-        eprintln!("🟡 [LLM RECOVERY] Recovered malformed function call into a tool request.");
+        log_warn!(
+            "steward",
+            "🟡 [LLM RECOVERY] Recovered malformed function call into a tool request."
+        );
         return Ok(recovered);
     }
 
@@ -315,10 +393,246 @@ pub async fn generate_turn(
             reason, res_json
         )
     };
-    eprintln!("🔴 [LLM ERROR] {}", msg);
+    log_warn!("steward", "🔴 [LLM ERROR] {}", msg);
     Err(anyhow::anyhow!(msg))
 }
 
+/// Handle for a Gemini cached content resource (`cachedContents/...`).
+#[derive(Debug, Clone)]
+pub struct CachedContent {
+    pub name: String,
+}
+
+/// Store `system_prompt` as a Gemini cached content that expires after `ttl_secs`.
+pub async fn create_cached_content(
+    system_prompt: &str,
+    api_key: &str,
+    model: &str,
+    ttl_secs: u64,
+) -> anyhow::Result<CachedContent> {
+    if crate::mock_llm::is_mock_model(model) {
+        return Err(anyhow::anyhow!("context caching is unavailable for the mock model"));
+    }
+
+    let url = "https://generativelanguage.googleapis.com/v1beta/cachedContents";
+    let payload = json!({
+        "model": format!("models/{}", model),
+        "systemInstruction": {
+            "parts": [{ "text": system_prompt }]
+        },
+        "ttl": format!("{}s", ttl_secs)
+    });
+
+    let response = POOLED_CLIENT
+        .post(url)
+        .header("x-goog-api-key", api_key)
+        .header("X-Goog-Api-Client", "Tellar/0.1")
+        .json(&payload)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let error_text = response.text().await?;
+        return Err(anyhow::anyhow!(
+            "Gemini Cache Error (Model: {}): {}",
+            model,
+            error_text
+        ));
+    }
+
+    let res_json: serde_json::Value = response.json().await?;
+    let name = res_json["name"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Gemini cache response has no name: {}", res_json))?;
+
+    Ok(CachedContent {
+        name: name.to_string(),
+    })
+}
+
+/// Delete a cached content early instead of waiting for its TTL.
+pub async fn delete_cached_content(name: &str, api_key: &str) -> anyhow::Result<()> {
+    let url = format!("https://generativelanguage.googleapis.com/v1beta/{}", name);
+    let response = POOLED_CLIENT
+        .delete(url)
+        .header("x-goog-api-key", api_key)
+        .header("X-Goog-Api-Client", "Tellar/0.1")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let error_text = response.text().await?;
+        return Err(anyhow::anyhow!("Gemini Cache Error: {}", error_text));
+    }
+    Ok(())
+}
+
+/// How an embedding will be used; Gemini embeds documents and queries differently.
+#[derive(Debug, Clone, Copy)]
+pub enum EmbeddingTask {
+    Document,
+    Query,
+}
+
+impl EmbeddingTask {
+    fn as_api(self) -> &'static str {
+        match self {
+            Self::Document => "RETRIEVAL_DOCUMENT",
+            Self::Query => "RETRIEVAL_QUERY",
+        }
+    }
+}
+
+/// Gemini accepts at most this many texts per `batchEmbedContents` call.
+const MAX_EMBED_BATCH: usize = 100;
+
+/// Embed each text with a Gemini embedding model, in order.
+pub async fn embed_texts(
+    texts: &[String],
+    task: EmbeddingTask,
+    api_key: &str,
+    model: &str,
+) -> anyhow::Result<Vec<Vec<f32>>> {
+    if crate::mock_llm::is_mock_model(model) {
+        return Ok(texts
+            .iter()
+            .map(|text| crate::mock_llm::mock_embedding(text))
+            .collect());
+    }
+
+    let url = format!(
+        "https://generativelanguage.googleapis.com/v1beta/models/{}:batchEmbedContents",
+        model
+    );
+    let mut vectors = Vec::with_capacity(texts.len());
+    for batch in texts.chunks(MAX_EMBED_BATCH) {
+        let requests: Vec<serde_json::Value> = batch
+            .iter()
+            .map(|text| {
+                json!({
+                    "model": format!("models/{}", model),
+                    "content": { "parts": [{ "text": text }] },
+                    "taskType": task.as_api()
+                })
+            })
+            .collect();
+        let response = POOLED_CLIENT
+            .post(&url)
+            .header("x-goog-api-key", api_key)
+            .header("X-Goog-Api-Client", "Tellar/0.1")
+            .json(&json!({ "requests": requests }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            return Err(anyhow::anyhow!(
+                "Gemini Embedding Error (Model: {}): {}",
+                model,
+                error_text
+            ));
+        }
+
+        let res_json: serde_json::Value = response.json().await?;
+        let embeddings = res_json["embeddings"]
+            .as_array()
+            .filter(|embeddings| embeddings.len() == batch.len())
+            .ok_or_else(|| anyhow::anyhow!("unexpected embedding response: {}", res_json))?;
+        for embedding in embeddings {
+            vectors.push(
+                embedding["values"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|value| value.as_f64().map(|value| value as f32))
+                    .collect(),
+            );
+        }
+    }
+    Ok(vectors)
+}
+
+/// Raw audio returned by a Gemini speech-generation model.
+#[derive(Debug, Clone)]
+pub struct SpeechAudio {
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+impl SpeechAudio {
+    /// Sample rate advertised in the mime type, e.g. `audio/L16;codec=pcm;rate=24000`.
+    pub fn sample_rate(&self) -> u32 {
+        parse_pcm_sample_rate(&self.mime_type).unwrap_or(24_000)
+    }
+}
+
+fn parse_pcm_sample_rate(mime_type: &str) -> Option<u32> {
+    mime_type
+        .split(';')
+        .filter_map(|segment| segment.trim().strip_prefix("rate="))
+        .find_map(|rate| rate.trim().parse::<u32>().ok())
+}
+
+/// Synthesize speech for `text` with a Gemini TTS model and prebuilt voice.
+pub async fn synthesize_speech(
+    text: &str,
+    api_key: &str,
+    model: &str,
+    voice: &str,
+) -> anyhow::Result<SpeechAudio> {
+    use base64::{Engine as _, engine::general_purpose};
+
+    let url = format!(
+        "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
+        model
+    );
+    let payload = json!({
+        "contents": [{ "parts": [{ "text": text }] }],
+        "generationConfig": {
+            "responseModalities": ["AUDIO"],
+            "speechConfig": {
+                "voiceConfig": {
+                    "prebuiltVoiceConfig": { "voiceName": voice }
+                }
+            }
+        }
+    });
+
+    let response = POOLED_CLIENT
+        .post(url)
+        .header("x-goog-api-key", api_key)
+        .header("X-Goog-Api-Client", "Tellar/0.1")
+        .json(&payload)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let error_text = response.text().await?;
+        return Err(anyhow::anyhow!(
+            "Gemini TTS Error (Model: {}): {}",
+            model,
+            error_text
+        ));
+    }
+
+    let res_json: serde_json::Value = response.json().await?;
+    let inline = res_json["candidates"][0]["content"]["parts"]
+        .as_array()
+        .and_then(|parts| parts.iter().find_map(|part| part.get("inlineData")))
+        .ok_or_else(|| anyhow::anyhow!("Gemini TTS returned no audio: {}", res_json))?;
+
+    let mime_type = inline["mimeType"]
+        .as_str()
+        .unwrap_or("audio/L16;codec=pcm;rate=24000")
+        .to_string();
+    let data = general_purpose::STANDARD.decode(inline["data"].as_str().unwrap_or_default())?;
+    if data.is_empty() {
+        return Err(anyhow::anyhow!("Gemini TTS returned an empty audio payload"));
+    }
+
+    Ok(SpeechAudio { mime_type, data })
+}
+
 /// Fetch available models from Gemini API
 pub async fn list_models(api_key: &str) -> anyhow::Result<Vec<String>> {
     let url = "https://generativelanguage.googleapis.com/v1beta/models";
@@ -428,6 +742,15 @@ mod tests {
         assert_eq!(serialized["functionResponse"]["id"], "tool-9");
     }
 
+    #[test]
+    fn test_parse_pcm_sample_rate_reads_rate_parameter() {
+        assert_eq!(
+            parse_pcm_sample_rate("audio/L16;codec=pcm;rate=24000"),
+            Some(24000)
+        );
+        assert_eq!(parse_pcm_sample_rate("audio/L16"), None);
+    }
+
     #[test]
     fn test_parse_pseudo_function_call_recovers_simple_args() {
         let recovered = parse_pseudo_function_call(r#"call:ls{path:"skills"}"#).unwrap();
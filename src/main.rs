@@ -4,8 +4,9 @@ use tellar::watch;
 
 use tellar::StewardNotification;
 use tellar::config::Config;
+use tellar::{log_error, log_info, log_warn};
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -17,11 +18,29 @@ struct Cli {
     /// 盟友会馆 (Guild) 目录 (默认: ~/.tellar)
     #[arg(short, long, global = true)]
     guild: Option<PathBuf>,
+
+    /// Named profile from tellar.yml's `profiles:` (default: $TELLAR_PROFILE)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// Call the model as usual but only log writes, commands, skills, and Discord posts
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Chat with the Steward from the terminal, without Discord
+    Chat,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    println!(
+    log_info!(
+        "runtime",
         r#"
     __________  ____    __    ___    ____ 
    /_  __/ __ \/ / /   /   |  / __ \/ __ \
@@ -36,42 +55,94 @@ async fn main() -> anyhow::Result<()> {
 
     // 1. Strict check: Guild must exist (no auto-init)
     if !guild_path.exists() {
-        eprintln!("❌ Guild directory not found at: {:?}", guild_path);
-        eprintln!("💡 Please run 'tellarctl setup' first to initialize your Cyber Steward.");
+        log_error!("runtime", "❌ Guild directory not found at: {:?}", guild_path);
+        log_warn!(
+            "runtime",
+            "💡 Please run 'tellarctl setup' first to initialize your Cyber Steward."
+        );
         std::process::exit(1);
     }
 
     // 2. Load configuration
     let config_file = guild_path.join("tellar.yml");
-    if !config_file.exists() {
-        eprintln!("❌ Configuration file not found at: {:?}", config_file);
-        eprintln!("💡 Please run 'tellarctl setup' to configure your API keys.");
+    if !config_file.exists() && !Config::has_env_overrides() {
+        log_error!("runtime", "❌ Configuration file not found at: {:?}", config_file);
+        log_warn!(
+            "runtime",
+            "💡 Please run 'tellarctl setup' to configure your API keys, or set TELLAR__ variables."
+        );
         std::process::exit(1);
     }
-    let config = Arc::new(Config::load(&config_file)?);
+    let profile = tellar::profiles::resolve(args.profile.as_deref());
+    let config = Arc::new(Config::load_profile(&config_file, profile.as_deref())?);
+    tellar::i18n::set_language(config.runtime.language);
+    if let Some(profile) = &profile {
+        log_info!("runtime", "🎚️ Profile: {}", profile);
+    }
+    if args.dry_run {
+        tellar::dry_run::enable(&guild_path);
+        log_info!(
+            "runtime",
+            "🧪 Dry run: side effects are logged to {} instead of performed.",
+            tellar::dry_run::DRY_RUN_LOG
+        );
+    }
 
-    // 3. Start Steward
-    println!("🌳 Guild: {}", guild_path.display());
-    println!("🕯️  Waking up the Cyber Steward...");
-    println!("Guild foundation: {:?}", guild_path);
-    println!("📖 Configuration loaded successfully!");
+    if let Some(Command::Chat) = args.command {
+        return tellar::chat::run_chat_repl(&guild_path, config).await;
+    }
 
-    // 4. Mirror Guild structure
-    let shared_mappings = Arc::new(RwLock::new(HashMap::new()));
-    if let Some(guild_id) = &config.discord.guild_id {
-        println!("🔍 Discovering channels for Guild: {}...", guild_id);
-        match discord::fetch_guild_channels(&config.discord.token, guild_id).await {
-            Ok(channels) => {
-                tellar::mirror_guild_structure(&guild_path, &channels)?;
-                let mut map = shared_mappings.write().await;
-                for (id, name) in channels {
-                    map.insert(id, name.clone());
-                }
+    // Refuse to share a guild with another running instance.
+    let _workspace_lock = match tellar::workspace_lock::WorkspaceLock::acquire(&guild_path) {
+        Ok(lock) => lock,
+        Err(e) => {
+            log_error!("runtime", "❌ Workspace is locked: {}", e);
+            std::process::exit(1);
+        }
+    };
+    tellar::runtime_log::init(&guild_path);
+
+    match tellar::journal::recover(&guild_path) {
+        Ok(repaired) => {
+            for path in repaired {
+                log_info!("runtime", "🩹 Repaired interrupted write to {}", path.display());
             }
-            Err(e) => eprintln!("⚠️ Guild discovery failed: {:?}", e),
         }
+        Err(e) => log_warn!("runtime", "⚠️ Failed to replay the blackboard journal: {}", e),
     }
+    match tellar::migrate::detect_version(&guild_path) {
+        Ok(version) if version < tellar::migrate::CURRENT_VERSION => log_warn!(
+            "runtime",
+            "⚠️ Workspace format {} is older than {}; run `tellarctl migrate` to upgrade it.",
+            version,
+            tellar::migrate::CURRENT_VERSION
+        ),
+        Ok(version) if version > tellar::migrate::CURRENT_VERSION => log_warn!(
+            "runtime",
+            "⚠️ Workspace format {} is newer than this Tellar understands ({}); update Tellar.",
+            version,
+            tellar::migrate::CURRENT_VERSION
+        ),
+        Ok(_) => {}
+        Err(e) => log_warn!("runtime", "⚠️ Unreadable workspace format version: {}", e),
+    }
+
+    // 3. Start Steward
+    log_info!("runtime", "🌳 Guild: {}", guild_path.display());
+    log_info!("runtime", "🕯️  Waking up the Cyber Steward...");
+    log_info!("runtime", "Guild foundation: {:?}", guild_path);
+    log_info!("runtime", "📖 Configuration loaded successfully!");
 
+    if config.git.enabled {
+        if let Err(e) = tellar::workspace_git::ensure_repo(&guild_path) {
+            log_warn!("runtime", "⚠️ Git history disabled for this run: {:?}", e);
+        } else if let Err(e) = tellar::workspace_git::untrack_runtime_paths(&guild_path) {
+            log_warn!("runtime", "⚠️ Failed to untrack runtime logs: {:?}", e);
+        }
+    }
+
+    // 4. Mirror Guild structure
+    let shared_mappings = Arc::new(RwLock::new(HashMap::new()));
     if let Some(manual) = &config.discord.channel_mappings {
         let mut map = shared_mappings.write().await;
         for (id, folder) in manual {
@@ -79,9 +150,44 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    if let Some(guild_id) = &config.discord.guild_id {
+        log_info!("runtime", "🔍 Discovering channels for Guild: {}...", guild_id);
+        if let Err(e) = discord::discovery::refresh_guild_channels(
+            &config.discord.token,
+            guild_id,
+            &guild_path,
+            &shared_mappings,
+        )
+        .await
+        {
+            log_warn!("runtime", "⚠️ Guild discovery failed: {:?}", e);
+        }
+
+        if config.discord.discovery_refresh_secs > 0 {
+            tokio::spawn(discord::discovery::start_discovery_refresh(
+                config.discord.token.clone(),
+                guild_id.clone(),
+                guild_path.clone(),
+                shared_mappings.clone(),
+                config.discord.discovery_refresh_secs,
+            ));
+        }
+    }
+
     // 5. [Perception Layer] Start Discord Inscriber
     let (notif_tx, notif_rx) = tokio::sync::mpsc::channel::<StewardNotification>(100);
 
+    #[cfg(unix)]
+    {
+        let guild_status = guild_path.clone();
+        let notif_tx_status = notif_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = tellar::introspect::serve(guild_status, notif_tx_status).await {
+                log_warn!("runtime", "⚠️ Status socket exited abnormally: {:?}", e);
+            }
+        });
+    }
+
     let config_discord = Arc::clone(&config);
     let guild_discord = guild_path.clone();
     let mappings_listener = shared_mappings.clone();
@@ -93,18 +199,82 @@ async fn main() -> anyhow::Result<()> {
             guild_discord,
             mappings_listener,
             notif_tx_discord,
+            config_discord.discord.admin_channel_id.clone(),
+            config_discord.discord.guild_id.clone(),
         )
         .await
         {
-            eprintln!("⚠️ Discord inscriber exited abnormally: {:?}", e);
+            log_warn!("runtime", "⚠️ Discord inscriber exited abnormally: {:?}", e);
         }
     });
 
+    // 5b. [Perception Layer] Start Email Inscriber (optional)
+    if config.email.enabled {
+        let config_email = Arc::clone(&config);
+        let guild_email = guild_path.clone();
+        tokio::spawn(async move {
+            if let Err(e) = tellar::email::start_email_inscriber(guild_email, config_email).await {
+                log_warn!("runtime", "⚠️ Email inscriber exited abnormally: {:?}", e);
+            }
+        });
+    }
+
+    // 5c. [Perception Layer] Start Inbound Webhook Endpoint (optional)
+    if config.inbound_webhook.enabled {
+        let config_webhook = Arc::clone(&config);
+        let guild_webhook = guild_path.clone();
+        let notif_tx_webhook = notif_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) =
+                tellar::webhook::start_webhook_server(guild_webhook, config_webhook, notif_tx_webhook)
+                    .await
+            {
+                log_warn!("runtime", "⚠️ Inbound webhook server exited abnormally: {:?}", e);
+            }
+        });
+    }
+
+    // 5d. [Perception Layer] Start ntfy Reply Listener (optional)
+    if config.ntfy.enabled && config.ntfy.reply_topic.is_some() {
+        let config_ntfy = Arc::clone(&config);
+        let guild_ntfy = guild_path.clone();
+        let notif_tx_ntfy = notif_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) =
+                tellar::ntfy::start_reply_listener(guild_ntfy, config_ntfy, notif_tx_ntfy).await
+            {
+                log_warn!("runtime", "⚠️ ntfy reply listener exited abnormally: {:?}", e);
+            }
+        });
+    }
+
+    // 5e. [Perception Layer] Start Messaging Bridges (optional)
+    if !config.bridges.is_empty() {
+        tellar::bridge::start_bridges(guild_path.clone(), Arc::clone(&config));
+    }
+
+    // 5f. [Perception Layer] Start RSS/Atom Feed Watcher (optional)
+    if config.feeds.enabled && !config.feeds.sources.is_empty() {
+        tokio::spawn(tellar::feeds::start_feed_watcher(
+            guild_path.clone(),
+            Arc::clone(&config),
+        ));
+    }
+
+    // 5g. [Perception Layer] Start ICS/CalDAV Calendar Watcher (optional)
+    if config.calendars.enabled && !config.calendars.sources.is_empty() {
+        tokio::spawn(tellar::calendar::start_calendar_watcher(
+            guild_path.clone(),
+            Arc::clone(&config),
+        ));
+    }
+
     // 6. [Rhythm Layer] Start the Heartbeat of Persistent Intent
     let guild_rhythm = guild_path.clone();
+    let config_rhythm = Arc::clone(&config);
     tokio::spawn(async move {
-        if let Err(e) = rhythm::run_rhythm(&guild_rhythm).await {
-            eprintln!("⚠️ Rhythm engine exited abnormally: {:?}", e);
+        if let Err(e) = rhythm::run_rhythm(&guild_rhythm, config_rhythm).await {
+            log_warn!("runtime", "⚠️ Rhythm engine exited abnormally: {:?}", e);
         }
     });
 
@@ -112,7 +282,14 @@ async fn main() -> anyhow::Result<()> {
     if let Err(e) =
         discord::sync_all_discord_events(&guild_path, Some(shared_mappings.clone())).await
     {
-        eprintln!("⚠️ Initial Discord event sync failed: {:?}", e);
+        log_warn!("runtime", "⚠️ Initial Discord event sync failed: {:?}", e);
+    }
+
+    // Resume or close sessions a previous run left in flight
+    for notification in
+        tellar::session_store::recover_interrupted_sessions(&guild_path, &config).await
+    {
+        let _ = notif_tx.send(notification).await;
     }
 
     // 7. [Orchestration Layer] Mount The Watchman
@@ -131,7 +308,7 @@ async fn main() -> anyhow::Result<()> {
     )
     .await
     {
-        eprintln!("⚠️ The Watchman has fallen: {:?}", e);
+        log_warn!("runtime", "⚠️ The Watchman has fallen: {:?}", e);
     }
 
     Ok(())
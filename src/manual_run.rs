@@ -0,0 +1,120 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/manual_run.rs
+ * Responsibility: Run a thread or ritual file on demand (`tellarctl run`, `POST /run`), bypassing the Watchman.
+ */
+
+use crate::config::Config;
+use crate::log_info;
+use crate::thread::doc::body_start;
+use anyhow::{Result, anyhow, bail};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Resolve a file given relative to the guild (or absolute) to a `.md` blackboard under
+/// `channels/` or `rituals/`.
+pub fn resolve_target(base_path: &Path, file: &Path) -> Result<PathBuf> {
+    let base = fs::canonicalize(base_path)
+        .map_err(|e| anyhow!("Guild {} is not readable: {}", base_path.display(), e))?;
+    let candidate = if file.is_absolute() {
+        file.to_path_buf()
+    } else {
+        base.join(file)
+    };
+    let target = fs::canonicalize(&candidate)
+        .map_err(|_| anyhow!("No such blackboard: {}", file.display()))?;
+    let Ok(relative) = target.strip_prefix(&base) else {
+        bail!("{} is outside the guild", file.display());
+    };
+    if !(relative.starts_with("channels") || relative.starts_with("rituals"))
+        || target.extension().and_then(|ext| ext.to_str()) != Some("md")
+        || !target.is_file()
+    {
+        bail!(
+            "{} is not a thread or ritual: expected a .md file under channels/ or rituals/",
+            file.display()
+        );
+    }
+    Ok(target)
+}
+
+/// Reopen steps that timed out (`- [!]`) so the run picks them up again. Returns how many.
+pub async fn reopen_failed_steps(base_path: &Path, path: &Path) -> Result<usize> {
+    let content = fs::read_to_string(path)?;
+    let body = body_start(&content);
+    let reopened = content[body..].matches("- [!]").count();
+    if reopened > 0 {
+        let updated = format!(
+            "{}{}",
+            &content[..body],
+            content[body..].replace("- [!]", "- [ ]")
+        );
+        crate::journal::write_async(base_path, path, &updated).await?;
+    }
+    Ok(reopened)
+}
+
+/// Execute `file` now, reopening timed-out steps first when `retry` is set. Failed steps
+/// stay unchecked anyway, so a plain run retries them too.
+pub async fn run_now(
+    base_path: &Path,
+    config: Arc<Config>,
+    file: &Path,
+    retry: bool,
+) -> Result<PathBuf> {
+    let target = resolve_target(base_path, file)?;
+    if retry {
+        let reopened = reopen_failed_steps(base_path, &target).await?;
+        if reopened > 0 {
+            log_info!(
+                "steward",
+                "🔁 Reopened {} timed-out step(s) in {:?}",
+                reopened,
+                file
+            );
+        }
+    }
+    log_info!("steward", "▶️ Manually running {:?}", file);
+    crate::thread::execute_thread_file(&target, base_path, config, None, None, None).await?;
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_targets_are_guild_blackboards_and_retry_reopens_timeouts() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("rituals")).unwrap();
+        fs::create_dir_all(base.join("brain")).unwrap();
+        let ritual = base.join("rituals/backup.md");
+        fs::write(
+            &ritual,
+            "---\ninjection_template: \"- [!] kept\"\n---\n- [x] pack\n- [!] upload\n- [ ] verify\n",
+        )
+        .unwrap();
+        fs::write(base.join("brain/KNOWLEDGE.md"), "").unwrap();
+
+        let resolved = resolve_target(base, Path::new("rituals/backup.md")).unwrap();
+        assert_eq!(resolved, fs::canonicalize(&ritual).unwrap());
+        assert_eq!(resolve_target(base, &ritual).unwrap(), resolved);
+        for bad in [
+            "rituals/missing.md",
+            "brain/KNOWLEDGE.md",
+            "rituals/../../etc",
+        ] {
+            assert!(resolve_target(base, Path::new(bad)).is_err(), "{}", bad);
+        }
+
+        assert_eq!(reopen_failed_steps(base, &ritual).await.unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(&ritual).unwrap(),
+            "---\ninjection_template: \"- [!] kept\"\n---\n- [x] pack\n- [ ] upload\n- [ ] verify\n"
+        );
+        assert_eq!(reopen_failed_steps(base, &ritual).await.unwrap(), 0);
+    }
+}
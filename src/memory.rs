@@ -0,0 +1,601 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/memory.rs
+ * Responsibility: `remember` / `forget` tools keeping structured facts in KNOWLEDGE.md files.
+ */
+
+use crate::tools::ToolExecutionResult;
+use chrono::{Local, NaiveDate, NaiveDateTime};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub const REMEMBER_TOOL_NAME: &str = "remember";
+pub const FORGET_TOOL_NAME: &str = "forget";
+const DEFAULT_SECTION: &str = "Remembered";
+
+/// `- fact <!-- memory: id=...; added=...; by=...; msg=...; expires=...; supersedes=... -->`
+static ENTRY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^- (.*?) <!-- memory: (.*?) -->\s*$").expect("valid memory entry regex")
+});
+
+/// Concurrent turns must not lose each other's edits.
+pub(crate) static MEMORY_LOCK: Mutex<()> = Mutex::new(());
+
+/// One remembered fact and its provenance, parsed from a KNOWLEDGE.md line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryEntry {
+    pub fact: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl MemoryEntry {
+    pub fn parse(line: &str) -> Option<Self> {
+        let caps = ENTRY_RE.captures(line.trim_end())?;
+        let fields = caps[2]
+            .split("; ")
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect();
+        Some(Self {
+            fact: caps[1].to_string(),
+            fields,
+        })
+    }
+
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn render(&self) -> String {
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        format!("- {} <!-- memory: {} -->", self.fact, fields.join("; "))
+    }
+
+    /// When the fact stops being true, from `expires=YYYY-MM-DD[ HH:MM]`.
+    pub fn expires_at(&self) -> Option<NaiveDateTime> {
+        parse_expiry(self.field("expires")?)
+    }
+}
+
+/// A date expires at the end of that day; a date and time expires at that minute.
+fn parse_expiry(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(23, 59, 59))
+        })
+}
+
+/// Keep metadata values on one line and free of the separators.
+pub(crate) fn clean_value(value: &str) -> String {
+    value
+        .replace(['\n', '\r'], " ")
+        .replace("; ", ", ")
+        .replace("-->", "->")
+        .trim()
+        .to_string()
+}
+
+pub(crate) fn new_entry_id() -> String {
+    let uuid = uuid::Uuid::new_v4().simple().to_string();
+    format!("m{}", &uuid[..8])
+}
+
+/// KNOWLEDGE.md for `scope`: the guild brain, or the calling channel's folder.
+fn knowledge_path(base_path: &Path, scope: &str, channel_id: &str) -> Result<PathBuf, String> {
+    match scope {
+        "guild" => Ok(base_path.join("brain").join("KNOWLEDGE.md")),
+        "channel" => (channel_id != "0")
+            .then(|| crate::discord::resolve_folder_by_id(base_path, channel_id))
+            .flatten()
+            .map(|folder| base_path.join("channels").join(folder).join("KNOWLEDGE.md"))
+            .ok_or_else(|| "this session has no channel folder; use scope=guild".to_string()),
+        other => Err(format!("unknown scope `{}`; use guild or channel", other)),
+    }
+}
+
+fn scope_arg<'a>(args: &'a Value, channel_id: &str) -> &'a str {
+    args.get("scope")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|scope| !scope.is_empty())
+        .unwrap_or(if channel_id == "0" {
+            "guild"
+        } else {
+            "channel"
+        })
+}
+
+fn relative(base_path: &Path, path: &Path) -> String {
+    path.strip_prefix(base_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Insert `line` at the end of the `## heading` section, creating the section if needed.
+pub(crate) fn insert_under_heading(content: &str, heading: &str, line: &str) -> String {
+    let marker = format!("## {}", heading);
+    let mut lines: Vec<&str> = content.lines().collect();
+    let Some(start) = lines.iter().position(|l| l.trim() == marker) else {
+        let mut next = content.trim_end().to_string();
+        if !next.is_empty() {
+            next.push_str("\n\n");
+        }
+        next.push_str(&format!("{}\n\n{}\n", marker, line));
+        return next;
+    };
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.starts_with("## ") || l.starts_with("# "))
+        .map(|offset| start + 1 + offset)
+        .unwrap_or(lines.len());
+    let mut insert_at = end;
+    while insert_at > start + 1 && lines[insert_at - 1].trim().is_empty() {
+        insert_at -= 1;
+    }
+    lines.insert(insert_at, line);
+    if insert_at == start + 1 {
+        lines.insert(insert_at, "");
+    }
+    let mut next = lines.join("\n");
+    next.push('\n');
+    next
+}
+
+fn string_arg<'a>(args: &'a Value, field: &str) -> Option<&'a str> {
+    args.get(field)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn run_remember(args: &Value, base_path: &Path, channel_id: &str) -> ToolExecutionResult {
+    let Some(fact) = string_arg(args, "fact") else {
+        return ToolExecutionResult::error("Error: Missing required argument `fact`.");
+    };
+    let path = match knowledge_path(base_path, scope_arg(args, channel_id), channel_id) {
+        Ok(path) => path,
+        Err(error) => return ToolExecutionResult::error(format!("Error: {}", error)),
+    };
+    let section = string_arg(args, "section").unwrap_or(DEFAULT_SECTION);
+
+    let id = new_entry_id();
+    let mut fields = vec![
+        ("id".to_string(), id.clone()),
+        (
+            "added".to_string(),
+            Local::now().format("%Y-%m-%d %H:%M").to_string(),
+        ),
+    ];
+    if let Some(by) = string_arg(args, "by") {
+        fields.push(("by".to_string(), clean_value(by)));
+    }
+    if channel_id != "0" {
+        fields.push(("channel".to_string(), channel_id.to_string()));
+    }
+    if let Some(message_id) = string_arg(args, "message_id") {
+        fields.push(("msg".to_string(), clean_value(message_id)));
+    }
+    if let Some(expires) = string_arg(args, "expires") {
+        if parse_expiry(expires).is_none() {
+            return ToolExecutionResult::error(
+                "Error: `expires` must be YYYY-MM-DD or YYYY-MM-DD HH:MM.",
+            );
+        }
+        fields.push(("expires".to_string(), clean_value(expires)));
+    }
+    if let Some(supersedes) = string_arg(args, "supersedes") {
+        fields.push(("supersedes".to_string(), clean_value(supersedes)));
+    }
+    let entry = MemoryEntry {
+        fact: clean_value(fact),
+        fields,
+    };
+
+    let _guard = MEMORY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    if content
+        .lines()
+        .filter_map(MemoryEntry::parse)
+        .any(|existing| existing.fact.eq_ignore_ascii_case(&entry.fact))
+    {
+        return ToolExecutionResult::success(format!(
+            "Already remembered in {}: {}",
+            relative(base_path, &path),
+            entry.fact
+        ));
+    }
+    let next = insert_under_heading(&content, &clean_value(section), &entry.render());
+    if let Some(parent) = path.parent()
+        && let Err(error) = fs::create_dir_all(parent)
+    {
+        return ToolExecutionResult::error(format!("Error creating folder: {}", error));
+    }
+    match fs::write(&path, next) {
+        Ok(_) => ToolExecutionResult::success(format!(
+            "Remembered in {} (id {}): {}",
+            relative(base_path, &path),
+            id,
+            entry.fact
+        )),
+        Err(error) => ToolExecutionResult::error(format!("Error writing knowledge: {}", error)),
+    }
+}
+
+fn run_forget(args: &Value, base_path: &Path, channel_id: &str) -> ToolExecutionResult {
+    let id = string_arg(args, "id");
+    let query = string_arg(args, "query").map(str::to_lowercase);
+    if id.is_none() && query.is_none() {
+        return ToolExecutionResult::error("Error: Pass `id` or `query` to choose what to forget.");
+    }
+    let all = args.get("all").and_then(Value::as_bool).unwrap_or(false);
+    let path = match knowledge_path(base_path, scope_arg(args, channel_id), channel_id) {
+        Ok(path) => path,
+        Err(error) => return ToolExecutionResult::error(format!("Error: {}", error)),
+    };
+
+    let _guard = MEMORY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let Ok(content) = fs::read_to_string(&path) else {
+        return ToolExecutionResult::error(format!(
+            "Error: {} does not exist.",
+            relative(base_path, &path)
+        ));
+    };
+    let matches = |line: &str| {
+        MemoryEntry::parse(line).filter(|entry| match (id, &query) {
+            (Some(id), _) => entry.field("id") == Some(id),
+            (None, Some(query)) => entry.fact.to_lowercase().contains(query.as_str()),
+            (None, None) => false,
+        })
+    };
+    let found: Vec<MemoryEntry> = content.lines().filter_map(matches).collect();
+    if found.is_empty() {
+        return ToolExecutionResult::error(format!(
+            "Error: No remembered fact in {} matches.",
+            relative(base_path, &path)
+        ));
+    }
+    if found.len() > 1 && !all {
+        let listing: Vec<String> = found
+            .iter()
+            .map(|entry| format!("- {} (id {})", entry.fact, entry.field("id").unwrap_or("?")))
+            .collect();
+        return ToolExecutionResult::error(format!(
+            "Error: {} facts match; pass an `id`, or all=true to forget every one:\n{}",
+            found.len(),
+            listing.join("\n")
+        ));
+    }
+
+    let mut next: String = content
+        .lines()
+        .filter(|line| matches(line).is_none())
+        .collect::<Vec<_>>()
+        .join("\n");
+    next.push('\n');
+    match fs::write(&path, next) {
+        Ok(_) => ToolExecutionResult::success(format!(
+            "Forgot {} fact(s) from {}:\n{}",
+            found.len(),
+            relative(base_path, &path),
+            found
+                .iter()
+                .map(|entry| format!("- {}", entry.fact))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )),
+        Err(error) => ToolExecutionResult::error(format!("Error writing knowledge: {}", error)),
+    }
+}
+
+/// A fact removed by [`prune_knowledge`], with the file it was in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrunedFact {
+    pub path: String,
+    pub entry: MemoryEntry,
+    pub reason: &'static str,
+}
+
+pub(crate) fn knowledge_files(base_path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![base_path.join("brain").join("KNOWLEDGE.md")];
+    if let Ok(entries) = fs::read_dir(base_path.join("channels")) {
+        let mut channels: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path().join("KNOWLEDGE.md"))
+            .collect();
+        channels.sort();
+        files.extend(channels);
+    }
+    if let Ok(entries) = fs::read_dir(base_path.join(crate::entities::ENTITIES_DIR)) {
+        let mut sheets: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+        sheets.sort();
+        files.extend(sheets);
+    }
+    files.retain(|path| path.is_file());
+    files
+}
+
+/// Remove remembered facts that expired before `now` or that a newer fact supersedes,
+/// across `brain/KNOWLEDGE.md`, every channel `KNOWLEDGE.md`, and the entity sheets. Hand-written lines
+/// without memory metadata are never touched, nor are files `may_write` refuses (it gets
+/// guild-relative paths); those still count when deciding what is superseded.
+pub fn prune_knowledge(
+    base_path: &Path,
+    now: NaiveDateTime,
+    may_write: &dyn Fn(&Path) -> bool,
+) -> std::io::Result<Vec<PrunedFact>> {
+    let _guard = MEMORY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let files: Vec<(PathBuf, String)> = knowledge_files(base_path)
+        .into_iter()
+        .filter_map(|path| fs::read_to_string(&path).ok().map(|content| (path, content)))
+        .collect();
+    let superseded: Vec<String> = files
+        .iter()
+        .flat_map(|(_, content)| content.lines().filter_map(MemoryEntry::parse))
+        .filter_map(|entry| entry.field("supersedes").map(str::to_string))
+        .collect();
+
+    let mut pruned = Vec::new();
+    for (path, content) in files {
+        if !may_write(path.strip_prefix(base_path).unwrap_or(&path)) {
+            continue;
+        }
+        let mut removed = Vec::new();
+        let kept: Vec<&str> = content
+            .lines()
+            .filter(|line| {
+                let Some(entry) = MemoryEntry::parse(line) else {
+                    return true;
+                };
+                let reason = if entry.expires_at().is_some_and(|at| at < now) {
+                    "expired"
+                } else if entry
+                    .field("id")
+                    .is_some_and(|id| superseded.iter().any(|s| s == id))
+                {
+                    "superseded"
+                } else {
+                    return true;
+                };
+                removed.push((entry, reason));
+                false
+            })
+            .collect();
+        if removed.is_empty() {
+            continue;
+        }
+        let mut next = kept.join("\n");
+        next.push('\n');
+        fs::write(&path, next)?;
+        let path = relative(base_path, &path);
+        pruned.extend(removed.into_iter().map(|(entry, reason)| PrunedFact {
+            path: path.clone(),
+            entry,
+            reason,
+        }));
+    }
+    Ok(pruned)
+}
+
+pub(crate) fn memory_tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": REMEMBER_TOOL_NAME,
+            "description": "Store a durable fact in KNOWLEDGE.md with an id, timestamp, and provenance. Use it when someone says \"remember that...\" or a decision should outlive the conversation, instead of editing KNOWLEDGE.md by hand. Set expires for time-bound facts and supersedes when a fact replaces an older one; the Guardian prunes both.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "fact": { "type": "string", "description": "One self-contained sentence" },
+                    "scope": { "type": "string", "enum": ["channel", "guild"], "description": "channel (default) for this channel's KNOWLEDGE.md, guild for brain/KNOWLEDGE.md" },
+                    "section": { "type": "string", "description": "Heading to file the fact under. Defaults to Remembered" },
+                    "by": { "type": "string", "description": "Who stated the fact" },
+                    "message_id": { "type": "string", "description": "Discord message ID the fact came from" },
+                    "expires": { "type": "string", "description": "YYYY-MM-DD or YYYY-MM-DD HH:MM after which the fact no longer holds" },
+                    "supersedes": { "type": "string", "description": "Id of an older fact this one replaces" }
+                },
+                "required": ["fact"]
+            }
+        }),
+        json!({
+            "name": FORGET_TOOL_NAME,
+            "description": "Remove facts stored with remember, by id or by matching text. Refuses ambiguous matches unless all=true.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string", "description": "Entry id reported by remember" },
+                    "query": { "type": "string", "description": "Case-insensitive text the fact contains" },
+                    "scope": { "type": "string", "enum": ["channel", "guild"], "description": "Which KNOWLEDGE.md to edit. Defaults to channel" },
+                    "all": { "type": "boolean", "description": "Forget every fact matching query" }
+                }
+            }
+        }),
+    ]
+}
+
+pub(crate) fn dispatch_memory_tool(
+    name: &str,
+    args: &Value,
+    base_path: &Path,
+    channel_id: &str,
+) -> Option<ToolExecutionResult> {
+    match name {
+        REMEMBER_TOOL_NAME => Some(run_remember(args, base_path, channel_id)),
+        FORGET_TOOL_NAME => Some(run_forget(args, base_path, channel_id)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn run(name: &str, args: Value, base_path: &Path) -> ToolExecutionResult {
+        dispatch_memory_tool(name, &args, base_path, "999123456").unwrap()
+    }
+
+    #[test]
+    fn test_remember_appends_entries_under_section_with_provenance() {
+        let dir = tempdir().unwrap();
+        let folder = dir.path().join("channels/general-123456");
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(
+            folder.join("KNOWLEDGE.md"),
+            "# General\n\n## Remembered\n\n- old note\n\n## Other\n\nkeep\n",
+        )
+        .unwrap();
+
+        let result = run(
+            REMEMBER_TOOL_NAME,
+            json!({ "fact": "The boiler is serviced by Heatwise", "by": "Ada" }),
+            dir.path(),
+        );
+        assert!(!result.is_error, "{}", result.output);
+        let content = fs::read_to_string(folder.join("KNOWLEDGE.md")).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines[4], "- old note");
+        let entry = MemoryEntry::parse(lines[5]).unwrap();
+        assert_eq!(entry.fact, "The boiler is serviced by Heatwise");
+        assert_eq!(entry.field("by"), Some("Ada"));
+        assert_eq!(entry.field("channel"), Some("999123456"));
+        assert!(content.ends_with("## Other\n\nkeep\n"));
+
+        let duplicate = run(
+            REMEMBER_TOOL_NAME,
+            json!({ "fact": "the boiler is serviced by heatwise" }),
+            dir.path(),
+        );
+        assert!(duplicate.output.starts_with("Already remembered"));
+
+        run(
+            REMEMBER_TOOL_NAME,
+            json!({ "fact": "Wifi password rotates monthly", "scope": "guild", "section": "Home" }),
+            dir.path(),
+        );
+        let guild = fs::read_to_string(dir.path().join("brain/KNOWLEDGE.md")).unwrap();
+        assert!(guild.starts_with("## Home\n\n- Wifi password rotates monthly <!-- memory: id=m"));
+    }
+
+    #[test]
+    fn test_forget_removes_by_id_or_unambiguous_query() {
+        let dir = tempdir().unwrap();
+        for fact in [
+            "Bins go out Tuesday",
+            "Bins are collected early in winter",
+            "Cat is fed at 7",
+        ] {
+            run(
+                REMEMBER_TOOL_NAME,
+                json!({ "fact": fact, "scope": "guild" }),
+                dir.path(),
+            );
+        }
+
+        let ambiguous = run(
+            FORGET_TOOL_NAME,
+            json!({ "query": "bins", "scope": "guild" }),
+            dir.path(),
+        );
+        assert!(ambiguous.is_error);
+        assert!(ambiguous.output.contains("2 facts match"));
+
+        let forgot = run(
+            FORGET_TOOL_NAME,
+            json!({ "query": "cat", "scope": "guild" }),
+            dir.path(),
+        );
+        assert_eq!(
+            forgot.output,
+            "Forgot 1 fact(s) from brain/KNOWLEDGE.md:\n- Cat is fed at 7"
+        );
+
+        let content = fs::read_to_string(dir.path().join("brain/KNOWLEDGE.md")).unwrap();
+        let id = content
+            .lines()
+            .filter_map(MemoryEntry::parse)
+            .find(|entry| entry.fact.starts_with("Bins go out"))
+            .and_then(|entry| entry.field("id").map(str::to_string))
+            .unwrap();
+        run(
+            FORGET_TOOL_NAME,
+            json!({ "id": id, "scope": "guild" }),
+            dir.path(),
+        );
+        let content = fs::read_to_string(dir.path().join("brain/KNOWLEDGE.md")).unwrap();
+        assert!(!content.contains("Bins go out"));
+        assert!(content.contains("Bins are collected early"));
+    }
+
+    #[test]
+    fn test_prune_knowledge_drops_expired_and_superseded_facts() {
+        let dir = tempdir().unwrap();
+        let folder = dir.path().join("channels/general-123456");
+        fs::create_dir_all(&folder).unwrap();
+        let old = run(
+            REMEMBER_TOOL_NAME,
+            json!({ "fact": "Standup is at 9", "message_id": "555" }),
+            dir.path(),
+        );
+        let old_id = old.output.split("(id ").nth(1).unwrap()[..9].to_string();
+        run(
+            REMEMBER_TOOL_NAME,
+            json!({ "fact": "Standup moved to 10", "supersedes": old_id, "scope": "guild" }),
+            dir.path(),
+        );
+        run(
+            REMEMBER_TOOL_NAME,
+            json!({ "fact": "Office closed for the move", "expires": "2026-03-01" }),
+            dir.path(),
+        );
+        let invalid = run(
+            REMEMBER_TOOL_NAME,
+            json!({ "fact": "Soon", "expires": "next week" }),
+            dir.path(),
+        );
+        assert!(invalid.is_error);
+        fs::write(
+            folder.join("KNOWLEDGE.md"),
+            fs::read_to_string(folder.join("KNOWLEDGE.md")).unwrap() + "- hand-written note\n",
+        )
+        .unwrap();
+
+        let on_the_day = NaiveDate::from_ymd_opt(2026, 3, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let pruned = prune_knowledge(dir.path(), on_the_day, &|_| true).unwrap();
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].reason, "superseded");
+        assert_eq!(pruned[0].entry.field("msg"), Some("555"));
+        assert_eq!(pruned[0].path, "channels/general-123456/KNOWLEDGE.md");
+
+        let pruned = prune_knowledge(
+            dir.path(),
+            on_the_day + chrono::Duration::days(1),
+            &|path| path.starts_with("channels"),
+        )
+        .unwrap();
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].reason, "expired");
+        let channel = fs::read_to_string(folder.join("KNOWLEDGE.md")).unwrap();
+        assert!(!channel.contains("Standup") && !channel.contains("Office"));
+        assert!(channel.contains("- hand-written note"));
+        let guild = fs::read_to_string(dir.path().join("brain/KNOWLEDGE.md")).unwrap();
+        assert!(guild.contains("Standup moved to 10"));
+    }
+}
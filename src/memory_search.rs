@@ -0,0 +1,241 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/memory_search.rs
+ * Responsibility: The `search_memory` tool: scored search across knowledge, summaries, and history archives.
+ */
+
+use crate::config::Config;
+use crate::knowledge_index::{self, ScoredChunk};
+use crate::tools::ToolExecutionResult;
+use serde_json::{Value, json};
+use std::fs;
+use std::path::Path;
+
+pub const SEARCH_MEMORY_TOOL_NAME: &str = "search_memory";
+const DEFAULT_LIMIT: usize = 8;
+const MAX_LIMIT: usize = 25;
+const SNIPPET_CHARS: usize = 600;
+
+fn terms(text: &str) -> Vec<String> {
+    let mut terms: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| term.chars().count() > 1)
+        .map(str::to_lowercase)
+        .collect();
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+/// Share of query terms the chunk contains, plus a bonus when the whole phrase appears.
+fn text_score(query: &str, query_terms: &[String], chunk: &str) -> f32 {
+    let lower = chunk.to_lowercase();
+    let hits = query_terms
+        .iter()
+        .filter(|term| lower.contains(term.as_str()))
+        .count();
+    if hits == 0 {
+        return 0.0;
+    }
+    let coverage = hits as f32 / query_terms.len() as f32;
+    let phrase = lower.contains(query.trim().to_lowercase().as_str());
+    if phrase { coverage + 0.5 } else { coverage }
+}
+
+/// Full-text search over every memory file, scoring chunks by query-term coverage.
+pub fn text_search(
+    base_path: &Path,
+    config: &Config,
+    query: &str,
+    limit: usize,
+) -> Vec<ScoredChunk> {
+    let query_terms = terms(query);
+    if query_terms.is_empty() {
+        return Vec::new();
+    }
+    let mut scored = Vec::new();
+    for source in knowledge_index::collect_sources(base_path) {
+        let Ok(content) = fs::read_to_string(base_path.join(&source)) else {
+            continue;
+        };
+        for chunk in knowledge_index::chunk_text(&content, config.knowledge_index.chunk_chars) {
+            let score = text_score(query, &query_terms, &chunk);
+            if score > 0.0 {
+                scored.push(ScoredChunk {
+                    source: source.clone(),
+                    text: chunk,
+                    score,
+                });
+            }
+        }
+    }
+    // Equal scores favor newer archives, whose paths sort later.
+    scored.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| b.source.cmp(&a.source))
+    });
+    scored.truncate(limit);
+    scored
+}
+
+fn render(query: &str, mode: &str, chunks: &[ScoredChunk]) -> String {
+    if chunks.is_empty() {
+        return format!("No memory matches `{}` ({} search).", query, mode);
+    }
+    let mut out = format!(
+        "{} match(es) for `{}` ({} search):\n",
+        chunks.len(),
+        query,
+        mode
+    );
+    for chunk in chunks {
+        let mut snippet: String = chunk.text.chars().take(SNIPPET_CHARS).collect();
+        if chunk.text.chars().count() > SNIPPET_CHARS {
+            snippet.push('…');
+        }
+        out.push_str(&format!(
+            "\n[{}] score {:.2}\n{}\n",
+            chunk.source, chunk.score, snippet
+        ));
+    }
+    out
+}
+
+pub(crate) async fn run_search_memory_tool(
+    args: &Value,
+    base_path: &Path,
+    config: &Config,
+) -> ToolExecutionResult {
+    let Some(query) = args
+        .get("query")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|query| !query.is_empty())
+    else {
+        return ToolExecutionResult::error("Error: Missing required argument `query`.");
+    };
+    let limit = args
+        .get("limit")
+        .and_then(Value::as_u64)
+        .map(|limit| (limit as usize).clamp(1, MAX_LIMIT))
+        .unwrap_or(DEFAULT_LIMIT);
+    let default_mode = if config.knowledge_index.enabled {
+        "semantic"
+    } else {
+        "text"
+    };
+    let mode = args
+        .get("mode")
+        .and_then(Value::as_str)
+        .unwrap_or(default_mode);
+
+    match mode {
+        "text" => {
+            let chunks = text_search(base_path, config, query, limit);
+            ToolExecutionResult::success(render(query, mode, &chunks))
+        }
+        "semantic" => {
+            if let Err(e) = knowledge_index::refresh(base_path, config).await {
+                return ToolExecutionResult::error(format!(
+                    "Error: Knowledge index refresh failed: {}. Retry with mode=text.",
+                    e
+                ));
+            }
+            match knowledge_index::search_sources(base_path, config, query, |_| true, limit).await {
+                Ok(chunks) => ToolExecutionResult::success(render(query, mode, &chunks)),
+                Err(e) => ToolExecutionResult::error(format!(
+                    "Error: Semantic search failed: {}. Retry with mode=text.",
+                    e
+                )),
+            }
+        }
+        other => ToolExecutionResult::error(format!(
+            "Error: Unknown mode `{}`; use text or semantic.",
+            other
+        )),
+    }
+}
+
+pub(crate) fn search_memory_tool_definition() -> Value {
+    json!({
+        "name": SEARCH_MEMORY_TOOL_NAME,
+        "description": "Search every KNOWLEDGE.md, channel SUMMARY.md, and archived thread under channels/*/history/ across all channels. Returns scored snippets with their file paths. Use it for questions like \"when did we decide X?\" before reading files one by one.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "query": { "type": "string", "description": "Words or a question to look for" },
+                "mode": { "type": "string", "enum": ["text", "semantic"], "description": "text matches words; semantic uses the knowledge index. Defaults to semantic when knowledge_index is enabled, otherwise text" },
+                "limit": { "type": "integer", "description": "Maximum snippets to return (default 8, max 25)" }
+            },
+            "required": ["query"]
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_search_memory_ranks_snippets_across_channels() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("brain")).unwrap();
+        fs::create_dir_all(base.join("channels/ops-111111/history/2026-02-01")).unwrap();
+        fs::create_dir_all(base.join("channels/home-222222")).unwrap();
+        fs::write(
+            base.join("brain/KNOWLEDGE.md"),
+            "# Garden\n\nWater on Sundays.",
+        )
+        .unwrap();
+        fs::write(
+            base.join("channels/ops-111111/history/2026-02-01/thread.md"),
+            "We decided to switch the backup provider to Backblaze.",
+        )
+        .unwrap();
+        fs::write(
+            base.join("channels/home-222222/SUMMARY.md"),
+            "# Channel Summary\n\nThe backup drive is in the study.",
+        )
+        .unwrap();
+        fs::write(
+            base.join("channels/home-222222/2026-02-02.md"),
+            "backup provider chat",
+        )
+        .unwrap();
+
+        let mut config = Config::default();
+        config.gemini.model = crate::mock_llm::MOCK_MODEL.to_string();
+
+        let result =
+            run_search_memory_tool(&json!({ "query": "backup provider" }), base, &config).await;
+        assert!(!result.is_error, "{}", result.output);
+        assert!(
+            result
+                .output
+                .starts_with("2 match(es) for `backup provider` (text search)")
+        );
+        let first = result
+            .output
+            .find("ops-111111/history/2026-02-01/thread.md")
+            .unwrap();
+        let second = result.output.find("home-222222/SUMMARY.md").unwrap();
+        assert!(first < second);
+        assert!(!result.output.contains("2026-02-02.md"));
+
+        let none = run_search_memory_tool(&json!({ "query": "zeppelin" }), base, &config).await;
+        assert_eq!(none.output, "No memory matches `zeppelin` (text search).");
+
+        config.knowledge_index.min_score = 0.0;
+        let semantic = run_search_memory_tool(
+            &json!({ "query": "backup provider", "mode": "semantic", "limit": 1 }),
+            base,
+            &config,
+        )
+        .await;
+        assert!(!semantic.is_error, "{}", semantic.output);
+        assert!(semantic.output.starts_with("1 match(es)"));
+    }
+}
@@ -0,0 +1,453 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/migrate.rs
+ * Responsibility: `tellarctl migrate`: detect a guild's workspace format version and upgrade its files to the current one.
+ */
+
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Holds the format version the guild's files follow.
+pub const VERSION_FILE: &str = ".tellar-format";
+/// Format this build reads and writes.
+pub const CURRENT_VERSION: u32 = MIGRATIONS.len() as u32 + 1;
+
+/// One upgrade step. It leaves the workspace at format `from + 1`.
+pub struct Migration {
+    pub from: u32,
+    pub summary: &'static str,
+    plan: fn(&Path) -> Result<Vec<Change>>,
+}
+
+/// Every migration, oldest first. Append new ones; never edit a released one.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 1,
+        summary: "Write each ritual's implicit task_id (its file name) into its header, so renaming the file keeps its run history and depends_on links",
+        plan: pin_ritual_task_ids,
+    },
+    Migration {
+        from: 2,
+        summary: "Mark pending reminders `source: local`, which `at:` one-shots now need before the Rhythm fires them",
+        plan: mark_reminders_local,
+    },
+    Migration {
+        from: 3,
+        summary: "Add brain/logs/ and brain/journal/ to the workspace .gitignore, so git history stops recording runtime logs",
+        plan: ignore_runtime_paths,
+    },
+];
+
+/// A file edit a migration makes, relative to the guild.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Rewrite {
+        path: PathBuf,
+        before: String,
+        after: String,
+    },
+    Move {
+        from: PathBuf,
+        to: PathBuf,
+    },
+}
+
+impl Change {
+    /// A unified diff for rewrites, git-style rename lines for moves.
+    pub fn render(&self) -> String {
+        match self {
+            Change::Rewrite {
+                path,
+                before,
+                after,
+            } => {
+                let path = path.display();
+                crate::diff::unified_diff(
+                    before,
+                    after,
+                    &format!("a/{}", path),
+                    &format!("b/{}", path),
+                    3,
+                )
+                .unwrap_or_default()
+            }
+            Change::Move { from, to } => {
+                format!("rename from {}\nrename to {}", from.display(), to.display())
+            }
+        }
+    }
+
+    fn apply(&self, base_path: &Path) -> Result<()> {
+        match self {
+            Change::Rewrite { path, after, .. } => {
+                let target = base_path.join(path);
+                // Write beside the file and swap it in, so a crash never leaves half a file.
+                let staged = target.with_extension("migrating");
+                fs::write(&staged, after)
+                    .with_context(|| format!("failed to write {}", staged.display()))?;
+                fs::rename(&staged, &target)
+                    .with_context(|| format!("failed to replace {}", target.display()))
+            }
+            Change::Move { from, to } => {
+                let target = base_path.join(to);
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::rename(base_path.join(from), &target).with_context(|| {
+                    format!("failed to move {} to {}", from.display(), to.display())
+                })
+            }
+        }
+    }
+}
+
+/// The format `base_path` is in. Guilds from before versioning have no version file
+/// and count as 1; an empty directory has nothing to upgrade.
+pub fn detect_version(base_path: &Path) -> Result<u32> {
+    match fs::read_to_string(base_path.join(VERSION_FILE)) {
+        Ok(version) => version
+            .trim()
+            .parse()
+            .with_context(|| format!("{} holds `{}`, not a version", VERSION_FILE, version.trim())),
+        Err(_)
+            if ["tellar.yml", "channels", "rituals", "brain"]
+                .iter()
+                .any(|entry| base_path.join(entry).exists()) =>
+        {
+            Ok(1)
+        }
+        Err(_) => Ok(CURRENT_VERSION),
+    }
+}
+
+pub fn write_version(base_path: &Path, version: u32) -> Result<()> {
+    let path = base_path.join(VERSION_FILE);
+    fs::write(&path, format!("{}\n", version))
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Migrations a guild at `version` still needs, oldest first.
+pub fn pending(version: u32) -> Result<Vec<&'static Migration>> {
+    if version > CURRENT_VERSION {
+        bail!(
+            "the workspace is at format {}, but this Tellar only knows up to {}; update Tellar",
+            version,
+            CURRENT_VERSION
+        );
+    }
+    Ok(MIGRATIONS.iter().filter(|m| m.from >= version).collect())
+}
+
+impl Migration {
+    /// What this migration would change in the guild as it is now.
+    pub fn plan(&self, base_path: &Path) -> Result<Vec<Change>> {
+        (self.plan)(base_path)
+    }
+
+    /// Make the changes, then record the new version, so an interrupted run resumes at
+    /// the first migration it did not finish.
+    pub fn apply(&self, base_path: &Path) -> Result<Vec<Change>> {
+        let changes = self.plan(base_path)?;
+        for change in &changes {
+            change.apply(base_path)?;
+        }
+        write_version(base_path, self.from + 1)?;
+        Ok(changes)
+    }
+}
+
+/// Byte range of the YAML between a document's front-matter fences.
+fn front_matter(content: &str) -> Option<std::ops::Range<usize>> {
+    let (first, rest) = content.split_once('\n')?;
+    if !crate::rhythm::is_fence(first) {
+        return None;
+    }
+    let start = first.len() + 1;
+    let mut offset = start;
+    for line in rest.split_inclusive('\n') {
+        if crate::rhythm::is_fence(line) {
+            return Some(start..offset);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+fn is_key_line(line: &str, key: &str) -> bool {
+    line.strip_prefix(key)
+        .is_some_and(|rest| rest.trim_start().starts_with(':'))
+}
+
+fn has_header_field(content: &str, key: &str) -> bool {
+    front_matter(content)
+        .is_some_and(|header| content[header].lines().any(|line| is_key_line(line, key)))
+}
+
+/// `content` with the top-level header field `from` renamed to `to`; `None` when it has
+/// no such field.
+pub fn rename_header_field(content: &str, from: &str, to: &str) -> Result<Option<String>> {
+    let Some(header) = front_matter(content) else {
+        return Ok(None);
+    };
+    if !has_header_field(content, from) {
+        return Ok(None);
+    }
+    if has_header_field(content, to) {
+        bail!("the header has both `{}` and `{}`", from, to);
+    }
+    let renamed: String = content[header.clone()]
+        .split_inclusive('\n')
+        .map(|line| match line.strip_prefix(from) {
+            Some(rest) if is_key_line(line, from) => format!("{}{}", to, rest),
+            _ => line.to_string(),
+        })
+        .collect();
+    Ok(Some(format!(
+        "{}{}{}",
+        &content[..header.start],
+        renamed,
+        &content[header.end..]
+    )))
+}
+
+/// `content` with `key: value` added at the end of its header; `None` when it has no
+/// header or already sets `key`.
+pub fn add_header_field(content: &str, key: &str, value: &str) -> Result<Option<String>> {
+    let Some(header) = front_matter(content) else {
+        return Ok(None);
+    };
+    if has_header_field(content, key) {
+        return Ok(None);
+    }
+    let value = serde_yml::to_string(value)?;
+    Ok(Some(format!(
+        "{}{}: {}\n{}",
+        &content[..header.end],
+        key,
+        value.trim_end(),
+        &content[header.end..]
+    )))
+}
+
+/// Markdown files under `dir` (guild-relative), skipping hidden entries, sorted.
+pub fn markdown_files(base_path: &Path, dir: &str) -> Vec<PathBuf> {
+    fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+        for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            if path.is_dir() {
+                walk(&path, files);
+            } else if path.extension().is_some_and(|ext| ext == "md") {
+                files.push(path);
+            }
+        }
+    }
+    let mut files = Vec::new();
+    walk(&base_path.join(dir), &mut files);
+    files.sort();
+    files
+}
+
+/// Rewrites renaming header field `from` to `to` in every Markdown file under `dir`.
+pub fn rename_field_in(base_path: &Path, dir: &str, from: &str, to: &str) -> Result<Vec<Change>> {
+    let mut changes = Vec::new();
+    for path in markdown_files(base_path, dir) {
+        let before = fs::read_to_string(&path)?;
+        let after = rename_header_field(&before, from, to)
+            .with_context(|| format!("cannot migrate {}", path.display()))?;
+        if let Some(after) = after {
+            changes.push(Change::Rewrite {
+                path: path.strip_prefix(base_path)?.to_path_buf(),
+                before,
+                after,
+            });
+        }
+    }
+    Ok(changes)
+}
+
+/// A move of guild folder `from` to `to`; none when `from` does not exist.
+pub fn move_folder(base_path: &Path, from: &str, to: &str) -> Result<Vec<Change>> {
+    if !base_path.join(from).exists() {
+        return Ok(Vec::new());
+    }
+    if base_path.join(to).exists() {
+        bail!("both {} and {} exist; merge them by hand", from, to);
+    }
+    Ok(vec![Change::Move {
+        from: PathBuf::from(from),
+        to: PathBuf::from(to),
+    }])
+}
+
+fn pin_ritual_task_ids(base_path: &Path) -> Result<Vec<Change>> {
+    let mut paths = Vec::new();
+    crate::rhythm::collect_thread_files(&base_path.join("rituals"), &mut paths)?;
+    paths.sort();
+    let mut changes = Vec::new();
+    for path in paths {
+        let before = fs::read_to_string(&path)?;
+        if crate::rhythm::parse_thread_metadata(&before).is_none() {
+            continue;
+        }
+        let task_id = crate::ritual_runs::ritual_task_id(None, &path);
+        if let Some(after) = add_header_field(&before, "task_id", &task_id)? {
+            changes.push(Change::Rewrite {
+                path: path.strip_prefix(base_path)?.to_path_buf(),
+                before,
+                after,
+            });
+        }
+    }
+    Ok(changes)
+}
+
+/// Reminder files `remind_me` and calendar leads wrote before they carried `source: local`.
+fn mark_reminders_local(base_path: &Path) -> Result<Vec<Change>> {
+    let mut changes = Vec::new();
+    for path in markdown_files(base_path, "rituals") {
+        let is_reminder = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("reminder-"));
+        if !is_reminder || path.components().any(|part| part.as_os_str() == "history") {
+            continue;
+        }
+        let before = fs::read_to_string(&path)?;
+        let Some((header, _)) = crate::rhythm::parse_thread_metadata(&before) else {
+            continue;
+        };
+        if header.at.is_none() || header.origin_channel.is_none() {
+            continue;
+        }
+        if let Some(after) = add_header_field(&before, "source", "local")? {
+            changes.push(Change::Rewrite {
+                path: path.strip_prefix(base_path)?.to_path_buf(),
+                before,
+                after,
+            });
+        }
+    }
+    Ok(changes)
+}
+
+/// Older guilds got their `.gitignore` before the runtime log and journal existed.
+fn ignore_runtime_paths(base_path: &Path) -> Result<Vec<Change>> {
+    let Ok(before) = fs::read_to_string(base_path.join(".gitignore")) else {
+        return Ok(Vec::new());
+    };
+    let missing: Vec<&str> = crate::workspace_git::RUNTIME_PATHS
+        .into_iter()
+        .filter(|entry| !before.lines().any(|line| line.trim() == *entry))
+        .collect();
+    if missing.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut after = before.clone();
+    if !after.is_empty() && !after.ends_with('\n') {
+        after.push('\n');
+    }
+    for entry in missing {
+        after.push_str(entry);
+        after.push('\n');
+    }
+    Ok(vec![Change::Rewrite {
+        path: PathBuf::from(".gitignore"),
+        before,
+        after,
+    }])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_migrations_upgrade_headers_and_folders() {
+        let dir = tempdir().unwrap();
+        assert_eq!(detect_version(dir.path()).unwrap(), CURRENT_VERSION);
+        let rituals = dir.path().join("rituals");
+        fs::create_dir_all(&rituals).unwrap();
+        fs::write(
+            rituals.join("backup.md"),
+            "---\nschedule: \"0 0 3 * * *\"\n---\n- [ ] Back up\n",
+        )
+        .unwrap();
+        fs::write(
+            rituals.join("report.md"),
+            "---\ntask_id: weekly\n---\n- [ ] Report\n",
+        )
+        .unwrap();
+        assert_eq!(detect_version(dir.path()).unwrap(), 1);
+
+        let migrations = pending(1).unwrap();
+        assert_eq!(migrations.len(), MIGRATIONS.len());
+        let planned = migrations[0].plan(dir.path()).unwrap();
+        assert_eq!(planned.len(), 1);
+        assert!(
+            planned[0]
+                .render()
+                .contains("--- a/rituals/backup.md\n+++ b/rituals/backup.md")
+        );
+        assert!(planned[0].render().contains("\n+task_id: backup\n"));
+        assert_eq!(migrations[0].apply(dir.path()).unwrap(), planned);
+        assert_eq!(
+            fs::read_to_string(rituals.join("backup.md")).unwrap(),
+            "---\nschedule: \"0 0 3 * * *\"\ntask_id: backup\n---\n- [ ] Back up\n"
+        );
+        assert_eq!(detect_version(dir.path()).unwrap(), 2);
+
+        let reminder = "---\nat: 2026-03-01T09:00:00+00:00\norigin_channel: '42'\ninjection_template: '- [ ] Reminder: Call'\n---\n# Reminder: Call\n";
+        fs::write(rituals.join("reminder-20260301-0900-call.md"), reminder).unwrap();
+        fs::create_dir_all(rituals.join("history/2026-02-01")).unwrap();
+        fs::write(
+            rituals.join("history/2026-02-01/reminder-20260201-0900-old.md"),
+            reminder,
+        )
+        .unwrap();
+        let marked = migrations[1].apply(dir.path()).unwrap();
+        assert_eq!(marked.len(), 1);
+        let (header, _) = crate::rhythm::parse_thread_metadata(
+            &fs::read_to_string(rituals.join("reminder-20260301-0900-call.md")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(header.source.as_deref(), Some("local"));
+        assert_eq!(detect_version(dir.path()).unwrap(), 3);
+
+        fs::write(dir.path().join(".gitignore"), "tellar.yml\nbrain/logs/").unwrap();
+        migrations[2].apply(dir.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.path().join(".gitignore")).unwrap(),
+            "tellar.yml\nbrain/logs/\nbrain/journal/\n"
+        );
+        assert!(migrations[2].plan(dir.path()).unwrap().is_empty());
+        assert_eq!(detect_version(dir.path()).unwrap(), 4);
+        assert!(pending(CURRENT_VERSION).unwrap().is_empty());
+        assert!(pending(CURRENT_VERSION + 1).is_err());
+
+        let renamed = rename_header_field(
+            "---\nchannel: \"42\"\nchannels_seen: 1\n---\nchannel: body\n",
+            "channel",
+            "origin_channel",
+        )
+        .unwrap();
+        assert_eq!(
+            renamed.as_deref(),
+            Some("---\norigin_channel: \"42\"\nchannels_seen: 1\n---\nchannel: body\n")
+        );
+        assert!(rename_header_field("---\na: 1\nb: 2\n---\n", "a", "b").is_err());
+
+        fs::create_dir_all(dir.path().join("brain/events")).unwrap();
+        let moved = move_folder(dir.path(), "brain/events", "brain/calendar").unwrap();
+        moved[0].apply(dir.path()).unwrap();
+        assert!(dir.path().join("brain/calendar").is_dir());
+        assert!(
+            move_folder(dir.path(), "brain/events", "brain/calendar")
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
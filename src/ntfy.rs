@@ -0,0 +1,393 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/ntfy.rs
+ * Responsibility: ntfy push delivery and the reply topic subscriber that inscribes phone replies.
+ */
+
+use crate::StewardNotification;
+use crate::config::{Config, NtfyConfig};
+use crate::discord::{ingest_store, resolve_folder_by_id};
+use crate::lifecycle::{LifecycleEvent, LifecycleEventKind};
+use crate::{log_info, log_warn};
+use chrono::{Local, TimeZone};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Every push Tellar publishes carries this tag, so the subscriber can skip its own echoes.
+const TELLAR_TAG: &str = "tellar";
+const RECONNECT_DELAY: Duration = Duration::from_secs(15);
+
+static PUBLISH_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("Failed to create ntfy reqwest client")
+});
+
+/// The subscription stream stays open indefinitely, so it must not carry a request timeout.
+static SUBSCRIBE_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .build()
+        .expect("Failed to create ntfy subscribe client")
+});
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PushMessage {
+    pub(crate) title: Option<String>,
+    pub(crate) message: String,
+    /// ntfy priority from 1 (min) to 5 (urgent); `None` uses the server default.
+    pub(crate) priority: Option<u8>,
+    pub(crate) tags: Vec<String>,
+}
+
+impl PushMessage {
+    fn body(&self, topic: &str) -> serde_json::Value {
+        let mut tags = self.tags.clone();
+        if !tags.iter().any(|tag| tag == TELLAR_TAG) {
+            tags.push(TELLAR_TAG.to_string());
+        }
+
+        let mut body = json!({ "topic": topic, "message": self.message, "tags": tags });
+        if let Some(title) = &self.title {
+            body["title"] = json!(title);
+        }
+        if let Some(priority) = self.priority {
+            body["priority"] = json!(priority.clamp(1, 5));
+        }
+        body
+    }
+}
+
+fn with_auth(request: reqwest::RequestBuilder, settings: &NtfyConfig) -> reqwest::RequestBuilder {
+    if settings.token.is_empty() {
+        request
+    } else {
+        request.bearer_auth(&settings.token)
+    }
+}
+
+/// Publish one push notification to `ntfy.topic`.
+pub(crate) async fn publish(settings: &NtfyConfig, push: &PushMessage) -> anyhow::Result<()> {
+    if !settings.enabled || settings.topic.is_empty() {
+        return Err(anyhow::anyhow!(
+            "ntfy is not configured. Set `ntfy.enabled: true` and `ntfy.topic` in tellar.yml"
+        ));
+    }
+
+    let request = PUBLISH_CLIENT
+        .post(settings.server.trim_end_matches('/'))
+        .json(&push.body(&settings.topic));
+    let response = with_auth(request, settings).send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("ntfy returned HTTP {}", response.status()));
+    }
+    Ok(())
+}
+
+fn subscribes_to(settings: &NtfyConfig, kind: LifecycleEventKind) -> bool {
+    settings.events.is_empty() || settings.events.iter().any(|event| event == kind.as_str())
+}
+
+pub(crate) fn lifecycle_push(event: &LifecycleEvent) -> PushMessage {
+    let (priority, tag) = match event.kind {
+        LifecycleEventKind::GuardianAlarm => (5, "rotating_light"),
+        LifecycleEventKind::TaskFailed => (4, "warning"),
+        LifecycleEventKind::RitualCompleted => (3, "white_check_mark"),
+        LifecycleEventKind::ThreadArchived => (2, "file_cabinet"),
+    };
+    let message = if event.detail.is_empty() {
+        event.thread.clone()
+    } else {
+        format!("{}\n{}", event.thread, event.detail)
+    };
+
+    PushMessage {
+        title: Some(event.summary.clone()),
+        message,
+        priority: Some(priority),
+        tags: vec![tag.to_string()],
+    }
+}
+
+/// Push a lifecycle event to the phone in the background when ntfy subscribes to it.
+pub(crate) fn notify_lifecycle(config: &Config, event: &LifecycleEvent) {
+    if !config.ntfy.enabled || !subscribes_to(&config.ntfy, event.kind) {
+        return;
+    }
+
+    let mut push = lifecycle_push(event);
+    push.message = crate::tools::mask_sensitive_data(&push.message, config);
+    let settings = config.ntfy.clone();
+    let kind = event.kind;
+    tokio::spawn(async move {
+        if let Err(error) = publish(&settings, &push).await {
+            log_warn!(
+                "runtime",
+                "⚠️ ntfy push failed for {}: {:?}",
+                kind.as_str(),
+                error
+            );
+        }
+    });
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub(crate) struct NtfyMessage {
+    pub(crate) id: String,
+    #[serde(default)]
+    pub(crate) time: i64,
+    #[serde(default)]
+    pub(crate) title: Option<String>,
+    #[serde(default)]
+    pub(crate) message: String,
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+}
+
+/// Parse one line of the `/<topic>/json` stream, keeping only real messages.
+pub(crate) fn parse_stream_line(line: &str) -> Option<NtfyMessage> {
+    let value: serde_json::Value = serde_json::from_str(line.trim()).ok()?;
+    if value.get("event").and_then(|event| event.as_str()) != Some("message") {
+        return None;
+    }
+    serde_json::from_value(value).ok()
+}
+
+/// Append a phone reply to today's blackboard of the reply channel.
+pub(crate) async fn inscribe_reply(
+    workspace_path: &Path,
+    channel_id: &str,
+    topic: &str,
+    reply: &NtfyMessage,
+) -> anyhow::Result<(PathBuf, String, String)> {
+    let folder = resolve_folder_by_id(workspace_path, channel_id)
+        .ok_or_else(|| anyhow::anyhow!("no channel folder found for channel_id {}", channel_id))?;
+
+    let content = match &reply.title {
+        Some(title) if !title.is_empty() => format!("**{}**\n\n{}", title, reply.message),
+        _ => reply.message.clone(),
+    };
+    let daily_file = format!("{}.md", Local::now().format("%Y-%m-%d"));
+    let message_id = format!("ntfy-{}", reply.id);
+    let timestamp = Local
+        .timestamp_opt(reply.time, 0)
+        .single()
+        .unwrap_or_else(Local::now)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string();
+
+    ingest_store::append_to_message_log(
+        workspace_path,
+        &format!("{}/{}", folder, daily_file),
+        &format!("ntfy:{}", topic),
+        "ntfy",
+        &content,
+        &message_id,
+        &timestamp,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    Ok((
+        workspace_path
+            .join("channels")
+            .join(folder)
+            .join(daily_file),
+        message_id,
+        content,
+    ))
+}
+
+async fn handle_reply(
+    workspace_path: &Path,
+    config: &Config,
+    channel_id: &str,
+    topic: &str,
+    reply: &NtfyMessage,
+    notif_tx: &mpsc::Sender<StewardNotification>,
+) -> anyhow::Result<()> {
+    let (blackboard_path, message_id, content) =
+        inscribe_reply(workspace_path, channel_id, topic, reply).await?;
+    log_info!(
+        "runtime",
+        "📲 ntfy reply inscribed into {:?}",
+        blackboard_path.file_name()
+    );
+
+    if config.ntfy.wake {
+        notif_tx
+            .send(StewardNotification {
+                blackboard_path,
+                channel_id: channel_id.to_string(),
+                guild_id: config
+                    .discord
+                    .guild_id
+                    .clone()
+                    .unwrap_or_else(|| "0".to_string()),
+                message_id,
+                content,
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+async fn stream_replies(
+    workspace_path: &Path,
+    config: &Config,
+    channel_id: &str,
+    topic: &str,
+    since: &mut Option<String>,
+    notif_tx: &mpsc::Sender<StewardNotification>,
+) -> anyhow::Result<()> {
+    let mut url = format!(
+        "{}/{}/json",
+        config.ntfy.server.trim_end_matches('/'),
+        topic
+    );
+    if let Some(id) = since.as_deref() {
+        // ntfy message IDs are alphanumeric, so they need no escaping.
+        url.push_str(&format!("?since={}", id));
+    }
+    let request = SUBSCRIBE_CLIENT.get(&url);
+    let mut response = with_auth(request, &config.ntfy).send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("ntfy returned HTTP {}", response.status()));
+    }
+
+    let mut buffer = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        buffer.extend_from_slice(&chunk);
+        while let Some(newline) = buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=newline).collect();
+            let Some(reply) = parse_stream_line(&String::from_utf8_lossy(&line)) else {
+                continue;
+            };
+            *since = Some(reply.id.clone());
+            if reply.tags.iter().any(|tag| tag == TELLAR_TAG) {
+                continue;
+            }
+            if let Err(error) =
+                handle_reply(workspace_path, config, channel_id, topic, &reply, notif_tx).await
+            {
+                log_warn!(
+                    "runtime",
+                    "⚠️ Failed to inscribe ntfy reply {}: {:?}",
+                    reply.id,
+                    error
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Subscribe to `ntfy.reply_topic` forever, reconnecting without losing cached messages.
+pub async fn start_reply_listener(
+    workspace_path: PathBuf,
+    config: Arc<Config>,
+    notif_tx: mpsc::Sender<StewardNotification>,
+) -> anyhow::Result<()> {
+    let (Some(topic), Some(channel_id)) = (
+        config.ntfy.reply_topic.clone(),
+        config.ntfy.reply_channel_id.clone(),
+    ) else {
+        return Err(anyhow::anyhow!(
+            "ntfy.reply_topic and ntfy.reply_channel_id must both be set"
+        ));
+    };
+    log_info!(
+        "runtime",
+        "📲 Listening for ntfy replies on {}/{}...",
+        config.ntfy.server,
+        topic
+    );
+
+    let mut since = None;
+    loop {
+        if let Err(error) = stream_replies(
+            &workspace_path,
+            &config,
+            &channel_id,
+            &topic,
+            &mut since,
+            &notif_tx,
+        )
+        .await
+        {
+            log_warn!("runtime", "⚠️ ntfy subscription dropped: {:?}", error);
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_stream_line_keeps_only_messages() {
+        assert!(
+            parse_stream_line(r#"{"id":"k1","time":1,"event":"keepalive","topic":"t"}"#).is_none()
+        );
+        assert!(parse_stream_line("not json").is_none());
+
+        let reply = parse_stream_line(
+            r#"{"id":"m1","time":1772618400,"event":"message","topic":"t","message":"yes, run it","tags":["phone"]}"#,
+        )
+        .unwrap();
+        assert_eq!(reply.id, "m1");
+        assert_eq!(reply.message, "yes, run it");
+        assert_eq!(reply.tags, vec!["phone"]);
+    }
+
+    #[test]
+    fn test_lifecycle_push_maps_priority_and_tags_tellar() {
+        let event = LifecycleEvent::new(
+            LifecycleEventKind::TaskFailed,
+            "rituals/backup.md",
+            "123",
+            "Task failed",
+            "disk full",
+        );
+        let push = lifecycle_push(&event);
+        assert_eq!(push.priority, Some(4));
+        assert_eq!(push.title.as_deref(), Some("Task failed"));
+
+        let body = push.body("alerts");
+        assert_eq!(body["topic"], "alerts");
+        assert_eq!(body["message"], "rituals/backup.md\ndisk full");
+        assert_eq!(body["tags"], json!(["warning", "tellar"]));
+    }
+
+    #[tokio::test]
+    async fn test_inscribe_reply_appends_to_channel_daily_log() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("channels/phone-654321")).unwrap();
+        let reply = NtfyMessage {
+            id: "m1".to_string(),
+            time: 1772618400,
+            title: None,
+            message: "yes, run it".to_string(),
+            tags: Vec::new(),
+        };
+
+        let (path, message_id, _) =
+            inscribe_reply(dir.path(), "111654321", "tellar-replies", &reply)
+                .await
+                .unwrap();
+        let log = std::fs::read_to_string(path).unwrap();
+        assert_eq!(message_id, "ntfy-m1");
+        assert!(log.contains("**Author**: ntfy:tellar-replies"));
+        assert!(log.contains("yes, run it"));
+    }
+}
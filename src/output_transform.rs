@@ -0,0 +1,142 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/output_transform.rs
+ * Responsibility: Trim skill tool output with a declared JSON pointer, jq expression, or regex before the model sees it.
+ */
+
+use anyhow::{Context, Result, anyhow};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+const JQ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Steps applied to a tool's stdout in this order: `pointer`, then `jq`, then `regex`.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct OutputTransform {
+    /// JSON pointer such as `/data/items/0/name`; stdout must be JSON.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
+    /// Filter passed to the `jq` binary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jq: Option<String>,
+    /// Keep every match, one per line, or its first capture group when it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regex: Option<String>,
+}
+
+fn render(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn apply_pointer(output: &str, pointer: &str) -> Result<String> {
+    let value: Value = serde_json::from_str(output).context("output is not JSON")?;
+    value
+        .pointer(pointer)
+        .map(render)
+        .ok_or_else(|| anyhow!("pointer `{}` matched nothing", pointer))
+}
+
+async fn apply_jq(output: &str, filter: &str) -> Result<String> {
+    let mut child = tokio::process::Command::new("jq")
+        .arg("-c")
+        .arg(filter)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => anyhow!("`jq` is not installed"),
+            _ => anyhow!("failed to start jq: {}", e),
+        })?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(output.as_bytes()).await?;
+    }
+    let result = tokio::time::timeout(JQ_TIMEOUT, child.wait_with_output())
+        .await
+        .map_err(|_| anyhow!("jq timed out"))??;
+    if !result.status.success() {
+        return Err(anyhow!(
+            "jq failed: {}",
+            String::from_utf8_lossy(&result.stderr).trim()
+        ));
+    }
+    // `-c` prints JSON strings quoted; unquote single string results.
+    let text = String::from_utf8_lossy(&result.stdout).trim().to_string();
+    Ok(match serde_json::from_str::<Value>(&text) {
+        Ok(Value::String(inner)) => inner,
+        _ => text,
+    })
+}
+
+fn apply_regex(output: &str, pattern: &str) -> Result<String> {
+    let re = Regex::new(pattern).with_context(|| format!("invalid regex `{}`", pattern))?;
+    let matches: Vec<&str> = re
+        .captures_iter(output)
+        .filter_map(|caps| caps.get(1).or_else(|| caps.get(0)))
+        .map(|m| m.as_str())
+        .collect();
+    if matches.is_empty() {
+        return Err(anyhow!("regex `{}` matched nothing", pattern));
+    }
+    Ok(matches.join("\n"))
+}
+
+impl OutputTransform {
+    pub async fn apply(&self, output: &str) -> Result<String> {
+        let mut current = output.to_string();
+        if let Some(pointer) = &self.pointer {
+            current = apply_pointer(&current, pointer)?;
+        }
+        if let Some(filter) = &self.jq {
+            current = apply_jq(&current, filter).await?;
+        }
+        if let Some(pattern) = &self.regex {
+            current = apply_regex(&current, pattern)?;
+        }
+        Ok(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_output_transform_extracts_pointer_then_regex() {
+        let output = r#"{"data":{"items":[{"name":"alpha v1.2"},{"name":"beta"}]}}"#;
+
+        let pointer = OutputTransform {
+            pointer: Some("/data/items/0/name".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(pointer.apply(output).await.unwrap(), "alpha v1.2");
+
+        let chained = OutputTransform {
+            pointer: Some("/data/items".to_string()),
+            regex: Some(r#""name":"(\w+)"#.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(chained.apply(output).await.unwrap(), "alpha\nbeta");
+
+        let missing = OutputTransform {
+            pointer: Some("/nope".to_string()),
+            ..Default::default()
+        };
+        assert!(missing.apply(output).await.is_err());
+        assert!(
+            OutputTransform::default()
+                .apply("plain")
+                .await
+                .is_ok_and(|out| out == "plain")
+        );
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/patch.rs
+ * Responsibility: Apply unified diffs to workspace text for the `patch` tool.
+ */
+
+#[derive(Debug, Clone, PartialEq)]
+struct Hunk {
+    /// 1-indexed start line in the original file, as declared by the hunk header.
+    old_start: usize,
+    old_lines: Vec<String>,
+    new_lines: Vec<String>,
+}
+
+fn parse_hunk_start(header: &str) -> Result<usize, String> {
+    // "@@ -12,5 +12,7 @@ optional section"
+    let old_range = header
+        .trim_start_matches('@')
+        .split_whitespace()
+        .find(|part| part.starts_with('-'))
+        .ok_or_else(|| format!("malformed hunk header `{}`", header))?;
+    old_range[1..]
+        .split(',')
+        .next()
+        .and_then(|start| start.parse::<usize>().ok())
+        .ok_or_else(|| format!("malformed hunk header `{}`", header))
+}
+
+fn parse_hunks(diff: &str) -> Result<Vec<Hunk>, String> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+    for line in diff.lines() {
+        if line.starts_with("@@") {
+            hunks.push(Hunk {
+                old_start: parse_hunk_start(line)?,
+                old_lines: Vec::new(),
+                new_lines: Vec::new(),
+            });
+            continue;
+        }
+        let Some(hunk) = hunks.last_mut() else {
+            // `diff --git`, `---`, `+++`, and any prose before the first hunk.
+            continue;
+        };
+        if line.starts_with('\\') {
+            // "\ No newline at end of file"
+            continue;
+        }
+        match line.chars().next() {
+            Some('+') => hunk.new_lines.push(line[1..].to_string()),
+            Some('-') => hunk.old_lines.push(line[1..].to_string()),
+            Some(' ') => {
+                hunk.old_lines.push(line[1..].to_string());
+                hunk.new_lines.push(line[1..].to_string());
+            }
+            // Models often drop the leading space on blank context lines.
+            None => {
+                hunk.old_lines.push(String::new());
+                hunk.new_lines.push(String::new());
+            }
+            Some(_) => return Err(format!("unexpected line in hunk: `{}`", line)),
+        }
+    }
+
+    if hunks.is_empty() {
+        return Err("no `@@` hunks found; provide a unified diff".to_string());
+    }
+    Ok(hunks)
+}
+
+fn matches_at(lines: &[String], at: usize, expected: &[String]) -> bool {
+    at + expected.len() <= lines.len()
+        && lines[at..at + expected.len()]
+            .iter()
+            .zip(expected)
+            .all(|(line, expected)| line.trim_end() == expected.trim_end())
+}
+
+/// Closest position at or after `floor` where `expected` matches, preferring `hint`.
+fn locate(lines: &[String], expected: &[String], hint: usize, floor: usize) -> Option<usize> {
+    let last = lines.len().checked_sub(expected.len())?;
+    if floor > last {
+        return None;
+    }
+    let mut candidates: Vec<usize> = (floor..=last).collect();
+    candidates.sort_by_key(|at| at.abs_diff(hint));
+    candidates
+        .into_iter()
+        .find(|at| matches_at(lines, *at, expected))
+}
+
+/// Apply every hunk of `diff` to `original`, tolerating shifted line numbers.
+/// Either all hunks apply or an error names the first one that did not.
+pub fn apply_unified_diff(original: &str, diff: &str) -> Result<String, String> {
+    let hunks = parse_hunks(diff)?;
+    let mut lines: Vec<String> = original.lines().map(str::to_string).collect();
+    let mut floor = 0usize;
+    // Drift between the line numbers the diff declares and where hunks actually landed.
+    let mut offset: isize = 0;
+
+    for (index, hunk) in hunks.iter().enumerate() {
+        let declared = hunk.old_start.saturating_sub(1) as isize;
+        let at = if hunk.old_lines.is_empty() {
+            // Pure insertion: `@@ -N,0` inserts after line N.
+            (hunk.old_start as isize + offset).clamp(floor as isize, lines.len() as isize) as usize
+        } else {
+            let hint = (declared + offset).max(0) as usize;
+            locate(&lines, &hunk.old_lines, hint, floor).ok_or_else(|| {
+                format!(
+                    "hunk {} (near line {}) does not match the file; re-read it and retry",
+                    index + 1,
+                    hunk.old_start
+                )
+            })?
+        };
+
+        lines.splice(
+            at..at + hunk.old_lines.len(),
+            hunk.new_lines.iter().cloned(),
+        );
+        offset =
+            at as isize - declared + hunk.new_lines.len() as isize - hunk.old_lines.len() as isize;
+        floor = at + hunk.new_lines.len();
+    }
+
+    let mut patched = lines.join("\n");
+    if original.ends_with('\n') || (original.is_empty() && !patched.is_empty()) {
+        patched.push('\n');
+    }
+    Ok(patched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_unified_diff_handles_offsets_and_multiple_hunks() {
+        let original = "title\n\none\ntwo\nthree\nfour\nfive\nsix\n";
+        // Line numbers are off by two, as if written against an older version.
+        let diff = "--- a/notes.md\n+++ b/notes.md\n@@ -1,2 +1,3 @@\n one\n-two\n+2\n+2.5\n@@ -4,2 +5,2 @@\n five\n-six\n+6\n";
+
+        let patched = apply_unified_diff(original, diff).unwrap();
+        assert_eq!(patched, "title\n\none\n2\n2.5\nthree\nfour\nfive\n6\n");
+    }
+
+    #[test]
+    fn test_apply_unified_diff_rejects_mismatched_context() {
+        let error = apply_unified_diff("a\nb\nc\n", "@@ -1,2 +1,2 @@\n a\n-x\n+y\n").unwrap_err();
+        assert!(error.contains("hunk 1"));
+        assert!(apply_unified_diff("a\n", "just prose").is_err());
+    }
+}
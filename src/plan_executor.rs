@@ -5,6 +5,7 @@
  */
 
 use crate::config::Config;
+use crate::context_cache;
 use crate::execution_contract::{
     ExecutableRoute, ExecutionFinalState, ExecutionOutcome, ExecutionPlan, ExecutionStepKind,
     ExecutionStepTrace, ExecutionTrace, PlanConfidence, PlanIntent, PlanStep, ResponseStyle,
@@ -16,7 +17,7 @@ use crate::task_response::{
     append_result_confidence_notice, ask_for_missing_response, reject_route_response,
     respond_step_fallback, tool_failure_response,
 };
-use crate::tools::dispatch_tool;
+use crate::tools::{dispatch_tool, is_read_only_tool};
 use anyhow::Result;
 use std::path::Path;
 
@@ -110,6 +111,8 @@ pub(crate) struct PlanExecutionContext<'a> {
     pub(crate) config: std::sync::Arc<Config>,
     pub(crate) channel_id: &'a str,
     pub(crate) system_prompt: &'a str,
+    /// Blackboard whose persisted session records each finished step.
+    pub(crate) blackboard: Option<&'a Path>,
 }
 
 fn build_respond_prompt(
@@ -137,15 +140,16 @@ async fn execute_respond_step(
     let observation = last_output.clone().unwrap_or_default();
     let response_prompt = build_respond_prompt(user_text, &observation, style, guidance);
 
-    match llm::generate_turn(
+    match context_cache::generate_channel_turn(
+        ctx.base_path,
+        ctx.channel_id,
         ctx.system_prompt,
+        &ctx.config,
         vec![llm::Message {
             role: llm::MessageRole::User,
             parts: vec![llm::MultimodalPart::text(response_prompt)],
         }],
-        &ctx.config.gemini.api_key,
-        &ctx.config.gemini.model,
-        0.4,
+        ctx.config.gemini.temperature.unwrap_or(0.4),
         None,
     )
     .await?
@@ -214,6 +218,33 @@ pub(crate) async fn execute_conversational_route(
     }
 }
 
+/// Why `steps` call more tools than `runtime.max_turns`, or more read-only tools than
+/// `runtime.read_only_budget`, allow; `None` when the plan fits.
+fn budget_violation(steps: &[PlanStep], config: &Config) -> Option<String> {
+    let calls: Vec<&str> = steps
+        .iter()
+        .filter_map(|step| match step {
+            PlanStep::CallTool { call } => Some(call.tool_name.as_str()),
+            _ => None,
+        })
+        .collect();
+    let runtime = &config.runtime;
+    if calls.len() > runtime.max_turns {
+        return Some(format!(
+            "The plan needs {} tool calls, but `runtime.max_turns` allows {}.",
+            calls.len(),
+            runtime.max_turns
+        ));
+    }
+    let lookups = calls.iter().filter(|name| is_read_only_tool(name)).count();
+    (lookups > runtime.read_only_budget).then(|| {
+        format!(
+            "The plan needs {} read-only lookups, but `runtime.read_only_budget` allows {}.",
+            lookups, runtime.read_only_budget
+        )
+    })
+}
+
 async fn execute_plan(
     plan: ExecutionPlan,
     ctx: PlanExecutionContext<'_>,
@@ -223,6 +254,9 @@ async fn execute_plan(
         confidence,
         steps,
     } = plan;
+    if let Some(reason) = budget_violation(&steps, &ctx.config) {
+        return Ok(finish_rejected_route(reason));
+    }
     let mut state = PlanExecutionState::new(intent, confidence);
     let user_text = ctx.workset.text();
 
@@ -278,6 +312,15 @@ async fn flush_tool_batch(
     let batch_len = batch.len();
 
     for (tool_name, result) in results {
+        if let Some(blackboard) = ctx.blackboard {
+            crate::session_store::record_step(
+                ctx.base_path,
+                blackboard,
+                &tool_name,
+                !result.is_error,
+                &result.output,
+            );
+        }
         if result.is_error {
             let response = tool_failure_response(&tool_name, &result.output);
             batch.clear();
@@ -313,7 +356,7 @@ async fn flush_tool_batch(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DiscordConfig, GeminiConfig, RuntimeConfig};
+    use crate::config::{Config, DiscordConfig, GeminiConfig};
     use crate::execution_contract::ToolCallSpec;
     use serde_json::json;
     use tempfile::tempdir;
@@ -323,13 +366,15 @@ mod tests {
             gemini: GeminiConfig {
                 api_key: "fake".to_string(),
                 model: "fake-model".to_string(),
+                temperature: None,
             },
             discord: DiscordConfig {
                 token: "fake".to_string(),
                 guild_id: None,
                 channel_mappings: None,
+                ..Default::default()
             },
-            runtime: RuntimeConfig::default(),
+            ..Default::default()
         }
     }
 
@@ -344,6 +389,7 @@ mod tests {
             config: std::sync::Arc::new(config.clone()),
             channel_id: "0",
             system_prompt: "test system prompt",
+            blackboard: None,
         }
     }
 
@@ -447,6 +493,38 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn plan_over_the_read_only_budget_is_rejected_before_running() {
+        let dir = tempdir().unwrap();
+        let mut config = test_config();
+        config.runtime.read_only_budget = 1;
+        let workset = Workset::new(vec!["look around".to_string()]);
+        let ls = PlanStep::CallTool {
+            call: ToolCallSpec {
+                tool_name: "ls".to_string(),
+                args: json!({ "path": "." }),
+            },
+        };
+        let plan = ExecutionPlan {
+            intent: PlanIntent::ToolExecution,
+            confidence: PlanConfidence::High,
+            steps: vec![ls.clone(), ls],
+        };
+
+        let outcome = execute_conversational_route(
+            ExecutableRoute::PlanAndExecute { plan },
+            test_ctx(&workset, dir.path(), &config),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome.final_state, ExecutionFinalState::Rejected);
+        assert!(outcome.user_response.contains(
+            "The plan needs 2 read-only lookups, but `runtime.read_only_budget` allows 1."
+        ));
+        assert_eq!(outcome.trace.steps.len(), 1);
+    }
+
     #[tokio::test]
     async fn successful_tool_only_plan_sets_completed_final_state() {
         let dir = tempdir().unwrap();
@@ -0,0 +1,109 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/profiles.rs
+ * Responsibility: Named `profiles:` in tellar.yml, each overlaying its own tokens, guild, and models on the shared config.
+ */
+
+use anyhow::{Result, bail};
+use serde_yml::Value;
+
+pub const PROFILES_KEY: &str = "profiles";
+/// Selects a profile when `--profile` is not given.
+pub const PROFILE_ENV: &str = "TELLAR_PROFILE";
+
+/// The profile to run: `--profile` when given, else `TELLAR_PROFILE`.
+pub fn resolve(flag: Option<&str>) -> Option<String> {
+    flag.map(str::to_string)
+        .or_else(|| std::env::var(PROFILE_ENV).ok())
+}
+
+/// Merge `overlay` into `base`: mappings key by key, anything else replaced.
+fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Mapping(base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Remove `profiles:` from the parsed config and overlay the named one. No name keeps the
+/// shared settings; an unknown name is an error listing the defined profiles.
+pub fn select(config: &mut Value, profile: Option<&str>) -> Result<()> {
+    let profiles = match config {
+        Value::Mapping(map) => map.remove(PROFILES_KEY),
+        _ => None,
+    };
+    let Some(name) = profile.map(str::trim).filter(|name| !name.is_empty()) else {
+        return Ok(());
+    };
+    let mut profiles = match profiles {
+        Some(Value::Mapping(profiles)) => profiles,
+        _ => bail!(
+            "Profile `{}` requested but tellar.yml defines no profiles",
+            name
+        ),
+    };
+    let Some(overlay) = profiles.remove(name) else {
+        let known: Vec<&str> = profiles.keys().filter_map(Value::as_str).collect();
+        bail!(
+            "Unknown profile `{}`; tellar.yml defines: {}",
+            name,
+            known.join(", ")
+        );
+    };
+    merge(config, overlay);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    const YAML: &str = "\
+gemini:
+  api_key: shared-key
+  model: gemini-2.5-flash
+discord:
+  token: home-token
+runtime:
+  max_turns: 8
+profiles:
+  work:
+    discord:
+      token: work-token
+      guild_id: \"111222333\"
+    gemini:
+      model: gemini-2.5-pro
+";
+
+    #[test]
+    fn test_profiles_overlay_the_shared_config() {
+        let mut value: Value = serde_yml::from_str(YAML).unwrap();
+        select(&mut value, None).unwrap();
+        let home: Config = serde_yml::from_value(value).unwrap();
+        assert_eq!(home.discord.token, "home-token");
+        assert_eq!(home.gemini.model, "gemini-2.5-flash");
+
+        let mut value: Value = serde_yml::from_str(YAML).unwrap();
+        select(&mut value, Some("work")).unwrap();
+        let work: Config = serde_yml::from_value(value).unwrap();
+        assert_eq!(work.discord.token, "work-token");
+        assert_eq!(work.discord.guild_id.as_deref(), Some("111222333"));
+        assert_eq!(work.gemini.model, "gemini-2.5-pro");
+        assert_eq!(work.gemini.api_key, "shared-key");
+        assert_eq!(work.runtime.max_turns, 8);
+
+        let mut value: Value = serde_yml::from_str(YAML).unwrap();
+        let error = select(&mut value, Some("lab")).unwrap_err().to_string();
+        assert_eq!(error, "Unknown profile `lab`; tellar.yml defines: work");
+    }
+}
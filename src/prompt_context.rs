@@ -4,6 +4,7 @@
  * Responsibility: Prompt loading and prompt-related test helpers.
  */
 
+use crate::log_info;
 use once_cell::sync::Lazy;
 
 use std::collections::HashMap;
@@ -50,7 +51,8 @@ pub(crate) fn load_unified_prompt(base_path: &Path, channel_id: &str) -> String
     if let Some(channel_prompt_path) = channel_prompt_path
         && channel_prompt_path.exists()
             && let Ok(channel_prompt) = std::fs::read_to_string(channel_prompt_path) {
-                println!(
+                log_info!(
+                    "steward",
                     "🎭 Loading channel-specific identity for ID: {}",
                     channel_id
                 );
@@ -103,17 +105,18 @@ pub(crate) async fn update_history_with_steering(
         .and_then(|m| m.parts.first())
         .and_then(|p| p.text.as_ref());
 
-    if let Some(new_msg) = last_blackboard_msg {
-        if Some(new_msg) != last_history_msg {
-            println!(
-                "📥 Steering: New user message detected mid-loop: '{}'",
-                new_msg
-            );
-            messages.push(llm::Message {
-                role: llm::MessageRole::User,
-                parts: vec![llm::MultimodalPart::text(new_msg.clone())],
-            });
-        }
+    if let Some(new_msg) = last_blackboard_msg
+        && Some(new_msg) != last_history_msg
+    {
+        log_info!(
+            "steward",
+            "📥 Steering: New user message detected mid-loop: '{}'",
+            new_msg
+        );
+        messages.push(llm::Message {
+            role: llm::MessageRole::User,
+            parts: vec![llm::MultimodalPart::text(new_msg.clone())],
+        });
     }
 
     Ok(())
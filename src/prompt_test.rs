@@ -0,0 +1,272 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/prompt_test.rs
+ * Responsibility: `tellarctl prompt test`: run one message through the real prompt, router, and plan with every tool mocked, and report each turn.
+ */
+
+use crate::config::Config;
+use crate::execution_contract::{PlanStep, RequestRoute};
+use crate::input::Workset;
+use crate::plan_executor::{PlanExecutionContext, execute_conversational_route};
+use crate::routing_catalog::collect_routing_tool_catalog;
+use crate::session::{conversation_prompt, resolve_task_route};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Channel ID that selects the base identity only, as local chat does.
+pub const BASE_CHANNEL_ID: &str = "0";
+
+tokio::task_local! {
+    static MOCKED_CALLS: RefCell<Vec<MockedCall>>;
+}
+
+/// A tool call the plan made, answered by the mock instead of the tool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockedCall {
+    pub tool: String,
+    pub args: Value,
+    pub output: String,
+}
+
+/// What one test run did, turn by turn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptTestReport {
+    pub channel_id: String,
+    pub model: String,
+    pub system_prompt: String,
+    /// Tools the router was offered, sorted.
+    pub tools: Vec<String>,
+    /// The route the router chose, one line per plan step.
+    pub route: Vec<String>,
+    pub calls: Vec<MockedCall>,
+    /// Executed steps, as `#1 CallTool:Completed(ls)`.
+    pub steps: Vec<String>,
+    pub final_state: String,
+    pub response: String,
+}
+
+fn mocked_output(tool: &str, args: &Value) -> String {
+    format!(
+        "[prompt test] `{}` was not executed; treat it as having succeeded with: {}",
+        tool, args
+    )
+}
+
+/// Inside a prompt test, record the call and answer it without running the tool.
+pub(crate) fn mock_tool_call(tool: &str, args: &Value) -> Option<String> {
+    MOCKED_CALLS
+        .try_with(|calls| {
+            let output = mocked_output(tool, args);
+            calls.borrow_mut().push(MockedCall {
+                tool: tool.to_string(),
+                args: args.clone(),
+                output: output.clone(),
+            });
+            output
+        })
+        .ok()
+}
+
+fn describe_route(route: &RequestRoute) -> Vec<String> {
+    match route {
+        RequestRoute::PlanAndExecute { plan } => {
+            let mut lines = vec![format!(
+                "plan (intent={}, confidence={})",
+                plan.intent.label(),
+                plan.confidence.label()
+            )];
+            for (index, step) in plan.steps.iter().enumerate() {
+                let step = match step {
+                    PlanStep::CallTool { call } => {
+                        format!("CallTool `{}` {}", call.tool_name, call.args)
+                    }
+                    PlanStep::Respond { style, guidance } => match guidance {
+                        Some(guidance) => format!("Respond {}: {}", style.label(), guidance),
+                        None => format!("Respond {}", style.label()),
+                    },
+                    PlanStep::AskForMissing { fields, prompt } => format!(
+                        "AskForMissing [{}]{}",
+                        fields.join(", "),
+                        prompt
+                            .as_deref()
+                            .map(|prompt| format!(": {}", prompt))
+                            .unwrap_or_default()
+                    ),
+                };
+                lines.push(format!("{}. {}", index + 1, step));
+            }
+            lines
+        }
+        RequestRoute::NeedsInput { fields, prompt } => vec![format!(
+            "needs_input [{}]{}",
+            fields.join(", "),
+            prompt
+                .as_deref()
+                .map(|prompt| format!(": {}", prompt))
+                .unwrap_or_default()
+        )],
+        RequestRoute::Reject { reason } => vec![format!("reject: {}", reason)],
+    }
+}
+
+/// Answer `message` as a conversational turn in `channel_id` would, without reading or
+/// writing a blackboard, session, trace, or commit. Every tool call is mocked.
+pub async fn run(
+    base_path: &Path,
+    config: Arc<Config>,
+    channel_id: &str,
+    message: &str,
+) -> anyhow::Result<PromptTestReport> {
+    let workset = Workset::new(vec![message.to_string()]);
+    let text = workset.text();
+    let (config, system_prompt) = conversation_prompt(base_path, config, channel_id, &text).await;
+    let mut tools: Vec<String> = collect_routing_tool_catalog(base_path, &config, &text)
+        .allowed_tools
+        .into_iter()
+        .collect();
+    tools.sort();
+
+    let route = resolve_task_route(
+        base_path,
+        Arc::clone(&config),
+        &workset,
+        "Prompt test",
+        "This task is not ready to execute. Provide the exact target or missing inputs.",
+    )
+    .await;
+    let planned = describe_route(&route);
+    let (outcome, calls) = MOCKED_CALLS
+        .scope(RefCell::new(Vec::new()), async {
+            let outcome = execute_conversational_route(
+                route.into_executable(),
+                PlanExecutionContext {
+                    workset: &workset,
+                    base_path,
+                    config: Arc::clone(&config),
+                    channel_id,
+                    system_prompt: &system_prompt,
+                    blackboard: None,
+                },
+            )
+            .await;
+            (outcome, MOCKED_CALLS.with(|calls| calls.take()))
+        })
+        .await;
+    let outcome = outcome?;
+
+    Ok(PromptTestReport {
+        channel_id: channel_id.to_string(),
+        model: config.gemini.model.clone(),
+        system_prompt,
+        tools,
+        route: planned,
+        calls,
+        steps: outcome
+            .trace
+            .view()
+            .steps
+            .iter()
+            .map(|step| {
+                format!(
+                    "#{} {}:{}({})",
+                    step.index,
+                    step.label,
+                    step.outcome.as_str(),
+                    step.detail
+                )
+            })
+            .collect(),
+        final_state: outcome.final_state.label().to_string(),
+        response: outcome.user_response,
+    })
+}
+
+impl PromptTestReport {
+    /// The trace for the terminal; the full system prompt only when `show_prompt`.
+    pub fn render(&self, show_prompt: bool) -> String {
+        let mut lines = vec![format!(
+            "🧪 Prompt test on channel {} with model `{}`",
+            self.channel_id, self.model
+        )];
+        lines.push(format!(
+            "\n── System prompt: {} chars",
+            self.system_prompt.chars().count()
+        ));
+        if show_prompt {
+            lines.push(self.system_prompt.trim_end().to_string());
+        }
+        lines.push(format!(
+            "\n── Tools offered: {}\n{}",
+            self.tools.len(),
+            self.tools.join(", ")
+        ));
+        lines.push("\n── Turn 1: router".to_string());
+        lines.extend(self.route.iter().cloned());
+        for (index, call) in self.calls.iter().enumerate() {
+            lines.push(format!(
+                "\n── Turn {}: tool `{}` (mocked)",
+                index + 2,
+                call.tool
+            ));
+            lines.push(format!("args: {}", call.args));
+            lines.push(format!("output: {}", call.output));
+        }
+        lines.push("\n── Steps".to_string());
+        lines.extend(self.steps.iter().cloned());
+        lines.push(format!("\n── Reply ({})", self.final_state));
+        lines.push(self.response.clone());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_prompt_test_mocks_tools_and_touches_nothing() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("agents")).unwrap();
+        std::fs::write(
+            dir.path().join("agents/AGENTS.md"),
+            "You are the test steward.",
+        )
+        .unwrap();
+        let mut config = Config::default();
+        config.gemini.model = crate::mock_llm::MOCK_MODEL.to_string();
+
+        let report = run(
+            dir.path(),
+            Arc::new(config),
+            BASE_CHANNEL_ID,
+            "list the files",
+        )
+        .await
+        .unwrap();
+        assert!(
+            report
+                .system_prompt
+                .starts_with("You are the test steward.")
+        );
+        assert!(report.tools.iter().any(|tool| tool == "ls"));
+        assert!(report.route[1].starts_with("1. CallTool `ls`"));
+        assert_eq!(report.calls.len(), 1);
+        assert_eq!(report.calls[0].tool, "ls");
+        assert_eq!(report.final_state, "Completed");
+        assert!(
+            report
+                .response
+                .contains("[prompt test] `ls` was not executed")
+        );
+        assert!(!dir.path().join("brain/sessions").exists());
+        assert!(!dir.path().join("channels").exists());
+
+        let rendered = report.render(false);
+        assert!(rendered.contains("\n── Turn 2: tool `ls` (mocked)\nargs: {"));
+        assert!(!rendered.contains("You are the test steward."));
+        assert!(mock_tool_call("ls", &Value::Null).is_none());
+    }
+}
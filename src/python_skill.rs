@@ -0,0 +1,113 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/python_skill.rs
+ * Responsibility: Manage per-skill Python virtualenvs and build the process for `python` skill tools.
+ */
+
+use crate::child_env::ChildEnv;
+use crate::skills::SkillMetadata;
+use anyhow::{Context, Result, anyhow, bail};
+use std::path::{Path, PathBuf};
+use std::process::Command as StdCommand;
+use tokio::process::Command;
+
+/// Virtualenv folder inside each Python skill.
+pub const VENV_DIR: &str = ".venv";
+const REQUIREMENTS_FILE: &str = "requirements.txt";
+
+pub fn venv_python(skill_dir: &Path) -> PathBuf {
+    skill_dir.join(VENV_DIR).join("bin").join("python")
+}
+
+pub fn needs_python_env(skill: &SkillMetadata) -> bool {
+    skill.tools.values().any(|tool| tool.python.is_some())
+}
+
+fn run(command: &mut StdCommand, what: &str) -> Result<()> {
+    let output = command
+        .output()
+        .with_context(|| format!("failed to start {}", what))?;
+    if !output.status.success() {
+        bail!(
+            "{} failed: {}",
+            what,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn has_uv() -> bool {
+    StdCommand::new("uv")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Create `skill_dir/.venv` if needed and install `requirements.txt` into it, preferring
+/// `uv` and falling back to `python3 -m venv` with pip. Returns the tool that was used.
+pub fn prepare_venv(skill_dir: &Path) -> Result<&'static str> {
+    let python = venv_python(skill_dir);
+    let requirements = skill_dir.join(REQUIREMENTS_FILE);
+    let uv = has_uv();
+
+    if !python.exists() {
+        let mut create = if uv {
+            let mut command = StdCommand::new("uv");
+            command.args(["venv", "--quiet", VENV_DIR]);
+            command
+        } else {
+            let mut command = StdCommand::new("python3");
+            command.args(["-m", "venv", VENV_DIR]);
+            command
+        };
+        run(create.current_dir(skill_dir), "creating the virtualenv")?;
+    }
+
+    if requirements.exists() {
+        let mut install = if uv {
+            let mut command = StdCommand::new("uv");
+            command.args(["pip", "install", "--quiet", "--python"]);
+            command.arg(&python);
+            command
+        } else {
+            let mut command = StdCommand::new(&python);
+            command.args(["-m", "pip", "install", "--quiet"]);
+            command
+        };
+        install.arg("-r").arg(&requirements);
+        run(
+            install.current_dir(skill_dir),
+            "installing requirements.txt",
+        )?;
+    }
+    Ok(if uv { "uv" } else { "venv + pip" })
+}
+
+/// The process for one call of a `python` skill tool: the skill's venv interpreter running
+/// `entry`. Arguments are written to its stdin by the caller.
+pub(crate) fn python_command(entry: &str, skill_dir: &Path, env: &ChildEnv) -> Result<Command> {
+    if !crate::wasm_skill::is_contained_path(entry) {
+        return Err(anyhow!(
+            "Python entrypoint `{}` must be a path inside the skill folder",
+            entry
+        ));
+    }
+    let script = skill_dir.join(entry);
+    if !script.is_file() {
+        return Err(anyhow!("Python entrypoint `{}` does not exist", entry));
+    }
+    let python = venv_python(skill_dir);
+    if !python.exists() {
+        return Err(anyhow!(
+            "Skill has no Python environment yet. Run `tellarctl skill test {}` or reinstall the skill to create {}.",
+            skill_dir.display(),
+            VENV_DIR
+        ));
+    }
+
+    let mut command = Command::new(python);
+    env.apply(&mut command);
+    command.arg(script);
+    Ok(command)
+}
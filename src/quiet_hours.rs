@@ -0,0 +1,206 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/quiet_hours.rs
+ * Responsibility: Quiet hours and holidays: hold scheduled injections and unsolicited posts until an allowed time.
+ */
+
+use crate::config::{Config, QuietHoursConfig};
+use crate::datetime::Zone;
+use crate::{log_info, log_warn};
+use chrono::{DateTime, Days, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use std::time::Duration;
+
+/// Days walked before giving up, so a calendar of nothing but holidays cannot spin forever.
+const MAX_DAYS: usize = 400;
+
+fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+fn is_holiday(settings: &QuietHoursConfig, date: NaiveDate) -> bool {
+    let full = date.format("%Y-%m-%d").to_string();
+    let yearly = date.format("%m-%d").to_string();
+    settings
+        .holidays
+        .iter()
+        .map(|day| day.trim())
+        .any(|day| day == full || day == yearly)
+}
+
+/// Wall-clock time at which the quiet window holding `at` ends, if it holds it.
+fn window_end(settings: &QuietHoursConfig, at: NaiveDateTime) -> Option<NaiveDateTime> {
+    let (start, end) = (parse_time(&settings.start)?, parse_time(&settings.end)?);
+    let time = at.time();
+    let end_today = at.date().and_time(end);
+    if start < end {
+        (start <= time && time < end).then_some(end_today)
+    } else if start > end {
+        if time >= start {
+            Some(end_today + Days::new(1))
+        } else {
+            (time < end).then_some(end_today)
+        }
+    } else {
+        None
+    }
+}
+
+/// The first moment at or after `now` outside quiet hours and holidays, or `None` when
+/// `now` itself is allowed.
+pub(crate) fn deferred_until(
+    settings: &QuietHoursConfig,
+    zone: Zone,
+    now: DateTime<FixedOffset>,
+) -> Option<DateTime<FixedOffset>> {
+    if !settings.enabled {
+        return None;
+    }
+    let start = zone.convert(now).naive_local();
+    let mut at = start;
+    for _ in 0..MAX_DAYS * 2 {
+        if is_holiday(settings, at.date()) {
+            at = (at.date() + Days::new(1)).and_time(NaiveTime::MIN);
+        } else if let Some(end) = window_end(settings, at) {
+            at = end;
+        } else {
+            break;
+        }
+    }
+    if at == start {
+        return None;
+    }
+    // A wall-clock time skipped by a DST jump falls back to an hour later.
+    zone.localize(at)
+        .or_else(|_| zone.localize(at + chrono::TimeDelta::hours(1)))
+        .ok()
+}
+
+fn quiet_zone(config: &Config) -> Zone {
+    let name = config
+        .quiet_hours
+        .timezone
+        .as_deref()
+        .or(config.runtime.timezone.as_deref());
+    Zone::parse(name).unwrap_or_else(|e| {
+        log_warn!("runtime", "⚠️ quiet_hours: {}; using local time", e);
+        Zone::Local
+    })
+}
+
+/// How long to hold back something due now, or `None` outside quiet hours.
+pub fn delay(config: &Config) -> Option<Duration> {
+    let now = Local::now().fixed_offset();
+    let until = deferred_until(&config.quiet_hours, quiet_zone(config), now)?;
+    Some((until - now).to_std().unwrap_or_default())
+}
+
+/// Sleep through quiet hours before doing `what`.
+pub async fn wait_until_allowed(config: &Config, what: &str) {
+    if let Some(wait) = delay(config) {
+        log_info!(
+            "runtime",
+            "🌙 Quiet hours: holding {} for {}m",
+            what,
+            wait.as_secs().div_ceil(60)
+        );
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Post an unsolicited message now, or hold it until quiet hours or degraded mode end. Held
+/// messages are kept in memory only, so a restart meanwhile drops them.
+pub async fn notify(config: &Config, channel_id: &str, text: String) -> anyhow::Result<()> {
+    if crate::api_health::is_degraded() {
+        log_info!(
+            "runtime",
+            "🚧 Degraded mode: holding a post to {}",
+            channel_id
+        );
+        crate::api_health::hold(channel_id, text);
+        return Ok(());
+    }
+    let token = config.discord.token.clone();
+    let Some(wait) = delay(config) else {
+        crate::discord::client::send_bot_message_or_file(&token, channel_id, &text).await?;
+        return Ok(());
+    };
+    log_info!(
+        "runtime",
+        "🌙 Quiet hours: holding a post to {} for {}m",
+        channel_id,
+        wait.as_secs().div_ceil(60)
+    );
+    let channel_id = channel_id.to_string();
+    tokio::spawn(async move {
+        tokio::time::sleep(wait).await;
+        if let Err(e) =
+            crate::discord::client::send_bot_message_or_file(&token, &channel_id, &text).await
+        {
+            log_warn!(
+                "runtime",
+                "⚠️ Failed to send held post to {}: {:?}",
+                channel_id,
+                e
+            );
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    #[test]
+    fn test_deferred_until_skips_quiet_window_and_holidays() {
+        let utc = Zone::parse(Some("UTC")).unwrap();
+        let mut settings = QuietHoursConfig {
+            enabled: true,
+            holidays: vec!["12-25".to_string(), "2026-03-04".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            deferred_until(&settings, utc, at("2026-03-02T03:00:00Z")),
+            Some(at("2026-03-02T07:00:00Z"))
+        );
+        assert_eq!(
+            deferred_until(&settings, utc, at("2026-03-02T23:30:00Z")),
+            Some(at("2026-03-03T07:00:00Z"))
+        );
+        assert_eq!(
+            deferred_until(&settings, utc, at("2026-03-02T12:00:00Z")),
+            None
+        );
+        // The night before a holiday runs on through the whole day to the next morning.
+        assert_eq!(
+            deferred_until(&settings, utc, at("2026-03-03T22:30:00Z")),
+            Some(at("2026-03-05T07:00:00Z"))
+        );
+        assert_eq!(
+            deferred_until(&settings, utc, at("2030-12-25T12:00:00Z")),
+            Some(at("2030-12-26T07:00:00Z"))
+        );
+        // Windows are read on the configured zone's wall clock.
+        let paris = Zone::parse(Some("Europe/Paris")).unwrap();
+        assert_eq!(
+            deferred_until(&settings, paris, at("2026-03-02T05:30:00Z")),
+            Some(at("2026-03-02T07:00:00+01:00"))
+        );
+
+        settings.start = "13:00".to_string();
+        settings.end = "14:00".to_string();
+        assert_eq!(
+            deferred_until(&settings, utc, at("2026-03-02T13:15:00Z")),
+            Some(at("2026-03-02T14:00:00Z"))
+        );
+        settings.enabled = false;
+        assert_eq!(
+            deferred_until(&settings, utc, at("2026-03-04T13:15:00Z")),
+            None
+        );
+    }
+}
@@ -0,0 +1,326 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/reminders.rs
+ * Responsibility: One-shot rituals: the `remind_me` tool and firing `at:` rituals into their channel.
+ */
+
+use crate::config::Config;
+use crate::datetime::{self, Zone};
+use crate::discord::{ingest_store, resolve_folder_by_id};
+use crate::tools::ToolExecutionResult;
+use crate::{log_info, log_warn};
+use chrono::{DateTime, FixedOffset, Local};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const REMIND_ME_TOOL_NAME: &str = "remind_me";
+const MAX_SLUG_CHARS: usize = 40;
+
+/// Header of a reminder ritual. It has no `status`, so the Steward never runs it as a
+/// thread; only the Rhythm reads it, and only because it is `source: local`.
+#[derive(Debug, Serialize)]
+struct ReminderHeader<'a> {
+    source: &'a str,
+    at: String,
+    origin_channel: &'a str,
+    injection_template: String,
+}
+
+fn string_arg<'a>(args: &'a Value, field: &str) -> Option<&'a str> {
+    args.get(field)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// When the reminder is due: `at` (or now), shifted by `in` when given.
+fn due_moment(args: &Value, zone: Zone) -> Result<DateTime<FixedOffset>, String> {
+    let at = string_arg(args, "at");
+    let delay = string_arg(args, "in");
+    if at.is_none() && delay.is_none() {
+        return Err("give `at` (a date and time) or `in` (a delay such as `90m`)".to_string());
+    }
+    let start = datetime::parse_moment(at.unwrap_or("now"), zone)?;
+    match delay {
+        Some(delay) => datetime::add_duration(start, delay, zone),
+        None => Ok(start),
+    }
+}
+
+fn reminder_path(base_path: &Path, due: DateTime<FixedOffset>, message: &str) -> PathBuf {
+    let slug: String = crate::entities::slugify(message)
+        .chars()
+        .take(MAX_SLUG_CHARS)
+        .collect();
+    let stem = format!("reminder-{}-{}", due.format("%Y%m%d-%H%M"), slug);
+    let rituals = base_path.join("rituals");
+    let mut path = rituals.join(format!("{}.md", stem));
+    let mut n = 2;
+    while path.exists() {
+        path = rituals.join(format!("{}-{}.md", stem, n));
+        n += 1;
+    }
+    path
+}
+
+/// Write the reminder ritual and return its path.
+pub(crate) fn create_reminder(
+    base_path: &Path,
+    channel_id: &str,
+    message: &str,
+    due: DateTime<FixedOffset>,
+) -> anyhow::Result<PathBuf> {
+    let header = serde_yml::to_string(&ReminderHeader {
+        source: "local",
+        at: due.to_rfc3339(),
+        origin_channel: channel_id,
+        injection_template: format!("- [ ] Reminder: {}", message),
+    })?;
+    let content = format!(
+        "---\n{}---\n# Reminder: {}\n\nSet on {} for {}. When it is due, the Rhythm adds the reminder to the channel's blackboard and moves this file to `rituals/history/`.\n",
+        header,
+        message,
+        Local::now().format("%Y-%m-%d %H:%M"),
+        due.format("%Y-%m-%d %H:%M %:z")
+    );
+    let path = reminder_path(base_path, due, message);
+    fs::create_dir_all(base_path.join("rituals"))?;
+    crate::journal::write(base_path, &path, &content)?;
+    Ok(path)
+}
+
+pub(crate) async fn run_remind_me_tool(
+    args: &Value,
+    base_path: &Path,
+    config: &Config,
+    channel_id: &str,
+) -> ToolExecutionResult {
+    let Some(message) = string_arg(args, "message") else {
+        return ToolExecutionResult::error("Error: Missing required argument `message`.");
+    };
+    let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+    if resolve_folder_by_id(base_path, channel_id).is_none() {
+        return ToolExecutionResult::error(
+            "Error: remind_me needs a channel to post into; this session has no channel folder.",
+        );
+    }
+    let zone_name = string_arg(args, "tz").or(config.runtime.timezone.as_deref());
+    let due = match Zone::parse(zone_name).and_then(|zone| due_moment(args, zone)) {
+        Ok(due) => due,
+        Err(e) => return ToolExecutionResult::error(format!("Error: {}", e)),
+    };
+    if due <= Local::now().fixed_offset() {
+        return ToolExecutionResult::error(format!(
+            "Error: {} is in the past; reminders must be due later.",
+            due.format("%Y-%m-%d %H:%M %:z")
+        ));
+    }
+
+    let (base, channel, text) = (
+        base_path.to_path_buf(),
+        channel_id.to_string(),
+        message.clone(),
+    );
+    let created =
+        tokio::task::spawn_blocking(move || create_reminder(&base, &channel, &text, due)).await;
+    let path = match created
+        .map_err(anyhow::Error::from)
+        .and_then(|created| created)
+    {
+        Ok(path) => path,
+        Err(e) => {
+            return ToolExecutionResult::error(format!("Error: Failed to save reminder: {}", e));
+        }
+    };
+    if let Err(e) = crate::rhythm::sync_job_from_file(&path).await {
+        log_warn!(
+            "rhythm",
+            "⚠️ Failed to schedule reminder {:?}: {:?}",
+            path,
+            e
+        );
+    }
+    ToolExecutionResult::success(format!(
+        "⏰ Reminder set for {}: {}\nSaved as rituals/{}; delete that file to cancel.",
+        due.format("%Y-%m-%d %H:%M %:z"),
+        message,
+        path.file_name().unwrap_or_default().to_string_lossy()
+    ))
+}
+
+/// Add a due one-shot ritual's TODO to its channel's daily log and move the ritual to
+/// `rituals/history/<today>/`. Returns the channel and the TODO, or `None` if the file is gone.
+pub(crate) async fn inscribe_one_shot(
+    base_path: &Path,
+    path: &Path,
+) -> anyhow::Result<Option<(String, String)>> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(None);
+    };
+    let header = crate::rhythm::parse_thread_metadata(&content).map(|(header, _)| header);
+    let todo = header
+        .as_ref()
+        .and_then(|header| header.injection_template.as_deref())
+        .map(str::trim)
+        .filter(|todo| !todo.is_empty())
+        .unwrap_or("- [ ] Reminder")
+        .to_string();
+    let channel_id = header
+        .and_then(|header| header.origin_channel)
+        .unwrap_or_else(|| "0".to_string());
+
+    let now = Local::now();
+    let inscribed = match resolve_folder_by_id(base_path, &channel_id) {
+        Some(folder) => {
+            ingest_store::append_to_message_log(
+                base_path,
+                &format!("{}/{}.md", folder, now.format("%Y-%m-%d")),
+                "Tellar Reminder",
+                "reminder",
+                &todo,
+                &format!("reminder-{}", uuid::Uuid::new_v4()),
+                &now.format("%Y-%m-%d %H:%M:%S").to_string(),
+                None,
+                Vec::new(),
+            )
+            .await
+        }
+        None => Err(anyhow::anyhow!(
+            "no channel folder found for channel_id {}",
+            channel_id
+        )),
+    };
+
+    // Archive even when inscribing failed, so a broken reminder does not fire on every start.
+    let today = now.format("%Y-%m-%d").to_string();
+    let history = base_path.join("rituals").join("history").join(&today);
+    fs::create_dir_all(&history)?;
+    fs::rename(path, history.join(path.file_name().unwrap_or_default()))?;
+
+    inscribed.map(|_| Some((channel_id, todo)))
+}
+
+/// Fire a due one-shot ritual: inscribe it, archive it, and post it to the channel.
+pub async fn fire_one_shot(base_path: &Path, path: &Path, config: &Config) {
+    crate::quiet_hours::wait_until_allowed(config, "a one-shot ritual").await;
+    match inscribe_one_shot(base_path, path).await {
+        Ok(Some((channel_id, todo))) => {
+            log_info!(
+                "rhythm",
+                "⏰ One-shot ritual fired into channel {}: {}",
+                channel_id,
+                todo
+            );
+            let todo = todo.trim_start_matches("- [ ] ");
+            let text = crate::i18n::Message::ReminderFired {
+                text: todo.strip_prefix("Reminder: ").unwrap_or(todo),
+            }
+            .render(crate::i18n::language());
+            if let Err(e) =
+                crate::discord::client::send_bot_message(&config.discord.token, &channel_id, &text)
+                    .await
+            {
+                log_warn!(
+                    "rhythm",
+                    "⚠️ Failed to post reminder to {}: {:?}",
+                    channel_id,
+                    e
+                );
+            }
+        }
+        Ok(None) => {}
+        Err(e) => log_warn!(
+            "rhythm",
+            "⚠️ One-shot ritual {:?} failed: {}",
+            path.file_name(),
+            e
+        ),
+    }
+}
+
+pub(crate) fn remind_me_tool_definition() -> Value {
+    json!({
+        "name": REMIND_ME_TOOL_NAME,
+        "description": "Set a one-shot reminder for this channel. When it is due, a `- [ ] Reminder: ...` TODO is added to the channel's blackboard and posted to the channel. Give `at`, `in`, or both (`in` is added to `at`).",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "message": { "type": "string", "description": "What to remind about" },
+                "at": { "type": "string", "description": "When: RFC 3339 or YYYY-MM-DD HH:MM, read in `tz`" },
+                "in": { "type": "string", "description": "Delay from `at` or now, e.g. '90m', '2h', '1d 3h'. Units w, d, h, m, s" },
+                "tz": { "type": "string", "description": "IANA timezone for `at`, e.g. Europe/Paris. Defaults to runtime.timezone, then the host's local time" }
+            },
+            "required": ["message"]
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_remind_me_writes_one_shot_ritual_that_fires_once() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("channels/general-123456")).unwrap();
+        let config = Config::default();
+
+        let result = run_remind_me_tool(
+            &json!({ "message": "Take the bread\nout", "in": "2h", "tz": "UTC" }),
+            base,
+            &config,
+            "999123456",
+        )
+        .await;
+        assert!(!result.is_error, "{}", result.output);
+        assert!(result.output.contains("Take the bread out"));
+        let path = fs::read_dir(base.join("rituals"))
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.path())
+            .next()
+            .unwrap();
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        assert!(name.starts_with("reminder-") && name.ends_with("-take-the-bread-out.md"));
+        let (header, _) =
+            crate::rhythm::parse_thread_metadata(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(header.at.as_deref().unwrap().ends_with("+00:00"));
+        assert_eq!(header.origin_channel.as_deref(), Some("999123456"));
+        assert_eq!(header.source.as_deref(), Some("local"));
+
+        let past = run_remind_me_tool(
+            &json!({ "message": "late", "at": "2001-01-01 09:00" }),
+            base,
+            &config,
+            "999123456",
+        )
+        .await;
+        assert!(past.is_error);
+        let homeless =
+            run_remind_me_tool(&json!({ "message": "x", "in": "1h" }), base, &config, "0").await;
+        assert!(homeless.is_error);
+
+        let (channel, todo) = inscribe_one_shot(base, &path).await.unwrap().unwrap();
+        assert_eq!(channel, "999123456");
+        assert_eq!(todo, "- [ ] Reminder: Take the bread out");
+        assert!(!path.exists());
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        assert!(
+            base.join("rituals/history")
+                .join(&today)
+                .join(&name)
+                .is_file()
+        );
+        let log = fs::read_to_string(
+            base.join("channels/general-123456")
+                .join(format!("{}.md", today)),
+        )
+        .unwrap();
+        assert!(log.contains("- [ ] Reminder: Take the bread out"));
+        assert!(inscribe_one_shot(base, &path).await.unwrap().is_none());
+    }
+}
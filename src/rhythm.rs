@@ -4,15 +4,22 @@
  * Responsibility: The Rhythm. The ghost that pulses the Workspace, breathing life into persistent Threads.
  */
 
-use chrono::Local;
+use crate::config::Config;
+use crate::datetime::Zone;
+use crate::skills::SkillMetadata;
+use crate::{log_error, log_info, log_warn};
+use chrono::{Local, Utc};
 use once_cell::sync::Lazy;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
+use std::future::Future;
 use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
-use tokio_cron_scheduler::{Job, JobScheduler};
+use tokio_cron_scheduler::{Job, JobScheduler, JobSchedulerError};
 use uuid::Uuid;
 
 /// Metadata format for autonomous threads
@@ -20,16 +27,51 @@ use uuid::Uuid;
 pub struct ThreadMetadata {
     pub discord_event_id: Option<String>, // Anchor to Discord Event
     pub schedule: Option<String>,         // Cron expression
+    pub every: Option<String>,            // Interval such as `15m` or `1h 30m`, instead of cron
     pub injection_template: Option<String>, // What to append
     #[allow(dead_code)]
     pub origin_channel: Option<String>, // Bound channel
+    pub timezone: Option<String>,       // IANA zone the schedule is written in
+    pub at: Option<String>,             // One-shot: fire once at this moment, then archive
+    pub source: Option<String>, // `local` schedules a ritual without a Discord event
+    #[serde(default)]
+    pub jitter_secs: u64, // Random delay of up to this many seconds before each injection
+    #[serde(default)]
+    pub skip_if_pending: bool, // Skip a run while an earlier injection is still unchecked
+    pub task_id: Option<String>,
+    pub status: Option<String>, // `paused` keeps the schedule but unregisters its job
+    #[serde(default)]
+    pub depends_on: Vec<String>, // Task ids that must complete today before this ritual runs
+    #[serde(default)]
+    pub essential: bool, // Keeps running while the steward is in degraded mode
+}
+
+impl ThreadMetadata {
+    /// Cron schedules and `at:` one-shots run only for rituals anchored to a Discord event
+    /// or declared `source: local` (or `calendar`, for synced calendar events), so a stray
+    /// `schedule:` or `at:` line in any document does not start a job.
+    fn may_schedule(&self) -> bool {
+        self.discord_event_id.is_some()
+            || self.source.as_deref().is_some_and(|source| {
+                let source = source.trim();
+                source.eq_ignore_ascii_case("local") || source.eq_ignore_ascii_case("calendar")
+            })
+    }
 }
 
 type JobMap = Arc<RwLock<HashMap<PathBuf, Uuid>>>;
+type RhythmContext = Option<(PathBuf, Arc<Config>)>;
 
 static SCHEDULER: Lazy<Arc<RwLock<Option<JobScheduler>>>> =
     Lazy::new(|| Arc::new(RwLock::new(None)));
 static JOB_MAP: Lazy<JobMap> = Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+static SKILL_HOOK_JOBS: Lazy<RwLock<Vec<Uuid>>> = Lazy::new(|| RwLock::new(Vec::new()));
+/// Guild root and config the Rhythm was started with, for jobs that fire later.
+static RHYTHM_CONTEXT: Lazy<RwLock<RhythmContext>> =
+    Lazy::new(|| RwLock::new(None));
+/// Zone from `runtime.timezone`, used by schedules whose ritual header names none.
+static DEFAULT_ZONE: Lazy<std::sync::RwLock<Zone>> =
+    Lazy::new(|| std::sync::RwLock::new(Zone::Local));
 static STREAM_LOG_NAME_RE: Lazy<regex::Regex> =
     Lazy::new(|| regex::Regex::new(r"^\d{4}-\d{2}-\d{2}\.md$").expect("valid stream log regex"));
 
@@ -39,26 +81,61 @@ fn is_stream_log_name(file_name: &str) -> bool {
 
 fn should_ignore_rhythm_file(path: &Path) -> bool {
     let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
-    file_name == "KNOWLEDGE.md" || is_stream_log_name(file_name)
+    file_name == "KNOWLEDGE.md"
+        || file_name == crate::channel_summary::SUMMARY_FILE
+        || is_stream_log_name(file_name)
+}
+
+type JobFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+fn default_zone() -> Zone {
+    DEFAULT_ZONE.read().map(|zone| *zone).unwrap_or(Zone::Local)
 }
 
-pub async fn run_rhythm(base_path: &Path) -> anyhow::Result<()> {
+/// The zone a schedule fires in: the ritual's own `timezone`, else `runtime.timezone`,
+/// else the host's local time. Unknown names fall back with a warning.
+fn schedule_zone(timezone: Option<&str>) -> Zone {
+    match timezone.map(str::trim).filter(|name| !name.is_empty()) {
+        None => default_zone(),
+        Some(name) => Zone::parse(Some(name)).unwrap_or_else(|e| {
+            log_warn!("rhythm", "⚠️ Schedule timezone: {}; using the default zone", e);
+            default_zone()
+        }),
+    }
+}
+
+/// A cron job that fires at `schedule` as read on `zone`'s wall clock.
+fn zoned_job<T>(schedule: &str, zone: Zone, run: T) -> Result<Job, JobSchedulerError>
+where
+    T: FnMut(Uuid, JobScheduler) -> JobFuture + Send + Sync + 'static,
+{
+    match zone {
+        Zone::Local => Job::new_async_tz(schedule, Local, run),
+        Zone::Named(tz) => Job::new_async_tz(schedule, tz, run),
+    }
+}
+
+pub async fn run_rhythm(base_path: &Path, config: Arc<Config>) -> anyhow::Result<()> {
     let sched = JobScheduler::new().await?;
+    match Zone::parse(config.runtime.timezone.as_deref()) {
+        Ok(zone) => {
+            if let Ok(mut default) = DEFAULT_ZONE.write() {
+                *default = zone;
+            }
+        }
+        Err(e) => log_warn!("rhythm", "⚠️ runtime.timezone: {}; schedules use local time", e),
+    }
+    *RHYTHM_CONTEXT.write().await = Some((base_path.to_path_buf(), Arc::clone(&config)));
     {
         let mut lock = SCHEDULER.write().await;
         *lock = Some(sched.clone());
     }
 
-    let rituals_dir = base_path.join("rituals");
-    if !rituals_dir.exists() {
-        return Ok(());
-    }
-
     // 1. Initial Scan
-    let rituals_dir_clone = rituals_dir.clone();
+    let rituals_dir = base_path.join("rituals");
     let initial_threads = tokio::task::spawn_blocking(move || {
         let mut paths = Vec::new();
-        let _ = collect_thread_files(&rituals_dir_clone, &mut paths);
+        let _ = collect_thread_files(&rituals_dir, &mut paths);
         paths
     })
     .await
@@ -67,10 +144,68 @@ pub async fn run_rhythm(base_path: &Path) -> anyhow::Result<()> {
     for path in initial_threads {
         let _ = sync_job_from_file(&path).await;
     }
+    sync_skill_hooks(base_path, Arc::clone(&config)).await?;
+
+    if config.guardian.enabled {
+        match parse_every(&config.guardian.interval) {
+            Ok(period) => {
+                let guardian_base = base_path.to_path_buf();
+                let guardian_config = Arc::clone(&config);
+                sched
+                    .add(Job::new_repeated_async(period, move |_uuid, _l| {
+                        let base_path = guardian_base.clone();
+                        let config = Arc::clone(&guardian_config);
+                        Box::pin(async move {
+                            crate::guardian::run_guardian_pulse(&base_path, &config).await;
+                        })
+                    })?)
+                    .await?;
+            }
+            Err(e) => log_warn!(
+                "rhythm",
+                "⚠️ guardian.interval: {}; the Guardian is not scheduled",
+                e
+            ),
+        }
+    }
+
+    if config.guardian.enabled && config.guardian.degraded.enabled {
+        let health_config = Arc::clone(&config);
+        sched
+            .add(Job::new_repeated_async(
+                Duration::from_secs(60),
+                move |_uuid, _l| {
+                    let config = Arc::clone(&health_config);
+                    Box::pin(async move { crate::api_health::evaluate(&config).await })
+                },
+            )?)
+            .await?;
+    }
+
+    if config.digest.enabled {
+        let digest_base = base_path.to_path_buf();
+        let digest_config = Arc::clone(&config);
+        let schedule = cron_with_seconds(&config.digest.schedule);
+        let job = zoned_job(&schedule, default_zone(), move |_uuid, _l| {
+            let base_path = digest_base.clone();
+            let config = Arc::clone(&digest_config);
+            Box::pin(async move { crate::digest::run_daily_digest(&base_path, &config).await })
+        });
+        match job {
+            Ok(job) => {
+                sched.add(job).await?;
+            }
+            Err(e) => log_warn!(
+                "rhythm",
+                "⚠️ Daily digest has an invalid schedule `{}`: {}",
+                schedule, e
+            ),
+        }
+    }
 
     // 2. Start scheduler
     sched.start().await?;
-    println!("💓 The Rhythm is pulsing...");
+    log_info!("rhythm", "💓 The Rhythm is pulsing...");
     Ok(())
 }
 
@@ -93,57 +228,150 @@ pub async fn sync_job_from_file(path: &PathBuf) -> anyhow::Result<()> {
     };
 
     if let Some((header, _)) = parse_thread_metadata(&content) {
-        // Only allow scheduling for files linked to a Discord Event (Rituals)
-        if header.discord_event_id.is_none() {
+        if crate::ritual_control::is_paused(&content) {
             handle_file_removal(path).await?;
             return Ok(());
         }
+        // Only allow scheduling for Discord Event rituals or ones explicitly marked local
+        if !header.may_schedule() {
+            handle_file_removal(path).await?;
+            return Ok(());
+        }
+        if let Some(at) = header.at.as_deref() {
+            return schedule_one_shot(sched, path, at, header.timezone.as_deref()).await;
+        }
 
-        if let (Some(cron_expr), Some(template)) = (header.schedule, header.injection_template) {
-            if cron_expr.is_empty() {
+        let every = match header.every.as_deref().map(parse_every).transpose() {
+            Ok(every) => every,
+            Err(e) => {
+                log_warn!("rhythm", "⚠️ Ritual {} has an invalid `every`: {}", file_name, e);
                 handle_file_removal(path).await?;
                 return Ok(());
             }
-
+        };
+        let cron_expr = header
+            .schedule
+            .filter(|schedule| !schedule.trim().is_empty());
+
+        if let (true, Some(template)) = (
+            every.is_some() || cron_expr.is_some(),
+            header.injection_template,
+        ) {
             // Remove existing job
             handle_file_removal(path).await?;
 
-            println!("👻 Ghosting: [{}] with rhythm [{}]", file_name, cron_expr);
-
             let path_clone = path.clone();
             let template_clone = template.to_string();
+            let jitter_secs = header.jitter_secs;
+            let skip_if_pending = header.skip_if_pending;
+            let depends_on = header.depends_on.clone();
+            let essential = header.essential;
 
-            let job = Job::new_async(cron_expr.as_str(), move |_uuid, _l| {
+            let run = move |_uuid: Uuid, _l: JobScheduler| -> JobFuture {
                 let path_exec = path_clone.clone();
                 let injection = template_clone.clone();
+                let depends_on = depends_on.clone();
 
                 Box::pin(async move {
-                    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-
-                    if let Ok(mut current_content) = tokio::fs::read_to_string(&path_exec).await {
-                        let block = format!(
-                            "\n\n--- [Ghostly Injection: {}] ---\n{}",
-                            timestamp, injection
+                    if jitter_secs > 0 {
+                        tokio::time::sleep(jitter_delay(jitter_secs)).await;
+                    }
+                    if let Some((_, config)) = RHYTHM_CONTEXT.read().await.clone() {
+                        crate::quiet_hours::wait_until_allowed(&config, "a ghostly injection")
+                            .await;
+                    }
+                    if !essential && crate::api_health::is_degraded() {
+                        log_info!(
+                            "rhythm",
+                            "🚧 Degraded mode: skipped {:?}; mark it `essential: true` to keep it running",
+                            path_exec.file_name().unwrap_or_default()
                         );
-                        current_content.push_str(&block);
-
-                        let updated =
-                            current_content.replace("status: waiting_for_human", "status: active");
+                        return;
+                    }
+                    if !depends_on.is_empty()
+                        && let Some((base_path, _)) = RHYTHM_CONTEXT.read().await.clone()
+                    {
+                        let pending = crate::ritual_runs::pending_prerequisites(
+                            &base_path,
+                            &depends_on,
+                            Local::now().date_naive(),
+                        );
+                        if !pending.is_empty() {
+                            log_info!(
+                                "rhythm",
+                                "⛓️ Ghost skipped {:?}: waiting for {}",
+                                path_exec.file_name().unwrap_or_default(),
+                                pending.join(", ")
+                            );
+                            return;
+                        }
+                    }
 
-                        if let Err(e) = tokio::fs::write(&path_exec, updated).await {
-                            eprintln!(
+                    if let Ok(current_content) = tokio::fs::read_to_string(&path_exec).await {
+                        if crate::ritual_control::is_paused(&current_content) {
+                            return;
+                        }
+                        if skip_if_pending && has_pending_steps(&current_content) {
+                            log_info!(
+                                "rhythm",
+                                "⏭️ Ghost skipped {:?}: the previous injection is still unprocessed",
+                                path_exec.file_name().unwrap_or_default()
+                            );
+                            return;
+                        }
+                        let updated = inject_template(&current_content, &injection);
+                        let base_path = RHYTHM_CONTEXT
+                            .read()
+                            .await
+                            .as_ref()
+                            .map(|(base_path, _)| base_path.clone());
+                        let written = match base_path {
+                            Some(base_path) => {
+                                crate::journal::write_async(&base_path, &path_exec, &updated).await
+                            }
+                            None => tokio::fs::write(&path_exec, updated).await,
+                        };
+
+                        if let Err(e) = written {
+                            log_error!(
+                                "rhythm",
                                 "❌ Ghost failed to inscribe thread {:?}: {:?}",
                                 path_exec, e
                             );
                         } else {
-                            println!(
+                            log_info!(
+                                "rhythm",
                                 "✍️ Ghost inscribed thread: {:?}",
                                 path_exec.file_name().unwrap()
                             );
                         }
                     }
                 })
-            })?;
+            };
+
+            let job = match (every, cron_expr) {
+                (Some(period), _) => {
+                    log_info!(
+                        "rhythm",
+                        "👻 Ghosting: [{}] every {}s",
+                        file_name,
+                        period.as_secs()
+                    );
+                    Job::new_repeated_async(period, run)?
+                }
+                (None, Some(cron_expr)) => {
+                    let zone = schedule_zone(header.timezone.as_deref());
+                    log_info!(
+                        "rhythm",
+                        "👻 Ghosting: [{}] with rhythm [{}] ({})",
+                        file_name,
+                        cron_expr,
+                        zone.label()
+                    );
+                    zoned_job(&cron_expr, zone, run)?
+                }
+                (None, None) => unreachable!("checked above"),
+            };
 
             let job_id = sched.add(job).await?;
             let mut map = JOB_MAP.write().await;
@@ -155,6 +383,172 @@ pub async fn sync_job_from_file(path: &PathBuf) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Append an injection block and wake a ritual that was waiting for a human.
+fn inject_template(content: &str, template: &str) -> String {
+    let mut next = content.to_string();
+    next.push_str(&format!(
+        "\n\n--- [Ghostly Injection: {}] ---\n{}",
+        Local::now().format("%Y-%m-%d %H:%M:%S"),
+        template
+    ));
+    next.replace("status: waiting_for_human", "status: active")
+}
+
+/// After `task_id` completes, wake every ritual that lists it in `depends_on` and whose
+/// prerequisites have now all completed today: inject its template, or, if it has none,
+/// note the release so the Watchman runs its waiting steps. Returns the released files.
+/// Blocking: async callers run it on the blocking pool.
+pub fn release_dependents(base_path: &Path, task_id: &str) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let _ = collect_thread_files(&base_path.join("rituals"), &mut paths);
+    let today = Local::now().date_naive();
+    let mut released = Vec::new();
+    for path in paths {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Some((header, _)) = parse_thread_metadata(&content) else {
+            continue;
+        };
+        if !header.depends_on.iter().any(|id| id == task_id)
+            || !crate::ritual_runs::pending_prerequisites(base_path, &header.depends_on, today)
+                .is_empty()
+        {
+            continue;
+        }
+        let updated = match header.injection_template.as_deref() {
+            Some(template) if !template.trim().is_empty() => inject_template(&content, template),
+            _ if has_pending_steps(&content) => format!(
+                "{}\n> [Tellar] ({}): Prerequisites complete: {}\n",
+                content,
+                Local::now().format("%Y-%m-%d %H:%M:%S"),
+                header.depends_on.join(", ")
+            ),
+            _ => continue,
+        };
+        match crate::journal::write(base_path, &path, &updated) {
+            Ok(_) => released.push(path),
+            Err(e) => log_warn!(
+                "rhythm",
+                "⚠️ Failed to release dependent ritual {:?}: {}",
+                path,
+                e
+            ),
+        }
+    }
+    released
+}
+
+/// Parse an `every:` interval: amounts with units `d`, `h`, `m`, or `s`, such as `15m`,
+/// `2h`, or `1h 30m`. Intervals shorter than a minute are refused.
+pub(crate) fn parse_every(value: &str) -> Result<Duration, String> {
+    let invalid = || {
+        format!(
+            "cannot parse interval `{}`; use units d, h, m, s (e.g. `15m`, `1h 30m`)",
+            value
+        )
+    };
+    let compact: String = value.split_whitespace().collect();
+    let mut total: u64 = 0;
+    let mut rest = compact.as_str();
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+        let amount: u64 = rest[..digits].parse().map_err(|_| invalid())?;
+        let after = &rest[digits..];
+        let unit_len = after
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let seconds = match after[..unit_len].to_ascii_lowercase().as_str() {
+            "d" | "day" | "days" => 86_400,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3_600,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            _ => return Err(invalid()),
+        };
+        total = amount
+            .checked_mul(seconds)
+            .and_then(|secs| total.checked_add(secs))
+            .ok_or_else(invalid)?;
+        rest = &after[unit_len..];
+    }
+    if total < 60 {
+        return Err(format!("interval `{}` is shorter than a minute", value));
+    }
+    Ok(Duration::from_secs(total))
+}
+
+/// A random delay in `0..=max_secs` seconds, so rituals sharing a schedule do not fire together.
+fn jitter_delay(max_secs: u64) -> Duration {
+    Duration::from_secs((Uuid::new_v4().as_u128() % (u128::from(max_secs) + 1)) as u64)
+}
+
+/// Whether the ritual body (not the header's template) still has an unchecked step.
+fn has_pending_steps(content: &str) -> bool {
+    parse_thread_metadata(content).is_some_and(|(_, body)| body.contains("- [ ] "))
+}
+
+/// Schedule a ritual with an `at:` header to fire once. Moments already past fire right away,
+/// so reminders due while the daemon was down are delivered on the next start.
+async fn schedule_one_shot(
+    sched: &JobScheduler,
+    path: &PathBuf,
+    at: &str,
+    timezone: Option<&str>,
+) -> anyhow::Result<()> {
+    handle_file_removal(path).await?;
+    let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+    let due = match crate::datetime::parse_moment(at, schedule_zone(timezone)) {
+        Ok(due) => due,
+        Err(e) => {
+            log_warn!("rhythm", "⚠️ One-shot ritual [{}] has an invalid `at`: {}", file_name, e);
+            return Ok(());
+        }
+    };
+    let delay = (due.with_timezone(&Utc) - Utc::now())
+        .to_std()
+        .unwrap_or_default()
+        .max(Duration::from_secs(1));
+    log_info!("rhythm", "⏰ One-shot: [{}] at [{}]", file_name, due.to_rfc3339());
+
+    let path_clone = path.clone();
+    let job = Job::new_one_shot_async(delay, move |_uuid, _l| {
+        let path = path_clone.clone();
+        Box::pin(async move {
+            JOB_MAP.write().await.remove(&path);
+            let context = RHYTHM_CONTEXT.read().await.clone();
+            if let Some((base_path, config)) = context {
+                crate::reminders::fire_one_shot(&base_path, &path, &config).await;
+            }
+        })
+    })?;
+    let job_id = sched.add(job).await?;
+    JOB_MAP.write().await.insert(path.clone(), job_id);
+    Ok(())
+}
+
+/// Every ritual with a scheduled job and when it next fires, soonest first.
+pub async fn scheduled_jobs() -> Vec<(PathBuf, Option<chrono::DateTime<Utc>>)> {
+    let Some(mut sched) = SCHEDULER.read().await.clone() else {
+        return Vec::new();
+    };
+    let jobs: Vec<(PathBuf, Uuid)> = JOB_MAP
+        .read()
+        .await
+        .iter()
+        .map(|(path, id)| (path.clone(), *id))
+        .collect();
+    let mut scheduled = Vec::new();
+    for (path, id) in jobs {
+        let next = sched.next_tick_for_job(id).await.ok().flatten();
+        scheduled.push((path, next));
+    }
+    scheduled.sort_by(|a, b| (a.1.is_none(), a.1, &a.0).cmp(&(b.1.is_none(), b.1, &b.0)));
+    scheduled
+}
+
 /// Reactive: Handle file removal by stopping the job
 pub async fn handle_file_removal(path: &PathBuf) -> anyhow::Result<()> {
     let mut map = JOB_MAP.write().await;
@@ -162,7 +556,8 @@ pub async fn handle_file_removal(path: &PathBuf) -> anyhow::Result<()> {
         let sched_lock = SCHEDULER.read().await;
         if let Some(sched) = &*sched_lock {
             let _ = sched.remove(&job_id).await;
-            println!(
+            log_info!(
+                "rhythm",
                 "🗑️ Rhythm removed for: {:?}",
                 path.file_name().unwrap_or_default()
             );
@@ -171,24 +566,170 @@ pub async fn handle_file_removal(path: &PathBuf) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn parse_thread_metadata(content: &str) -> Option<(ThreadMetadata, &str)> {
-    if !content.starts_with("---") {
-        return None;
+/// A skill tool the Rhythm runs on a schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SkillHook {
+    skill: String,
+    tool: String,
+    schedule: String,
+}
+
+/// Parse a ritual `schedule` the way its job will be built, so a bad expression is caught
+/// when the ritual is written instead of when the Rhythm syncs it. Returns the expression
+/// with a seconds field.
+pub(crate) fn check_schedule(schedule: &str, zone: Zone) -> Result<String, String> {
+    let schedule = cron_with_seconds(schedule);
+    zoned_job(&schedule, zone, |_uuid, _l| Box::pin(async {}))
+        .map(|_| schedule.clone())
+        .map_err(|e| {
+            format!(
+                "invalid cron expression `{}` ({:?}); use minute hour day-of-month month \
+                 day-of-week, optionally preceded by seconds",
+                schedule, e
+            )
+        })
+}
+
+/// The scheduler wants a seconds field; accept classic five-field cron by running at second 0.
+fn cron_with_seconds(schedule: &str) -> String {
+    let schedule = schedule.trim();
+    if schedule.split_whitespace().count() == 5 {
+        format!("0 {}", schedule)
+    } else {
+        schedule.to_string()
+    }
+}
+
+fn collect_skill_hooks(base_path: &Path) -> Vec<SkillHook> {
+    let mut hooks = Vec::new();
+    for (meta, _) in SkillMetadata::discover_skills(base_path) {
+        for (tool, schedule) in &meta.runtime.hooks {
+            if !meta.tools.contains_key(tool) {
+                log_warn!(
+                    "rhythm",
+                    "⚠️ Skill `{}` declares a hook for unknown tool `{}`",
+                    meta.name, tool
+                );
+                continue;
+            }
+            hooks.push(SkillHook {
+                skill: meta.name.clone(),
+                tool: tool.clone(),
+                schedule: cron_with_seconds(schedule),
+            });
+        }
+    }
+    hooks
+}
+
+async fn run_skill_hook(base_path: &Path, config: &Config, hook: &SkillHook) {
+    // Resolve the skill again so edits since registration take effect.
+    let Some((meta, dir)) = SkillMetadata::discover_skills(base_path)
+        .into_iter()
+        .find(|(meta, _)| meta.name == hook.skill)
+    else {
+        return;
+    };
+    let Some(tool) = meta.tools.get(&hook.tool) else {
+        return;
+    };
+    let args = serde_json::json!({});
+    if crate::dry_run::is_enabled() {
+        crate::dry_run::record("skill hook", &format!("{}.{}", hook.skill, hook.tool), args);
+        return;
+    }
+    let started = std::time::Instant::now();
+    let outcome =
+        crate::skills::execute_skill_tool(tool, &meta, &dir, base_path, &args, config).await;
+    let call = crate::skill_audit::SkillCall {
+        skill: &hook.skill,
+        tool: &hook.tool,
+        args: &args,
+        caller: "rhythm",
+    };
+    crate::skill_audit::record_skill_call(base_path, config, &call, &outcome, started.elapsed());
+    crate::alerts::after_skill_call(base_path, config, &hook.skill, &hook.tool).await;
+    match outcome {
+        Ok(output) => {
+            let preview: String = output.chars().take(200).collect();
+            log_info!(
+                "rhythm",
+                "🪝 Skill hook {}/{} ran: {}",
+                hook.skill, hook.tool, preview
+            );
+        }
+        Err(e) => log_warn!("rhythm", "⚠️ Skill hook {}/{} failed: {}", hook.skill, hook.tool, e),
+    }
+}
+
+/// Replace the scheduled skill hooks with the ones the skills currently declare.
+pub async fn sync_skill_hooks(base_path: &Path, config: Arc<Config>) -> anyhow::Result<()> {
+    let sched_lock = SCHEDULER.read().await;
+    let sched = match &*sched_lock {
+        Some(s) => s,
+        None => return Ok(()), // Not initialized yet
+    };
+
+    let mut jobs = SKILL_HOOK_JOBS.write().await;
+    for job_id in jobs.drain(..) {
+        let _ = sched.remove(&job_id).await;
+    }
+
+    for hook in collect_skill_hooks(base_path) {
+        let base_path = base_path.to_path_buf();
+        let config = Arc::clone(&config);
+        let hook_exec = hook.clone();
+        let job = zoned_job(&hook.schedule, default_zone(), move |_uuid, _l| {
+            let base_path = base_path.clone();
+            let config = Arc::clone(&config);
+            let hook = hook_exec.clone();
+            Box::pin(async move { run_skill_hook(&base_path, &config, &hook).await })
+        });
+        match job {
+            Ok(job) => {
+                jobs.push(sched.add(job).await?);
+                log_info!(
+                    "rhythm",
+                    "🪝 Skill hook: [{}/{}] with rhythm [{}]",
+                    hook.skill, hook.tool, hook.schedule
+                );
+            }
+            Err(e) => log_warn!(
+                "rhythm",
+                "⚠️ Skill hook {}/{} has an invalid schedule `{}`: {}",
+                hook.skill, hook.tool, hook.schedule, e
+            ),
+        }
     }
-    let parts: Vec<&str> = content.splitn(3, "---").collect();
-    if parts.len() < 3 {
+    Ok(())
+}
+
+/// A front-matter fence: a line of three or more dashes (Discord event rituals use five).
+pub(crate) fn is_fence(line: &str) -> bool {
+    let line = line.trim_end();
+    line.len() >= 3 && line.chars().all(|c| c == '-')
+}
+
+pub(crate) fn parse_thread_metadata(content: &str) -> Option<(ThreadMetadata, &str)> {
+    let (first, rest) = content.split_once('\n')?;
+    if !is_fence(first) {
         return None;
     }
-    let yaml_str = parts[1];
-    let body = parts[2].trim();
-    if let Ok(header) = serde_yml::from_str::<ThreadMetadata>(yaml_str) {
-        Some((header, body))
-    } else {
-        None
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if is_fence(line) {
+            let yaml_str = &rest[..offset];
+            let body = rest[offset + line.len()..].trim();
+            return serde_yml::from_str::<ThreadMetadata>(yaml_str)
+                .ok()
+                .map(|header| (header, body));
+        }
+        offset += line.len();
     }
+    None
 }
 
-fn collect_thread_files(dir: &Path, paths: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+pub(crate) fn collect_thread_files(dir: &Path, paths: &mut Vec<PathBuf>) -> anyhow::Result<()> {
     if !dir.is_dir() {
         return Ok(());
     }
@@ -231,6 +772,131 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn test_ritual_timezone_header_picks_schedule_zone() {
+        let content = "-----\ndiscord_event_id: \"42\"\nschedule: \"0 30 9 * * *\"\ntimezone: \"Europe/Paris\"\ninjection_template: |\n  - [ ] Stand-up\n-----\n# Ritual\n";
+        let (header, _) = parse_thread_metadata(content).unwrap();
+        assert_eq!(header.timezone.as_deref(), Some("Europe/Paris"));
+        assert_eq!(
+            schedule_zone(header.timezone.as_deref()),
+            Zone::Named(chrono_tz::Europe::Paris)
+        );
+        assert_eq!(schedule_zone(Some("UTC")), Zone::Named(chrono_tz::UTC));
+        assert_eq!(schedule_zone(Some("Mars/Olympus")), default_zone());
+        assert!(zoned_job("0 30 9 * * *", Zone::Named(chrono_tz::Asia::Tokyo), |_, _| {
+            Box::pin(async {})
+        })
+        .is_ok());
+    }
+
+    #[test]
+    fn test_local_rituals_schedule_without_discord_event() {
+        let header = |extra: &str| {
+            let content = format!("---\nschedule: \"0 0 7 * * *\"\n{}\n---\nbody", extra);
+            parse_thread_metadata(&content).unwrap().0
+        };
+        assert!(header("source: local").may_schedule());
+        assert!(header("discord_event_id: \"42\"").may_schedule());
+        assert!(!header("").may_schedule());
+        assert!(!header("source: discord").may_schedule());
+
+        let one_shot = |extra: &str| {
+            let content = format!(
+                "---\nat: \"2026-03-01 09:00\"\norigin_channel: \"42\"\n{}\n---\nbody",
+                extra
+            );
+            parse_thread_metadata(&content).unwrap().0
+        };
+        assert!(!one_shot("").may_schedule());
+        assert!(one_shot("source: local").may_schedule());
+    }
+
+    #[test]
+    fn test_parse_every_accepts_simple_intervals() {
+        assert_eq!(parse_every("15m"), Ok(Duration::from_secs(900)));
+        assert_eq!(parse_every("2h"), Ok(Duration::from_secs(7200)));
+        assert_eq!(parse_every("1h 30m"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_every("90 minutes"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_every("1d"), Ok(Duration::from_secs(86_400)));
+        assert!(parse_every("30s").is_err());
+        assert!(parse_every("*/15 * * * *").is_err());
+        assert!(parse_every("").is_err());
+        let (header, _) =
+            parse_thread_metadata("---
+source: local
+every: 45m
+---
+body").unwrap();
+        assert_eq!(header.every.as_deref(), Some("45m"));
+        assert!(header.may_schedule());
+    }
+
+    fn record_completed(base: &Path, task_id: &str) {
+        let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        crate::ritual_runs::record_run(
+            base,
+            &crate::ritual_runs::RitualRun {
+                task_id: task_id.to_string(),
+                started_at: now.clone(),
+                finished_at: now,
+                result: "completed".to_string(),
+                steps_completed: 1,
+                tools: Vec::new(),
+                tokens: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_release_dependents_after_prerequisites_complete() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let rituals = base.join("rituals");
+        fs::create_dir_all(&rituals).unwrap();
+        let report = rituals.join("report.md");
+        let waiting = rituals.join("cleanup.md");
+        fs::write(
+            &report,
+            "---\nstatus: waiting_for_human\ndepends_on: [backup, verify]\ninjection_template: |\n  - [ ] Send the report\n---\n# Report\n",
+        )
+        .unwrap();
+        fs::write(
+            &waiting,
+            "---\nstatus: active\ndepends_on: [backup]\n---\n- [ ] Prune snapshots\n",
+        )
+        .unwrap();
+
+        record_completed(base, "backup");
+        let released = release_dependents(base, "backup");
+        assert_eq!(released, vec![waiting.clone()]);
+        assert!(
+            fs::read_to_string(&waiting)
+                .unwrap()
+                .contains("Prerequisites complete: backup")
+        );
+
+        record_completed(base, "verify");
+        assert_eq!(release_dependents(base, "verify"), vec![report.clone()]);
+        let report_content = fs::read_to_string(&report).unwrap();
+        assert!(report_content.contains("status: active"));
+        assert!(has_pending_steps(&report_content));
+    }
+
+    #[test]
+    fn test_overlap_and_jitter_options() {
+        let content = "---\nschedule: \"0 */5 * * * *\"\njitter_secs: 30\nskip_if_pending: true\ninjection_template: |\n  - [ ] Poll the queue\n---\n# Queue\n";
+        let (header, _) = parse_thread_metadata(content).unwrap();
+        assert_eq!(header.jitter_secs, 30);
+        assert!(header.skip_if_pending);
+        assert!(!has_pending_steps(content));
+        let injected = format!("{}\n--- [Ghostly Injection: now] ---\n- [ ] Poll the queue", content);
+        assert!(has_pending_steps(&injected));
+        assert!(!has_pending_steps(&injected.replace("[ ]", "[x]")));
+        assert!((0..200).all(|_| jitter_delay(3) <= Duration::from_secs(3)));
+        assert_eq!(jitter_delay(0), Duration::ZERO);
+    }
+
     #[test]
     fn test_collect_thread_files_skips_knowledge_logs_and_history() {
         let dir = tempdir().unwrap();
@@ -250,4 +916,39 @@ mod tests {
             Some("deploy.md")
         );
     }
+
+    #[test]
+    fn test_collect_skill_hooks_keeps_hooks_for_declared_tools() {
+        let guild = tempdir().unwrap();
+        let skill_dir = guild.path().join("skills").join("digest");
+        fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            r#"---
+name: digest
+hooks:
+  daily_digest: "0 8 * * *"
+  missing_tool: "0 9 * * *"
+tools:
+  daily_digest:
+    description: Summarize the day
+    shell: printf done
+    parameters:
+      type: object
+---
+"#,
+        )
+        .unwrap();
+
+        let hooks = collect_skill_hooks(guild.path());
+        assert_eq!(
+            hooks,
+            vec![SkillHook {
+                skill: "digest".to_string(),
+                tool: "daily_digest".to_string(),
+                schedule: "0 0 8 * * *".to_string(),
+            }]
+        );
+        assert!(Job::new_async(hooks[0].schedule.as_str(), |_, _| Box::pin(async {})).is_ok());
+    }
 }
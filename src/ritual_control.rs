@@ -0,0 +1,223 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/ritual_control.rs
+ * Responsibility: Pause and resume rituals from Discord via `/ritual` commands and the ⏸️ reaction.
+ */
+
+use crate::log_warn;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const PAUSE_EMOJI: &str = "⏸";
+pub const PAUSED_STATUS: &str = "paused";
+/// Marker in ritual messages naming the ritual a ⏸️ reaction toggles.
+pub const RITUAL_MARKER: &str = "[Ritual: ";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RitualCommand {
+    Pause(String),
+    Resume(String),
+    List,
+}
+
+/// Parse `/ritual pause <name>`, `/ritual resume <name>`, or `/ritual list`.
+pub fn parse_command(text: &str) -> Option<RitualCommand> {
+    let mut words = text.trim().strip_prefix("/ritual")?.split_whitespace();
+    let action = words.next().unwrap_or("list");
+    let name = words.collect::<Vec<_>>().join(" ");
+    match (action, name.is_empty()) {
+        ("list", _) => Some(RitualCommand::List),
+        ("pause", false) => Some(RitualCommand::Pause(name)),
+        ("resume", false) => Some(RitualCommand::Resume(name)),
+        _ => None,
+    }
+}
+
+pub fn is_pause_emoji(emoji: &str) -> bool {
+    emoji.trim_end_matches('\u{fe0f}') == PAUSE_EMOJI
+}
+
+/// The ritual named in a message's `[Ritual: <task_id>]` marker.
+pub fn ritual_from_message(content: &str) -> Option<&str> {
+    let start = content.find(RITUAL_MARKER)? + RITUAL_MARKER.len();
+    let end = content[start..].find(']')?;
+    Some(content[start..start + end].trim())
+}
+
+/// The ritual whose `task_id` or file name (without `.md`) is `name`.
+pub fn find_ritual(base_path: &Path, name: &str) -> Option<PathBuf> {
+    let name = name.trim().trim_end_matches(".md");
+    let mut paths = Vec::new();
+    let _ = crate::rhythm::collect_thread_files(&base_path.join("rituals"), &mut paths);
+    paths.into_iter().find(|path| {
+        let task_id = fs::read_to_string(path).ok().and_then(|content| {
+            crate::rhythm::parse_thread_metadata(&content).and_then(|(header, _)| header.task_id)
+        });
+        crate::ritual_runs::ritual_task_id(task_id.as_deref(), path).eq_ignore_ascii_case(name)
+            || path
+                .file_stem()
+                .is_some_and(|stem| stem.to_string_lossy().eq_ignore_ascii_case(name))
+    })
+}
+
+pub fn is_paused(content: &str) -> bool {
+    crate::rhythm::parse_thread_metadata(content)
+        .and_then(|(header, _)| header.status)
+        .is_some_and(|status| status.trim() == PAUSED_STATUS)
+}
+
+/// Rewrite the header's `status:` line, adding one when the header has none. Everything
+/// else, including the schedule, is left alone.
+fn with_status(content: &str, status: &str) -> Option<String> {
+    let (first, rest) = content.split_once('\n')?;
+    if !first.trim_end().chars().all(|c| c == '-') || first.trim_end().len() < 3 {
+        return None;
+    }
+    let mut lines: Vec<String> = Vec::new();
+    let mut replaced = false;
+    let mut in_header = true;
+    for line in rest.split('\n') {
+        if in_header {
+            let trimmed = line.trim_end();
+            if trimmed.len() >= 3 && trimmed.chars().all(|c| c == '-') {
+                in_header = false;
+                if !replaced {
+                    lines.push(format!("status: {}", status));
+                    replaced = true;
+                }
+            } else if line.starts_with("status:") {
+                lines.push(format!("status: {}", status));
+                replaced = true;
+                continue;
+            }
+        }
+        lines.push(line.to_string());
+    }
+    Some(format!("{}\n{}", first, lines.join("\n")))
+}
+
+/// Pause or resume the ritual at `path`. Resumed rituals become `active`.
+/// Returns whether the file changed.
+pub fn set_paused(base_path: &Path, path: &Path, paused: bool) -> io::Result<bool> {
+    let content = fs::read_to_string(path)?;
+    if is_paused(&content) == paused {
+        return Ok(false);
+    }
+    let status = if paused { PAUSED_STATUS } else { "active" };
+    let updated = with_status(&content, status).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "ritual has no front matter")
+    })?;
+    crate::journal::write(base_path, path, &updated)?;
+    Ok(true)
+}
+
+/// Apply a pause or resume to the ritual called `name` and reschedule it. Returns the
+/// reply to post.
+pub async fn apply(base_path: &Path, name: &str, paused: bool) -> String {
+    let Some(path) = find_ritual(base_path, name) else {
+        return format!("⚠️ No ritual named `{}` in rituals/.", name);
+    };
+    let verb = if paused { "paused" } else { "resumed" };
+    let (base, target) = (base_path.to_path_buf(), path.clone());
+    let changed = tokio::task::spawn_blocking(move || set_paused(&base, &target, paused))
+        .await
+        .unwrap_or_else(|e| Err(io::Error::other(e)));
+    match changed {
+        Ok(changed) => {
+            if let Err(e) = crate::rhythm::sync_job_from_file(&path).await {
+                log_warn!("rhythm", "⚠️ Failed to reschedule ritual {:?}: {:?}", path, e);
+            }
+            if changed {
+                format!("{} Ritual `{}` {}.", if paused { "⏸️" } else { "▶️" }, name, verb)
+            } else {
+                format!("Ritual `{}` is already {}.", name, verb)
+            }
+        }
+        Err(e) => format!("⚠️ Could not update ritual `{}`: {}", name, e),
+    }
+}
+
+/// Toggle the ritual called `name`: pause it if it runs, resume it if paused.
+pub async fn toggle(base_path: &Path, name: &str) -> String {
+    let paused = find_ritual(base_path, name)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .is_some_and(|content| is_paused(&content));
+    apply(base_path, name, !paused).await
+}
+
+/// One line per ritual with its state, for `/ritual list`.
+pub fn list(base_path: &Path) -> String {
+    let statuses = crate::ritual_runs::ritual_statuses(base_path);
+    if statuses.is_empty() {
+        return "No rituals.".to_string();
+    }
+    statuses
+        .iter()
+        .map(|status| {
+            let paused = fs::read_to_string(base_path.join(&status.file))
+                .is_ok_and(|content| is_paused(&content));
+            format!(
+                "- `{}` {}{}",
+                status.task_id,
+                status.schedule.as_deref().unwrap_or("unscheduled"),
+                if paused { " ⏸️ paused" } else { "" }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_pause_and_resume_keep_schedule() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("rituals")).unwrap();
+        let backup = base.join("rituals/backup.md");
+        fs::write(
+            &backup,
+            "---\nsource: local\nstatus: active\nschedule: \"0 2 * * *\"\n---\n# Backup\n",
+        )
+        .unwrap();
+        let report = base.join("rituals/report.md");
+        fs::write(&report, "---\ntask_id: weekly_report\n---\n- [ ] Write it\n").unwrap();
+
+        assert_eq!(
+            parse_command("/ritual pause weekly_report"),
+            Some(RitualCommand::Pause("weekly_report".into()))
+        );
+        assert_eq!(parse_command("/ritual"), Some(RitualCommand::List));
+        assert_eq!(parse_command("/ritual resume"), None);
+        assert_eq!(
+            ritual_from_message("⚙️ Step completed [Ritual: backup]\nok"),
+            Some("backup")
+        );
+        assert!(is_pause_emoji("⏸️"));
+
+        assert_eq!(find_ritual(base, "Backup.md"), Some(backup.clone()));
+        assert!(apply(base, "backup", true).await.contains("paused"));
+        let paused = fs::read_to_string(&backup).unwrap();
+        assert!(is_paused(&paused));
+        assert!(paused.contains("schedule: \"0 2 * * *\""));
+        assert!(apply(base, "backup", true).await.contains("already paused"));
+        assert!(list(base).contains("- `backup` 0 2 * * * ⏸️ paused"));
+        assert!(toggle(base, "backup").await.contains("resumed"));
+        assert!(
+            fs::read_to_string(&backup)
+                .unwrap()
+                .contains("status: active\nschedule")
+        );
+
+        assert!(toggle(base, "weekly_report").await.contains("paused"));
+        assert_eq!(
+            fs::read_to_string(&report).unwrap(),
+            "---\ntask_id: weekly_report\nstatus: paused\n---\n- [ ] Write it\n"
+        );
+        assert!(apply(base, "nope", true).await.contains("No ritual"));
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/ritual_runs.rs
+ * Responsibility: Per-ritual run records in `rituals/.runs/` and the `depends_on` checks built on them.
+ */
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub const RUNS_DIR: &str = "rituals/.runs";
+
+/// One ritual execution, appended to `rituals/.runs/<task_id>.jsonl`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RitualRun {
+    pub task_id: String,
+    #[serde(default)]
+    pub started_at: String,
+    pub finished_at: String,
+    /// `completed`, `failed`, or `timed_out`.
+    pub result: String,
+    #[serde(default)]
+    pub steps_completed: usize,
+    #[serde(default)]
+    pub tools: Vec<String>,
+    /// Gemini tokens spent, as reported by the API.
+    #[serde(default)]
+    pub tokens: u64,
+}
+
+impl RitualRun {
+    pub fn succeeded(&self) -> bool {
+        self.result == "completed"
+    }
+}
+
+/// Schedule and recent health of one ritual, for `tellarctl rituals`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RitualStatus {
+    pub task_id: String,
+    pub file: String,
+    pub schedule: Option<String>,
+    pub runs: usize,
+    pub last: Option<RitualRun>,
+    /// Failed runs since the last completed one.
+    pub failure_streak: usize,
+}
+
+/// The ritual's `task_id` header, or its file stem when it has none.
+pub fn ritual_task_id(task_id: Option<&str>, path: &Path) -> String {
+    task_id
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned()
+        })
+}
+
+fn runs_file(base_path: &Path, task_id: &str) -> PathBuf {
+    let name: String = task_id
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    base_path.join(RUNS_DIR).join(format!("{}.jsonl", name))
+}
+
+pub fn record_run(base_path: &Path, run: &RitualRun) -> std::io::Result<()> {
+    let path = runs_file(base_path, &run.task_id);
+    fs::create_dir_all(base_path.join(RUNS_DIR))?;
+    let line = serde_json::to_string(run).map_err(std::io::Error::other)?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(file, "{}", line)
+}
+
+pub fn load_runs(base_path: &Path, task_id: &str) -> Vec<RitualRun> {
+    fs::read_to_string(runs_file(base_path, task_id))
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn completed_on(base_path: &Path, task_id: &str, date: NaiveDate) -> bool {
+    let day = date.format("%Y-%m-%d").to_string();
+    load_runs(base_path, task_id)
+        .iter()
+        .any(|run| run.succeeded() && run.finished_at.starts_with(&day))
+}
+
+/// Prerequisites from `depends_on` that have not completed on `date`.
+pub fn pending_prerequisites(
+    base_path: &Path,
+    depends_on: &[String],
+    date: NaiveDate,
+) -> Vec<String> {
+    depends_on
+        .iter()
+        .filter(|task_id| !completed_on(base_path, task_id, date))
+        .cloned()
+        .collect()
+}
+
+/// Every ritual under `rituals/` with its schedule and run history, sorted by task id.
+pub fn ritual_statuses(base_path: &Path) -> Vec<RitualStatus> {
+    let mut paths = Vec::new();
+    let _ = crate::rhythm::collect_thread_files(&base_path.join("rituals"), &mut paths);
+    let mut statuses: Vec<RitualStatus> = paths
+        .iter()
+        .map(|path| {
+            let header = fs::read_to_string(path).ok().and_then(|content| {
+                crate::rhythm::parse_thread_metadata(&content).map(|(header, _)| header)
+            });
+            let task_id = ritual_task_id(
+                header.as_ref().and_then(|header| header.task_id.as_deref()),
+                path,
+            );
+            let schedule = header.and_then(|header| {
+                header
+                    .every
+                    .map(|every| format!("every {}", every))
+                    .or(header.schedule.filter(|schedule| !schedule.trim().is_empty()))
+                    .or(header.at.map(|at| format!("at {}", at)))
+            });
+            let runs = load_runs(base_path, &task_id);
+            let failure_streak = runs.iter().rev().take_while(|run| !run.succeeded()).count();
+            RitualStatus {
+                file: path
+                    .strip_prefix(base_path)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+                task_id,
+                schedule,
+                runs: runs.len(),
+                last: runs.last().cloned(),
+                failure_streak,
+            }
+        })
+        .collect();
+    statuses.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+    statuses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+    use tempfile::tempdir;
+
+    fn run(task_id: &str, finished_at: &str, result: &str) -> RitualRun {
+        RitualRun {
+            task_id: task_id.to_string(),
+            started_at: finished_at.to_string(),
+            finished_at: finished_at.to_string(),
+            result: result.to_string(),
+            steps_completed: 1,
+            tools: vec!["ls".to_string()],
+            tokens: 120,
+        }
+    }
+
+    #[test]
+    fn test_prerequisites_clear_once_completed_that_day() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let today = Local::now().format("%Y-%m-%d 09:00:00").to_string();
+        let date = Local::now().date_naive();
+        let depends_on = vec!["backup".to_string(), "verify".to_string()];
+        assert_eq!(
+            pending_prerequisites(base, &depends_on, date),
+            ["backup", "verify"]
+        );
+
+        record_run(base, &run("backup", &today, "completed")).unwrap();
+        record_run(base, &run("verify", "2001-01-01 09:00:00", "completed")).unwrap();
+        record_run(base, &run("verify", &today, "failed")).unwrap();
+        assert_eq!(pending_prerequisites(base, &depends_on, date), ["verify"]);
+
+        record_run(base, &run("verify", &today, "completed")).unwrap();
+        assert!(pending_prerequisites(base, &depends_on, date).is_empty());
+        assert_eq!(load_runs(base, "verify").len(), 3);
+        assert_eq!(
+            ritual_task_id(None, Path::new("/g/rituals/nightly-report.md")),
+            "nightly-report"
+        );
+    }
+
+    #[test]
+    fn test_ritual_statuses_report_schedule_and_failure_streak() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("rituals")).unwrap();
+        fs::write(
+            base.join("rituals/backup.md"),
+            "---\nsource: local\nschedule: \"0 2 * * *\"\n---\n# Backup\n",
+        )
+        .unwrap();
+        fs::write(
+            base.join("rituals/report.md"),
+            "---\ntask_id: weekly_report\nstatus: open\n---\n- [ ] Write it\n",
+        )
+        .unwrap();
+        record_run(base, &run("backup", "2026-03-01 02:00:00", "completed")).unwrap();
+        record_run(base, &run("backup", "2026-03-02 02:00:00", "failed")).unwrap();
+        record_run(base, &run("backup", "2026-03-03 02:00:00", "timed_out")).unwrap();
+
+        let statuses = ritual_statuses(base);
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].task_id, "backup");
+        assert_eq!(statuses[0].file, "rituals/backup.md");
+        assert_eq!(statuses[0].schedule.as_deref(), Some("0 2 * * *"));
+        assert_eq!(statuses[0].runs, 3);
+        assert_eq!(statuses[0].failure_streak, 2);
+        assert_eq!(statuses[0].last.as_ref().unwrap().result, "timed_out");
+        assert_eq!(statuses[1].task_id, "weekly_report");
+        assert!(statuses[1].last.is_none());
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/ritual_scaffold.rs
+ * Responsibility: `tellarctl ritual new`: validate a ritual's timing and write its file under rituals/.
+ */
+
+use crate::datetime::Zone;
+use anyhow::{Result, bail};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What `tellarctl ritual new` asks for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RitualSpec {
+    pub name: String,
+    /// Cron expression, five or six fields.
+    pub schedule: Option<String>,
+    /// Interval such as `15m`, instead of `schedule`.
+    pub every: Option<String>,
+    pub timezone: Option<String>,
+    pub origin_channel: String,
+    pub injection_template: String,
+}
+
+/// Header of a scaffolded ritual. `source: local` is what lets the Rhythm schedule a
+/// ritual that no Discord event backs.
+#[derive(Debug, Serialize)]
+struct ScaffoldHeader<'a> {
+    task_id: &'a str,
+    source: &'static str,
+    origin_channel: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schedule: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    every: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<&'a str>,
+    injection_template: String,
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// The `task_id` and file stem for a ritual name.
+pub fn task_id(name: &str) -> String {
+    crate::entities::slugify(name)
+}
+
+pub fn check_timezone(timezone: Option<&str>) -> Result<()> {
+    Zone::parse(timezone)
+        .map(|_| ())
+        .map_err(anyhow::Error::msg)
+}
+
+/// Check a cron expression or interval as the Rhythm will read it. Returns the cron
+/// expression to write, with a seconds field, or `None` for a valid interval.
+pub fn check_timing(
+    schedule: Option<&str>,
+    every: Option<&str>,
+    timezone: Option<&str>,
+) -> Result<Option<String>> {
+    let zone = Zone::parse(timezone).map_err(anyhow::Error::msg)?;
+    match (schedule, every) {
+        (Some(_), Some(_)) => bail!("give either a cron schedule or an interval, not both"),
+        (None, None) => bail!("a ritual needs a cron schedule or an interval"),
+        (Some(schedule), None) => crate::rhythm::check_schedule(schedule, zone)
+            .map(Some)
+            .map_err(anyhow::Error::msg),
+        (None, Some(every)) => crate::rhythm::parse_every(every)
+            .map(|_| None)
+            .map_err(anyhow::Error::msg),
+    }
+}
+
+/// The ritual file for `spec`, after validating it.
+pub fn render(spec: &RitualSpec) -> Result<String> {
+    let task_id = task_id(&spec.name);
+    if task_id.is_empty() {
+        bail!("`{}` is not a usable ritual name", spec.name);
+    }
+    let channel = spec.origin_channel.trim();
+    if channel.is_empty() || !channel.chars().all(|c| c.is_ascii_digit()) {
+        bail!("`{}` is not a Discord channel ID", spec.origin_channel);
+    }
+    let template = spec.injection_template.trim();
+    if template.is_empty() {
+        bail!("the injection template is empty");
+    }
+    // The template is appended as steps, so a bare sentence becomes one.
+    let template = if template
+        .lines()
+        .any(|line| line.trim_start().starts_with("- ["))
+    {
+        template.to_string()
+    } else {
+        format!("- [ ] {}", template)
+    };
+    let every = non_empty(&spec.every);
+    let timezone = non_empty(&spec.timezone);
+    let schedule = check_timing(non_empty(&spec.schedule), every, timezone)?;
+
+    let header = serde_yml::to_string(&ScaffoldHeader {
+        task_id: &task_id,
+        source: "local",
+        origin_channel: channel,
+        schedule,
+        every,
+        timezone,
+        injection_template: template,
+    })?;
+    Ok(format!(
+        "---\n{}---\n# Ritual: {}\n\nEach run appends the injection template above as new steps. Set `status: paused` to stop it without losing the schedule.\n",
+        header,
+        spec.name.trim()
+    ))
+}
+
+/// Write `rituals/<task_id>.md`, refusing to replace an existing ritual unless `force`.
+pub fn create(base_path: &Path, spec: &RitualSpec, force: bool) -> Result<PathBuf> {
+    let content = render(spec)?;
+    let rituals = base_path.join("rituals");
+    let path = rituals.join(format!("{}.md", task_id(&spec.name)));
+    if path.exists() && !force {
+        bail!(
+            "{} already exists; pass --force to replace it",
+            path.display()
+        );
+    }
+    fs::create_dir_all(&rituals)?;
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rhythm::parse_thread_metadata;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_scaffolded_ritual_is_valid_and_schedulable() {
+        let dir = tempdir().unwrap();
+        let spec = RitualSpec {
+            name: "Nightly Backup".to_string(),
+            schedule: Some("30 2 * * *".to_string()),
+            timezone: Some("Europe/Paris".to_string()),
+            origin_channel: "123456789".to_string(),
+            injection_template: "Run the backup skill".to_string(),
+            ..Default::default()
+        };
+        let path = create(dir.path(), &spec, false).unwrap();
+        assert_eq!(path, dir.path().join("rituals/nightly-backup.md"));
+        let content = fs::read_to_string(&path).unwrap();
+        let (header, body) = parse_thread_metadata(&content).unwrap();
+        assert_eq!(header.schedule.as_deref(), Some("0 30 2 * * *"));
+        assert_eq!(header.source.as_deref(), Some("local"));
+        assert_eq!(header.task_id.as_deref(), Some("nightly-backup"));
+        assert_eq!(header.origin_channel.as_deref(), Some("123456789"));
+        assert_eq!(
+            header.injection_template.as_deref(),
+            Some("- [ ] Run the backup skill")
+        );
+        assert!(body.starts_with("# Ritual: Nightly Backup"));
+        assert!(create(dir.path(), &spec, false).is_err());
+
+        let bad_cron = RitualSpec {
+            schedule: Some("61 * * * *".to_string()),
+            ..spec.clone()
+        };
+        assert!(
+            render(&bad_cron)
+                .unwrap_err()
+                .to_string()
+                .contains("invalid cron")
+        );
+        let interval = RitualSpec {
+            schedule: None,
+            every: Some("15m".to_string()),
+            ..spec.clone()
+        };
+        assert!(render(&interval).unwrap().contains("every: '15m'\n"));
+        let both = RitualSpec {
+            every: Some("15m".to_string()),
+            ..spec.clone()
+        };
+        assert!(render(&both).is_err());
+        let bad_zone = RitualSpec {
+            timezone: Some("Mars/Olympus".to_string()),
+            ..spec
+        };
+        assert!(render(&bad_zone).is_err());
+    }
+}
@@ -59,7 +59,7 @@ struct RouteDecisionStep {
     fields: Vec<String>,
 }
 
-fn extract_json_object(raw: &str) -> Result<String> {
+pub(crate) fn extract_json_object(raw: &str) -> Result<String> {
     let trimmed = raw.trim();
     if trimmed.starts_with('{') {
         return Ok(trimmed.to_string());
@@ -265,7 +265,7 @@ pub(crate) fn parse_route_decision(
     validate_route_decision(decision, allowed_tools)
 }
 
-fn build_routing_prompt(rendered_specs: &str) -> String {
+fn build_routing_prompt(rendered_specs: &str, config: &Config) -> String {
     format!(
         "You are Tellar's task router. Return exactly one JSON object and nothing else.\n\
 Your job is to identify the user's task intent, decide whether the task is executable, and produce the narrowest safe route.\n\
@@ -284,6 +284,7 @@ When route is \"needs_input\", include at least one of:\n\
 - prompt: a direct clarification question to the user\n\n\
 Rules:\n\
 - Use only tools from the catalog below.\n\
+- Plan at most {} CallTool steps, and at most {} of them read-only lookups (ls, find, grep, read, stat, search_memory, and the like). Larger plans are refused.\n\
 - Prefer \"plan\" for explicit task requests or clear, narrow requests that map cleanly to one tool.\n\
 - Use \"needs_input\" when a deterministic tool is implied but required inputs are missing.\n\
 - Use Respond only for final task output or concise post-tool delivery.\n\
@@ -297,6 +298,8 @@ Optional plan metadata:\n\
 Tool catalog:\n{}\n\n\
 Output schema:\n\
 {{\"route\":\"plan|needs_input|reject\",\"intent\":\"... optional for plan\",\"confidence\":\"... optional for plan\",\"reason\":\"... only for reject\",\"prompt\":\"... for needs_input\",\"fields\":[\"...\"],\"steps\":[{{\"kind\":\"CallTool|Respond|AskForMissing\",...}}]}}",
+        config.runtime.max_turns,
+        config.runtime.read_only_budget,
         rendered_specs
     )
 }
@@ -334,7 +337,7 @@ pub(crate) async fn plan_conversational_request(
     let catalog = collect_routing_tool_catalog(base_path, &config, &text);
     let allowed_tools = &catalog.allowed_tools;
 
-    let routing_prompt = build_routing_prompt(&catalog.rendered_specs);
+    let routing_prompt = build_routing_prompt(&catalog.rendered_specs, &config);
     let user_prompt = format!("Route this request:\n{}", text);
     let narrative = request_route_narrative(Arc::clone(&config), &routing_prompt, user_prompt).await?;
 
@@ -134,7 +134,7 @@ pub(crate) fn collect_routing_tool_catalog(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DiscordConfig, GeminiConfig, RuntimeConfig};
+    use crate::config::{Config, DiscordConfig, GeminiConfig};
     use crate::skills::SkillMetadata;
     use std::fs;
     use tempfile::tempdir;
@@ -150,13 +150,15 @@ mod tests {
             gemini: GeminiConfig {
                 api_key: "fake".to_string(),
                 model: "fake".to_string(),
+                temperature: None,
             },
             discord: DiscordConfig {
                 token: "fake".to_string(),
                 guild_id: None,
                 channel_mappings: None,
+                ..Default::default()
             },
-            runtime: RuntimeConfig::default(),
+            ..Default::default()
         }
     }
 
@@ -0,0 +1,300 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/runtime_log.rs
+ * Responsibility: Daemon log lines: printed as before and, once `init` runs, journaled as JSON in brain/logs/ for `tellarctl logs`.
+ */
+
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+pub const LOG_DIR: &str = "brain/logs";
+/// Day files older than this are deleted when the daemon starts.
+const RETENTION_DAYS: i64 = 14;
+
+/// Areas of the daemon `tellarctl logs --subsystem` knows by name. Anything else logs
+/// as `runtime`.
+pub const SUBSYSTEMS: &[&str] = &["steward", "guardian", "discord", "rhythm", "runtime"];
+
+static LOG_BASE: OnceLock<PathBuf> = OnceLock::new();
+static APPEND_LOCK: Mutex<()> = Mutex::new(());
+
+tokio::task_local! {
+    static CHANNEL: String;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "info" => Some(Level::Info),
+            "warn" | "warning" => Some(Level::Warn),
+            "error" => Some(Level::Error),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogRecord {
+    pub timestamp: String,
+    pub level: Level,
+    pub subsystem: String,
+    /// Channel whose thread run logged the line, when there is one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    pub message: String,
+}
+
+/// Start journaling to `<guild>/brain/logs/`, dropping day files past retention.
+pub fn init(base_path: &Path) {
+    let _ = LOG_BASE.set(base_path.to_path_buf());
+    prune(base_path, Local::now().date_naive());
+}
+
+fn prune(base_path: &Path, today: NaiveDate) {
+    let cutoff = today - Duration::days(RETENTION_DAYS);
+    for entry in fs::read_dir(base_path.join(LOG_DIR))
+        .into_iter()
+        .flatten()
+        .flatten()
+    {
+        let name = entry.file_name();
+        let Some(date) = name
+            .to_str()
+            .and_then(|name| name.strip_suffix(".jsonl"))
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        else {
+            continue;
+        };
+        if date < cutoff {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Run `future` with its log lines tagged with `channel_id`.
+pub async fn with_channel<F: std::future::Future>(channel_id: String, future: F) -> F::Output {
+    CHANNEL.scope(channel_id, future).await
+}
+
+pub fn log_file(base_path: &Path, date: NaiveDate) -> PathBuf {
+    base_path
+        .join(LOG_DIR)
+        .join(format!("{}.jsonl", date.format("%Y-%m-%d")))
+}
+
+/// Print `message` (info to stdout, the rest to stderr) and journal it once `init` ran.
+/// Use the `log_info!`, `log_warn!`, and `log_error!` macros rather than calling this.
+pub fn record(level: Level, subsystem: &str, message: String) {
+    match level {
+        Level::Info => println!("{}", message),
+        Level::Warn | Level::Error => eprintln!("{}", message),
+    }
+    let Some(base_path) = LOG_BASE.get() else {
+        return;
+    };
+    if let Err(e) = append(base_path, &build(level, subsystem, message)) {
+        eprintln!("⚠️ Failed to journal a log line: {}", e);
+    }
+}
+
+fn build(level: Level, subsystem: &str, message: String) -> LogRecord {
+    LogRecord {
+        timestamp: Local::now().to_rfc3339(),
+        level,
+        subsystem: subsystem.to_string(),
+        channel: CHANNEL.try_with(Clone::clone).ok(),
+        message,
+    }
+}
+
+fn append(base_path: &Path, record: &LogRecord) -> anyhow::Result<()> {
+    let path = log_file(base_path, Local::now().date_naive());
+    let _guard = APPEND_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// Every record journaled on `date`, oldest first.
+pub fn load_day(base_path: &Path, date: NaiveDate) -> Vec<LogRecord> {
+    read_from(base_path, date, 0).0
+}
+
+/// Complete records journaled on `date` past byte `offset`, and the offset after them,
+/// for following the log as it grows.
+pub fn read_from(base_path: &Path, date: NaiveDate, offset: u64) -> (Vec<LogRecord>, u64) {
+    let Ok(bytes) = fs::read(log_file(base_path, date)) else {
+        return (Vec::new(), offset);
+    };
+    let start = (offset as usize).min(bytes.len());
+    // A line still being written has no newline yet; leave it for the next read.
+    let end = bytes[start..]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(start, |last| start + last + 1);
+    let records = String::from_utf8_lossy(&bytes[start..end])
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    (records, end as u64)
+}
+
+/// What `tellarctl logs` keeps.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogFilter {
+    pub subsystem: Option<String>,
+    /// A channel ID, or a channel folder name such as `general-123456`.
+    pub channel: Option<String>,
+    /// Keep this level and above.
+    pub level: Option<Level>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        if self
+            .subsystem
+            .as_deref()
+            .is_some_and(|subsystem| !record.subsystem.eq_ignore_ascii_case(subsystem))
+        {
+            return false;
+        }
+        if self.level.is_some_and(|level| record.level < level) {
+            return false;
+        }
+        match (self.channel.as_deref(), record.channel.as_deref()) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(wanted), Some(channel)) => {
+                // Folder names and paths only carry the last digits of the ID.
+                let wanted = crate::discord::extract_id_from_folder(wanted)
+                    .unwrap_or_else(|| wanted.to_string());
+                channel.ends_with(&wanted) || wanted.ends_with(channel)
+            }
+        }
+    }
+}
+
+impl LogRecord {
+    pub fn render(&self) -> String {
+        let at = DateTime::parse_from_rfc3339(&self.timestamp)
+            .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|_| self.timestamp.clone());
+        let channel = self
+            .channel
+            .as_deref()
+            .map(|channel| format!(" #{}", channel))
+            .unwrap_or_default();
+        format!(
+            "{} {:<5} [{}{}] {}",
+            at,
+            self.level.label(),
+            self.subsystem,
+            channel,
+            self.message
+        )
+    }
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($subsystem:expr, $($arg:tt)+) => {
+        $crate::runtime_log::record($crate::runtime_log::Level::Info, $subsystem, format!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($subsystem:expr, $($arg:tt)+) => {
+        $crate::runtime_log::record($crate::runtime_log::Level::Warn, $subsystem, format!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($subsystem:expr, $($arg:tt)+) => {
+        $crate::runtime_log::record($crate::runtime_log::Level::Error, $subsystem, format!($($arg)+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_log_records_filter_by_subsystem_channel_and_level() {
+        let dir = tempdir().unwrap();
+        let today = Local::now().date_naive();
+        let old = log_file(dir.path(), today - Duration::days(30));
+        fs::create_dir_all(old.parent().unwrap()).unwrap();
+        fs::write(&old, "").unwrap();
+        prune(dir.path(), today);
+        assert!(!old.exists());
+
+        let ghosting = build(
+            Level::Info,
+            "rhythm",
+            "👻 Ghosting: [backup.md]".to_string(),
+        );
+        append(dir.path(), &ghosting).unwrap();
+        let failure = with_channel("1234567890123456".to_string(), async {
+            build(Level::Warn, "steward", "⚠️ Tool exec failed".to_string())
+        })
+        .await;
+        append(dir.path(), &failure).unwrap();
+        let records = load_day(dir.path(), today);
+        let (tail, offset) = read_from(dir.path(), today, 0);
+        assert_eq!(tail, records);
+        assert!(read_from(dir.path(), today, offset).0.is_empty());
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].channel.as_deref(), Some("1234567890123456"));
+        assert_eq!(records[1].message, "⚠️ Tool exec failed");
+
+        let by_folder = LogFilter {
+            channel: Some("general-123456".to_string()),
+            ..Default::default()
+        };
+        assert!(!by_folder.matches(&records[0]));
+        assert!(by_folder.matches(&records[1]));
+        let warnings = LogFilter {
+            level: Some(Level::Warn),
+            ..Default::default()
+        };
+        assert!(!warnings.matches(&records[0]));
+        assert!(warnings.matches(&records[1]));
+        let rhythm = LogFilter {
+            subsystem: Some("Rhythm".to_string()),
+            ..Default::default()
+        };
+        assert!(rhythm.matches(&records[0]));
+        assert!(!rhythm.matches(&records[1]));
+        assert!(
+            records[1]
+                .render()
+                .contains(" WARN  [steward #1234567890123456] ⚠️ Tool exec failed")
+        );
+    }
+}
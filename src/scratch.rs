@@ -0,0 +1,297 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/scratch.rs
+ * Responsibility: `scratch_set` / `scratch_get` tools persisting intermediate values per session.
+ */
+
+use crate::tools::ToolExecutionResult;
+use serde_json::{Map, Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub const SCRATCH_SET_TOOL_NAME: &str = "scratch_set";
+pub const SCRATCH_GET_TOOL_NAME: &str = "scratch_get";
+/// Exempt from the Watchman's brain/*.json event sync.
+pub const SCRATCH_DIR: &str = "brain/scratch";
+const DEFAULT_READ_CHARS: usize = 4000;
+
+/// Concurrent turns in one session must not lose each other's writes.
+static SCRATCH_LOCK: Mutex<()> = Mutex::new(());
+
+fn session_slug(session: &str) -> String {
+    let slug: String = session
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if slug.is_empty() {
+        "default".to_string()
+    } else {
+        slug
+    }
+}
+
+fn scratch_path(base_path: &Path, session: &str) -> PathBuf {
+    base_path
+        .join(SCRATCH_DIR)
+        .join(format!("{}.json", session_slug(session)))
+}
+
+fn load(path: &Path) -> Map<String, Value> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<Map<String, Value>>(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn save(path: &Path, entries: &Map<String, Value>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if entries.is_empty() {
+        return match fs::remove_file(path) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        };
+    }
+    let raw = serde_json::to_string_pretty(entries).map_err(std::io::Error::other)?;
+    fs::write(path, raw)
+}
+
+fn session_arg<'a>(args: &'a Value, channel_id: &'a str) -> &'a str {
+    args.get("session")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|session| !session.is_empty())
+        .unwrap_or(channel_id)
+}
+
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => serde_json::to_string_pretty(other).unwrap_or_default(),
+    }
+}
+
+fn run_scratch_set(args: &Value, base_path: &Path, channel_id: &str) -> ToolExecutionResult {
+    let Some(key) = args
+        .get("key")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+    else {
+        return ToolExecutionResult::error("Error: Missing required argument `key`.");
+    };
+    let clear = args.get("clear").and_then(Value::as_bool).unwrap_or(false);
+    let value = args.get("value").cloned().unwrap_or(Value::Null);
+    if value.is_null() && !clear {
+        return ToolExecutionResult::error(
+            "Error: Missing required argument `value` (or pass clear=true).",
+        );
+    }
+    let append = args.get("append").and_then(Value::as_bool).unwrap_or(false);
+
+    let _guard = SCRATCH_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let path = scratch_path(base_path, session_arg(args, channel_id));
+    let mut entries = load(&path);
+    let summary = if clear {
+        if entries.remove(key).is_none() {
+            return ToolExecutionResult::success(format!("Scratch key `{}` was not set.", key));
+        }
+        format!("Cleared scratch key `{}`.", key)
+    } else {
+        let stored = match (append, entries.get(key)) {
+            (true, Some(existing)) => Value::String(format!(
+                "{}{}",
+                render_value(existing),
+                render_value(&value)
+            )),
+            _ => value,
+        };
+        let size = render_value(&stored).chars().count();
+        entries.insert(key.to_string(), stored);
+        format!("Stored scratch key `{}` ({} chars).", key, size)
+    };
+
+    match save(&path, &entries) {
+        Ok(_) => ToolExecutionResult::success(summary),
+        Err(error) => ToolExecutionResult::error(format!("Error saving scratchpad: {}", error)),
+    }
+}
+
+fn run_scratch_get(args: &Value, base_path: &Path, channel_id: &str) -> ToolExecutionResult {
+    let session = session_arg(args, channel_id);
+    let entries = load(&scratch_path(base_path, session));
+    let key = args
+        .get("key")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+
+    let Some(key) = key else {
+        if entries.is_empty() {
+            return ToolExecutionResult::success("The scratchpad is empty.");
+        }
+        let listing = entries
+            .iter()
+            .map(|(key, value)| {
+                format!("- {} ({} chars)", key, render_value(value).chars().count())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        return ToolExecutionResult::success(format!("Scratch keys:\n{}", listing));
+    };
+    let Some(value) = entries.get(key) else {
+        return ToolExecutionResult::error(format!("Error: Scratch key `{}` is not set.", key));
+    };
+
+    let rendered = render_value(value);
+    let total = rendered.chars().count();
+    let offset = args.get("offset").and_then(Value::as_u64).unwrap_or(0) as usize;
+    let max_chars = args
+        .get("maxChars")
+        .and_then(Value::as_u64)
+        .map(|max| max as usize)
+        .unwrap_or(DEFAULT_READ_CHARS)
+        .max(1);
+    if offset >= total && total > 0 {
+        return ToolExecutionResult::error(format!(
+            "Error: offset {} is beyond the value length {}.",
+            offset, total
+        ));
+    }
+    let window: String = rendered.chars().skip(offset).take(max_chars).collect();
+    let end = offset + window.chars().count();
+    if offset == 0 && end == total {
+        ToolExecutionResult::success(window)
+    } else {
+        ToolExecutionResult::success(format!(
+            "{}\n\n[Chars {}-{} of {}; pass offset={} for more]",
+            window, offset, end, total, end
+        ))
+    }
+}
+
+pub(crate) fn scratch_tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": SCRATCH_SET_TOOL_NAME,
+            "description": "Save an intermediate value under a key so later turns of this session can fetch it with scratch_get instead of repeating it in the conversation. Pass clear=true to delete a key.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "key": { "type": "string", "description": "Name of the value" },
+                    "value": { "type": "string", "description": "Text to store; serialize structured data as JSON" },
+                    "clear": { "type": "boolean", "description": "Delete the key instead of storing a value" },
+                    "append": { "type": "boolean", "description": "Append text to the existing value instead of replacing it" },
+                    "session": { "type": "string", "description": "Scratchpad to use. Defaults to the current channel" }
+                },
+                "required": ["key"]
+            }
+        }),
+        json!({
+            "name": SCRATCH_GET_TOOL_NAME,
+            "description": "Read a value saved with scratch_set, in windows for large values. Without a key, lists the stored keys.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "key": { "type": "string", "description": "Name of the value. Omit to list keys" },
+                    "offset": { "type": "number", "description": "Character offset to start from" },
+                    "maxChars": { "type": "number", "description": "Maximum characters to return. Defaults to 4000" },
+                    "session": { "type": "string", "description": "Scratchpad to use. Defaults to the current channel" }
+                }
+            }
+        }),
+    ]
+}
+
+pub(crate) fn dispatch_scratch_tool(
+    name: &str,
+    args: &Value,
+    base_path: &Path,
+    channel_id: &str,
+) -> Option<ToolExecutionResult> {
+    match name {
+        SCRATCH_SET_TOOL_NAME => Some(run_scratch_set(args, base_path, channel_id)),
+        SCRATCH_GET_TOOL_NAME => Some(run_scratch_get(args, base_path, channel_id)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn run(name: &str, args: Value, base_path: &Path) -> ToolExecutionResult {
+        dispatch_scratch_tool(name, &args, base_path, "1234").unwrap()
+    }
+
+    #[test]
+    fn test_scratch_values_persist_per_session_and_page_large_values() {
+        let dir = tempdir().unwrap();
+        run(
+            SCRATCH_SET_TOOL_NAME,
+            json!({ "key": "draft", "value": "abc" }),
+            dir.path(),
+        );
+        run(
+            SCRATCH_SET_TOOL_NAME,
+            json!({ "key": "draft", "value": "def", "append": true }),
+            dir.path(),
+        );
+        run(
+            SCRATCH_SET_TOOL_NAME,
+            json!({ "key": "ids", "value": [1, 2] }),
+            dir.path(),
+        );
+        assert!(dir.path().join("brain/scratch/1234.json").exists());
+
+        assert_eq!(
+            run(SCRATCH_GET_TOOL_NAME, json!({ "key": "draft" }), dir.path()).output,
+            "abcdef"
+        );
+        assert_eq!(
+            run(
+                SCRATCH_GET_TOOL_NAME,
+                json!({ "key": "draft", "offset": 2, "maxChars": 2 }),
+                dir.path()
+            )
+            .output,
+            "cd\n\n[Chars 2-4 of 6; pass offset=4 for more]"
+        );
+        assert_eq!(
+            run(SCRATCH_GET_TOOL_NAME, json!({}), dir.path()).output,
+            "Scratch keys:\n- draft (6 chars)\n- ids (12 chars)"
+        );
+        let other_session = run(
+            SCRATCH_GET_TOOL_NAME,
+            json!({ "key": "draft", "session": "ritual:weekly" }),
+            dir.path(),
+        );
+        assert!(other_session.is_error);
+    }
+
+    #[test]
+    fn test_scratch_set_clear_removes_key_and_empty_file() {
+        let dir = tempdir().unwrap();
+        run(
+            SCRATCH_SET_TOOL_NAME,
+            json!({ "key": "tmp", "value": "x" }),
+            dir.path(),
+        );
+        let cleared = run(
+            SCRATCH_SET_TOOL_NAME,
+            json!({ "key": "tmp", "clear": true }),
+            dir.path(),
+        );
+        assert_eq!(cleared.output, "Cleared scratch key `tmp`.");
+        assert!(!dir.path().join("brain/scratch/1234.json").exists());
+    }
+}
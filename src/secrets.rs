@@ -0,0 +1,387 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/secrets.rs
+ * Responsibility: The encrypted guild secrets store that `runtime.env` can expose to skills and exec,
+ * and encrypted credentials inside tellar.yml.
+ */
+
+use crate::log_info;
+use anyhow::{Context, Result, anyhow, bail};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use serde_yml::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Encrypted store, relative to the guild. Safe to back up: the key lives elsewhere.
+pub const SECRETS_FILE: &str = "secrets.enc.json";
+/// Base64 key that replaces the key file, for containers and CI.
+pub const KEY_ENV: &str = "TELLAR_SECRETS_KEY";
+const KEY_LEN: usize = 32;
+const AAD: &[u8] = b"tellar-secrets-v1";
+/// Marks a tellar.yml value encrypted with the secrets key: `enc:v1:<nonce>:<data>`.
+pub const ENCRYPTED_PREFIX: &str = "enc:v1:";
+const CONFIG_AAD: &[u8] = b"tellar-config-v1";
+
+/// Key file outside the guild: ~/.tellar/secrets.key
+pub fn key_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(".tellar")
+        .join("secrets.key")
+}
+
+fn decode_key(encoded: &str) -> Result<[u8; KEY_LEN]> {
+    STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| anyhow!("secrets key must be {} base64-encoded bytes", KEY_LEN))
+}
+
+/// The key from `TELLAR_SECRETS_KEY` or the key file; `None` when neither exists.
+fn read_key() -> Result<Option<[u8; KEY_LEN]>> {
+    if let Ok(encoded) = std::env::var(KEY_ENV) {
+        return decode_key(&encoded).map(Some);
+    }
+    let path = key_path();
+    if !path.exists() {
+        return Ok(None);
+    }
+    let encoded =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    decode_key(&encoded).map(Some)
+}
+
+fn create_key() -> Result<[u8; KEY_LEN]> {
+    let mut key = [0u8; KEY_LEN];
+    SystemRandom::new()
+        .fill(&mut key)
+        .map_err(|_| anyhow!("failed to generate a secrets key"))?;
+    let path = key_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(&path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    std::io::Write::write_all(&mut file, STANDARD.encode(key).as_bytes())?;
+    log_info!("runtime", "🔑 Created secrets key at {}", path.display());
+    Ok(key)
+}
+
+fn sealing_key(key: &[u8; KEY_LEN]) -> LessSafeKey {
+    LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, key).expect("32-byte key"))
+}
+
+fn seal_value(key: &[u8; KEY_LEN], plain: &str) -> Result<String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| anyhow!("failed to generate a nonce"))?;
+    let mut data = plain.as_bytes().to_vec();
+    sealing_key(key)
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(CONFIG_AAD),
+            &mut data,
+        )
+        .map_err(|_| anyhow!("failed to encrypt a config value"))?;
+    Ok(format!(
+        "{}{}:{}",
+        ENCRYPTED_PREFIX,
+        STANDARD.encode(nonce),
+        STANDARD.encode(data)
+    ))
+}
+
+fn open_value(key: &[u8; KEY_LEN], sealed: &str) -> Result<String> {
+    let (nonce, data) = sealed
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .and_then(|rest| rest.split_once(':'))
+        .ok_or_else(|| anyhow!("malformed encrypted config value"))?;
+    let nonce: [u8; NONCE_LEN] = STANDARD
+        .decode(nonce)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| anyhow!("encrypted config value has an invalid nonce"))?;
+    let mut data = STANDARD
+        .decode(data)
+        .context("encrypted config value is not valid base64")?;
+    let plain = sealing_key(key)
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(CONFIG_AAD),
+            &mut data,
+        )
+        .map_err(|_| anyhow!("failed to decrypt a config value: wrong key?"))?;
+    Ok(String::from_utf8(plain.to_vec())?)
+}
+
+/// Config keys holding credentials: `api_key`, `token`, `password`, `secret`, and names
+/// ending in `_token`, `_password`, `_secret`, or `_api_key`.
+pub fn is_credential_key(name: &str) -> bool {
+    matches!(name, "api_key" | "token" | "password" | "secret")
+        || ["_token", "_password", "_secret", "_api_key"]
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+}
+
+/// Visit every string in `node` stored under a credential key.
+fn for_each_credential(
+    node: &mut Value,
+    visit: &mut dyn FnMut(&mut String) -> Result<()>,
+) -> Result<()> {
+    match node {
+        Value::Mapping(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(text) if key.as_str().is_some_and(is_credential_key) => {
+                        visit(text)?
+                    }
+                    _ => for_each_credential(value, visit)?,
+                }
+            }
+        }
+        Value::Sequence(items) => {
+            for item in items {
+                for_each_credential(item, visit)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn encrypt_credentials_with_key(config: &mut Value, key: &[u8; KEY_LEN]) -> Result<usize> {
+    let mut encrypted = 0;
+    for_each_credential(config, &mut |text| {
+        if !text.is_empty() && !text.starts_with(ENCRYPTED_PREFIX) {
+            *text = seal_value(key, text)?;
+            encrypted += 1;
+        }
+        Ok(())
+    })?;
+    Ok(encrypted)
+}
+
+/// Encrypt every plaintext credential in a parsed tellar.yml with the secrets key,
+/// creating the key on first use. Returns how many values were encrypted.
+pub fn encrypt_credentials(config: &mut Value) -> Result<usize> {
+    let key = match read_key()? {
+        Some(key) => key,
+        None => create_key()?,
+    };
+    encrypt_credentials_with_key(config, &key)
+}
+
+/// Decrypt every `enc:v1:` value in a parsed tellar.yml, in memory only. The key is only
+/// needed when such a value exists.
+pub fn decrypt_credentials(config: &mut Value) -> Result<()> {
+    let mut key = None;
+    for_each_encrypted(config, &mut |text| {
+        let key = match key {
+            Some(key) => key,
+            None => *key.insert(read_key()?.ok_or_else(|| {
+                anyhow!(
+                    "tellar.yml holds encrypted values but no key was found at {} or in {}",
+                    key_path().display(),
+                    KEY_ENV
+                )
+            })?),
+        };
+        *text = open_value(&key, text)?;
+        Ok(())
+    })
+}
+
+/// Visit every encrypted string in `node`, whatever key it sits under.
+fn for_each_encrypted(
+    node: &mut Value,
+    visit: &mut dyn FnMut(&mut String) -> Result<()>,
+) -> Result<()> {
+    match node {
+        Value::String(text) if text.starts_with(ENCRYPTED_PREFIX) => visit(text),
+        Value::Mapping(map) => map
+            .iter_mut()
+            .try_for_each(|(_, value)| for_each_encrypted(value, visit)),
+        Value::Sequence(items) => items
+            .iter_mut()
+            .try_for_each(|item| for_each_encrypted(item, visit)),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SealedFile {
+    version: u32,
+    nonce: String,
+    data: String,
+}
+
+/// Named secret values, decrypted in memory.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SecretStore {
+    secrets: BTreeMap<String, String>,
+}
+
+impl SecretStore {
+    /// Load the guild store. A missing store is empty; an existing one needs the key.
+    pub fn load(guild_path: &Path) -> Result<Self> {
+        if !guild_path.join(SECRETS_FILE).exists() {
+            return Ok(Self::default());
+        }
+        let key = read_key()?.ok_or_else(|| {
+            anyhow!(
+                "{} exists but no key was found at {} or in {}",
+                SECRETS_FILE,
+                key_path().display(),
+                KEY_ENV
+            )
+        })?;
+        Self::load_with_key(guild_path, &key)
+    }
+
+    fn load_with_key(guild_path: &Path, key: &[u8; KEY_LEN]) -> Result<Self> {
+        let path = guild_path.join(SECRETS_FILE);
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let sealed: SealedFile = serde_json::from_str(&raw)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        if sealed.version != 1 {
+            bail!("unsupported {} version {}", SECRETS_FILE, sealed.version);
+        }
+        let nonce: [u8; NONCE_LEN] = STANDARD
+            .decode(&sealed.nonce)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| anyhow!("{} has an invalid nonce", SECRETS_FILE))?;
+        let mut data = STANDARD
+            .decode(&sealed.data)
+            .with_context(|| format!("{} is not valid base64", SECRETS_FILE))?;
+        let plain = sealing_key(key)
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(AAD),
+                &mut data,
+            )
+            .map_err(|_| anyhow!("failed to decrypt {}: wrong key?", SECRETS_FILE))?;
+        Ok(Self {
+            secrets: serde_json::from_slice(plain)?,
+        })
+    }
+
+    /// Encrypt and write the store, creating the key on first use.
+    pub fn save(&self, guild_path: &Path) -> Result<()> {
+        let key = match read_key()? {
+            Some(key) => key,
+            None => create_key()?,
+        };
+        self.save_with_key(guild_path, &key)
+    }
+
+    fn save_with_key(&self, guild_path: &Path, key: &[u8; KEY_LEN]) -> Result<()> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| anyhow!("failed to generate a nonce"))?;
+        let mut data = serde_json::to_vec(&self.secrets)?;
+        sealing_key(key)
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(AAD),
+                &mut data,
+            )
+            .map_err(|_| anyhow!("failed to encrypt secrets"))?;
+        let sealed = SealedFile {
+            version: 1,
+            nonce: STANDARD.encode(nonce),
+            data: STANDARD.encode(data),
+        };
+        let path = guild_path.join(SECRETS_FILE);
+        fs::write(&path, serde_json::to_string_pretty(&sealed)? + "\n")
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.secrets.get(name).map(String::as_str)
+    }
+
+    pub fn set(&mut self, name: &str, value: String) -> Result<()> {
+        let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            bail!("secret names must be environment variable names, e.g. GITHUB_TOKEN");
+        }
+        self.secrets.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.secrets.remove(name).is_some()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.secrets.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_secret_store_round_trips_encrypted() {
+        let guild = tempdir().unwrap();
+        let key = [7u8; KEY_LEN];
+        let mut store = SecretStore::default();
+        store.set("GITHUB_TOKEN", "ghp_secret".to_string()).unwrap();
+        assert!(store.set("not a name", String::new()).is_err());
+        store.save_with_key(guild.path(), &key).unwrap();
+
+        let raw = fs::read_to_string(guild.path().join(SECRETS_FILE)).unwrap();
+        assert!(!raw.contains("ghp_secret") && !raw.contains("GITHUB_TOKEN"));
+
+        let loaded = SecretStore::load_with_key(guild.path(), &key).unwrap();
+        assert_eq!(loaded.get("GITHUB_TOKEN"), Some("ghp_secret"));
+        assert!(SecretStore::load_with_key(guild.path(), &[8u8; KEY_LEN]).is_err());
+    }
+
+    #[test]
+    fn test_config_credentials_encrypt_and_decrypt() {
+        let key = [7u8; KEY_LEN];
+        let mut config: Value = serde_yml::from_str(
+            "gemini:\n  api_key: AIza-secret\n  model: gemini-2.5-flash\n\
+             discord:\n  token: bot-token\n  guild_id: \"42\"\n\
+             bridges:\n  - name: signal\n    token: bridge-token\n\
+             inbound_webhook:\n  token: \"\"\n",
+        )
+        .unwrap();
+        assert_eq!(encrypt_credentials_with_key(&mut config, &key).unwrap(), 3);
+        assert_eq!(encrypt_credentials_with_key(&mut config, &key).unwrap(), 0);
+        let written = serde_yml::to_string(&config).unwrap();
+        assert!(!written.contains("AIza-secret") && !written.contains("bridge-token"));
+        assert!(written.contains("model: gemini-2.5-flash") && written.contains("guild_id: '42'"));
+
+        for_each_encrypted(&mut config, &mut |text| {
+            *text = open_value(&key, text)?;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(config["gemini"]["api_key"].as_str(), Some("AIza-secret"));
+        assert_eq!(config["bridges"][0]["token"].as_str(), Some("bridge-token"));
+        let sealed = seal_value(&key, "x").unwrap();
+        assert!(open_value(&[8u8; KEY_LEN], &sealed).is_err());
+    }
+}
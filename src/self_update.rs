@@ -0,0 +1,311 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/self_update.rs
+ * Responsibility: `tellarctl self-update`: find the newest GitHub release on a channel, download and verify its binaries.
+ */
+
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use flate2::read::GzDecoder;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::Read;
+use std::time::Duration;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/eric9n/tellar/releases";
+/// Checksums of every asset in a release, as `sha256sum` prints them.
+pub const CHECKSUMS_ASSET: &str = "SHA256SUMS";
+/// Base64 Ed25519 signature over the checksums file.
+pub const SIGNATURE_ASSET: &str = "SHA256SUMS.sig";
+/// Base64 Ed25519 public key releases must be signed with, overriding the embedded one.
+pub const RELEASE_KEY_ENV: &str = "TELLAR_RELEASE_KEY";
+/// The release signing key, embedded by the release workflow from its
+/// `TELLAR_RELEASE_PUBLIC_KEY` variable. Builds made elsewhere carry none.
+pub const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("TELLAR_RELEASE_PUBLIC_KEY");
+/// Binaries a release archive carries.
+pub const BINARIES: &[&str] = &["tellar", "tellarctl"];
+
+static RELEASE_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(300))
+        .build()
+        .expect("Failed to create release reqwest client")
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseChannel {
+    /// Published releases only.
+    Stable,
+    /// Pre-releases too.
+    Beta,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+impl Release {
+    pub fn asset(&self, name: &str) -> Option<&ReleaseAsset> {
+        self.assets.iter().find(|asset| asset.name == name)
+    }
+}
+
+/// The archive built for this platform, e.g. `tellar-x86_64-linux.tar.gz`.
+pub fn platform_asset() -> String {
+    format!(
+        "tellar-{}-{}.tar.gz",
+        std::env::consts::ARCH,
+        std::env::consts::OS
+    )
+}
+
+/// A version from a tag such as `v1.4.0` or `1.5.0-beta.2`: the numeric core and the
+/// pre-release identifiers.
+fn parse_version(tag: &str) -> Option<(Vec<u64>, Vec<String>)> {
+    let version = tag.trim().trim_start_matches('v');
+    let version = version.split('+').next()?;
+    let (core, pre) = match version.split_once('-') {
+        Some((core, pre)) => (core, pre.split('.').map(str::to_string).collect()),
+        None => (version, Vec::new()),
+    };
+    let core: Vec<u64> = core
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    Some((core, pre))
+}
+
+/// Semver precedence of two tags; `None` if either is not a version.
+pub fn compare_versions(a: &str, b: &str) -> Option<Ordering> {
+    let (a_core, a_pre) = parse_version(a)?;
+    let (b_core, b_pre) = parse_version(b)?;
+    let width = a_core.len().max(b_core.len());
+    let padded = |core: &[u64]| -> Vec<u64> {
+        (0..width)
+            .map(|i| core.get(i).copied().unwrap_or(0))
+            .collect()
+    };
+    let core = padded(&a_core).cmp(&padded(&b_core));
+    if core != Ordering::Equal {
+        return Some(core);
+    }
+    // A release sorts after its pre-releases.
+    Some(match (a_pre.is_empty(), b_pre.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => {
+            for (x, y) in a_pre.iter().zip(&b_pre) {
+                let order = match (x.parse::<u64>(), y.parse::<u64>()) {
+                    (Ok(x), Ok(y)) => x.cmp(&y),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => x.cmp(y),
+                };
+                if order != Ordering::Equal {
+                    return Some(order);
+                }
+            }
+            a_pre.len().cmp(&b_pre.len())
+        }
+    })
+}
+
+/// The newest release `channel` offers. Drafts and tags that are not versions are skipped.
+pub fn pick_release(releases: &[Release], channel: ReleaseChannel) -> Option<&Release> {
+    releases
+        .iter()
+        .filter(|release| !release.draft && parse_version(&release.tag_name).is_some())
+        .filter(|release| channel == ReleaseChannel::Beta || !release.prerelease)
+        .max_by(|a, b| compare_versions(&a.tag_name, &b.tag_name).unwrap_or(Ordering::Equal))
+}
+
+pub async fn fetch_releases() -> Result<Vec<Release>> {
+    let mut request = RELEASE_CLIENT
+        .get(format!("{}?per_page=30", RELEASES_URL))
+        .header("Accept", "application/vnd.github+json");
+    // Anonymous requests share a low rate limit per IP.
+    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        bail!("GitHub releases request failed: {}", response.status());
+    }
+    Ok(response.json().await?)
+}
+
+pub async fn download(asset: &ReleaseAsset) -> Result<Vec<u8>> {
+    let response = RELEASE_CLIENT
+        .get(&asset.browser_download_url)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        bail!("downloading {} failed: {}", asset.name, response.status());
+    }
+    Ok(response.bytes().await?.to_vec())
+}
+
+/// Check `data` against its line in the checksums file.
+pub fn verify_checksum(checksums: &str, name: &str, data: &[u8]) -> Result<()> {
+    let expected = checksums
+        .lines()
+        .filter_map(|line| line.split_once(char::is_whitespace))
+        .find(|(_, file)| file.trim().trim_start_matches('*') == name)
+        .map(|(hash, _)| hash.to_ascii_lowercase())
+        .with_context(|| format!("{} has no checksum for {}", CHECKSUMS_ASSET, name))?;
+    let actual: String = Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    if actual != expected {
+        bail!(
+            "checksum mismatch for {}: expected {}, got {}",
+            name,
+            expected,
+            actual
+        );
+    }
+    Ok(())
+}
+
+/// The key to check signatures with: `--key`, then `TELLAR_RELEASE_KEY`, then the
+/// embedded release key.
+pub fn release_key(flag: Option<String>) -> Option<String> {
+    flag.or_else(|| std::env::var(RELEASE_KEY_ENV).ok())
+        .or_else(|| RELEASE_PUBLIC_KEY.map(str::to_string))
+        .filter(|key| !key.trim().is_empty())
+}
+
+/// Check the Ed25519 signature over the checksums file; both inputs are base64.
+pub fn verify_signature(checksums: &[u8], signature: &str, public_key: &str) -> Result<()> {
+    let key = BASE64
+        .decode(public_key.trim())
+        .context("release key is not base64")?;
+    let signature = BASE64
+        .decode(signature.trim())
+        .context("release signature is not base64")?;
+    ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, key)
+        .verify(checksums, &signature)
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "{} signature does not match the release key",
+                CHECKSUMS_ASSET
+            )
+        })
+}
+
+/// The binaries in a release archive, by name.
+pub fn unpack_binaries(archive: &[u8]) -> Result<HashMap<String, Vec<u8>>> {
+    let mut binaries = HashMap::new();
+    let mut tar = tar::Archive::new(GzDecoder::new(archive));
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !entry.header().entry_type().is_file() || !BINARIES.contains(&name) {
+            continue;
+        }
+        let name = name.to_string();
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        binaries.insert(name, bytes);
+    }
+    for name in BINARIES {
+        if !binaries.contains_key(*name) {
+            bail!("release archive has no `{}` binary", name);
+        }
+    }
+    Ok(binaries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::signature::KeyPair;
+
+    fn release(tag: &str, prerelease: bool) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            draft: false,
+            prerelease,
+            assets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_release_selection_and_verification() {
+        assert_eq!(
+            compare_versions("v0.10.0", "0.9.3"),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_versions("v1.0.0-beta.2", "v1.0.0"),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            compare_versions("1.0.0-beta.10", "1.0.0-beta.9"),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(compare_versions("v1.2", "1.2.0"), Some(Ordering::Equal));
+        assert_eq!(compare_versions("nightly", "1.0.0"), None);
+
+        let releases = vec![
+            release("v0.2.0", false),
+            release("v0.3.0-beta.1", true),
+            release("nightly", true),
+            release("v0.1.5", false),
+        ];
+        assert_eq!(
+            pick_release(&releases, ReleaseChannel::Stable)
+                .unwrap()
+                .tag_name,
+            "v0.2.0"
+        );
+        assert_eq!(
+            pick_release(&releases, ReleaseChannel::Beta)
+                .unwrap()
+                .tag_name,
+            "v0.3.0-beta.1"
+        );
+
+        let archive = b"binary bytes";
+        let hash: String = Sha256::digest(archive)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let checksums = format!("{}  tellar-x86_64-linux.tar.gz\n", hash);
+        assert!(verify_checksum(&checksums, "tellar-x86_64-linux.tar.gz", archive).is_ok());
+        assert!(verify_checksum(&checksums, "tellar-x86_64-linux.tar.gz", b"tampered").is_err());
+        assert!(verify_checksum(&checksums, "tellar-aarch64-linux.tar.gz", archive).is_err());
+
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = ring::signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        let pair = ring::signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let key = BASE64.encode(pair.public_key().as_ref());
+        let signature = BASE64.encode(pair.sign(checksums.as_bytes()).as_ref());
+        assert!(verify_signature(checksums.as_bytes(), &signature, &key).is_ok());
+        assert!(verify_signature(b"other sums", &signature, &key).is_err());
+        assert_eq!(release_key(Some(key.clone())), Some(key));
+    }
+}
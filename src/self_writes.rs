@@ -0,0 +1,80 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/self_writes.rs
+ * Responsibility: Remember Tellar's own blackboard writes so the Watchman does not react to them.
+ */
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{DefaultHasher, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long a write is remembered; its filesystem events arrive well within this.
+const REMEMBER_FOR: Duration = Duration::from_secs(60);
+
+/// Content hash and time of the last write Tellar made to each path.
+static RECENT: Lazy<Mutex<HashMap<PathBuf, (u64, Instant)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn key(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn remember(path: &Path, bytes: &[u8]) {
+    let now = Instant::now();
+    let mut recent = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+    recent.retain(|_, (_, at)| now.duration_since(*at) < REMEMBER_FOR);
+    recent.insert(key(path), (content_hash(bytes), now));
+}
+
+/// Write `content` to `path` through the guild journal and remember it as Tellar's own write.
+pub async fn write(base_path: &Path, path: &Path, content: &str) -> io::Result<()> {
+    crate::journal::write_async(base_path, path, content).await?;
+    remember(path, content.as_bytes());
+    Ok(())
+}
+
+/// Whether `path` still holds exactly what Tellar recently wrote to it. An edit made
+/// since, even within the same second, changes the content and is not suppressed.
+pub fn is_own_write(path: &Path) -> bool {
+    let hash = {
+        let recent = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+        match recent.get(&key(path)) {
+            Some((hash, at)) if at.elapsed() < REMEMBER_FOR => *hash,
+            _ => return false,
+        }
+    };
+    fs::read(path).is_ok_and(|bytes| content_hash(&bytes) == hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_only_unchanged_own_writes_are_recognized() {
+        let dir = tempdir().unwrap();
+        let ritual = dir.path().join("daily.md");
+        assert!(!is_own_write(&ritual));
+
+        write(dir.path(), &ritual, "- [x] step\n").await.unwrap();
+        assert!(is_own_write(&ritual));
+        assert!(is_own_write(&dir.path().join(".").join("daily.md")));
+
+        fs::write(&ritual, "- [x] step\n- [ ] another\n").unwrap();
+        assert!(!is_own_write(&ritual));
+        fs::write(dir.path().join("other.md"), "- [x] step\n").unwrap();
+        assert!(!is_own_write(&dir.path().join("other.md")));
+    }
+}
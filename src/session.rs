@@ -4,20 +4,26 @@
  * Responsibility: Orchestrate task routing and finite plan execution for ritual and conversational work.
  */
 
+use crate::channel_summary::summary_section;
 use crate::config::Config;
+use crate::entities::entity_section;
 use crate::execution_contract::{
     ConversationalLoopOutcome, ConversationalLoopState, ExecutionOutcome, RequestRoute,
 };
 use crate::input::{Workset, collect_pending_workset};
+use crate::knowledge_index::relevant_knowledge;
+use crate::log_warn;
 use crate::plan_executor::{PlanExecutionContext, execute_conversational_route};
 use crate::prompt_context::load_unified_prompt;
 use crate::router::plan_conversational_request;
+use crate::session_store::{self, SessionMode, SessionRecord};
 use crate::task_policy::apply_request_route_policy;
 use crate::task_response::no_new_workset_response;
+use crate::trace_log::{planned_tool_calls, record_execution};
 use std::path::Path;
 use std::sync::Arc;
 
-async fn resolve_task_route(
+pub(crate) async fn resolve_task_route(
     base_path: &Path,
     config: Arc<Config>,
     workset: &Workset,
@@ -28,7 +34,8 @@ async fn resolve_task_route(
         match plan_conversational_request(base_path, config, workset).await {
             Ok(route) => route,
             Err(err) => {
-                eprintln!(
+                log_warn!(
+                    "steward",
                     "⚠️ {} router failed, returning clarification request: {}",
                     execution_label, err
                 );
@@ -41,12 +48,13 @@ async fn resolve_task_route(
     );
 
     if let Some(note) = policy_decision.log_note() {
-        eprintln!("🧭 {} routing note: {}", execution_label, note);
+        log_warn!("steward", "🧭 {} routing note: {}", execution_label, note);
     }
 
     policy_decision.route
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn execute_task_route(
     workset: &Workset,
     base_path: &Path,
@@ -55,26 +63,55 @@ async fn execute_task_route(
     system_prompt: &str,
     execution_label: &str,
     route: RequestRoute,
+    blackboard: &Path,
 ) -> anyhow::Result<ExecutionOutcome> {
+    let planned_calls = planned_tool_calls(&route);
+    session_store::record_plan(
+        base_path,
+        blackboard,
+        planned_calls.iter().map(|call| call.tool_name.clone()).collect(),
+    );
     let outcome = execute_conversational_route(
         route.into_executable(),
         PlanExecutionContext {
             workset,
             base_path,
-            config,
+            config: Arc::clone(&config),
             channel_id,
             system_prompt,
+            blackboard: Some(blackboard),
         },
     )
     .await?;
 
-    eprintln!(
+    log_warn!(
+        "steward",
         "🧭 {} plan executed: final_state={} success={} {}",
         execution_label,
         outcome.final_state.label(),
         outcome.is_terminal_success(),
         outcome.trace.summarize()
     );
+    record_execution(
+        base_path,
+        &config,
+        channel_id,
+        execution_label,
+        &workset.text(),
+        &planned_calls,
+        &outcome,
+    );
+    if config.git.enabled {
+        crate::workspace_git::commit_turn(
+            base_path,
+            &config.git,
+            execution_label,
+            channel_id,
+            &workset.text(),
+            &planned_calls,
+        )
+        .await;
+    }
 
     Ok(outcome)
 }
@@ -82,13 +119,34 @@ async fn execute_task_route(
 pub(crate) async fn execute_ritual_step(
     task: &str,
     _full_context: &str,
-    _path: &Path,
+    path: &Path,
+    base_path: &Path,
+    config: Arc<Config>,
+    channel_id: &str,
+) -> anyhow::Result<ExecutionOutcome> {
+    session_store::begin(
+        base_path,
+        &SessionRecord::new(path, channel_id, None, SessionMode::Ritual, task),
+    );
+    let outcome = run_ritual_step(task, path, base_path, config, channel_id).await;
+    session_store::finish(base_path, path);
+    outcome
+}
+
+async fn run_ritual_step(
+    task: &str,
+    path: &Path,
     base_path: &Path,
     config: Arc<Config>,
     channel_id: &str,
 ) -> anyhow::Result<ExecutionOutcome> {
-    let system_prompt_str = load_unified_prompt(base_path, channel_id);
     let ritual_workset = Workset::new(vec![task.to_string()]);
+    let (config, channel_preferences) =
+        crate::channel_settings::for_channel(base_path, config, channel_id);
+    let system_prompt_str = load_unified_prompt(base_path, channel_id)
+        + &channel_preferences
+        + &entity_section(base_path, task)
+        + &relevant_knowledge(base_path, &config, channel_id, task).await;
     let route = resolve_task_route(
         base_path,
         Arc::clone(&config),
@@ -106,13 +164,14 @@ pub(crate) async fn execute_ritual_step(
         &system_prompt_str,
         "Ritual",
         route,
+        path,
     )
     .await
 }
 
 pub(crate) async fn run_conversational_loop(
     full_context: &str,
-    _path: &Path,
+    path: &Path,
     base_path: &Path,
     config: Arc<Config>,
     trigger_id: Option<String>,
@@ -127,23 +186,65 @@ pub(crate) async fn run_conversational_loop(
         });
     }
 
-    let system_prompt_str = load_unified_prompt(base_path, channel_id);
+    session_store::begin(
+        base_path,
+        &SessionRecord::new(
+            path,
+            channel_id,
+            trigger_id,
+            SessionMode::Conversation,
+            &workset.text(),
+        ),
+    );
+    let outcome = run_planned_conversation(&workset, path, base_path, config, channel_id).await;
+    session_store::finish(base_path, path);
+    outcome
+}
+
+/// The channel's config and the system prompt a conversational turn about `text` runs
+/// with: identity, channel preferences, summary, entities, and relevant knowledge.
+pub(crate) async fn conversation_prompt(
+    base_path: &Path,
+    config: Arc<Config>,
+    channel_id: &str,
+    text: &str,
+) -> (Arc<Config>, String) {
+    let (config, channel_preferences) =
+        crate::channel_settings::for_channel(base_path, config, channel_id);
+    let system_prompt = load_unified_prompt(base_path, channel_id)
+        + &channel_preferences
+        + &summary_section(base_path, channel_id)
+        + &entity_section(base_path, text)
+        + &relevant_knowledge(base_path, &config, channel_id, text).await;
+    (config, system_prompt)
+}
+
+async fn run_planned_conversation(
+    workset: &Workset,
+    path: &Path,
+    base_path: &Path,
+    config: Arc<Config>,
+    channel_id: &str,
+) -> anyhow::Result<ConversationalLoopOutcome> {
+    let (config, system_prompt_str) =
+        conversation_prompt(base_path, config, channel_id, &workset.text()).await;
     let route = resolve_task_route(
         base_path,
         Arc::clone(&config),
-        &workset,
+        workset,
         "Conversational",
         "This task is not ready to execute. Provide the exact target or missing inputs.",
     )
     .await;
     let outcome = execute_task_route(
-        &workset,
+        workset,
         base_path,
         config,
         channel_id,
         &system_prompt_str,
         "Conversational",
         route,
+        path,
     )
     .await?;
 
@@ -0,0 +1,308 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/session_store.rs
+ * Responsibility: Persist in-flight session state so a restarted daemon can resume or close it.
+ */
+
+use crate::StewardNotification;
+use crate::config::{Config, Language};
+use crate::i18n::Message;
+use crate::{log_info, log_warn};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const SESSIONS_DIR: &str = "brain/sessions";
+const MAX_SAVED_OUTPUT_CHARS: usize = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionMode {
+    Conversation,
+    Ritual,
+}
+
+/// A tool step that finished before the session was interrupted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionStep {
+    pub tool: String,
+    pub succeeded: bool,
+    pub output: String,
+}
+
+/// State of one blackboard's in-flight session, saved to `brain/sessions/<key>.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub blackboard: PathBuf,
+    pub channel_id: String,
+    pub trigger_id: Option<String>,
+    pub mode: SessionMode,
+    pub request: String,
+    #[serde(default)]
+    pub planned_tools: Vec<String>,
+    #[serde(default)]
+    pub steps: Vec<SessionStep>,
+    pub started_at: String,
+    pub updated_at: String,
+}
+
+impl SessionRecord {
+    pub fn new(
+        blackboard: &Path,
+        channel_id: &str,
+        trigger_id: Option<String>,
+        mode: SessionMode,
+        request: &str,
+    ) -> Self {
+        let now = timestamp();
+        Self {
+            blackboard: blackboard.to_path_buf(),
+            channel_id: channel_id.to_string(),
+            trigger_id,
+            mode,
+            request: request.to_string(),
+            planned_tools: Vec::new(),
+            steps: Vec::new(),
+            started_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Turns taken so far: one per finished tool step.
+    pub fn turns(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// A Discord conversation that had not changed anything yet can simply run again.
+    /// CLI chat and bridges (channel `0`) have nobody waiting on the daemon.
+    pub fn can_resume(&self) -> bool {
+        self.mode == SessionMode::Conversation
+            && self.channel_id != "0"
+            && self.trigger_id.is_some()
+            && self.steps.is_empty()
+    }
+}
+
+fn timestamp() -> String {
+    Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn session_path(base_path: &Path, blackboard: &Path) -> PathBuf {
+    let key = blackboard.strip_prefix(base_path).unwrap_or(blackboard);
+    let digest: String = Sha256::digest(key.to_string_lossy().as_bytes())
+        .iter()
+        .take(8)
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    base_path
+        .join(SESSIONS_DIR)
+        .join(format!("{}.json", digest))
+}
+
+fn save(base_path: &Path, record: &SessionRecord) {
+    let path = session_path(base_path, &record.blackboard);
+    let written = fs::create_dir_all(path.parent().unwrap_or(base_path)).and_then(|_| {
+        let json = serde_json::to_string_pretty(record).map_err(std::io::Error::other)?;
+        fs::write(&path, json)
+    });
+    if let Err(e) = written {
+        log_warn!(
+            "steward",
+            "⚠️ Failed to persist session state {:?}: {}",
+            path,
+            e
+        );
+    }
+}
+
+fn load(base_path: &Path, blackboard: &Path) -> Option<SessionRecord> {
+    let content = fs::read_to_string(session_path(base_path, blackboard)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Record that a session started on `record.blackboard`.
+pub fn begin(base_path: &Path, record: &SessionRecord) {
+    save(base_path, record);
+}
+
+/// Record the tools the routed plan intends to call.
+pub fn record_plan(base_path: &Path, blackboard: &Path, planned_tools: Vec<String>) {
+    if let Some(mut record) = load(base_path, blackboard) {
+        record.planned_tools = planned_tools;
+        record.updated_at = timestamp();
+        save(base_path, &record);
+    }
+}
+
+/// Record a finished tool step.
+pub fn record_step(base_path: &Path, blackboard: &Path, tool: &str, succeeded: bool, output: &str) {
+    if let Some(mut record) = load(base_path, blackboard) {
+        record.steps.push(SessionStep {
+            tool: tool.to_string(),
+            succeeded,
+            output: output.chars().take(MAX_SAVED_OUTPUT_CHARS).collect(),
+        });
+        record.updated_at = timestamp();
+        save(base_path, &record);
+    }
+}
+
+/// The session ended normally; forget its state.
+pub fn finish(base_path: &Path, blackboard: &Path) {
+    let _ = fs::remove_file(session_path(base_path, blackboard));
+}
+
+/// Sessions that were still running when the daemon stopped.
+pub fn interrupted_sessions(base_path: &Path) -> Vec<SessionRecord> {
+    let Ok(entries) = fs::read_dir(base_path.join(SESSIONS_DIR)) else {
+        return Vec::new();
+    };
+    let mut records: Vec<SessionRecord> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let content = fs::read_to_string(entry.path()).ok()?;
+            serde_json::from_str(&content).ok()
+        })
+        .collect();
+    records.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    records
+}
+
+fn interruption_note(record: &SessionRecord, language: Language) -> String {
+    let done: Vec<String> = record
+        .steps
+        .iter()
+        .map(|step| {
+            format!(
+                "`{}`{}",
+                step.tool,
+                if step.succeeded {
+                    String::new()
+                } else {
+                    Message::StepFailed.render(language)
+                }
+            )
+        })
+        .collect();
+    Message::SessionInterrupted {
+        started: &record.started_at,
+        turns: record.turns(),
+        steps: &done.join(", "),
+    }
+    .render(language)
+}
+
+/// Resume or close every interrupted session. Conversations that had not run a tool yet
+/// are returned as notifications to run again; the rest get a note on their blackboard
+/// and in their channel. Ritual steps stay unchecked, so the ritual picks them up again.
+pub async fn recover_interrupted_sessions(
+    base_path: &Path,
+    config: &Config,
+) -> Vec<StewardNotification> {
+    let mut resumed = Vec::new();
+    for record in interrupted_sessions(base_path) {
+        finish(base_path, &record.blackboard);
+        if !record.blackboard.is_file() {
+            continue;
+        }
+        if record.can_resume() {
+            log_info!(
+                "steward",
+                "♻️ Resuming interrupted session on {:?}",
+                record.blackboard.file_name()
+            );
+            resumed.push(StewardNotification {
+                blackboard_path: record.blackboard.clone(),
+                channel_id: record.channel_id.clone(),
+                guild_id: config.discord.guild_id.clone().unwrap_or_default(),
+                message_id: record.trigger_id.clone().unwrap_or_default(),
+                content: record.request.clone(),
+            });
+            continue;
+        }
+
+        let note = interruption_note(&record, config.runtime.language);
+        log_info!(
+            "steward",
+            "🧹 Closing interrupted session on {:?}",
+            record.blackboard.file_name()
+        );
+        let mut content = fs::read_to_string(&record.blackboard).unwrap_or_default();
+        content.push_str(&format!("\n> [Tellar] ({}): {}\n", timestamp(), note));
+        if let Err(e) = crate::journal::write_async(base_path, &record.blackboard, &content).await {
+            log_warn!("steward", "⚠️ Failed to note interrupted session: {}", e);
+        }
+        if record.mode == SessionMode::Conversation
+            && record.channel_id != "0"
+            && let Err(e) = crate::discord::client::send_bot_message(
+                &config.discord.token,
+                &record.channel_id,
+                &format!("⚠️ {}", note),
+            )
+            .await
+        {
+            log_warn!(
+                "steward",
+                "⚠️ Failed to report interrupted session to {}: {:?}",
+                record.channel_id,
+                e
+            );
+        }
+    }
+    resumed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_interrupted_sessions_resume_or_close() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        let folder = base.join("channels/general-123456");
+        fs::create_dir_all(&folder).unwrap();
+        let quiet = folder.join("2026-03-01.md");
+        let busy = base.join("rituals/cleanup.md");
+        fs::create_dir_all(busy.parent().unwrap()).unwrap();
+        fs::write(&quiet, "log\n").unwrap();
+        fs::write(&busy, "- [ ] tidy\n").unwrap();
+
+        begin(
+            base,
+            &SessionRecord::new(
+                &quiet,
+                "999123456",
+                Some("m1".into()),
+                SessionMode::Conversation,
+                "hello",
+            ),
+        );
+        begin(
+            base,
+            &SessionRecord::new(&busy, "999123456", None, SessionMode::Ritual, "tidy"),
+        );
+        record_plan(base, &busy, vec!["ls".into(), "write".into()]);
+        record_step(base, &busy, "ls", true, "a.md");
+        assert_eq!(interrupted_sessions(base).len(), 2);
+
+        let resumed = recover_interrupted_sessions(base, &Config::default()).await;
+        assert_eq!(resumed.len(), 1);
+        assert_eq!(resumed[0].blackboard_path, quiet);
+        assert_eq!(resumed[0].message_id, "m1");
+        let ritual = fs::read_to_string(&busy).unwrap();
+        assert!(ritual.contains("- [ ] tidy"));
+        assert!(ritual.contains("after 1 step(s): `ls`. It was not resumed"));
+        assert!(interrupted_sessions(base).is_empty());
+
+        begin(
+            base,
+            &SessionRecord::new(&quiet, "999123456", None, SessionMode::Conversation, "hi"),
+        );
+        finish(base, &quiet);
+        assert!(interrupted_sessions(base).is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/skill_audit.rs
+ * Responsibility: Journal every skill tool execution to brain/audit/skills.jsonl and flag unusual patterns.
+ */
+
+use crate::config::Config;
+use crate::log_warn;
+use crate::skills::SkillExitError;
+use crate::tools::mask_sensitive_data;
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+pub const AUDIT_FILE: &str = "brain/audit/skills.jsonl";
+const MAX_ARGS_CHARS: usize = 400;
+/// A tool failing at least half of this many calls is worth a look.
+const FAILURE_ALERT_MIN_CALLS: usize = 3;
+const BURST_CALLS_PER_HOUR: usize = 30;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillAuditRecord {
+    pub timestamp: String,
+    pub skill: String,
+    pub tool: String,
+    pub args: String,
+    /// `None` when the process never exited on its own (timeout, spawn failure).
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+    /// Channel that triggered the call, or `rhythm` for scheduled hooks.
+    pub caller: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Who ran which skill tool with what arguments.
+pub(crate) struct SkillCall<'a> {
+    pub skill: &'a str,
+    pub tool: &'a str,
+    pub args: &'a Value,
+    pub caller: &'a str,
+}
+
+fn clip(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+    let mut clipped: String = text.chars().take(limit).collect();
+    clipped.push_str(" …");
+    clipped
+}
+
+fn build_record(
+    config: &Config,
+    call: &SkillCall,
+    result: &Result<String>,
+    elapsed: std::time::Duration,
+) -> SkillAuditRecord {
+    let (exit_code, error) = match result {
+        Ok(_) => (Some(0), None),
+        Err(error) => (
+            error.downcast_ref::<SkillExitError>().map(|exit| exit.code),
+            Some(
+                error
+                    .to_string()
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+        ),
+    };
+    SkillAuditRecord {
+        timestamp: Local::now().to_rfc3339(),
+        skill: call.skill.to_string(),
+        tool: call.tool.to_string(),
+        args: clip(
+            &mask_sensitive_data(&call.args.to_string(), config),
+            MAX_ARGS_CHARS,
+        ),
+        exit_code,
+        duration_ms: elapsed.as_millis() as u64,
+        caller: call.caller.to_string(),
+        error,
+    }
+}
+
+fn append_record(base_path: &Path, record: &SkillAuditRecord) -> Result<()> {
+    let path = base_path.join(AUDIT_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// Journal one skill execution; failures are logged and never interrupt the call.
+pub(crate) fn record_skill_call(
+    base_path: &Path,
+    config: &Config,
+    call: &SkillCall,
+    result: &Result<String>,
+    elapsed: std::time::Duration,
+) {
+    let record = build_record(config, call, result, elapsed);
+    if let Err(error) = append_record(base_path, &record) {
+        log_warn!("steward", "⚠️ Failed to journal skill call: {:?}", error);
+    }
+}
+
+/// Records from the last `days` days, oldest first.
+pub fn load_records(base_path: &Path, days: i64) -> Vec<SkillAuditRecord> {
+    let Ok(content) = fs::read_to_string(base_path.join(AUDIT_FILE)) else {
+        return Vec::new();
+    };
+    let since = Local::now() - Duration::days(days.max(1));
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<SkillAuditRecord>(line).ok())
+        .filter(|record| {
+            DateTime::parse_from_rfc3339(&record.timestamp).is_ok_and(|at| at >= since)
+        })
+        .collect()
+}
+
+/// Patterns a guardian should look at, one sentence each: tools that fail often, time
+/// out, or suddenly run in bursts.
+pub fn unusual_patterns(records: &[SkillAuditRecord]) -> Vec<String> {
+    let mut by_tool: BTreeMap<String, Vec<&SkillAuditRecord>> = BTreeMap::new();
+    for record in records {
+        by_tool
+            .entry(format!("{}/{}", record.skill, record.tool))
+            .or_default()
+            .push(record);
+    }
+
+    let mut findings = Vec::new();
+    for (tool, calls) in by_tool {
+        let failures = calls
+            .iter()
+            .filter(|call| call.exit_code != Some(0))
+            .count();
+        if calls.len() >= FAILURE_ALERT_MIN_CALLS && failures * 2 >= calls.len() {
+            findings.push(format!(
+                "`{}` failed {} of {} calls",
+                tool,
+                failures,
+                calls.len()
+            ));
+        }
+        let timeouts = calls
+            .iter()
+            .filter(|call| {
+                call.error
+                    .as_deref()
+                    .is_some_and(|error| error.contains("timed out"))
+            })
+            .count();
+        if timeouts > 0 {
+            findings.push(format!("`{}` timed out {} time(s)", tool, timeouts));
+        }
+
+        let times: Vec<DateTime<chrono::FixedOffset>> = calls
+            .iter()
+            .filter_map(|call| DateTime::parse_from_rfc3339(&call.timestamp).ok())
+            .collect();
+        let busiest = (0..times.len())
+            .map(|start| {
+                times[start..]
+                    .iter()
+                    .take_while(|at| **at - times[start] < Duration::hours(1))
+                    .count()
+            })
+            .max()
+            .unwrap_or(0);
+        if busiest > BURST_CALLS_PER_HOUR {
+            findings.push(format!("`{}` ran {} times within one hour", tool, busiest));
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_skill_audit_records_calls_and_flags_failing_tools() {
+        let guild = tempdir().unwrap();
+        let config = Config::default();
+        let args = serde_json::json!({ "city": "Oslo" });
+        let call = SkillCall {
+            skill: "weather",
+            tool: "forecast",
+            args: &args,
+            caller: "123",
+        };
+        let elapsed = std::time::Duration::from_millis(40);
+        record_skill_call(guild.path(), &config, &call, &Ok("sunny".into()), elapsed);
+        for _ in 0..2 {
+            let failed: Result<String> = Err(SkillExitError {
+                code: 2,
+                output: "boom".to_string(),
+            }
+            .into());
+            record_skill_call(guild.path(), &config, &call, &failed, elapsed);
+        }
+        let timed_out: Result<String> = Err(anyhow!("Skill tool timed out after 60s: `x`"));
+        record_skill_call(guild.path(), &config, &call, &timed_out, elapsed);
+
+        let records = load_records(guild.path(), 7);
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].exit_code, Some(0));
+        assert_eq!(records[0].args, r#"{"city":"Oslo"}"#);
+        assert_eq!(records[0].caller, "123");
+        assert_eq!(records[1].exit_code, Some(2));
+        assert_eq!(records[3].exit_code, None);
+
+        assert_eq!(
+            unusual_patterns(&records),
+            vec![
+                "`weather/forecast` failed 3 of 4 calls",
+                "`weather/forecast` timed out 1 time(s)",
+            ]
+        );
+        assert!(unusual_patterns(&records[..1]).is_empty());
+    }
+}
@@ -0,0 +1,310 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/skill_registry.rs
+ * Responsibility: Fetch skills from git or a registry index into skills/ and track them in skills/skills.lock.json.
+ */
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+pub const LOCK_FILE: &str = "skills/skills.lock.json";
+
+/// Where an installed skill came from and which commit is on disk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedSkill {
+    pub git: String,
+    /// Skill folder inside the repository, when it is not the repository root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Branch or tag to follow; the remote default branch when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    pub commit: String,
+    pub installed_at: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SkillLock {
+    pub skills: BTreeMap<String, LockedSkill>,
+}
+
+impl SkillLock {
+    pub fn load(guild_path: &Path) -> Result<Self> {
+        let path = guild_path.join(LOCK_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&raw).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, guild_path: &Path) -> Result<()> {
+        let path = guild_path.join(LOCK_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+/// A git repository, optionally narrowed to one folder inside it.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SkillSource {
+    pub git: String,
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+impl SkillSource {
+    /// Folder name the skill gets under skills/ when none is given.
+    pub fn default_name(&self) -> String {
+        let tail = match &self.path {
+            Some(path) => path.trim_end_matches('/'),
+            None => self.git.trim_end_matches('/'),
+        };
+        let tail = tail.rsplit(['/', ':']).next().unwrap_or(tail);
+        tail.trim_end_matches(".git").to_string()
+    }
+}
+
+pub fn is_git_source(source: &str) -> bool {
+    source.contains("://") || source.starts_with("git@") || source.ends_with(".git")
+}
+
+fn validate_skill_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+    if !valid {
+        bail!(
+            "invalid skill name `{}`; use letters, digits, `-`, `_`, or `.`",
+            name
+        );
+    }
+    Ok(())
+}
+
+async fn load_index(registry_index: &str) -> Result<BTreeMap<String, SkillSource>> {
+    let raw = if registry_index.starts_with("http://") || registry_index.starts_with("https://") {
+        reqwest::get(registry_index)
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("failed to fetch registry index {}", registry_index))?
+            .text()
+            .await?
+    } else {
+        fs::read_to_string(registry_index)
+            .with_context(|| format!("failed to read registry index {}", registry_index))?
+    };
+    serde_json::from_str(&raw).context("registry index must map skill names to {\"git\", \"path\"}")
+}
+
+/// Treat `source` as a git URL, or look it up by name in `skills.registry_index`.
+pub async fn resolve_source(source: &str, registry_index: &str) -> Result<SkillSource> {
+    if is_git_source(source) || Path::new(source).join(".git").exists() {
+        return Ok(SkillSource {
+            git: source.to_string(),
+            path: None,
+        });
+    }
+    if registry_index.trim().is_empty() {
+        bail!(
+            "`{}` is not a git URL and `skills.registry_index` is not configured",
+            source
+        );
+    }
+    load_index(registry_index.trim())
+        .await?
+        .remove(source)
+        .ok_or_else(|| anyhow!("`{}` is not listed in the registry index", source))
+}
+
+fn git(args: &[&str], cwd: Option<&Path>) -> Result<String> {
+    let mut command = Command::new("git");
+    if let Some(cwd) = cwd {
+        command.arg("-C").arg(cwd);
+    }
+    let output = command.args(args).output().context("failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Commit the remote currently has for `rev` (or its default branch).
+pub fn remote_commit(git_url: &str, rev: Option<&str>) -> Result<String> {
+    let refs = git(&["ls-remote", "--", git_url, rev.unwrap_or("HEAD")], None)?;
+    refs.lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .next()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("{} has no ref `{}`", git_url, rev.unwrap_or("HEAD")))
+}
+
+/// Clone `source` into skills/<name>, replacing any previous copy, and return its lock entry.
+/// The `.git` folder is dropped so the skill is plain files in the guild.
+pub fn fetch_skill(
+    guild_path: &Path,
+    name: &str,
+    source: &SkillSource,
+    rev: Option<&str>,
+) -> Result<LockedSkill> {
+    validate_skill_name(name)?;
+    if let Some(path) = &source.path
+        && (path.contains("..") || path.starts_with('/'))
+    {
+        bail!("skill path `{}` must stay inside the repository", path);
+    }
+    let skills_dir = guild_path.join("skills");
+    fs::create_dir_all(&skills_dir)?;
+    let staging = skills_dir.join(format!(".{}.incoming", name));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+
+    let staging_arg = staging.to_string_lossy().to_string();
+    let mut clone = vec!["clone", "--quiet", "--depth", "1"];
+    if let Some(rev) = rev {
+        clone.extend(["--branch", rev]);
+    }
+    clone.extend(["--", source.git.as_str(), staging_arg.as_str()]);
+    let fetched = git(&clone, None).and_then(|_| git(&["rev-parse", "HEAD"], Some(&staging)));
+    let commit = match fetched {
+        Ok(commit) => commit,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging);
+            return Err(e);
+        }
+    };
+
+    let result = install_from_staging(&staging, &skills_dir.join(name), source.path.as_deref());
+    let _ = fs::remove_dir_all(&staging);
+    result?;
+    Ok(LockedSkill {
+        git: source.git.clone(),
+        path: source.path.clone(),
+        rev: rev.map(str::to_string),
+        commit,
+        installed_at: chrono::Local::now().to_rfc3339(),
+    })
+}
+
+fn install_from_staging(staging: &Path, target: &Path, subdir: Option<&str>) -> Result<()> {
+    let skill_root = match subdir {
+        Some(subdir) => staging.join(subdir),
+        None => staging.to_path_buf(),
+    };
+    if !skill_root.join("SKILL.md").exists() && !skill_root.join("SKILL.json").exists() {
+        bail!("no SKILL.md or SKILL.json found in the fetched source");
+    }
+    let _ = fs::remove_dir_all(skill_root.join(".git"));
+    if target.exists() {
+        fs::remove_dir_all(target)
+            .with_context(|| format!("failed to replace {}", target.display()))?;
+    }
+    fs::rename(&skill_root, target)
+        .with_context(|| format!("failed to move skill into {}", target.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn commit_skill_repo(repo: &Path, body: &str) {
+        fs::create_dir_all(repo.join("skills/weather")).unwrap();
+        fs::write(repo.join("skills/weather/SKILL.md"), body).unwrap();
+        let path = repo.to_string_lossy().to_string();
+        if !repo.join(".git").exists() {
+            git(&["init", "--quiet", &path], None).unwrap();
+        }
+        git(&["add", "-A"], Some(repo)).unwrap();
+        git(
+            &[
+                "-c",
+                "user.name=t",
+                "-c",
+                "user.email=t@localhost",
+                "commit",
+                "--quiet",
+                "-m",
+                body,
+            ],
+            Some(repo),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fetch_skill_installs_subfolder_and_tracks_commit() {
+        let repo = tempdir().unwrap();
+        let guild = tempdir().unwrap();
+        commit_skill_repo(repo.path(), "v1");
+        let url = format!("file://{}", repo.path().display());
+
+        let index = guild.path().join("index.json");
+        fs::write(
+            &index,
+            serde_json::json!({ "weather": { "git": url, "path": "skills/weather" } }).to_string(),
+        )
+        .unwrap();
+        let source = resolve_source("weather", &index.to_string_lossy())
+            .await
+            .unwrap();
+        assert_eq!(source.default_name(), "weather");
+
+        let locked = fetch_skill(guild.path(), "weather", &source, None).unwrap();
+        let installed = guild.path().join("skills/weather");
+        assert_eq!(
+            fs::read_to_string(installed.join("SKILL.md")).unwrap(),
+            "v1"
+        );
+        assert!(!installed.join(".git").exists());
+        assert_eq!(locked.commit, remote_commit(&url, None).unwrap());
+
+        let mut lock = SkillLock::default();
+        lock.skills.insert("weather".to_string(), locked.clone());
+        lock.save(guild.path()).unwrap();
+        assert_eq!(
+            SkillLock::load(guild.path()).unwrap().skills["weather"],
+            locked
+        );
+
+        commit_skill_repo(repo.path(), "v2");
+        assert_ne!(remote_commit(&url, None).unwrap(), locked.commit);
+        fetch_skill(guild.path(), "weather", &source, None).unwrap();
+        assert_eq!(
+            fs::read_to_string(installed.join("SKILL.md")).unwrap(),
+            "v2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_source_needs_index_for_bare_names() {
+        assert!(
+            resolve_source("https://example.com/org/notes.git", "")
+                .await
+                .is_ok()
+        );
+        let err = resolve_source("notes", "").await.unwrap_err();
+        assert!(err.to_string().contains("skills.registry_index"));
+        assert!(validate_skill_name("../x").is_err());
+        let source = SkillSource {
+            git: "git@github.com:org/notes-skill.git".to_string(),
+            path: None,
+        };
+        assert_eq!(source.default_name(), "notes-skill");
+    }
+}
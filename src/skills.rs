@@ -5,15 +5,18 @@
  */
 
 use anyhow::{Result, anyhow};
+use crate::log_warn;
 use once_cell::sync::Lazy;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 use std::time::SystemTime;
+use tokio::io::AsyncWriteExt;
 
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct SkillMetadata {
@@ -21,13 +24,146 @@ pub struct SkillMetadata {
     pub tools: HashMap<String, SkillTool>,
     #[serde(skip)]
     pub guidance: String,
+    #[serde(flatten)]
+    pub runtime: SkillRuntime,
+}
+
+/// Where a skill's tool commands run.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillCwd {
+    #[default]
+    Skill,
+    Workspace,
+}
+
+impl SkillCwd {
+    fn is_default(&self) -> bool {
+        *self == Self::Skill
+    }
+}
+
+/// Execution settings a skill declares next to its tools in SKILL.json or SKILL.md.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct SkillRuntime {
+    /// Free-form version shown by `tellarctl skill list`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Seconds before a tool call is killed; 60 when absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    /// Variables the skill needs. Calls fail fast when `runtime.env` does not expose one.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+    /// Names from the guild secrets store (`tellarctl secret set`) injected as variables.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secrets: Vec<String>,
+    #[serde(skip_serializing_if = "SkillCwd::is_default")]
+    pub cwd: SkillCwd,
+    /// What `wasm` tools may reach. Shell tools ignore it.
+    #[serde(skip_serializing_if = "WasiCapabilities::is_default")]
+    pub wasi: WasiCapabilities,
+    /// Tools the Rhythm runs on a cron schedule with empty arguments, keyed by tool name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub hooks: BTreeMap<String, String>,
+}
+
+/// Capabilities granted to a skill's WASI modules on top of their own folder.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct WasiCapabilities {
+    /// Guild-relative folders mounted read-write at `/guild/<dir>`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dirs: Vec<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub network: bool,
+}
+
+impl WasiCapabilities {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl SkillRuntime {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(timeout) = self.timeout_secs
+            && !(1..=MAX_SKILL_TIMEOUT_SECS).contains(&timeout)
+        {
+            return Err(anyhow!(
+                "timeout_secs must be between 1 and {}",
+                MAX_SKILL_TIMEOUT_SECS
+            ));
+        }
+        for (field, names) in [("env", &self.env), ("secrets", &self.secrets)] {
+            for name in names {
+                let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !valid {
+                    return Err(anyhow!("{} entry `{}` is not a variable name", field, name));
+                }
+            }
+        }
+        for (tool, schedule) in &self.hooks {
+            if schedule.trim().is_empty() {
+                return Err(anyhow!("hook `{}` needs a cron schedule", tool));
+            }
+        }
+        for dir in &self.wasi.dirs {
+            if !crate::wasm_skill::is_contained_path(dir) {
+                return Err(anyhow!(
+                    "wasi.dirs entry `{}` must stay inside the guild",
+                    dir
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.timeout_secs
+                .unwrap_or(DEFAULT_SKILL_TIMEOUT_SECS)
+                .clamp(1, MAX_SKILL_TIMEOUT_SECS),
+        )
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct SkillTool {
     pub description: String,
+    #[serde(default)]
     pub shell: String, // The command or script to run
+    /// WASI module inside the skill folder, run with wasmtime instead of `shell`.
+    #[serde(default)]
+    pub wasm: Option<String>,
+    /// Script inside the skill folder, run with the skill's virtualenv instead of `shell`.
+    #[serde(default)]
+    pub python: Option<String>,
     pub parameters: Value,
+    /// Sample calls `tellarctl skill test` runs against the real command.
+    #[serde(default)]
+    pub examples: Vec<SkillExample>,
+    /// Extraction applied to stdout before the result reaches the model.
+    #[serde(default)]
+    pub output: Option<crate::output_transform::OutputTransform>,
+}
+
+/// One sample call of a skill tool and what its output must look like.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SkillExample {
+    #[serde(default = "empty_example_args")]
+    pub args: Value,
+    /// Regex the output (or the error, with `expect_error`) must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub expect_error: bool,
+}
+
+fn empty_example_args() -> Value {
+    Value::Object(Default::default())
 }
 
 #[derive(Debug, Deserialize)]
@@ -37,6 +173,8 @@ struct InstalledSkill {
     #[serde(default)]
     guidance: Option<String>,
     tools: Vec<InstalledSkillTool>,
+    #[serde(flatten)]
+    runtime: SkillRuntime,
 }
 
 #[derive(Debug, Deserialize)]
@@ -44,13 +182,46 @@ struct InstalledSkillTool {
     name: String,
     description: String,
     parameters: Value,
+    #[serde(default)]
     command: String,
+    #[serde(default)]
+    wasm: Option<String>,
+    #[serde(default)]
+    python: Option<String>,
+    #[serde(default)]
+    examples: Vec<SkillExample>,
+    #[serde(default)]
+    output: Option<crate::output_transform::OutputTransform>,
 }
 
+/// Which skill folders are switched off, kept by `tellarctl skill disable` and `enable`.
+pub const MANIFEST_FILE: &str = "skills/skills.manifest.json";
+
 const DEFAULT_SKILL_TIMEOUT_SECS: u64 = 60;
+const MAX_SKILL_TIMEOUT_SECS: u64 = 3600;
+
+/// A skill process that ran to completion but exited unsuccessfully.
+#[derive(Debug)]
+pub struct SkillExitError {
+    pub code: i32,
+    pub output: String,
+}
+
+impl std::fmt::Display for SkillExitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Skill tool failed with exit code {}:\n{}",
+            self.code, self.output
+        )
+    }
+}
+
+impl std::error::Error for SkillExitError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SkillDiscoveryStamp {
+    generation: u64,
     skills_dir_modified: Option<SystemTime>,
     entry_count: usize,
     latest_skill_file_modified: Option<SystemTime>,
@@ -65,6 +236,10 @@ struct CachedSkillDiscovery {
 static SKILL_DISCOVERY_CACHE: Lazy<RwLock<HashMap<PathBuf, CachedSkillDiscovery>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
 
+/// Bumped by the Watchman when a skill file changes, so edits landing within the same
+/// mtime tick still invalidate every cache keyed on `SkillDiscoveryStamp`.
+static SKILL_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 fn modified_time(path: &Path) -> Option<SystemTime> {
     fs::metadata(path).ok()?.modified().ok()
 }
@@ -95,13 +270,53 @@ pub(crate) fn skill_discovery_stamp(base_path: &Path) -> SkillDiscoveryStamp {
         }
     }
 
+    if let Some(modified) = modified_time(&base_path.join(MANIFEST_FILE)) {
+        latest_skill_file_modified = Some(
+            latest_skill_file_modified
+                .map(|current: SystemTime| current.max(modified))
+                .unwrap_or(modified),
+        );
+    }
+
     SkillDiscoveryStamp {
+        generation: SKILL_GENERATION.load(Ordering::SeqCst),
         skills_dir_modified: modified_time(&skills_dir),
         entry_count,
         latest_skill_file_modified,
     }
 }
 
+/// Skill folders under skills/ that discovery leaves out. Folders not listed are enabled.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillManifest {
+    #[serde(default)]
+    pub disabled: BTreeSet<String>,
+}
+
+impl SkillManifest {
+    pub fn load(base_path: &Path) -> Result<Self> {
+        let path = base_path.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = fs::read_to_string(&path)?;
+        serde_json::from_str(&raw).map_err(|e| anyhow!("failed to parse {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, base_path: &Path) -> Result<()> {
+        let path = base_path.join(MANIFEST_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn is_enabled(&self, folder: &str) -> bool {
+        !self.disabled.contains(folder)
+    }
+}
+
 fn discover_skills_uncached(base_path: &Path) -> Vec<(SkillMetadata, PathBuf)> {
     let mut skills = Vec::new();
     let skills_dir = base_path.join("skills");
@@ -110,9 +325,16 @@ fn discover_skills_uncached(base_path: &Path) -> Vec<(SkillMetadata, PathBuf)> {
         return skills;
     }
 
+    let manifest = SkillManifest::load(base_path).unwrap_or_else(|e| {
+        log_warn!("steward", "⚠️ {}; treating every skill as enabled.", e);
+        SkillManifest::default()
+    });
     if let Ok(entries) = fs::read_dir(skills_dir) {
         for entry in entries.flatten() {
             let path = entry.path();
+            if !manifest.is_enabled(&entry.file_name().to_string_lossy()) {
+                continue;
+            }
             if path.is_dir() {
                 let installed_file = path.join("SKILL.json");
                 if installed_file.exists() {
@@ -122,7 +344,8 @@ fn discover_skills_uncached(base_path: &Path) -> Vec<(SkillMetadata, PathBuf)> {
                             continue;
                         }
                         Err(e) => {
-                            eprintln!(
+                            log_warn!(
+                                "steward",
                                 "⚠️ Failed to load cached SKILL.json at {}: {}. Falling back to SKILL.md.",
                                 installed_file.display(),
                                 e
@@ -138,7 +361,8 @@ fn discover_skills_uncached(base_path: &Path) -> Vec<(SkillMetadata, PathBuf)> {
                             skills.push((meta, path));
                         }
                         Err(e) => {
-                            eprintln!(
+                            log_warn!(
+                                "steward",
                                 "⚠️ Failed to load legacy skill at {}: {}",
                                 skill_md.display(),
                                 e
@@ -165,7 +389,11 @@ impl SkillMetadata {
                 SkillTool {
                     description: tool.description,
                     shell: tool.command,
+                    wasm: tool.wasm,
+                    python: tool.python,
                     parameters: tool.parameters,
+                    examples: tool.examples,
+                    output: tool.output,
                 },
             );
         }
@@ -174,6 +402,7 @@ impl SkillMetadata {
             name: installed.name,
             tools,
             guidance: installed.guidance.unwrap_or(installed.description),
+            runtime: installed.runtime,
         })
     }
 
@@ -232,6 +461,40 @@ impl SkillMetadata {
     }
 }
 
+/// Tool names that appeared or disappeared in a skill reload.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SkillReload {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+fn skill_tool_names(skills: &[(SkillMetadata, PathBuf)]) -> BTreeSet<String> {
+    skills
+        .iter()
+        .flat_map(|(meta, _)| meta.tools.keys().cloned())
+        .collect()
+}
+
+/// Drop the cached skill registry for `base_path`, rescan it, and report tool changes.
+pub fn reload_skills(base_path: &Path) -> SkillReload {
+    let previous = SKILL_DISCOVERY_CACHE
+        .read()
+        .ok()
+        .and_then(|cache| {
+            cache
+                .get(base_path)
+                .map(|cached| skill_tool_names(&cached.skills))
+        })
+        .unwrap_or_default();
+    SKILL_GENERATION.fetch_add(1, Ordering::SeqCst);
+    let current = skill_tool_names(&SkillMetadata::discover_skills(base_path));
+
+    SkillReload {
+        added: current.difference(&previous).cloned().collect(),
+        removed: previous.difference(&current).cloned().collect(),
+    }
+}
+
 pub fn build_relevant_skill_guidance(base_path: &Path, text: &str) -> Option<String> {
     let normalized = text.to_ascii_lowercase();
     let mut blocks = Vec::new();
@@ -491,48 +754,146 @@ fn render_simple_shell_template(shell: &str, args: &Value) -> Result<String> {
 
 pub async fn execute_skill_tool(
     tool: &SkillTool,
+    skill: &SkillMetadata,
     skill_dir: &Path,
     workspace_dir: &Path,
     args: &Value,
     config: &crate::config::Config,
 ) -> Result<String> {
-    let command_line = render_simple_shell_template(tool.shell.trim(), args)?;
-    if command_line.is_empty() {
-        return Err(anyhow!("Empty execution line in skill tool"));
+    // Only `runtime.env.base`, this skill's `runtime.env.skills` entry, and the stored
+    // secrets it declares reach the process.
+    let env =
+        crate::child_env::skill_env(config, workspace_dir, &skill.name, &skill.runtime.secrets);
+    let missing: Vec<&str> = skill
+        .runtime
+        .env
+        .iter()
+        .filter(|name| !env.vars.iter().any(|(var, _)| var == *name))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(anyhow!(
+            "Skill `{}` needs {} in its environment. Expose it under `runtime.env.skills.{}` in tellar.yml.",
+            skill.name,
+            missing.join(", "),
+            skill.name
+        ));
+    }
+    let missing_secrets: Vec<&str> = skill
+        .runtime
+        .secrets
+        .iter()
+        .filter(|name| !env.vars.iter().any(|(var, _)| var == *name))
+        .map(String::as_str)
+        .collect();
+    if !missing_secrets.is_empty() {
+        return Err(anyhow!(
+            "Skill `{}` needs the secret {}. Store it with `tellarctl secret set {}`.",
+            skill.name,
+            missing_secrets.join(", "),
+            missing_secrets[0]
+        ));
     }
 
-    let mut cmd = tokio::process::Command::new("sh");
-    cmd.arg("-lc").arg(&command_line);
+    let (mut cmd, command_line) = match (&tool.wasm, &tool.python) {
+        (Some(module), _) => (
+            crate::wasm_skill::wasm_command(
+                module,
+                skill_dir,
+                workspace_dir,
+                &skill.runtime.wasi,
+                &env,
+            )?,
+            module.clone(),
+        ),
+        (None, Some(entry)) => (
+            crate::python_skill::python_command(entry, skill_dir, &env)?,
+            entry.clone(),
+        ),
+        (None, None) => {
+            let command_line = render_simple_shell_template(tool.shell.trim(), args)?;
+            if command_line.is_empty() {
+                return Err(anyhow!("Empty execution line in skill tool"));
+            }
+            let mut cmd = tokio::process::Command::new("sh");
+            cmd.arg("-lc").arg(&command_line);
+            env.apply(&mut cmd);
+            (cmd, command_line)
+        }
+    };
 
     let args_json = serde_json::to_string(args)?;
 
-    // Skills run from their own directory for predictable relative paths, but they are not
-    // sandboxed to that directory. User-installed skills are treated as trusted extensions.
-    let output_future = cmd
-        .current_dir(skill_dir)
+    // Shell skills run from their own directory for predictable relative paths unless they ask
+    // for the workspace, but they are not sandboxed to either. User-installed shell skills are
+    // treated as trusted extensions; untrusted ones should ship `wasm` tools instead.
+    let cwd = match skill.runtime.cwd {
+        SkillCwd::Skill => skill_dir,
+        SkillCwd::Workspace => workspace_dir,
+    };
+    // Python tools read their arguments as JSON on stdin; others get no stdin.
+    let stdin_payload = tool.python.as_ref().map(|_| args_json.clone());
+    let stdin = if stdin_payload.is_some() {
+        std::process::Stdio::piped()
+    } else {
+        std::process::Stdio::null()
+    };
+    let spawned = cmd
+        .current_dir(cwd)
         .env("TELLAR_ARGS", &args_json)
         .env("SKILL_DIR", skill_dir)
         .env("TELLAR_WORKSPACE", workspace_dir)
+        .env("TELLAR_DB", workspace_dir.join(crate::sql::DATABASE_PATH))
         .env("TELLAR_CORE_TOOLS", "ls,find,grep,read,write,edit")
-        .env("GEMINI_API_KEY", &config.gemini.api_key)
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
-        .output();
+        .stdin(stdin)
+        .kill_on_drop(true)
+        .spawn();
+    let mut child = spawned.map_err(|e| {
+        if tool.wasm.is_some() && e.kind() == std::io::ErrorKind::NotFound {
+            anyhow!(
+                "WASM skill tools need `{}` on PATH",
+                crate::wasm_skill::WASMTIME_PROGRAM
+            )
+        } else {
+            anyhow!("Failed to execute skill tool `{}`: {}", command_line, e)
+        }
+    })?;
+    let child_stdin = child.stdin.take();
+    let run = async move {
+        if let (Some(payload), Some(mut pipe)) = (stdin_payload, child_stdin) {
+            // A script that exits without reading its input is not an error.
+            let _ = pipe.write_all(payload.as_bytes()).await;
+        }
+        child.wait_with_output().await
+    };
 
-    let timeout_secs = DEFAULT_SKILL_TIMEOUT_SECS;
-    let output = tokio::time::timeout(Duration::from_secs(timeout_secs), output_future)
+    let timeout = skill.runtime.timeout();
+    let output = tokio::time::timeout(timeout, run)
         .await
         .map_err(|_| {
             anyhow!(
                 "Skill tool timed out after {}s: `{}`",
-                timeout_secs,
+                timeout.as_secs(),
                 command_line
             )
         })?
         .map_err(|e| anyhow!("Failed to execute skill tool `{}`: {}", command_line, e))?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let mut stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if let Some(transform) = &tool.output
+        && output.status.success()
+    {
+        stdout = match transform.apply(&stdout).await {
+            Ok(extracted) => extracted,
+            Err(e) => format!(
+                "⚠️ Output transform failed ({}); raw output follows.\n{}",
+                e, stdout
+            ),
+        };
+    }
 
     let mut result = String::new();
     if !stdout.is_empty() {
@@ -546,12 +907,11 @@ pub async fn execute_skill_tool(
     }
 
     if !output.status.success() {
-        let code = output.status.code().unwrap_or(-1);
-        return Err(anyhow!(
-            "Skill tool failed with exit code {}:\n{}",
-            code,
-            result
-        ));
+        return Err(SkillExitError {
+            code: output.status.code().unwrap_or(-1),
+            output: result,
+        }
+        .into());
     }
 
     if result.is_empty() {
@@ -564,7 +924,7 @@ pub async fn execute_skill_tool(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DiscordConfig, GeminiConfig, RuntimeConfig};
+    use crate::config::{Config, DiscordConfig, GeminiConfig};
     use serde_json::json;
     use tempfile::tempdir;
 
@@ -633,30 +993,274 @@ Body
         let tool = SkillTool {
             description: "pwd".to_string(),
             shell: "printf \"$PWD\"".to_string(),
+            wasm: None,
+            python: None,
             parameters: json!({ "type": "object" }),
+            examples: Vec::new(),
+            output: None,
         };
         let config = Config {
             gemini: GeminiConfig {
                 api_key: "fake".to_string(),
                 model: "fake".to_string(),
+                temperature: None,
             },
             discord: DiscordConfig {
                 token: "fake".to_string(),
                 guild_id: None,
                 channel_mappings: None,
+                ..Default::default()
             },
-            runtime: RuntimeConfig::default(),
+            ..Default::default()
         };
 
-        let output = execute_skill_tool(&tool, dir.path(), workspace.path(), &json!({}), &config)
-            .await
-            .unwrap();
+        let skill = SkillMetadata {
+            name: "sample".to_string(),
+            ..Default::default()
+        };
+        let output = execute_skill_tool(
+            &tool,
+            &skill,
+            dir.path(),
+            workspace.path(),
+            &json!({}),
+            &config,
+        )
+        .await
+        .unwrap();
 
         let expected = std::fs::canonicalize(dir.path()).unwrap();
         let actual = std::fs::canonicalize(output).unwrap();
         assert_eq!(actual, expected);
     }
 
+    #[tokio::test]
+    async fn test_execute_skill_tool_honors_declared_runtime() {
+        let guild = tempdir().unwrap();
+        let skill_dir = guild.path().join("skills").join("slow");
+        fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            r#"---
+name: slow
+timeout_secs: 1
+env: [SLOW_TOKEN]
+cwd: workspace
+tools:
+  wait:
+    description: Wait
+    shell: printf "$PWD"; sleep {{seconds}}
+    parameters:
+      type: object
+---
+"#,
+        )
+        .unwrap();
+        let meta = SkillMetadata::from_file(&skill_dir.join("SKILL.md")).unwrap();
+        assert_eq!(meta.runtime.timeout_secs, Some(1));
+        assert_eq!(meta.runtime.cwd, SkillCwd::Workspace);
+        let tool = meta.tools.get("wait").unwrap();
+
+        let mut config = Config::default();
+        let err = execute_skill_tool(
+            tool,
+            &meta,
+            &skill_dir,
+            guild.path(),
+            &json!({ "seconds": 0 }),
+            &config,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("runtime.env.skills.slow"));
+
+        config.runtime.env.skills.insert(
+            "slow".to_string(),
+            crate::config::EnvRules {
+                set: [("SLOW_TOKEN".to_string(), "t".to_string())].into(),
+                ..Default::default()
+            },
+        );
+        let output = execute_skill_tool(
+            tool,
+            &meta,
+            &skill_dir,
+            guild.path(),
+            &json!({ "seconds": 0 }),
+            &config,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            std::fs::canonicalize(output).unwrap(),
+            std::fs::canonicalize(guild.path()).unwrap()
+        );
+
+        let err = execute_skill_tool(
+            tool,
+            &meta,
+            &skill_dir,
+            guild.path(),
+            &json!({ "seconds": 5 }),
+            &config,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("timed out after 1s"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_skill_tool_applies_output_transform() {
+        let dir = tempdir().unwrap();
+        let tool = SkillTool {
+            description: "json".to_string(),
+            shell: r#"printf '{"status":"ok","items":[1,2,3]}'"#.to_string(),
+            wasm: None,
+            python: None,
+            parameters: json!({ "type": "object" }),
+            examples: Vec::new(),
+            output: Some(crate::output_transform::OutputTransform {
+                pointer: Some("/items".to_string()),
+                ..Default::default()
+            }),
+        };
+        let skill = SkillMetadata {
+            name: "sample".to_string(),
+            ..Default::default()
+        };
+        let output = execute_skill_tool(
+            &tool,
+            &skill,
+            dir.path(),
+            dir.path(),
+            &json!({}),
+            &Config::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(output, "[1,2,3]");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_python_skill_tool_reads_args_from_stdin() {
+        let Ok(found) = std::process::Command::new("sh")
+            .args(["-c", "command -v python3"])
+            .output()
+        else {
+            return;
+        };
+        let interpreter = String::from_utf8_lossy(&found.stdout).trim().to_string();
+        if interpreter.is_empty() {
+            return;
+        }
+        let dir = tempdir().unwrap();
+        let bin = dir.path().join(crate::python_skill::VENV_DIR).join("bin");
+        fs::create_dir_all(&bin).unwrap();
+        std::os::unix::fs::symlink(&interpreter, bin.join("python")).unwrap();
+        fs::write(
+            dir.path().join("main.py"),
+            "import json, sys\nprint('hi ' + json.load(sys.stdin)['name'])\n",
+        )
+        .unwrap();
+
+        let tool = SkillTool {
+            description: "greet".to_string(),
+            shell: String::new(),
+            wasm: None,
+            python: Some("main.py".to_string()),
+            parameters: json!({ "type": "object" }),
+            examples: Vec::new(),
+            output: None,
+        };
+        let skill = SkillMetadata {
+            name: "greeter".to_string(),
+            ..Default::default()
+        };
+        let output = execute_skill_tool(
+            &tool,
+            &skill,
+            dir.path(),
+            dir.path(),
+            &json!({ "name": "Ada" }),
+            &Config::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(output, "hi Ada");
+    }
+
+    #[test]
+    fn test_reload_skills_reports_tool_changes_within_same_mtime() {
+        let guild = tempdir().unwrap();
+        let skill_dir = guild.path().join("skills").join("sample");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let write_skill = |tools: &[&str]| {
+            let tools: String = tools
+                .iter()
+                .map(|name| {
+                    format!(
+                        "  {}:\n    description: d\n    shell: printf hi\n    parameters:\n      type: object\n",
+                        name
+                    )
+                })
+                .collect();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: sample\ntools:\n{}---\n", tools),
+            )
+            .unwrap();
+        };
+
+        write_skill(&["alpha", "beta"]);
+        assert_eq!(SkillMetadata::discover_skills(guild.path()).len(), 1);
+        let stamp = skill_discovery_stamp(guild.path());
+
+        write_skill(&["beta", "gamma"]);
+        let reload = reload_skills(guild.path());
+        assert_eq!(reload.added, vec!["gamma"]);
+        assert_eq!(reload.removed, vec!["alpha"]);
+        assert_ne!(skill_discovery_stamp(guild.path()), stamp);
+        assert!(
+            SkillMetadata::discover_skills(guild.path())[0]
+                .0
+                .tools
+                .contains_key("gamma")
+        );
+    }
+
+    #[test]
+    fn test_disabled_skills_are_left_out_of_discovery() {
+        let guild = tempdir().unwrap();
+        for name in ["alpha", "beta"] {
+            let skill_dir = guild.path().join("skills").join(name);
+            fs::create_dir_all(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                format!(
+                    "---\nname: {}\nversion: 1.2.0\ntools:\n  {}_tool:\n    description: d\n    shell: printf hi\n    parameters:\n      type: object\n---\n",
+                    name, name
+                ),
+            )
+            .unwrap();
+        }
+        let names = |skills: Vec<(SkillMetadata, PathBuf)>| -> Vec<String> {
+            let mut names: Vec<String> = skills.into_iter().map(|(meta, _)| meta.name).collect();
+            names.sort();
+            names
+        };
+        let skills = SkillMetadata::discover_skills(guild.path());
+        assert_eq!(skills[0].0.runtime.version.as_deref(), Some("1.2.0"));
+        assert_eq!(names(skills), vec!["alpha", "beta"]);
+
+        let mut manifest = SkillManifest::default();
+        manifest.disabled.insert("alpha".to_string());
+        manifest.save(guild.path()).unwrap();
+        assert_eq!(SkillManifest::load(guild.path()).unwrap(), manifest);
+        reload_skills(guild.path());
+        assert_eq!(names(SkillMetadata::discover_skills(guild.path())), vec!["beta"]);
+    }
+
     #[test]
     fn test_build_relevant_skill_guidance_matches_skill_name_and_body() {
         let guild = tempdir().unwrap();
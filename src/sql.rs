@@ -0,0 +1,239 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/sql.rs
+ * Responsibility: The `sql` core tool over the guild-scoped SQLite database at brain/tellar.db.
+ */
+
+use crate::tools::ToolExecutionResult;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rusqlite::limits::Limit;
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{Connection, params_from_iter};
+use serde_json::{Value, json};
+use std::path::Path;
+use std::time::Duration;
+
+pub const SQL_TOOL_NAME: &str = "sql";
+pub const DATABASE_PATH: &str = "brain/tellar.db";
+const DEFAULT_MAX_ROWS: usize = 50;
+const MAX_ROWS_CAP: usize = 500;
+const MAX_CELL_CHARS: usize = 200;
+
+/// `VACUUM INTO` writes a copy of the database to any path. ATTACH is shut off by the
+/// connection's attached-database limit instead.
+static VACUUM_INTO: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\bvacuum\b[^;]*\binto\b").expect("valid regex"));
+
+fn open_database(base_path: &Path) -> rusqlite::Result<Connection> {
+    let path = base_path.join(DATABASE_PATH);
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let connection = Connection::open(path)?;
+    // Skills and concurrent turns may share the file.
+    connection.busy_timeout(Duration::from_secs(5))?;
+    // No ATTACH, so no statement can open database files outside brain/tellar.db.
+    connection.set_limit(Limit::SQLITE_LIMIT_ATTACHED, 0)?;
+    Ok(connection)
+}
+
+fn json_to_sql(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(flag) => SqlValue::Integer(i64::from(*flag)),
+        Value::Number(number) => number
+            .as_i64()
+            .map(SqlValue::Integer)
+            .or_else(|| number.as_f64().map(SqlValue::Real))
+            .unwrap_or(SqlValue::Null),
+        Value::String(text) => SqlValue::Text(text.clone()),
+        other => SqlValue::Text(other.to_string()),
+    }
+}
+
+fn render_cell(value: ValueRef<'_>) -> String {
+    let rendered = match value {
+        ValueRef::Null => "NULL".to_string(),
+        ValueRef::Integer(number) => number.to_string(),
+        ValueRef::Real(number) => number.to_string(),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned(),
+        ValueRef::Blob(bytes) => format!("<blob {} bytes>", bytes.len()),
+    };
+    let flattened = rendered.replace('\n', " ").replace('|', "\\|");
+    if flattened.chars().count() > MAX_CELL_CHARS {
+        let cut: String = flattened.chars().take(MAX_CELL_CHARS).collect();
+        format!("{}…", cut)
+    } else {
+        flattened
+    }
+}
+
+/// Run one statement and render result rows as a markdown table, or report the change count.
+pub(crate) fn run_statement(
+    connection: &Connection,
+    query: &str,
+    params: &[Value],
+    max_rows: usize,
+) -> Result<String, String> {
+    if VACUUM_INTO.is_match(query) {
+        return Err("VACUUM INTO is not allowed; use brain/tellar.db only.".into());
+    }
+    let mut statement = connection.prepare(query).map_err(|e| e.to_string())?;
+    let columns: Vec<String> = statement
+        .column_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let bound = params_from_iter(params.iter().map(json_to_sql));
+
+    if columns.is_empty() {
+        let changed = statement.execute(bound).map_err(|e| e.to_string())?;
+        return Ok(format!("OK. {} row(s) affected.", changed));
+    }
+
+    let mut rows = statement.query(bound).map_err(|e| e.to_string())?;
+    let mut lines = vec![
+        format!("| {} |", columns.join(" | ")),
+        format!("|{}", " --- |".repeat(columns.len())),
+    ];
+    let mut count = 0;
+    let mut truncated = false;
+    while let Some(row) = rows.next().map_err(|e| e.to_string())? {
+        if count >= max_rows {
+            truncated = true;
+            break;
+        }
+        let cells = (0..columns.len())
+            .map(|index| row.get_ref(index).map(render_cell))
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())?;
+        lines.push(format!("| {} |", cells.join(" | ")));
+        count += 1;
+    }
+
+    if count == 0 {
+        return Ok(format!("No rows. Columns: {}", columns.join(", ")));
+    }
+    if truncated {
+        lines.push(format!(
+            "\n[Showing first {} rows; add LIMIT/WHERE or raise maxRows]",
+            max_rows
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+pub(crate) fn sql_tool_definition() -> Value {
+    json!({
+        "name": SQL_TOOL_NAME,
+        "description": "Run one SQL statement against the guild's SQLite database (brain/tellar.db). Use it for structured data such as inventories, habit trackers, and metrics instead of markdown tables. SELECT results come back as a markdown table.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "query": { "type": "string", "description": "A single SQLite statement, e.g. CREATE TABLE, INSERT, SELECT. Use ?1, ?2 placeholders for values" },
+                "params": { "type": "array", "items": { "type": ["string", "number", "integer", "boolean", "null"] }, "description": "Values bound to the ? placeholders, in order; numbers, booleans, and null bind as SQL values, not text" },
+                "maxRows": { "type": "number", "description": "Maximum rows to return. Defaults to 50" }
+            },
+            "required": ["query"]
+        }
+    })
+}
+
+/// Run the tool on the blocking pool, since a locked database can hold it for the busy timeout.
+pub(crate) async fn run_sql_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let (args, base_path) = (args.clone(), base_path.to_path_buf());
+    tokio::task::spawn_blocking(move || run_sql(&args, &base_path))
+        .await
+        .unwrap_or_else(|e| ToolExecutionResult::error(format!("Error: SQL failed: {}", e)))
+}
+
+fn run_sql(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let Some(query) = args
+        .get("query")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|query| !query.is_empty())
+    else {
+        return ToolExecutionResult::error("Error: Missing required argument `query`.");
+    };
+    let params = args
+        .get("params")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let max_rows = args
+        .get("maxRows")
+        .and_then(Value::as_u64)
+        .map(|rows| rows as usize)
+        .unwrap_or(DEFAULT_MAX_ROWS)
+        .clamp(1, MAX_ROWS_CAP);
+
+    let connection = match open_database(base_path) {
+        Ok(connection) => connection,
+        Err(error) => {
+            return ToolExecutionResult::error(format!(
+                "Error: Cannot open {}: {}",
+                DATABASE_PATH, error
+            ));
+        }
+    };
+    match run_statement(&connection, query, &params, max_rows) {
+        Ok(output) => ToolExecutionResult::success(output),
+        Err(error) => ToolExecutionResult::error(format!("Error: SQL failed: {}", error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_sql_tool_creates_inserts_and_selects_in_brain_database() {
+        let dir = tempdir().unwrap();
+        let run = |args: Value| run_sql(&args, dir.path());
+
+        let created =
+            run(json!({ "query": "CREATE TABLE habits (name TEXT, streak INTEGER, note TEXT)" }));
+        assert!(!created.is_error, "{}", created.output);
+        let inserted = run(json!({
+            "query": "INSERT INTO habits VALUES (?1, ?2, ?3), ('read', 3, NULL)",
+            "params": ["walk", 12, "a|b"]
+        }));
+        assert_eq!(inserted.output, "OK. 2 row(s) affected.");
+
+        let selected =
+            run(json!({ "query": "SELECT name, streak, note FROM habits ORDER BY streak DESC" }));
+        assert_eq!(
+            selected.output,
+            "| name | streak | note |\n| --- | --- | --- |\n| walk | 12 | a\\|b |\n| read | 3 | NULL |"
+        );
+        assert!(dir.path().join(DATABASE_PATH).exists());
+
+        let limited = run(json!({ "query": "SELECT name FROM habits", "maxRows": 1 }));
+        assert!(limited.output.contains("Showing first 1 rows"));
+    }
+
+    #[tokio::test]
+    async fn test_sql_tool_rejects_statements_that_escape_the_database() {
+        let dir = tempdir().unwrap();
+        let outside = dir.path().join("outside.db");
+        for query in [
+            format!("ATTACH DATABASE '{}' AS other", outside.display()),
+            format!("/**/ attach database '{}' as other", outside.display()),
+        ] {
+            let result = run_sql_tool(&json!({ "query": query }), dir.path()).await;
+            assert!(result.is_error, "{}", result.output);
+        }
+        assert!(!outside.exists());
+        let vacuum = run_sql_tool(
+            &json!({ "query": "vacuum into '/tmp/copy.db'" }),
+            dir.path(),
+        )
+        .await;
+        assert!(vacuum.output.contains("not allowed"));
+        let invalid = run_sql_tool(&json!({ "query": "SELEC 1" }), dir.path()).await;
+        assert!(invalid.output.starts_with("Error: SQL failed"));
+    }
+}
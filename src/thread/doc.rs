@@ -16,6 +16,12 @@ pub(crate) struct TaskHeader {
     pub(crate) schedule: Option<String>,
     pub(crate) injection_template: Option<String>,
     pub(crate) origin_channel: Option<String>,
+    /// A step still running after this many seconds is abandoned and marked failed.
+    pub(crate) max_runtime_secs: Option<u64>,
+    pub(crate) task_id: Option<String>,
+    /// Task ids of rituals that must complete today before this one runs.
+    #[serde(default)]
+    pub(crate) depends_on: Vec<String>,
 }
 
 pub(crate) fn parse_task_document(content: &str) -> Option<(TaskHeader, &str)> {
@@ -35,6 +41,18 @@ pub(crate) fn parse_task_document(content: &str) -> Option<(TaskHeader, &str)> {
     }
 }
 
+/// Byte offset where the document body starts, past the front matter. Steps are only read
+/// from the body, so a header's `injection_template` is never run as a step.
+pub(crate) fn body_start(content: &str) -> usize {
+    if !content.starts_with("---") {
+        return 0;
+    }
+    content[3..]
+        .find("---")
+        .map(|end| 3 + end + 3)
+        .unwrap_or(0)
+}
+
 pub(crate) fn is_conversational_log(path: &Path) -> bool {
     let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
     file_name.len() == 13
@@ -4,23 +4,30 @@
  * Responsibility: Execute thread files, dispatch role sessions, and persist results.
  */
 
-use self::doc::{extract_channel_id_from_path, is_conversational_log, parse_task_document};
+use self::doc::{
+    body_start, extract_channel_id_from_path, is_conversational_log, parse_task_document,
+};
 use self::store::{
     append_discord_response_log, append_internal_task_error_log, append_local_response_log,
     append_processing_error_log, append_task_result_log, history_destination,
-    should_archive_thread,
+    mark_task_timed_out, should_archive_thread,
 };
 use crate::config::Config;
 use crate::discord::client as discord_client;
+use crate::i18n::Message;
+use crate::lifecycle::{self, LifecycleEvent, LifecycleEventKind};
 use crate::session::{execute_ritual_step, run_conversational_loop};
 use crate::tools::mask_sensitive_data;
+use crate::{log_error, log_info, log_warn};
 use chrono::Local;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::sync::Semaphore;
 
 pub mod doc;
@@ -36,10 +43,18 @@ struct PendingThreadRun {
 static EXECUTING_FILES: Lazy<Mutex<HashSet<PathBuf>>> = Lazy::new(|| Mutex::new(HashSet::new()));
 static PENDING_THREAD_RUNS: Lazy<Mutex<HashMap<PathBuf, PendingThreadRun>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
-static CONCURRENCY_LIMITER: Lazy<Arc<Semaphore>> = Lazy::new(|| Arc::new(Semaphore::new(5)));
+/// Semaphores bounding thread runs: `""` for the whole guild, otherwise one per channel ID.
+/// Each is sized from the config the first time it is needed.
+static CONCURRENCY_LIMITERS: Lazy<Mutex<HashMap<String, Arc<Semaphore>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 static PENDING_TODO_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"- \[ \] (.*)").expect("valid todo capture regex"));
 
+/// Threads holding a coalesced trigger until their current run ends.
+pub(crate) fn queued_reruns() -> usize {
+    PENDING_THREAD_RUNS.lock().unwrap().len()
+}
+
 pub async fn execute_thread_file(
     path: &PathBuf,
     base_path: &Path,
@@ -64,7 +79,19 @@ pub async fn execute_thread_file(
         executing.insert(path.clone());
     }
 
-    let _permit = CONCURRENCY_LIMITER.acquire().await.unwrap();
+    let channel_limiter = channel_concurrency_limiter(
+        path,
+        &config,
+        next_run.target_channel_id.as_deref(),
+    );
+    let _channel_permit = match channel_limiter {
+        Some(limiter) => Some(limiter.acquire_owned().await.unwrap()),
+        None => None,
+    };
+    let _permit = concurrency_limiter("", config.runtime.max_concurrent_threads)
+        .acquire_owned()
+        .await
+        .unwrap();
     let res = loop {
         let PendingThreadRun {
             trigger_id,
@@ -72,15 +99,23 @@ pub async fn execute_thread_file(
             target_guild_id,
         } = next_run;
 
-        let result = execute_thread_file_internal(
+        // Tag the run's log lines with its channel for `tellarctl logs --channel`.
+        let log_channel = target_channel_id
+            .clone()
+            .unwrap_or_else(|| extract_channel_id_from_path(path));
+        let run = execute_thread_file_internal(
             path,
             base_path,
             Arc::clone(&config),
             trigger_id,
             target_channel_id,
             target_guild_id,
-        )
-        .await;
+        );
+        let result = if log_channel == "0" {
+            run.await
+        } else {
+            crate::runtime_log::with_channel(log_channel, run).await
+        };
 
         let pending_rerun = {
             let mut pending = PENDING_THREAD_RUNS.lock().unwrap();
@@ -89,7 +124,8 @@ pub async fn execute_thread_file(
 
         match pending_rerun {
             Some(pending) => {
-                println!(
+                log_info!(
+                    "steward",
                     "🔁 Re-running thread {:?} to process a coalesced trigger.",
                     path.file_name()
                 );
@@ -107,6 +143,34 @@ pub async fn execute_thread_file(
     res
 }
 
+fn concurrency_limiter(key: &str, permits: usize) -> Arc<Semaphore> {
+    let mut limiters = CONCURRENCY_LIMITERS.lock().unwrap();
+    limiters
+        .entry(key.to_string())
+        .or_insert_with(|| Arc::new(Semaphore::new(permits.max(1))))
+        .clone()
+}
+
+/// The limiter for the channel of `path`, when `runtime.channel_limits` caps its concurrency.
+fn channel_concurrency_limiter(
+    path: &Path,
+    config: &Config,
+    target_channel_id: Option<&str>,
+) -> Option<Arc<Semaphore>> {
+    if config.runtime.channel_limits.is_empty() {
+        return None;
+    }
+    let channel_id = target_channel_id
+        .map(str::to_string)
+        .unwrap_or_else(|| extract_channel_id_from_path(path));
+    let permits = config
+        .runtime
+        .channel_limits
+        .get(&channel_id)?
+        .max_concurrent_threads?;
+    Some(concurrency_limiter(&channel_id, permits))
+}
+
 static FILE_LOCKS: Lazy<Mutex<HashMap<PathBuf, Arc<tokio::sync::Mutex<()>>>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
@@ -118,6 +182,28 @@ fn get_file_lock(path: &Path) -> Arc<tokio::sync::Mutex<()>> {
         .clone()
 }
 
+/// Summarize an oversized daily log in the background, holding the file lock so the
+/// rewrite cannot interleave with the next turn.
+fn spawn_log_summary(base_path: &Path, path: &Path, config: Arc<Config>) {
+    if !config.summary.enabled {
+        return;
+    }
+    let (base_path, path) = (base_path.to_path_buf(), path.to_path_buf());
+    tokio::spawn(async move {
+        let file_lock = get_file_lock(&path);
+        let _guard = file_lock.lock().await;
+        match crate::channel_summary::summarize_log_if_needed(&base_path, &path, &config).await {
+            Ok(true) => log_info!(
+                "steward",
+                "🧾 Summarized older messages of {:?} into SUMMARY.md",
+                path.file_name()
+            ),
+            Ok(false) => {}
+            Err(e) => log_warn!("steward", "⚠️ Failed to summarize {:?}: {}", path.file_name(), e),
+        }
+    });
+}
+
 async fn execute_thread_file_internal(
     path: &PathBuf,
     base_path: &Path,
@@ -142,7 +228,8 @@ async fn execute_thread_file_internal(
         Some(id) => id,
         None => {
             let fallback = extract_channel_id_from_path(path);
-            println!(
+            log_warn!(
+                "steward",
                 "⚠️ Steward using fallback channel ID: {} for {:?}",
                 fallback,
                 path.file_name()
@@ -157,77 +244,225 @@ async fn execute_thread_file_internal(
     }
 
     if !is_log {
-        while let Some(caps) = PENDING_TODO_RE.captures(&content) {
+        let header = header_owned.as_ref().expect("ritual header checked above");
+        if header.status.trim() == crate::ritual_control::PAUSED_STATUS {
+            log_info!("steward", "⏸️ Ritual #{} is paused; leaving its steps alone.", thread_id);
+            return Ok(());
+        }
+        let max_runtime_secs = header.max_runtime_secs.filter(|secs| *secs > 0);
+        let pending = crate::ritual_runs::pending_prerequisites(
+            base_path,
+            &header.depends_on,
+            Local::now().date_naive(),
+        );
+        if !pending.is_empty() {
+            log_info!(
+                "steward",
+                "⛓️ Ritual #{} waits for prerequisites: {}",
+                thread_id,
+                pending.join(", ")
+            );
+            return Ok(());
+        }
+        let body_offset = body_start(&content);
+        let task_id = crate::ritual_runs::ritual_task_id(header.task_id.as_deref(), path);
+        let mut steps_completed = 0usize;
+        let mut steps_attempted = 0usize;
+        let mut run_failure: Option<&str> = None;
+        let mut tools_used = BTreeSet::new();
+        let run_started = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let token_meter = Arc::new(AtomicU64::new(0));
+        while let Some(caps) = PENDING_TODO_RE.captures(&content[body_offset..]) {
             let task_line = caps.get(0).unwrap().as_str();
             let task_desc = caps.get(1).unwrap().as_str();
+            let task_label = task_desc.to_string();
 
-            println!("⚙️ Executing step in #{}: {}", thread_id, task_desc);
+            log_info!("steward", "⚙️ Executing step in #{}: {}", thread_id, task_desc);
+            steps_attempted += 1;
 
-            let outcome = match execute_ritual_step(
-                task_desc,
-                &content,
-                path,
-                base_path,
-                Arc::clone(&config),
-                &channel_id,
-            )
-            .await
-            {
+            let step = crate::llm::metered(
+                &token_meter,
+                execute_ritual_step(
+                    task_desc,
+                    &content,
+                    path,
+                    base_path,
+                    Arc::clone(&config),
+                    &channel_id,
+                ),
+            );
+            let result = match max_runtime_secs {
+                Some(limit) => match tokio::time::timeout(Duration::from_secs(limit), step).await {
+                    Ok(result) => result,
+                    Err(_) => {
+                        log_warn!(
+                            "steward",
+                            "⏱️ Step in #{} exceeded max_runtime_secs ({}s): {}",
+                            thread_id, limit, task_label
+                        );
+                        crate::session_store::finish(base_path, path);
+                        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+                        content = format!(
+                            "{}{}",
+                            &content[..body_offset],
+                            mark_task_timed_out(
+                                &content[body_offset..],
+                                task_line,
+                                &timestamp,
+                                limit,
+                                config.runtime.language,
+                            )
+                        );
+                        crate::self_writes::write(base_path, path, &content).await?;
+                        lifecycle::emit(
+                            &config,
+                            LifecycleEvent::new(
+                                LifecycleEventKind::TaskFailed,
+                                thread_id,
+                                &channel_id,
+                                format!("Task timed out: {}", task_label),
+                                format!("Exceeded max_runtime_secs of {}s", limit),
+                            ),
+                        );
+                        run_failure = Some("timed_out");
+                        break;
+                    }
+                },
+                None => step.await,
+            };
+            let outcome = match result {
                 Ok(outcome) => outcome,
                 Err(e) => {
-                    eprintln!("❌ Error executing task in #{}: {}", thread_id, e);
+                    log_error!("steward", "❌ Error executing task in #{}: {}", thread_id, e);
                     let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-                    content = append_internal_task_error_log(&content, &timestamp, &e.to_string());
-                    tokio::fs::write(path, &content).await?;
+                    content = append_internal_task_error_log(
+                        &content,
+                        &timestamp,
+                        &e.to_string(),
+                        config.runtime.language,
+                    );
+                    crate::self_writes::write(base_path, path, &content).await?;
+                    lifecycle::emit(
+                        &config,
+                        LifecycleEvent::new(
+                            LifecycleEventKind::TaskFailed,
+                            thread_id,
+                            &channel_id,
+                            format!("Task failed: {}", task_label),
+                            e.to_string(),
+                        ),
+                    );
+                    run_failure = Some("failed");
                     break;
                 }
             };
+            tools_used.extend(outcome.trace.called_tools().map(str::to_string));
 
             let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-            let (next_content, completed) =
-                append_task_result_log(&content, task_line, &outcome, &timestamp);
-            content = next_content;
+            let (next_body, completed) = append_task_result_log(
+                &content[body_offset..],
+                task_line,
+                &outcome,
+                &timestamp,
+                config.runtime.language,
+            );
+            content = format!("{}{}", &content[..body_offset], next_body);
 
             if completed {
-                tokio::fs::write(path, &content).await?;
+                crate::self_writes::write(base_path, path, &content).await?;
+                steps_completed += 1;
 
                 let sanitized_result = mask_sensitive_data(&outcome.user_response, &config);
-                if let Err(e) = discord_client::send_bot_message(
-                    &config.discord.token,
+                if let Err(e) = crate::quiet_hours::notify(
+                    &config,
                     &channel_id,
-                    &format!(
-                        "⚙️ Step completed in **#{}**\n{}",
-                        thread_id, sanitized_result
+                    format!(
+                        "⚙️ Step completed in **#{}** {}{}]\n{}",
+                        thread_id,
+                        crate::ritual_control::RITUAL_MARKER,
+                        task_id,
+                        sanitized_result
                     ),
                 )
                 .await
                 {
-                    eprintln!(
+                    log_error!(
+                        "steward",
                         "❌ Failed to send Discord ritual message to {}: {:?}",
                         channel_id, e
                     );
                 }
             } else {
-                tokio::fs::write(path, &content).await?;
+                crate::self_writes::write(base_path, path, &content).await?;
+                lifecycle::emit(
+                    &config,
+                    LifecycleEvent::new(
+                        LifecycleEventKind::TaskFailed,
+                        thread_id,
+                        &channel_id,
+                        format!("Task failed ({}): {}", outcome.final_state.label(), task_label),
+                        outcome.user_response.clone(),
+                    ),
+                );
+                run_failure = Some("failed");
                 break;
             }
         }
+
+        if steps_attempted > 0 {
+            let run = crate::ritual_runs::RitualRun {
+                task_id: task_id.clone(),
+                started_at: run_started,
+                finished_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                result: run_failure.unwrap_or("completed").to_string(),
+                steps_completed,
+                tools: tools_used.into_iter().collect(),
+                tokens: token_meter.load(Ordering::Relaxed),
+            };
+            if let Err(e) = crate::ritual_runs::record_run(base_path, &run) {
+                log_warn!("steward", "⚠️ Failed to record ritual run for {}: {}", task_id, e);
+            }
+            crate::alerts::after_ritual_run(base_path, &config, &task_id).await;
+        }
+
+        if steps_completed > 0 && !PENDING_TODO_RE.is_match(&content[body_offset..]) {
+            let (base, finished) = (base_path.to_path_buf(), task_id.clone());
+            let released = tokio::task::spawn_blocking(move || {
+                crate::rhythm::release_dependents(&base, &finished)
+            })
+            .await
+            .unwrap_or_default();
+            for dependent in released {
+                log_info!("steward", "⛓️ Ritual {} released {:?}", task_id, dependent.file_name());
+            }
+            lifecycle::emit(
+                &config,
+                LifecycleEvent::new(
+                    LifecycleEventKind::RitualCompleted,
+                    thread_id,
+                    &channel_id,
+                    format!("Ritual completed: {} step(s)", steps_completed),
+                    String::new(),
+                ),
+            );
+        }
     } else {
-        println!("🗣️ Conversational Mode in #{}...", thread_id);
+        log_info!("steward", "🗣️ Conversational Mode in #{}...", thread_id);
         let _ = discord_client::broadcast_typing(&config.discord.token, &channel_id).await;
 
         match run_conversational_loop(&content, path, base_path, Arc::clone(&config), trigger_id, &channel_id)
             .await
         {
             Ok(outcome) => {
-                println!(
+                log_info!(
+                    "steward",
                     "🗣️ Conversational outcome in #{}: {}",
                     thread_id,
                     outcome.log_summary()
                 );
 
                 let sanitized_result = mask_sensitive_data(&outcome.user_response, &config);
-                match discord_client::send_bot_message(
+                match discord_client::send_bot_message_or_file(
                     &config.discord.token,
                     &channel_id,
                     &sanitized_result,
@@ -244,16 +479,19 @@ async fn execute_thread_file_internal(
                             &msg.id.to_string(),
                             &outcome.user_response,
                         );
-                        if let Err(error) = tokio::fs::write(path, &content).await {
-                            eprintln!(
+                        if let Err(error) = crate::self_writes::write(base_path, path, &content).await {
+                            log_warn!(
+                                "steward",
                                 "⚠️ Failed to persist Discord-backed response log for {:?}: {:?}",
                                 path.file_name(),
                                 error
                             );
                         }
+                        spawn_log_summary(base_path, path, Arc::clone(&config));
                     }
                     Err(e) => {
-                        eprintln!(
+                        log_error!(
+                            "steward",
                             "❌ Failed to send Discord message to {}: {:?}",
                             channel_id, e
                         );
@@ -263,8 +501,9 @@ async fn execute_thread_file_internal(
                             &timestamp.to_string(),
                             &outcome.user_response,
                         );
-                        if let Err(error) = tokio::fs::write(path, &content).await {
-                            eprintln!(
+                        if let Err(error) = crate::self_writes::write(base_path, path, &content).await {
+                            log_warn!(
+                                "steward",
                                 "⚠️ Failed to persist local fallback response log for {:?}: {:?}",
                                 path.file_name(),
                                 error
@@ -274,12 +513,27 @@ async fn execute_thread_file_internal(
                 }
             }
             Err(e) => {
-                eprintln!("❌ Steward loop failed in #{}: {:?}", thread_id, e);
+                log_error!("steward", "❌ Steward loop failed in #{}: {:?}", thread_id, e);
+                lifecycle::emit(
+                    &config,
+                    LifecycleEvent::new(
+                        LifecycleEventKind::TaskFailed,
+                        thread_id,
+                        &channel_id,
+                        "Conversational task failed",
+                        e.to_string(),
+                    ),
+                );
                 let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-                content =
-                    append_processing_error_log(&content, &timestamp.to_string(), &e.to_string());
-                if let Err(error) = tokio::fs::write(path, &content).await {
-                    eprintln!(
+                content = append_processing_error_log(
+                    &content,
+                    &timestamp.to_string(),
+                    &e.to_string(),
+                    config.runtime.language,
+                );
+                if let Err(error) = crate::self_writes::write(base_path, path, &content).await {
+                    log_warn!(
+                        "steward",
                         "⚠️ Failed to persist processing error log for {:?}: {:?}",
                         path.file_name(),
                         error
@@ -289,11 +543,12 @@ async fn execute_thread_file_internal(
                 if let Err(error) = discord_client::send_bot_message(
                     &config.discord.token,
                     &channel_id,
-                    "⚠️ *管家在处理您的请求时遇到了异常，请稍后再试，或检查黑板记录。*",
+                    &Message::ProcessingFailed.render(config.runtime.language),
                 )
                 .await
                 {
-                    eprintln!(
+                    log_warn!(
+                        "steward",
                         "⚠️ Failed to send processing-error notification to {}: {:?}",
                         channel_id, error
                     );
@@ -312,20 +567,32 @@ async fn execute_thread_file_internal(
                 if let Some(file_name) = path.file_name() {
                     let dest_path = history_destination(parent, file_name, &today);
                     if let Err(e) = fs::rename(path, &dest_path) {
-                        eprintln!("⚠️ Failed to archive thread: {:?}", e);
+                        log_warn!("steward", "⚠️ Failed to archive thread: {:?}", e);
                     } else {
-                        println!("📦 Thread archived to history/{}", today);
+                        log_info!("steward", "📦 Thread archived to history/{}", today);
+                        lifecycle::emit(
+                            &config,
+                            LifecycleEvent::new(
+                                LifecycleEventKind::ThreadArchived,
+                                thread_id,
+                                &channel_id,
+                                format!("Thread archived to history/{}", today),
+                                dest_path.display().to_string(),
+                            ),
+                        );
                         if let Err(error) = discord_client::send_bot_message(
                             &config.discord.token,
                             &channel_id,
-                            &format!(
-                                "📦 Thread **#{}** has been archived to history/{}",
-                                thread_id, today
-                            ),
+                            &Message::ThreadArchived {
+                                thread: thread_id,
+                                date: &today,
+                            }
+                            .render(config.runtime.language),
                         )
                         .await
                         {
-                            eprintln!(
+                            log_warn!(
+                                "steward",
                                 "⚠️ Failed to send archive notification to {}: {:?}",
                                 channel_id, error
                             );
@@ -4,7 +4,9 @@
  * Responsibility: Thread file persistence helpers, log entry formatting, and archive path rules.
  */
 
+use crate::config::Language;
 use crate::execution_contract::ExecutionOutcome;
+use crate::i18n::Message;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::ffi::OsStr;
@@ -17,22 +19,30 @@ pub(crate) fn append_task_result_log(
     task_line: &str,
     outcome: &ExecutionOutcome,
     timestamp: &str,
+    language: Language,
 ) -> (String, bool) {
     if outcome.is_terminal_success() {
         let updated_line = task_line.replace("[ ]", "[x]");
         let log_entry = format!(
-            "\n> [{}] Execution result: {}",
-            timestamp, outcome.user_response
+            "\n> [{}] {}",
+            timestamp,
+            Message::ExecutionResult {
+                response: &outcome.user_response
+            }
+            .render(language)
         );
         let mut next = content.replacen(task_line, &updated_line, 1);
         next.push_str(&log_entry);
         (next, true)
     } else {
         let log_entry = format!(
-            "\n> [{}] ❌ Task failed ({}): {}",
+            "\n> [{}] {}",
             timestamp,
-            outcome.final_state.label(),
-            outcome.user_response
+            Message::TaskFailed {
+                state: outcome.final_state.label(),
+                response: &outcome.user_response
+            }
+            .render(language)
         );
         let mut next = content.to_string();
         next.push_str(&log_entry);
@@ -44,11 +54,35 @@ pub(crate) fn append_internal_task_error_log(
     content: &str,
     timestamp: &str,
     error: &str,
+    language: Language,
 ) -> String {
     let mut next = content.to_string();
     next.push_str(&format!(
-        "\n> [{}] ❌ Task failed (InternalError): {}",
-        timestamp, error
+        "\n> [{}] {}",
+        timestamp,
+        Message::TaskFailed {
+            state: "InternalError",
+            response: error
+        }
+        .render(language)
+    ));
+    next
+}
+
+/// Mark a step that ran past `max_runtime_secs` as failed (`- [!]`) so it is not picked
+/// up again, and log why.
+pub(crate) fn mark_task_timed_out(
+    content: &str,
+    task_line: &str,
+    timestamp: &str,
+    limit_secs: u64,
+    language: Language,
+) -> String {
+    let mut next = content.replacen(task_line, &task_line.replacen("[ ]", "[!]", 1), 1);
+    next.push_str(&format!(
+        "\n> [{}] {}",
+        timestamp,
+        Message::TaskTimedOut { limit_secs }.render(language)
     ));
     next
 }
@@ -82,11 +116,17 @@ pub(crate) fn append_local_response_log(
     next
 }
 
-pub(crate) fn append_processing_error_log(content: &str, timestamp: &str, error: &str) -> String {
+pub(crate) fn append_processing_error_log(
+    content: &str,
+    timestamp: &str,
+    error: &str,
+    language: Language,
+) -> String {
     let mut next = content.to_string();
     next.push_str(&format!(
-        "\n\n> [Tellar] ({}): ❌ Error processing request: {}",
-        timestamp, error
+        "\n\n> [Tellar] ({}): {}",
+        timestamp,
+        Message::ProcessingError { error }.render(language)
     ));
     next
 }
@@ -128,6 +168,7 @@ mod tests {
             "- [ ] Ship release",
             &outcome,
             "2026-02-27 12:00:00",
+            Language::En,
         );
 
         assert!(completed);
@@ -153,6 +194,7 @@ mod tests {
             "- [ ] Ship release",
             &outcome,
             "2026-02-27 12:00:00",
+            Language::En,
         );
 
         assert!(completed);
@@ -177,11 +219,34 @@ mod tests {
             "- [ ] Ship release",
             &outcome,
             "2026-02-27 12:00:00",
+            Language::En,
         );
 
         assert!(!completed);
         assert!(updated.contains("- [ ] Ship release"));
         assert!(updated.contains("❌ Task failed (Failed): network failed"));
+        let (updated, _) = append_task_result_log(
+            content,
+            "- [ ] Ship release",
+            &outcome,
+            "2026-02-27 12:00:00",
+            Language::Zh,
+        );
+        assert!(updated.contains("❌ 任务失败 (Failed)：network failed"));
+    }
+
+    #[test]
+    fn test_mark_task_timed_out_fails_the_step() {
+        let content = "---\nstatus: active\nmax_runtime_secs: 60\n---\n- [ ] Crawl the site\n- [ ] Report";
+        let updated = mark_task_timed_out(
+            content,
+            "- [ ] Crawl the site",
+            "2026-02-27 12:00:00",
+            60,
+            Language::En,
+        );
+        assert!(updated.contains("- [!] Crawl the site\n- [ ] Report"));
+        assert!(updated.contains("❌ Task failed (TimedOut): still running after max_runtime_secs of 60s"));
     }
 
     #[test]
@@ -6,12 +6,25 @@
 
 use crate::config::Config;
 use crate::delivery;
+use crate::log_info;
 use crate::skills::{self, SkillMetadata};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use once_cell::sync::Lazy;
+use regex::RegexBuilder;
 use serde_json::{Value, json};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use tokio::process::Command;
+
+/// Redirects are returned, not followed, so every hop goes back through the allowlist
+/// as a new call.
+static HTTP_TOOL_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .expect("Failed to create http_request reqwest client")
+});
 
 #[derive(Debug, Clone)]
 struct ResolvedTargetPath {
@@ -46,7 +59,7 @@ impl ToolExecutionResult {
     }
 }
 
-fn normalize_path(path: &str) -> &str {
+pub(crate) fn normalize_path(path: &str) -> &str {
     let p = path.strip_prefix("guild/").unwrap_or(path);
     p.strip_prefix("./").unwrap_or(p)
 }
@@ -109,7 +122,7 @@ fn require_non_empty_string_arg<'a>(
     })
 }
 
-fn require_safe_rel_path<'a>(
+pub(crate) fn require_safe_rel_path<'a>(
     args: &'a Value,
     field: &str,
     base_path: &Path,
@@ -334,6 +347,58 @@ pub(crate) fn run_find_tool(args: &Value, base_path: &Path) -> ToolExecutionResu
     }
 }
 
+/// Files whose first bytes contain NUL are treated as binary and skipped by `grep`.
+const BINARY_SNIFF_BYTES: usize = 8192;
+
+fn string_list_arg(args: &Value, field: &str) -> Vec<String> {
+    match args.get(field) {
+        Some(Value::String(value)) => vec![value.clone()],
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn build_glob_set(args: &Value, field: &str) -> Result<Option<GlobSet>, ToolExecutionResult> {
+    let patterns = string_list_arg(args, field);
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &patterns {
+        let glob = Glob::new(pattern).map_err(|e| {
+            ToolExecutionResult::error(format!(
+                "Error: Invalid `{}` glob `{}`: {}",
+                field, pattern, e
+            ))
+        })?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map(Some)
+        .map_err(|e| ToolExecutionResult::error(format!("Error: Invalid `{}` globs: {}", field, e)))
+}
+
+/// A glob matches either the guild-relative path (`brain/**/*.md`) or the bare file name (`*.md`).
+fn glob_matches(set: &GlobSet, display: &str, path: &Path) -> bool {
+    set.is_match(display)
+        || path
+            .file_name()
+            .is_some_and(|file_name| set.is_match(Path::new(file_name)))
+}
+
+fn read_text_file(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    if bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return None;
+    }
+    String::from_utf8(bytes).ok()
+}
+
 pub(crate) fn run_grep_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
     let pattern = match require_non_empty_string_arg(args, "pattern") {
         Ok(value) => value,
@@ -352,55 +417,115 @@ pub(crate) fn run_grep_tool(args: &Value, base_path: &Path) -> ToolExecutionResu
         .get("caseSensitive")
         .and_then(Value::as_bool)
         .unwrap_or(false);
+    let is_regex = args.get("regex").and_then(Value::as_bool).unwrap_or(false);
     let max_matches = args.get("maxMatches").and_then(Value::as_u64).unwrap_or(50) as usize;
+    let before = args.get("before").and_then(Value::as_u64).unwrap_or(0) as usize;
+    let after = args.get("after").and_then(Value::as_u64).unwrap_or(0) as usize;
 
-    let paths = match collect_target_paths(base_path, &target, recursive, usize::MAX) {
-        Ok(paths) => paths,
+    let source = if is_regex {
+        pattern.to_string()
+    } else {
+        regex::escape(pattern)
+    };
+    let matcher = match RegexBuilder::new(&source)
+        .case_insensitive(!case_sensitive)
+        .build()
+    {
+        Ok(matcher) => matcher,
+        Err(e) => {
+            return ToolExecutionResult::error(format!(
+                "Error: Invalid regex `{}`: {}",
+                pattern, e
+            ));
+        }
+    };
+    let include = match build_glob_set(args, "include") {
+        Ok(set) => set,
+        Err(err) => return err,
+    };
+    let exclude = match build_glob_set(args, "exclude") {
+        Ok(set) => set,
         Err(err) => return err,
     };
 
-    let needle = if case_sensitive {
-        pattern.to_string()
-    } else {
-        pattern.to_lowercase()
+    let paths = match collect_target_paths(base_path, &target, recursive, usize::MAX) {
+        Ok(paths) => paths,
+        Err(err) => return err,
     };
 
-    let mut matches = Vec::new();
-    for (display, path) in paths {
+    let mut output: Vec<String> = Vec::new();
+    let mut match_count = 0;
+    'files: for (display, path) in paths {
         if !path.is_file() {
             continue;
         }
-        let Ok(content) = std::fs::read_to_string(&path) else {
+        if include
+            .as_ref()
+            .is_some_and(|set| !glob_matches(set, &display, &path))
+            || exclude
+                .as_ref()
+                .is_some_and(|set| glob_matches(set, &display, &path))
+        {
+            continue;
+        }
+        let Some(content) = read_text_file(&path) else {
             continue;
         };
 
-        for (index, line) in content.lines().enumerate() {
-            let haystack = if case_sensitive {
-                line.to_string()
-            } else {
-                line.to_lowercase()
-            };
-            if haystack.contains(&needle) {
-                matches.push(format!("{}:{}: {}", display, index + 1, line));
-                if matches.len() >= max_matches {
-                    return ToolExecutionResult::success(matches.join("\n"));
+        let lines: Vec<&str> = content.lines().collect();
+        // Last line already printed for this file, so overlapping context is not repeated.
+        let mut printed_until: Option<usize> = None;
+        for (index, line) in lines.iter().enumerate() {
+            if !matcher.is_match(line) {
+                continue;
+            }
+            let from = index.saturating_sub(before);
+            let from = printed_until.map_or(from, |last| from.max(last + 1));
+            if (before > 0 || after > 0)
+                && !output.is_empty()
+                && printed_until.is_none_or(|last| from > last + 1)
+            {
+                output.push("--".to_string());
+            }
+            for (context, context_line) in lines.iter().enumerate().take(index).skip(from) {
+                output.push(format!("{}-{}- {}", display, context + 1, context_line));
+            }
+            output.push(format!("{}:{}: {}", display, index + 1, line));
+            printed_until = Some(index);
+            match_count += 1;
+
+            let context_end = (index + after).min(lines.len() - 1);
+            for (context, context_line) in lines
+                .iter()
+                .enumerate()
+                .take(context_end + 1)
+                .skip(index + 1)
+            {
+                if matcher.is_match(context_line) {
+                    // Let the main loop print the next match with its own marker.
+                    break;
                 }
+                output.push(format!("{}-{}- {}", display, context + 1, context_line));
+                printed_until = Some(context);
+            }
+            if match_count >= max_matches {
+                break 'files;
             }
         }
     }
 
-    if matches.is_empty() {
+    if output.is_empty() {
         ToolExecutionResult::success(format!(
             "No matches for `{}` under {}.",
             pattern, target.rel_path
         ))
     } else {
-        ToolExecutionResult::success(matches.join("\n"))
+        ToolExecutionResult::success(output.join("\n"))
     }
 }
 
 pub(crate) fn core_tool_definitions() -> Vec<Value> {
-    vec![
+    let mut definitions = vec![
         json!({
             "name": "ls",
             "description": "List files and directories inside the guild. Use this for discovery instead of shell access.",
@@ -431,12 +556,17 @@ pub(crate) fn core_tool_definitions() -> Vec<Value> {
         }),
         json!({
             "name": "grep",
-            "description": "Search text files for a string pattern. Use this to find filenames, symbols, IDs, or text snippets inside the guild.",
+            "description": "Search text files for a string or regex pattern, skipping binary files. Use this to find filenames, symbols, IDs, or text snippets inside the guild.",
             "parameters": {
                 "type": "object",
                 "properties": {
-                    "pattern": { "type": "string", "description": "The text to search for" },
+                    "pattern": { "type": "string", "description": "The text to search for, or a regex when `regex` is true" },
+                    "regex": { "type": "boolean", "description": "Treat pattern as a regular expression. Defaults to false (literal text)" },
                     "path": { "type": "string", "description": "Path to search under, relative to guild root. Defaults to '.'" },
+                    "include": { "type": "array", "items": { "type": "string" }, "description": "Only search files matching these globs, e.g. ['*.md', 'brain/**']" },
+                    "exclude": { "type": "array", "items": { "type": "string" }, "description": "Skip files matching these globs, e.g. ['brain/traces/**']" },
+                    "before": { "type": "number", "description": "Context lines to show before each match (like grep -B)" },
+                    "after": { "type": "number", "description": "Context lines to show after each match (like grep -A)" },
                     "recursive": { "type": "boolean", "description": "Whether to search subdirectories. Defaults to true" },
                     "caseSensitive": { "type": "boolean", "description": "Whether matching should be case sensitive" },
                     "maxMatches": { "type": "number", "description": "Maximum number of matches to return. Defaults to 50" }
@@ -446,7 +576,7 @@ pub(crate) fn core_tool_definitions() -> Vec<Value> {
         }),
         json!({
             "name": "read",
-            "description": "Read the contents of a file. Supports line-based reading with offset and limit.",
+            "description": "Read the contents of a file. Supports line-based reading with offset and limit. Also pages through brain/artifacts/ files holding the full text of truncated tool output.",
             "parameters": {
                 "type": "object",
                 "properties": {
@@ -459,12 +589,13 @@ pub(crate) fn core_tool_definitions() -> Vec<Value> {
         }),
         json!({
             "name": "write",
-            "description": "Write content to a file. Overwrites existing content. Creates parent directories.",
+            "description": "Write content to a file. Overwrites existing content unless append=true. Creates parent directories. Use append for logs and `patch` for targeted changes to large files.",
             "parameters": {
                 "type": "object",
                 "properties": {
                     "path": { "type": "string", "description": "Path to the file to write (relative to guild root)" },
-                    "content": { "type": "string", "description": "The content to write" }
+                    "content": { "type": "string", "description": "The content to write" },
+                    "append": { "type": "boolean", "description": "Append to the end of the file instead of overwriting it" }
                 },
                 "required": ["path", "content"]
             }
@@ -482,6 +613,26 @@ pub(crate) fn core_tool_definitions() -> Vec<Value> {
                 "required": ["path", "oldText", "newText"]
             }
         }),
+        json!({
+            "name": "patch",
+            "description": "Apply a unified diff (`@@ -start,count +start,count @@` hunks with ' ', '-', '+' lines) to one file. Hunks may span many lines; context must match the file. All hunks apply or none do.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Path to the file to patch (relative to guild root)" },
+                    "diff": { "type": "string", "description": "Unified diff for this file" }
+                },
+                "required": ["path", "diff"]
+            }
+        }),
+    ];
+    definitions.extend(crate::file_ops::file_tool_definitions());
+    definitions.extend(crate::archive::archive_tool_definitions());
+    definitions.extend(crate::file_info::file_info_tool_definitions());
+    definitions.extend(crate::scratch::scratch_tool_definitions());
+    definitions.extend(crate::memory::memory_tool_definitions());
+    definitions.extend(crate::entities::entity_tool_definitions());
+    definitions.extend([
         json!({
             "name": "exec",
             "description": "Run a host shell command. This is a privileged tool: when runtime.privileged=false it rejects immediately. Use this for absolute host paths, system scripts, or cross-workspace operations.",
@@ -493,7 +644,35 @@ pub(crate) fn core_tool_definitions() -> Vec<Value> {
                 "required": ["command"]
             }
         }),
-    ]
+    ]);
+    definitions.extend(crate::exec_jobs::exec_job_tool_definitions());
+    definitions.extend([
+        json!({
+            "name": "http_request",
+            "description": "Call an HTTP API directly instead of shelling out to curl. Only hosts in http.allowed_domains are reachable unless runtime.privileged=true. Returns the status line, content type, and a truncated body. Redirects are not followed: call the returned Location to continue.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "url": { "type": "string", "description": "Absolute http(s) URL" },
+                    "method": { "type": "string", "description": "GET, POST, PUT, PATCH, DELETE, or HEAD. Defaults to GET" },
+                    "headers": { "type": "object", "description": "Request headers as name/value strings" },
+                    "json": { "type": "object", "description": "JSON request body; sets Content-Type: application/json" },
+                    "body": { "type": "string", "description": "Raw request body, used when `json` is absent" }
+                },
+                "required": ["url"]
+            }
+        }),
+        crate::web_search::web_search_tool_definition(),
+        crate::memory_search::search_memory_tool_definition(),
+        crate::fetch_url::fetch_url_tool_definition(),
+        crate::sql::sql_tool_definition(),
+        crate::datetime::datetime_tool_definition(),
+        crate::reminders::remind_me_tool_definition(),
+        crate::diff::diff_tool_definition(),
+        crate::image_ops::image_tool_definition(),
+        crate::trace_log::explain_tool_definition(),
+    ]);
+    definitions
 }
 
 async fn run_exec_tool(args: &Value, base_path: &Path, config: &Config) -> ToolExecutionResult {
@@ -508,18 +687,26 @@ async fn run_exec_tool(args: &Value, base_path: &Path, config: &Config) -> ToolE
         );
     }
 
-    let output = match config.runtime.exec_mode {
+    let mode = config.runtime.exec_mode;
+    match mode {
         crate::config::ExecMode::Unrestricted => {
-            println!("🔴 [AUDIT] Executing host command: {}", command);
-            Command::new("sh")
-                .arg("-lc")
-                .arg(command)
-                .current_dir(base_path)
-                .env("TELLAR_WORKSPACE", base_path)
-                .output()
-                .await
+            log_info!("steward", "🔴 [AUDIT] Executing host command: {}", command)
         }
-    };
+        _ => log_info!(
+            "steward",
+            "🟠 [AUDIT] Executing {:?} sandboxed command: {}",
+            mode, command
+        ),
+    }
+    let output = crate::exec_sandbox::exec_command(
+        mode,
+        &config.runtime.exec_sandbox,
+        command,
+        base_path,
+        &crate::child_env::exec_env(config, base_path),
+    )
+    .output()
+    .await;
 
     match output {
         Ok(output) => {
@@ -551,7 +738,203 @@ async fn run_exec_tool(args: &Value, base_path: &Path, config: &Config) -> ToolE
                 ToolExecutionResult::error(format!("Command failed ({}):\n{}", code, combined))
             }
         }
-        Err(e) => ToolExecutionResult::error(format!("Error executing command: {}", e)),
+        Err(e) => match crate::exec_sandbox::sandbox_program(mode) {
+            Some(program) if e.kind() == std::io::ErrorKind::NotFound => {
+                ToolExecutionResult::error(format!(
+                    "Error: runtime.exec_mode needs `{}`, which is not installed on this host.",
+                    program
+                ))
+            }
+            _ => ToolExecutionResult::error(format!("Error executing command: {}", e)),
+        },
+    }
+}
+
+pub(crate) fn tool_pattern_matches(patterns: &[String], name: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        let pattern = pattern.trim();
+        match pattern.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => pattern == name,
+        }
+    })
+}
+
+/// Apply `runtime.tools`: channel rules first, then guild-wide ones. Returns why a call is refused.
+pub(crate) fn tool_policy_violation(
+    config: &Config,
+    name: &str,
+    channel_id: &str,
+) -> Option<String> {
+    let policy = &config.runtime.tools;
+    if let Some(rules) = policy.channels.get(channel_id) {
+        if tool_pattern_matches(&rules.deny, name) {
+            return Some(format!("`{}` is denied in this channel", name));
+        }
+        if !rules.allow.is_empty() {
+            return (!tool_pattern_matches(&rules.allow, name))
+                .then(|| format!("`{}` is not in this channel's allow list", name));
+        }
+    }
+    if tool_pattern_matches(&policy.deny, name) {
+        return Some(format!("`{}` is denied for this guild", name));
+    }
+    if !policy.allow.is_empty() && !tool_pattern_matches(&policy.allow, name) {
+        return Some(format!("`{}` is not in the guild allow list", name));
+    }
+    None
+}
+
+/// `host` equals an allowed domain or is one of its subdomains.
+pub(crate) fn is_domain_allowed(host: &str, allowed_domains: &[String]) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    allowed_domains.iter().any(|domain| {
+        let domain = domain.trim().trim_start_matches("*.").to_ascii_lowercase();
+        domain == "*"
+            || host == domain
+            || host
+                .strip_suffix(&domain)
+                .is_some_and(|prefix| prefix.ends_with('.'))
+    })
+}
+
+/// Ceiling on bytes read when `http.max_response_bytes` is 0 (no truncation).
+const HTTP_BODY_HARD_CAP: usize = 3_000_000;
+
+/// Read at most `limit` bytes of the body, then stop downloading, so a huge response
+/// never lands in memory. Notes the cut, with the full size when the server sent one.
+async fn read_capped_body(
+    mut response: reqwest::Response,
+    limit: usize,
+) -> reqwest::Result<String> {
+    let limit = if limit == 0 { HTTP_BODY_HARD_CAP } else { limit };
+    let total = response.content_length();
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if body.len() > limit {
+            body.truncate(limit);
+            let of = total
+                .map(|total| format!(" of {}", total))
+                .unwrap_or_default();
+            return Ok(format!(
+                "{}\n... [body truncated at {}{} bytes]",
+                String::from_utf8_lossy(&body),
+                limit,
+                of
+            ));
+        }
+    }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+async fn run_http_request_tool(args: &Value, config: &Config) -> ToolExecutionResult {
+    let raw_url = match require_non_empty_string_arg(args, "url") {
+        Ok(value) => value,
+        Err(err) => return err,
+    };
+    let url = match reqwest::Url::parse(raw_url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => url,
+        _ => {
+            return ToolExecutionResult::error(format!(
+                "Error: `{}` is not an absolute http(s) URL.",
+                raw_url
+            ));
+        }
+    };
+    let host = url.host_str().unwrap_or_default();
+    if !config.runtime.privileged && !is_domain_allowed(host, &config.http.allowed_domains) {
+        return ToolExecutionResult::error(format!(
+            "Error: `{}` is not in http.allowed_domains and runtime.privileged=false. Explain the limitation or ask the operator to allow the domain.",
+            host
+        ));
+    }
+
+    let method_name = args
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or("GET")
+        .to_ascii_uppercase();
+    let method = match method_name.as_str() {
+        "GET" => reqwest::Method::GET,
+        "POST" => reqwest::Method::POST,
+        "PUT" => reqwest::Method::PUT,
+        "PATCH" => reqwest::Method::PATCH,
+        "DELETE" => reqwest::Method::DELETE,
+        "HEAD" => reqwest::Method::HEAD,
+        other => {
+            return ToolExecutionResult::error(format!(
+                "Error: Unsupported HTTP method `{}`.",
+                other
+            ));
+        }
+    };
+
+    let mut request = HTTP_TOOL_CLIENT
+        .request(method, url.clone())
+        .timeout(std::time::Duration::from_secs(
+            config.http.timeout_secs.max(1),
+        ));
+    if let Some(headers) = args.get("headers").and_then(Value::as_object) {
+        for (name, value) in headers {
+            let value = match value {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            request = request.header(name.as_str(), value);
+        }
+    }
+    if let Some(body) = args.get("json").filter(|body| !body.is_null()) {
+        request = request.json(body);
+    } else if let Some(body) = args.get("body").and_then(Value::as_str) {
+        request = request.body(body.to_string());
+    }
+
+    log_info!("steward", "🌐 [AUDIT] {} {}", method_name, url);
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(error) => {
+            return ToolExecutionResult::error(format!("Error: HTTP request failed: {}", error));
+        }
+    };
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("unknown")
+        .to_string();
+    let location = response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .filter(|_| status.is_redirection())
+        .and_then(|value| value.to_str().ok())
+        .and_then(|location| url.join(location).ok())
+        .map(|location| {
+            format!(
+                "\nLocation: {} (not followed; request it to continue, if its host is allowed)",
+                location
+            )
+        })
+        .unwrap_or_default();
+    let body = match read_capped_body(response, config.http.max_response_bytes).await {
+        Ok(body) => body,
+        Err(error) => {
+            return ToolExecutionResult::error(format!(
+                "Error: Failed to read HTTP response body: {}",
+                error
+            ));
+        }
+    };
+
+    let output = format!(
+        "HTTP {}\nContent-Type: {}{}\n\n{}",
+        status, content_type, location, body
+    );
+    if status.is_success() {
+        ToolExecutionResult::success(output)
+    } else {
+        ToolExecutionResult::error(output)
     }
 }
 
@@ -605,12 +988,52 @@ fn run_write_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
         let _ = fs::create_dir_all(parent);
     }
 
-    match std::fs::write(&full_path, content) {
+    let append = args.get("append").and_then(Value::as_bool).unwrap_or(false);
+    let written = if append {
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&full_path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, content.as_bytes()))
+    } else {
+        std::fs::write(&full_path, content)
+    };
+
+    match written {
+        Ok(_) if append => {
+            ToolExecutionResult::success(format!("Successfully appended to {}", rel_path))
+        }
         Ok(_) => ToolExecutionResult::success(format!("Successfully wrote to {}", rel_path)),
         Err(error) => ToolExecutionResult::error(format!("Error writing file: {}", error)),
     }
 }
 
+fn run_patch_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
+    let rel_path = match require_safe_rel_path(args, "path", base_path) {
+        Ok(path) => path,
+        Err(err) => return err,
+    };
+    let diff = match require_non_empty_string_arg(args, "diff") {
+        Ok(diff) => diff,
+        Err(err) => return err,
+    };
+    let file_path = base_path.join(rel_path);
+    let Ok(content) = std::fs::read_to_string(&file_path) else {
+        return ToolExecutionResult::error(format!("Error: File not found: {}", rel_path));
+    };
+
+    match crate::patch::apply_unified_diff(&content, diff) {
+        Ok(patched) => match std::fs::write(&file_path, patched) {
+            Ok(_) => ToolExecutionResult::success(format!("Successfully patched {}", rel_path)),
+            Err(error) => ToolExecutionResult::error(format!("Error writing file: {}", error)),
+        },
+        Err(error) => ToolExecutionResult::error(format!(
+            "Error: Patch not applied to {}: {}",
+            rel_path, error
+        )),
+    }
+}
+
 fn run_edit_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
     let rel_path = match require_safe_rel_path(args, "path", base_path) {
         Ok(path) => path,
@@ -656,10 +1079,19 @@ fn run_edit_tool(args: &Value, base_path: &Path) -> ToolExecutionResult {
 pub fn mask_sensitive_data(text: &str, config: &Config) -> String {
     let mut masked = text.to_string();
 
-    let secrets = [
+    let mut secrets = vec![
         (&config.gemini.api_key, "[REDACTED_GEMINI_KEY]"),
         (&config.discord.token, "[REDACTED_DISCORD_TOKEN]"),
+        (&config.email.password, "[REDACTED_EMAIL_PASSWORD]"),
+        (&config.ntfy.token, "[REDACTED_NTFY_TOKEN]"),
+        (&config.web_search.api_key, "[REDACTED_SEARCH_KEY]"),
     ];
+    secrets.extend(
+        config
+            .bridges
+            .iter()
+            .map(|bridge| (&bridge.token, "[REDACTED_BRIDGE_TOKEN]")),
+    );
 
     for (secret, replacement) in secrets {
         if secret.len() > 10 {
@@ -667,8 +1099,9 @@ pub fn mask_sensitive_data(text: &str, config: &Config) -> String {
             masked = masked.replace(secret, replacement);
 
             // Simple prefix match (first 12 chars) to catch truncated logs or substrings
-            let prefix = &secret[..12];
-            if masked.contains(prefix) {
+            if let Some(prefix) = secret.get(..12)
+                && masked.contains(prefix)
+            {
                 // Not ideal, simple replace. We don't want to replace tiny prefixes. 12 is usually safe.
                 masked = masked.replace(prefix, replacement);
             }
@@ -714,24 +1147,43 @@ async fn dispatch_skill_tool(
     args: &Value,
     base_path: &Path,
     config: &Config,
+    channel_id: &str,
 ) -> Option<ToolExecutionResult> {
-    let mut selected: Option<(String, skills::SkillTool, PathBuf)> = None;
+    let mut selected: Option<(SkillMetadata, skills::SkillTool, PathBuf)> = None;
 
     for (meta, dir) in SkillMetadata::discover_skills(base_path) {
         if let Some(tool) = meta.tools.get(name).cloned() {
             if let Some((existing_skill, _, _)) = &selected {
                 return Some(ToolExecutionResult::error(format!(
                     "Error: Tool `{}` is ambiguous across multiple skills ({} and {}). Rename one of the tools.",
-                    name, existing_skill, meta.name
+                    name, existing_skill.name, meta.name
                 )));
             }
 
-            selected = Some((meta.name, tool, dir));
+            selected = Some((meta, tool, dir));
         }
     }
 
-    let (_, tool, dir) = selected?;
-    let result = match skills::execute_skill_tool(&tool, &dir, base_path, args, config).await {
+    let (skill, tool, dir) = selected?;
+    let issues = crate::arg_schema::validate_args(&tool.parameters, args);
+    if !issues.is_empty() {
+        return Some(ToolExecutionResult::error(format!(
+            "Error: {}",
+            crate::arg_schema::describe_issues(name, &tool.parameters, &issues)
+        )));
+    }
+
+    let started = std::time::Instant::now();
+    let outcome = skills::execute_skill_tool(&tool, &skill, &dir, base_path, args, config).await;
+    let call = crate::skill_audit::SkillCall {
+        skill: &skill.name,
+        tool: name,
+        args,
+        caller: channel_id,
+    };
+    crate::skill_audit::record_skill_call(base_path, config, &call, &outcome, started.elapsed());
+    crate::alerts::after_skill_call(base_path, config, &skill.name, name).await;
+    let result = match outcome {
         Ok(output) => ToolExecutionResult::success(output),
         Err(error) => {
             ToolExecutionResult::error(format!("Error executing skill tool `{}`: {}", name, error))
@@ -753,7 +1205,7 @@ async fn dispatch_extension_tool(
         return result;
     }
 
-    if let Some(result) = dispatch_skill_tool(name, args, base_path, config).await {
+    if let Some(result) = dispatch_skill_tool(name, args, base_path, config, channel_id).await {
         return result;
     }
 
@@ -772,7 +1224,15 @@ fn dispatch_core_sync_tool(
         "read" => run_read_tool(args, base_path),
         "write" => run_write_tool(args, base_path),
         "edit" => run_edit_tool(args, base_path),
-        _ => return None,
+        "patch" => run_patch_tool(args, base_path),
+        crate::datetime::DATETIME_TOOL_NAME => crate::datetime::run_datetime_tool(args),
+        crate::diff::DIFF_TOOL_NAME => crate::diff::run_diff_tool(args, base_path),
+        crate::image_ops::IMAGE_TOOL_NAME => crate::image_ops::run_image_tool(args, base_path),
+        _ => {
+            return crate::file_ops::dispatch_file_tool(name, args, base_path)
+                .or_else(|| crate::archive::dispatch_archive_tool(name, args, base_path))
+                .or_else(|| crate::file_info::dispatch_file_info_tool(name, args, base_path));
+        }
     };
 
     Some(result)
@@ -792,9 +1252,54 @@ async fn dispatch_builtin_tool(
         return Some(run_exec_tool(args, base_path, config).await);
     }
 
+    if name == crate::sql::SQL_TOOL_NAME {
+        return Some(crate::sql::run_sql_tool(args, base_path).await);
+    }
+
+    if let Some(result) = crate::exec_jobs::dispatch_exec_job_tool(name, args, base_path, config) {
+        return Some(result);
+    }
+
+    if name == "http_request" {
+        return Some(run_http_request_tool(args, config).await);
+    }
+
+    if name == crate::web_search::WEB_SEARCH_TOOL_NAME {
+        return Some(crate::web_search::run_web_search_tool(args, config).await);
+    }
+
+    if name == crate::memory_search::SEARCH_MEMORY_TOOL_NAME {
+        return Some(crate::memory_search::run_search_memory_tool(args, base_path, config).await);
+    }
+
+    if name == crate::fetch_url::FETCH_URL_TOOL_NAME {
+        return Some(crate::fetch_url::run_fetch_url_tool(args, config).await);
+    }
+
     None
 }
 
+/// Tools that only look things up; `runtime.read_only_budget` caps how many a plan may call.
+const READ_ONLY_TOOLS: &[&str] = &[
+    "ls",
+    "find",
+    "grep",
+    "read",
+    crate::file_info::STAT_TOOL_NAME,
+    crate::file_info::HASH_TOOL_NAME,
+    crate::diff::DIFF_TOOL_NAME,
+    crate::datetime::DATETIME_TOOL_NAME,
+    crate::memory_search::SEARCH_MEMORY_TOOL_NAME,
+    crate::scratch::SCRATCH_GET_TOOL_NAME,
+    crate::trace_log::EXPLAIN_TOOL_NAME,
+    crate::exec_jobs::EXEC_STATUS_TOOL_NAME,
+    crate::exec_jobs::EXEC_LOGS_TOOL_NAME,
+];
+
+pub(crate) fn is_read_only_tool(name: &str) -> bool {
+    READ_ONLY_TOOLS.contains(&name)
+}
+
 pub(crate) async fn dispatch_tool(
     name: &str,
     args: &Value,
@@ -802,15 +1307,66 @@ pub(crate) async fn dispatch_tool(
     config: &Config,
     channel_id: &str,
 ) -> ToolExecutionResult {
-    let output = match dispatch_builtin_tool(name, args, base_path, config).await {
-        Some(result) => result,
-        None => dispatch_extension_tool(name, args, base_path, config, channel_id).await,
+    if let Some(reason) = tool_policy_violation(config, name, channel_id) {
+        return ToolExecutionResult::error(format!(
+            "Error: Tool policy: {}. Adjust `runtime.tools` in tellar.yml to change this.",
+            reason
+        ));
+    }
+    if let Some(output) = crate::prompt_test::mock_tool_call(name, args) {
+        return ToolExecutionResult::success(output);
+    }
+    if crate::dry_run::is_enabled() && !crate::dry_run::runs_for_real(name) {
+        crate::dry_run::record("tool", name, args.clone());
+        return ToolExecutionResult::success(crate::dry_run::simulated_tool_output(name, args));
+    }
+    if crate::approval::requires_approval(config, name)
+        && let Some(refusal) = crate::approval::request_approval(config, name, args, channel_id)
+            .await
+            .refusal(name)
+    {
+        return ToolExecutionResult::error(refusal);
+    }
+
+    let output = if name == crate::trace_log::EXPLAIN_TOOL_NAME {
+        crate::trace_log::run_explain_tool(args, base_path, channel_id)
+    } else if let Some(result) =
+        crate::scratch::dispatch_scratch_tool(name, args, base_path, channel_id)
+    {
+        result
+    } else if let Some(result) =
+        crate::memory::dispatch_memory_tool(name, args, base_path, channel_id)
+    {
+        result
+    } else if let Some(result) =
+        crate::entities::dispatch_entity_tool(name, args, base_path, channel_id)
+    {
+        result
+    } else if name == crate::reminders::REMIND_ME_TOOL_NAME {
+        crate::reminders::run_remind_me_tool(args, base_path, config, channel_id).await
+    } else {
+        match dispatch_builtin_tool(name, args, base_path, config).await {
+            Some(result) => result,
+            None => dispatch_extension_tool(name, args, base_path, config, channel_id).await,
+        }
     };
 
-    output.with_truncated_output(config.runtime.max_tool_output_bytes)
+    crate::artifacts::spill_large_output(
+        output,
+        name,
+        base_path,
+        config.runtime.max_tool_output_bytes,
+    )
 }
 
+const TRUNCATION_HINT: &str = "💡 **Hint**: Data is too large for the session history. Narrow the path, reduce the line window, or search for a more specific pattern before reading again.";
+
 fn truncate_output(output: String, limit: usize) -> String {
+    truncate_output_with_hint(output, limit, TRUNCATION_HINT)
+}
+
+/// Keep the head and tail of `output` within `limit` bytes and append `hint`.
+pub(crate) fn truncate_output_with_hint(output: String, limit: usize, hint: &str) -> String {
     if limit == 0 {
         return output;
     }
@@ -830,10 +1386,11 @@ fn truncate_output(output: String, limit: usize) -> String {
         let suffix = &output[suffix_start..];
 
         format!(
-            "{} ... [TRUNCATED {} bytes] ... {}\n\n💡 **Hint**: Data is too large for the session history. Narrow the path, reduce the line window, or search for a more specific pattern before reading again.",
+            "{} ... [TRUNCATED {} bytes] ... {}\n\n{}",
             prefix,
             output.len() - (prefix_end + (output.len() - suffix_start)),
-            suffix
+            suffix,
+            hint
         )
     } else {
         output
@@ -913,13 +1470,15 @@ mod tests {
             gemini: crate::config::GeminiConfig {
                 api_key: "fake".to_string(),
                 model: "fake-model".to_string(),
+                temperature: None,
             },
             discord: crate::config::DiscordConfig {
                 token: "fake".to_string(),
                 guild_id: None,
                 channel_mappings: None,
+                ..Default::default()
             },
-            runtime: crate::config::RuntimeConfig::default(),
+            ..Default::default()
         }
     }
 
@@ -957,6 +1516,88 @@ mod tests {
         assert_eq!(result.output, "host-ok");
     }
 
+    #[test]
+    fn test_is_domain_allowed_matches_subdomains_only() {
+        let allowed = vec!["example.com".to_string(), "*.weather.gov".to_string()];
+        assert!(is_domain_allowed("example.com", &allowed));
+        assert!(is_domain_allowed("api.example.com", &allowed));
+        assert!(is_domain_allowed("API.Weather.gov", &allowed));
+        assert!(!is_domain_allowed("badexample.com", &allowed));
+        assert!(!is_domain_allowed("example.com.evil.net", &allowed));
+        assert!(is_domain_allowed("anything.net", &["*".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_http_request_tool_enforces_allowlist_and_truncates_body() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 4096];
+            let read = socket.read(&mut request).await.unwrap();
+            let request = String::from_utf8_lossy(&request[..read]).to_lowercase();
+            assert!(request.starts_with("post /items"));
+            assert!(request.contains("x-trace: abc"));
+            socket
+                .write_all(b"HTTP/1.1 201 Created\r\nContent-Type: text/plain\r\nContent-Length: 26\r\nConnection: close\r\n\r\nabcdefghijklmnopqrstuvwxyz")
+                .await
+                .unwrap();
+        });
+
+        let dir = tempdir().unwrap();
+        let mut config = test_config();
+        let args = json!({
+            "url": format!("http://127.0.0.1:{}/items", port),
+            "method": "post",
+            "headers": { "x-trace": "abc" },
+            "json": { "name": "milk" }
+        });
+        let rejected = dispatch_tool("http_request", &args, dir.path(), &config, "0").await;
+        assert!(rejected.is_error);
+        assert!(rejected.output.contains("http.allowed_domains"));
+
+        config.http.allowed_domains = vec!["127.0.0.1".to_string()];
+        config.http.max_response_bytes = 10;
+        let result = dispatch_tool("http_request", &args, dir.path(), &config, "0").await;
+        server.await.unwrap();
+        assert!(!result.is_error, "{}", result.output);
+        assert!(result.output.starts_with("HTTP 201 Created"));
+        assert!(result.output.contains("abcdefghij\n... [body truncated at 10 of 26 bytes]"));
+    }
+
+    #[tokio::test]
+    async fn test_http_request_tool_does_not_follow_redirects() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 4096];
+            let _ = socket.read(&mut request).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 302 Found\r\nLocation: http://169.254.169.254/latest\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let dir = tempdir().unwrap();
+        let mut config = test_config();
+        config.http.allowed_domains = vec!["127.0.0.1".to_string()];
+        let args = json!({ "url": format!("http://127.0.0.1:{}/moved", port) });
+        let result = dispatch_tool("http_request", &args, dir.path(), &config, "0").await;
+        server.await.unwrap();
+        assert!(result.is_error);
+        assert!(result.output.starts_with("HTTP 302 Found"));
+        assert!(
+            result
+                .output
+                .contains("Location: http://169.254.169.254/latest (not followed;")
+        );
+    }
+
     #[tokio::test]
     async fn test_dispatch_tool_rejects_missing_write_content() {
         let dir = tempdir().unwrap();
@@ -1003,6 +1644,81 @@ mod tests {
         assert!(grep_result.output.contains("docs/alpha.txt:2: find me"));
     }
 
+    #[test]
+    fn test_grep_tool_supports_regex_globs_context_and_skips_binary() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("brain/traces")).unwrap();
+        fs::write(
+            dir.path().join("brain/log.md"),
+            "intro\nticket #42 opened\nowner: ana\nnotes\nmisc\nticket #7 closed\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("brain/traces/turn.md"), "ticket #99\n").unwrap();
+        fs::write(dir.path().join("brain/data.txt"), "ticket #1\n").unwrap();
+        fs::write(dir.path().join("brain/blob.md"), b"ticket #5\0\x01").unwrap();
+
+        let result = run_grep_tool(
+            &json!({
+                "pattern": r"ticket #\d+",
+                "regex": true,
+                "include": ["*.md"],
+                "exclude": ["brain/traces/**"],
+                "before": 1,
+                "after": 1
+            }),
+            dir.path(),
+        );
+        assert!(!result.is_error, "{}", result.output);
+        assert_eq!(
+            result.output,
+            "brain/log.md-1- intro\nbrain/log.md:2: ticket #42 opened\nbrain/log.md-3- owner: ana\n--\nbrain/log.md-5- misc\nbrain/log.md:6: ticket #7 closed"
+        );
+
+        let literal = run_grep_tool(&json!({ "pattern": "#\\d" }), dir.path());
+        assert!(literal.output.starts_with("No matches"));
+        let invalid = run_grep_tool(&json!({ "pattern": "(", "regex": true }), dir.path());
+        assert!(invalid.is_error);
+    }
+
+    #[tokio::test]
+    async fn test_tool_policy_applies_channel_overrides_before_guild_rules() {
+        let dir = tempdir().unwrap();
+        let mut config = test_config();
+        config.runtime.tools.deny = vec!["exec".to_string(), "send_*".to_string()];
+        config.runtime.tools.channels.insert(
+            "111".to_string(),
+            crate::config::ToolRules {
+                allow: Vec::new(),
+                deny: vec!["write".to_string(), "edit".to_string()],
+            },
+        );
+        config.runtime.tools.channels.insert(
+            "222".to_string(),
+            crate::config::ToolRules {
+                allow: vec!["read".to_string(), "send_message".to_string()],
+                deny: Vec::new(),
+            },
+        );
+
+        assert!(tool_policy_violation(&config, "write", "333").is_none());
+        assert!(tool_policy_violation(&config, "send_voice_note", "333").is_some());
+        assert!(tool_policy_violation(&config, "read", "111").is_none());
+        assert!(tool_policy_violation(&config, "send_message", "222").is_none());
+        assert!(tool_policy_violation(&config, "ls", "222").is_some());
+
+        let refused = dispatch_tool(
+            "write",
+            &json!({ "path": "notes.md", "content": "x" }),
+            dir.path(),
+            &config,
+            "111",
+        )
+        .await;
+        assert!(refused.is_error);
+        assert!(refused.output.contains("denied in this channel"));
+        assert!(!dir.path().join("notes.md").exists());
+    }
+
     #[test]
     fn test_read_only_tools_reject_missing_target_path() {
         let dir = tempdir().unwrap();
@@ -1033,6 +1749,43 @@ mod tests {
         assert!(result.output.contains("offset 3 is beyond file length 2"));
     }
 
+    #[test]
+    fn test_write_append_and_patch_tools_modify_files_in_place() {
+        let dir = tempdir().unwrap();
+        let write = |args: Value| dispatch_core_sync_tool("write", &args, dir.path()).unwrap();
+        assert!(!write(json!({ "path": "run.md", "content": "one\n", "append": true })).is_error);
+        let appended = write(json!({ "path": "run.md", "content": "two\n", "append": true }));
+        assert_eq!(appended.output, "Successfully appended to run.md");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("run.md")).unwrap(),
+            "one\ntwo\n"
+        );
+
+        let patched = dispatch_core_sync_tool(
+            "patch",
+            &json!({ "path": "run.md", "diff": "@@ -1,2 +1,2 @@\n one\n-two\n+2\n" }),
+            dir.path(),
+        )
+        .unwrap();
+        assert!(!patched.is_error, "{}", patched.output);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("run.md")).unwrap(),
+            "one\n2\n"
+        );
+
+        let rejected = dispatch_core_sync_tool(
+            "patch",
+            &json!({ "path": "run.md", "diff": "@@ -1 +1 @@\n-missing\n+x\n" }),
+            dir.path(),
+        )
+        .unwrap();
+        assert!(rejected.is_error);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("run.md")).unwrap(),
+            "one\n2\n"
+        );
+    }
+
     #[test]
     fn test_edit_tool_rejects_non_unique_match() {
         let dir = tempdir().unwrap();
@@ -1099,4 +1852,40 @@ mod tests {
         assert!(result.is_error);
         assert!(result.output.contains("ambiguous across multiple skills"));
     }
+
+    #[tokio::test]
+    async fn test_dispatch_tool_rejects_skill_args_that_break_schema() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path().join("skills").join("weather");
+        fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.json"),
+            json!({
+                "name": "weather",
+                "description": "Weather",
+                "tools": [{
+                    "name": "forecast",
+                    "description": "Forecast",
+                    "parameters": {
+                        "type": "object",
+                        "required": ["city"],
+                        "properties": { "city": { "type": "string" } }
+                    },
+                    "command": "printf {{city}}"
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let config = test_config();
+        let args = json!({ "city": 7 });
+        let result = dispatch_tool("forecast", &args, dir.path(), &config, "0").await;
+        assert!(result.is_error);
+        assert!(result.output.contains("expected string, got integer"));
+
+        let args = json!({ "city": "Oslo" });
+        let result = dispatch_tool("forecast", &args, dir.path(), &config, "0").await;
+        assert_eq!(result.output, "Oslo");
+    }
 }
@@ -0,0 +1,396 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/trace_log.rs
+ * Responsibility: Persist execution traces to brain/traces/ and serve them back through the `explain` tool.
+ */
+
+use crate::config::Config;
+use crate::execution_contract::{
+    ExecutionOutcome, ExecutionStepKind, PlanStep, RequestRoute, ToolCallSpec,
+};
+use crate::log_warn;
+use crate::tools::{ToolExecutionResult, mask_sensitive_data};
+use chrono::{Duration, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub const TRACE_DIR: &str = "brain/traces";
+pub const EXPLAIN_TOOL_NAME: &str = "explain";
+const MAX_TEXT_CHARS: usize = 1200;
+const MAX_ARGS_CHARS: usize = 400;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TracedToolCall {
+    pub(crate) tool: String,
+    pub(crate) args: String,
+    /// `None` when the plan stopped before this call ran.
+    pub(crate) succeeded: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TraceRecord {
+    pub(crate) timestamp: String,
+    pub(crate) channel_id: String,
+    pub(crate) label: String,
+    pub(crate) request: String,
+    pub(crate) intent: String,
+    pub(crate) confidence: String,
+    pub(crate) final_state: String,
+    pub(crate) steps: Vec<String>,
+    pub(crate) tool_calls: Vec<TracedToolCall>,
+    pub(crate) response: String,
+}
+
+fn clip(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+    let mut clipped: String = text.chars().take(limit).collect();
+    clipped.push_str(" …");
+    clipped
+}
+
+/// Tool calls the router planned, in execution order.
+pub(crate) fn planned_tool_calls(route: &RequestRoute) -> Vec<ToolCallSpec> {
+    match route {
+        RequestRoute::PlanAndExecute { plan } => plan
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                PlanStep::CallTool { call } => Some(call.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub(crate) fn build_trace_record(
+    config: &Config,
+    channel_id: &str,
+    label: &str,
+    request: &str,
+    planned_calls: &[ToolCallSpec],
+    outcome: &ExecutionOutcome,
+) -> TraceRecord {
+    let mut results = outcome
+        .trace
+        .steps
+        .iter()
+        .filter_map(|step| match &step.step {
+            ExecutionStepKind::CalledTool { succeeded, .. } => Some(*succeeded),
+            _ => None,
+        });
+    let tool_calls = planned_calls
+        .iter()
+        .map(|call| TracedToolCall {
+            tool: call.tool_name.clone(),
+            args: clip(
+                &mask_sensitive_data(&call.args.to_string(), config),
+                MAX_ARGS_CHARS,
+            ),
+            succeeded: results.next(),
+        })
+        .collect();
+
+    let view = outcome.trace.view();
+    TraceRecord {
+        timestamp: Local::now().to_rfc3339(),
+        channel_id: channel_id.to_string(),
+        label: label.to_string(),
+        request: clip(&mask_sensitive_data(request, config), MAX_TEXT_CHARS),
+        intent: view.intent.label().to_string(),
+        confidence: view.confidence.label().to_string(),
+        final_state: outcome.final_state.label().to_string(),
+        steps: view
+            .steps
+            .iter()
+            .map(|step| {
+                format!(
+                    "#{} {}:{}({})",
+                    step.index,
+                    step.label,
+                    step.outcome.as_str(),
+                    step.detail
+                )
+            })
+            .collect(),
+        tool_calls,
+        response: clip(
+            &mask_sensitive_data(&outcome.user_response, config),
+            MAX_TEXT_CHARS,
+        ),
+    }
+}
+
+fn trace_file(base_path: &Path, date: chrono::NaiveDate) -> PathBuf {
+    base_path
+        .join(TRACE_DIR)
+        .join(format!("{}.jsonl", date.format("%Y-%m-%d")))
+}
+
+pub(crate) fn append_trace_record(base_path: &Path, record: &TraceRecord) -> anyhow::Result<()> {
+    let path = trace_file(base_path, Local::now().date_naive());
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// Journal one executed plan; failures are logged and never interrupt the task.
+pub(crate) fn record_execution(
+    base_path: &Path,
+    config: &Config,
+    channel_id: &str,
+    label: &str,
+    request: &str,
+    planned_calls: &[ToolCallSpec],
+    outcome: &ExecutionOutcome,
+) {
+    let record = build_trace_record(config, channel_id, label, request, planned_calls, outcome);
+    if let Err(error) = append_trace_record(base_path, &record) {
+        log_warn!(
+            "steward",
+            "⚠️ Failed to journal execution trace: {:?}",
+            error
+        );
+    }
+}
+
+fn matches_query(record: &TraceRecord, query: &str) -> bool {
+    let query = query.to_lowercase();
+    [&record.request, &record.response]
+        .into_iter()
+        .any(|text| text.to_lowercase().contains(&query))
+        || record.tool_calls.iter().any(|call| {
+            call.tool.to_lowercase().contains(&query) || call.args.to_lowercase().contains(&query)
+        })
+}
+
+/// Every record journaled on `date`, oldest first.
+pub(crate) fn load_day_traces(base_path: &Path, date: chrono::NaiveDate) -> Vec<TraceRecord> {
+    fs::read_to_string(trace_file(base_path, date))
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Most recent matching records first, skipping earlier `explain` lookups.
+pub(crate) fn load_recent_traces(
+    base_path: &Path,
+    channel_id: Option<&str>,
+    query: Option<&str>,
+    days: i64,
+    limit: usize,
+) -> Vec<TraceRecord> {
+    let today = Local::now().date_naive();
+    let mut records = Vec::new();
+    for offset in 0..days.max(1) {
+        let Ok(content) = fs::read_to_string(trace_file(base_path, today - Duration::days(offset)))
+        else {
+            continue;
+        };
+        let mut day: Vec<TraceRecord> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .filter(|record: &TraceRecord| {
+                channel_id.is_none_or(|id| record.channel_id == id)
+                    && !record
+                        .tool_calls
+                        .iter()
+                        .any(|call| call.tool == EXPLAIN_TOOL_NAME)
+                    && query.is_none_or(|query| matches_query(record, query))
+            })
+            .collect();
+        day.reverse();
+        records.extend(day);
+        if records.len() >= limit {
+            break;
+        }
+    }
+    records.truncate(limit);
+    records
+}
+
+pub(crate) fn render_trace_records(records: &[TraceRecord]) -> String {
+    if records.is_empty() {
+        return "No matching execution traces were recorded. Say so plainly instead of guessing why an action happened.".to_string();
+    }
+
+    let mut rendered = String::from(
+        "Recorded execution traces (most recent first). Explain past actions only from these records and cite their timestamps.\n",
+    );
+    for record in records {
+        rendered.push_str(&format!(
+            "\n### {} — {} (channel {})\n- Request: {}\n- Plan: intent={} confidence={} final_state={}\n",
+            record.timestamp,
+            record.label,
+            record.channel_id,
+            record.request.replace('\n', " "),
+            record.intent,
+            record.confidence,
+            record.final_state
+        ));
+        for call in &record.tool_calls {
+            let status = match call.succeeded {
+                Some(true) => "succeeded",
+                Some(false) => "failed",
+                None => "not run",
+            };
+            rendered.push_str(&format!(
+                "- Tool `{}` {} with args {}\n",
+                call.tool, status, call.args
+            ));
+        }
+        rendered.push_str(&format!("- Steps: {}\n", record.steps.join(" -> ")));
+        rendered.push_str(&format!(
+            "- Response: {}\n",
+            record.response.replace('\n', " ")
+        ));
+    }
+    rendered
+}
+
+pub(crate) fn explain_tool_definition() -> Value {
+    serde_json::json!({
+        "name": EXPLAIN_TOOL_NAME,
+        "description": "Retrieve recorded execution traces (requests, tool calls with arguments, outcomes, timestamps) so you can explain why you did something. Use this when asked about your past actions; never reconstruct them from memory.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "query": { "type": "string", "description": "Optional text to match in the request, response, tool names, or tool arguments" },
+                "limit": { "type": "number", "description": "Maximum number of traces to return. Defaults to 5" },
+                "days": { "type": "number", "description": "How many days back to search. Defaults to 3" },
+                "allChannels": { "type": "boolean", "description": "Search every channel instead of only the current one" }
+            }
+        }
+    })
+}
+
+pub(crate) fn run_explain_tool(
+    args: &Value,
+    base_path: &Path,
+    channel_id: &str,
+) -> ToolExecutionResult {
+    let query = args
+        .get("query")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|query| !query.is_empty());
+    let limit = args
+        .get("limit")
+        .and_then(Value::as_u64)
+        .unwrap_or(5)
+        .clamp(1, 20) as usize;
+    let days = args
+        .get("days")
+        .and_then(Value::as_i64)
+        .unwrap_or(3)
+        .clamp(1, 30);
+    let all_channels = args
+        .get("allChannels")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    let scope = (!all_channels).then_some(channel_id);
+    let records = load_recent_traces(base_path, scope, query, days, limit);
+    ToolExecutionResult::success(render_trace_records(&records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution_contract::{
+        ExecutionFinalState, ExecutionStepTrace, ExecutionTrace, PlanConfidence, PlanIntent,
+    };
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    fn outcome(succeeded: bool) -> ExecutionOutcome {
+        ExecutionOutcome {
+            final_state: if succeeded {
+                ExecutionFinalState::Completed
+            } else {
+                ExecutionFinalState::Failed
+            },
+            user_response: "Deleted the stale draft.".to_string(),
+            trace: ExecutionTrace {
+                intent: PlanIntent::ToolExecution,
+                confidence: PlanConfidence::High,
+                steps: vec![ExecutionStepTrace {
+                    index: 1,
+                    step: ExecutionStepKind::CalledTool {
+                        tool_name: "write".to_string(),
+                        succeeded,
+                    },
+                }],
+            },
+        }
+    }
+
+    fn calls() -> Vec<ToolCallSpec> {
+        vec![
+            ToolCallSpec {
+                tool_name: "write".to_string(),
+                args: json!({ "path": "rituals/draft.md", "content": "" }),
+            },
+            ToolCallSpec {
+                tool_name: "send_message".to_string(),
+                args: json!({ "content": "done" }),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_build_trace_record_pairs_planned_calls_with_results() {
+        let record = build_trace_record(
+            &Config::default(),
+            "123",
+            "Conversational",
+            "why is the draft empty?",
+            &calls(),
+            &outcome(false),
+        );
+
+        assert_eq!(record.final_state, "Failed");
+        assert_eq!(record.tool_calls[0].succeeded, Some(false));
+        assert!(record.tool_calls[0].args.contains("rituals/draft.md"));
+        assert_eq!(record.tool_calls[1].succeeded, None);
+        assert_eq!(record.steps, vec!["#1 CallTool:Error(write)"]);
+    }
+
+    #[test]
+    fn test_explain_tool_filters_by_channel_and_query() {
+        let dir = tempdir().unwrap();
+        let config = Config::default();
+        for (channel, request) in [("123", "clean the draft"), ("456", "other channel")] {
+            record_execution(
+                dir.path(),
+                &config,
+                channel,
+                "Conversational",
+                request,
+                &calls(),
+                &outcome(true),
+            );
+        }
+
+        let result = run_explain_tool(&json!({ "query": "draft" }), dir.path(), "123");
+        assert!(!result.is_error);
+        assert!(result.output.contains("clean the draft"));
+        assert!(result.output.contains("Tool `write` succeeded"));
+        assert!(!result.output.contains("other channel"));
+
+        let result = run_explain_tool(&json!({ "query": "nothing-like-this" }), dir.path(), "123");
+        assert!(result.output.contains("No matching execution traces"));
+    }
+}
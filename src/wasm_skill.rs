@@ -0,0 +1,142 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/wasm_skill.rs
+ * Responsibility: Run WASI skill modules with the wasmtime CLI, granting only the capabilities the skill declares.
+ */
+
+use crate::child_env::ChildEnv;
+use crate::skills::WasiCapabilities;
+use anyhow::{Result, anyhow};
+use std::path::{Component, Path};
+use tokio::process::Command;
+
+pub(crate) const WASMTIME_PROGRAM: &str = "wasmtime";
+/// Guest path of the skill folder, which every module can read and write.
+const GUEST_SKILL_DIR: &str = "/skill";
+/// Guest path under which granted guild folders appear.
+const GUEST_GUILD_DIR: &str = "/guild";
+
+/// True when `path` is relative and cannot climb out of the folder it is joined to.
+pub fn is_contained_path(path: &str) -> bool {
+    let path = Path::new(path);
+    !path.as_os_str().is_empty()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+fn wasm_argv(
+    module: &Path,
+    skill_dir: &Path,
+    workspace_dir: &Path,
+    capabilities: &WasiCapabilities,
+    forwarded: &[String],
+) -> Vec<String> {
+    let mut argv = vec![WASMTIME_PROGRAM.to_string(), "run".to_string()];
+    let mut preopen = |host: &Path, guest: &str| {
+        argv.push("--dir".to_string());
+        argv.push(format!("{}::{}", host.display(), guest));
+    };
+    preopen(skill_dir, GUEST_SKILL_DIR);
+    for dir in &capabilities.dirs {
+        let dir = dir.trim_matches('/');
+        preopen(
+            &workspace_dir.join(dir),
+            &format!("{}/{}", GUEST_GUILD_DIR, dir),
+        );
+    }
+    if capabilities.network {
+        for flag in ["inherit-network=y", "allow-ip-name-lookup=y"] {
+            argv.extend(["-S".to_string(), flag.to_string()]);
+        }
+    }
+    for literal in [
+        format!("SKILL_DIR={}", GUEST_SKILL_DIR),
+        format!("TELLAR_WORKSPACE={}", GUEST_GUILD_DIR),
+    ] {
+        argv.extend(["--env".to_string(), literal]);
+    }
+    // A bare name makes wasmtime copy the value from its own environment, keeping
+    // secrets off the command line.
+    for name in forwarded {
+        argv.extend(["--env".to_string(), name.clone()]);
+    }
+    argv.push(module.to_string_lossy().to_string());
+    argv
+}
+
+/// The wasmtime process for one call of a WASI skill tool, not yet spawned. The guest sees
+/// `TELLAR_ARGS`, the variables granted in `env`, the skill folder, and `capabilities.dirs`.
+pub(crate) fn wasm_command(
+    module: &str,
+    skill_dir: &Path,
+    workspace_dir: &Path,
+    capabilities: &WasiCapabilities,
+    env: &ChildEnv,
+) -> Result<Command> {
+    if !is_contained_path(module) {
+        return Err(anyhow!(
+            "WASM module `{}` must be a path inside the skill folder",
+            module
+        ));
+    }
+    let module_path = skill_dir.join(module);
+    if !module_path.is_file() {
+        return Err(anyhow!("WASM module `{}` does not exist", module));
+    }
+
+    let mut forwarded = vec!["TELLAR_ARGS".to_string()];
+    forwarded.extend(env.granted.iter().cloned());
+    let argv = wasm_argv(
+        &module_path,
+        skill_dir,
+        workspace_dir,
+        capabilities,
+        &forwarded,
+    );
+    let mut command = Command::new(&argv[0]);
+    env.apply(&mut command);
+    command.args(&argv[1..]);
+    Ok(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wasm_argv_grants_only_declared_capabilities() {
+        let skill_dir = Path::new("/srv/guild/skills/ocr");
+        let guild = Path::new("/srv/guild");
+        let sealed = wasm_argv(
+            &skill_dir.join("ocr.wasm"),
+            skill_dir,
+            guild,
+            &WasiCapabilities::default(),
+            &["TELLAR_ARGS".to_string()],
+        )
+        .join(" ");
+        assert_eq!(
+            sealed,
+            "wasmtime run --dir /srv/guild/skills/ocr::/skill --env SKILL_DIR=/skill --env TELLAR_WORKSPACE=/guild --env TELLAR_ARGS /srv/guild/skills/ocr/ocr.wasm"
+        );
+
+        let open = wasm_argv(
+            &skill_dir.join("ocr.wasm"),
+            skill_dir,
+            guild,
+            &WasiCapabilities {
+                dirs: vec!["brain/attachments/".to_string()],
+                network: true,
+            },
+            &[],
+        )
+        .join(" ");
+        assert!(open.contains("--dir /srv/guild/brain/attachments::/guild/brain/attachments"));
+        assert!(open.contains("-S inherit-network=y"));
+
+        assert!(is_contained_path("bin/tool.wasm"));
+        assert!(!is_contained_path("../tool.wasm"));
+        assert!(!is_contained_path("/usr/lib/tool.wasm"));
+    }
+}
@@ -5,15 +5,18 @@
  */
 
 use crate::StewardNotification;
-use crate::config::Config;
+use crate::config::{Config, WatchMode};
 use crate::thread;
+use crate::{log_info, log_warn};
+use globset::{GlobBuilder, GlobMatcher};
 use notify::{
-    EventKind, RecursiveMode, Watcher,
-    event::{CreateKind, ModifyKind},
+    EventKind, PollWatcher, RecursiveMode, Watcher,
+    event::{CreateKind, MetadataKind, ModifyKind},
 };
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tokio::sync::mpsc;
 
@@ -21,6 +24,7 @@ use tokio::sync::mpsc;
 enum WatchAction {
     SyncBrainEvents,
     ExecuteRitual,
+    ReloadSkills,
     Ignore,
 }
 
@@ -29,13 +33,144 @@ fn is_relevant_fs_event(kind: &EventKind) -> bool {
         kind,
         EventKind::Modify(ModifyKind::Data(_))
             | EventKind::Modify(ModifyKind::Any)
+            // The polling backend reports writes as a newer modification time.
+            | EventKind::Modify(ModifyKind::Metadata(MetadataKind::WriteTime))
             | EventKind::Create(CreateKind::Any)
             | EventKind::Create(CreateKind::File)
     )
 }
 
-fn classify_watch_path(path: &Path, brain_dir: &Path, rituals_dir: &Path) -> WatchAction {
-    if path.starts_with(brain_dir) && path.extension().and_then(|s| s.to_str()) == Some("json") {
+/// Skill folders can appear, vanish, or be renamed into place, so every kind of change counts.
+fn is_skill_fs_event(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    )
+}
+
+/// A skill folder directly under skills/ or its SKILL.json / SKILL.md.
+/// Hidden folders are skipped: `tellarctl skill add` stages clones in them.
+fn is_skill_path(path: &Path, skills_dir: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(skills_dir) else {
+        return false;
+    };
+    let mut components = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy());
+    match (components.next(), components.next(), components.next()) {
+        (Some(folder), None, None) => !folder.starts_with('.'),
+        (Some(folder), Some(file), None) => {
+            !folder.starts_with('.') && (file == "SKILL.json" || file == "SKILL.md")
+        }
+        _ => false,
+    }
+}
+
+/// One `watch.ignore` pattern, read the way `.gitignore` reads it.
+#[derive(Debug)]
+struct IgnoreRule {
+    matcher: GlobMatcher,
+    /// `!pattern` un-ignores what an earlier pattern ignored.
+    negated: bool,
+    /// `pattern/` only matches folders.
+    dir_only: bool,
+    /// A pattern with a `/` before its end matches from the guild root; others match any
+    /// single path component.
+    anchored: bool,
+}
+
+/// Gitignore-style patterns whose changes the Watchman skips. The last matching pattern
+/// wins, and a matched folder covers everything inside it.
+#[derive(Debug, Default)]
+struct IgnoreRules {
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreRules {
+    /// Parse `patterns`, skipping blank lines, `#` comments, and invalid globs with a warning.
+    fn new(patterns: &[String]) -> Self {
+        let rules = patterns
+            .iter()
+            .filter_map(|pattern| {
+                let pattern = pattern.trim();
+                if pattern.is_empty() || (pattern.starts_with('#') && !pattern.ends_with('#')) {
+                    return None;
+                }
+                let (negated, pattern) = match pattern.strip_prefix('!') {
+                    Some(rest) => (true, rest),
+                    None => (false, pattern),
+                };
+                let (dir_only, pattern) = match pattern.strip_suffix('/') {
+                    Some(rest) => (true, rest),
+                    None => (false, pattern),
+                };
+                let anchored = pattern.contains('/');
+                let pattern = pattern.trim_start_matches('/');
+                match GlobBuilder::new(pattern).literal_separator(true).build() {
+                    Ok(glob) => Some(IgnoreRule {
+                        matcher: glob.compile_matcher(),
+                        negated,
+                        dir_only,
+                        anchored,
+                    }),
+                    Err(e) => {
+                        log_warn!(
+                            "rhythm",
+                            "⚠️ Ignoring invalid watch.ignore pattern `{}`: {}",
+                            pattern,
+                            e
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Whether a guild-relative path is ignored. `is_dir` says whether its last component
+    /// is a folder; every earlier one is.
+    fn is_ignored(&self, relative: &Path, is_dir: bool) -> bool {
+        let components: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let last = components.len().saturating_sub(1);
+        let mut ignored = false;
+        for rule in &self.rules {
+            let applies = |index: usize| index < last || is_dir || !rule.dir_only;
+            let matched = if rule.anchored {
+                (0..components.len()).any(|index| {
+                    applies(index) && rule.matcher.is_match(components[..=index].join("/"))
+                })
+            } else {
+                components
+                    .iter()
+                    .enumerate()
+                    .any(|(index, component)| applies(index) && rule.matcher.is_match(component))
+            };
+            if matched {
+                ignored = !rule.negated;
+            }
+        }
+        ignored
+    }
+}
+
+fn classify_watch_path(
+    path: &Path,
+    brain_dir: &Path,
+    rituals_dir: &Path,
+    skills_dir: &Path,
+) -> WatchAction {
+    if is_skill_path(path, skills_dir) {
+        WatchAction::ReloadSkills
+    } else if path.starts_with(brain_dir)
+        && !path.starts_with(brain_dir.join("scratch"))
+        && !path.starts_with(brain_dir.join("jobs"))
+        && !path.starts_with(brain_dir.join("sessions"))
+        && path.extension().and_then(|s| s.to_str()) == Some("json")
+    {
         WatchAction::SyncBrainEvents
     } else if path.starts_with(rituals_dir)
         && path.extension().and_then(|s| s.to_str()) == Some("md")
@@ -46,6 +181,72 @@ fn classify_watch_path(path: &Path, brain_dir: &Path, rituals_dir: &Path) -> Wat
     }
 }
 
+type FsEventSender = mpsc::UnboundedSender<notify::Event>;
+
+/// Forward events to the Watchman loop, and backend errors to `errors` when given so the
+/// loop can fall back to polling.
+fn event_handler(
+    events: FsEventSender,
+    errors: Option<mpsc::UnboundedSender<notify::Error>>,
+) -> impl FnMut(notify::Result<notify::Event>) + Send + 'static {
+    move |res| match res {
+        Ok(event) => {
+            if events.send(event).is_err() {
+                log_warn!(
+                    "rhythm",
+                    "⚠️ Watchman dropped a filesystem event because the receiver is closed."
+                );
+            }
+        }
+        Err(error) => {
+            log_warn!(
+                "rhythm",
+                "⚠️ Watchman filesystem watcher error: {:?}",
+                error
+            );
+            if let Some(errors) = &errors {
+                let _ = errors.send(error);
+            }
+        }
+    }
+}
+
+fn watch_dirs(
+    mut watcher: Box<dyn Watcher + Send>,
+    dirs: &[PathBuf],
+) -> notify::Result<Box<dyn Watcher + Send>> {
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::Recursive)?;
+    }
+    Ok(watcher)
+}
+
+fn native_watcher(
+    dirs: &[PathBuf],
+    events: FsEventSender,
+    errors: mpsc::UnboundedSender<notify::Error>,
+) -> notify::Result<Box<dyn Watcher + Send>> {
+    let watcher = notify::recommended_watcher(event_handler(events, Some(errors)))?;
+    watch_dirs(Box::new(watcher), dirs)
+}
+
+fn polling_watcher(
+    dirs: &[PathBuf],
+    events: FsEventSender,
+    interval: Duration,
+) -> notify::Result<Box<dyn Watcher + Send>> {
+    log_info!(
+        "rhythm",
+        "👁️ The Watchman is polling for changes every {:?}.",
+        interval
+    );
+    let watcher = PollWatcher::new(
+        event_handler(events, None),
+        notify::Config::default().with_poll_interval(interval),
+    )?;
+    watch_dirs(Box::new(watcher), dirs)
+}
+
 pub async fn start_watchman(
     base_path: &Path,
     config: Arc<Config>,
@@ -55,34 +256,51 @@ pub async fn start_watchman(
     let brain_dir = base_path.join("brain");
     let channels_dir = base_path.join("channels");
     let rituals_dir = base_path.join("rituals");
+    let skills_dir = base_path.join("skills");
 
-    for dir in &[&brain_dir, &channels_dir, &rituals_dir] {
+    for dir in &[&brain_dir, &channels_dir, &rituals_dir, &skills_dir] {
         if !dir.exists() {
             std::fs::create_dir_all(dir)?;
         }
     }
 
-    println!("👁️ The Watchman is observing brain/, channels/, and rituals/...");
+    log_info!(
+        "rhythm",
+        "👁️ The Watchman is observing brain/, channels/, rituals/, and skills/..."
+    );
+    // Prime the skill registry so the first reload can report what changed.
+    crate::skills::SkillMetadata::discover_skills(base_path);
 
-    let (fs_tx, mut fs_rx) = tokio::sync::mpsc::unbounded_channel();
-    let mut watcher =
-        notify::recommended_watcher(move |res: notify::Result<notify::Event>| match res {
-            Ok(event) => {
-                if fs_tx.send(event).is_err() {
-                    eprintln!(
-                        "⚠️ Watchman dropped a filesystem event because the receiver is closed."
-                    );
-                }
-            }
+    let dirs = [
+        brain_dir.clone(),
+        channels_dir.clone(),
+        rituals_dir.clone(),
+        skills_dir.clone(),
+    ];
+    let poll_interval = Duration::from_secs(config.watch.poll_interval_secs.max(1));
+    let (fs_tx, mut fs_rx) = mpsc::unbounded_channel();
+    let (error_tx, mut error_rx) = mpsc::unbounded_channel();
+    let mut polling = config.watch.mode == WatchMode::Poll;
+    // Held only to keep the backend alive; replaced when falling back to polling.
+    let mut _watcher = if polling {
+        drop(error_tx);
+        polling_watcher(&dirs, fs_tx.clone(), poll_interval)?
+    } else {
+        match native_watcher(&dirs, fs_tx.clone(), error_tx) {
+            Ok(watcher) => watcher,
             Err(error) => {
-                eprintln!("⚠️ Watchman filesystem watcher error: {:?}", error);
+                log_warn!(
+                    "rhythm",
+                    "⚠️ Native filesystem watcher unavailable ({:?}); falling back to polling.",
+                    error
+                );
+                polling = true;
+                polling_watcher(&dirs, fs_tx.clone(), poll_interval)?
             }
-        })?;
-
-    watcher.watch(&brain_dir, RecursiveMode::Recursive)?;
-    watcher.watch(&channels_dir, RecursiveMode::Recursive)?;
-    watcher.watch(&rituals_dir, RecursiveMode::Recursive)?;
+        }
+    };
 
+    let ignore = IgnoreRules::new(&config.watch.ignore);
     let base_path_clone = base_path.to_path_buf();
     let config_clone = Arc::clone(&config);
 
@@ -90,7 +308,7 @@ pub async fn start_watchman(
         tokio::select! {
             // Priority 1: Conversational Notifications (MPSC Trigger)
             Some(notif) = notif_rx.recv() => {
-                println!("📢 Watchman received signal: awakens Steward...");
+                log_info!("rhythm", "📢 Watchman received signal: awakens Steward...");
                 // Trigger immediate execution with full context
                 if let Err(error) = thread::execute_thread_file(
                     &notif.blackboard_path,
@@ -100,35 +318,101 @@ pub async fn start_watchman(
                     Some(notif.channel_id),
                     Some(notif.guild_id)
                 ).await {
-                    eprintln!("⚠️ Watchman failed to execute conversational trigger: {:?}", error);
+                    log_warn!(
+                        "rhythm",
+                        "⚠️ Watchman failed to execute conversational trigger: {:?}",
+                        error
+                    );
                 }
 
 
             },
 
+            // A failing native backend may be missing events: switch to polling for good.
+            Some(error) = error_rx.recv(), if !polling => {
+                log_warn!(
+                    "rhythm",
+                    "⚠️ Native filesystem watcher reported {:?}; falling back to polling.",
+                    error
+                );
+                match polling_watcher(&dirs, fs_tx.clone(), poll_interval) {
+                    Ok(watcher) => {
+                        _watcher = watcher;
+                        polling = true;
+                    }
+                    Err(error) => log_warn!(
+                        "rhythm",
+                        "⚠️ Watchman could not start polling: {:?}",
+                        error
+                    ),
+                }
+            },
+
             // Priority 2: Filesystem Events (Watch Trigger - System/Non-Conversational)
             Some(event) = fs_rx.recv() => {
-                if is_relevant_fs_event(&event.kind) {
-                    for path in event.paths {
-                        let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
-
-                        match classify_watch_path(&path, &brain_dir, &rituals_dir) {
-                            WatchAction::SyncBrainEvents => {
-                                if let Err(error) = crate::discord::sync_all_discord_events(&base_path_clone, Some(mappings.clone())).await {
-                                    eprintln!("⚠️ Watchman failed to sync brain events: {:?}", error);
-                                }
-                            }
-                            WatchAction::ExecuteRitual => {
-                                println!("⚙️ Watchman detected ritual edit: {:?}, awakening Steward...", file_name);
-                                if let Err(error) = thread::execute_thread_file(&path, &base_path_clone, config_clone.clone(), None, None, None).await {
-                                    eprintln!("⚠️ Watchman failed to execute ritual trigger for {:?}: {:?}", file_name, error);
-                                }
+                let relevant = is_relevant_fs_event(&event.kind);
+                let mut skills_changed = false;
+                for path in event.paths {
+                    let relative = path.strip_prefix(&base_path_clone).unwrap_or(&path);
+                    if ignore.is_ignored(relative, path.is_dir()) {
+                        continue;
+                    }
+                    // Results the steward just wrote back would otherwise re-trigger it.
+                    if crate::self_writes::is_own_write(&path) {
+                        continue;
+                    }
+                    let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+
+                    match classify_watch_path(&path, &brain_dir, &rituals_dir, &skills_dir) {
+                        WatchAction::ReloadSkills => {
+                            skills_changed |= is_skill_fs_event(&event.kind);
+                        }
+                        _ if !relevant => {}
+                        WatchAction::SyncBrainEvents => {
+                            if let Err(error) = crate::discord::sync_all_discord_events(&base_path_clone, Some(mappings.clone())).await {
+                                log_warn!(
+                                    "rhythm",
+                                    "⚠️ Watchman failed to sync brain events: {:?}",
+                                    error
+                                );
                             }
-                            WatchAction::Ignore => {
-                                // Channels are intentionally passive to filesystem events.
-                                // They only react to Discord message signals (MPSC).
+                        }
+                        WatchAction::ExecuteRitual => {
+                            log_info!(
+                                "rhythm",
+                                "⚙️ Watchman detected ritual edit: {:?}, awakening Steward...",
+                                file_name
+                            );
+                            if let Err(error) = thread::execute_thread_file(&path, &base_path_clone, config_clone.clone(), None, None, None).await {
+                                log_warn!(
+                                    "rhythm",
+                                    "⚠️ Watchman failed to execute ritual trigger for {:?}: {:?}",
+                                    file_name,
+                                    error
+                                );
                             }
                         }
+                        WatchAction::Ignore => {
+                            // Channels are intentionally passive to filesystem events.
+                            // They only react to Discord message signals (MPSC).
+                        }
+                    }
+                }
+
+                if skills_changed {
+                    let reload = crate::skills::reload_skills(&base_path_clone);
+                    if !reload.added.is_empty() {
+                        log_info!("rhythm", "🧩 Skill tools added: {}", reload.added.join(", "));
+                    }
+                    if !reload.removed.is_empty() {
+                        log_info!("rhythm", "🧩 Skill tools removed: {}", reload.removed.join(", "));
+                    }
+                    if let Err(error) = crate::rhythm::sync_skill_hooks(&base_path_clone, config_clone.clone()).await {
+                        log_warn!(
+                            "rhythm",
+                            "⚠️ Watchman failed to reschedule skill hooks: {:?}",
+                            error
+                        );
                     }
                 }
             },
@@ -152,29 +436,116 @@ mod tests {
         assert!(is_relevant_fs_event(&EventKind::Modify(ModifyKind::Data(
             DataChange::Any
         ))));
+        assert!(is_relevant_fs_event(&EventKind::Modify(
+            ModifyKind::Metadata(MetadataKind::WriteTime)
+        )));
+        assert!(!is_relevant_fs_event(&EventKind::Modify(
+            ModifyKind::Metadata(MetadataKind::Permissions)
+        )));
         assert!(!is_relevant_fs_event(&EventKind::Access(
             notify::event::AccessKind::Any
         )));
     }
 
+    #[tokio::test]
+    async fn test_polling_watcher_reports_new_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let rituals = dir.path().join("rituals");
+        std::fs::create_dir_all(&rituals).unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let _watcher = polling_watcher(
+            std::slice::from_ref(&rituals),
+            tx,
+            Duration::from_millis(50),
+        )
+        .unwrap();
+        std::fs::write(rituals.join("daily.md"), "- [ ] step\n").unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(is_relevant_fs_event(&event.kind));
+        assert!(event.paths[0].ends_with("daily.md"));
+    }
+
+    #[test]
+    fn test_ignore_rules_follow_gitignore_conventions() {
+        let mut patterns = crate::config::WatchConfig::default().ignore;
+        patterns.extend(["# comment", "/rituals/drafts/", "!keep.md", "[bad"].map(String::from));
+        let ignore = IgnoreRules::new(&patterns);
+        for (path, is_dir, expected) in [
+            ("rituals/.daily.md.swp", false, true),
+            ("rituals/daily.md~", false, true),
+            ("rituals/#daily.md#", false, true),
+            ("rituals/history/2026-03-01/daily.md", false, true),
+            ("channels/general-123456/history", true, true),
+            ("rituals/history", false, false),
+            ("brain/.trash/events/evt.json", false, true),
+            ("rituals/drafts/idea.md", false, true),
+            ("channels/drafts/idea.md", false, false),
+            ("rituals/history/keep.md", false, false),
+            ("rituals/daily.md", false, false),
+            ("skills/weather/SKILL.md", false, false),
+        ] {
+            assert_eq!(
+                ignore.is_ignored(Path::new(path), is_dir),
+                expected,
+                "{}",
+                path
+            );
+        }
+        assert!(!IgnoreRules::default().is_ignored(Path::new("a/history/b.md"), false));
+    }
+
     #[test]
     fn test_classify_watch_path_routes_expected_targets() {
         let brain_dir = Path::new("/tmp/guild/brain");
         let rituals_dir = Path::new("/tmp/guild/rituals");
+        let skills_dir = Path::new("/tmp/guild/skills");
 
         assert_eq!(
             classify_watch_path(
                 Path::new("/tmp/guild/brain/events/evt.json"),
                 brain_dir,
-                rituals_dir
+                rituals_dir,
+                skills_dir
             ),
             WatchAction::SyncBrainEvents
         );
+        assert_eq!(
+            classify_watch_path(
+                Path::new("/tmp/guild/brain/scratch/1234.json"),
+                brain_dir,
+                rituals_dir,
+                skills_dir
+            ),
+            WatchAction::Ignore
+        );
+        assert_eq!(
+            classify_watch_path(
+                Path::new("/tmp/guild/brain/jobs/20260101-000000-0.json"),
+                brain_dir,
+                rituals_dir,
+                skills_dir
+            ),
+            WatchAction::Ignore
+        );
+        assert_eq!(
+            classify_watch_path(
+                Path::new("/tmp/guild/brain/sessions/1a2b3c4d.json"),
+                brain_dir,
+                rituals_dir,
+                skills_dir
+            ),
+            WatchAction::Ignore
+        );
         assert_eq!(
             classify_watch_path(
                 Path::new("/tmp/guild/rituals/daily.md"),
                 brain_dir,
-                rituals_dir
+                rituals_dir,
+                skills_dir
             ),
             WatchAction::ExecuteRitual
         );
@@ -182,9 +553,30 @@ mod tests {
             classify_watch_path(
                 Path::new("/tmp/guild/channels/general/2026-02-27.md"),
                 brain_dir,
-                rituals_dir
+                rituals_dir,
+                skills_dir
             ),
             WatchAction::Ignore
         );
+        for (path, expected) in [
+            (
+                "/tmp/guild/skills/weather/SKILL.json",
+                WatchAction::ReloadSkills,
+            ),
+            ("/tmp/guild/skills/weather", WatchAction::ReloadSkills),
+            (
+                "/tmp/guild/skills/weather/scripts/run.sh",
+                WatchAction::Ignore,
+            ),
+            (
+                "/tmp/guild/skills/.weather.incoming/SKILL.md",
+                WatchAction::Ignore,
+            ),
+        ] {
+            assert_eq!(
+                classify_watch_path(Path::new(path), brain_dir, rituals_dir, skills_dir),
+                expected
+            );
+        }
     }
 }
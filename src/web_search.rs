@@ -0,0 +1,224 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/web_search.rs
+ * Responsibility: The `web_search` core tool backed by SearxNG, Brave, or Google Custom Search.
+ */
+
+use crate::config::{Config, SearchProvider, WebSearchConfig};
+use crate::tools::ToolExecutionResult;
+use once_cell::sync::Lazy;
+use serde_json::{Value, json};
+use std::time::Duration;
+
+pub const WEB_SEARCH_TOOL_NAME: &str = "web_search";
+const BRAVE_ENDPOINT: &str = "https://api.search.brave.com/res/v1/web/search";
+const GOOGLE_CSE_ENDPOINT: &str = "https://www.googleapis.com/customsearch/v1";
+const MAX_RESULTS_CAP: usize = 10;
+
+static SEARCH_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .user_agent("Tellar/0.1")
+        .timeout(Duration::from_secs(20))
+        .build()
+        .expect("Failed to create web search reqwest client")
+});
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SearchResult {
+    pub(crate) title: String,
+    pub(crate) url: String,
+    pub(crate) snippet: String,
+}
+
+/// Map a provider response onto titles, URLs, and snippets.
+pub(crate) fn parse_results(provider: SearchProvider, body: &Value) -> Vec<SearchResult> {
+    let (items, url_key, snippet_key) = match provider {
+        SearchProvider::Searxng => (&body["results"], "url", "content"),
+        SearchProvider::Brave => (&body["web"]["results"], "url", "description"),
+        SearchProvider::GoogleCse => (&body["items"], "link", "snippet"),
+    };
+    items
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            let url = item[url_key].as_str()?.to_string();
+            Some(SearchResult {
+                title: item["title"].as_str().unwrap_or(&url).trim().to_string(),
+                snippet: item[snippet_key]
+                    .as_str()
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                url,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn render_results(query: &str, results: &[SearchResult]) -> String {
+    if results.is_empty() {
+        return format!("No web results for \"{}\".", query);
+    }
+    let mut rendered = format!("Web results for \"{}\":\n", query);
+    for (index, result) in results.iter().enumerate() {
+        rendered.push_str(&format!(
+            "\n{}. {}\n   {}\n",
+            index + 1,
+            result.title,
+            result.url
+        ));
+        if !result.snippet.is_empty() {
+            rendered.push_str(&format!("   {}\n", result.snippet));
+        }
+    }
+    rendered
+}
+
+fn build_request(
+    settings: &WebSearchConfig,
+    query: &str,
+    count: usize,
+) -> anyhow::Result<reqwest::RequestBuilder> {
+    let count = count.to_string();
+    let request = match settings.provider {
+        SearchProvider::Searxng => {
+            if settings.base_url.trim().is_empty() {
+                return Err(anyhow::anyhow!(
+                    "web_search.base_url is required for SearxNG"
+                ));
+            }
+            let url = reqwest::Url::parse_with_params(
+                &format!("{}/search", settings.base_url.trim_end_matches('/')),
+                &[("q", query), ("format", "json")],
+            )?;
+            SEARCH_CLIENT.get(url)
+        }
+        SearchProvider::Brave => {
+            let url = reqwest::Url::parse_with_params(
+                BRAVE_ENDPOINT,
+                &[("q", query), ("count", count.as_str())],
+            )?;
+            SEARCH_CLIENT
+                .get(url)
+                .header("Accept", "application/json")
+                .header("X-Subscription-Token", &settings.api_key)
+        }
+        SearchProvider::GoogleCse => {
+            let url = reqwest::Url::parse_with_params(
+                GOOGLE_CSE_ENDPOINT,
+                &[
+                    ("key", settings.api_key.as_str()),
+                    ("cx", settings.cx.as_str()),
+                    ("q", query),
+                    ("num", count.as_str()),
+                ],
+            )?;
+            SEARCH_CLIENT.get(url)
+        }
+    };
+    Ok(request)
+}
+
+async fn search(
+    settings: &WebSearchConfig,
+    query: &str,
+    count: usize,
+) -> anyhow::Result<Vec<SearchResult>> {
+    // Strip the URL from transport errors: Google CSE carries the API key in the query.
+    let response = build_request(settings, query, count)?
+        .send()
+        .await
+        .map_err(|error| error.without_url())?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow::anyhow!("search provider returned HTTP {}", status));
+    }
+    let body: Value = response.json().await.map_err(|error| error.without_url())?;
+    let mut results = parse_results(settings.provider, &body);
+    results.truncate(count);
+    Ok(results)
+}
+
+pub(crate) fn web_search_tool_definition() -> Value {
+    json!({
+        "name": WEB_SEARCH_TOOL_NAME,
+        "description": "Search the web for current information. Returns numbered titles, URLs, and snippets; cite the URLs you rely on.",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "query": { "type": "string", "description": "The search query" },
+                "count": { "type": "number", "description": "Number of results to return. Defaults to web_search.max_results" }
+            },
+            "required": ["query"]
+        }
+    })
+}
+
+pub(crate) async fn run_web_search_tool(args: &Value, config: &Config) -> ToolExecutionResult {
+    let settings = &config.web_search;
+    if !settings.enabled {
+        return ToolExecutionResult::error(
+            "Error: `web_search` is not configured. Set `web_search.enabled` and a provider in tellar.yml.",
+        );
+    }
+    let Some(query) = args
+        .get("query")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|query| !query.is_empty())
+    else {
+        return ToolExecutionResult::error("Error: Missing required argument `query`.");
+    };
+    let count = args
+        .get("count")
+        .and_then(Value::as_u64)
+        .map(|count| count as usize)
+        .unwrap_or(settings.max_results)
+        .clamp(1, MAX_RESULTS_CAP);
+
+    match search(settings, query, count).await {
+        Ok(results) => ToolExecutionResult::success(render_results(query, &results)),
+        Err(error) => ToolExecutionResult::error(format!("Error: Web search failed: {}", error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_results_normalizes_each_provider() {
+        let searxng = json!({ "results": [
+            { "title": "Rust 2024", "url": "https://blog.rust-lang.org/", "content": "Edition\n  notes" }
+        ]});
+        let brave = json!({ "web": { "results": [
+            { "title": "Brave hit", "url": "https://brave.example/", "description": "From Brave" }
+        ]}});
+        let google = json!({ "items": [
+            { "title": "Google hit", "link": "https://google.example/", "snippet": "From CSE" },
+            { "title": "No link" }
+        ]});
+
+        let results = parse_results(SearchProvider::Searxng, &searxng);
+        assert_eq!(results[0].snippet, "Edition notes");
+        assert_eq!(
+            parse_results(SearchProvider::Brave, &brave)[0].url,
+            "https://brave.example/"
+        );
+        let results = parse_results(SearchProvider::GoogleCse, &google);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].snippet, "From CSE");
+
+        let rendered = render_results("rust", &results);
+        assert!(rendered.contains("1. Google hit\n   https://google.example/\n   From CSE"));
+    }
+
+    #[tokio::test]
+    async fn test_web_search_tool_requires_configuration() {
+        let result = run_web_search_tool(&json!({ "query": "news" }), &Config::default()).await;
+        assert!(result.is_error);
+        assert!(result.output.contains("web_search.enabled"));
+    }
+}
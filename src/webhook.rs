@@ -0,0 +1,501 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/webhook.rs
+ * Responsibility: Inbound webhook endpoint that inscribes external payloads into channel blackboards.
+ */
+
+use crate::StewardNotification;
+use crate::config::{BridgeConfig, BridgeKind, Config, WebhookRoute};
+use crate::discord::{ingest_store, resolve_folder_by_id};
+use crate::{log_info, log_warn};
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::{Path as UrlPath, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use chrono::Local;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+const MAX_PAYLOAD_CHARS: usize = 6000;
+
+static PLACEHOLDER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{\{\s*([A-Za-z0-9_.\-]+)\s*\}\}").expect("valid webhook placeholder regex")
+});
+
+struct WebhookState {
+    workspace_path: PathBuf,
+    config: Arc<Config>,
+    notif_tx: mpsc::Sender<StewardNotification>,
+}
+
+/// Render `{{a.b.c}}` placeholders from a JSON payload. Strings are inserted raw,
+/// other values as compact JSON, and missing fields as an empty string.
+pub(crate) fn render_payload_template(template: &str, payload: &Value) -> String {
+    render_template_with(template, payload, str::to_string)
+}
+
+/// For a template that is itself JSON, render it with strings JSON-escaped, so quotes and
+/// newlines in values keep the body valid. `None` when the template is not JSON.
+pub(crate) fn render_json_payload_template(template: &str, payload: &Value) -> Option<String> {
+    let probe = PLACEHOLDER_RE.replace_all(template, "0");
+    serde_json::from_str::<Value>(&probe).ok()?;
+    Some(render_template_with(template, payload, |text| {
+        let quoted = Value::String(text.to_string()).to_string();
+        quoted[1..quoted.len() - 1].to_string()
+    }))
+}
+
+fn render_template_with(
+    template: &str,
+    payload: &Value,
+    render_string: impl Fn(&str) -> String,
+) -> String {
+    PLACEHOLDER_RE
+        .replace_all(template, |caps: &regex::Captures| {
+            let mut value = payload;
+            for segment in caps[1].split('.') {
+                value = match value {
+                    Value::Array(items) => match segment.parse::<usize>() {
+                        Ok(index) => items.get(index).unwrap_or(&Value::Null),
+                        Err(_) => &Value::Null,
+                    },
+                    _ => value.get(segment).unwrap_or(&Value::Null),
+                };
+            }
+            match value {
+                Value::Null => String::new(),
+                Value::String(text) => render_string(text),
+                other => other.to_string(),
+            }
+        })
+        .into_owned()
+}
+
+fn default_payload_text(payload: &Value) -> String {
+    let mut pretty = serde_json::to_string_pretty(payload).unwrap_or_default();
+    if pretty.chars().count() > MAX_PAYLOAD_CHARS {
+        pretty = pretty.chars().take(MAX_PAYLOAD_CHARS).collect();
+        pretty.push_str("\n... [payload truncated]");
+    }
+    format!("```json\n{}\n```", pretty)
+}
+
+pub(crate) struct InscribedWebhook {
+    pub(crate) blackboard_path: PathBuf,
+    pub(crate) message_id: String,
+    pub(crate) content: String,
+}
+
+/// Append a webhook payload to today's blackboard of the route's channel.
+pub(crate) async fn inscribe_webhook(
+    workspace_path: &Path,
+    name: &str,
+    route: &WebhookRoute,
+    payload: &Value,
+) -> anyhow::Result<InscribedWebhook> {
+    let folder = resolve_folder_by_id(workspace_path, &route.channel_id).ok_or_else(|| {
+        anyhow::anyhow!(
+            "no channel folder found for channel_id {}",
+            route.channel_id
+        )
+    })?;
+
+    let content = match &route.template {
+        Some(template) => render_payload_template(template, payload),
+        None => default_payload_text(payload),
+    };
+    let daily_file = format!("{}.md", Local::now().format("%Y-%m-%d"));
+    let message_id = format!("webhook-{}", uuid::Uuid::new_v4());
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    ingest_store::append_to_message_log(
+        workspace_path,
+        &format!("{}/{}", folder, daily_file),
+        &format!("webhook:{}", name),
+        "webhook",
+        &content,
+        &message_id,
+        &timestamp,
+        None,
+        Vec::new(),
+    )
+    .await?;
+
+    Ok(InscribedWebhook {
+        blackboard_path: workspace_path
+            .join("channels")
+            .join(folder)
+            .join(daily_file),
+        message_id,
+        content,
+    })
+}
+
+/// Byte equality that takes the same time wherever the first difference is, so a
+/// token cannot be guessed one byte at a time. (ring deprecated its own helper.)
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Whether the request carries `token` in `X-Tellar-Token` or `Authorization: Bearer`.
+/// An empty `token` authorizes nothing; callers that allow open access check for it first.
+fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+
+    let matches = |presented: Option<&str>| {
+        presented.is_some_and(|presented| constant_time_eq(presented.as_bytes(), token.as_bytes()))
+    };
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    matches(header("x-tellar-token"))
+        || matches(header("authorization").and_then(|value| value.strip_prefix("Bearer ")))
+}
+
+async fn handle_hook(
+    State(state): State<Arc<WebhookState>>,
+    UrlPath(name): UrlPath<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, axum::Json<Value>) {
+    let settings = &state.config.inbound_webhook;
+    if !settings.token.is_empty() && !is_authorized(&headers, &settings.token) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            axum::Json(json!({ "error": "invalid webhook token" })),
+        );
+    }
+
+    let Some(route) = settings.routes.get(&name) else {
+        return (
+            StatusCode::NOT_FOUND,
+            axum::Json(json!({ "error": format!("unknown webhook route `{}`", name) })),
+        );
+    };
+
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(error) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                axum::Json(json!({ "error": format!("invalid JSON payload: {}", error) })),
+            );
+        }
+    };
+
+    let inscribed = match inscribe_webhook(&state.workspace_path, &name, route, &payload).await {
+        Ok(inscribed) => inscribed,
+        Err(error) => {
+            log_warn!("runtime", "⚠️ Failed to inscribe webhook `{}`: {:?}", name, error);
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                axum::Json(json!({ "error": error.to_string() })),
+            );
+        }
+    };
+    log_info!(
+        "runtime",
+        "🪝 Webhook `{}` inscribed into {:?}",
+        name,
+        inscribed.blackboard_path.file_name()
+    );
+
+    if route.wake
+        && let Err(error) = state
+            .notif_tx
+            .send(StewardNotification {
+                blackboard_path: inscribed.blackboard_path.clone(),
+                channel_id: route.channel_id.clone(),
+                guild_id: state
+                    .config
+                    .discord
+                    .guild_id
+                    .clone()
+                    .unwrap_or_else(|| "0".to_string()),
+                message_id: inscribed.message_id.clone(),
+                content: inscribed.content.clone(),
+            })
+            .await
+    {
+        log_warn!(
+            "runtime",
+            "⚠️ Failed to enqueue steward notification for webhook `{}`: {:?}",
+            name, error
+        );
+    }
+
+    (
+        StatusCode::ACCEPTED,
+        axum::Json(json!({ "message_id": inscribed.message_id, "woke_steward": route.wake })),
+    )
+}
+
+/// The secret an HTTP bridge's inbound posts must carry: its own `token`, else
+/// `inbound_webhook.token`. `None` when neither is set, and the bridge accepts nothing.
+fn bridge_token<'a>(bridge: &'a BridgeConfig, config: &'a Config) -> Option<&'a str> {
+    [bridge.token.as_str(), config.inbound_webhook.token.as_str()]
+        .into_iter()
+        .find(|token| !token.is_empty())
+}
+
+/// Accept an inbound message for an HTTP bridge and answer it in the background.
+async fn handle_bridge(
+    State(state): State<Arc<WebhookState>>,
+    UrlPath(name): UrlPath<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, axum::Json<Value>) {
+    let Some(bridge) = state
+        .config
+        .bridges
+        .iter()
+        .find(|bridge| bridge.name == name && bridge.kind == BridgeKind::Http)
+        .cloned()
+    else {
+        return (
+            StatusCode::NOT_FOUND,
+            axum::Json(json!({ "error": format!("unknown bridge `{}`", name) })),
+        );
+    };
+
+    let Some(token) = bridge_token(&bridge, &state.config) else {
+        return (
+            StatusCode::FORBIDDEN,
+            axum::Json(json!({ "error": format!("bridge `{}` has no token configured", name) })),
+        );
+    };
+    if !is_authorized(&headers, token) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            axum::Json(json!({ "error": "invalid bridge token" })),
+        );
+    }
+
+    let Some(message) = serde_json::from_slice::<Value>(&body)
+        .ok()
+        .and_then(|payload| crate::bridge::parse_http_message(&payload))
+    else {
+        return (
+            StatusCode::BAD_REQUEST,
+            axum::Json(json!({ "error": "expected {\"from\", \"text\"} JSON payload" })),
+        );
+    };
+
+    let workspace_path = state.workspace_path.clone();
+    let config = Arc::clone(&state.config);
+    tokio::spawn(async move {
+        if let Err(error) =
+            crate::bridge::handle_inbound(&workspace_path, config, &bridge, message).await
+        {
+            log_warn!(
+                "runtime",
+                "⚠️ {} bridge failed to handle a message: {:?}",
+                bridge.name,
+                error
+            );
+        }
+    });
+
+    (StatusCode::ACCEPTED, axum::Json(json!({ "accepted": true })))
+}
+
+#[derive(Deserialize)]
+struct RunRequest {
+    path: PathBuf,
+    #[serde(default)]
+    retry: bool,
+}
+
+/// Run a thread or ritual file now, in the background, as `tellarctl run` does. Only
+/// mounted when `inbound_webhook.token` is set.
+async fn handle_run(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, axum::Json<Value>) {
+    if !is_authorized(&headers, &state.config.inbound_webhook.token) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            axum::Json(json!({ "error": "invalid webhook token" })),
+        );
+    }
+    let Ok(request) = serde_json::from_slice::<RunRequest>(&body) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            axum::Json(json!({ "error": "expected {\"path\", \"retry\"} JSON payload" })),
+        );
+    };
+    if let Err(error) = crate::manual_run::resolve_target(&state.workspace_path, &request.path) {
+        return (
+            StatusCode::NOT_FOUND,
+            axum::Json(json!({ "error": error.to_string() })),
+        );
+    }
+
+    let workspace_path = state.workspace_path.clone();
+    let config = Arc::clone(&state.config);
+    let path = request.path.clone();
+    tokio::spawn(async move {
+        if let Err(error) =
+            crate::manual_run::run_now(&workspace_path, config, &path, request.retry).await
+        {
+            log_warn!("runtime", "⚠️ Manual run of {:?} failed: {:?}", path, error);
+        }
+    });
+
+    (
+        StatusCode::ACCEPTED,
+        axum::Json(json!({ "path": request.path, "retry": request.retry })),
+    )
+}
+
+/// `GET /healthz`, unauthenticated, for container healthchecks: the process is up, and
+/// whether it is in degraded mode.
+async fn handle_healthz() -> axum::Json<Value> {
+    let status = if crate::api_health::is_degraded() {
+        "degraded"
+    } else {
+        "ok"
+    };
+    axum::Json(json!({ "status": status }))
+}
+
+/// Serve `POST /hooks/<name>` on `inbound_webhook.bind` until the process exits.
+pub async fn start_webhook_server(
+    workspace_path: PathBuf,
+    config: Arc<Config>,
+    notif_tx: mpsc::Sender<StewardNotification>,
+) -> anyhow::Result<()> {
+    let bind = config.inbound_webhook.bind.clone();
+    let has_token = !config.inbound_webhook.token.is_empty();
+    if !has_token {
+        log_warn!(
+            "runtime",
+            "⚠️ inbound_webhook.token is empty; /hooks accepts unauthenticated requests and /run is disabled."
+        );
+    }
+
+    for bridge in &config.bridges {
+        if bridge.kind == BridgeKind::Http && bridge_token(bridge, &config).is_none() {
+            log_warn!(
+                "runtime",
+                "⚠️ Bridge `{}` has no token and neither does inbound_webhook; it will refuse every message.",
+                bridge.name
+            );
+        }
+    }
+
+    let state = Arc::new(WebhookState {
+        workspace_path,
+        config,
+        notif_tx,
+    });
+    let mut app = Router::new()
+        .route("/hooks/{name}", post(handle_hook))
+        .route("/bridges/{name}", post(handle_bridge))
+        .route("/healthz", get(handle_healthz));
+    if has_token {
+        app = app.route("/run", post(handle_run));
+    }
+    let app = app.with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(&bind).await?;
+    log_info!(
+        "runtime",
+        "🪝 Inbound webhooks listening on http://{}/hooks/<name>",
+        bind
+    );
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_render_payload_template_resolves_nested_fields() {
+        let payload = json!({
+            "action": "opened",
+            "repository": { "full_name": "eric9n/tellar" },
+            "labels": [{ "name": "bug" }],
+            "number": 42
+        });
+
+        let rendered = render_payload_template(
+            "{{action}} #{{number}} in {{ repository.full_name }} [{{labels.0.name}}]{{missing}}",
+            &payload,
+        );
+        assert_eq!(rendered, "opened #42 in eric9n/tellar [bug]");
+    }
+
+    #[tokio::test]
+    async fn test_inscribe_webhook_appends_to_channel_daily_log() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("channels/alerts-654321")).unwrap();
+        let route = WebhookRoute {
+            channel_id: "111654321".to_string(),
+            template: Some("Monitor {{monitor}} is {{status}}".to_string()),
+            wake: true,
+        };
+
+        let inscribed = inscribe_webhook(
+            dir.path(),
+            "uptime",
+            &route,
+            &json!({ "monitor": "api", "status": "down" }),
+        )
+        .await
+        .unwrap();
+
+        let log = std::fs::read_to_string(&inscribed.blackboard_path).unwrap();
+        assert!(
+            inscribed
+                .blackboard_path
+                .starts_with(dir.path().join("channels/alerts-654321"))
+        );
+        assert!(log.contains("**Author**: webhook:uptime"));
+        assert!(log.contains(&format!("**Message ID**: {}", inscribed.message_id)));
+        assert!(log.contains("Monitor api is down"));
+    }
+
+    #[test]
+    fn test_is_authorized_accepts_header_or_bearer_token() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_authorized(&headers, "secret"));
+        assert!(!is_authorized(&headers, ""));
+
+        headers.insert("x-tellar-token", "secret".parse().unwrap());
+        assert!(is_authorized(&headers, "secret"));
+        assert!(!is_authorized(&headers, "secres"));
+        assert!(!is_authorized(&headers, "secret2"));
+
+        let mut bearer = HeaderMap::new();
+        bearer.insert("authorization", "Bearer secret".parse().unwrap());
+        assert!(is_authorized(&bearer, "secret"));
+    }
+
+    #[test]
+    fn test_bridge_token_prefers_bridge_secret_and_requires_one() {
+        let mut config = Config::default();
+        let mut bridge = BridgeConfig {
+            name: "whatsapp".to_string(),
+            kind: BridgeKind::Http,
+            ..BridgeConfig::default()
+        };
+        assert_eq!(bridge_token(&bridge, &config), None);
+
+        config.inbound_webhook.token = "shared".to_string();
+        assert_eq!(bridge_token(&bridge, &config), Some("shared"));
+
+        bridge.token = "bridge-secret".to_string();
+        assert_eq!(bridge_token(&bridge, &config), Some("bridge-secret"));
+    }
+}
@@ -0,0 +1,259 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/workspace_git.rs
+ * Responsibility: Commit the Steward's workspace edits to git and read file history back.
+ */
+
+use crate::config::GitConfig;
+use crate::execution_contract::ToolCallSpec;
+use crate::{log_info, log_warn};
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+
+/// Workspace folders that carry the Steward's documents. `tellar.yml` is never tracked.
+const TRACKED_PATHS: [&str; 5] = ["agents", "brain", "channels", "rituals", "skills"];
+const DEFAULT_GITIGNORE: &str = "tellar.yml\n.tellar.lock\nbrain/attachments/\nbrain/traces/\nbrain/feeds/\nbrain/tellar.db-*\nbrain/artifacts/\nbrain/index/\nbrain/sessions/\nbrain/logs/\nbrain/journal/\nskills/*/.venv/\n";
+/// Runtime folders rewritten on every log line or write; ignored, and untracked in guilds
+/// that committed them before they were ignored.
+pub const RUNTIME_PATHS: [&str; 2] = ["brain/logs/", "brain/journal/"];
+const MAX_SUBJECT_CHARS: usize = 72;
+
+/// Turns run concurrently; git's index lock must not be contended.
+static COMMIT_LOCK: Mutex<()> = Mutex::new(());
+
+fn git(base_path: &Path, args: &[&str]) -> anyhow::Result<std::process::Output> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(base_path)
+        .args(args)
+        .output()
+        .map_err(|error| anyhow::anyhow!("failed to run git: {}", error))?;
+    Ok(output)
+}
+
+fn git_checked(base_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = git(base_path, args)?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Initialize the workspace repository and its `.gitignore` on first use.
+pub fn ensure_repo(base_path: &Path) -> anyhow::Result<()> {
+    if !base_path.join(".git").exists() {
+        git_checked(base_path, &["init", "-q"])?;
+        log_info!("guardian", "📚 Initialized git history for {:?}", base_path);
+    }
+    let gitignore = base_path.join(".gitignore");
+    if !gitignore.exists() {
+        std::fs::write(gitignore, DEFAULT_GITIGNORE)?;
+    }
+    Ok(())
+}
+
+/// Stop tracking [`RUNTIME_PATHS`] if an older `.gitignore` let them be committed. The
+/// files stay on disk.
+pub fn untrack_runtime_paths(base_path: &Path) -> anyhow::Result<()> {
+    let mut args = vec!["rm", "-r", "-q", "--cached", "--ignore-unmatch", "--"];
+    args.extend(RUNTIME_PATHS);
+    git_checked(base_path, &args).map(|_| ())
+}
+
+/// Subject names the turn and request; the body lists channel and tools so `git log` reads
+/// as a journal of what the Steward did.
+pub(crate) fn turn_commit_message(
+    label: &str,
+    channel_id: &str,
+    request: &str,
+    planned_calls: &[ToolCallSpec],
+) -> String {
+    let request = request
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .unwrap_or("workspace update");
+    let mut subject = format!("{}: {}", label, request);
+    if subject.chars().count() > MAX_SUBJECT_CHARS {
+        subject = subject.chars().take(MAX_SUBJECT_CHARS - 1).collect();
+        subject.push('…');
+    }
+
+    let mut message = format!("{}\n\nChannel: {}", subject, channel_id);
+    if !planned_calls.is_empty() {
+        let tools: Vec<&str> = planned_calls
+            .iter()
+            .map(|call| call.tool_name.as_str())
+            .collect();
+        message.push_str(&format!("\nTools: {}", tools.join(", ")));
+    }
+    message
+}
+
+/// Stage the tracked folders and commit them. Returns the short hash, or `None` when
+/// nothing changed.
+pub fn commit_workspace(
+    base_path: &Path,
+    settings: &GitConfig,
+    message: &str,
+) -> anyhow::Result<Option<String>> {
+    let _guard = COMMIT_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    ensure_repo(base_path)?;
+
+    let mut add = vec!["add", "-A", "--", ".gitignore"];
+    add.extend(
+        TRACKED_PATHS
+            .iter()
+            .copied()
+            .filter(|path| base_path.join(path).exists()),
+    );
+    git_checked(base_path, &add)?;
+
+    if git(base_path, &["diff", "--cached", "--quiet"])?
+        .status
+        .success()
+    {
+        return Ok(None);
+    }
+
+    let name = format!("user.name={}", settings.author_name);
+    let email = format!("user.email={}", settings.author_email);
+    git_checked(
+        base_path,
+        &["-c", &name, "-c", &email, "commit", "-q", "-m", message],
+    )?;
+    Ok(Some(
+        git_checked(base_path, &["rev-parse", "--short", "HEAD"])?
+            .trim()
+            .to_string(),
+    ))
+}
+
+/// Commit after an agent turn; failures are logged and never interrupt the task.
+pub(crate) async fn commit_turn(
+    base_path: &Path,
+    settings: &GitConfig,
+    label: &str,
+    channel_id: &str,
+    request: &str,
+    planned_calls: &[ToolCallSpec],
+) {
+    let message = turn_commit_message(label, channel_id, request, planned_calls);
+    let base_path = base_path.to_path_buf();
+    let settings = settings.clone();
+    match tokio::task::spawn_blocking(move || commit_workspace(&base_path, &settings, &message))
+        .await
+    {
+        Ok(Ok(Some(hash))) => log_info!("guardian", "📚 Workspace committed as {}", hash),
+        Ok(Ok(None)) => {}
+        Ok(Err(error)) => log_warn!(
+            "guardian",
+            "⚠️ Failed to commit workspace changes: {:?}",
+            error
+        ),
+        Err(error) => log_warn!("guardian", "⚠️ Workspace commit task panicked: {:?}", error),
+    }
+}
+
+/// `git log` for one workspace file, following renames, optionally with patches.
+pub fn file_history(
+    base_path: &Path,
+    file: &str,
+    limit: usize,
+    patch: bool,
+) -> anyhow::Result<String> {
+    if !base_path.join(".git").exists() {
+        return Err(anyhow::anyhow!(
+            "{:?} has no git history yet. Set `git.enabled: true` in tellar.yml.",
+            base_path
+        ));
+    }
+    let limit = format!("-n{}", limit.max(1));
+    let mut args = vec![
+        "log",
+        "--follow",
+        limit.as_str(),
+        "--date=format:%Y-%m-%d %H:%M:%S",
+        "--format=%h  %ad  %s",
+    ];
+    if patch {
+        args.push("--patch");
+    }
+    args.extend(["--", file]);
+    git_checked(base_path, &args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_turn_commit_message_summarizes_request_and_tools() {
+        let message = turn_commit_message(
+            "Conversational",
+            "123",
+            "### New Messages\n\nPlease remember that the boiler was serviced today",
+            &[ToolCallSpec {
+                tool_name: "write".to_string(),
+                args: json!({}),
+            }],
+        );
+        let mut lines = message.lines();
+        let subject = lines.next().unwrap();
+        assert!(subject.starts_with("Conversational: Please remember"));
+        assert!(subject.chars().count() <= MAX_SUBJECT_CHARS);
+        assert!(message.contains("Channel: 123\nTools: write"));
+    }
+
+    #[test]
+    fn test_commit_workspace_tracks_documents_but_not_config() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("brain")).unwrap();
+        std::fs::write(dir.path().join("brain/KNOWLEDGE.md"), "# Knowledge\n").unwrap();
+        std::fs::write(
+            dir.path().join("tellar.yml"),
+            "gemini:\n  api_key: secret\n",
+        )
+        .unwrap();
+        let settings = GitConfig::default();
+
+        let hash = commit_workspace(dir.path(), &settings, "first").unwrap();
+        assert!(hash.is_some());
+        assert_eq!(
+            commit_workspace(dir.path(), &settings, "again").unwrap(),
+            None
+        );
+
+        let tracked = git_checked(dir.path(), &["ls-files"]).unwrap();
+        assert!(tracked.contains("brain/KNOWLEDGE.md"));
+        assert!(!tracked.contains("tellar.yml"));
+
+        std::fs::write(
+            dir.path().join("brain/KNOWLEDGE.md"),
+            "# Knowledge\n- boiler\n",
+        )
+        .unwrap();
+        commit_workspace(dir.path(), &settings, "remember boiler").unwrap();
+        let history = file_history(dir.path(), "brain/KNOWLEDGE.md", 10, true).unwrap();
+        assert!(history.contains("remember boiler"));
+        assert!(history.contains("+- boiler"));
+
+        std::fs::create_dir_all(dir.path().join("brain/logs")).unwrap();
+        std::fs::write(dir.path().join("brain/logs/tellar.jsonl"), "{}\n").unwrap();
+        git_checked(dir.path(), &["add", "-f", "brain/logs/tellar.jsonl"]).unwrap();
+        untrack_runtime_paths(dir.path()).unwrap();
+        commit_workspace(dir.path(), &settings, "log line").unwrap();
+        let tracked = git_checked(dir.path(), &["ls-files"]).unwrap();
+        assert!(!tracked.contains("brain/logs"));
+        assert!(dir.path().join("brain/logs/tellar.jsonl").exists());
+    }
+}
@@ -0,0 +1,226 @@
+/*
+ * Tellar - Minimal Document-Driven Cyber Steward
+ * File Path: src/workspace_lock.rs
+ * Responsibility: Exclusive guild lock file so only one tellar process serves a workspace.
+ */
+
+#[cfg(unix)]
+use crate::log_warn;
+use anyhow::{Context, Result, anyhow};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub const LOCK_FILE_NAME: &str = ".tellar.lock";
+
+/// Holds the guild lock for the lifetime of the process; the file is removed on drop.
+/// On unix the open file also carries an exclusive `flock`, which the kernel drops when
+/// the process dies, so a crash never leaves a lock that looks held.
+#[derive(Debug)]
+pub struct WorkspaceLock {
+    path: PathBuf,
+    #[cfg(unix)]
+    _file: fs::File,
+}
+
+/// Owner recorded in an existing lock file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockOwner {
+    pub pid: u32,
+    pub started_at: String,
+}
+
+fn parse_lock_file(content: &str) -> Option<LockOwner> {
+    let mut lines = content.lines();
+    let pid = lines.next()?.trim().parse().ok()?;
+    let started_at = lines.next().unwrap_or("unknown").trim().to_string();
+    Some(LockOwner { pid, started_at })
+}
+
+#[cfg(unix)]
+pub fn is_process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks existence; EPERM means it exists under another user.
+    let alive = unsafe { libc::kill(pid, 0) == 0 };
+    alive || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+pub fn is_process_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Read the current lock owner, if a lock file exists and is well-formed.
+pub fn read_lock_owner(guild_path: &Path) -> Option<LockOwner> {
+    let content = fs::read_to_string(guild_path.join(LOCK_FILE_NAME)).ok()?;
+    parse_lock_file(&content)
+}
+
+fn held_error(owner: Option<LockOwner>, path: &Path) -> anyhow::Error {
+    let owner = owner
+        .map(|owner| format!("PID {}, started {}", owner.pid, owner.started_at))
+        .unwrap_or_else(|| "starting up".to_string());
+    anyhow!(
+        "another tellar instance ({}) already holds {}, under whatever profile it runs. Stop it first, or delete the lock file if that process is not tellar.",
+        owner,
+        path.display()
+    )
+}
+
+fn owner_record() -> String {
+    format!(
+        "{}\n{}\n",
+        std::process::id(),
+        chrono::Local::now().to_rfc3339()
+    )
+}
+
+impl WorkspaceLock {
+    /// Acquire the guild lock with an exclusive `flock` on the lock file, then record this
+    /// process in it. A file nobody has locked is left over from a crash and is taken
+    /// over, unless it names another live process (a tellar from before `flock`).
+    #[cfg(unix)]
+    pub fn acquire(guild_path: &Path) -> Result<Self> {
+        use std::os::unix::fs::MetadataExt;
+        use std::os::unix::io::AsRawFd;
+
+        let path = guild_path.join(LOCK_FILE_NAME);
+        for _ in 0..3 {
+            let mut file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&path)
+                .with_context(|| format!("opening lock file {}", path.display()))?;
+            if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } != 0 {
+                let error = std::io::Error::last_os_error();
+                if error.raw_os_error() == Some(libc::EWOULDBLOCK) {
+                    // The holder may not have written its PID yet; it is held all the same.
+                    return Err(held_error(read_lock_owner(guild_path), &path));
+                }
+                return Err(error).with_context(|| format!("locking {}", path.display()));
+            }
+            // The previous holder may have removed the file between our open and flock,
+            // leaving us a lock on a file nobody else can see.
+            let current = fs::metadata(&path).map(|meta| meta.ino()).ok();
+            if current != Some(file.metadata()?.ino()) {
+                continue;
+            }
+
+            if let Some(owner) = read_lock_owner(guild_path) {
+                if owner.pid != std::process::id() && is_process_alive(owner.pid) {
+                    return Err(held_error(Some(owner), &path));
+                }
+                log_warn!(
+                    "runtime",
+                    "🧹 Taking over stale workspace lock {} (previous PID: {}).",
+                    path.display(),
+                    owner.pid
+                );
+            }
+            file.set_len(0)?;
+            file.write_all(owner_record().as_bytes())
+                .with_context(|| format!("writing lock file {}", path.display()))?;
+            return Ok(Self { path, _file: file });
+        }
+
+        Err(anyhow!(
+            "could not acquire workspace lock {} (it was recreated concurrently)",
+            path.display()
+        ))
+    }
+
+    /// Acquire the guild lock by hard-linking a fully written temp file into place, so
+    /// the lock never exists without its owner. Without `flock` or a process check, an
+    /// existing lock is never treated as stale.
+    #[cfg(not(unix))]
+    pub fn acquire(guild_path: &Path) -> Result<Self> {
+        let path = guild_path.join(LOCK_FILE_NAME);
+        let staged = guild_path.join(format!("{}.{}", LOCK_FILE_NAME, std::process::id()));
+        fs::write(&staged, owner_record())
+            .with_context(|| format!("writing {}", staged.display()))?;
+        let linked = fs::hard_link(&staged, &path);
+        let _ = fs::remove_file(&staged);
+        match linked {
+            Ok(()) => Ok(Self { path }),
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {
+                Err(held_error(read_lock_owner(guild_path), &path))
+            }
+            Err(error) => {
+                Err(error).with_context(|| format!("creating lock file {}", path.display()))
+            }
+        }
+    }
+}
+
+impl Drop for WorkspaceLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_acquire_refuses_live_owner_and_releases_on_drop() {
+        let dir = tempdir().unwrap();
+        // PID 1 is always alive on unix; pretend it holds the lock.
+        fs::write(
+            dir.path().join(LOCK_FILE_NAME),
+            "1\n2026-03-04T10:00:00+00:00\n",
+        )
+        .unwrap();
+        let error = WorkspaceLock::acquire(dir.path()).unwrap_err();
+        assert!(error.to_string().contains("PID 1"));
+
+        fs::remove_file(dir.path().join(LOCK_FILE_NAME)).unwrap();
+        let lock = WorkspaceLock::acquire(dir.path()).unwrap();
+        assert_eq!(read_lock_owner(dir.path()).unwrap().pid, std::process::id());
+        drop(lock);
+        assert!(!dir.path().join(LOCK_FILE_NAME).exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_acquire_refuses_a_flocked_lock_even_before_its_pid_is_written() {
+        use std::os::unix::io::AsRawFd;
+
+        let dir = tempdir().unwrap();
+        let file = fs::File::create(dir.path().join(LOCK_FILE_NAME)).unwrap();
+        assert_eq!(
+            unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) },
+            0
+        );
+        let error = WorkspaceLock::acquire(dir.path()).unwrap_err();
+        assert!(error.to_string().contains("starting up"), "{}", error);
+        drop(file);
+
+        let lock = WorkspaceLock::acquire(dir.path()).unwrap();
+        assert!(WorkspaceLock::acquire(dir.path()).is_err());
+        drop(lock);
+        assert!(WorkspaceLock::acquire(dir.path()).is_ok());
+    }
+
+    #[test]
+    fn test_acquire_replaces_stale_or_corrupt_lock() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join(LOCK_FILE_NAME), "not a pid").unwrap();
+        let lock = WorkspaceLock::acquire(dir.path()).unwrap();
+        assert_eq!(read_lock_owner(dir.path()).unwrap().pid, std::process::id());
+        drop(lock);
+
+        // u32::MAX is never a live PID.
+        fs::write(
+            dir.path().join(LOCK_FILE_NAME),
+            format!("{}\nold\n", u32::MAX),
+        )
+        .unwrap();
+        assert!(WorkspaceLock::acquire(dir.path()).is_ok());
+    }
+}
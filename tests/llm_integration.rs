@@ -39,13 +39,15 @@ async fn test_full_plan_driven_ritual_turn_with_gemini_3() {
         gemini: tellar::config::GeminiConfig {
             api_key: api_key.clone(),
             model: "gemini-3-flash-preview".to_string(),
+            temperature: None,
         },
         discord: tellar::config::DiscordConfig {
             token: "fake".to_string(),
             guild_id: None,
             channel_mappings: None,
+            ..Default::default()
         },
-        runtime: tellar::config::RuntimeConfig::default(),
+        ..Default::default()
     };
 
     // 2. Prepare initial state
@@ -126,20 +128,25 @@ async fn test_privileged_request_with_exec_disabled_settles_without_completing_r
     )
     .unwrap();
 
-    let mut runtime = tellar::config::RuntimeConfig::default();
-    runtime.privileged = false;
+    let runtime = tellar::config::RuntimeConfig {
+        privileged: false,
+        ..Default::default()
+    };
 
     let config = tellar::config::Config {
         gemini: tellar::config::GeminiConfig {
             api_key,
             model: "gemini-3-flash-preview".to_string(),
+            temperature: None,
         },
         discord: tellar::config::DiscordConfig {
             token: "fake".to_string(),
             guild_id: None,
             channel_mappings: None,
+            ..Default::default()
         },
         runtime,
+        ..Default::default()
     };
 
     let path = base_path.join("rituals").join("host_path.md");